@@ -0,0 +1,76 @@
+use ble_data_struct::{
+    data_types::{
+        complete_local_name::CompleteLocalName,
+        data_type_parser::{DataTypeParseResult, DataTypeParseResults, Parser},
+        flags::Flags,
+        manufacturer_specific_data::ManufacturerSpecificData,
+        service_data_16bit_uuid::ServiceData16BitUUID,
+    },
+    uuid_from_u16,
+};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+/// Legacy advertising payload with 6 data structures (28 bytes).
+const PAYLOAD: [u8; 28] = [
+    0x02, 0x01, 0x06, // Flags
+    0x03, 0x03, 0x0f, 0x18, // Complete List of 16-bit Service Class UUIDs
+    0x02, 0x0a, 0x00, // Tx Power Level
+    0x06, 0xff, 0x4c, 0x00, 0x01, 0x02, 0x03, // Manufacturer Specific Data
+    0x04, 0x16, 0x0f, 0x18, 0x64, // Service Data - 16-bit UUID
+    0x05, 0x09, 0x74, 0x65, 0x6d, 0x70, // Complete Local Name
+];
+
+fn parse_single_structure(c: &mut Criterion) {
+    let data = PAYLOAD[10..17].to_vec();
+    c.bench_function("parse_single_structure", |b| {
+        b.iter(|| DataTypeParseResult::from(black_box(&data)))
+    });
+}
+
+fn parse_payload(c: &mut Criterion) {
+    let data = PAYLOAD.to_vec();
+    let results = DataTypeParseResults::from(&data);
+    assert_eq!(6, results.results.len());
+    assert_eq!(0, results.errors().count());
+    c.bench_function("parse_payload", |b| {
+        b.iter(|| DataTypeParseResults::from(black_box(&data)))
+    });
+}
+
+fn parse_payload_reuse(c: &mut Criterion) {
+    let mut parser = Parser::default();
+    c.bench_function("parse_payload_reuse", |b| {
+        b.iter(|| parser.parse(black_box(&PAYLOAD)).results.len())
+    });
+}
+
+fn serialize(c: &mut Criterion) {
+    let flags = Flags::try_from(&PAYLOAD[0..3].to_vec()).unwrap();
+    let manufacturer_specific_data = ManufacturerSpecificData::new(0x004c, &vec![0x01, 0x02, 0x03]);
+    let service_data = ServiceData16BitUUID::new(&uuid_from_u16(0x180f), &vec![0x64]);
+    let complete_local_name = CompleteLocalName::new(&"temp".to_string());
+    c.bench_function("serialize", |b| {
+        b.iter(|| {
+            let mut data: Vec<u8> = Vec::with_capacity(31);
+            let mut flags: Vec<u8> = black_box(&flags).clone().into();
+            let mut manufacturer_specific_data: Vec<u8> =
+                black_box(&manufacturer_specific_data).clone().into();
+            let mut service_data: Vec<u8> = black_box(&service_data).clone().into();
+            let mut complete_local_name: Vec<u8> = black_box(&complete_local_name).clone().into();
+            data.append(&mut flags);
+            data.append(&mut manufacturer_specific_data);
+            data.append(&mut service_data);
+            data.append(&mut complete_local_name);
+            data
+        })
+    });
+}
+
+criterion_group!(
+    benches,
+    parse_single_structure,
+    parse_payload,
+    parse_payload_reuse,
+    serialize
+);
+criterion_main!(benches);
@@ -0,0 +1,4 @@
+fn main() {
+    #[cfg(feature = "uniffi")]
+    uniffi::generate_scaffolding("src/ble_data_struct.udl").unwrap();
+}
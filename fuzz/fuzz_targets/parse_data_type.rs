@@ -0,0 +1,24 @@
+#![no_main]
+
+use arbitrary::Arbitrary;
+use ble_data_struct::data_types::data_type_parser::DataTypeParseResult;
+use libfuzzer_sys::fuzz_target;
+
+/// One data structure with consistent length byte, so every data type parser is reached.
+#[derive(Arbitrary, Debug)]
+struct Input {
+    data_type: u8,
+    data: Vec<u8>,
+    truncate: bool,
+}
+
+fuzz_target!(|input: Input| {
+    let mut value: Vec<u8> = input.data;
+    value.truncate(254);
+    value.insert(0, input.data_type);
+    value.insert(0, value.len() as u8);
+    if input.truncate {
+        value.pop();
+    }
+    let _ = DataTypeParseResult::from(&value);
+});
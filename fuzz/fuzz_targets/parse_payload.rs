@@ -0,0 +1,20 @@
+#![no_main]
+
+use ble_data_struct::data_types::{
+    data_type_parser::{DataTypeParseResults, Parser},
+    parse_options::{ParseOptions, Utf8DecodeMode},
+};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let results = DataTypeParseResults::from(&data.to_vec());
+    assert_eq!(results.results.len(), results.offsets().len());
+    for (_, offset, _) in results.errors() {
+        assert!(offset.unwrap() < data.len());
+    }
+    let _ = results.service_uuids();
+    let _ = results.device_name();
+
+    let mut parser = Parser::new(ParseOptions::new(Utf8DecodeMode::Strict));
+    assert_eq!(results.results.len(), parser.parse(data).results.len());
+});
@@ -0,0 +1,76 @@
+#![no_main]
+
+use arbitrary::Arbitrary;
+use ble_data_struct::data_types::{
+    advertising_interval::AdvertisingInterval, appearance::Appearance,
+    class_of_device::ClassOfDevice,
+    complete_list_of_128bit_service_uuids::CompleteListOf128BitServiceUuids,
+    complete_list_of_16bit_service_uuids::CompleteListOf16BitServiceUuids,
+    complete_list_of_32bit_service_uuids::CompleteListOf32BitServiceUuids,
+    complete_local_name::CompleteLocalName, flags::Flags, le_role::LeRole,
+    manufacturer_specific_data::ManufacturerSpecificData,
+    peripheral_connection_interval_range::PeripheralConnectionIntervalRange,
+    service_data_128bit_uuid::ServiceData128BitUUID, service_data_16bit_uuid::ServiceData16BitUUID,
+    service_data_32bit_uuid::ServiceData32BitUUID, shortened_local_name::ShortenedLocalName,
+    tx_power_level::TxPowerLevel,
+};
+use libfuzzer_sys::fuzz_target;
+
+/// Data types generated by their `arbitrary` feature implementations.
+#[derive(Arbitrary, Debug)]
+enum Input {
+    AdvertisingInterval(AdvertisingInterval),
+    Appearance(Appearance),
+    ClassOfDevice(ClassOfDevice),
+    CompleteListOf16BitServiceUuids(CompleteListOf16BitServiceUuids),
+    CompleteListOf32BitServiceUuids(CompleteListOf32BitServiceUuids),
+    CompleteListOf128BitServiceUuids(CompleteListOf128BitServiceUuids),
+    CompleteLocalName(CompleteLocalName),
+    Flags(Flags),
+    LeRole(LeRole),
+    ManufacturerSpecificData(ManufacturerSpecificData),
+    PeripheralConnectionIntervalRange(PeripheralConnectionIntervalRange),
+    ServiceData16BitUUID(ServiceData16BitUUID),
+    ServiceData32BitUUID(ServiceData32BitUUID),
+    ServiceData128BitUUID(ServiceData128BitUUID),
+    ShortenedLocalName(ShortenedLocalName),
+    TxPowerLevel(TxPowerLevel),
+}
+
+/// Serialize `value`, parse it again and compare.
+macro_rules! round_trip {
+    ($data_type:ident, $value:expr) => {{
+        let value: $data_type = $value;
+        let data: Vec<u8> = value.clone().into();
+        assert_eq!(Ok(value), $data_type::try_from(&data));
+    }};
+}
+
+fuzz_target!(|input: Input| {
+    match input {
+        Input::AdvertisingInterval(value) => round_trip!(AdvertisingInterval, value),
+        Input::Appearance(value) => round_trip!(Appearance, value),
+        Input::ClassOfDevice(value) => round_trip!(ClassOfDevice, value),
+        Input::CompleteListOf16BitServiceUuids(value) => {
+            round_trip!(CompleteListOf16BitServiceUuids, value)
+        }
+        Input::CompleteListOf32BitServiceUuids(value) => {
+            round_trip!(CompleteListOf32BitServiceUuids, value)
+        }
+        Input::CompleteListOf128BitServiceUuids(value) => {
+            round_trip!(CompleteListOf128BitServiceUuids, value)
+        }
+        Input::CompleteLocalName(value) => round_trip!(CompleteLocalName, value),
+        Input::Flags(value) => round_trip!(Flags, value),
+        Input::LeRole(value) => round_trip!(LeRole, value),
+        Input::ManufacturerSpecificData(value) => round_trip!(ManufacturerSpecificData, value),
+        Input::PeripheralConnectionIntervalRange(value) => {
+            round_trip!(PeripheralConnectionIntervalRange, value)
+        }
+        Input::ServiceData16BitUUID(value) => round_trip!(ServiceData16BitUUID, value),
+        Input::ServiceData32BitUUID(value) => round_trip!(ServiceData32BitUUID, value),
+        Input::ServiceData128BitUUID(value) => round_trip!(ServiceData128BitUUID, value),
+        Input::ShortenedLocalName(value) => round_trip!(ShortenedLocalName, value),
+        Input::TxPowerLevel(value) => round_trip!(TxPowerLevel, value),
+    }
+});
@@ -0,0 +1,257 @@
+//! Advertising PDU airtime module.
+
+use crate::{
+    data_types::lint::LEGACY_ADVERTISING_DATA_MAX,
+    extended_header::{adi::ADI_SIZE, aux_ptr::AUX_PTR_SIZE, sync_info::SYNC_INFO_SIZE},
+};
+
+/// AdvA / TargetA size in octets.
+pub const ADDRESS_SIZE: usize = 6;
+
+/// CTEInfo size in octets.
+pub const CTE_INFO_SIZE: usize = 1;
+
+/// TxPower size in octets.
+pub const TX_POWER_SIZE: usize = 1;
+
+/// Extended Header Length max value (6 bits).
+pub const EXTENDED_HEADER_LENGTH_MAX: usize = 63;
+
+/// PDU payload max size in octets.
+pub const PDU_PAYLOAD_MAX: usize = 255;
+
+/// PHY.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Phy {
+    /// LE 1M
+    Le1M,
+
+    /// LE 2M
+    Le2M,
+
+    /// LE Coded (S=2)
+    LeCodedS2,
+
+    /// LE Coded (S=8)
+    LeCodedS8,
+}
+
+/// Extended header fields of Common Extended Advertising Payload Format.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct ExtendedHeaderOptions {
+    /// AdvA
+    pub adv_a: bool,
+
+    /// TargetA
+    pub target_a: bool,
+
+    /// CTEInfo
+    pub cte_info: bool,
+
+    /// ADI
+    pub adi: bool,
+
+    /// AuxPtr
+    pub aux_ptr: bool,
+
+    /// SyncInfo
+    pub sync_info: bool,
+
+    /// TxPower
+    pub tx_power: bool,
+
+    /// ACAD length in octets
+    pub acad_length: usize,
+}
+
+impl ExtendedHeaderOptions {
+    /// Get Extended Header Length (Extended Header Flags, fields and ACAD).
+    ///
+    /// `0` if no field is present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::airtime::ExtendedHeaderOptions;
+    ///
+    /// assert_eq!(0, ExtendedHeaderOptions::default().length());
+    ///
+    /// let options = ExtendedHeaderOptions {
+    ///     adv_a: true,
+    ///     adi: true,
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(9, options.length());
+    /// ```
+    pub fn length(&self) -> usize {
+        let fields = [
+            (self.adv_a, ADDRESS_SIZE),
+            (self.target_a, ADDRESS_SIZE),
+            (self.cte_info, CTE_INFO_SIZE),
+            (self.adi, ADI_SIZE),
+            (self.aux_ptr, AUX_PTR_SIZE),
+            (self.sync_info, SYNC_INFO_SIZE),
+            (self.tx_power, TX_POWER_SIZE),
+        ];
+        let length: usize = fields
+            .iter()
+            .filter(|(present, _)| *present)
+            .map(|(_, size)| size)
+            .sum::<usize>()
+            + self.acad_length;
+        if length == 0 {
+            0
+        } else {
+            1 + length
+        }
+    }
+}
+
+/// Get PDU payload length of legacy advertising PDU (AdvA and AdvData).
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::airtime::legacy_pdu_length;
+///
+/// assert_eq!(Ok(37), legacy_pdu_length(31));
+/// assert_eq!(Err("Invalid data size :32".to_string()), legacy_pdu_length(32));
+/// ```
+pub fn legacy_pdu_length(data_length: usize) -> Result<usize, String> {
+    if data_length > LEGACY_ADVERTISING_DATA_MAX {
+        return Err(format!("Invalid data size :{}", data_length));
+    }
+    Ok(ADDRESS_SIZE + data_length)
+}
+
+/// Get PDU payload length of extended advertising PDU (Common Extended Advertising Payload Format).
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::airtime::{extended_pdu_length, ExtendedHeaderOptions};
+///
+/// let options = ExtendedHeaderOptions {
+///     adv_a: true,
+///     adi: true,
+///     ..Default::default()
+/// };
+/// assert_eq!(Ok(40), extended_pdu_length(&options, 30));
+/// assert_eq!(Err("Invalid data size :256".to_string()), extended_pdu_length(&options, 246));
+///
+/// let options = ExtendedHeaderOptions {
+///     acad_length: 63,
+///     ..Default::default()
+/// };
+/// assert_eq!(
+///     Err("Invalid Extended Header Length :64".to_string()),
+///     extended_pdu_length(&options, 0)
+/// );
+/// ```
+pub fn extended_pdu_length(
+    options: &ExtendedHeaderOptions,
+    data_length: usize,
+) -> Result<usize, String> {
+    let header_length = options.length();
+    if header_length > EXTENDED_HEADER_LENGTH_MAX {
+        return Err(format!("Invalid Extended Header Length :{}", header_length));
+    }
+    let length = 1 + header_length + data_length;
+    if length > PDU_PAYLOAD_MAX {
+        return Err(format!("Invalid data size :{}", length));
+    }
+    Ok(length)
+}
+
+/// Get on-air time of PDU in microseconds.
+///
+/// `pdu_length` is PDU payload length (without PDU header).
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::airtime::{airtime_micros, legacy_pdu_length, Phy};
+///
+/// let pdu_length = legacy_pdu_length(31).unwrap();
+/// assert_eq!(376, airtime_micros(Phy::Le1M, pdu_length));
+/// assert_eq!(192, airtime_micros(Phy::Le2M, pdu_length));
+/// assert_eq!(1054, airtime_micros(Phy::LeCodedS2, pdu_length));
+/// assert_eq!(3088, airtime_micros(Phy::LeCodedS8, pdu_length));
+/// ```
+pub fn airtime_micros(phy: Phy, pdu_length: usize) -> u32 {
+    // PDU header (2 octets), PDU payload and CRC (3 octets)
+    let bits = ((2 + pdu_length + 3) * 8) as u32;
+    match phy {
+        // Preamble (1 octet) and Access Address (4 octets) at 1 µs/bit
+        Phy::Le1M => 40 + bits,
+        // Preamble (2 octets) and Access Address (4 octets) at 0.5 µs/bit
+        Phy::Le2M => (48 + bits) / 2,
+        // Preamble 80 µs, FEC block 1 (Access Address, CI, TERM1) 296 µs, TERM2 3 bits
+        Phy::LeCodedS2 => 80 + 296 + (bits + 3) * 2,
+        Phy::LeCodedS8 => 80 + 296 + (bits + 3) * 8,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::airtime::*;
+
+    #[test]
+    fn test_length() {
+        let options = ExtendedHeaderOptions {
+            adv_a: true,
+            target_a: true,
+            cte_info: true,
+            adi: true,
+            aux_ptr: true,
+            sync_info: true,
+            tx_power: true,
+            acad_length: 0,
+        };
+        assert_eq!(38, options.length());
+
+        let options = ExtendedHeaderOptions {
+            acad_length: 1,
+            ..Default::default()
+        };
+        assert_eq!(2, options.length());
+    }
+
+    #[test]
+    fn test_legacy_pdu_length() {
+        assert_eq!(Ok(6), legacy_pdu_length(0));
+        assert_eq!(Ok(37), legacy_pdu_length(LEGACY_ADVERTISING_DATA_MAX));
+        assert!(legacy_pdu_length(LEGACY_ADVERTISING_DATA_MAX + 1).is_err());
+    }
+
+    #[test]
+    fn test_extended_pdu_length() {
+        let options = ExtendedHeaderOptions::default();
+        assert_eq!(Ok(1), extended_pdu_length(&options, 0));
+        assert_eq!(Ok(255), extended_pdu_length(&options, 254));
+        assert_eq!(
+            Err("Invalid data size :256".to_string()),
+            extended_pdu_length(&options, 255)
+        );
+
+        let options = ExtendedHeaderOptions {
+            aux_ptr: true,
+            acad_length: 59,
+            ..Default::default()
+        };
+        assert_eq!(Ok(64), extended_pdu_length(&options, 0));
+    }
+
+    #[test]
+    fn test_airtime_micros() {
+        assert_eq!(80, airtime_micros(Phy::Le1M, 0));
+        assert_eq!(44, airtime_micros(Phy::Le2M, 0));
+        assert_eq!(462, airtime_micros(Phy::LeCodedS2, 0));
+        assert_eq!(720, airtime_micros(Phy::LeCodedS8, 0));
+
+        assert_eq!(2120, airtime_micros(Phy::Le1M, PDU_PAYLOAD_MAX));
+        assert_eq!(1064, airtime_micros(Phy::Le2M, PDU_PAYLOAD_MAX));
+        assert_eq!(4542, airtime_micros(Phy::LeCodedS2, PDU_PAYLOAD_MAX));
+        assert_eq!(17040, airtime_micros(Phy::LeCodedS8, PDU_PAYLOAD_MAX));
+    }
+}
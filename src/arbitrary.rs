@@ -0,0 +1,401 @@
+//! [`Arbitrary`] implementation module for fuzzing.
+//!
+//! Values are built with the data type constructors, so every generated value serializes to a
+//! well-formed AD structure and parses back to itself.
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+use uuid::Uuid;
+
+use crate::{
+    data_types::{
+        advertising_interval::AdvertisingInterval, appearance::Appearance,
+        class_of_device::ClassOfDevice,
+        complete_list_of_128bit_service_uuids::CompleteListOf128BitServiceUuids,
+        complete_list_of_16bit_service_uuids::CompleteListOf16BitServiceUuids,
+        complete_list_of_32bit_service_uuids::CompleteListOf32BitServiceUuids,
+        complete_local_name::CompleteLocalName, flags::Flags, le_role::LeRole,
+        manufacturer_specific_data::ManufacturerSpecificData,
+        peripheral_connection_interval_range::PeripheralConnectionIntervalRange,
+        service_data_128bit_uuid::ServiceData128BitUUID,
+        service_data_16bit_uuid::ServiceData16BitUUID,
+        service_data_32bit_uuid::ServiceData32BitUUID, shortened_local_name::ShortenedLocalName,
+        tx_power_level::TxPowerLevel,
+    },
+    uuid_from_u16, uuid_from_u32,
+};
+
+/// Generate 1 to `max` UUIDs from `T` values.
+fn uuids<'a, T: Arbitrary<'a>>(
+    u: &mut Unstructured<'a>,
+    max: usize,
+    f: fn(T) -> Uuid,
+) -> Result<Vec<Uuid>> {
+    let len = u.int_in_range(1..=max)?;
+    (0..len).map(|_| Ok(f(u.arbitrary()?))).collect()
+}
+
+/// Generate not empty name up to 254 octets.
+fn name(u: &mut Unstructured) -> Result<String> {
+    let mut name: String = u.arbitrary()?;
+    while name.len() > 254 {
+        name.pop();
+    }
+    if name.is_empty() {
+        name.push(u.arbitrary()?);
+    }
+    Ok(name)
+}
+
+/// Generate data up to `max` octets.
+fn data(u: &mut Unstructured, max: usize) -> Result<Vec<u8>> {
+    let len = u.arbitrary_len::<u8>()?.min(max);
+    Ok(u.bytes(len)?.to_vec())
+}
+
+impl<'a> Arbitrary<'a> for AdvertisingInterval {
+    /// Generate [`AdvertisingInterval`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arbitrary::{Arbitrary, Unstructured};
+    /// use ble_data_struct::data_types::advertising_interval::AdvertisingInterval;
+    ///
+    /// let mut u = Unstructured::new(&[0x01, 0x02]);
+    /// assert_eq!(AdvertisingInterval::new(0x0201), AdvertisingInterval::arbitrary(&mut u).unwrap());
+    /// ```
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::new(u.arbitrary()?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for Appearance {
+    /// Generate [`Appearance`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arbitrary::{Arbitrary, Unstructured};
+    /// use ble_data_struct::data_types::appearance::Appearance;
+    ///
+    /// let mut u = Unstructured::new(&[0x44, 0x14]);
+    /// assert_eq!(Appearance::new(0x1444), Appearance::arbitrary(&mut u).unwrap());
+    /// ```
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::new(u.arbitrary()?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for ClassOfDevice {
+    /// Generate [`ClassOfDevice`] (upper 8 bits are cleared).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arbitrary::{Arbitrary, Unstructured};
+    /// use ble_data_struct::data_types::class_of_device::ClassOfDevice;
+    ///
+    /// let mut u = Unstructured::new(&[0x01, 0x02, 0x03, 0xff]);
+    /// assert_eq!(ClassOfDevice::new(0x030201), ClassOfDevice::arbitrary(&mut u).unwrap());
+    /// ```
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::new(u.arbitrary::<u32>()? & 0x00ff_ffff))
+    }
+}
+
+impl<'a> Arbitrary<'a> for CompleteListOf16BitServiceUuids {
+    /// Generate [`CompleteListOf16BitServiceUuids`] with 1 to 126 UUIDs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arbitrary::{Arbitrary, Unstructured};
+    /// use ble_data_struct::data_types::complete_list_of_16bit_service_uuids::CompleteListOf16BitServiceUuids;
+    ///
+    /// let mut u = Unstructured::new(&[0x00, 0x0f, 0x18]);
+    /// let result = CompleteListOf16BitServiceUuids::arbitrary(&mut u).unwrap();
+    /// assert_eq!(1, result.uuids.len());
+    /// ```
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::new(&uuids(u, 126, uuid_from_u16)?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for CompleteListOf32BitServiceUuids {
+    /// Generate [`CompleteListOf32BitServiceUuids`] with 1 to 63 UUIDs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arbitrary::{Arbitrary, Unstructured};
+    /// use ble_data_struct::data_types::complete_list_of_32bit_service_uuids::CompleteListOf32BitServiceUuids;
+    ///
+    /// let mut u = Unstructured::new(&[0x00, 0x0f, 0x18, 0x00, 0x00]);
+    /// let result = CompleteListOf32BitServiceUuids::arbitrary(&mut u).unwrap();
+    /// assert_eq!(1, result.uuids.len());
+    /// ```
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::new(&uuids(u, 63, uuid_from_u32)?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for CompleteListOf128BitServiceUuids {
+    /// Generate [`CompleteListOf128BitServiceUuids`] with 1 to 15 UUIDs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arbitrary::{Arbitrary, Unstructured};
+    /// use ble_data_struct::data_types::complete_list_of_128bit_service_uuids::CompleteListOf128BitServiceUuids;
+    ///
+    /// let mut u = Unstructured::new(&[0x00; 17]);
+    /// let result = CompleteListOf128BitServiceUuids::arbitrary(&mut u).unwrap();
+    /// assert_eq!(1, result.uuids.len());
+    /// ```
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::new(&uuids(u, 15, Uuid::from_u128)?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for CompleteLocalName {
+    /// Generate [`CompleteLocalName`] with 1 to 254 octets name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arbitrary::{Arbitrary, Unstructured};
+    /// use ble_data_struct::data_types::complete_local_name::CompleteLocalName;
+    ///
+    /// let mut u = Unstructured::new(&[]);
+    /// let result = CompleteLocalName::arbitrary(&mut u).unwrap();
+    /// assert!(!result.complete_local_name.is_empty());
+    /// ```
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::new(&name(u)?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for Flags {
+    /// Generate [`Flags`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arbitrary::{Arbitrary, Unstructured};
+    /// use ble_data_struct::data_types::flags::Flags;
+    ///
+    /// let mut u = Unstructured::new(&[0x06]);
+    /// assert_eq!(Flags::from_bits(0x06), Flags::arbitrary(&mut u).unwrap());
+    /// ```
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::from_bits(u.arbitrary()?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for LeRole {
+    /// Generate [`LeRole`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arbitrary::{Arbitrary, Unstructured};
+    /// use ble_data_struct::data_types::le_role::LeRole;
+    ///
+    /// let mut u = Unstructured::new(&[0x01]);
+    /// assert_eq!(LeRole::new(0x01), LeRole::arbitrary(&mut u).unwrap());
+    /// ```
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::new(u.arbitrary()?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for ManufacturerSpecificData {
+    /// Generate [`ManufacturerSpecificData`] with up to 252 octets data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arbitrary::{Arbitrary, Unstructured};
+    /// use ble_data_struct::data_types::manufacturer_specific_data::ManufacturerSpecificData;
+    ///
+    /// let mut u = Unstructured::new(&[0xa8, 0x0c]);
+    /// let result = ManufacturerSpecificData::arbitrary(&mut u).unwrap();
+    /// assert_eq!(0x0ca8, result.company_identifier);
+    /// ```
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let company_identifier = u.arbitrary()?;
+        Ok(Self::new(company_identifier, &data(u, 252)?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for PeripheralConnectionIntervalRange {
+    /// Generate [`PeripheralConnectionIntervalRange`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arbitrary::{Arbitrary, Unstructured};
+    /// use ble_data_struct::data_types::peripheral_connection_interval_range::PeripheralConnectionIntervalRange;
+    ///
+    /// let mut u = Unstructured::new(&[0x06, 0x00, 0x80, 0x0c]);
+    /// assert_eq!(
+    ///     PeripheralConnectionIntervalRange::new(0x0006, 0x0c80),
+    ///     PeripheralConnectionIntervalRange::arbitrary(&mut u).unwrap()
+    /// );
+    /// ```
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let minimum_value = u.arbitrary()?;
+        Ok(Self::new(minimum_value, u.arbitrary()?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for ServiceData16BitUUID {
+    /// Generate [`ServiceData16BitUUID`] with up to 252 octets additional service data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arbitrary::{Arbitrary, Unstructured};
+    /// use ble_data_struct::{data_types::service_data_16bit_uuid::ServiceData16BitUUID, uuid_from_u16};
+    ///
+    /// let mut u = Unstructured::new(&[0x0f, 0x18]);
+    /// let result = ServiceData16BitUUID::arbitrary(&mut u).unwrap();
+    /// assert_eq!(uuid_from_u16(0x180f), result.uuid);
+    /// ```
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let uuid = uuid_from_u16(u.arbitrary()?);
+        Ok(Self::new(&uuid, &data(u, 252)?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for ServiceData32BitUUID {
+    /// Generate [`ServiceData32BitUUID`] with up to 250 octets additional service data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arbitrary::{Arbitrary, Unstructured};
+    /// use ble_data_struct::{data_types::service_data_32bit_uuid::ServiceData32BitUUID, uuid_from_u32};
+    ///
+    /// let mut u = Unstructured::new(&[0x0f, 0x18, 0x00, 0x00]);
+    /// let result = ServiceData32BitUUID::arbitrary(&mut u).unwrap();
+    /// assert_eq!(uuid_from_u32(0x180f), result.uuid);
+    /// ```
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let uuid = uuid_from_u32(u.arbitrary()?);
+        Ok(Self::new(&uuid, &data(u, 250)?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for ServiceData128BitUUID {
+    /// Generate [`ServiceData128BitUUID`] with up to 238 octets additional service data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arbitrary::{Arbitrary, Unstructured};
+    /// use ble_data_struct::data_types::service_data_128bit_uuid::ServiceData128BitUUID;
+    /// use uuid::Uuid;
+    ///
+    /// let mut u = Unstructured::new(&[0x00; 16]);
+    /// let result = ServiceData128BitUUID::arbitrary(&mut u).unwrap();
+    /// assert_eq!(Uuid::nil(), result.uuid);
+    /// ```
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let uuid = Uuid::from_u128(u.arbitrary()?);
+        Ok(Self::new(&uuid, &data(u, 238)?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for ShortenedLocalName {
+    /// Generate [`ShortenedLocalName`] with 1 to 254 octets name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arbitrary::{Arbitrary, Unstructured};
+    /// use ble_data_struct::data_types::shortened_local_name::ShortenedLocalName;
+    ///
+    /// let mut u = Unstructured::new(&[]);
+    /// let result = ShortenedLocalName::arbitrary(&mut u).unwrap();
+    /// assert!(!result.shortened_local_name.is_empty());
+    /// ```
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::new(&name(u)?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for TxPowerLevel {
+    /// Generate [`TxPowerLevel`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arbitrary::{Arbitrary, Unstructured};
+    /// use ble_data_struct::data_types::tx_power_level::TxPowerLevel;
+    ///
+    /// let mut u = Unstructured::new(&[0xfc]);
+    /// assert_eq!(TxPowerLevel::new(-4), TxPowerLevel::arbitrary(&mut u).unwrap());
+    /// ```
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::new(u.arbitrary()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arbitrary::{Arbitrary, Unstructured};
+
+    use crate::data_types::{
+        advertising_interval::AdvertisingInterval, appearance::Appearance,
+        class_of_device::ClassOfDevice,
+        complete_list_of_128bit_service_uuids::CompleteListOf128BitServiceUuids,
+        complete_list_of_16bit_service_uuids::CompleteListOf16BitServiceUuids,
+        complete_list_of_32bit_service_uuids::CompleteListOf32BitServiceUuids,
+        complete_local_name::CompleteLocalName, flags::Flags, le_role::LeRole,
+        manufacturer_specific_data::ManufacturerSpecificData,
+        peripheral_connection_interval_range::PeripheralConnectionIntervalRange,
+        service_data_128bit_uuid::ServiceData128BitUUID,
+        service_data_16bit_uuid::ServiceData16BitUUID,
+        service_data_32bit_uuid::ServiceData32BitUUID, shortened_local_name::ShortenedLocalName,
+        tx_power_level::TxPowerLevel,
+    };
+
+    /// Generate `data_type` from `input`, serialize it, parse it again and compare.
+    macro_rules! assert_round_trip {
+        ($data_type:ident, $input:expr) => {{
+            let value = $data_type::arbitrary(&mut Unstructured::new($input)).unwrap();
+            let data: Vec<u8> = value.clone().into();
+            assert_eq!(Ok(value), $data_type::try_from(&data));
+        }};
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let inputs: Vec<Vec<u8>> = vec![
+            vec![],
+            vec![0x00; 8],
+            vec![0xff; 300],
+            (0..=255).collect(),
+            (0..=255).rev().cycle().take(1024).collect(),
+        ];
+        for input in &inputs {
+            assert_round_trip!(AdvertisingInterval, input);
+            assert_round_trip!(Appearance, input);
+            assert_round_trip!(ClassOfDevice, input);
+            assert_round_trip!(CompleteListOf16BitServiceUuids, input);
+            assert_round_trip!(CompleteListOf32BitServiceUuids, input);
+            assert_round_trip!(CompleteListOf128BitServiceUuids, input);
+            assert_round_trip!(CompleteLocalName, input);
+            assert_round_trip!(Flags, input);
+            assert_round_trip!(LeRole, input);
+            assert_round_trip!(ManufacturerSpecificData, input);
+            assert_round_trip!(PeripheralConnectionIntervalRange, input);
+            assert_round_trip!(ServiceData16BitUUID, input);
+            assert_round_trip!(ServiceData32BitUUID, input);
+            assert_round_trip!(ServiceData128BitUUID, input);
+            assert_round_trip!(ShortenedLocalName, input);
+            assert_round_trip!(TxPowerLevel, input);
+        }
+    }
+}
@@ -0,0 +1,248 @@
+//! AltBeacon (Beacon Code: 0xbeac) module.
+
+use crate::data_types::manufacturer_specific_data::ManufacturerSpecificData;
+
+/// AltBeacon Beacon Code
+pub const ALTBEACON_CODE: u16 = 0xbeac;
+
+/// AltBeacon.
+#[derive(Debug, PartialEq, Clone)]
+pub struct AltBeacon {
+    /// Company Identifier Code (MFG ID)
+    pub company_identifier: u16,
+
+    /// Beacon ID
+    pub beacon_id: [u8; 20],
+
+    /// Reference RSSI (dBm at 1m)
+    pub reference_rssi: i8,
+
+    /// Manufacturer reserved
+    pub manufacturer_reserved: u8,
+}
+
+impl AltBeacon {
+    /// Create [`AltBeacon`] from Parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::beacons::altbeacon::AltBeacon;
+    ///
+    /// let beacon_id = [0x01u8; 20];
+    /// let result = AltBeacon::new(0x0118, beacon_id, -59, 0x00);
+    /// assert_eq!(0x0118, result.company_identifier);
+    /// assert_eq!(beacon_id, result.beacon_id);
+    /// assert_eq!(-59, result.reference_rssi);
+    /// assert_eq!(0x00, result.manufacturer_reserved);
+    /// ```
+    pub fn new(
+        company_identifier: u16,
+        beacon_id: [u8; 20],
+        reference_rssi: i8,
+        manufacturer_reserved: u8,
+    ) -> Self {
+        Self {
+            company_identifier,
+            beacon_id,
+            reference_rssi,
+            manufacturer_reserved,
+        }
+    }
+}
+
+impl TryFrom<&Vec<u8>> for AltBeacon {
+    type Error = String;
+    /// Create [`AltBeacon`] from 26 bytes vendor payload (Company Identifier Code and Manufacturer Specific Data).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::beacons::altbeacon::AltBeacon;
+    ///
+    /// let mut data: Vec<u8> = vec![0x18, 0x01, 0xbe, 0xac];
+    /// data.append(&mut [0x01u8; 20].to_vec());
+    /// data.append(&mut vec![0xc5, 0x00]);
+    ///
+    /// let result = AltBeacon::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let altbeacon = result.unwrap();
+    /// assert_eq!(0x0118, altbeacon.company_identifier);
+    /// assert_eq!([0x01u8; 20], altbeacon.beacon_id);
+    /// assert_eq!(-59, altbeacon.reference_rssi);
+    /// assert_eq!(0x00, altbeacon.manufacturer_reserved);
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = AltBeacon::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        let len = value.len();
+        if len != 26 {
+            return Err(format!("Invalid data size :{}", len).to_string());
+        }
+        let beacon_code = u16::from_be_bytes(value[2..4].try_into().unwrap());
+        if beacon_code != ALTBEACON_CODE {
+            return Err(format!("Invalid beacon code :{}", beacon_code).to_string());
+        }
+        Ok(Self {
+            company_identifier: u16::from_le_bytes(value[0..2].try_into().unwrap()),
+            beacon_id: value[4..24].try_into().unwrap(),
+            reference_rssi: value[24] as i8,
+            manufacturer_reserved: value[25],
+        })
+    }
+}
+
+impl TryFrom<&ManufacturerSpecificData> for AltBeacon {
+    type Error = String;
+    /// Create [`AltBeacon`] from [`ManufacturerSpecificData`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     beacons::altbeacon::AltBeacon,
+    ///     data_types::manufacturer_specific_data::ManufacturerSpecificData,
+    /// };
+    ///
+    /// let mut data: Vec<u8> = vec![0xbe, 0xac];
+    /// data.append(&mut [0x01u8; 20].to_vec());
+    /// data.append(&mut vec![0xc5, 0x00]);
+    ///
+    /// let result = AltBeacon::try_from(&ManufacturerSpecificData::new(0x0118, &data));
+    /// assert_eq!(Ok(AltBeacon::new(0x0118, [0x01u8; 20], -59, 0x00)), result);
+    ///
+    /// let result = AltBeacon::try_from(&ManufacturerSpecificData::new(0x0118, &data[1..].to_vec()));
+    /// assert!(result.is_err());
+    /// ```
+    fn try_from(value: &ManufacturerSpecificData) -> Result<Self, String> {
+        let mut data = u16::to_le_bytes(value.company_identifier).to_vec();
+        data.extend_from_slice(&value.manufacturer_specific_data);
+        Self::try_from(&data)
+    }
+}
+
+impl From<AltBeacon> for Vec<u8> {
+    /// Create 26 bytes vendor payload (Company Identifier Code and Manufacturer Specific Data) from [`AltBeacon`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::beacons::altbeacon::AltBeacon;
+    ///
+    /// let mut data: Vec<u8> = vec![0x18, 0x01, 0xbe, 0xac];
+    /// data.append(&mut [0x01u8; 20].to_vec());
+    /// data.append(&mut vec![0xc5, 0x00]);
+    ///
+    /// let into_data: Vec<u8> = AltBeacon::new(0x0118, [0x01u8; 20], -59, 0x00).into();
+    /// assert_eq!(data, into_data);
+    /// ```
+    fn from(value: AltBeacon) -> Self {
+        let mut data: Vec<u8> = Vec::new();
+        data.append(&mut u16::to_le_bytes(value.company_identifier).to_vec());
+        data.append(&mut u16::to_be_bytes(ALTBEACON_CODE).to_vec());
+        data.append(&mut value.beacon_id.to_vec());
+        data.push(value.reference_rssi as u8);
+        data.push(value.manufacturer_reserved);
+        data
+    }
+}
+
+impl From<AltBeacon> for ManufacturerSpecificData {
+    /// Create [`ManufacturerSpecificData`] from [`AltBeacon`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     beacons::altbeacon::AltBeacon,
+    ///     data_types::manufacturer_specific_data::ManufacturerSpecificData,
+    /// };
+    ///
+    /// let result: ManufacturerSpecificData = AltBeacon::new(0x0118, [0x01u8; 20], -59, 0x00).into();
+    /// assert_eq!(0x0118, result.company_identifier);
+    /// assert_eq!(24, result.manufacturer_specific_data.len());
+    /// assert_eq!(27, result.length);
+    /// ```
+    fn from(value: AltBeacon) -> Self {
+        let company_identifier = value.company_identifier;
+        let data: Vec<u8> = value.into();
+        ManufacturerSpecificData::new(company_identifier, &data[2..].to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        beacons::altbeacon::AltBeacon,
+        data_types::manufacturer_specific_data::ManufacturerSpecificData,
+    };
+
+    fn payload() -> Vec<u8> {
+        let mut data: Vec<u8> = vec![0x18, 0x01, 0xbe, 0xac];
+        data.append(&mut [0x01u8; 20].to_vec());
+        data.append(&mut vec![0xc5, 0x00]);
+        data
+    }
+
+    #[test]
+    fn test_new() {
+        let result = AltBeacon::new(0x0118, [0x01u8; 20], -59, 0x00);
+        assert_eq!(0x0118, result.company_identifier);
+        assert_eq!([0x01u8; 20], result.beacon_id);
+        assert_eq!(-59, result.reference_rssi);
+        assert_eq!(0x00, result.manufacturer_reserved);
+    }
+
+    #[test]
+    fn test_try_from() {
+        assert_eq!(
+            Ok(AltBeacon::new(0x0118, [0x01u8; 20], -59, 0x00)),
+            AltBeacon::try_from(&payload())
+        );
+
+        let mut data = payload();
+        data[0] = 0xff;
+        data[1] = 0xff;
+        assert_eq!(
+            Ok(AltBeacon::new(0xffff, [0x01u8; 20], -59, 0x00)),
+            AltBeacon::try_from(&data)
+        );
+
+        let data: Vec<u8> = Vec::new();
+        assert_eq!(
+            Err(format!("Invalid data size :{}", data.len())),
+            AltBeacon::try_from(&data)
+        );
+
+        let mut data = payload();
+        data[2] = 0x00;
+        assert!(AltBeacon::try_from(&data).is_err());
+    }
+
+    #[test]
+    fn test_try_from_manufacturer_specific_data() {
+        let data = payload();
+        let result =
+            AltBeacon::try_from(&ManufacturerSpecificData::new(0x0118, &data[2..].to_vec()));
+        assert_eq!(Ok(AltBeacon::new(0x0118, [0x01u8; 20], -59, 0x00)), result);
+    }
+
+    #[test]
+    fn test_into() {
+        let into_data: Vec<u8> = AltBeacon::new(0x0118, [0x01u8; 20], -59, 0x00).into();
+        assert_eq!(payload(), into_data);
+
+        let result: ManufacturerSpecificData =
+            AltBeacon::new(0x0118, [0x01u8; 20], -59, 0x00).into();
+        assert_eq!(
+            ManufacturerSpecificData::new(0x0118, &payload()[2..].to_vec()),
+            result
+        );
+    }
+}
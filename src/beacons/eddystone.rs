@@ -0,0 +1,1004 @@
+//! Eddystone (Service UUID: 0xfeaa) module.
+
+use crate::{data_types::service_data_16bit_uuid::ServiceData16BitUUID, uuid_from_u16};
+
+/// Eddystone Service UUID
+pub const EDDYSTONE_SERVICE_UUID: u16 = 0xfeaa;
+
+/// Eddystone-UID Frame Type
+pub const FRAME_TYPE_UID: u8 = 0x00;
+
+/// Eddystone-URL Frame Type
+pub const FRAME_TYPE_URL: u8 = 0x10;
+
+/// Eddystone-TLM Frame Type
+pub const FRAME_TYPE_TLM: u8 = 0x20;
+
+/// Eddystone-TLM unencrypted TLM version
+pub const TLM_VERSION_UNENCRYPTED: u8 = 0x00;
+
+/// Eddystone-TLM encrypted TLM (ETLM) version
+pub const TLM_VERSION_ENCRYPTED: u8 = 0x01;
+
+/// Eddystone-TLM temperature value when not supported.
+pub const TLM_TEMPERATURE_NOT_SUPPORTED: i16 = -0x8000;
+
+/// Eddystone-URL URL Scheme Prefix.
+const URL_SCHEME_PREFIXES: [&str; 4] = ["http://www.", "https://www.", "http://", "https://"];
+
+/// Eddystone-URL HTTP URL encoding.
+const URL_EXPANSIONS: [&str; 14] = [
+    ".com/", ".org/", ".edu/", ".net/", ".info/", ".biz/", ".gov/", ".com", ".org", ".edu", ".net",
+    ".info", ".biz", ".gov",
+];
+
+/// Eddystone-URL maximum encoded URL length.
+const URL_MAX_ENCODED_LENGTH: usize = 17;
+
+/// Eddystone-UID.
+#[derive(Debug, PartialEq, Clone)]
+pub struct EddystoneUid {
+    /// Ranging Data (Calibrated Tx power at 0 m)
+    pub ranging_data: i8,
+
+    /// 10-byte Namespace
+    pub namespace: [u8; 10],
+
+    /// 6-byte Instance
+    pub instance: [u8; 6],
+}
+
+impl EddystoneUid {
+    /// Create [`EddystoneUid`] from Parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::beacons::eddystone::EddystoneUid;
+    ///
+    /// let namespace = [0x01u8, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a];
+    /// let instance = [0x0bu8, 0x0c, 0x0d, 0x0e, 0x0f, 0x10];
+    /// let result = EddystoneUid::new(-20, namespace, instance);
+    /// assert_eq!(-20, result.ranging_data);
+    /// assert_eq!(namespace, result.namespace);
+    /// assert_eq!(instance, result.instance);
+    /// ```
+    pub fn new(ranging_data: i8, namespace: [u8; 10], instance: [u8; 6]) -> Self {
+        Self {
+            ranging_data,
+            namespace,
+            instance,
+        }
+    }
+}
+
+impl TryFrom<&Vec<u8>> for EddystoneUid {
+    type Error = String;
+    /// Create [`EddystoneUid`] from Eddystone frame.
+    ///
+    /// The trailing 2 bytes RFU field is optional.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::beacons::eddystone::EddystoneUid;
+    ///
+    /// let namespace = [0x01u8, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a];
+    /// let instance = [0x0bu8, 0x0c, 0x0d, 0x0e, 0x0f, 0x10];
+    /// let mut data: Vec<u8> = vec![0x00, 0xec];
+    /// data.append(&mut namespace.to_vec());
+    /// data.append(&mut instance.to_vec());
+    /// data.append(&mut vec![0x00, 0x00]);
+    ///
+    /// let result = EddystoneUid::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let eddystone_uid = result.unwrap();
+    /// assert_eq!(-20, eddystone_uid.ranging_data);
+    /// assert_eq!(namespace, eddystone_uid.namespace);
+    /// assert_eq!(instance, eddystone_uid.instance);
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = EddystoneUid::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        let len = value.len();
+        if len != 18 && len != 20 {
+            return Err(format!("Invalid data size :{}", len).to_string());
+        }
+        if value[0] != FRAME_TYPE_UID {
+            return Err(format!("Invalid frame type :{}", value[0]).to_string());
+        }
+        Ok(Self {
+            ranging_data: value[1] as i8,
+            namespace: value[2..12].try_into().unwrap(),
+            instance: value[12..18].try_into().unwrap(),
+        })
+    }
+}
+
+impl TryFrom<&ServiceData16BitUUID> for EddystoneUid {
+    type Error = String;
+    /// Create [`EddystoneUid`] from [`ServiceData16BitUUID`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     beacons::eddystone::EddystoneUid,
+    ///     data_types::service_data_16bit_uuid::ServiceData16BitUUID, uuid_from_u16,
+    /// };
+    ///
+    /// let namespace = [0x01u8, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a];
+    /// let instance = [0x0bu8, 0x0c, 0x0d, 0x0e, 0x0f, 0x10];
+    /// let mut data: Vec<u8> = vec![0x00, 0xec];
+    /// data.append(&mut namespace.to_vec());
+    /// data.append(&mut instance.to_vec());
+    ///
+    /// let service_data = ServiceData16BitUUID::new(&uuid_from_u16(0xfeaa), &data);
+    /// let result = EddystoneUid::try_from(&service_data);
+    /// assert_eq!(Ok(EddystoneUid::new(-20, namespace, instance)), result);
+    ///
+    /// let service_data = ServiceData16BitUUID::new(&uuid_from_u16(0x180f), &data);
+    /// let result = EddystoneUid::try_from(&service_data);
+    /// assert!(result.is_err());
+    /// ```
+    fn try_from(value: &ServiceData16BitUUID) -> Result<Self, String> {
+        if value.uuid != uuid_from_u16(EDDYSTONE_SERVICE_UUID) {
+            return Err(format!("Invalid service uuid :{}", value.uuid).to_string());
+        }
+        Self::try_from(&value.additional_service_data.to_vec())
+    }
+}
+
+impl From<EddystoneUid> for Vec<u8> {
+    /// Create Eddystone frame from [`EddystoneUid`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::beacons::eddystone::EddystoneUid;
+    ///
+    /// let namespace = [0x01u8, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a];
+    /// let instance = [0x0bu8, 0x0c, 0x0d, 0x0e, 0x0f, 0x10];
+    /// let mut data: Vec<u8> = vec![0x00, 0xec];
+    /// data.append(&mut namespace.to_vec());
+    /// data.append(&mut instance.to_vec());
+    /// data.append(&mut vec![0x00, 0x00]);
+    ///
+    /// let into_data: Vec<u8> = EddystoneUid::new(-20, namespace, instance).into();
+    /// assert_eq!(data, into_data);
+    /// ```
+    fn from(value: EddystoneUid) -> Self {
+        let mut data: Vec<u8> = Vec::new();
+        data.push(FRAME_TYPE_UID);
+        data.push(value.ranging_data as u8);
+        data.append(&mut value.namespace.to_vec());
+        data.append(&mut value.instance.to_vec());
+        data.append(&mut vec![0x00, 0x00]);
+        data
+    }
+}
+
+impl From<EddystoneUid> for ServiceData16BitUUID {
+    /// Create [`ServiceData16BitUUID`] from [`EddystoneUid`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     beacons::eddystone::EddystoneUid,
+    ///     data_types::service_data_16bit_uuid::ServiceData16BitUUID, uuid_from_u16,
+    /// };
+    ///
+    /// let namespace = [0x01u8, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a];
+    /// let instance = [0x0bu8, 0x0c, 0x0d, 0x0e, 0x0f, 0x10];
+    /// let result: ServiceData16BitUUID = EddystoneUid::new(-20, namespace, instance).into();
+    /// assert_eq!(uuid_from_u16(0xfeaa), result.uuid);
+    /// assert_eq!(20, result.additional_service_data.len());
+    /// ```
+    fn from(value: EddystoneUid) -> Self {
+        let data: Vec<u8> = value.into();
+        ServiceData16BitUUID::new(&uuid_from_u16(EDDYSTONE_SERVICE_UUID), &data)
+    }
+}
+
+/// Encode URL to Eddystone-URL URL Scheme Prefix and Encoded URL.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::beacons::eddystone::encode_url;
+///
+/// assert_eq!(
+///     Ok(vec![0x00, b'g', b'o', b'o', b'g', b'l', b'e', 0x07]),
+///     encode_url("http://www.google.com")
+/// );
+/// assert_eq!(
+///     Ok(vec![0x03, b'e', b'x', b'a', b'm', b'p', b'l', b'e', 0x00, b'a']),
+///     encode_url("https://example.com/a")
+/// );
+/// assert!(encode_url("ftp://example.com").is_err());
+/// ```
+pub fn encode_url(url: &str) -> Result<Vec<u8>, String> {
+    let (scheme, prefix) = URL_SCHEME_PREFIXES
+        .iter()
+        .enumerate()
+        .find(|(_, prefix)| url.starts_with(*prefix))
+        .ok_or(format!("Invalid url scheme :{}", url).to_string())?;
+    let mut data: Vec<u8> = vec![scheme as u8];
+    let mut rest = &url[prefix.len()..];
+    while !rest.is_empty() {
+        match URL_EXPANSIONS
+            .iter()
+            .enumerate()
+            .find(|(_, expansion)| rest.starts_with(*expansion))
+        {
+            Some((code, expansion)) => {
+                data.push(code as u8);
+                rest = &rest[expansion.len()..];
+            }
+            None => {
+                let c = rest.as_bytes()[0];
+                if !(0x21..0x7f).contains(&c) {
+                    return Err(format!("Invalid url character :{}", c).to_string());
+                }
+                data.push(c);
+                rest = &rest[1..];
+            }
+        }
+    }
+    if data.len() - 1 > URL_MAX_ENCODED_LENGTH {
+        return Err(format!("Invalid encoded url size :{}", data.len() - 1).to_string());
+    }
+    Ok(data)
+}
+
+/// Decode URL from Eddystone-URL URL Scheme Prefix and Encoded URL.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::beacons::eddystone::decode_url;
+///
+/// assert_eq!(
+///     Ok("http://www.google.com".to_string()),
+///     decode_url(&[0x00, b'g', b'o', b'o', b'g', b'l', b'e', 0x07])
+/// );
+/// assert!(decode_url(&[0x04]).is_err());
+/// assert!(decode_url(&[]).is_err());
+/// ```
+pub fn decode_url(data: &[u8]) -> Result<String, String> {
+    let (scheme, encoded) = data
+        .split_first()
+        .ok_or(format!("Invalid data size :{}", data.len()).to_string())?;
+    let mut url = URL_SCHEME_PREFIXES
+        .get(*scheme as usize)
+        .ok_or(format!("Invalid url scheme :{}", scheme).to_string())?
+        .to_string();
+    for c in encoded {
+        match URL_EXPANSIONS.get(*c as usize) {
+            Some(expansion) => url.push_str(expansion),
+            None if (0x21..0x7f).contains(c) => url.push(*c as char),
+            None => return Err(format!("Invalid url character :{}", c).to_string()),
+        }
+    }
+    Ok(url)
+}
+
+/// Eddystone-URL.
+#[derive(Debug, PartialEq, Clone)]
+pub struct EddystoneUrl {
+    /// Tx Power (Calibrated Tx power at 0 m)
+    pub tx_power: i8,
+
+    /// URL
+    pub url: String,
+}
+
+impl EddystoneUrl {
+    /// Create [`EddystoneUrl`] from Parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::beacons::eddystone::EddystoneUrl;
+    ///
+    /// let result = EddystoneUrl::new(-20, "https://example.com/");
+    /// assert_eq!(-20, result.tx_power);
+    /// assert_eq!("https://example.com/", result.url);
+    /// ```
+    pub fn new(tx_power: i8, url: &str) -> Self {
+        Self {
+            tx_power,
+            url: url.to_string(),
+        }
+    }
+}
+
+impl TryFrom<&Vec<u8>> for EddystoneUrl {
+    type Error = String;
+    /// Create [`EddystoneUrl`] from Eddystone frame.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::beacons::eddystone::EddystoneUrl;
+    ///
+    /// let data: Vec<u8> = vec![0x10, 0xec, 0x03, b'e', b'x', b'a', b'm', b'p', b'l', b'e', 0x00];
+    /// let result = EddystoneUrl::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let eddystone_url = result.unwrap();
+    /// assert_eq!(-20, eddystone_url.tx_power);
+    /// assert_eq!("https://example.com/", eddystone_url.url);
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = EddystoneUrl::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        let len = value.len();
+        if !(3..=3 + URL_MAX_ENCODED_LENGTH).contains(&len) {
+            return Err(format!("Invalid data size :{}", len).to_string());
+        }
+        if value[0] != FRAME_TYPE_URL {
+            return Err(format!("Invalid frame type :{}", value[0]).to_string());
+        }
+        Ok(Self {
+            tx_power: value[1] as i8,
+            url: decode_url(&value[2..])?,
+        })
+    }
+}
+
+impl TryFrom<&ServiceData16BitUUID> for EddystoneUrl {
+    type Error = String;
+    /// Create [`EddystoneUrl`] from [`ServiceData16BitUUID`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     beacons::eddystone::EddystoneUrl,
+    ///     data_types::service_data_16bit_uuid::ServiceData16BitUUID, uuid_from_u16,
+    /// };
+    ///
+    /// let data: Vec<u8> = vec![0x10, 0xec, 0x03, b'e', b'x', b'a', b'm', b'p', b'l', b'e', 0x00];
+    /// let service_data = ServiceData16BitUUID::new(&uuid_from_u16(0xfeaa), &data);
+    /// let result = EddystoneUrl::try_from(&service_data);
+    /// assert_eq!(
+    ///     Ok(EddystoneUrl::new(-20, "https://example.com/")),
+    ///     result
+    /// );
+    ///
+    /// let service_data = ServiceData16BitUUID::new(&uuid_from_u16(0x180f), &data);
+    /// let result = EddystoneUrl::try_from(&service_data);
+    /// assert!(result.is_err());
+    /// ```
+    fn try_from(value: &ServiceData16BitUUID) -> Result<Self, String> {
+        if value.uuid != uuid_from_u16(EDDYSTONE_SERVICE_UUID) {
+            return Err(format!("Invalid service uuid :{}", value.uuid).to_string());
+        }
+        Self::try_from(&value.additional_service_data.to_vec())
+    }
+}
+
+impl TryFrom<EddystoneUrl> for Vec<u8> {
+    type Error = String;
+    /// Create Eddystone frame from [`EddystoneUrl`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::beacons::eddystone::EddystoneUrl;
+    ///
+    /// let data: Vec<u8> = vec![0x10, 0xec, 0x03, b'e', b'x', b'a', b'm', b'p', b'l', b'e', 0x00];
+    /// let result = Vec::<u8>::try_from(EddystoneUrl::new(-20, "https://example.com/"));
+    /// assert_eq!(Ok(data), result);
+    ///
+    /// let result = Vec::<u8>::try_from(EddystoneUrl::new(-20, "ftp://example.com/"));
+    /// assert!(result.is_err());
+    /// ```
+    fn try_from(value: EddystoneUrl) -> Result<Self, String> {
+        let mut data: Vec<u8> = Vec::new();
+        data.push(FRAME_TYPE_URL);
+        data.push(value.tx_power as u8);
+        data.append(&mut encode_url(&value.url)?);
+        Ok(data)
+    }
+}
+
+impl TryFrom<EddystoneUrl> for ServiceData16BitUUID {
+    type Error = String;
+    /// Create [`ServiceData16BitUUID`] from [`EddystoneUrl`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     beacons::eddystone::EddystoneUrl,
+    ///     data_types::service_data_16bit_uuid::ServiceData16BitUUID, uuid_from_u16,
+    /// };
+    ///
+    /// let result =
+    ///     ServiceData16BitUUID::try_from(EddystoneUrl::new(-20, "https://example.com/"));
+    /// assert!(result.is_ok());
+    /// let service_data = result.unwrap();
+    /// assert_eq!(uuid_from_u16(0xfeaa), service_data.uuid);
+    /// assert_eq!(11, service_data.additional_service_data.len());
+    /// ```
+    fn try_from(value: EddystoneUrl) -> Result<Self, String> {
+        let data: Vec<u8> = value.try_into()?;
+        Ok(ServiceData16BitUUID::new(
+            &uuid_from_u16(EDDYSTONE_SERVICE_UUID),
+            &data,
+        ))
+    }
+}
+
+/// Eddystone-TLM unencrypted TLM (version 0x00).
+#[derive(Debug, PartialEq, Clone)]
+pub struct UnencryptedTlm {
+    /// Battery voltage (1 mV/bit, 0 if not supported)
+    pub battery_voltage: u16,
+
+    /// Beacon temperature (signed 8.8 fixed-point, [`TLM_TEMPERATURE_NOT_SUPPORTED`] if not supported)
+    pub temperature: i16,
+
+    /// Advertising PDU count
+    pub advertising_pdu_count: u32,
+
+    /// Time since power-on or reboot (0.1 second resolution)
+    pub time_since_power_on: u32,
+}
+
+impl UnencryptedTlm {
+    /// Create [`UnencryptedTlm`] from Parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::beacons::eddystone::UnencryptedTlm;
+    ///
+    /// let result = UnencryptedTlm::new(3000, 0x1980, 0x01020304, 0x05060708);
+    /// assert_eq!(3000, result.battery_voltage);
+    /// assert_eq!(0x1980, result.temperature);
+    /// assert_eq!(0x01020304, result.advertising_pdu_count);
+    /// assert_eq!(0x05060708, result.time_since_power_on);
+    /// ```
+    pub fn new(
+        battery_voltage: u16,
+        temperature: i16,
+        advertising_pdu_count: u32,
+        time_since_power_on: u32,
+    ) -> Self {
+        Self {
+            battery_voltage,
+            temperature,
+            advertising_pdu_count,
+            time_since_power_on,
+        }
+    }
+
+    /// Get beacon temperature (Celsius).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::beacons::eddystone::{UnencryptedTlm, TLM_TEMPERATURE_NOT_SUPPORTED};
+    ///
+    /// let result = UnencryptedTlm::new(3000, 0x1980, 0, 0);
+    /// assert_eq!(Some(25.5), result.temperature_celsius());
+    ///
+    /// let result = UnencryptedTlm::new(3000, -0x0180, 0, 0);
+    /// assert_eq!(Some(-1.5), result.temperature_celsius());
+    ///
+    /// let result = UnencryptedTlm::new(3000, TLM_TEMPERATURE_NOT_SUPPORTED, 0, 0);
+    /// assert_eq!(None, result.temperature_celsius());
+    /// ```
+    pub fn temperature_celsius(&self) -> Option<f32> {
+        if self.temperature == TLM_TEMPERATURE_NOT_SUPPORTED {
+            None
+        } else {
+            Some(self.temperature as f32 / 256.0)
+        }
+    }
+}
+
+/// Eddystone-TLM encrypted TLM (version 0x01).
+#[derive(Debug, PartialEq, Clone)]
+pub struct EncryptedTlm {
+    /// Encrypted TLM data
+    pub etlm: [u8; 12],
+
+    /// Salt
+    pub salt: [u8; 2],
+
+    /// Message Integrity Check
+    pub message_integrity_check: [u8; 2],
+}
+
+impl EncryptedTlm {
+    /// Create [`EncryptedTlm`] from Parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::beacons::eddystone::EncryptedTlm;
+    ///
+    /// let result = EncryptedTlm::new([0x01; 12], [0x02, 0x03], [0x04, 0x05]);
+    /// assert_eq!([0x01; 12], result.etlm);
+    /// assert_eq!([0x02, 0x03], result.salt);
+    /// assert_eq!([0x04, 0x05], result.message_integrity_check);
+    /// ```
+    pub fn new(etlm: [u8; 12], salt: [u8; 2], message_integrity_check: [u8; 2]) -> Self {
+        Self {
+            etlm,
+            salt,
+            message_integrity_check,
+        }
+    }
+}
+
+/// Eddystone-TLM.
+#[derive(Debug, PartialEq, Clone)]
+pub enum EddystoneTlm {
+    /// Unencrypted TLM (version 0x00)
+    Unencrypted(UnencryptedTlm),
+
+    /// Encrypted TLM (version 0x01)
+    Encrypted(EncryptedTlm),
+}
+
+impl TryFrom<&Vec<u8>> for EddystoneTlm {
+    type Error = String;
+    /// Create [`EddystoneTlm`] from Eddystone frame.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::beacons::eddystone::{EddystoneTlm, EncryptedTlm, UnencryptedTlm};
+    ///
+    /// let data: Vec<u8> = vec![
+    ///     0x20, 0x00, 0x0b, 0xb8, 0x19, 0x80, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08,
+    /// ];
+    /// let result = EddystoneTlm::try_from(&data);
+    /// assert_eq!(
+    ///     Ok(EddystoneTlm::Unencrypted(UnencryptedTlm::new(
+    ///         3000, 0x1980, 0x01020304, 0x05060708
+    ///     ))),
+    ///     result
+    /// );
+    ///
+    /// let mut data: Vec<u8> = vec![0x20, 0x01];
+    /// data.append(&mut [0x01u8; 12].to_vec());
+    /// data.append(&mut vec![0x02, 0x03, 0x04, 0x05]);
+    /// let result = EddystoneTlm::try_from(&data);
+    /// assert_eq!(
+    ///     Ok(EddystoneTlm::Encrypted(EncryptedTlm::new(
+    ///         [0x01; 12],
+    ///         [0x02, 0x03],
+    ///         [0x04, 0x05]
+    ///     ))),
+    ///     result
+    /// );
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = EddystoneTlm::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        let len = value.len();
+        if len < 2 {
+            return Err(format!("Invalid data size :{}", len).to_string());
+        }
+        if value[0] != FRAME_TYPE_TLM {
+            return Err(format!("Invalid frame type :{}", value[0]).to_string());
+        }
+        match value[1] {
+            TLM_VERSION_UNENCRYPTED if len == 14 => Ok(EddystoneTlm::Unencrypted(UnencryptedTlm {
+                battery_voltage: u16::from_be_bytes(value[2..4].try_into().unwrap()),
+                temperature: i16::from_be_bytes(value[4..6].try_into().unwrap()),
+                advertising_pdu_count: u32::from_be_bytes(value[6..10].try_into().unwrap()),
+                time_since_power_on: u32::from_be_bytes(value[10..14].try_into().unwrap()),
+            })),
+            TLM_VERSION_ENCRYPTED if len == 18 => Ok(EddystoneTlm::Encrypted(EncryptedTlm {
+                etlm: value[2..14].try_into().unwrap(),
+                salt: value[14..16].try_into().unwrap(),
+                message_integrity_check: value[16..18].try_into().unwrap(),
+            })),
+            TLM_VERSION_UNENCRYPTED | TLM_VERSION_ENCRYPTED => {
+                Err(format!("Invalid data size :{}", len).to_string())
+            }
+            version => Err(format!("Invalid version :{}", version).to_string()),
+        }
+    }
+}
+
+impl TryFrom<&ServiceData16BitUUID> for EddystoneTlm {
+    type Error = String;
+    /// Create [`EddystoneTlm`] from [`ServiceData16BitUUID`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     beacons::eddystone::{EddystoneTlm, UnencryptedTlm},
+    ///     data_types::service_data_16bit_uuid::ServiceData16BitUUID, uuid_from_u16,
+    /// };
+    ///
+    /// let data: Vec<u8> = vec![
+    ///     0x20, 0x00, 0x0b, 0xb8, 0x19, 0x80, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08,
+    /// ];
+    /// let service_data = ServiceData16BitUUID::new(&uuid_from_u16(0xfeaa), &data);
+    /// let result = EddystoneTlm::try_from(&service_data);
+    /// assert_eq!(
+    ///     Ok(EddystoneTlm::Unencrypted(UnencryptedTlm::new(
+    ///         3000, 0x1980, 0x01020304, 0x05060708
+    ///     ))),
+    ///     result
+    /// );
+    ///
+    /// let service_data = ServiceData16BitUUID::new(&uuid_from_u16(0x180f), &data);
+    /// let result = EddystoneTlm::try_from(&service_data);
+    /// assert!(result.is_err());
+    /// ```
+    fn try_from(value: &ServiceData16BitUUID) -> Result<Self, String> {
+        if value.uuid != uuid_from_u16(EDDYSTONE_SERVICE_UUID) {
+            return Err(format!("Invalid service uuid :{}", value.uuid).to_string());
+        }
+        Self::try_from(&value.additional_service_data.to_vec())
+    }
+}
+
+impl From<EddystoneTlm> for Vec<u8> {
+    /// Create Eddystone frame from [`EddystoneTlm`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::beacons::eddystone::{EddystoneTlm, UnencryptedTlm};
+    ///
+    /// let data: Vec<u8> = vec![
+    ///     0x20, 0x00, 0x0b, 0xb8, 0x19, 0x80, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08,
+    /// ];
+    /// let into_data: Vec<u8> = EddystoneTlm::Unencrypted(UnencryptedTlm::new(
+    ///     3000, 0x1980, 0x01020304, 0x05060708,
+    /// ))
+    /// .into();
+    /// assert_eq!(data, into_data);
+    /// ```
+    fn from(value: EddystoneTlm) -> Self {
+        let mut data: Vec<u8> = vec![FRAME_TYPE_TLM];
+        match value {
+            EddystoneTlm::Unencrypted(tlm) => {
+                data.push(TLM_VERSION_UNENCRYPTED);
+                data.append(&mut tlm.battery_voltage.to_be_bytes().to_vec());
+                data.append(&mut tlm.temperature.to_be_bytes().to_vec());
+                data.append(&mut tlm.advertising_pdu_count.to_be_bytes().to_vec());
+                data.append(&mut tlm.time_since_power_on.to_be_bytes().to_vec());
+            }
+            EddystoneTlm::Encrypted(tlm) => {
+                data.push(TLM_VERSION_ENCRYPTED);
+                data.append(&mut tlm.etlm.to_vec());
+                data.append(&mut tlm.salt.to_vec());
+                data.append(&mut tlm.message_integrity_check.to_vec());
+            }
+        }
+        data
+    }
+}
+
+impl From<EddystoneTlm> for ServiceData16BitUUID {
+    /// Create [`ServiceData16BitUUID`] from [`EddystoneTlm`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     beacons::eddystone::{EddystoneTlm, EncryptedTlm},
+    ///     data_types::service_data_16bit_uuid::ServiceData16BitUUID, uuid_from_u16,
+    /// };
+    ///
+    /// let result: ServiceData16BitUUID =
+    ///     EddystoneTlm::Encrypted(EncryptedTlm::new([0x01; 12], [0x02, 0x03], [0x04, 0x05])).into();
+    /// assert_eq!(uuid_from_u16(0xfeaa), result.uuid);
+    /// assert_eq!(18, result.additional_service_data.len());
+    /// ```
+    fn from(value: EddystoneTlm) -> Self {
+        let data: Vec<u8> = value.into();
+        ServiceData16BitUUID::new(&uuid_from_u16(EDDYSTONE_SERVICE_UUID), &data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        beacons::eddystone::{
+            decode_url, encode_url, EddystoneTlm, EddystoneUid, EddystoneUrl, EncryptedTlm,
+            UnencryptedTlm, TLM_TEMPERATURE_NOT_SUPPORTED,
+        },
+        data_types::service_data_16bit_uuid::ServiceData16BitUUID,
+        uuid_from_u16,
+    };
+
+    const NAMESPACE: [u8; 10] = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a];
+    const INSTANCE: [u8; 6] = [0x0b, 0x0c, 0x0d, 0x0e, 0x0f, 0x10];
+
+    fn uid_frame() -> Vec<u8> {
+        let mut data: Vec<u8> = vec![0x00, 0xec];
+        data.append(&mut NAMESPACE.to_vec());
+        data.append(&mut INSTANCE.to_vec());
+        data.append(&mut vec![0x00, 0x00]);
+        data
+    }
+
+    #[test]
+    fn test_uid_new() {
+        let result = EddystoneUid::new(-20, NAMESPACE, INSTANCE);
+        assert_eq!(-20, result.ranging_data);
+        assert_eq!(NAMESPACE, result.namespace);
+        assert_eq!(INSTANCE, result.instance);
+    }
+
+    #[test]
+    fn test_uid_try_from() {
+        let expected = EddystoneUid::new(-20, NAMESPACE, INSTANCE);
+        assert_eq!(Ok(expected.clone()), EddystoneUid::try_from(&uid_frame()));
+        assert_eq!(
+            Ok(expected),
+            EddystoneUid::try_from(&uid_frame()[..18].to_vec())
+        );
+
+        let data: Vec<u8> = Vec::new();
+        assert_eq!(
+            Err(format!("Invalid data size :{}", data.len())),
+            EddystoneUid::try_from(&data)
+        );
+
+        let mut data = uid_frame();
+        data[0] = 0x10;
+        assert!(EddystoneUid::try_from(&data).is_err());
+    }
+
+    #[test]
+    fn test_uid_try_from_service_data() {
+        let service_data = ServiceData16BitUUID::new(&uuid_from_u16(0xfeaa), &uid_frame());
+        assert_eq!(
+            Ok(EddystoneUid::new(-20, NAMESPACE, INSTANCE)),
+            EddystoneUid::try_from(&service_data)
+        );
+
+        let service_data = ServiceData16BitUUID::new(&uuid_from_u16(0x180f), &uid_frame());
+        assert!(EddystoneUid::try_from(&service_data).is_err());
+    }
+
+    #[test]
+    fn test_uid_into() {
+        let into_data: Vec<u8> = EddystoneUid::new(-20, NAMESPACE, INSTANCE).into();
+        assert_eq!(uid_frame(), into_data);
+
+        let result: ServiceData16BitUUID = EddystoneUid::new(-20, NAMESPACE, INSTANCE).into();
+        assert_eq!(
+            ServiceData16BitUUID::new(&uuid_from_u16(0xfeaa), &uid_frame()),
+            result
+        );
+        let into_data: Vec<u8> = result.into();
+        assert_eq!(
+            Ok(EddystoneUid::new(-20, NAMESPACE, INSTANCE)),
+            EddystoneUid::try_from(&ServiceData16BitUUID::try_from(&into_data).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_encode_url() {
+        assert_eq!(
+            Ok(vec![0x00, b'g', b'o', b'o', b'g', b'l', b'e', 0x07]),
+            encode_url("http://www.google.com")
+        );
+        assert_eq!(
+            Ok(vec![0x01, b'a', 0x04, b'b']),
+            encode_url("https://www.a.info/b")
+        );
+        assert_eq!(Ok(vec![0x02, b'a', 0x0b]), encode_url("http://a.info"));
+        assert_eq!(
+            Ok(vec![
+                0x03, b'e', b'x', b'a', b'm', b'p', b'l', b'e', 0x00, b'a'
+            ]),
+            encode_url("https://example.com/a")
+        );
+        assert!(encode_url("ftp://example.com").is_err());
+        assert!(encode_url("https://exa mple.com").is_err());
+        assert!(encode_url("https://abcdefghijklmnopqr").is_err());
+        assert!(encode_url("https://abcdefghijklmnopq").is_ok());
+    }
+
+    #[test]
+    fn test_decode_url() {
+        assert_eq!(
+            Ok("http://www.google.com".to_string()),
+            decode_url(&[0x00, b'g', b'o', b'o', b'g', b'l', b'e', 0x07])
+        );
+        assert_eq!(
+            Ok("https://www.a.info/b".to_string()),
+            decode_url(&[0x01, b'a', 0x04, b'b'])
+        );
+        assert!(decode_url(&[0x04]).is_err());
+        assert!(decode_url(&[0x03, 0x0e]).is_err());
+        assert!(decode_url(&[0x03, 0x20]).is_err());
+        assert!(decode_url(&[]).is_err());
+    }
+
+    #[test]
+    fn test_url_round_trip() {
+        for url in [
+            "http://www.google.com",
+            "https://example.com/",
+            "https://goo.gl/S6zT6P",
+            "http://a.org/b.edu/c.net",
+            "https://www.abc.gov/x.biz",
+        ] {
+            let data = encode_url(url).unwrap();
+            assert_eq!(Ok(url.to_string()), decode_url(&data));
+        }
+    }
+
+    #[test]
+    fn test_url_new() {
+        let result = EddystoneUrl::new(-20, "https://example.com/");
+        assert_eq!(-20, result.tx_power);
+        assert_eq!("https://example.com/", result.url);
+    }
+
+    #[test]
+    fn test_url_try_from() {
+        let data: Vec<u8> = vec![
+            0x10, 0xec, 0x03, b'e', b'x', b'a', b'm', b'p', b'l', b'e', 0x00,
+        ];
+        assert_eq!(
+            Ok(EddystoneUrl::new(-20, "https://example.com/")),
+            EddystoneUrl::try_from(&data)
+        );
+
+        let service_data = ServiceData16BitUUID::new(&uuid_from_u16(0xfeaa), &data);
+        assert_eq!(
+            Ok(EddystoneUrl::new(-20, "https://example.com/")),
+            EddystoneUrl::try_from(&service_data)
+        );
+
+        let data: Vec<u8> = Vec::new();
+        assert_eq!(
+            Err(format!("Invalid data size :{}", data.len())),
+            EddystoneUrl::try_from(&data)
+        );
+
+        let data: Vec<u8> = vec![0x00, 0xec, 0x03];
+        assert!(EddystoneUrl::try_from(&data).is_err());
+    }
+
+    #[test]
+    fn test_url_into() {
+        let data: Vec<u8> = vec![
+            0x10, 0xec, 0x03, b'e', b'x', b'a', b'm', b'p', b'l', b'e', 0x00,
+        ];
+        let eddystone_url = EddystoneUrl::new(-20, "https://example.com/");
+        assert_eq!(Ok(data.clone()), Vec::<u8>::try_from(eddystone_url.clone()));
+        assert_eq!(
+            Ok(ServiceData16BitUUID::new(&uuid_from_u16(0xfeaa), &data)),
+            ServiceData16BitUUID::try_from(eddystone_url)
+        );
+
+        let eddystone_url = EddystoneUrl::new(-20, "ftp://example.com/");
+        assert!(Vec::<u8>::try_from(eddystone_url).is_err());
+    }
+
+    fn tlm_frame() -> Vec<u8> {
+        vec![
+            0x20, 0x00, 0x0b, 0xb8, 0x19, 0x80, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08,
+        ]
+    }
+
+    fn etlm_frame() -> Vec<u8> {
+        let mut data: Vec<u8> = vec![0x20, 0x01];
+        data.append(&mut [0x01u8; 12].to_vec());
+        data.append(&mut vec![0x02, 0x03, 0x04, 0x05]);
+        data
+    }
+
+    #[test]
+    fn test_tlm_new() {
+        let result = UnencryptedTlm::new(3000, 0x1980, 0x01020304, 0x05060708);
+        assert_eq!(3000, result.battery_voltage);
+        assert_eq!(0x1980, result.temperature);
+        assert_eq!(0x01020304, result.advertising_pdu_count);
+        assert_eq!(0x05060708, result.time_since_power_on);
+
+        let result = EncryptedTlm::new([0x01; 12], [0x02, 0x03], [0x04, 0x05]);
+        assert_eq!([0x01; 12], result.etlm);
+        assert_eq!([0x02, 0x03], result.salt);
+        assert_eq!([0x04, 0x05], result.message_integrity_check);
+    }
+
+    #[test]
+    fn test_tlm_temperature_celsius() {
+        assert_eq!(
+            Some(25.5),
+            UnencryptedTlm::new(0, 0x1980, 0, 0).temperature_celsius()
+        );
+        assert_eq!(
+            Some(-1.5),
+            UnencryptedTlm::new(0, -0x0180, 0, 0).temperature_celsius()
+        );
+        assert_eq!(
+            None,
+            UnencryptedTlm::new(0, TLM_TEMPERATURE_NOT_SUPPORTED, 0, 0).temperature_celsius()
+        );
+    }
+
+    #[test]
+    fn test_tlm_try_from() {
+        assert_eq!(
+            Ok(EddystoneTlm::Unencrypted(UnencryptedTlm::new(
+                3000, 0x1980, 0x01020304, 0x05060708
+            ))),
+            EddystoneTlm::try_from(&tlm_frame())
+        );
+        assert_eq!(
+            Ok(EddystoneTlm::Encrypted(EncryptedTlm::new(
+                [0x01; 12],
+                [0x02, 0x03],
+                [0x04, 0x05]
+            ))),
+            EddystoneTlm::try_from(&etlm_frame())
+        );
+
+        let service_data = ServiceData16BitUUID::new(&uuid_from_u16(0xfeaa), &tlm_frame());
+        assert!(EddystoneTlm::try_from(&service_data).is_ok());
+        let service_data = ServiceData16BitUUID::new(&uuid_from_u16(0x180f), &tlm_frame());
+        assert!(EddystoneTlm::try_from(&service_data).is_err());
+
+        let data: Vec<u8> = Vec::new();
+        assert_eq!(
+            Err(format!("Invalid data size :{}", data.len())),
+            EddystoneTlm::try_from(&data)
+        );
+        assert!(EddystoneTlm::try_from(&tlm_frame()[..13].to_vec()).is_err());
+        assert!(EddystoneTlm::try_from(&etlm_frame()[..14].to_vec()).is_err());
+
+        let mut data = tlm_frame();
+        data[1] = 0x02;
+        assert!(EddystoneTlm::try_from(&data).is_err());
+
+        let mut data = tlm_frame();
+        data[0] = 0x00;
+        assert!(EddystoneTlm::try_from(&data).is_err());
+    }
+
+    #[test]
+    fn test_tlm_into() {
+        let into_data: Vec<u8> =
+            EddystoneTlm::Unencrypted(UnencryptedTlm::new(3000, 0x1980, 0x01020304, 0x05060708))
+                .into();
+        assert_eq!(tlm_frame(), into_data);
+
+        let result: ServiceData16BitUUID =
+            EddystoneTlm::Encrypted(EncryptedTlm::new([0x01; 12], [0x02, 0x03], [0x04, 0x05]))
+                .into();
+        assert_eq!(
+            ServiceData16BitUUID::new(&uuid_from_u16(0xfeaa), &etlm_frame()),
+            result
+        );
+    }
+}
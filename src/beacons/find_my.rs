@@ -0,0 +1,216 @@
+//! Apple Find My network (Company Identifier: 0x004c, Type: 0x12) module.
+
+use crate::{
+    beacons::ibeacon::APPLE_COMPANY_IDENTIFIER,
+    data_types::manufacturer_specific_data::ManufacturerSpecificData,
+};
+
+/// Find My offline finding Type
+pub const FIND_MY_TYPE: u8 = 0x12;
+
+/// Find My separated state remaining data length
+pub const FIND_MY_SEPARATED_LENGTH: u8 = 0x19;
+
+/// Find My nearby state remaining data length
+pub const FIND_MY_NEARBY_LENGTH: u8 = 0x02;
+
+/// Apple Find My network advertisement.
+#[derive(Debug, PartialEq, Clone)]
+pub struct FindMy {
+    /// Status (battery level and flags)
+    pub status: u8,
+
+    /// Public key bytes 6 - 27 (empty in nearby state)
+    pub public_key_fragment: Vec<u8>,
+
+    /// Public key bits (bits 0 - 1 of first public key byte)
+    pub public_key_bits: u8,
+
+    /// Hint (separated state only)
+    pub hint: Option<u8>,
+}
+
+impl FindMy {
+    /// Create [`FindMy`] from Parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::beacons::find_my::FindMy;
+    ///
+    /// let result = FindMy::new(0x10, &[0x01u8; 22].to_vec(), 0x02, Some(0x00));
+    /// assert_eq!(0x10, result.status);
+    /// assert_eq!([0x01u8; 22].to_vec(), result.public_key_fragment);
+    /// assert_eq!(0x02, result.public_key_bits);
+    /// assert_eq!(Some(0x00), result.hint);
+    /// ```
+    pub fn new(
+        status: u8,
+        public_key_fragment: &[u8],
+        public_key_bits: u8,
+        hint: Option<u8>,
+    ) -> Self {
+        Self {
+            status,
+            public_key_fragment: public_key_fragment.to_vec(),
+            public_key_bits,
+            hint,
+        }
+    }
+
+    /// check separated state (full public key fragment present).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::beacons::find_my::FindMy;
+    ///
+    /// let result = FindMy::new(0x10, &[0x01u8; 22].to_vec(), 0x02, Some(0x00));
+    /// assert!(result.is_separated());
+    ///
+    /// let result = FindMy::new(0x10, &[], 0x02, None);
+    /// assert!(!result.is_separated());
+    /// ```
+    pub fn is_separated(&self) -> bool {
+        self.hint.is_some()
+    }
+}
+
+impl TryFrom<&Vec<u8>> for FindMy {
+    type Error = String;
+    /// Create [`FindMy`] from vendor payload (Company Identifier Code and Manufacturer Specific Data).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::beacons::find_my::FindMy;
+    ///
+    /// let mut data: Vec<u8> = vec![0x4c, 0x00, 0x12, 0x19, 0x10];
+    /// data.append(&mut [0x01u8; 22].to_vec());
+    /// data.append(&mut vec![0x02, 0x00]);
+    /// let result = FindMy::try_from(&data);
+    /// assert_eq!(Ok(FindMy::new(0x10, &[0x01u8; 22], 0x02, Some(0x00))), result);
+    ///
+    /// let data: Vec<u8> = vec![0x4c, 0x00, 0x12, 0x02, 0x10, 0x02];
+    /// let result = FindMy::try_from(&data);
+    /// assert_eq!(Ok(FindMy::new(0x10, &[], 0x02, None)), result);
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = FindMy::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        let len = value.len();
+        if len < 4 || len != 4 + value[3] as usize {
+            return Err(format!("Invalid data size :{}", len).to_string());
+        }
+        let company_identifier = u16::from_le_bytes(value[0..2].try_into().unwrap());
+        if company_identifier != APPLE_COMPANY_IDENTIFIER {
+            return Err(format!("Invalid company identifier :{}", company_identifier).to_string());
+        }
+        if value[2] != FIND_MY_TYPE {
+            return Err(format!("Invalid type :{}", value[2]).to_string());
+        }
+        match value[3] {
+            FIND_MY_SEPARATED_LENGTH => Ok(Self {
+                status: value[4],
+                public_key_fragment: value[5..27].to_vec(),
+                public_key_bits: value[27],
+                hint: Some(value[28]),
+            }),
+            FIND_MY_NEARBY_LENGTH => Ok(Self {
+                status: value[4],
+                public_key_fragment: Vec::new(),
+                public_key_bits: value[5],
+                hint: None,
+            }),
+            length => Err(format!("Invalid length :{}", length).to_string()),
+        }
+    }
+}
+
+impl TryFrom<&ManufacturerSpecificData> for FindMy {
+    type Error = String;
+    /// Create [`FindMy`] from [`ManufacturerSpecificData`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     beacons::find_my::FindMy,
+    ///     data_types::manufacturer_specific_data::ManufacturerSpecificData,
+    /// };
+    ///
+    /// let data: Vec<u8> = vec![0x12, 0x02, 0x10, 0x02];
+    /// let result = FindMy::try_from(&ManufacturerSpecificData::new(0x004c, &data));
+    /// assert_eq!(Ok(FindMy::new(0x10, &[], 0x02, None)), result);
+    ///
+    /// let result = FindMy::try_from(&ManufacturerSpecificData::new(0x0006, &data));
+    /// assert!(result.is_err());
+    /// ```
+    fn try_from(value: &ManufacturerSpecificData) -> Result<Self, String> {
+        let mut data = u16::to_le_bytes(value.company_identifier).to_vec();
+        data.extend_from_slice(&value.manufacturer_specific_data);
+        Self::try_from(&data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        beacons::find_my::FindMy, data_types::manufacturer_specific_data::ManufacturerSpecificData,
+    };
+
+    #[test]
+    fn test_new() {
+        let result = FindMy::new(0x10, &[0x01u8; 22], 0x02, Some(0x00));
+        assert_eq!(0x10, result.status);
+        assert_eq!([0x01u8; 22].to_vec(), result.public_key_fragment);
+        assert_eq!(0x02, result.public_key_bits);
+        assert_eq!(Some(0x00), result.hint);
+        assert!(result.is_separated());
+
+        let result = FindMy::new(0x10, &[], 0x02, None);
+        assert!(!result.is_separated());
+    }
+
+    #[test]
+    fn test_try_from() {
+        let mut data: Vec<u8> = vec![0x4c, 0x00, 0x12, 0x19, 0x10];
+        data.append(&mut [0x01u8; 22].to_vec());
+        data.append(&mut vec![0x02, 0x00]);
+        assert_eq!(
+            Ok(FindMy::new(0x10, &[0x01u8; 22], 0x02, Some(0x00))),
+            FindMy::try_from(&data)
+        );
+        assert!(FindMy::try_from(&data[..28].to_vec()).is_err());
+
+        let data: Vec<u8> = vec![0x4c, 0x00, 0x12, 0x02, 0x10, 0x02];
+        assert_eq!(
+            Ok(FindMy::new(0x10, &[], 0x02, None)),
+            FindMy::try_from(&data)
+        );
+
+        let data: Vec<u8> = vec![0x12, 0x02, 0x10, 0x02];
+        assert_eq!(
+            Ok(FindMy::new(0x10, &[], 0x02, None)),
+            FindMy::try_from(&ManufacturerSpecificData::new(0x004c, &data))
+        );
+
+        let data: Vec<u8> = Vec::new();
+        assert_eq!(
+            Err(format!("Invalid data size :{}", data.len())),
+            FindMy::try_from(&data)
+        );
+
+        let data: Vec<u8> = vec![0x4c, 0x00, 0x02, 0x02, 0x10, 0x02];
+        assert!(FindMy::try_from(&data).is_err());
+
+        let data: Vec<u8> = vec![0x4c, 0x00, 0x12, 0x01, 0x10];
+        assert!(FindMy::try_from(&data).is_err());
+    }
+}
@@ -0,0 +1,226 @@
+//! Govee thermometer (H5075 / H5102) module.
+
+use crate::data_types::manufacturer_specific_data::ManufacturerSpecificData;
+
+/// Govee H5075 Company Identifier
+pub const GOVEE_H5075_COMPANY_IDENTIFIER: u16 = 0xec88;
+
+/// Govee H5101 / H5102 Company Identifier
+pub const GOVEE_H5102_COMPANY_IDENTIFIER: u16 = 0x0001;
+
+/// Govee thermometer model.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum GoveeModel {
+    /// H5072 / H5075
+    H5075,
+
+    /// H5101 / H5102
+    H5102,
+}
+
+/// Govee thermometer.
+#[derive(Debug, PartialEq, Clone)]
+pub struct GoveeThermometer {
+    /// Model
+    pub model: GoveeModel,
+
+    /// 24-bit encoded temperature and humidity
+    pub encoded_value: u32,
+
+    /// Battery (%)
+    pub battery: u8,
+}
+
+impl GoveeThermometer {
+    /// Create [`GoveeThermometer`] from Parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::beacons::govee::{GoveeModel, GoveeThermometer};
+    ///
+    /// let result = GoveeThermometer::new(GoveeModel::H5075, 0x036d2a, 100);
+    /// assert_eq!(GoveeModel::H5075, result.model);
+    /// assert_eq!(0x036d2a, result.encoded_value);
+    /// assert_eq!(100, result.battery);
+    /// ```
+    pub fn new(model: GoveeModel, encoded_value: u32, battery: u8) -> Self {
+        Self {
+            model,
+            encoded_value: encoded_value & 0x00ffffff,
+            battery,
+        }
+    }
+
+    /// Get temperature (Celsius).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::beacons::govee::{GoveeModel, GoveeThermometer};
+    ///
+    /// let result = GoveeThermometer::new(GoveeModel::H5075, 0x036d2a, 100);
+    /// assert_eq!(22.4, result.temperature_celsius());
+    ///
+    /// let result = GoveeThermometer::new(GoveeModel::H5075, 0x80c5ea, 100);
+    /// assert_eq!(-5.0, result.temperature_celsius());
+    /// ```
+    pub fn temperature_celsius(&self) -> f64 {
+        let temperature = ((self.encoded_value & 0x7fffff) / 1000) as f64 / 10.0;
+        if self.encoded_value & 0x800000 == 0 {
+            temperature
+        } else {
+            -temperature
+        }
+    }
+
+    /// Get relative humidity (%).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::beacons::govee::{GoveeModel, GoveeThermometer};
+    ///
+    /// let result = GoveeThermometer::new(GoveeModel::H5075, 0x036d2a, 100);
+    /// assert_eq!(55.4, result.humidity_percent());
+    /// ```
+    pub fn humidity_percent(&self) -> f64 {
+        ((self.encoded_value & 0x7fffff) % 1000) as f64 / 10.0
+    }
+}
+
+impl TryFrom<&Vec<u8>> for GoveeThermometer {
+    type Error = String;
+    /// Create [`GoveeThermometer`] from 8 bytes vendor payload (Company Identifier Code and Manufacturer Specific Data).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::beacons::govee::{GoveeModel, GoveeThermometer};
+    ///
+    /// let data: Vec<u8> = vec![0x88, 0xec, 0x00, 0x03, 0x6d, 0x2a, 0x64, 0x00];
+    /// let result = GoveeThermometer::try_from(&data);
+    /// assert_eq!(Ok(GoveeThermometer::new(GoveeModel::H5075, 0x036d2a, 100)), result);
+    ///
+    /// let data: Vec<u8> = vec![0x01, 0x00, 0x01, 0x01, 0x03, 0x6d, 0x2a, 0x64];
+    /// let result = GoveeThermometer::try_from(&data);
+    /// assert_eq!(Ok(GoveeThermometer::new(GoveeModel::H5102, 0x036d2a, 100)), result);
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = GoveeThermometer::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        let len = value.len();
+        if len != 8 {
+            return Err(format!("Invalid data size :{}", len).to_string());
+        }
+        let company_identifier = u16::from_le_bytes(value[0..2].try_into().unwrap());
+        let (model, offset) = match company_identifier {
+            GOVEE_H5075_COMPANY_IDENTIFIER => (GoveeModel::H5075, 3),
+            GOVEE_H5102_COMPANY_IDENTIFIER => (GoveeModel::H5102, 4),
+            _ => {
+                return Err(
+                    format!("Invalid company identifier :{}", company_identifier).to_string(),
+                )
+            }
+        };
+        Ok(Self {
+            model,
+            encoded_value: u32::from_be_bytes([
+                0x00,
+                value[offset],
+                value[offset + 1],
+                value[offset + 2],
+            ]),
+            battery: value[offset + 3],
+        })
+    }
+}
+
+impl TryFrom<&ManufacturerSpecificData> for GoveeThermometer {
+    type Error = String;
+    /// Create [`GoveeThermometer`] from [`ManufacturerSpecificData`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     beacons::govee::{GoveeModel, GoveeThermometer},
+    ///     data_types::manufacturer_specific_data::ManufacturerSpecificData,
+    /// };
+    ///
+    /// let data: Vec<u8> = vec![0x00, 0x03, 0x6d, 0x2a, 0x64, 0x00];
+    /// let result = GoveeThermometer::try_from(&ManufacturerSpecificData::new(0xec88, &data));
+    /// assert_eq!(Ok(GoveeThermometer::new(GoveeModel::H5075, 0x036d2a, 100)), result);
+    ///
+    /// let result = GoveeThermometer::try_from(&ManufacturerSpecificData::new(0x004c, &data));
+    /// assert!(result.is_err());
+    /// ```
+    fn try_from(value: &ManufacturerSpecificData) -> Result<Self, String> {
+        let mut data = u16::to_le_bytes(value.company_identifier).to_vec();
+        data.extend_from_slice(&value.manufacturer_specific_data);
+        Self::try_from(&data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        beacons::govee::{GoveeModel, GoveeThermometer},
+        data_types::manufacturer_specific_data::ManufacturerSpecificData,
+    };
+
+    #[test]
+    fn test_new() {
+        let result = GoveeThermometer::new(GoveeModel::H5102, 0xff036d2a, 50);
+        assert_eq!(GoveeModel::H5102, result.model);
+        assert_eq!(0x036d2a, result.encoded_value);
+        assert_eq!(50, result.battery);
+    }
+
+    #[test]
+    fn test_values() {
+        let result = GoveeThermometer::new(GoveeModel::H5075, 0x036d2a, 100);
+        assert_eq!(22.4, result.temperature_celsius());
+        assert_eq!(55.4, result.humidity_percent());
+
+        let result = GoveeThermometer::new(GoveeModel::H5075, 0x80c5ea, 100);
+        assert_eq!(-5.0, result.temperature_celsius());
+        assert_eq!(66.6, result.humidity_percent());
+    }
+
+    #[test]
+    fn test_try_from() {
+        let data: Vec<u8> = vec![0x88, 0xec, 0x00, 0x03, 0x6d, 0x2a, 0x64, 0x00];
+        assert_eq!(
+            Ok(GoveeThermometer::new(GoveeModel::H5075, 0x036d2a, 100)),
+            GoveeThermometer::try_from(&data)
+        );
+
+        let data: Vec<u8> = vec![0x01, 0x00, 0x01, 0x01, 0x03, 0x6d, 0x2a, 0x64];
+        assert_eq!(
+            Ok(GoveeThermometer::new(GoveeModel::H5102, 0x036d2a, 100)),
+            GoveeThermometer::try_from(&data)
+        );
+
+        let data: Vec<u8> = vec![0x01, 0x01, 0x03, 0x6d, 0x2a, 0x64];
+        assert_eq!(
+            Ok(GoveeThermometer::new(GoveeModel::H5102, 0x036d2a, 100)),
+            GoveeThermometer::try_from(&ManufacturerSpecificData::new(0x0001, &data))
+        );
+
+        let data: Vec<u8> = Vec::new();
+        assert_eq!(
+            Err(format!("Invalid data size :{}", data.len())),
+            GoveeThermometer::try_from(&data)
+        );
+
+        let data: Vec<u8> = vec![0x4c, 0x00, 0x00, 0x03, 0x6d, 0x2a, 0x64, 0x00];
+        assert!(GoveeThermometer::try_from(&data).is_err());
+    }
+}
@@ -0,0 +1,273 @@
+//! iBeacon (Company Identifier: 0x004c, Beacon Type: 0x02) module.
+
+use uuid::Uuid;
+
+use crate::data_types::manufacturer_specific_data::ManufacturerSpecificData;
+
+/// Apple, Inc. Company Identifier
+pub const APPLE_COMPANY_IDENTIFIER: u16 = 0x004c;
+
+/// iBeacon Beacon Type
+pub const IBEACON_TYPE: u8 = 0x02;
+
+/// iBeacon remaining data length
+pub const IBEACON_DATA_LENGTH: u8 = 0x15;
+
+/// iBeacon.
+#[derive(Debug, PartialEq, Clone)]
+pub struct IBeacon {
+    /// Proximity UUID
+    pub proximity_uuid: Uuid,
+
+    /// Major
+    pub major: u16,
+
+    /// Minor
+    pub minor: u16,
+
+    /// Measured Power (dBm at 1m)
+    pub measured_power: i8,
+}
+
+impl IBeacon {
+    /// Create [`IBeacon`] from Parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uuid::uuid;
+    /// use ble_data_struct::beacons::ibeacon::IBeacon;
+    ///
+    /// let proximity_uuid = uuid!("e2c56db5-dffb-48d2-b060-d0f5a71096e0");
+    /// let result = IBeacon::new(proximity_uuid, 0x0102, 0x0304, -59);
+    /// assert_eq!(proximity_uuid, result.proximity_uuid);
+    /// assert_eq!(0x0102, result.major);
+    /// assert_eq!(0x0304, result.minor);
+    /// assert_eq!(-59, result.measured_power);
+    /// ```
+    pub fn new(proximity_uuid: Uuid, major: u16, minor: u16, measured_power: i8) -> Self {
+        Self {
+            proximity_uuid,
+            major,
+            minor,
+            measured_power,
+        }
+    }
+}
+
+impl TryFrom<&Vec<u8>> for IBeacon {
+    type Error = String;
+    /// Create [`IBeacon`] from 25 bytes vendor payload (Company Identifier Code and Manufacturer Specific Data).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uuid::uuid;
+    /// use ble_data_struct::beacons::ibeacon::IBeacon;
+    ///
+    /// let proximity_uuid = uuid!("e2c56db5-dffb-48d2-b060-d0f5a71096e0");
+    /// let mut data: Vec<u8> = vec![0x4c, 0x00, 0x02, 0x15];
+    /// data.append(&mut proximity_uuid.as_bytes().to_vec());
+    /// data.append(&mut vec![0x01, 0x02, 0x03, 0x04, 0xc5]);
+    ///
+    /// let result = IBeacon::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let ibeacon = result.unwrap();
+    /// assert_eq!(proximity_uuid, ibeacon.proximity_uuid);
+    /// assert_eq!(0x0102, ibeacon.major);
+    /// assert_eq!(0x0304, ibeacon.minor);
+    /// assert_eq!(-59, ibeacon.measured_power);
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = IBeacon::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        let len = value.len();
+        if len != 25 {
+            return Err(format!("Invalid data size :{}", len).to_string());
+        }
+        let company_identifier = u16::from_le_bytes(value[0..2].try_into().unwrap());
+        if company_identifier != APPLE_COMPANY_IDENTIFIER {
+            return Err(format!("Invalid company identifier :{}", company_identifier).to_string());
+        }
+        if value[2] != IBEACON_TYPE || value[3] != IBEACON_DATA_LENGTH {
+            return Err(format!("Invalid beacon type :{}", value[2]).to_string());
+        }
+        Ok(Self {
+            proximity_uuid: Uuid::from_bytes(value[4..20].try_into().unwrap()),
+            major: u16::from_be_bytes(value[20..22].try_into().unwrap()),
+            minor: u16::from_be_bytes(value[22..24].try_into().unwrap()),
+            measured_power: value[24] as i8,
+        })
+    }
+}
+
+impl TryFrom<&ManufacturerSpecificData> for IBeacon {
+    type Error = String;
+    /// Create [`IBeacon`] from [`ManufacturerSpecificData`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uuid::uuid;
+    /// use ble_data_struct::{
+    ///     beacons::ibeacon::IBeacon,
+    ///     data_types::manufacturer_specific_data::ManufacturerSpecificData,
+    /// };
+    ///
+    /// let proximity_uuid = uuid!("e2c56db5-dffb-48d2-b060-d0f5a71096e0");
+    /// let mut data: Vec<u8> = vec![0x02, 0x15];
+    /// data.append(&mut proximity_uuid.as_bytes().to_vec());
+    /// data.append(&mut vec![0x01, 0x02, 0x03, 0x04, 0xc5]);
+    ///
+    /// let result = IBeacon::try_from(&ManufacturerSpecificData::new(0x004c, &data));
+    /// assert!(result.is_ok());
+    /// assert_eq!(IBeacon::new(proximity_uuid, 0x0102, 0x0304, -59), result.unwrap());
+    ///
+    /// let result = IBeacon::try_from(&ManufacturerSpecificData::new(0x0ca8, &data));
+    /// assert!(result.is_err());
+    /// ```
+    fn try_from(value: &ManufacturerSpecificData) -> Result<Self, String> {
+        let mut data = u16::to_le_bytes(value.company_identifier).to_vec();
+        data.extend_from_slice(&value.manufacturer_specific_data);
+        Self::try_from(&data)
+    }
+}
+
+impl From<IBeacon> for Vec<u8> {
+    /// Create 25 bytes vendor payload (Company Identifier Code and Manufacturer Specific Data) from [`IBeacon`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uuid::uuid;
+    /// use ble_data_struct::beacons::ibeacon::IBeacon;
+    ///
+    /// let proximity_uuid = uuid!("e2c56db5-dffb-48d2-b060-d0f5a71096e0");
+    /// let mut data: Vec<u8> = vec![0x4c, 0x00, 0x02, 0x15];
+    /// data.append(&mut proximity_uuid.as_bytes().to_vec());
+    /// data.append(&mut vec![0x01, 0x02, 0x03, 0x04, 0xc5]);
+    ///
+    /// let into_data: Vec<u8> = IBeacon::new(proximity_uuid, 0x0102, 0x0304, -59).into();
+    /// assert_eq!(25, into_data.len());
+    /// assert_eq!(data, into_data);
+    /// ```
+    fn from(value: IBeacon) -> Self {
+        let mut data: Vec<u8> = Vec::new();
+        data.append(&mut u16::to_le_bytes(APPLE_COMPANY_IDENTIFIER).to_vec());
+        data.push(IBEACON_TYPE);
+        data.push(IBEACON_DATA_LENGTH);
+        data.append(&mut value.proximity_uuid.as_bytes().to_vec());
+        data.append(&mut u16::to_be_bytes(value.major).to_vec());
+        data.append(&mut u16::to_be_bytes(value.minor).to_vec());
+        data.push(value.measured_power as u8);
+        data
+    }
+}
+
+impl From<IBeacon> for ManufacturerSpecificData {
+    /// Create [`ManufacturerSpecificData`] from [`IBeacon`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uuid::uuid;
+    /// use ble_data_struct::{
+    ///     beacons::ibeacon::IBeacon,
+    ///     data_types::manufacturer_specific_data::ManufacturerSpecificData,
+    /// };
+    ///
+    /// let proximity_uuid = uuid!("e2c56db5-dffb-48d2-b060-d0f5a71096e0");
+    /// let result: ManufacturerSpecificData =
+    ///     IBeacon::new(proximity_uuid, 0x0102, 0x0304, -59).into();
+    /// assert_eq!(0x004c, result.company_identifier);
+    /// assert_eq!(23, result.manufacturer_specific_data.len());
+    /// assert_eq!(26, result.length);
+    /// ```
+    fn from(value: IBeacon) -> Self {
+        let data: Vec<u8> = value.into();
+        ManufacturerSpecificData::new(APPLE_COMPANY_IDENTIFIER, &data[2..].to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::{uuid, Uuid};
+
+    use crate::{
+        beacons::ibeacon::IBeacon, data_types::manufacturer_specific_data::ManufacturerSpecificData,
+    };
+
+    const PROXIMITY_UUID: Uuid = uuid!("e2c56db5-dffb-48d2-b060-d0f5a71096e0");
+
+    fn payload() -> Vec<u8> {
+        let mut data: Vec<u8> = vec![0x4c, 0x00, 0x02, 0x15];
+        data.append(&mut PROXIMITY_UUID.as_bytes().to_vec());
+        data.append(&mut vec![0x01, 0x02, 0x03, 0x04, 0xc5]);
+        data
+    }
+
+    #[test]
+    fn test_new() {
+        let result = IBeacon::new(PROXIMITY_UUID, 0x0102, 0x0304, -59);
+        assert_eq!(PROXIMITY_UUID, result.proximity_uuid);
+        assert_eq!(0x0102, result.major);
+        assert_eq!(0x0304, result.minor);
+        assert_eq!(-59, result.measured_power);
+    }
+
+    #[test]
+    fn test_try_from() {
+        let result = IBeacon::try_from(&payload());
+        assert_eq!(
+            Ok(IBeacon::new(PROXIMITY_UUID, 0x0102, 0x0304, -59)),
+            result
+        );
+
+        let data: Vec<u8> = Vec::new();
+        let result = IBeacon::try_from(&data);
+        assert_eq!(Err(format!("Invalid data size :{}", data.len())), result);
+
+        let mut data = payload();
+        data[0] = 0xa8;
+        data[1] = 0x0c;
+        assert!(IBeacon::try_from(&data).is_err());
+
+        let mut data = payload();
+        data[2] = 0x03;
+        assert!(IBeacon::try_from(&data).is_err());
+    }
+
+    #[test]
+    fn test_try_from_manufacturer_specific_data() {
+        let data = payload();
+        let result = IBeacon::try_from(&ManufacturerSpecificData::new(0x004c, &data[2..].to_vec()));
+        assert_eq!(
+            Ok(IBeacon::new(PROXIMITY_UUID, 0x0102, 0x0304, -59)),
+            result
+        );
+
+        let result = IBeacon::try_from(&ManufacturerSpecificData::new(0x0ca8, &data[2..].to_vec()));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_into() {
+        let into_data: Vec<u8> = IBeacon::new(PROXIMITY_UUID, 0x0102, 0x0304, -59).into();
+        assert_eq!(payload(), into_data);
+
+        let result: ManufacturerSpecificData =
+            IBeacon::new(PROXIMITY_UUID, 0x0102, 0x0304, -59).into();
+        assert_eq!(
+            ManufacturerSpecificData::new(0x004c, &payload()[2..].to_vec()),
+            result
+        );
+        let into_data: Vec<u8> = result.into();
+        assert_eq!(27, into_data.len());
+    }
+}
@@ -0,0 +1,148 @@
+//! Manufacturer Specific Data dispatch module.
+
+use crate::{
+    beacons::{
+        altbeacon::AltBeacon, find_my::FindMy, govee::GoveeThermometer, ibeacon::IBeacon,
+        microsoft_cdp::MicrosoftCdp, ruuvi::RuuviRawV2,
+    },
+    data_types::manufacturer_specific_data::ManufacturerSpecificData,
+};
+
+/// Decoded Manufacturer Specific Data.
+#[derive(Debug, PartialEq, Clone)]
+pub enum ManufacturerData {
+    /// iBeacon
+    IBeacon(IBeacon),
+
+    /// AltBeacon
+    AltBeacon(AltBeacon),
+
+    /// Apple Find My network advertisement
+    FindMy(FindMy),
+
+    /// Microsoft Connected Devices Platform beacon
+    MicrosoftCdp(MicrosoftCdp),
+
+    /// Ruuvi Data Format 5 (RAWv2)
+    RuuviRawV2(RuuviRawV2),
+
+    /// Govee thermometer
+    GoveeThermometer(GoveeThermometer),
+
+    /// Unknown layout
+    Unknown(ManufacturerSpecificData),
+}
+
+impl From<&ManufacturerSpecificData> for ManufacturerData {
+    /// Create [`ManufacturerData`] from [`ManufacturerSpecificData`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     beacons::manufacturer_data::ManufacturerData,
+    ///     data_types::manufacturer_specific_data::ManufacturerSpecificData,
+    /// };
+    ///
+    /// let data: Vec<u8> = vec![0x00, 0x03, 0x6d, 0x2a, 0x64, 0x00];
+    /// let result = ManufacturerData::from(&ManufacturerSpecificData::new(0xec88, &data));
+    /// assert!(matches!(result, ManufacturerData::GoveeThermometer(_)));
+    ///
+    /// let data: Vec<u8> = vec![0x01];
+    /// let result = ManufacturerData::from(&ManufacturerSpecificData::new(0x0ca8, &data));
+    /// assert!(matches!(result, ManufacturerData::Unknown(_)));
+    /// ```
+    fn from(value: &ManufacturerSpecificData) -> Self {
+        if let Ok(data) = IBeacon::try_from(value) {
+            ManufacturerData::IBeacon(data)
+        } else if let Ok(data) = FindMy::try_from(value) {
+            ManufacturerData::FindMy(data)
+        } else if let Ok(data) = MicrosoftCdp::try_from(value) {
+            ManufacturerData::MicrosoftCdp(data)
+        } else if let Ok(data) = RuuviRawV2::try_from(value) {
+            ManufacturerData::RuuviRawV2(data)
+        } else if let Ok(data) = GoveeThermometer::try_from(value) {
+            ManufacturerData::GoveeThermometer(data)
+        } else if let Ok(data) = AltBeacon::try_from(value) {
+            ManufacturerData::AltBeacon(data)
+        } else {
+            ManufacturerData::Unknown(value.clone())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::uuid;
+
+    use crate::{
+        beacons::{
+            altbeacon::AltBeacon,
+            find_my::FindMy,
+            govee::{GoveeModel, GoveeThermometer},
+            ibeacon::IBeacon,
+            manufacturer_data::ManufacturerData,
+            microsoft_cdp::MicrosoftCdp,
+            ruuvi::RuuviRawV2,
+        },
+        data_types::manufacturer_specific_data::ManufacturerSpecificData,
+    };
+
+    #[test]
+    fn test_from() {
+        let ibeacon = IBeacon::new(uuid!("e2c56db5-dffb-48d2-b060-d0f5a71096e0"), 1, 2, -59);
+        let data: ManufacturerSpecificData = ibeacon.clone().into();
+        assert_eq!(
+            ManufacturerData::IBeacon(ibeacon),
+            ManufacturerData::from(&data)
+        );
+
+        let altbeacon = AltBeacon::new(0x004c, [0x01; 20], -59, 0x00);
+        let data: ManufacturerSpecificData = altbeacon.clone().into();
+        assert_eq!(
+            ManufacturerData::AltBeacon(altbeacon),
+            ManufacturerData::from(&data)
+        );
+
+        let cdp = MicrosoftCdp::new(0x01, 0x01, 0x09, 0x20, 0x00, [0x01; 4], [0x02; 16]);
+        let data: ManufacturerSpecificData = cdp.clone().into();
+        assert_eq!(
+            ManufacturerData::MicrosoftCdp(cdp),
+            ManufacturerData::from(&data)
+        );
+
+        let data = ManufacturerSpecificData::new(
+            0x0499,
+            &vec![
+                0x05, 0x12, 0xfc, 0x53, 0x94, 0xc3, 0x7c, 0x00, 0x04, 0xff, 0xfc, 0x04, 0x0c, 0xac,
+                0x36, 0x42, 0x00, 0xcd, 0xcb, 0xb8, 0x33, 0x4c, 0x88, 0x4f,
+            ],
+        );
+        assert_eq!(
+            ManufacturerData::RuuviRawV2(RuuviRawV2::try_from(&data).unwrap()),
+            ManufacturerData::from(&data)
+        );
+
+        let data = ManufacturerSpecificData::new(0xec88, &vec![0x00, 0x03, 0x6d, 0x2a, 0x64, 0x00]);
+        assert_eq!(
+            ManufacturerData::GoveeThermometer(GoveeThermometer::new(
+                GoveeModel::H5075,
+                0x036d2a,
+                100
+            )),
+            ManufacturerData::from(&data)
+        );
+
+        let data = ManufacturerSpecificData::new(0x004c, &vec![0x12, 0x02, 0x10, 0x02]);
+        assert_eq!(
+            ManufacturerData::FindMy(FindMy::new(0x10, &[], 0x02, None)),
+            ManufacturerData::from(&data)
+        );
+
+        let data = ManufacturerSpecificData::new(0x004c, &vec![0x01]);
+        assert_eq!(
+            ManufacturerData::Unknown(data.clone()),
+            ManufacturerData::from(&data)
+        );
+    }
+}
@@ -0,0 +1,322 @@
+//! Microsoft Connected Devices Platform beacon (Company Identifier: 0x0006) module.
+
+use crate::data_types::manufacturer_specific_data::ManufacturerSpecificData;
+
+/// Microsoft Company Identifier
+pub const MICROSOFT_COMPANY_IDENTIFIER: u16 = 0x0006;
+
+/// Scenario Type: Bluetooth
+pub const SCENARIO_TYPE_BLUETOOTH: u8 = 0x01;
+
+/// Device Type: Xbox One
+pub const DEVICE_TYPE_XBOX_ONE: u8 = 1;
+
+/// Device Type: Apple iPhone
+pub const DEVICE_TYPE_APPLE_IPHONE: u8 = 6;
+
+/// Device Type: Apple iPad
+pub const DEVICE_TYPE_APPLE_IPAD: u8 = 7;
+
+/// Device Type: Android device
+pub const DEVICE_TYPE_ANDROID_DEVICE: u8 = 8;
+
+/// Device Type: Windows 10 Desktop
+pub const DEVICE_TYPE_WINDOWS_10_DESKTOP: u8 = 9;
+
+/// Device Type: Windows 10 Phone
+pub const DEVICE_TYPE_WINDOWS_10_PHONE: u8 = 11;
+
+/// Device Type: Linux device
+pub const DEVICE_TYPE_LINUX_DEVICE: u8 = 12;
+
+/// Device Type: Windows IoT
+pub const DEVICE_TYPE_WINDOWS_IOT: u8 = 13;
+
+/// Device Type: Surface Hub
+pub const DEVICE_TYPE_SURFACE_HUB: u8 = 14;
+
+/// Device Type: Windows laptop
+pub const DEVICE_TYPE_WINDOWS_LAPTOP: u8 = 15;
+
+/// Device Type: Windows tablet
+pub const DEVICE_TYPE_WINDOWS_TABLET: u8 = 16;
+
+/// Microsoft Connected Devices Platform beacon.
+#[derive(Debug, PartialEq, Clone)]
+pub struct MicrosoftCdp {
+    /// Scenario Type
+    pub scenario_type: u8,
+
+    /// Version (upper 3 bits of Version and Device Type)
+    pub version: u8,
+
+    /// Device Type (lower 5 bits of Version and Device Type)
+    pub device_type: u8,
+
+    /// Version and Flags
+    pub version_and_flags: u8,
+
+    /// Reserved
+    pub reserved: u8,
+
+    /// Salt
+    pub salt: [u8; 4],
+
+    /// Device Hash
+    pub device_hash: [u8; 16],
+}
+
+impl MicrosoftCdp {
+    /// Create [`MicrosoftCdp`] from Parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::beacons::microsoft_cdp::{
+    ///     MicrosoftCdp, DEVICE_TYPE_WINDOWS_10_DESKTOP, SCENARIO_TYPE_BLUETOOTH,
+    /// };
+    ///
+    /// let result = MicrosoftCdp::new(
+    ///     SCENARIO_TYPE_BLUETOOTH,
+    ///     0x01,
+    ///     DEVICE_TYPE_WINDOWS_10_DESKTOP,
+    ///     0x20,
+    ///     0x00,
+    ///     [0x01, 0x02, 0x03, 0x04],
+    ///     [0x05; 16],
+    /// );
+    /// assert_eq!(SCENARIO_TYPE_BLUETOOTH, result.scenario_type);
+    /// assert_eq!(0x01, result.version);
+    /// assert_eq!(DEVICE_TYPE_WINDOWS_10_DESKTOP, result.device_type);
+    /// assert_eq!(0x20, result.version_and_flags);
+    /// assert_eq!(0x00, result.reserved);
+    /// assert_eq!([0x01, 0x02, 0x03, 0x04], result.salt);
+    /// assert_eq!([0x05; 16], result.device_hash);
+    /// ```
+    pub fn new(
+        scenario_type: u8,
+        version: u8,
+        device_type: u8,
+        version_and_flags: u8,
+        reserved: u8,
+        salt: [u8; 4],
+        device_hash: [u8; 16],
+    ) -> Self {
+        Self {
+            scenario_type,
+            version: version & 0b111,
+            device_type: device_type & 0b11111,
+            version_and_flags,
+            reserved,
+            salt,
+            device_hash,
+        }
+    }
+}
+
+impl TryFrom<&Vec<u8>> for MicrosoftCdp {
+    type Error = String;
+    /// Create [`MicrosoftCdp`] from 26 bytes vendor payload (Company Identifier Code and Manufacturer Specific Data).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::beacons::microsoft_cdp::{MicrosoftCdp, DEVICE_TYPE_WINDOWS_10_DESKTOP};
+    ///
+    /// let mut data: Vec<u8> = vec![0x06, 0x00, 0x01, 0x29, 0x20, 0x00, 0x01, 0x02, 0x03, 0x04];
+    /// data.append(&mut [0x05u8; 16].to_vec());
+    ///
+    /// let result = MicrosoftCdp::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let cdp = result.unwrap();
+    /// assert_eq!(0x01, cdp.scenario_type);
+    /// assert_eq!(0x01, cdp.version);
+    /// assert_eq!(DEVICE_TYPE_WINDOWS_10_DESKTOP, cdp.device_type);
+    /// assert_eq!([0x01, 0x02, 0x03, 0x04], cdp.salt);
+    /// assert_eq!([0x05; 16], cdp.device_hash);
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = MicrosoftCdp::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        let len = value.len();
+        if len != 26 {
+            return Err(format!("Invalid data size :{}", len).to_string());
+        }
+        let company_identifier = u16::from_le_bytes(value[0..2].try_into().unwrap());
+        if company_identifier != MICROSOFT_COMPANY_IDENTIFIER {
+            return Err(format!("Invalid company identifier :{}", company_identifier).to_string());
+        }
+        Ok(Self {
+            scenario_type: value[2],
+            version: value[3] >> 5,
+            device_type: value[3] & 0b11111,
+            version_and_flags: value[4],
+            reserved: value[5],
+            salt: value[6..10].try_into().unwrap(),
+            device_hash: value[10..26].try_into().unwrap(),
+        })
+    }
+}
+
+impl TryFrom<&ManufacturerSpecificData> for MicrosoftCdp {
+    type Error = String;
+    /// Create [`MicrosoftCdp`] from [`ManufacturerSpecificData`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     beacons::microsoft_cdp::MicrosoftCdp,
+    ///     data_types::manufacturer_specific_data::ManufacturerSpecificData,
+    /// };
+    ///
+    /// let mut data: Vec<u8> = vec![0x01, 0x29, 0x20, 0x00, 0x01, 0x02, 0x03, 0x04];
+    /// data.append(&mut [0x05u8; 16].to_vec());
+    ///
+    /// let result = MicrosoftCdp::try_from(&ManufacturerSpecificData::new(0x0006, &data));
+    /// assert!(result.is_ok());
+    ///
+    /// let result = MicrosoftCdp::try_from(&ManufacturerSpecificData::new(0x004c, &data));
+    /// assert!(result.is_err());
+    /// ```
+    fn try_from(value: &ManufacturerSpecificData) -> Result<Self, String> {
+        let mut data = u16::to_le_bytes(value.company_identifier).to_vec();
+        data.extend_from_slice(&value.manufacturer_specific_data);
+        Self::try_from(&data)
+    }
+}
+
+impl From<MicrosoftCdp> for Vec<u8> {
+    /// Create 26 bytes vendor payload (Company Identifier Code and Manufacturer Specific Data) from [`MicrosoftCdp`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::beacons::microsoft_cdp::MicrosoftCdp;
+    ///
+    /// let mut data: Vec<u8> = vec![0x06, 0x00, 0x01, 0x29, 0x20, 0x00, 0x01, 0x02, 0x03, 0x04];
+    /// data.append(&mut [0x05u8; 16].to_vec());
+    ///
+    /// let into_data: Vec<u8> =
+    ///     MicrosoftCdp::new(0x01, 0x01, 0x09, 0x20, 0x00, [0x01, 0x02, 0x03, 0x04], [0x05; 16])
+    ///         .into();
+    /// assert_eq!(data, into_data);
+    /// ```
+    fn from(value: MicrosoftCdp) -> Self {
+        let mut data: Vec<u8> = Vec::new();
+        data.append(&mut u16::to_le_bytes(MICROSOFT_COMPANY_IDENTIFIER).to_vec());
+        data.push(value.scenario_type);
+        data.push((value.version << 5) | value.device_type);
+        data.push(value.version_and_flags);
+        data.push(value.reserved);
+        data.append(&mut value.salt.to_vec());
+        data.append(&mut value.device_hash.to_vec());
+        data
+    }
+}
+
+impl From<MicrosoftCdp> for ManufacturerSpecificData {
+    /// Create [`ManufacturerSpecificData`] from [`MicrosoftCdp`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     beacons::microsoft_cdp::MicrosoftCdp,
+    ///     data_types::manufacturer_specific_data::ManufacturerSpecificData,
+    /// };
+    ///
+    /// let result: ManufacturerSpecificData =
+    ///     MicrosoftCdp::new(0x01, 0x01, 0x09, 0x20, 0x00, [0x01, 0x02, 0x03, 0x04], [0x05; 16])
+    ///         .into();
+    /// assert_eq!(0x0006, result.company_identifier);
+    /// assert_eq!(24, result.manufacturer_specific_data.len());
+    /// ```
+    fn from(value: MicrosoftCdp) -> Self {
+        let data: Vec<u8> = value.into();
+        ManufacturerSpecificData::new(MICROSOFT_COMPANY_IDENTIFIER, &data[2..].to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        beacons::microsoft_cdp::{
+            MicrosoftCdp, DEVICE_TYPE_WINDOWS_10_DESKTOP, SCENARIO_TYPE_BLUETOOTH,
+        },
+        data_types::manufacturer_specific_data::ManufacturerSpecificData,
+    };
+
+    fn payload() -> Vec<u8> {
+        let mut data: Vec<u8> = vec![0x06, 0x00, 0x01, 0x29, 0x20, 0x00, 0x01, 0x02, 0x03, 0x04];
+        data.append(&mut [0x05u8; 16].to_vec());
+        data
+    }
+
+    fn cdp() -> MicrosoftCdp {
+        MicrosoftCdp::new(
+            SCENARIO_TYPE_BLUETOOTH,
+            0x01,
+            DEVICE_TYPE_WINDOWS_10_DESKTOP,
+            0x20,
+            0x00,
+            [0x01, 0x02, 0x03, 0x04],
+            [0x05; 16],
+        )
+    }
+
+    #[test]
+    fn test_new() {
+        let result = cdp();
+        assert_eq!(SCENARIO_TYPE_BLUETOOTH, result.scenario_type);
+        assert_eq!(0x01, result.version);
+        assert_eq!(DEVICE_TYPE_WINDOWS_10_DESKTOP, result.device_type);
+        assert_eq!(0x20, result.version_and_flags);
+        assert_eq!(0x00, result.reserved);
+        assert_eq!([0x01, 0x02, 0x03, 0x04], result.salt);
+        assert_eq!([0x05; 16], result.device_hash);
+
+        let result = MicrosoftCdp::new(0x01, 0xff, 0xff, 0x00, 0x00, [0; 4], [0; 16]);
+        assert_eq!(0b111, result.version);
+        assert_eq!(0b11111, result.device_type);
+    }
+
+    #[test]
+    fn test_try_from() {
+        assert_eq!(Ok(cdp()), MicrosoftCdp::try_from(&payload()));
+
+        let data: Vec<u8> = Vec::new();
+        assert_eq!(
+            Err(format!("Invalid data size :{}", data.len())),
+            MicrosoftCdp::try_from(&data)
+        );
+
+        let mut data = payload();
+        data[0] = 0x4c;
+        assert!(MicrosoftCdp::try_from(&data).is_err());
+
+        let data = payload();
+        assert_eq!(
+            Ok(cdp()),
+            MicrosoftCdp::try_from(&ManufacturerSpecificData::new(0x0006, &data[2..].to_vec()))
+        );
+    }
+
+    #[test]
+    fn test_into() {
+        let into_data: Vec<u8> = cdp().into();
+        assert_eq!(payload(), into_data);
+
+        let result: ManufacturerSpecificData = cdp().into();
+        assert_eq!(
+            ManufacturerSpecificData::new(0x0006, &payload()[2..].to_vec()),
+            result
+        );
+    }
+}
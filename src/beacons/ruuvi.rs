@@ -0,0 +1,413 @@
+//! Ruuvi Data Format 5 (RAWv2) (Company Identifier: 0x0499) module.
+
+use crate::data_types::manufacturer_specific_data::ManufacturerSpecificData;
+
+/// Ruuvi Innovations Ltd. Company Identifier
+pub const RUUVI_COMPANY_IDENTIFIER: u16 = 0x0499;
+
+/// Data Format 5 (RAWv2)
+pub const DATA_FORMAT_RAWV2: u8 = 0x05;
+
+/// Ruuvi Data Format 5 (RAWv2).
+///
+/// Fields hold raw values, use accessor methods for scaled values.
+#[derive(Debug, PartialEq, Clone)]
+pub struct RuuviRawV2 {
+    /// Temperature (0.005 degrees)
+    pub temperature: i16,
+
+    /// Humidity (0.0025%)
+    pub humidity: u16,
+
+    /// Pressure (1 Pa, offset -50000 Pa)
+    pub pressure: u16,
+
+    /// Acceleration X (mG)
+    pub acceleration_x: i16,
+
+    /// Acceleration Y (mG)
+    pub acceleration_y: i16,
+
+    /// Acceleration Z (mG)
+    pub acceleration_z: i16,
+
+    /// Power info (11 bits battery voltage and 5 bits TX power)
+    pub power_info: u16,
+
+    /// Movement counter
+    pub movement_counter: u8,
+
+    /// Measurement sequence number
+    pub measurement_sequence_number: u16,
+
+    /// MAC address
+    pub mac_address: [u8; 6],
+}
+
+impl RuuviRawV2 {
+    /// Get temperature (Celsius).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::beacons::ruuvi::RuuviRawV2;
+    ///
+    /// let data: Vec<u8> = vec![
+    ///     0x99, 0x04, 0x05, 0x12, 0xfc, 0x53, 0x94, 0xc3, 0x7c, 0x00, 0x04, 0xff, 0xfc, 0x04, 0x0c,
+    ///     0xac, 0x36, 0x42, 0x00, 0xcd, 0xcb, 0xb8, 0x33, 0x4c, 0x88, 0x4f,
+    /// ];
+    /// let result = RuuviRawV2::try_from(&data).unwrap();
+    /// assert_eq!(Some(24.3), result.temperature_celsius());
+    /// ```
+    pub fn temperature_celsius(&self) -> Option<f64> {
+        if self.temperature == i16::MIN {
+            None
+        } else {
+            Some(self.temperature as f64 * 5.0 / 1000.0)
+        }
+    }
+
+    /// Get relative humidity (%).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::beacons::ruuvi::RuuviRawV2;
+    ///
+    /// let data: Vec<u8> = vec![
+    ///     0x99, 0x04, 0x05, 0x12, 0xfc, 0x53, 0x94, 0xc3, 0x7c, 0x00, 0x04, 0xff, 0xfc, 0x04, 0x0c,
+    ///     0xac, 0x36, 0x42, 0x00, 0xcd, 0xcb, 0xb8, 0x33, 0x4c, 0x88, 0x4f,
+    /// ];
+    /// let result = RuuviRawV2::try_from(&data).unwrap();
+    /// assert_eq!(Some(53.49), result.humidity_percent());
+    /// ```
+    pub fn humidity_percent(&self) -> Option<f64> {
+        if self.humidity == u16::MAX {
+            None
+        } else {
+            Some(self.humidity as f64 * 25.0 / 10000.0)
+        }
+    }
+
+    /// Get atmospheric pressure (Pa).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::beacons::ruuvi::RuuviRawV2;
+    ///
+    /// let data: Vec<u8> = vec![
+    ///     0x99, 0x04, 0x05, 0x12, 0xfc, 0x53, 0x94, 0xc3, 0x7c, 0x00, 0x04, 0xff, 0xfc, 0x04, 0x0c,
+    ///     0xac, 0x36, 0x42, 0x00, 0xcd, 0xcb, 0xb8, 0x33, 0x4c, 0x88, 0x4f,
+    /// ];
+    /// let result = RuuviRawV2::try_from(&data).unwrap();
+    /// assert_eq!(Some(100044), result.pressure_pa());
+    /// ```
+    pub fn pressure_pa(&self) -> Option<u32> {
+        if self.pressure == u16::MAX {
+            None
+        } else {
+            Some(self.pressure as u32 + 50000)
+        }
+    }
+
+    /// Get acceleration X, Y, Z (G).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::beacons::ruuvi::RuuviRawV2;
+    ///
+    /// let data: Vec<u8> = vec![
+    ///     0x99, 0x04, 0x05, 0x12, 0xfc, 0x53, 0x94, 0xc3, 0x7c, 0x00, 0x04, 0xff, 0xfc, 0x04, 0x0c,
+    ///     0xac, 0x36, 0x42, 0x00, 0xcd, 0xcb, 0xb8, 0x33, 0x4c, 0x88, 0x4f,
+    /// ];
+    /// let result = RuuviRawV2::try_from(&data).unwrap();
+    /// assert_eq!(Some((0.004, -0.004, 1.036)), result.acceleration_g());
+    /// ```
+    pub fn acceleration_g(&self) -> Option<(f64, f64, f64)> {
+        if self.acceleration_x == i16::MIN
+            || self.acceleration_y == i16::MIN
+            || self.acceleration_z == i16::MIN
+        {
+            None
+        } else {
+            Some((
+                self.acceleration_x as f64 / 1000.0,
+                self.acceleration_y as f64 / 1000.0,
+                self.acceleration_z as f64 / 1000.0,
+            ))
+        }
+    }
+
+    /// Get battery voltage (mV).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::beacons::ruuvi::RuuviRawV2;
+    ///
+    /// let data: Vec<u8> = vec![
+    ///     0x99, 0x04, 0x05, 0x12, 0xfc, 0x53, 0x94, 0xc3, 0x7c, 0x00, 0x04, 0xff, 0xfc, 0x04, 0x0c,
+    ///     0xac, 0x36, 0x42, 0x00, 0xcd, 0xcb, 0xb8, 0x33, 0x4c, 0x88, 0x4f,
+    /// ];
+    /// let result = RuuviRawV2::try_from(&data).unwrap();
+    /// assert_eq!(Some(2977), result.battery_voltage_mv());
+    /// ```
+    pub fn battery_voltage_mv(&self) -> Option<u16> {
+        let battery_voltage = self.power_info >> 5;
+        if battery_voltage == 0b11111111111 {
+            None
+        } else {
+            Some(battery_voltage + 1600)
+        }
+    }
+
+    /// Get TX power (dBm).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::beacons::ruuvi::RuuviRawV2;
+    ///
+    /// let data: Vec<u8> = vec![
+    ///     0x99, 0x04, 0x05, 0x12, 0xfc, 0x53, 0x94, 0xc3, 0x7c, 0x00, 0x04, 0xff, 0xfc, 0x04, 0x0c,
+    ///     0xac, 0x36, 0x42, 0x00, 0xcd, 0xcb, 0xb8, 0x33, 0x4c, 0x88, 0x4f,
+    /// ];
+    /// let result = RuuviRawV2::try_from(&data).unwrap();
+    /// assert_eq!(Some(4), result.tx_power_dbm());
+    /// ```
+    pub fn tx_power_dbm(&self) -> Option<i8> {
+        let tx_power = (self.power_info & 0b11111) as i8;
+        if tx_power == 0b11111 {
+            None
+        } else {
+            Some(tx_power * 2 - 40)
+        }
+    }
+
+    /// Get movement counter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::beacons::ruuvi::RuuviRawV2;
+    ///
+    /// let data: Vec<u8> = vec![
+    ///     0x99, 0x04, 0x05, 0x12, 0xfc, 0x53, 0x94, 0xc3, 0x7c, 0x00, 0x04, 0xff, 0xfc, 0x04, 0x0c,
+    ///     0xac, 0x36, 0x42, 0x00, 0xcd, 0xcb, 0xb8, 0x33, 0x4c, 0x88, 0x4f,
+    /// ];
+    /// let result = RuuviRawV2::try_from(&data).unwrap();
+    /// assert_eq!(Some(66), result.movement());
+    /// ```
+    pub fn movement(&self) -> Option<u8> {
+        if self.movement_counter == u8::MAX {
+            None
+        } else {
+            Some(self.movement_counter)
+        }
+    }
+
+    /// Get measurement sequence number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::beacons::ruuvi::RuuviRawV2;
+    ///
+    /// let data: Vec<u8> = vec![
+    ///     0x99, 0x04, 0x05, 0x12, 0xfc, 0x53, 0x94, 0xc3, 0x7c, 0x00, 0x04, 0xff, 0xfc, 0x04, 0x0c,
+    ///     0xac, 0x36, 0x42, 0x00, 0xcd, 0xcb, 0xb8, 0x33, 0x4c, 0x88, 0x4f,
+    /// ];
+    /// let result = RuuviRawV2::try_from(&data).unwrap();
+    /// assert_eq!(Some(205), result.sequence_number());
+    /// ```
+    pub fn sequence_number(&self) -> Option<u16> {
+        if self.measurement_sequence_number == u16::MAX {
+            None
+        } else {
+            Some(self.measurement_sequence_number)
+        }
+    }
+}
+
+impl TryFrom<&Vec<u8>> for RuuviRawV2 {
+    type Error = String;
+    /// Create [`RuuviRawV2`] from 26 bytes vendor payload (Company Identifier Code and Manufacturer Specific Data).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::beacons::ruuvi::RuuviRawV2;
+    ///
+    /// let data: Vec<u8> = vec![
+    ///     0x99, 0x04, 0x05, 0x12, 0xfc, 0x53, 0x94, 0xc3, 0x7c, 0x00, 0x04, 0xff, 0xfc, 0x04, 0x0c,
+    ///     0xac, 0x36, 0x42, 0x00, 0xcd, 0xcb, 0xb8, 0x33, 0x4c, 0x88, 0x4f,
+    /// ];
+    /// let result = RuuviRawV2::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let ruuvi = result.unwrap();
+    /// assert_eq!(0x12fc, ruuvi.temperature);
+    /// assert_eq!([0xcb, 0xb8, 0x33, 0x4c, 0x88, 0x4f], ruuvi.mac_address);
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = RuuviRawV2::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        let len = value.len();
+        if len != 26 {
+            return Err(format!("Invalid data size :{}", len).to_string());
+        }
+        let company_identifier = u16::from_le_bytes(value[0..2].try_into().unwrap());
+        if company_identifier != RUUVI_COMPANY_IDENTIFIER {
+            return Err(format!("Invalid company identifier :{}", company_identifier).to_string());
+        }
+        if value[2] != DATA_FORMAT_RAWV2 {
+            return Err(format!("Invalid data format :{}", value[2]).to_string());
+        }
+        Ok(Self {
+            temperature: i16::from_be_bytes(value[3..5].try_into().unwrap()),
+            humidity: u16::from_be_bytes(value[5..7].try_into().unwrap()),
+            pressure: u16::from_be_bytes(value[7..9].try_into().unwrap()),
+            acceleration_x: i16::from_be_bytes(value[9..11].try_into().unwrap()),
+            acceleration_y: i16::from_be_bytes(value[11..13].try_into().unwrap()),
+            acceleration_z: i16::from_be_bytes(value[13..15].try_into().unwrap()),
+            power_info: u16::from_be_bytes(value[15..17].try_into().unwrap()),
+            movement_counter: value[17],
+            measurement_sequence_number: u16::from_be_bytes(value[18..20].try_into().unwrap()),
+            mac_address: value[20..26].try_into().unwrap(),
+        })
+    }
+}
+
+impl TryFrom<&ManufacturerSpecificData> for RuuviRawV2 {
+    type Error = String;
+    /// Create [`RuuviRawV2`] from [`ManufacturerSpecificData`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     beacons::ruuvi::RuuviRawV2,
+    ///     data_types::manufacturer_specific_data::ManufacturerSpecificData,
+    /// };
+    ///
+    /// let data: Vec<u8> = vec![
+    ///     0x05, 0x12, 0xfc, 0x53, 0x94, 0xc3, 0x7c, 0x00, 0x04, 0xff, 0xfc, 0x04, 0x0c, 0xac, 0x36,
+    ///     0x42, 0x00, 0xcd, 0xcb, 0xb8, 0x33, 0x4c, 0x88, 0x4f,
+    /// ];
+    /// let result = RuuviRawV2::try_from(&ManufacturerSpecificData::new(0x0499, &data));
+    /// assert!(result.is_ok());
+    ///
+    /// let result = RuuviRawV2::try_from(&ManufacturerSpecificData::new(0x004c, &data));
+    /// assert!(result.is_err());
+    /// ```
+    fn try_from(value: &ManufacturerSpecificData) -> Result<Self, String> {
+        let mut data = u16::to_le_bytes(value.company_identifier).to_vec();
+        data.extend_from_slice(&value.manufacturer_specific_data);
+        Self::try_from(&data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        beacons::ruuvi::RuuviRawV2,
+        data_types::manufacturer_specific_data::ManufacturerSpecificData,
+    };
+
+    fn payload() -> Vec<u8> {
+        vec![
+            0x99, 0x04, 0x05, 0x12, 0xfc, 0x53, 0x94, 0xc3, 0x7c, 0x00, 0x04, 0xff, 0xfc, 0x04,
+            0x0c, 0xac, 0x36, 0x42, 0x00, 0xcd, 0xcb, 0xb8, 0x33, 0x4c, 0x88, 0x4f,
+        ]
+    }
+
+    #[test]
+    fn test_try_from() {
+        let result = RuuviRawV2::try_from(&payload()).unwrap();
+        assert_eq!(0x12fc, result.temperature);
+        assert_eq!(0x5394, result.humidity);
+        assert_eq!(0xc37c, result.pressure);
+        assert_eq!(4, result.acceleration_x);
+        assert_eq!(-4, result.acceleration_y);
+        assert_eq!(1036, result.acceleration_z);
+        assert_eq!(0xac36, result.power_info);
+        assert_eq!(66, result.movement_counter);
+        assert_eq!(205, result.measurement_sequence_number);
+        assert_eq!([0xcb, 0xb8, 0x33, 0x4c, 0x88, 0x4f], result.mac_address);
+
+        let data = payload();
+        assert_eq!(
+            Ok(result),
+            RuuviRawV2::try_from(&ManufacturerSpecificData::new(0x0499, &data[2..].to_vec()))
+        );
+
+        let data: Vec<u8> = Vec::new();
+        assert_eq!(
+            Err(format!("Invalid data size :{}", data.len())),
+            RuuviRawV2::try_from(&data)
+        );
+
+        let mut data = payload();
+        data[2] = 0x03;
+        assert!(RuuviRawV2::try_from(&data).is_err());
+
+        let mut data = payload();
+        data[0] = 0x4c;
+        data[1] = 0x00;
+        assert!(RuuviRawV2::try_from(&data).is_err());
+    }
+
+    #[test]
+    fn test_scaled_values() {
+        let result = RuuviRawV2::try_from(&payload()).unwrap();
+        assert_eq!(Some(24.3), result.temperature_celsius());
+        assert_eq!(Some(53.49), result.humidity_percent());
+        assert_eq!(Some(100044), result.pressure_pa());
+        assert_eq!(Some((0.004, -0.004, 1.036)), result.acceleration_g());
+        assert_eq!(Some(2977), result.battery_voltage_mv());
+        assert_eq!(Some(4), result.tx_power_dbm());
+        assert_eq!(Some(66), result.movement());
+        assert_eq!(Some(205), result.sequence_number());
+    }
+
+    #[test]
+    fn test_invalid_values() {
+        let data: Vec<u8> = vec![
+            0x99, 0x04, 0x05, 0x80, 0x00, 0xff, 0xff, 0xff, 0xff, 0x80, 0x00, 0x80, 0x00, 0x80,
+            0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        ];
+        let result = RuuviRawV2::try_from(&data).unwrap();
+        assert_eq!(None, result.temperature_celsius());
+        assert_eq!(None, result.humidity_percent());
+        assert_eq!(None, result.pressure_pa());
+        assert_eq!(None, result.acceleration_g());
+        assert_eq!(None, result.battery_voltage_mv());
+        assert_eq!(None, result.tx_power_dbm());
+        assert_eq!(None, result.movement());
+        assert_eq!(None, result.sequence_number());
+    }
+
+    #[test]
+    fn test_min_values() {
+        let data: Vec<u8> = vec![
+            0x99, 0x04, 0x05, 0x80, 0x01, 0x00, 0x00, 0x00, 0x00, 0x80, 0x01, 0x80, 0x01, 0x80,
+            0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0xcb, 0xb8, 0x33, 0x4c, 0x88, 0x4f,
+        ];
+        let result = RuuviRawV2::try_from(&data).unwrap();
+        assert_eq!(Some(-163.835), result.temperature_celsius());
+        assert_eq!(Some(0.0), result.humidity_percent());
+        assert_eq!(Some(50000), result.pressure_pa());
+        assert_eq!(Some((-32.767, -32.767, -32.767)), result.acceleration_g());
+        assert_eq!(Some(1600), result.battery_voltage_mv());
+        assert_eq!(Some(-40), result.tx_power_dbm());
+        assert_eq!(Some(0), result.movement());
+        assert_eq!(Some(0), result.sequence_number());
+    }
+}
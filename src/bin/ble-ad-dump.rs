@@ -0,0 +1,386 @@
+//! Dump advertising payloads from hex strings or a btsnoop file.
+//!
+//! ```text
+//! ble-ad-dump [--json | --pretty] [--btsnoop FILE] [HEX ...]
+//! ```
+//!
+//! Without `HEX` and `--btsnoop`, hex strings are read from stdin (one payload per line).
+
+use std::{
+    env, fs,
+    io::{self, BufRead},
+    process,
+};
+
+use ble_data_struct::{
+    bluez::BlueZAdvertisement, data_types::data_type_parser::DataTypeParseResults, pretty::pretty,
+    replay,
+};
+use serde_json::{json, Map, Value};
+
+/// One advertising payload with its source.
+#[derive(Debug, PartialEq, Clone)]
+struct Advertisement {
+    /// Source (command line argument index, line number or btsnoop record number)
+    source: String,
+
+    /// Advertiser address
+    address: Option<String>,
+
+    /// RSSI (dBm)
+    rssi: Option<i8>,
+
+    /// Advertising payload
+    payload: Vec<u8>,
+}
+
+fn main() {
+    let mut json = false;
+    let mut pretty_text = false;
+    let mut btsnoop: Option<String> = None;
+    let mut hex: Vec<String> = Vec::new();
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--json" => json = true,
+            "--pretty" => pretty_text = true,
+            "--btsnoop" => match args.next() {
+                Some(path) => btsnoop = Some(path),
+                None => exit("--btsnoop requires FILE"),
+            },
+            "-h" | "--help" => {
+                println!("usage: ble-ad-dump [--json | --pretty] [--btsnoop FILE] [HEX ...]");
+                return;
+            }
+            _ => hex.push(arg),
+        }
+    }
+
+    let mut advertisements: Vec<Advertisement> = Vec::new();
+    if let Some(path) = btsnoop {
+        match fs::read(&path)
+            .map_err(|error| error.to_string())
+            .and_then(|data| read_btsnoop(&data))
+        {
+            Ok(mut result) => advertisements.append(&mut result),
+            Err(error) => exit(&format!("{} :{}", path, error)),
+        }
+    } else if hex.is_empty() {
+        for (index, line) in io::stdin().lock().lines().enumerate() {
+            let line = line.unwrap_or_else(|error| exit(&error.to_string()));
+            if !line.trim().is_empty() {
+                advertisements.push(from_hex(format!("line {}", index + 1), &line));
+            }
+        }
+    }
+    for (index, value) in hex.iter().enumerate() {
+        advertisements.push(from_hex(format!("arg {}", index + 1), value));
+    }
+
+    for advertisement in &advertisements {
+        if json {
+            println!("{}", to_json(advertisement));
+        } else if pretty_text {
+            print!("{}", to_pretty(advertisement));
+        } else {
+            print!("{}", to_text(advertisement));
+        }
+    }
+}
+
+fn exit(message: &str) -> ! {
+    eprintln!("ble-ad-dump: {}", message);
+    process::exit(1)
+}
+
+fn from_hex(source: String, value: &str) -> Advertisement {
+    match parse_hex(value) {
+        Ok(payload) => Advertisement {
+            source,
+            address: None,
+            rssi: None,
+            payload,
+        },
+        Err(error) => exit(&format!("{} :{}", source, error)),
+    }
+}
+
+/// Parse hex string, ignoring `0x` prefix, whitespace, `:` and `-` separators.
+fn parse_hex(value: &str) -> Result<Vec<u8>, String> {
+    let value = value.trim();
+    let value = value
+        .strip_prefix("0x")
+        .or_else(|| value.strip_prefix("0X"))
+        .unwrap_or(value);
+    let digits: Vec<u8> = value
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != ':' && *c != '-')
+        .map(|c| match c.to_digit(16) {
+            Some(digit) => Ok(digit as u8),
+            None => Err(format!("Invalid hex digit :{}", c)),
+        })
+        .collect::<Result<Vec<u8>, String>>()?;
+    if !digits.len().is_multiple_of(2) {
+        return Err(format!("Invalid hex length :{}", digits.len()));
+    }
+    Ok(digits
+        .chunks(2)
+        .map(|pair| (pair[0] << 4) | pair[1])
+        .collect())
+}
+
+fn to_hex(value: &[u8]) -> String {
+    value.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Read advertising reports from btsnoop file content.
+fn read_btsnoop(data: &[u8]) -> Result<Vec<Advertisement>, String> {
+    Ok(replay::read_btsnoop(data)?
+        .into_iter()
+        .map(|(record, scan_record)| Advertisement {
+            source: format!(
+                "btsnoop record {}{}",
+                record,
+                if scan_record.truncated {
+                    " (truncated)"
+                } else {
+                    ""
+                }
+            ),
+            address: scan_record.address,
+            rssi: scan_record.rssi,
+            payload: scan_record.payload,
+        })
+        .collect())
+}
+
+fn to_text(advertisement: &Advertisement) -> String {
+    let mut text = advertisement.source.clone();
+    if let Some(address) = &advertisement.address {
+        text += &format!(" {}", address);
+    }
+    if let Some(rssi) = advertisement.rssi {
+        text += &format!(" rssi {}", rssi);
+    }
+    text += &format!(" ({} bytes)\n", advertisement.payload.len());
+
+    let results = DataTypeParseResults::from_payload_with_options(
+        &advertisement.payload,
+        &Default::default(),
+    );
+    for (result, offset) in results.results.iter().zip(results.offsets().iter()) {
+        let end =
+            (offset + 1 + advertisement.payload[*offset] as usize).min(advertisement.payload.len());
+        text += &format!(
+            "  [{:3}] {}\n        {:?}\n",
+            offset,
+            to_hex(&advertisement.payload[*offset..end]),
+            result
+        );
+    }
+    text
+}
+
+fn to_pretty(advertisement: &Advertisement) -> String {
+    let mut text = advertisement.source.clone();
+    if let Some(address) = &advertisement.address {
+        text += &format!(" {}", address);
+    }
+    if let Some(rssi) = advertisement.rssi {
+        text += &format!(" rssi {}", rssi);
+    }
+    text + "\n" + &pretty(&advertisement.payload)
+}
+
+fn to_json(advertisement: &Advertisement) -> Value {
+    let results = DataTypeParseResults::from_payload_with_options(
+        &advertisement.payload,
+        &Default::default(),
+    );
+    let structures: Vec<Value> = results
+        .results
+        .iter()
+        .zip(results.offsets().iter())
+        .map(|(result, offset)| {
+            let payload = &advertisement.payload;
+            let end = (offset + 1 + payload[*offset] as usize).min(payload.len());
+            json!({
+                "offset": offset,
+                "data_type": payload.get(offset + 1),
+                "data": to_hex(payload.get(offset + 2..end).unwrap_or_default()),
+                "error": result.error(),
+            })
+        })
+        .collect();
+
+    let bluez = BlueZAdvertisement::from(&results);
+    let manufacturer_data: Map<String, Value> = bluez
+        .manufacturer_data
+        .iter()
+        .map(|(company_identifier, data)| {
+            (
+                format!("0x{:04x}", company_identifier),
+                Value::from(to_hex(data)),
+            )
+        })
+        .collect();
+    let service_data: Map<String, Value> = bluez
+        .service_data
+        .iter()
+        .map(|(uuid, data)| (uuid.to_string(), Value::from(to_hex(data))))
+        .collect();
+    json!({
+        "source": advertisement.source,
+        "address": advertisement.address,
+        "rssi": advertisement.rssi,
+        "payload": to_hex(&advertisement.payload),
+        "local_name": bluez.local_name,
+        "tx_power": bluez.tx_power,
+        "service_uuids": bluez.service_uuids.iter().map(|uuid| uuid.to_string()).collect::<Vec<String>>(),
+        "manufacturer_data": manufacturer_data,
+        "service_data": service_data,
+        "structures": structures,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::{parse_hex, read_btsnoop, to_json, to_pretty, to_text, Advertisement};
+
+    fn btsnoop(datalink: u32, packets: &[(u32, Vec<u8>)]) -> Vec<u8> {
+        let mut data: Vec<u8> = b"btsnoop\0".to_vec();
+        data.extend_from_slice(&1u32.to_be_bytes());
+        data.extend_from_slice(&datalink.to_be_bytes());
+        for (flags, packet) in packets {
+            data.extend_from_slice(&(packet.len() as u32).to_be_bytes());
+            data.extend_from_slice(&(packet.len() as u32).to_be_bytes());
+            data.extend_from_slice(&flags.to_be_bytes());
+            data.extend_from_slice(&0u32.to_be_bytes());
+            data.extend_from_slice(&0u64.to_be_bytes());
+            data.extend_from_slice(packet);
+        }
+        data
+    }
+
+    fn le_advertising_report(payload: &[u8], rssi: i8) -> Vec<u8> {
+        let mut event: Vec<u8> = vec![0x3e, 0x00, 0x02, 0x01, 0x00, 0x00];
+        event.extend_from_slice(&[0x06, 0x05, 0x04, 0x03, 0x02, 0x01]);
+        event.push(payload.len() as u8);
+        event.extend_from_slice(payload);
+        event.push(rssi as u8);
+        event[1] = event.len() as u8 - 2;
+        event
+    }
+
+    #[test]
+    fn test_parse_hex() {
+        assert_eq!(Ok(vec![0x02, 0x01, 0x06]), parse_hex("020106"));
+        assert_eq!(Ok(vec![0x02, 0x01, 0x06]), parse_hex(" 0x02 01:06 "));
+        assert_eq!(Ok(vec![0xab, 0xcd]), parse_hex("AB-cd"));
+        assert_eq!(Ok(Vec::new()), parse_hex(""));
+        assert_eq!(Err("Invalid hex length :3".to_string()), parse_hex("020"));
+        assert_eq!(Err("Invalid hex digit :g".to_string()), parse_hex("0g"));
+    }
+
+    #[test]
+    fn test_read_btsnoop() {
+        let event = le_advertising_report(&[0x02, 0x01, 0x06], -60);
+        let mut h4 = vec![0x04];
+        h4.extend_from_slice(&event);
+        let data = btsnoop(1002, &[(0x03, vec![0x01, 0x03, 0x0c, 0x00]), (0x03, h4)]);
+        let result = read_btsnoop(&data).unwrap();
+        assert_eq!(
+            vec![Advertisement {
+                source: "btsnoop record 2".to_string(),
+                address: Some("01:02:03:04:05:06".to_string()),
+                rssi: Some(-60),
+                payload: vec![0x02, 0x01, 0x06],
+            }],
+            result
+        );
+
+        let data = btsnoop(1001, &[(0x00, event.clone()), (0x03, event)]);
+        let result = read_btsnoop(&data).unwrap();
+        assert_eq!(1, result.len());
+        assert_eq!("btsnoop record 2", result[0].source);
+
+        let data = btsnoop(1000, &[]);
+        assert_eq!(
+            Err("Unsupported datalink type :1000".to_string()),
+            read_btsnoop(&data)
+        );
+        assert!(read_btsnoop(b"snoop").is_err());
+
+        let data = btsnoop(1002, &[(0x03, vec![0x04, 0x3e])]);
+        assert_eq!(
+            Err("Truncated record :1".to_string()),
+            read_btsnoop(&data[..data.len() - 1])
+        );
+    }
+
+    #[test]
+    fn test_to_text() {
+        let advertisement = Advertisement {
+            source: "arg 1".to_string(),
+            address: None,
+            rssi: None,
+            payload: vec![0x02, 0x0a, 0xfc],
+        };
+        assert_eq!(
+            "arg 1 (3 bytes)\n  [  0] 020afc\n        TxPowerLevelResult(Ok(TxPowerLevel { length: 2, tx_power_level: -4 }))\n",
+            to_text(&advertisement)
+        );
+    }
+
+    #[test]
+    fn test_to_pretty() {
+        let advertisement = Advertisement {
+            source: "btsnoop record 1".to_string(),
+            address: Some("01:02:03:04:05:06".to_string()),
+            rssi: Some(-60),
+            payload: vec![0x02, 0x0a, 0xfc],
+        };
+        assert_eq!(
+            concat!(
+                "btsnoop record 1 01:02:03:04:05:06 rssi -60\n",
+                "Advertising Data (3 bytes)\n",
+                "    [0] Tx Power Level\n",
+                "        Length: 2\n",
+                "        Type: Tx Power Level (0x0a)\n",
+                "        Raw: 020afc\n",
+                "        Power Level: -4 dBm\n",
+            ),
+            to_pretty(&advertisement)
+        );
+    }
+
+    #[test]
+    fn test_to_json() {
+        let advertisement = Advertisement {
+            source: "line 1".to_string(),
+            address: Some("01:02:03:04:05:06".to_string()),
+            rssi: Some(-60),
+            payload: vec![0x04, 0xff, 0x4c, 0x00, 0x01, 0x02, 0x0a],
+        };
+        assert_eq!(
+            json!({
+                "source": "line 1",
+                "address": "01:02:03:04:05:06",
+                "rssi": -60,
+                "payload": "04ff4c0001020a",
+                "local_name": null,
+                "tx_power": null,
+                "service_uuids": [],
+                "manufacturer_data": {"0x004c": "01"},
+                "service_data": {},
+                "structures": [
+                    {"offset": 0, "data_type": 0xff, "data": "4c0001", "error": null},
+                    {"offset": 5, "data_type": 0x0a, "data": "", "error": "Invalid data size :2"},
+                ],
+            }),
+            to_json(&advertisement)
+        );
+    }
+}
@@ -0,0 +1,245 @@
+//! BlueZ D-Bus style advertisement dictionary module.
+
+use std::collections::BTreeMap;
+
+use uuid::Uuid;
+
+use crate::{
+    data_types::{
+        complete_list_of_128bit_service_uuids::CompleteListOf128BitServiceUuids,
+        complete_list_of_16bit_service_uuids::CompleteListOf16BitServiceUuids,
+        complete_list_of_32bit_service_uuids::CompleteListOf32BitServiceUuids,
+        complete_local_name::CompleteLocalName,
+        data_type_parser::{DataTypeParseResult, DataTypeParseResults},
+        manufacturer_specific_data::ManufacturerSpecificData,
+        service_data_128bit_uuid::ServiceData128BitUUID,
+        service_data_16bit_uuid::ServiceData16BitUUID,
+        service_data_32bit_uuid::ServiceData32BitUUID,
+        tx_power_level::TxPowerLevel,
+    },
+    short_form, ShortUuid,
+};
+
+/// BlueZ D-Bus style advertisement dictionary.
+///
+/// Same shape as BlueZ `org.bluez.Device1` / `org.bluez.LEAdvertisement1` properties.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct BlueZAdvertisement {
+    /// ManufacturerData (Company Identifier to data)
+    pub manufacturer_data: BTreeMap<u16, Vec<u8>>,
+
+    /// ServiceData (UUID to data)
+    pub service_data: BTreeMap<Uuid, Vec<u8>>,
+
+    /// UUIDs
+    pub service_uuids: Vec<Uuid>,
+
+    /// LocalName
+    pub local_name: Option<String>,
+
+    /// TxPower
+    pub tx_power: Option<i8>,
+}
+
+impl From<&DataTypeParseResults> for BlueZAdvertisement {
+    /// Create [`BlueZAdvertisement`] from [`DataTypeParseResults`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     bluez::BlueZAdvertisement,
+    ///     data_types::{
+    ///         complete_local_name::CompleteLocalName, data_type_parser::DataTypeParseResults,
+    ///         manufacturer_specific_data::ManufacturerSpecificData,
+    ///         service_data_16bit_uuid::ServiceData16BitUUID,
+    ///     },
+    ///     uuid_from_u16,
+    /// };
+    ///
+    /// let mut data: Vec<u8> = CompleteLocalName::new(&"name".to_string()).into();
+    /// data.append(&mut ServiceData16BitUUID::new(&uuid_from_u16(0x180f), &vec![0x64]).into());
+    /// data.append(&mut ManufacturerSpecificData::new(0x004c, &vec![0x01, 0x02]).into());
+    ///
+    /// let result = BlueZAdvertisement::from(&DataTypeParseResults::from(&data));
+    /// assert_eq!(Some("name".to_string()), result.local_name);
+    /// assert_eq!(Some(&vec![0x64]), result.service_data.get(&uuid_from_u16(0x180f)));
+    /// assert_eq!(Some(&vec![0x01, 0x02]), result.manufacturer_data.get(&0x004c));
+    /// ```
+    fn from(value: &DataTypeParseResults) -> Self {
+        let mut manufacturer_data: BTreeMap<u16, Vec<u8>> = BTreeMap::new();
+        let mut service_data: BTreeMap<Uuid, Vec<u8>> = BTreeMap::new();
+        let mut tx_power = None;
+        for result in &value.results {
+            match result {
+                DataTypeParseResult::ManufacturerSpecificDataResult(Ok(data_type)) => {
+                    manufacturer_data
+                        .entry(data_type.company_identifier)
+                        .or_insert_with(|| data_type.manufacturer_specific_data.clone());
+                }
+                DataTypeParseResult::ServiceData16BitUUIDResult(Ok(data_type)) => {
+                    service_data
+                        .entry(data_type.uuid)
+                        .or_insert_with(|| data_type.additional_service_data.to_vec());
+                }
+                DataTypeParseResult::ServiceData32BitUUIDResult(Ok(data_type)) => {
+                    service_data
+                        .entry(data_type.uuid)
+                        .or_insert_with(|| data_type.additional_service_data.to_vec());
+                }
+                DataTypeParseResult::ServiceData128BitUUIDResult(Ok(data_type)) => {
+                    service_data
+                        .entry(data_type.uuid)
+                        .or_insert_with(|| data_type.additional_service_data.to_vec());
+                }
+                DataTypeParseResult::TxPowerLevelResult(Ok(data_type)) => {
+                    tx_power.get_or_insert(data_type.tx_power_level);
+                }
+                _ => {}
+            }
+        }
+        Self {
+            manufacturer_data,
+            service_data,
+            service_uuids: value.service_uuids().uuids,
+            local_name: value.device_name().map(|name| name.to_string()),
+            tx_power,
+        }
+    }
+}
+
+impl From<&BlueZAdvertisement> for Vec<u8> {
+    /// Create advertising payload from [`BlueZAdvertisement`].
+    ///
+    /// UUIDs and ServiceData use the shortest UUID form, and Flags are not added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     bluez::BlueZAdvertisement,
+    ///     data_types::{data_type::DataType, tx_power_level::TxPowerLevel},
+    ///     uuid_from_u16,
+    /// };
+    ///
+    /// let mut advertisement = BlueZAdvertisement::default();
+    /// advertisement.service_uuids.push(uuid_from_u16(0x180f));
+    /// advertisement.tx_power = Some(-4);
+    ///
+    /// let data: Vec<u8> = (&advertisement).into();
+    /// assert_eq!(vec![0x03, 0x03, 0x0f, 0x18, 0x02, TxPowerLevel::data_type(), 0xfc], data);
+    /// ```
+    fn from(value: &BlueZAdvertisement) -> Self {
+        let mut uuids16: Vec<Uuid> = Vec::new();
+        let mut uuids32: Vec<Uuid> = Vec::new();
+        let mut uuids128: Vec<Uuid> = Vec::new();
+        for uuid in &value.service_uuids {
+            match short_form(uuid) {
+                Some(ShortUuid::Uuid16(_)) => uuids16.push(*uuid),
+                Some(ShortUuid::Uuid32(_)) => uuids32.push(*uuid),
+                None => uuids128.push(*uuid),
+            }
+        }
+
+        let mut data: Vec<u8> = Vec::new();
+        if !uuids16.is_empty() {
+            data.append(&mut CompleteListOf16BitServiceUuids::new(&uuids16).into());
+        }
+        if !uuids32.is_empty() {
+            data.append(&mut CompleteListOf32BitServiceUuids::new(&uuids32).into());
+        }
+        if !uuids128.is_empty() {
+            data.append(&mut CompleteListOf128BitServiceUuids::new(&uuids128).into());
+        }
+        if let Some(local_name) = &value.local_name {
+            data.append(&mut CompleteLocalName::new(local_name).into());
+        }
+        if let Some(tx_power) = value.tx_power {
+            data.append(&mut TxPowerLevel::new(tx_power).into());
+        }
+        for (uuid, service_data) in &value.service_data {
+            match short_form(uuid) {
+                Some(ShortUuid::Uuid16(_)) => {
+                    data.append(&mut ServiceData16BitUUID::new(uuid, service_data).into())
+                }
+                Some(ShortUuid::Uuid32(_)) => {
+                    data.append(&mut ServiceData32BitUUID::new(uuid, service_data).into())
+                }
+                None => data.append(&mut ServiceData128BitUUID::new(uuid, service_data).into()),
+            }
+        }
+        for (company_identifier, manufacturer_data) in &value.manufacturer_data {
+            data.append(
+                &mut ManufacturerSpecificData::new(*company_identifier, manufacturer_data).into(),
+            );
+        }
+        data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use uuid::uuid;
+
+    use crate::{
+        bluez::BlueZAdvertisement,
+        data_types::{
+            data_type_parser::DataTypeParseResults,
+            manufacturer_specific_data::ManufacturerSpecificData,
+        },
+        uuid_from_u16, uuid_from_u32,
+    };
+
+    #[test]
+    fn test_from_results() {
+        let mut data: Vec<u8> = ManufacturerSpecificData::new(0x004c, &vec![0x01]).into();
+        data.append(&mut ManufacturerSpecificData::new(0x004c, &vec![0x02]).into());
+        data.append(&mut vec![0x02, 0x0a, 0x7f]);
+        let result = BlueZAdvertisement::from(&DataTypeParseResults::from(&data));
+        assert_eq!(
+            BTreeMap::from([(0x004c, vec![0x01])]),
+            result.manufacturer_data
+        );
+        assert!(result.service_data.is_empty());
+        assert!(result.service_uuids.is_empty());
+        assert_eq!(None, result.local_name);
+        assert_eq!(Some(127), result.tx_power);
+
+        let data: Vec<u8> = Vec::new();
+        let result = BlueZAdvertisement::from(&DataTypeParseResults::from(&data));
+        assert_eq!(BlueZAdvertisement::default(), result);
+    }
+
+    #[test]
+    fn test_into() {
+        let advertisement = BlueZAdvertisement {
+            manufacturer_data: BTreeMap::from([(0x004c, vec![0x01, 0x02]), (0x0006, vec![])]),
+            service_data: BTreeMap::from([
+                (uuid_from_u16(0x180f), vec![0x64]),
+                (uuid_from_u32(0x12345678), vec![0x01]),
+                (
+                    uuid!("12345678-90ab-cdef-1234-567890abcdef"),
+                    vec![0x02, 0x03],
+                ),
+            ]),
+            service_uuids: vec![
+                uuid_from_u16(0x180f),
+                uuid_from_u16(0x180a),
+                uuid_from_u32(0x12345678),
+                uuid!("12345678-90ab-cdef-1234-567890abcdef"),
+            ],
+            local_name: Some("name".to_string()),
+            tx_power: Some(-20),
+        };
+        let data: Vec<u8> = (&advertisement).into();
+        let results = DataTypeParseResults::from(&data);
+        assert_eq!(0, results.errors().count());
+        assert!(results.service_uuids().is_complete);
+        assert_eq!(advertisement, BlueZAdvertisement::from(&results));
+
+        let data: Vec<u8> = (&BlueZAdvertisement::default()).into();
+        assert!(data.is_empty());
+    }
+}
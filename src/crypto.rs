@@ -0,0 +1,171 @@
+//! Bluetooth cryptographic toolbox module.
+
+use aes::{
+    cipher::{BlockEncrypt, KeyInit},
+    Aes128,
+};
+use cmac::{Cmac, Mac};
+
+/// Security function e (AES-128, most significant octet first).
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::crypto::e;
+///
+/// let key = [
+///     0x2b, 0x7e, 0x15, 0x16, 0x28, 0xae, 0xd2, 0xa6, 0xab, 0xf7, 0x15, 0x88, 0x09, 0xcf, 0x4f,
+///     0x3c,
+/// ];
+/// let plaintext = [
+///     0x6b, 0xc1, 0xbe, 0xe2, 0x2e, 0x40, 0x9f, 0x96, 0xe9, 0x3d, 0x7e, 0x11, 0x73, 0x93, 0x17,
+///     0x2a,
+/// ];
+/// assert_eq!(
+///     [
+///         0x3a, 0xd7, 0x7b, 0xb4, 0x0d, 0x7a, 0x36, 0x60, 0xa8, 0x9e, 0xca, 0xf3, 0x24, 0x66, 0xef,
+///         0x97
+///     ],
+///     e(&key, &plaintext)
+/// );
+/// ```
+pub fn e(key: &[u8; 16], plaintext: &[u8; 16]) -> [u8; 16] {
+    let mut block = (*plaintext).into();
+    Aes128::new(key.into()).encrypt_block(&mut block);
+    block.into()
+}
+
+/// AES-CMAC.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::crypto::aes_cmac;
+///
+/// let key = [
+///     0x2b, 0x7e, 0x15, 0x16, 0x28, 0xae, 0xd2, 0xa6, 0xab, 0xf7, 0x15, 0x88, 0x09, 0xcf, 0x4f,
+///     0x3c,
+/// ];
+/// assert_eq!(
+///     [
+///         0xbb, 0x1d, 0x69, 0x29, 0xe9, 0x59, 0x37, 0x28, 0x7f, 0xa3, 0x7d, 0x12, 0x9b, 0x75, 0x67,
+///         0x46
+///     ],
+///     aes_cmac(&key, &[])
+/// );
+/// ```
+pub fn aes_cmac(key: &[u8; 16], m: &[u8]) -> [u8; 16] {
+    let mut mac = <Cmac<Aes128> as Mac>::new(key.into());
+    mac.update(m);
+    mac.finalize().into_bytes().into()
+}
+
+/// Mesh salt generation function s1.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::crypto::s1;
+///
+/// assert_eq!(
+///     [
+///         0xb7, 0x3c, 0xef, 0xbd, 0x64, 0x1e, 0xf2, 0xea, 0x59, 0x8c, 0x2b, 0x6e, 0xfb, 0x62, 0xf7,
+///         0x9c
+///     ],
+///     s1(b"test")
+/// );
+/// ```
+pub fn s1(m: &[u8]) -> [u8; 16] {
+    aes_cmac(&[0u8; 16], m)
+}
+
+/// Coordinated Set hash function sih.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::crypto::sih;
+///
+/// let sirk = [
+///     0x45, 0x7d, 0x7d, 0x09, 0x21, 0xa1, 0xfd, 0x22, 0xce, 0xcd, 0x8c, 0x86, 0xdd, 0x72, 0xcc,
+///     0xcd,
+/// ];
+/// assert_eq!(0x1948da, sih(&sirk, 0x69f563));
+/// ```
+pub fn sih(k: &[u8; 16], r: u32) -> u32 {
+    let mut r_dash = [0u8; 16];
+    r_dash[13..].copy_from_slice(&r.to_be_bytes()[1..]);
+    let result = e(k, &r_dash);
+    u32::from_be_bytes([0, result[13], result[14], result[15]])
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::crypto::{aes_cmac, e, s1, sih};
+
+    const KEY: [u8; 16] = [
+        0x2b, 0x7e, 0x15, 0x16, 0x28, 0xae, 0xd2, 0xa6, 0xab, 0xf7, 0x15, 0x88, 0x09, 0xcf, 0x4f,
+        0x3c,
+    ];
+
+    #[test]
+    fn test_aes_cmac() {
+        assert_eq!(
+            [
+                0xbb, 0x1d, 0x69, 0x29, 0xe9, 0x59, 0x37, 0x28, 0x7f, 0xa3, 0x7d, 0x12, 0x9b, 0x75,
+                0x67, 0x46
+            ],
+            aes_cmac(&KEY, &[])
+        );
+        assert_eq!(
+            [
+                0x07, 0x0a, 0x16, 0xb4, 0x6b, 0x4d, 0x41, 0x44, 0xf7, 0x9b, 0xdd, 0x9d, 0xd0, 0x4a,
+                0x28, 0x7c
+            ],
+            aes_cmac(
+                &KEY,
+                &[
+                    0x6b, 0xc1, 0xbe, 0xe2, 0x2e, 0x40, 0x9f, 0x96, 0xe9, 0x3d, 0x7e, 0x11, 0x73,
+                    0x93, 0x17, 0x2a
+                ]
+            )
+        );
+    }
+
+    #[test]
+    fn test_s1() {
+        assert_eq!(
+            [
+                0xb7, 0x3c, 0xef, 0xbd, 0x64, 0x1e, 0xf2, 0xea, 0x59, 0x8c, 0x2b, 0x6e, 0xfb, 0x62,
+                0xf7, 0x9c
+            ],
+            s1(b"test")
+        );
+    }
+
+    #[test]
+    fn test_e() {
+        assert_eq!(
+            [
+                0x3a, 0xd7, 0x7b, 0xb4, 0x0d, 0x7a, 0x36, 0x60, 0xa8, 0x9e, 0xca, 0xf3, 0x24, 0x66,
+                0xef, 0x97
+            ],
+            e(
+                &KEY,
+                &[
+                    0x6b, 0xc1, 0xbe, 0xe2, 0x2e, 0x40, 0x9f, 0x96, 0xe9, 0x3d, 0x7e, 0x11, 0x73,
+                    0x93, 0x17, 0x2a
+                ]
+            )
+        );
+    }
+
+    #[test]
+    fn test_sih() {
+        let sirk = [
+            0x45, 0x7d, 0x7d, 0x09, 0x21, 0xa1, 0xfd, 0x22, 0xce, 0xcd, 0x8c, 0x86, 0xdd, 0x72,
+            0xcc, 0xcd,
+        ];
+        assert_eq!(0x1948da, sih(&sirk, 0x69f563));
+    }
+}
@@ -0,0 +1,775 @@
+//! AD type module.
+
+use crate::data_types::{codes::*, data_type::DataType};
+
+/// EIR/AD/SRD/ACAD/OOB data type.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum AdType {
+    /// Flags (`0x01`).
+    Flags,
+
+    /// Incomplete List of 16-bit Service Class UUIDs (`0x02`).
+    IncompleteListOf16BitServiceUuids,
+
+    /// Complete List of 16-bit Service Class UUIDs (`0x03`).
+    CompleteListOf16BitServiceUuids,
+
+    /// Incomplete List of 32-bit Service Class UUIDs (`0x04`).
+    IncompleteListOf32BitServiceUuids,
+
+    /// Complete List of 32-bit Service Class UUIDs (`0x05`).
+    CompleteListOf32BitServiceUuids,
+
+    /// Incomplete List of 128-bit Service Class UUIDs (`0x06`).
+    IncompleteListOf128BitServiceUuids,
+
+    /// Complete List of 128-bit Service Class UUIDs (`0x07`).
+    CompleteListOf128BitServiceUuids,
+
+    /// Shortened Local Name (`0x08`).
+    ShortenedLocalName,
+
+    /// Complete Local Name (`0x09`).
+    CompleteLocalName,
+
+    /// Tx Power Level (`0x0a`).
+    TxPowerLevel,
+
+    /// Class of Device (`0x0d`).
+    ClassOfDevice,
+
+    /// Secure Simple Pairing Hash C-192 (`0x0e`).
+    SecureSimplePairingHashC192,
+
+    /// Secure Simple Pairing Randomizer R-192 (`0x0f`).
+    SecureSimplePairingRandomizerR192,
+
+    /// Security Manager TK Value (`0x10`).
+    SecurityManagerTkValue,
+
+    /// Security Manager Out of Band (`0x11`).
+    SecurityManagerOutOfBand,
+
+    /// Peripheral Connection Interval Range (`0x12`).
+    PeripheralConnectionIntervalRange,
+
+    /// List of 16-bit Service Solicitation UUIDs (`0x14`).
+    ListOf16BitServiceSolicitationUUIDs,
+
+    /// List of 128-bit Service Solicitation UUIDs (`0x15`).
+    ListOf128BitServiceSolicitationUUIDs,
+
+    /// Service Data - 16-bit UUID (`0x16`).
+    ServiceData16BitUUID,
+
+    /// Public Target Address (`0x17`).
+    PublicTargetAddress,
+
+    /// Random Target Address (`0x18`).
+    RandomTargetAddress,
+
+    /// Appearance (`0x19`).
+    Appearance,
+
+    /// Advertising Interval (`0x1a`).
+    AdvertisingInterval,
+
+    /// LE Bluetooth Device Address (`0x1b`).
+    LeBluetoothDeviceAddress,
+
+    /// LE Role (`0x1c`).
+    LeRole,
+
+    /// Secure Simple Pairing Hash C-256 (`0x1d`).
+    SecureSimplePairingHashC256,
+
+    /// Secure Simple Pairing Randomizer R-256 (`0x1e`).
+    SecureSimplePairingRandomizerR256,
+
+    /// List of 32-bit Service Solicitation UUIDs (`0x1f`).
+    ListOf32BitServiceSolicitationUUIDs,
+
+    /// Service Data - 32-bit UUID (`0x20`).
+    ServiceData32BitUUID,
+
+    /// Service Data - 128-bit UUID (`0x21`).
+    ServiceData128BitUUID,
+
+    /// LE Secure Connections Confirmation Value (`0x22`).
+    LeSecureConnectionsConfirmationValue,
+
+    /// LE Secure Connections Random Value (`0x23`).
+    LeSecureConnectionsRandomValue,
+
+    /// Uniform Resource Identifier (`0x24`).
+    UniformResourceIdentifier,
+
+    /// Indoor Positioning (`0x25`).
+    IndoorPositioning,
+
+    /// Transport Discovery Data (`0x26`).
+    TransportDiscoveryData,
+
+    /// LE Supported Features (`0x27`).
+    LeSupportedFeatures,
+
+    /// Channel Map Update Indication (`0x28`).
+    ChannelMapUpdateIndication,
+
+    /// Mesh Beacon (`0x2b`).
+    MeshBeacon,
+
+    /// BIGInfo (`0x2c`).
+    BigInfo,
+
+    /// Broadcast_Code (`0x2d`).
+    BroadcastCode,
+
+    /// Resolvable Set Identifier (`0x2e`).
+    ResolvableSetIdentifier,
+
+    /// Advertising Interval - long (`0x2f`).
+    AdvertisingIntervalLong,
+
+    /// Encrypted Data (`0x31`).
+    EncryptedData,
+
+    /// Periodic Advertising Response Timing Information (`0x32`).
+    PeriodicAdvertisingResponseTimingInformation,
+
+    /// Electronic Shelf Label (`0x34`).
+    ElectronicShelfLabel,
+
+    /// 3D Information Data (`0x3d`).
+    ThreeDInformationData,
+
+    /// Manufacturer Specific Data (`0xff`).
+    ManufacturerSpecificData,
+
+    /// Unsupported data type.
+    Unknown(u8),
+}
+
+impl AdType {
+    /// Create [`AdType`] from data type value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::ad_type::AdType;
+    ///
+    /// assert_eq!(AdType::Flags, AdType::of(0x01));
+    /// assert_eq!(AdType::Appearance, AdType::of(0x19));
+    /// assert_eq!(AdType::Unknown(0x00), AdType::of(0x00));
+    /// ```
+    pub const fn of(data_type: u8) -> Self {
+        match data_type {
+            FLAGS => AdType::Flags,
+            INCOMPLETE_LIST_OF_16BIT_SERVICE_UUIDS => AdType::IncompleteListOf16BitServiceUuids,
+            COMPLETE_LIST_OF_16BIT_SERVICE_UUIDS => AdType::CompleteListOf16BitServiceUuids,
+            INCOMPLETE_LIST_OF_32BIT_SERVICE_UUIDS => AdType::IncompleteListOf32BitServiceUuids,
+            COMPLETE_LIST_OF_32BIT_SERVICE_UUIDS => AdType::CompleteListOf32BitServiceUuids,
+            INCOMPLETE_LIST_OF_128BIT_SERVICE_UUIDS => AdType::IncompleteListOf128BitServiceUuids,
+            COMPLETE_LIST_OF_128BIT_SERVICE_UUIDS => AdType::CompleteListOf128BitServiceUuids,
+            SHORTENED_LOCAL_NAME => AdType::ShortenedLocalName,
+            COMPLETE_LOCAL_NAME => AdType::CompleteLocalName,
+            TX_POWER_LEVEL => AdType::TxPowerLevel,
+            CLASS_OF_DEVICE => AdType::ClassOfDevice,
+            SIMPLE_PAIRING_HASH_C192 => AdType::SecureSimplePairingHashC192,
+            SIMPLE_PAIRING_RANDOMIZER_R192 => AdType::SecureSimplePairingRandomizerR192,
+            SECURITY_MANAGER_TK_VALUE => AdType::SecurityManagerTkValue,
+            SECURITY_MANAGER_OUT_OF_BAND_FLAGS => AdType::SecurityManagerOutOfBand,
+            PERIPHERAL_CONNECTION_INTERVAL_RANGE => AdType::PeripheralConnectionIntervalRange,
+            LIST_OF_16BIT_SERVICE_SOLICITATION_UUIDS => AdType::ListOf16BitServiceSolicitationUUIDs,
+            LIST_OF_128BIT_SERVICE_SOLICITATION_UUIDS => {
+                AdType::ListOf128BitServiceSolicitationUUIDs
+            }
+            SERVICE_DATA_16BIT_UUID => AdType::ServiceData16BitUUID,
+            PUBLIC_TARGET_ADDRESS => AdType::PublicTargetAddress,
+            RANDOM_TARGET_ADDRESS => AdType::RandomTargetAddress,
+            APPEARANCE => AdType::Appearance,
+            ADVERTISING_INTERVAL => AdType::AdvertisingInterval,
+            LE_BLUETOOTH_DEVICE_ADDRESS => AdType::LeBluetoothDeviceAddress,
+            LE_ROLE => AdType::LeRole,
+            SIMPLE_PAIRING_HASH_C256 => AdType::SecureSimplePairingHashC256,
+            SIMPLE_PAIRING_RANDOMIZER_R256 => AdType::SecureSimplePairingRandomizerR256,
+            LIST_OF_32BIT_SERVICE_SOLICITATION_UUIDS => AdType::ListOf32BitServiceSolicitationUUIDs,
+            SERVICE_DATA_32BIT_UUID => AdType::ServiceData32BitUUID,
+            SERVICE_DATA_128BIT_UUID => AdType::ServiceData128BitUUID,
+            LE_SECURE_CONNECTIONS_CONFIRMATION_VALUE => {
+                AdType::LeSecureConnectionsConfirmationValue
+            }
+            LE_SECURE_CONNECTIONS_RANDOM_VALUE => AdType::LeSecureConnectionsRandomValue,
+            URI => AdType::UniformResourceIdentifier,
+            INDOOR_POSITIONING => AdType::IndoorPositioning,
+            TRANSPORT_DISCOVERY_DATA => AdType::TransportDiscoveryData,
+            LE_SUPPORTED_FEATURES => AdType::LeSupportedFeatures,
+            CHANNEL_MAP_UPDATE_INDICATION => AdType::ChannelMapUpdateIndication,
+            MESH_BEACON => AdType::MeshBeacon,
+            BIG_INFO => AdType::BigInfo,
+            BROADCAST_CODE => AdType::BroadcastCode,
+            RESOLVABLE_SET_IDENTIFIER => AdType::ResolvableSetIdentifier,
+            ADVERTISING_INTERVAL_LONG => AdType::AdvertisingIntervalLong,
+            ENCRYPTED_ADVERTISING_DATA => AdType::EncryptedData,
+            PERIODIC_ADVERTISING_RESPONSE_TIMING_INFORMATION => {
+                AdType::PeriodicAdvertisingResponseTimingInformation
+            }
+            ELECTRONIC_SHELF_LABEL => AdType::ElectronicShelfLabel,
+            THREE_D_INFORMATION_DATA => AdType::ThreeDInformationData,
+            MANUFACTURER_SPECIFIC_DATA => AdType::ManufacturerSpecificData,
+            _ => AdType::Unknown(data_type),
+        }
+    }
+
+    /// Get data type value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::ad_type::AdType;
+    ///
+    /// assert_eq!(0x01, AdType::Flags.value());
+    /// assert_eq!(0x19, AdType::Appearance.value());
+    /// assert_eq!(0x00, AdType::Unknown(0x00).value());
+    /// ```
+    pub const fn value(&self) -> u8 {
+        match self {
+            AdType::Flags => FLAGS,
+            AdType::IncompleteListOf16BitServiceUuids => INCOMPLETE_LIST_OF_16BIT_SERVICE_UUIDS,
+            AdType::CompleteListOf16BitServiceUuids => COMPLETE_LIST_OF_16BIT_SERVICE_UUIDS,
+            AdType::IncompleteListOf32BitServiceUuids => INCOMPLETE_LIST_OF_32BIT_SERVICE_UUIDS,
+            AdType::CompleteListOf32BitServiceUuids => COMPLETE_LIST_OF_32BIT_SERVICE_UUIDS,
+            AdType::IncompleteListOf128BitServiceUuids => INCOMPLETE_LIST_OF_128BIT_SERVICE_UUIDS,
+            AdType::CompleteListOf128BitServiceUuids => COMPLETE_LIST_OF_128BIT_SERVICE_UUIDS,
+            AdType::ShortenedLocalName => SHORTENED_LOCAL_NAME,
+            AdType::CompleteLocalName => COMPLETE_LOCAL_NAME,
+            AdType::TxPowerLevel => TX_POWER_LEVEL,
+            AdType::ClassOfDevice => CLASS_OF_DEVICE,
+            AdType::SecureSimplePairingHashC192 => SIMPLE_PAIRING_HASH_C192,
+            AdType::SecureSimplePairingRandomizerR192 => SIMPLE_PAIRING_RANDOMIZER_R192,
+            AdType::SecurityManagerTkValue => SECURITY_MANAGER_TK_VALUE,
+            AdType::SecurityManagerOutOfBand => SECURITY_MANAGER_OUT_OF_BAND_FLAGS,
+            AdType::PeripheralConnectionIntervalRange => PERIPHERAL_CONNECTION_INTERVAL_RANGE,
+            AdType::ListOf16BitServiceSolicitationUUIDs => LIST_OF_16BIT_SERVICE_SOLICITATION_UUIDS,
+            AdType::ListOf128BitServiceSolicitationUUIDs => {
+                LIST_OF_128BIT_SERVICE_SOLICITATION_UUIDS
+            }
+            AdType::ServiceData16BitUUID => SERVICE_DATA_16BIT_UUID,
+            AdType::PublicTargetAddress => PUBLIC_TARGET_ADDRESS,
+            AdType::RandomTargetAddress => RANDOM_TARGET_ADDRESS,
+            AdType::Appearance => APPEARANCE,
+            AdType::AdvertisingInterval => ADVERTISING_INTERVAL,
+            AdType::LeBluetoothDeviceAddress => LE_BLUETOOTH_DEVICE_ADDRESS,
+            AdType::LeRole => LE_ROLE,
+            AdType::SecureSimplePairingHashC256 => SIMPLE_PAIRING_HASH_C256,
+            AdType::SecureSimplePairingRandomizerR256 => SIMPLE_PAIRING_RANDOMIZER_R256,
+            AdType::ListOf32BitServiceSolicitationUUIDs => LIST_OF_32BIT_SERVICE_SOLICITATION_UUIDS,
+            AdType::ServiceData32BitUUID => SERVICE_DATA_32BIT_UUID,
+            AdType::ServiceData128BitUUID => SERVICE_DATA_128BIT_UUID,
+            AdType::LeSecureConnectionsConfirmationValue => {
+                LE_SECURE_CONNECTIONS_CONFIRMATION_VALUE
+            }
+            AdType::LeSecureConnectionsRandomValue => LE_SECURE_CONNECTIONS_RANDOM_VALUE,
+            AdType::UniformResourceIdentifier => URI,
+            AdType::IndoorPositioning => INDOOR_POSITIONING,
+            AdType::TransportDiscoveryData => TRANSPORT_DISCOVERY_DATA,
+            AdType::LeSupportedFeatures => LE_SUPPORTED_FEATURES,
+            AdType::ChannelMapUpdateIndication => CHANNEL_MAP_UPDATE_INDICATION,
+            AdType::MeshBeacon => MESH_BEACON,
+            AdType::BigInfo => BIG_INFO,
+            AdType::BroadcastCode => BROADCAST_CODE,
+            AdType::ResolvableSetIdentifier => RESOLVABLE_SET_IDENTIFIER,
+            AdType::AdvertisingIntervalLong => ADVERTISING_INTERVAL_LONG,
+            AdType::EncryptedData => ENCRYPTED_ADVERTISING_DATA,
+            AdType::PeriodicAdvertisingResponseTimingInformation => {
+                PERIODIC_ADVERTISING_RESPONSE_TIMING_INFORMATION
+            }
+            AdType::ElectronicShelfLabel => ELECTRONIC_SHELF_LABEL,
+            AdType::ThreeDInformationData => THREE_D_INFORMATION_DATA,
+            AdType::ManufacturerSpecificData => MANUFACTURER_SPECIFIC_DATA,
+            AdType::Unknown(data_type) => *data_type,
+        }
+    }
+
+    /// Get data type name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::ad_type::AdType;
+    ///
+    /// assert_eq!("Flags", AdType::Flags.name());
+    /// assert_eq!("Service Data - 16-bit UUID", AdType::ServiceData16BitUUID.name());
+    /// assert_eq!("Unknown", AdType::Unknown(0x00).name());
+    /// ```
+    pub const fn name(&self) -> &'static str {
+        match self {
+            AdType::Flags => "Flags",
+            AdType::IncompleteListOf16BitServiceUuids => {
+                "Incomplete List of 16-bit Service Class UUIDs"
+            }
+            AdType::CompleteListOf16BitServiceUuids => {
+                "Complete List of 16-bit Service Class UUIDs"
+            }
+            AdType::IncompleteListOf32BitServiceUuids => {
+                "Incomplete List of 32-bit Service Class UUIDs"
+            }
+            AdType::CompleteListOf32BitServiceUuids => {
+                "Complete List of 32-bit Service Class UUIDs"
+            }
+            AdType::IncompleteListOf128BitServiceUuids => {
+                "Incomplete List of 128-bit Service Class UUIDs"
+            }
+            AdType::CompleteListOf128BitServiceUuids => {
+                "Complete List of 128-bit Service Class UUIDs"
+            }
+            AdType::ShortenedLocalName => "Shortened Local Name",
+            AdType::CompleteLocalName => "Complete Local Name",
+            AdType::TxPowerLevel => "Tx Power Level",
+            AdType::ClassOfDevice => "Class of Device",
+            AdType::SecureSimplePairingHashC192 => "Secure Simple Pairing Hash C-192",
+            AdType::SecureSimplePairingRandomizerR192 => "Secure Simple Pairing Randomizer R-192",
+            AdType::SecurityManagerTkValue => "Security Manager TK Value",
+            AdType::SecurityManagerOutOfBand => "Security Manager Out of Band",
+            AdType::PeripheralConnectionIntervalRange => "Peripheral Connection Interval Range",
+            AdType::ListOf16BitServiceSolicitationUUIDs => {
+                "List of 16-bit Service Solicitation UUIDs"
+            }
+            AdType::ListOf128BitServiceSolicitationUUIDs => {
+                "List of 128-bit Service Solicitation UUIDs"
+            }
+            AdType::ServiceData16BitUUID => "Service Data - 16-bit UUID",
+            AdType::PublicTargetAddress => "Public Target Address",
+            AdType::RandomTargetAddress => "Random Target Address",
+            AdType::Appearance => "Appearance",
+            AdType::AdvertisingInterval => "Advertising Interval",
+            AdType::LeBluetoothDeviceAddress => "LE Bluetooth Device Address",
+            AdType::LeRole => "LE Role",
+            AdType::SecureSimplePairingHashC256 => "Secure Simple Pairing Hash C-256",
+            AdType::SecureSimplePairingRandomizerR256 => "Secure Simple Pairing Randomizer R-256",
+            AdType::ListOf32BitServiceSolicitationUUIDs => {
+                "List of 32-bit Service Solicitation UUIDs"
+            }
+            AdType::ServiceData32BitUUID => "Service Data - 32-bit UUID",
+            AdType::ServiceData128BitUUID => "Service Data - 128-bit UUID",
+            AdType::LeSecureConnectionsConfirmationValue => {
+                "LE Secure Connections Confirmation Value"
+            }
+            AdType::LeSecureConnectionsRandomValue => "LE Secure Connections Random Value",
+            AdType::UniformResourceIdentifier => "Uniform Resource Identifier",
+            AdType::IndoorPositioning => "Indoor Positioning",
+            AdType::TransportDiscoveryData => "Transport Discovery Data",
+            AdType::LeSupportedFeatures => "LE Supported Features",
+            AdType::ChannelMapUpdateIndication => "Channel Map Update Indication",
+            AdType::MeshBeacon => "Mesh Beacon",
+            AdType::BigInfo => "BIGInfo",
+            AdType::BroadcastCode => "Broadcast_Code",
+            AdType::ResolvableSetIdentifier => "Resolvable Set Identifier",
+            AdType::AdvertisingIntervalLong => "Advertising Interval - long",
+            AdType::EncryptedData => "Encrypted Data",
+            AdType::PeriodicAdvertisingResponseTimingInformation => {
+                "Periodic Advertising Response Timing Information"
+            }
+            AdType::ElectronicShelfLabel => "Electronic Shelf Label",
+            AdType::ThreeDInformationData => "3D Information Data",
+            AdType::ManufacturerSpecificData => "Manufacturer Specific Data",
+            AdType::Unknown(_) => "Unknown",
+        }
+    }
+
+    /// check [`AdType`] is `T`'s data type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{ad_type::AdType, appearance::Appearance, flags::Flags};
+    ///
+    /// assert!(AdType::of(0x19).matches::<Appearance>());
+    /// assert!(!AdType::of(0x19).matches::<Flags>());
+    /// assert!(!AdType::of(0x00).matches::<Appearance>());
+    /// ```
+    pub fn matches<T: DataType>(&self) -> bool {
+        self.value() == T::data_type()
+    }
+}
+
+impl From<u8> for AdType {
+    /// Create [`AdType`] from [`u8`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::ad_type::AdType;
+    ///
+    /// assert_eq!(AdType::Flags, AdType::from(0x01));
+    /// assert_eq!(AdType::Unknown(0x00), AdType::from(0x00));
+    /// ```
+    fn from(value: u8) -> Self {
+        Self::of(value)
+    }
+}
+
+impl From<AdType> for u8 {
+    /// Create [`u8`] from [`AdType`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::ad_type::AdType;
+    ///
+    /// assert_eq!(0x01u8, u8::from(AdType::Flags));
+    /// assert_eq!(0x00u8, u8::from(AdType::Unknown(0x00)));
+    /// ```
+    fn from(value: AdType) -> Self {
+        value.value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data_types::{
+        ad_type::AdType, advertising_interval::AdvertisingInterval,
+        advertising_interval_long::AdvertisingIntervalLong, appearance::Appearance,
+        channel_map_update_indication::ChannelMapUpdateIndication, class_of_device::ClassOfDevice,
+        complete_list_of_128bit_service_uuids::CompleteListOf128BitServiceUuids,
+        complete_list_of_16bit_service_uuids::CompleteListOf16BitServiceUuids,
+        complete_list_of_32bit_service_uuids::CompleteListOf32BitServiceUuids,
+        complete_local_name::CompleteLocalName, data_type::DataType,
+        electronic_shelf_label::ElectronicShelfLabel, encrypted_data::EncryptedData, flags::Flags,
+        incomplete_list_of_128bit_service_uuids::IncompleteListOf128BitServiceUuids,
+        incomplete_list_of_16bit_service_uuids::IncompleteListOf16BitServiceUuids,
+        incomplete_list_of_32bit_service_uuids::IncompleteListOf32BitServiceUuids,
+        indoor_positioning::IndoorPositioning,
+        le_bluetooth_device_address::LeBluetoothDeviceAddress, le_role::LeRole,
+        le_supported_features::LeSupportedFeatures,
+        list_of_128bit_service_solicitation_uuids::ListOf128BitServiceSolicitationUUIDs,
+        list_of_16bit_service_solicitation_uuids::ListOf16BitServiceSolicitationUUIDs,
+        list_of_32bit_service_solicitation_uuids::ListOf32BitServiceSolicitationUUIDs,
+        manufacturer_specific_data::ManufacturerSpecificData,
+        periodic_advertising_response_timing_information::PeriodicAdvertisingResponseTimingInformation,
+        peripheral_connection_interval_range::PeripheralConnectionIntervalRange,
+        public_target_address::PublicTargetAddress, random_target_address::RandomTargetAddress,
+        service_data_128bit_uuid::ServiceData128BitUUID,
+        service_data_16bit_uuid::ServiceData16BitUUID,
+        service_data_32bit_uuid::ServiceData32BitUUID, shortened_local_name::ShortenedLocalName,
+        three_d_information_data::ThreeDInformationData,
+        transport_discovery_data::TransportDiscoveryData, tx_power_level::TxPowerLevel,
+        uniform_resource_identifier::UniformResourceIdentifier,
+    };
+
+    #[cfg(feature = "le-audio")]
+    use crate::data_types::{
+        big_info::BigInfo, broadcast_code::BroadcastCode,
+        resolvable_set_identifier::ResolvableSetIdentifier,
+    };
+
+    #[cfg(feature = "mesh")]
+    use crate::data_types::mesh_beacon::MeshBeacon;
+
+    #[cfg(feature = "pairing")]
+    use crate::data_types::{
+        le_secure_connections_confirmation_value::LeSecureConnectionsConfirmationValue,
+        le_secure_connections_random_value::LeSecureConnectionsRandomValue,
+        secure_simple_pairing_hash_c192::SecureSimplePairingHashC192,
+        secure_simple_pairing_hash_c256::SecureSimplePairingHashC256,
+        secure_simple_pairing_randomizer_r192::SecureSimplePairingRandomizerR192,
+        secure_simple_pairing_randomizer_r256::SecureSimplePairingRandomizerR256,
+        security_manager_oob::SecurityManagerOutOfBand,
+        security_manager_tk_value::SecurityManagerTkValue,
+    };
+
+    #[test]
+    fn test_name() {
+        for data_type in 0..=u8::MAX {
+            let ad_type = AdType::of(data_type);
+            match ad_type {
+                AdType::Unknown(_) => assert_eq!("Unknown", ad_type.name()),
+                _ => assert_ne!("Unknown", ad_type.name()),
+            }
+        }
+        assert_eq!("Complete Local Name", AdType::CompleteLocalName.name());
+        assert_eq!(
+            "Manufacturer Specific Data",
+            AdType::ManufacturerSpecificData.name()
+        );
+    }
+
+    #[test]
+    fn test_of() {
+        assert_eq!(AdType::Flags, AdType::of(Flags::data_type()));
+        assert_eq!(
+            AdType::IncompleteListOf16BitServiceUuids,
+            AdType::of(IncompleteListOf16BitServiceUuids::data_type())
+        );
+        assert_eq!(
+            AdType::CompleteListOf16BitServiceUuids,
+            AdType::of(CompleteListOf16BitServiceUuids::data_type())
+        );
+        assert_eq!(
+            AdType::IncompleteListOf32BitServiceUuids,
+            AdType::of(IncompleteListOf32BitServiceUuids::data_type())
+        );
+        assert_eq!(
+            AdType::CompleteListOf32BitServiceUuids,
+            AdType::of(CompleteListOf32BitServiceUuids::data_type())
+        );
+        assert_eq!(
+            AdType::IncompleteListOf128BitServiceUuids,
+            AdType::of(IncompleteListOf128BitServiceUuids::data_type())
+        );
+        assert_eq!(
+            AdType::CompleteListOf128BitServiceUuids,
+            AdType::of(CompleteListOf128BitServiceUuids::data_type())
+        );
+        assert_eq!(
+            AdType::ShortenedLocalName,
+            AdType::of(ShortenedLocalName::data_type())
+        );
+        assert_eq!(
+            AdType::CompleteLocalName,
+            AdType::of(CompleteLocalName::data_type())
+        );
+        assert_eq!(AdType::TxPowerLevel, AdType::of(TxPowerLevel::data_type()));
+        assert_eq!(
+            AdType::ClassOfDevice,
+            AdType::of(ClassOfDevice::data_type())
+        );
+        #[cfg(feature = "pairing")]
+        assert_eq!(
+            AdType::SecureSimplePairingHashC192,
+            AdType::of(SecureSimplePairingHashC192::data_type())
+        );
+        #[cfg(feature = "pairing")]
+        assert_eq!(
+            AdType::SecureSimplePairingRandomizerR192,
+            AdType::of(SecureSimplePairingRandomizerR192::data_type())
+        );
+        #[cfg(feature = "pairing")]
+        assert_eq!(
+            AdType::SecurityManagerTkValue,
+            AdType::of(SecurityManagerTkValue::data_type())
+        );
+        #[cfg(feature = "pairing")]
+        assert_eq!(
+            AdType::SecurityManagerOutOfBand,
+            AdType::of(SecurityManagerOutOfBand::data_type())
+        );
+        assert_eq!(
+            AdType::PeripheralConnectionIntervalRange,
+            AdType::of(PeripheralConnectionIntervalRange::data_type())
+        );
+        assert_eq!(
+            AdType::ListOf16BitServiceSolicitationUUIDs,
+            AdType::of(ListOf16BitServiceSolicitationUUIDs::data_type())
+        );
+        assert_eq!(
+            AdType::ListOf128BitServiceSolicitationUUIDs,
+            AdType::of(ListOf128BitServiceSolicitationUUIDs::data_type())
+        );
+        assert_eq!(
+            AdType::ServiceData16BitUUID,
+            AdType::of(ServiceData16BitUUID::data_type())
+        );
+        assert_eq!(
+            AdType::PublicTargetAddress,
+            AdType::of(PublicTargetAddress::data_type())
+        );
+        assert_eq!(
+            AdType::RandomTargetAddress,
+            AdType::of(RandomTargetAddress::data_type())
+        );
+        assert_eq!(AdType::Appearance, AdType::of(Appearance::data_type()));
+        assert_eq!(
+            AdType::AdvertisingInterval,
+            AdType::of(AdvertisingInterval::data_type())
+        );
+        assert_eq!(
+            AdType::LeBluetoothDeviceAddress,
+            AdType::of(LeBluetoothDeviceAddress::data_type())
+        );
+        assert_eq!(AdType::LeRole, AdType::of(LeRole::data_type()));
+        #[cfg(feature = "pairing")]
+        assert_eq!(
+            AdType::SecureSimplePairingHashC256,
+            AdType::of(SecureSimplePairingHashC256::data_type())
+        );
+        #[cfg(feature = "pairing")]
+        assert_eq!(
+            AdType::SecureSimplePairingRandomizerR256,
+            AdType::of(SecureSimplePairingRandomizerR256::data_type())
+        );
+        assert_eq!(
+            AdType::ListOf32BitServiceSolicitationUUIDs,
+            AdType::of(ListOf32BitServiceSolicitationUUIDs::data_type())
+        );
+        assert_eq!(
+            AdType::ServiceData32BitUUID,
+            AdType::of(ServiceData32BitUUID::data_type())
+        );
+        assert_eq!(
+            AdType::ServiceData128BitUUID,
+            AdType::of(ServiceData128BitUUID::data_type())
+        );
+        #[cfg(feature = "pairing")]
+        assert_eq!(
+            AdType::LeSecureConnectionsConfirmationValue,
+            AdType::of(LeSecureConnectionsConfirmationValue::data_type())
+        );
+        #[cfg(feature = "pairing")]
+        assert_eq!(
+            AdType::LeSecureConnectionsRandomValue,
+            AdType::of(LeSecureConnectionsRandomValue::data_type())
+        );
+        assert_eq!(
+            AdType::UniformResourceIdentifier,
+            AdType::of(UniformResourceIdentifier::data_type())
+        );
+        assert_eq!(
+            AdType::IndoorPositioning,
+            AdType::of(IndoorPositioning::data_type())
+        );
+        assert_eq!(
+            AdType::TransportDiscoveryData,
+            AdType::of(TransportDiscoveryData::data_type())
+        );
+        assert_eq!(
+            AdType::LeSupportedFeatures,
+            AdType::of(LeSupportedFeatures::data_type())
+        );
+        assert_eq!(
+            AdType::ChannelMapUpdateIndication,
+            AdType::of(ChannelMapUpdateIndication::data_type())
+        );
+        #[cfg(feature = "mesh")]
+        assert_eq!(AdType::MeshBeacon, AdType::of(MeshBeacon::data_type()));
+        #[cfg(feature = "le-audio")]
+        assert_eq!(AdType::BigInfo, AdType::of(BigInfo::data_type()));
+        #[cfg(feature = "le-audio")]
+        assert_eq!(
+            AdType::BroadcastCode,
+            AdType::of(BroadcastCode::data_type())
+        );
+        #[cfg(feature = "le-audio")]
+        assert_eq!(
+            AdType::ResolvableSetIdentifier,
+            AdType::of(ResolvableSetIdentifier::data_type())
+        );
+        assert_eq!(
+            AdType::AdvertisingIntervalLong,
+            AdType::of(AdvertisingIntervalLong::data_type())
+        );
+        assert_eq!(
+            AdType::EncryptedData,
+            AdType::of(EncryptedData::data_type())
+        );
+        assert_eq!(
+            AdType::PeriodicAdvertisingResponseTimingInformation,
+            AdType::of(PeriodicAdvertisingResponseTimingInformation::data_type())
+        );
+        assert_eq!(
+            AdType::ElectronicShelfLabel,
+            AdType::of(ElectronicShelfLabel::data_type())
+        );
+        assert_eq!(
+            AdType::ThreeDInformationData,
+            AdType::of(ThreeDInformationData::data_type())
+        );
+        assert_eq!(
+            AdType::ManufacturerSpecificData,
+            AdType::of(ManufacturerSpecificData::data_type())
+        );
+        assert_eq!(AdType::Unknown(0x00), AdType::of(0x00));
+    }
+
+    #[test]
+    fn test_value() {
+        for data_type in 0..=u8::MAX {
+            assert_eq!(data_type, AdType::of(data_type).value());
+        }
+    }
+
+    #[test]
+    fn test_of_const() {
+        const AD_TYPE: AdType = AdType::of(0x09);
+        const VALUE: u8 = AD_TYPE.value();
+        assert_eq!(AdType::CompleteLocalName, AD_TYPE);
+        assert_eq!(0x09, VALUE);
+    }
+
+    #[test]
+    fn test_matches() {
+        assert!(AdType::of(0x01).matches::<Flags>());
+        assert!(AdType::of(0x02).matches::<IncompleteListOf16BitServiceUuids>());
+        assert!(AdType::of(0x03).matches::<CompleteListOf16BitServiceUuids>());
+        assert!(AdType::of(0x04).matches::<IncompleteListOf32BitServiceUuids>());
+        assert!(AdType::of(0x05).matches::<CompleteListOf32BitServiceUuids>());
+        assert!(AdType::of(0x06).matches::<IncompleteListOf128BitServiceUuids>());
+        assert!(AdType::of(0x07).matches::<CompleteListOf128BitServiceUuids>());
+        assert!(AdType::of(0x08).matches::<ShortenedLocalName>());
+        assert!(AdType::of(0x09).matches::<CompleteLocalName>());
+        assert!(AdType::of(0x0a).matches::<TxPowerLevel>());
+        assert!(AdType::of(0x0d).matches::<ClassOfDevice>());
+        #[cfg(feature = "pairing")]
+        assert!(AdType::of(0x0e).matches::<SecureSimplePairingHashC192>());
+        #[cfg(feature = "pairing")]
+        assert!(AdType::of(0x0f).matches::<SecureSimplePairingRandomizerR192>());
+        #[cfg(feature = "pairing")]
+        assert!(AdType::of(0x10).matches::<SecurityManagerTkValue>());
+        #[cfg(feature = "pairing")]
+        assert!(AdType::of(0x11).matches::<SecurityManagerOutOfBand>());
+        assert!(AdType::of(0x12).matches::<PeripheralConnectionIntervalRange>());
+        assert!(AdType::of(0x14).matches::<ListOf16BitServiceSolicitationUUIDs>());
+        assert!(AdType::of(0x15).matches::<ListOf128BitServiceSolicitationUUIDs>());
+        assert!(AdType::of(0x16).matches::<ServiceData16BitUUID>());
+        assert!(AdType::of(0x17).matches::<PublicTargetAddress>());
+        assert!(AdType::of(0x18).matches::<RandomTargetAddress>());
+        assert!(AdType::of(0x19).matches::<Appearance>());
+        assert!(AdType::of(0x1a).matches::<AdvertisingInterval>());
+        assert!(AdType::of(0x1b).matches::<LeBluetoothDeviceAddress>());
+        assert!(AdType::of(0x1c).matches::<LeRole>());
+        #[cfg(feature = "pairing")]
+        assert!(AdType::of(0x1d).matches::<SecureSimplePairingHashC256>());
+        #[cfg(feature = "pairing")]
+        assert!(AdType::of(0x1e).matches::<SecureSimplePairingRandomizerR256>());
+        assert!(AdType::of(0x1f).matches::<ListOf32BitServiceSolicitationUUIDs>());
+        assert!(AdType::of(0x20).matches::<ServiceData32BitUUID>());
+        assert!(AdType::of(0x21).matches::<ServiceData128BitUUID>());
+        #[cfg(feature = "pairing")]
+        assert!(AdType::of(0x22).matches::<LeSecureConnectionsConfirmationValue>());
+        #[cfg(feature = "pairing")]
+        assert!(AdType::of(0x23).matches::<LeSecureConnectionsRandomValue>());
+        assert!(AdType::of(0x24).matches::<UniformResourceIdentifier>());
+        assert!(AdType::of(0x25).matches::<IndoorPositioning>());
+        assert!(AdType::of(0x26).matches::<TransportDiscoveryData>());
+        assert!(AdType::of(0x27).matches::<LeSupportedFeatures>());
+        assert!(AdType::of(0x28).matches::<ChannelMapUpdateIndication>());
+        #[cfg(feature = "mesh")]
+        assert!(AdType::of(0x2b).matches::<MeshBeacon>());
+        #[cfg(feature = "le-audio")]
+        assert!(AdType::of(0x2c).matches::<BigInfo>());
+        #[cfg(feature = "le-audio")]
+        assert!(AdType::of(0x2d).matches::<BroadcastCode>());
+        #[cfg(feature = "le-audio")]
+        assert!(AdType::of(0x2e).matches::<ResolvableSetIdentifier>());
+        assert!(AdType::of(0x2f).matches::<AdvertisingIntervalLong>());
+        assert!(AdType::of(0x31).matches::<EncryptedData>());
+        assert!(AdType::of(0x32).matches::<PeriodicAdvertisingResponseTimingInformation>());
+        assert!(AdType::of(0x34).matches::<ElectronicShelfLabel>());
+        assert!(AdType::of(0x3d).matches::<ThreeDInformationData>());
+        assert!(AdType::of(0xff).matches::<ManufacturerSpecificData>());
+        assert!(!AdType::of(0x19).matches::<Flags>());
+        assert!(!AdType::of(0x00).matches::<Appearance>());
+    }
+
+    #[test]
+    fn test_from_u8() {
+        assert_eq!(AdType::Flags, AdType::from(0x01));
+        assert_eq!(AdType::Unknown(0x00), AdType::from(0x00));
+    }
+
+    #[test]
+    fn test_into_u8() {
+        assert_eq!(0x01u8, u8::from(AdType::Flags));
+        assert_eq!(0x00u8, u8::from(AdType::Unknown(0x00)));
+    }
+}
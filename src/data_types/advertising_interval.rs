@@ -1,6 +1,9 @@
 //! Advertising Interval (Data Type Value: 0x1a) module.
 
-use crate::data_types::data_type::DataType;
+use crate::data_types::{
+    ad_type::AdType,
+    data_type::{validate_length, DataType},
+};
 
 /// Advertising Interval.
 #[derive(Debug, PartialEq, Clone)]
@@ -24,7 +27,7 @@ impl AdvertisingInterval {
     /// assert_eq!(3, result.length);
     /// assert_eq!(advertising_interval, result.advertising_interval);
     /// ```
-    pub fn new(advertising_interval: u16) -> Self {
+    pub const fn new(advertising_interval: u16) -> Self {
         Self {
             length: 3,
             advertising_interval,
@@ -58,9 +61,9 @@ impl AdvertisingInterval {
 /// advInterval value
 pub const ADVINTERVAL_VALUE: f32 = 0.625;
 
-impl TryFrom<&Vec<u8>> for AdvertisingInterval {
+impl TryFrom<&[u8]> for AdvertisingInterval {
     type Error = String;
-    /// Create [`AdvertisingInterval`] from [`Vec<u8>`].
+    /// Create [`AdvertisingInterval`] from `u8` slice.
     ///
     /// # Examples
     ///
@@ -74,26 +77,26 @@ impl TryFrom<&Vec<u8>> for AdvertisingInterval {
     /// data.push(AdvertisingInterval::data_type());
     /// data.append(&mut advertising_interval.to_le_bytes().to_vec());
     ///
-    /// let result = AdvertisingInterval::try_from(&data);
+    /// let result = AdvertisingInterval::try_from(data.as_slice());
     /// assert!(result.is_ok());
     /// let data_type = result.unwrap();
     /// assert_eq!(length, data_type.length);
     /// assert_eq!(advertising_interval, data_type.advertising_interval);
     ///
     /// let data: Vec<u8> = Vec::new();
-    /// let result = AdvertisingInterval::try_from(&data);
+    /// let result = AdvertisingInterval::try_from(data.as_slice());
     /// assert!(result.is_err());
     /// assert_eq!(
     ///     format!("Invalid data size :{}", data.len()),
     ///     result.unwrap_err()
     /// );
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+    fn try_from(value: &[u8]) -> Result<Self, String> {
         let len = value.len();
         if len < 4 {
             return Err(format!("Invalid data size :{}", len).to_string());
         }
-        let length = value[0];
+        let length = validate_length(value, 3, 3)?;
         Ok(Self {
             length,
             advertising_interval: u16::from_le_bytes(value[2..4].try_into().unwrap()),
@@ -101,6 +104,41 @@ impl TryFrom<&Vec<u8>> for AdvertisingInterval {
     }
 }
 
+impl TryFrom<&Vec<u8>> for AdvertisingInterval {
+    type Error = String;
+    /// Create [`AdvertisingInterval`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{advertising_interval::AdvertisingInterval, data_type::DataType};
+    ///
+    /// let advertising_interval: u16 = 0x01;
+    /// let length = 3;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(AdvertisingInterval::data_type());
+    /// data.append(&mut advertising_interval.to_le_bytes().to_vec());
+    ///
+    /// let result = AdvertisingInterval::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert_eq!(advertising_interval, data_type.advertising_interval);
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = AdvertisingInterval::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        Self::try_from(value.as_slice())
+    }
+}
+
 impl Into<Vec<u8>> for AdvertisingInterval {
     /// Create [`Vec<u8>`] from [`AdvertisingInterval`].
     ///
@@ -161,8 +199,9 @@ impl DataType for AdvertisingInterval {
 /// assert!(is_advertising_interval(0x1a));
 /// assert!(!is_advertising_interval(0x00));
 /// ```
+#[deprecated(note = "use `AdType::of(data_type).matches::<AdvertisingInterval>()` instead")]
 pub fn is_advertising_interval(data_type: u8) -> bool {
-    AdvertisingInterval::data_type() == data_type
+    AdType::of(data_type).matches::<AdvertisingInterval>()
 }
 
 #[cfg(test)]
@@ -238,6 +277,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_is_advertising_interval() {
         assert!(is_advertising_interval(0x1a));
         assert!(!is_advertising_interval(0x00));
@@ -1,6 +1,9 @@
 //! Advertising Interval - long (Data Type Value: 0x2f) module.
 
-use crate::data_types::data_type::DataType;
+use crate::data_types::{
+    ad_type::AdType,
+    data_type::{validate_length, DataType},
+};
 
 /// Advertising Interval - long.
 #[derive(Debug, PartialEq, Clone)]
@@ -39,7 +42,7 @@ impl AdvertisingIntervalLong {
     ///     result.advertising_interval_long
     /// );
     /// ```
-    pub fn new(is_u32: bool, advertising_interval_long: u32) -> Self {
+    pub const fn new(is_u32: bool, advertising_interval_long: u32) -> Self {
         Self {
             length: if is_u32 { 5 } else { 4 },
             is_u32,
@@ -84,9 +87,9 @@ impl AdvertisingIntervalLong {
 /// advInterval value
 pub const ADVINTERVAL_VALUE: f32 = 0.625;
 
-impl TryFrom<&Vec<u8>> for AdvertisingIntervalLong {
+impl TryFrom<&[u8]> for AdvertisingIntervalLong {
     type Error = String;
-    /// Create [`AdvertisingIntervalLong`] from [`Vec<u8>`].
+    /// Create [`AdvertisingIntervalLong`] from `u8` slice.
     ///
     /// # Examples
     ///
@@ -99,7 +102,7 @@ impl TryFrom<&Vec<u8>> for AdvertisingIntervalLong {
     /// data.push(length);
     /// data.push(AdvertisingIntervalLong::data_type());
     /// data.append(&mut advertising_interval_long.to_le_bytes().to_vec());
-    /// let result = AdvertisingIntervalLong::try_from(&data);
+    /// let result = AdvertisingIntervalLong::try_from(data.as_slice());
     /// assert!(result.is_ok());
     /// let data_type = result.unwrap();
     /// assert_eq!(length, data_type.length);
@@ -110,7 +113,7 @@ impl TryFrom<&Vec<u8>> for AdvertisingIntervalLong {
     /// data.push(length);
     /// data.push(AdvertisingIntervalLong::data_type());
     /// data.append(&mut advertising_interval_long.to_le_bytes()[..3].to_vec());
-    /// let result = AdvertisingIntervalLong::try_from(&data);
+    /// let result = AdvertisingIntervalLong::try_from(data.as_slice());
     /// assert!(result.is_ok());
     /// let data_type = result.unwrap();
     /// assert_eq!(length, data_type.length);
@@ -120,32 +123,78 @@ impl TryFrom<&Vec<u8>> for AdvertisingIntervalLong {
     /// );
     ///
     /// let data: Vec<u8> = Vec::new();
-    /// let result = AdvertisingIntervalLong::try_from(&data);
+    /// let result = AdvertisingIntervalLong::try_from(data.as_slice());
     /// assert!(result.is_err());
     /// assert_eq!(
     ///     format!("Invalid data size :{}", data.len()),
     ///     result.unwrap_err()
     /// );
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, Self::Error> {
+    fn try_from(value: &[u8]) -> Result<Self, String> {
         let len = value.len();
         if len < 4 {
             return Err(format!("Invalid data size :{}", len).to_string());
         }
-        let mut value = value.to_vec();
-        let length = value[0];
+        let length = validate_length(value, 4, 5)?;
         let is_u32 = length == 5;
-        if !is_u32 {
-            value.push(0x00);
-        };
+        let mut bytes = [0u8; 4];
+        bytes[..length as usize - 1].copy_from_slice(&value[2..1 + length as usize]);
         Ok(Self {
             length,
             is_u32,
-            advertising_interval_long: u32::from_le_bytes(value[2..6].try_into().unwrap()),
+            advertising_interval_long: u32::from_le_bytes(bytes),
         })
     }
 }
 
+impl TryFrom<&Vec<u8>> for AdvertisingIntervalLong {
+    type Error = String;
+    /// Create [`AdvertisingIntervalLong`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{advertising_interval_long::AdvertisingIntervalLong, data_type::DataType};
+    ///
+    /// let advertising_interval_long: u32 = 0x01020304u32;
+    /// let length = 5;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(AdvertisingIntervalLong::data_type());
+    /// data.append(&mut advertising_interval_long.to_le_bytes().to_vec());
+    /// let result = AdvertisingIntervalLong::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert_eq!(advertising_interval_long, data_type.advertising_interval_long);
+    ///
+    /// let length = 4;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(AdvertisingIntervalLong::data_type());
+    /// data.append(&mut advertising_interval_long.to_le_bytes()[..3].to_vec());
+    /// let result = AdvertisingIntervalLong::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert_eq!(
+    ///     advertising_interval_long & 0x00ffffff,
+    ///     data_type.advertising_interval_long
+    /// );
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = AdvertisingIntervalLong::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        Self::try_from(value.as_slice())
+    }
+}
+
 impl Into<Vec<u8>> for AdvertisingIntervalLong {
     /// Create [`Vec<u8>`] from [`AdvertisingIntervalLong`].
     ///
@@ -225,8 +274,9 @@ impl DataType for AdvertisingIntervalLong {
 /// assert!(is_advertising_interval_long(0x2f));
 /// assert!(!is_advertising_interval_long(0x00));
 /// ```
+#[deprecated(note = "use `AdType::of(data_type).matches::<AdvertisingIntervalLong>()` instead")]
 pub fn is_advertising_interval_long(data_type: u8) -> bool {
-    AdvertisingIntervalLong::data_type() == data_type
+    AdType::of(data_type).matches::<AdvertisingIntervalLong>()
 }
 
 #[cfg(test)]
@@ -348,6 +398,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_is_advertising_interval_long() {
         assert!(is_advertising_interval_long(0x2f));
         assert!(!is_advertising_interval_long(0x00));
@@ -1,6 +1,11 @@
 //! Appearance (Data Type Value: 0x19) module.
 
-use crate::data_types::data_type::DataType;
+use std::fmt;
+
+use crate::data_types::{
+    ad_type::AdType,
+    data_type::{validate_length, DataType},
+};
 
 /// Appearance.
 #[derive(Debug, PartialEq, Clone)]
@@ -25,13 +30,51 @@ impl Appearance {
     /// assert_eq!(3, result.length);
     /// assert_eq!(appearance, result.appearance);
     /// ```
-    pub fn new(appearance: u16) -> Self {
+    pub const fn new(appearance: u16) -> Self {
         Self {
             length: 3,
             appearance,
         }
     }
 
+    /// Create [`Appearance`] from assigned `Appearance`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::appearance::{Appearance, APPEARANCE_HEART_RATE_BELT};
+    ///
+    /// let result = Appearance::try_new(APPEARANCE_HEART_RATE_BELT);
+    /// assert_eq!(Ok(Appearance::new(APPEARANCE_HEART_RATE_BELT)), result);
+    ///
+    /// let result = Appearance::try_new(0x0345);
+    /// assert_eq!(Err("Unassigned appearance :0x0345".to_string()), result);
+    /// ```
+    pub fn try_new(appearance: u16) -> Result<Self, String> {
+        let result = Self::new(appearance);
+        if !result.is_assigned() {
+            return Err(format!("Unassigned appearance :{:#06x}", appearance).to_string());
+        }
+        Ok(result)
+    }
+
+    /// Create [`Appearance`] from Category and Subcategory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::appearance::{Appearance, APPEARANCE_HEART_RATE_BELT};
+    ///
+    /// let result = Appearance::from_category_subcategory(0x00d, 0x01);
+    /// assert_eq!(3, result.length);
+    /// assert_eq!(APPEARANCE_HEART_RATE_BELT, result.appearance);
+    /// assert_eq!(0x00d, result.category());
+    /// assert_eq!(0x01, result.sub_category());
+    /// ```
+    pub fn from_category_subcategory(category: u16, sub_category: u16) -> Self {
+        Self::new(((category & 0b00000011_11111111) << 6) | (sub_category & 0b00111111))
+    }
+
     /// Get Category.
     ///
     /// # Examples
@@ -73,11 +116,1552 @@ impl Appearance {
     pub const fn sub_category(&self) -> u16 {
         self.appearance & 0b00111111
     }
+
+    /// Get Category name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::appearance::{Appearance, APPEARANCE_HEART_RATE_BELT};
+    ///
+    /// let result = Appearance::new(APPEARANCE_HEART_RATE_BELT);
+    /// assert_eq!(Some("Heart Rate Sensor"), result.category_name());
+    ///
+    /// let result = Appearance::new(0xffff);
+    /// assert_eq!(None, result.category_name());
+    /// ```
+    pub fn category_name(&self) -> Option<&'static str> {
+        let category = self.category();
+        APPEARANCE_CATEGORY_NAMES
+            .iter()
+            .find(|(value, _)| *value == category)
+            .map(|(_, name)| *name)
+    }
+
+    /// Get Subcategory name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::appearance::{Appearance, APPEARANCE_HEART_RATE_BELT};
+    ///
+    /// let result = Appearance::new(APPEARANCE_HEART_RATE_BELT);
+    /// assert_eq!(Some("Heart Rate Belt"), result.sub_category_name());
+    ///
+    /// let result = Appearance::new(0xffff);
+    /// assert_eq!(None, result.sub_category_name());
+    /// ```
+    pub fn sub_category_name(&self) -> Option<&'static str> {
+        APPEARANCE_SUB_CATEGORY_NAMES
+            .iter()
+            .find(|(value, _)| *value == self.appearance)
+            .map(|(_, name)| *name)
+    }
+
+    /// check Appearance is in the assigned numbers list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::appearance::{Appearance, APPEARANCE_HEART_RATE_BELT};
+    ///
+    /// assert!(Appearance::new(APPEARANCE_HEART_RATE_BELT).is_assigned());
+    /// assert!(!Appearance::new(0x0345).is_assigned());
+    /// ```
+    pub fn is_assigned(&self) -> bool {
+        self.sub_category_name().is_some()
+    }
+
+    /// Get Appearance name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::appearance::{Appearance, APPEARANCE_HEART_RATE_BELT};
+    ///
+    /// let result = Appearance::new(APPEARANCE_HEART_RATE_BELT);
+    /// assert_eq!(
+    ///     Some("Heart Rate Sensor: Heart Rate Belt".to_string()),
+    ///     result.name()
+    /// );
+    ///
+    /// let result = Appearance::new(0x0345);
+    /// assert_eq!(None, result.name());
+    /// ```
+    pub fn name(&self) -> Option<String> {
+        match (self.category_name(), self.sub_category_name()) {
+            (Some(category_name), Some(sub_category_name)) => {
+                Some(format!("{}: {}", category_name, sub_category_name))
+            }
+            _ => None,
+        }
+    }
 }
 
-impl TryFrom<&Vec<u8>> for Appearance {
+/// Generic Unknown
+pub const APPEARANCE_GENERIC_UNKNOWN: u16 = 0x0000;
+
+/// Generic Phone
+pub const APPEARANCE_GENERIC_PHONE: u16 = 0x0040;
+
+/// Generic Computer
+pub const APPEARANCE_GENERIC_COMPUTER: u16 = 0x0080;
+
+/// Desktop Workstation
+pub const APPEARANCE_DESKTOP_WORKSTATION: u16 = 0x0081;
+
+/// Server-class Computer
+pub const APPEARANCE_SERVER_CLASS_COMPUTER: u16 = 0x0082;
+
+/// Laptop
+pub const APPEARANCE_LAPTOP: u16 = 0x0083;
+
+/// Handheld PC/PDA (clamshell)
+pub const APPEARANCE_HANDHELD_PC_PDA_CLAMSHELL: u16 = 0x0084;
+
+/// Palm-size PC/PDA
+pub const APPEARANCE_PALM_SIZE_PC_PDA: u16 = 0x0085;
+
+/// Wearable computer (watch size)
+pub const APPEARANCE_WEARABLE_COMPUTER_WATCH_SIZE: u16 = 0x0086;
+
+/// Tablet
+pub const APPEARANCE_TABLET: u16 = 0x0087;
+
+/// Docking Station
+pub const APPEARANCE_DOCKING_STATION: u16 = 0x0088;
+
+/// All in One
+pub const APPEARANCE_ALL_IN_ONE: u16 = 0x0089;
+
+/// Blade Server
+pub const APPEARANCE_BLADE_SERVER: u16 = 0x008a;
+
+/// Convertible
+pub const APPEARANCE_CONVERTIBLE: u16 = 0x008b;
+
+/// Detachable
+pub const APPEARANCE_DETACHABLE: u16 = 0x008c;
+
+/// IoT Gateway
+pub const APPEARANCE_IOT_GATEWAY: u16 = 0x008d;
+
+/// Mini PC
+pub const APPEARANCE_MINI_PC: u16 = 0x008e;
+
+/// Stick PC
+pub const APPEARANCE_STICK_PC: u16 = 0x008f;
+
+/// Generic Watch
+pub const APPEARANCE_GENERIC_WATCH: u16 = 0x00c0;
+
+/// Sports Watch
+pub const APPEARANCE_SPORTS_WATCH: u16 = 0x00c1;
+
+/// Smartwatch
+pub const APPEARANCE_SMARTWATCH: u16 = 0x00c2;
+
+/// Generic Clock
+pub const APPEARANCE_GENERIC_CLOCK: u16 = 0x0100;
+
+/// Generic Display
+pub const APPEARANCE_GENERIC_DISPLAY: u16 = 0x0140;
+
+/// Generic Remote Control
+pub const APPEARANCE_GENERIC_REMOTE_CONTROL: u16 = 0x0180;
+
+/// Generic Eye-glasses
+pub const APPEARANCE_GENERIC_EYE_GLASSES: u16 = 0x01c0;
+
+/// Generic Tag
+pub const APPEARANCE_GENERIC_TAG: u16 = 0x0200;
+
+/// Generic Keyring
+pub const APPEARANCE_GENERIC_KEYRING: u16 = 0x0240;
+
+/// Generic Media Player
+pub const APPEARANCE_GENERIC_MEDIA_PLAYER: u16 = 0x0280;
+
+/// Generic Barcode Scanner
+pub const APPEARANCE_GENERIC_BARCODE_SCANNER: u16 = 0x02c0;
+
+/// Generic Thermometer
+pub const APPEARANCE_GENERIC_THERMOMETER: u16 = 0x0300;
+
+/// Ear Thermometer
+pub const APPEARANCE_EAR_THERMOMETER: u16 = 0x0301;
+
+/// Generic Heart Rate Sensor
+pub const APPEARANCE_GENERIC_HEART_RATE_SENSOR: u16 = 0x0340;
+
+/// Heart Rate Belt
+pub const APPEARANCE_HEART_RATE_BELT: u16 = 0x0341;
+
+/// Generic Blood Pressure
+pub const APPEARANCE_GENERIC_BLOOD_PRESSURE: u16 = 0x0380;
+
+/// Arm Blood Pressure
+pub const APPEARANCE_ARM_BLOOD_PRESSURE: u16 = 0x0381;
+
+/// Wrist Blood Pressure
+pub const APPEARANCE_WRIST_BLOOD_PRESSURE: u16 = 0x0382;
+
+/// Generic Human Interface Device
+pub const APPEARANCE_GENERIC_HUMAN_INTERFACE_DEVICE: u16 = 0x03c0;
+
+/// Keyboard
+pub const APPEARANCE_KEYBOARD: u16 = 0x03c1;
+
+/// Mouse
+pub const APPEARANCE_MOUSE: u16 = 0x03c2;
+
+/// Joystick
+pub const APPEARANCE_JOYSTICK: u16 = 0x03c3;
+
+/// Gamepad
+pub const APPEARANCE_GAMEPAD: u16 = 0x03c4;
+
+/// Digitizer Tablet
+pub const APPEARANCE_DIGITIZER_TABLET: u16 = 0x03c5;
+
+/// Card Reader
+pub const APPEARANCE_CARD_READER: u16 = 0x03c6;
+
+/// Digital Pen
+pub const APPEARANCE_DIGITAL_PEN: u16 = 0x03c7;
+
+/// Barcode Scanner
+pub const APPEARANCE_BARCODE_SCANNER: u16 = 0x03c8;
+
+/// Touchpad
+pub const APPEARANCE_TOUCHPAD: u16 = 0x03c9;
+
+/// Presentation Remote
+pub const APPEARANCE_PRESENTATION_REMOTE: u16 = 0x03ca;
+
+/// Generic Glucose Meter
+pub const APPEARANCE_GENERIC_GLUCOSE_METER: u16 = 0x0400;
+
+/// Generic Running Walking Sensor
+pub const APPEARANCE_GENERIC_RUNNING_WALKING_SENSOR: u16 = 0x0440;
+
+/// In-Shoe Running Walking Sensor
+pub const APPEARANCE_IN_SHOE_RUNNING_WALKING_SENSOR: u16 = 0x0441;
+
+/// On-Shoe Running Walking Sensor
+pub const APPEARANCE_ON_SHOE_RUNNING_WALKING_SENSOR: u16 = 0x0442;
+
+/// On-Hip Running Walking Sensor
+pub const APPEARANCE_ON_HIP_RUNNING_WALKING_SENSOR: u16 = 0x0443;
+
+/// Generic Cycling
+pub const APPEARANCE_GENERIC_CYCLING: u16 = 0x0480;
+
+/// Cycling Computer
+pub const APPEARANCE_CYCLING_COMPUTER: u16 = 0x0481;
+
+/// Speed Sensor
+pub const APPEARANCE_SPEED_SENSOR: u16 = 0x0482;
+
+/// Cadence Sensor
+pub const APPEARANCE_CADENCE_SENSOR: u16 = 0x0483;
+
+/// Power Sensor
+pub const APPEARANCE_POWER_SENSOR: u16 = 0x0484;
+
+/// Speed and Cadence Sensor
+pub const APPEARANCE_SPEED_AND_CADENCE_SENSOR: u16 = 0x0485;
+
+/// Generic Control Device
+pub const APPEARANCE_GENERIC_CONTROL_DEVICE: u16 = 0x04c0;
+
+/// Switch
+pub const APPEARANCE_SWITCH: u16 = 0x04c1;
+
+/// Multi-switch
+pub const APPEARANCE_MULTI_SWITCH: u16 = 0x04c2;
+
+/// Button
+pub const APPEARANCE_BUTTON: u16 = 0x04c3;
+
+/// Slider
+pub const APPEARANCE_SLIDER: u16 = 0x04c4;
+
+/// Rotary Switch
+pub const APPEARANCE_ROTARY_SWITCH: u16 = 0x04c5;
+
+/// Touch Panel
+pub const APPEARANCE_TOUCH_PANEL: u16 = 0x04c6;
+
+/// Single Switch
+pub const APPEARANCE_SINGLE_SWITCH: u16 = 0x04c7;
+
+/// Double Switch
+pub const APPEARANCE_DOUBLE_SWITCH: u16 = 0x04c8;
+
+/// Triple Switch
+pub const APPEARANCE_TRIPLE_SWITCH: u16 = 0x04c9;
+
+/// Battery Switch
+pub const APPEARANCE_BATTERY_SWITCH: u16 = 0x04ca;
+
+/// Energy Harvesting Switch
+pub const APPEARANCE_ENERGY_HARVESTING_SWITCH: u16 = 0x04cb;
+
+/// Push Button
+pub const APPEARANCE_PUSH_BUTTON: u16 = 0x04cc;
+
+/// Dial
+pub const APPEARANCE_DIAL: u16 = 0x04cd;
+
+/// Generic Network Device
+pub const APPEARANCE_GENERIC_NETWORK_DEVICE: u16 = 0x0500;
+
+/// Access Point
+pub const APPEARANCE_ACCESS_POINT: u16 = 0x0501;
+
+/// Mesh Device
+pub const APPEARANCE_MESH_DEVICE: u16 = 0x0502;
+
+/// Mesh Network Proxy
+pub const APPEARANCE_MESH_NETWORK_PROXY: u16 = 0x0503;
+
+/// Generic Sensor
+pub const APPEARANCE_GENERIC_SENSOR: u16 = 0x0540;
+
+/// Motion Sensor
+pub const APPEARANCE_MOTION_SENSOR: u16 = 0x0541;
+
+/// Air quality Sensor
+pub const APPEARANCE_AIR_QUALITY_SENSOR: u16 = 0x0542;
+
+/// Temperature Sensor
+pub const APPEARANCE_TEMPERATURE_SENSOR: u16 = 0x0543;
+
+/// Humidity Sensor
+pub const APPEARANCE_HUMIDITY_SENSOR: u16 = 0x0544;
+
+/// Leak Sensor
+pub const APPEARANCE_LEAK_SENSOR: u16 = 0x0545;
+
+/// Smoke Sensor
+pub const APPEARANCE_SMOKE_SENSOR: u16 = 0x0546;
+
+/// Occupancy Sensor
+pub const APPEARANCE_OCCUPANCY_SENSOR: u16 = 0x0547;
+
+/// Contact Sensor
+pub const APPEARANCE_CONTACT_SENSOR: u16 = 0x0548;
+
+/// Carbon Monoxide Sensor
+pub const APPEARANCE_CARBON_MONOXIDE_SENSOR: u16 = 0x0549;
+
+/// Carbon Dioxide Sensor
+pub const APPEARANCE_CARBON_DIOXIDE_SENSOR: u16 = 0x054a;
+
+/// Ambient Light Sensor
+pub const APPEARANCE_AMBIENT_LIGHT_SENSOR: u16 = 0x054b;
+
+/// Energy Sensor
+pub const APPEARANCE_ENERGY_SENSOR: u16 = 0x054c;
+
+/// Color Light Sensor
+pub const APPEARANCE_COLOR_LIGHT_SENSOR: u16 = 0x054d;
+
+/// Rain Sensor
+pub const APPEARANCE_RAIN_SENSOR: u16 = 0x054e;
+
+/// Fire Sensor
+pub const APPEARANCE_FIRE_SENSOR: u16 = 0x054f;
+
+/// Wind Sensor
+pub const APPEARANCE_WIND_SENSOR: u16 = 0x0550;
+
+/// Proximity Sensor
+pub const APPEARANCE_PROXIMITY_SENSOR: u16 = 0x0551;
+
+/// Multi-Sensor
+pub const APPEARANCE_MULTI_SENSOR: u16 = 0x0552;
+
+/// Flush Mounted Sensor
+pub const APPEARANCE_FLUSH_MOUNTED_SENSOR: u16 = 0x0553;
+
+/// Ceiling Mounted Sensor
+pub const APPEARANCE_CEILING_MOUNTED_SENSOR: u16 = 0x0554;
+
+/// Wall Mounted Sensor
+pub const APPEARANCE_WALL_MOUNTED_SENSOR: u16 = 0x0555;
+
+/// Multisensor
+pub const APPEARANCE_MULTISENSOR: u16 = 0x0556;
+
+/// Energy Meter
+pub const APPEARANCE_ENERGY_METER: u16 = 0x0557;
+
+/// Flame Detector
+pub const APPEARANCE_FLAME_DETECTOR: u16 = 0x0558;
+
+/// Vehicle Tire Pressure Sensor
+pub const APPEARANCE_VEHICLE_TIRE_PRESSURE_SENSOR: u16 = 0x0559;
+
+/// Generic Light Fixtures
+pub const APPEARANCE_GENERIC_LIGHT_FIXTURES: u16 = 0x0580;
+
+/// Wall Light
+pub const APPEARANCE_WALL_LIGHT: u16 = 0x0581;
+
+/// Ceiling Light
+pub const APPEARANCE_CEILING_LIGHT: u16 = 0x0582;
+
+/// Floor Light
+pub const APPEARANCE_FLOOR_LIGHT: u16 = 0x0583;
+
+/// Cabinet Light
+pub const APPEARANCE_CABINET_LIGHT: u16 = 0x0584;
+
+/// Desk Light
+pub const APPEARANCE_DESK_LIGHT: u16 = 0x0585;
+
+/// Troffer Light
+pub const APPEARANCE_TROFFER_LIGHT: u16 = 0x0586;
+
+/// Pendant Light
+pub const APPEARANCE_PENDANT_LIGHT: u16 = 0x0587;
+
+/// In-ground Light
+pub const APPEARANCE_IN_GROUND_LIGHT: u16 = 0x0588;
+
+/// Flood Light
+pub const APPEARANCE_FLOOD_LIGHT: u16 = 0x0589;
+
+/// Underwater Light
+pub const APPEARANCE_UNDERWATER_LIGHT: u16 = 0x058a;
+
+/// Bollard with Light
+pub const APPEARANCE_BOLLARD_WITH_LIGHT: u16 = 0x058b;
+
+/// Pathway Light
+pub const APPEARANCE_PATHWAY_LIGHT: u16 = 0x058c;
+
+/// Garden Light
+pub const APPEARANCE_GARDEN_LIGHT: u16 = 0x058d;
+
+/// Pole-top Light
+pub const APPEARANCE_POLE_TOP_LIGHT: u16 = 0x058e;
+
+/// Spotlight
+pub const APPEARANCE_SPOTLIGHT: u16 = 0x058f;
+
+/// Linear Light
+pub const APPEARANCE_LINEAR_LIGHT: u16 = 0x0590;
+
+/// Street Light
+pub const APPEARANCE_STREET_LIGHT: u16 = 0x0591;
+
+/// Shelves Light
+pub const APPEARANCE_SHELVES_LIGHT: u16 = 0x0592;
+
+/// Bay Light
+pub const APPEARANCE_BAY_LIGHT: u16 = 0x0593;
+
+/// Emergency Exit Light
+pub const APPEARANCE_EMERGENCY_EXIT_LIGHT: u16 = 0x0594;
+
+/// Light Controller
+pub const APPEARANCE_LIGHT_CONTROLLER: u16 = 0x0595;
+
+/// Light Driver
+pub const APPEARANCE_LIGHT_DRIVER: u16 = 0x0596;
+
+/// Bulb
+pub const APPEARANCE_BULB: u16 = 0x0597;
+
+/// Low-bay Light
+pub const APPEARANCE_LOW_BAY_LIGHT: u16 = 0x0598;
+
+/// High-bay Light
+pub const APPEARANCE_HIGH_BAY_LIGHT: u16 = 0x0599;
+
+/// Generic Fan
+pub const APPEARANCE_GENERIC_FAN: u16 = 0x05c0;
+
+/// Ceiling Fan
+pub const APPEARANCE_CEILING_FAN: u16 = 0x05c1;
+
+/// Axial Fan
+pub const APPEARANCE_AXIAL_FAN: u16 = 0x05c2;
+
+/// Exhaust Fan
+pub const APPEARANCE_EXHAUST_FAN: u16 = 0x05c3;
+
+/// Pedestal Fan
+pub const APPEARANCE_PEDESTAL_FAN: u16 = 0x05c4;
+
+/// Desk Fan
+pub const APPEARANCE_DESK_FAN: u16 = 0x05c5;
+
+/// Wall Fan
+pub const APPEARANCE_WALL_FAN: u16 = 0x05c6;
+
+/// Generic HVAC
+pub const APPEARANCE_GENERIC_HVAC: u16 = 0x0600;
+
+/// Thermostat
+pub const APPEARANCE_THERMOSTAT: u16 = 0x0601;
+
+/// Humidifier
+pub const APPEARANCE_HUMIDIFIER: u16 = 0x0602;
+
+/// De-humidifier
+pub const APPEARANCE_DE_HUMIDIFIER: u16 = 0x0603;
+
+/// Heater
+pub const APPEARANCE_HEATER: u16 = 0x0604;
+
+/// Radiator
+pub const APPEARANCE_HVAC_RADIATOR: u16 = 0x0605;
+
+/// Boiler
+pub const APPEARANCE_HVAC_BOILER: u16 = 0x0606;
+
+/// Heat Pump
+pub const APPEARANCE_HVAC_HEAT_PUMP: u16 = 0x0607;
+
+/// Infrared Heater
+pub const APPEARANCE_HVAC_INFRARED_HEATER: u16 = 0x0608;
+
+/// Radiant Panel Heater
+pub const APPEARANCE_HVAC_RADIANT_PANEL_HEATER: u16 = 0x0609;
+
+/// Fan Heater
+pub const APPEARANCE_HVAC_FAN_HEATER: u16 = 0x060a;
+
+/// Air Curtain
+pub const APPEARANCE_HVAC_AIR_CURTAIN: u16 = 0x060b;
+
+/// Generic Air Conditioning
+pub const APPEARANCE_GENERIC_AIR_CONDITIONING: u16 = 0x0640;
+
+/// Generic Humidifier
+pub const APPEARANCE_GENERIC_HUMIDIFIER: u16 = 0x0680;
+
+/// Generic Heating
+pub const APPEARANCE_GENERIC_HEATING: u16 = 0x06c0;
+
+/// Radiator
+pub const APPEARANCE_HEATING_RADIATOR: u16 = 0x06c1;
+
+/// Boiler
+pub const APPEARANCE_HEATING_BOILER: u16 = 0x06c2;
+
+/// Heat Pump
+pub const APPEARANCE_HEATING_HEAT_PUMP: u16 = 0x06c3;
+
+/// Infrared Heater
+pub const APPEARANCE_HEATING_INFRARED_HEATER: u16 = 0x06c4;
+
+/// Radiant Panel Heater
+pub const APPEARANCE_HEATING_RADIANT_PANEL_HEATER: u16 = 0x06c5;
+
+/// Fan Heater
+pub const APPEARANCE_HEATING_FAN_HEATER: u16 = 0x06c6;
+
+/// Air Curtain
+pub const APPEARANCE_HEATING_AIR_CURTAIN: u16 = 0x06c7;
+
+/// Generic Access Control
+pub const APPEARANCE_GENERIC_ACCESS_CONTROL: u16 = 0x0700;
+
+/// Access Door
+pub const APPEARANCE_ACCESS_DOOR: u16 = 0x0701;
+
+/// Garage Door
+pub const APPEARANCE_GARAGE_DOOR: u16 = 0x0702;
+
+/// Emergency Exit Door
+pub const APPEARANCE_EMERGENCY_EXIT_DOOR: u16 = 0x0703;
+
+/// Access Lock
+pub const APPEARANCE_ACCESS_LOCK: u16 = 0x0704;
+
+/// Elevator
+pub const APPEARANCE_ELEVATOR: u16 = 0x0705;
+
+/// Window
+pub const APPEARANCE_WINDOW: u16 = 0x0706;
+
+/// Entrance Gate
+pub const APPEARANCE_ENTRANCE_GATE: u16 = 0x0707;
+
+/// Door Lock
+pub const APPEARANCE_DOOR_LOCK: u16 = 0x0708;
+
+/// Locker
+pub const APPEARANCE_LOCKER: u16 = 0x0709;
+
+/// Generic Motorized Device
+pub const APPEARANCE_GENERIC_MOTORIZED_DEVICE: u16 = 0x0740;
+
+/// Motorized Gate
+pub const APPEARANCE_MOTORIZED_GATE: u16 = 0x0741;
+
+/// Awning
+pub const APPEARANCE_AWNING: u16 = 0x0742;
+
+/// Blinds or Shades
+pub const APPEARANCE_BLINDS_OR_SHADES: u16 = 0x0743;
+
+/// Curtains
+pub const APPEARANCE_CURTAINS: u16 = 0x0744;
+
+/// Screen
+pub const APPEARANCE_SCREEN: u16 = 0x0745;
+
+/// Generic Power Device
+pub const APPEARANCE_GENERIC_POWER_DEVICE: u16 = 0x0780;
+
+/// Power Outlet
+pub const APPEARANCE_POWER_OUTLET: u16 = 0x0781;
+
+/// Power Strip
+pub const APPEARANCE_POWER_STRIP: u16 = 0x0782;
+
+/// Plug
+pub const APPEARANCE_PLUG: u16 = 0x0783;
+
+/// Power Supply
+pub const APPEARANCE_POWER_SUPPLY: u16 = 0x0784;
+
+/// LED Driver
+pub const APPEARANCE_LED_DRIVER: u16 = 0x0785;
+
+/// Fluorescent Lamp Gear
+pub const APPEARANCE_FLUORESCENT_LAMP_GEAR: u16 = 0x0786;
+
+/// HID Lamp Gear
+pub const APPEARANCE_HID_LAMP_GEAR: u16 = 0x0787;
+
+/// Charge Case
+pub const APPEARANCE_CHARGE_CASE: u16 = 0x0788;
+
+/// Power Bank
+pub const APPEARANCE_POWER_BANK: u16 = 0x0789;
+
+/// Generic Light Source
+pub const APPEARANCE_GENERIC_LIGHT_SOURCE: u16 = 0x07c0;
+
+/// Incandescent Light Bulb
+pub const APPEARANCE_INCANDESCENT_LIGHT_BULB: u16 = 0x07c1;
+
+/// LED Lamp
+pub const APPEARANCE_LED_LAMP: u16 = 0x07c2;
+
+/// HID Lamp
+pub const APPEARANCE_HID_LAMP: u16 = 0x07c3;
+
+/// Fluorescent Lamp
+pub const APPEARANCE_FLUORESCENT_LAMP: u16 = 0x07c4;
+
+/// LED Array
+pub const APPEARANCE_LED_ARRAY: u16 = 0x07c5;
+
+/// Multi-Color LED Array
+pub const APPEARANCE_MULTI_COLOR_LED_ARRAY: u16 = 0x07c6;
+
+/// Low voltage halogen
+pub const APPEARANCE_LOW_VOLTAGE_HALOGEN: u16 = 0x07c7;
+
+/// Organic light emitting diode (OLED)
+pub const APPEARANCE_ORGANIC_LIGHT_EMITTING_DIODE_OLED: u16 = 0x07c8;
+
+/// Generic Window Covering
+pub const APPEARANCE_GENERIC_WINDOW_COVERING: u16 = 0x0800;
+
+/// Window Shades
+pub const APPEARANCE_WINDOW_SHADES: u16 = 0x0801;
+
+/// Window Blinds
+pub const APPEARANCE_WINDOW_BLINDS: u16 = 0x0802;
+
+/// Window Awning
+pub const APPEARANCE_WINDOW_AWNING: u16 = 0x0803;
+
+/// Window Curtain
+pub const APPEARANCE_WINDOW_CURTAIN: u16 = 0x0804;
+
+/// Exterior Shutter
+pub const APPEARANCE_EXTERIOR_SHUTTER: u16 = 0x0805;
+
+/// Exterior Screen
+pub const APPEARANCE_EXTERIOR_SCREEN: u16 = 0x0806;
+
+/// Generic Audio Sink
+pub const APPEARANCE_GENERIC_AUDIO_SINK: u16 = 0x0840;
+
+/// Standalone Speaker
+pub const APPEARANCE_STANDALONE_SPEAKER: u16 = 0x0841;
+
+/// Soundbar
+pub const APPEARANCE_SOUNDBAR: u16 = 0x0842;
+
+/// Bookshelf Speaker
+pub const APPEARANCE_BOOKSHELF_SPEAKER: u16 = 0x0843;
+
+/// Standmounted Speaker
+pub const APPEARANCE_STANDMOUNTED_SPEAKER: u16 = 0x0844;
+
+/// Speakerphone
+pub const APPEARANCE_SPEAKERPHONE: u16 = 0x0845;
+
+/// Generic Audio Source
+pub const APPEARANCE_GENERIC_AUDIO_SOURCE: u16 = 0x0880;
+
+/// Microphone
+pub const APPEARANCE_MICROPHONE: u16 = 0x0881;
+
+/// Alarm
+pub const APPEARANCE_ALARM: u16 = 0x0882;
+
+/// Bell
+pub const APPEARANCE_BELL: u16 = 0x0883;
+
+/// Horn
+pub const APPEARANCE_HORN: u16 = 0x0884;
+
+/// Broadcasting Device
+pub const APPEARANCE_BROADCASTING_DEVICE: u16 = 0x0885;
+
+/// Service Desk
+pub const APPEARANCE_SERVICE_DESK: u16 = 0x0886;
+
+/// Kiosk
+pub const APPEARANCE_KIOSK: u16 = 0x0887;
+
+/// Broadcasting Room
+pub const APPEARANCE_BROADCASTING_ROOM: u16 = 0x0888;
+
+/// Auditorium
+pub const APPEARANCE_AUDITORIUM: u16 = 0x0889;
+
+/// Generic Motorized Vehicle
+pub const APPEARANCE_GENERIC_MOTORIZED_VEHICLE: u16 = 0x08c0;
+
+/// Car
+pub const APPEARANCE_CAR: u16 = 0x08c1;
+
+/// Large Goods Vehicle
+pub const APPEARANCE_LARGE_GOODS_VEHICLE: u16 = 0x08c2;
+
+/// 2-Wheeled Vehicle
+pub const APPEARANCE_TWO_WHEELED_VEHICLE: u16 = 0x08c3;
+
+/// Motorbike
+pub const APPEARANCE_MOTORBIKE: u16 = 0x08c4;
+
+/// Scooter
+pub const APPEARANCE_SCOOTER: u16 = 0x08c5;
+
+/// Moped
+pub const APPEARANCE_MOPED: u16 = 0x08c6;
+
+/// 3-Wheeled Vehicle
+pub const APPEARANCE_THREE_WHEELED_VEHICLE: u16 = 0x08c7;
+
+/// Light Vehicle
+pub const APPEARANCE_LIGHT_VEHICLE: u16 = 0x08c8;
+
+/// Quad Bike
+pub const APPEARANCE_QUAD_BIKE: u16 = 0x08c9;
+
+/// Minibus
+pub const APPEARANCE_MINIBUS: u16 = 0x08ca;
+
+/// Bus
+pub const APPEARANCE_BUS: u16 = 0x08cb;
+
+/// Trolley
+pub const APPEARANCE_TROLLEY: u16 = 0x08cc;
+
+/// Agricultural Vehicle
+pub const APPEARANCE_AGRICULTURAL_VEHICLE: u16 = 0x08cd;
+
+/// Camper / Caravan
+pub const APPEARANCE_CAMPER_CARAVAN: u16 = 0x08ce;
+
+/// Recreational Vehicle / Motor Home
+pub const APPEARANCE_RECREATIONAL_VEHICLE_MOTOR_HOME: u16 = 0x08cf;
+
+/// Generic Domestic Appliance
+pub const APPEARANCE_GENERIC_DOMESTIC_APPLIANCE: u16 = 0x0900;
+
+/// Refrigerator
+pub const APPEARANCE_REFRIGERATOR: u16 = 0x0901;
+
+/// Freezer
+pub const APPEARANCE_FREEZER: u16 = 0x0902;
+
+/// Oven
+pub const APPEARANCE_OVEN: u16 = 0x0903;
+
+/// Microwave
+pub const APPEARANCE_MICROWAVE: u16 = 0x0904;
+
+/// Toaster
+pub const APPEARANCE_TOASTER: u16 = 0x0905;
+
+/// Washing Machine
+pub const APPEARANCE_WASHING_MACHINE: u16 = 0x0906;
+
+/// Dryer
+pub const APPEARANCE_DRYER: u16 = 0x0907;
+
+/// Coffee maker
+pub const APPEARANCE_COFFEE_MAKER: u16 = 0x0908;
+
+/// Clothes iron
+pub const APPEARANCE_CLOTHES_IRON: u16 = 0x0909;
+
+/// Curling iron
+pub const APPEARANCE_CURLING_IRON: u16 = 0x090a;
+
+/// Hair dryer
+pub const APPEARANCE_HAIR_DRYER: u16 = 0x090b;
+
+/// Vacuum cleaner
+pub const APPEARANCE_VACUUM_CLEANER: u16 = 0x090c;
+
+/// Robotic vacuum cleaner
+pub const APPEARANCE_ROBOTIC_VACUUM_CLEANER: u16 = 0x090d;
+
+/// Rice cooker
+pub const APPEARANCE_RICE_COOKER: u16 = 0x090e;
+
+/// Clothes steamer
+pub const APPEARANCE_CLOTHES_STEAMER: u16 = 0x090f;
+
+/// Generic Wearable Audio Device
+pub const APPEARANCE_GENERIC_WEARABLE_AUDIO_DEVICE: u16 = 0x0940;
+
+/// Earbud
+pub const APPEARANCE_EARBUD: u16 = 0x0941;
+
+/// Headset
+pub const APPEARANCE_HEADSET: u16 = 0x0942;
+
+/// Headphones
+pub const APPEARANCE_HEADPHONES: u16 = 0x0943;
+
+/// Neck Band
+pub const APPEARANCE_NECK_BAND: u16 = 0x0944;
+
+/// Generic Aircraft
+pub const APPEARANCE_GENERIC_AIRCRAFT: u16 = 0x0980;
+
+/// Light Aircraft
+pub const APPEARANCE_LIGHT_AIRCRAFT: u16 = 0x0981;
+
+/// Microlight
+pub const APPEARANCE_MICROLIGHT: u16 = 0x0982;
+
+/// Paraglider
+pub const APPEARANCE_PARAGLIDER: u16 = 0x0983;
+
+/// Large Passenger Aircraft
+pub const APPEARANCE_LARGE_PASSENGER_AIRCRAFT: u16 = 0x0984;
+
+/// Generic AV Equipment
+pub const APPEARANCE_GENERIC_AV_EQUIPMENT: u16 = 0x09c0;
+
+/// Amplifier
+pub const APPEARANCE_AMPLIFIER: u16 = 0x09c1;
+
+/// Receiver
+pub const APPEARANCE_RECEIVER: u16 = 0x09c2;
+
+/// Radio
+pub const APPEARANCE_RADIO: u16 = 0x09c3;
+
+/// Tuner
+pub const APPEARANCE_TUNER: u16 = 0x09c4;
+
+/// Turntable
+pub const APPEARANCE_TURNTABLE: u16 = 0x09c5;
+
+/// CD Player
+pub const APPEARANCE_CD_PLAYER: u16 = 0x09c6;
+
+/// DVD Player
+pub const APPEARANCE_DVD_PLAYER: u16 = 0x09c7;
+
+/// Bluray Player
+pub const APPEARANCE_BLURAY_PLAYER: u16 = 0x09c8;
+
+/// Optical Disc Player
+pub const APPEARANCE_OPTICAL_DISC_PLAYER: u16 = 0x09c9;
+
+/// Set-Top Box
+pub const APPEARANCE_SET_TOP_BOX: u16 = 0x09ca;
+
+/// Generic Display Equipment
+pub const APPEARANCE_GENERIC_DISPLAY_EQUIPMENT: u16 = 0x0a00;
+
+/// Television
+pub const APPEARANCE_TELEVISION: u16 = 0x0a01;
+
+/// Monitor
+pub const APPEARANCE_MONITOR: u16 = 0x0a02;
+
+/// Projector
+pub const APPEARANCE_PROJECTOR: u16 = 0x0a03;
+
+/// Generic Hearing aid
+pub const APPEARANCE_GENERIC_HEARING_AID: u16 = 0x0a40;
+
+/// In-ear hearing aid
+pub const APPEARANCE_IN_EAR_HEARING_AID: u16 = 0x0a41;
+
+/// Behind-ear hearing aid
+pub const APPEARANCE_BEHIND_EAR_HEARING_AID: u16 = 0x0a42;
+
+/// Cochlear Implant
+pub const APPEARANCE_COCHLEAR_IMPLANT: u16 = 0x0a43;
+
+/// Generic Gaming
+pub const APPEARANCE_GENERIC_GAMING: u16 = 0x0a80;
+
+/// Home Video Game Console
+pub const APPEARANCE_HOME_VIDEO_GAME_CONSOLE: u16 = 0x0a81;
+
+/// Portable handheld console
+pub const APPEARANCE_PORTABLE_HANDHELD_CONSOLE: u16 = 0x0a82;
+
+/// Generic Signage
+pub const APPEARANCE_GENERIC_SIGNAGE: u16 = 0x0ac0;
+
+/// Digital Signage
+pub const APPEARANCE_DIGITAL_SIGNAGE: u16 = 0x0ac1;
+
+/// Electronic Label
+pub const APPEARANCE_ELECTRONIC_LABEL: u16 = 0x0ac2;
+
+/// Generic Pulse Oximeter
+pub const APPEARANCE_GENERIC_PULSE_OXIMETER: u16 = 0x0c40;
+
+/// Fingertip Pulse Oximeter
+pub const APPEARANCE_FINGERTIP_PULSE_OXIMETER: u16 = 0x0c41;
+
+/// Wrist Worn Pulse Oximeter
+pub const APPEARANCE_WRIST_WORN_PULSE_OXIMETER: u16 = 0x0c42;
+
+/// Generic Weight Scale
+pub const APPEARANCE_GENERIC_WEIGHT_SCALE: u16 = 0x0c80;
+
+/// Generic Personal Mobility Device
+pub const APPEARANCE_GENERIC_PERSONAL_MOBILITY_DEVICE: u16 = 0x0cc0;
+
+/// Powered Wheelchair
+pub const APPEARANCE_POWERED_WHEELCHAIR: u16 = 0x0cc1;
+
+/// Mobility Scooter
+pub const APPEARANCE_MOBILITY_SCOOTER: u16 = 0x0cc2;
+
+/// Generic Continuous Glucose Monitor
+pub const APPEARANCE_GENERIC_CONTINUOUS_GLUCOSE_MONITOR: u16 = 0x0d00;
+
+/// Generic Insulin Pump
+pub const APPEARANCE_GENERIC_INSULIN_PUMP: u16 = 0x0d40;
+
+/// Insulin Pump, durable pump
+pub const APPEARANCE_INSULIN_PUMP_DURABLE_PUMP: u16 = 0x0d41;
+
+/// Insulin Pump, patch pump
+pub const APPEARANCE_INSULIN_PUMP_PATCH_PUMP: u16 = 0x0d44;
+
+/// Insulin Pen
+pub const APPEARANCE_INSULIN_PEN: u16 = 0x0d48;
+
+/// Generic Medication Delivery
+pub const APPEARANCE_GENERIC_MEDICATION_DELIVERY: u16 = 0x0d80;
+
+/// Generic Spirometer
+pub const APPEARANCE_GENERIC_SPIROMETER: u16 = 0x0dc0;
+
+/// Handheld Spirometer
+pub const APPEARANCE_HANDHELD_SPIROMETER: u16 = 0x0dc1;
+
+/// Generic Outdoor Sports Activity
+pub const APPEARANCE_GENERIC_OUTDOOR_SPORTS_ACTIVITY: u16 = 0x1440;
+
+/// Location Display
+pub const APPEARANCE_LOCATION_DISPLAY: u16 = 0x1441;
+
+/// Location and Navigation Display
+pub const APPEARANCE_LOCATION_AND_NAVIGATION_DISPLAY: u16 = 0x1442;
+
+/// Location Pod
+pub const APPEARANCE_LOCATION_POD: u16 = 0x1443;
+
+/// Location and Navigation Pod
+pub const APPEARANCE_LOCATION_AND_NAVIGATION_POD: u16 = 0x1444;
+
+/// Generic Industrial Measurement Device
+pub const APPEARANCE_GENERIC_INDUSTRIAL_MEASUREMENT_DEVICE: u16 = 0x1480;
+
+/// Torque Testing Device
+pub const APPEARANCE_TORQUE_TESTING_DEVICE: u16 = 0x1481;
+
+/// Caliper
+pub const APPEARANCE_CALIPER: u16 = 0x1482;
+
+/// Dial Indicator
+pub const APPEARANCE_DIAL_INDICATOR: u16 = 0x1483;
+
+/// Micrometer
+pub const APPEARANCE_MICROMETER: u16 = 0x1484;
+
+/// Height Gauge
+pub const APPEARANCE_HEIGHT_GAUGE: u16 = 0x1485;
+
+/// Force Gauge
+pub const APPEARANCE_FORCE_GAUGE: u16 = 0x1486;
+
+/// Generic Industrial Tools
+pub const APPEARANCE_GENERIC_INDUSTRIAL_TOOLS: u16 = 0x14c0;
+
+/// Machine Tool Holder
+pub const APPEARANCE_MACHINE_TOOL_HOLDER: u16 = 0x14c1;
+
+/// Generic Clamping Device
+pub const APPEARANCE_GENERIC_CLAMPING_DEVICE: u16 = 0x14c2;
+
+/// Clamping Jaws/Jaw Chuck
+pub const APPEARANCE_CLAMPING_JAWS_JAW_CHUCK: u16 = 0x14c3;
+
+/// Clamping (Collet) Chuck
+pub const APPEARANCE_CLAMPING_COLLET_CHUCK: u16 = 0x14c4;
+
+/// Clamping Mandrel
+pub const APPEARANCE_CLAMPING_MANDREL: u16 = 0x14c5;
+
+/// Vise
+pub const APPEARANCE_VISE: u16 = 0x14c6;
+
+/// Zero-Point Clamping System
+pub const APPEARANCE_ZERO_POINT_CLAMPING_SYSTEM: u16 = 0x14c7;
+
+/// Torque Wrench
+pub const APPEARANCE_TORQUE_WRENCH: u16 = 0x14c8;
+
+/// Torque Screwdriver
+pub const APPEARANCE_TORQUE_SCREWDRIVER: u16 = 0x14c9;
+
+/// Appearance Category names.
+const APPEARANCE_CATEGORY_NAMES: [(u16, &str); 54] = [
+    (0x000, "Unknown"),
+    (0x001, "Phone"),
+    (0x002, "Computer"),
+    (0x003, "Watch"),
+    (0x004, "Clock"),
+    (0x005, "Display"),
+    (0x006, "Remote Control"),
+    (0x007, "Eye-glasses"),
+    (0x008, "Tag"),
+    (0x009, "Keyring"),
+    (0x00a, "Media Player"),
+    (0x00b, "Barcode Scanner"),
+    (0x00c, "Thermometer"),
+    (0x00d, "Heart Rate Sensor"),
+    (0x00e, "Blood Pressure"),
+    (0x00f, "Human Interface Device"),
+    (0x010, "Glucose Meter"),
+    (0x011, "Running Walking Sensor"),
+    (0x012, "Cycling"),
+    (0x013, "Control Device"),
+    (0x014, "Network Device"),
+    (0x015, "Sensor"),
+    (0x016, "Light Fixtures"),
+    (0x017, "Fan"),
+    (0x018, "HVAC"),
+    (0x019, "Air Conditioning"),
+    (0x01a, "Humidifier"),
+    (0x01b, "Heating"),
+    (0x01c, "Access Control"),
+    (0x01d, "Motorized Device"),
+    (0x01e, "Power Device"),
+    (0x01f, "Light Source"),
+    (0x020, "Window Covering"),
+    (0x021, "Audio Sink"),
+    (0x022, "Audio Source"),
+    (0x023, "Motorized Vehicle"),
+    (0x024, "Domestic Appliance"),
+    (0x025, "Wearable Audio Device"),
+    (0x026, "Aircraft"),
+    (0x027, "AV Equipment"),
+    (0x028, "Display Equipment"),
+    (0x029, "Hearing aid"),
+    (0x02a, "Gaming"),
+    (0x02b, "Signage"),
+    (0x031, "Pulse Oximeter"),
+    (0x032, "Weight Scale"),
+    (0x033, "Personal Mobility Device"),
+    (0x034, "Continuous Glucose Monitor"),
+    (0x035, "Insulin Pump"),
+    (0x036, "Medication Delivery"),
+    (0x037, "Spirometer"),
+    (0x051, "Outdoor Sports Activity"),
+    (0x052, "Industrial Measurement Device"),
+    (0x053, "Industrial Tools"),
+];
+
+/// Appearance Subcategory names.
+const APPEARANCE_SUB_CATEGORY_NAMES: [(u16, &str); 319] = [
+    (APPEARANCE_GENERIC_UNKNOWN, "Generic Unknown"),
+    (APPEARANCE_GENERIC_PHONE, "Generic Phone"),
+    (APPEARANCE_GENERIC_COMPUTER, "Generic Computer"),
+    (APPEARANCE_DESKTOP_WORKSTATION, "Desktop Workstation"),
+    (APPEARANCE_SERVER_CLASS_COMPUTER, "Server-class Computer"),
+    (APPEARANCE_LAPTOP, "Laptop"),
+    (
+        APPEARANCE_HANDHELD_PC_PDA_CLAMSHELL,
+        "Handheld PC/PDA (clamshell)",
+    ),
+    (APPEARANCE_PALM_SIZE_PC_PDA, "Palm-size PC/PDA"),
+    (
+        APPEARANCE_WEARABLE_COMPUTER_WATCH_SIZE,
+        "Wearable computer (watch size)",
+    ),
+    (APPEARANCE_TABLET, "Tablet"),
+    (APPEARANCE_DOCKING_STATION, "Docking Station"),
+    (APPEARANCE_ALL_IN_ONE, "All in One"),
+    (APPEARANCE_BLADE_SERVER, "Blade Server"),
+    (APPEARANCE_CONVERTIBLE, "Convertible"),
+    (APPEARANCE_DETACHABLE, "Detachable"),
+    (APPEARANCE_IOT_GATEWAY, "IoT Gateway"),
+    (APPEARANCE_MINI_PC, "Mini PC"),
+    (APPEARANCE_STICK_PC, "Stick PC"),
+    (APPEARANCE_GENERIC_WATCH, "Generic Watch"),
+    (APPEARANCE_SPORTS_WATCH, "Sports Watch"),
+    (APPEARANCE_SMARTWATCH, "Smartwatch"),
+    (APPEARANCE_GENERIC_CLOCK, "Generic Clock"),
+    (APPEARANCE_GENERIC_DISPLAY, "Generic Display"),
+    (APPEARANCE_GENERIC_REMOTE_CONTROL, "Generic Remote Control"),
+    (APPEARANCE_GENERIC_EYE_GLASSES, "Generic Eye-glasses"),
+    (APPEARANCE_GENERIC_TAG, "Generic Tag"),
+    (APPEARANCE_GENERIC_KEYRING, "Generic Keyring"),
+    (APPEARANCE_GENERIC_MEDIA_PLAYER, "Generic Media Player"),
+    (
+        APPEARANCE_GENERIC_BARCODE_SCANNER,
+        "Generic Barcode Scanner",
+    ),
+    (APPEARANCE_GENERIC_THERMOMETER, "Generic Thermometer"),
+    (APPEARANCE_EAR_THERMOMETER, "Ear Thermometer"),
+    (
+        APPEARANCE_GENERIC_HEART_RATE_SENSOR,
+        "Generic Heart Rate Sensor",
+    ),
+    (APPEARANCE_HEART_RATE_BELT, "Heart Rate Belt"),
+    (APPEARANCE_GENERIC_BLOOD_PRESSURE, "Generic Blood Pressure"),
+    (APPEARANCE_ARM_BLOOD_PRESSURE, "Arm Blood Pressure"),
+    (APPEARANCE_WRIST_BLOOD_PRESSURE, "Wrist Blood Pressure"),
+    (
+        APPEARANCE_GENERIC_HUMAN_INTERFACE_DEVICE,
+        "Generic Human Interface Device",
+    ),
+    (APPEARANCE_KEYBOARD, "Keyboard"),
+    (APPEARANCE_MOUSE, "Mouse"),
+    (APPEARANCE_JOYSTICK, "Joystick"),
+    (APPEARANCE_GAMEPAD, "Gamepad"),
+    (APPEARANCE_DIGITIZER_TABLET, "Digitizer Tablet"),
+    (APPEARANCE_CARD_READER, "Card Reader"),
+    (APPEARANCE_DIGITAL_PEN, "Digital Pen"),
+    (APPEARANCE_BARCODE_SCANNER, "Barcode Scanner"),
+    (APPEARANCE_TOUCHPAD, "Touchpad"),
+    (APPEARANCE_PRESENTATION_REMOTE, "Presentation Remote"),
+    (APPEARANCE_GENERIC_GLUCOSE_METER, "Generic Glucose Meter"),
+    (
+        APPEARANCE_GENERIC_RUNNING_WALKING_SENSOR,
+        "Generic Running Walking Sensor",
+    ),
+    (
+        APPEARANCE_IN_SHOE_RUNNING_WALKING_SENSOR,
+        "In-Shoe Running Walking Sensor",
+    ),
+    (
+        APPEARANCE_ON_SHOE_RUNNING_WALKING_SENSOR,
+        "On-Shoe Running Walking Sensor",
+    ),
+    (
+        APPEARANCE_ON_HIP_RUNNING_WALKING_SENSOR,
+        "On-Hip Running Walking Sensor",
+    ),
+    (APPEARANCE_GENERIC_CYCLING, "Generic Cycling"),
+    (APPEARANCE_CYCLING_COMPUTER, "Cycling Computer"),
+    (APPEARANCE_SPEED_SENSOR, "Speed Sensor"),
+    (APPEARANCE_CADENCE_SENSOR, "Cadence Sensor"),
+    (APPEARANCE_POWER_SENSOR, "Power Sensor"),
+    (
+        APPEARANCE_SPEED_AND_CADENCE_SENSOR,
+        "Speed and Cadence Sensor",
+    ),
+    (APPEARANCE_GENERIC_CONTROL_DEVICE, "Generic Control Device"),
+    (APPEARANCE_SWITCH, "Switch"),
+    (APPEARANCE_MULTI_SWITCH, "Multi-switch"),
+    (APPEARANCE_BUTTON, "Button"),
+    (APPEARANCE_SLIDER, "Slider"),
+    (APPEARANCE_ROTARY_SWITCH, "Rotary Switch"),
+    (APPEARANCE_TOUCH_PANEL, "Touch Panel"),
+    (APPEARANCE_SINGLE_SWITCH, "Single Switch"),
+    (APPEARANCE_DOUBLE_SWITCH, "Double Switch"),
+    (APPEARANCE_TRIPLE_SWITCH, "Triple Switch"),
+    (APPEARANCE_BATTERY_SWITCH, "Battery Switch"),
+    (
+        APPEARANCE_ENERGY_HARVESTING_SWITCH,
+        "Energy Harvesting Switch",
+    ),
+    (APPEARANCE_PUSH_BUTTON, "Push Button"),
+    (APPEARANCE_DIAL, "Dial"),
+    (APPEARANCE_GENERIC_NETWORK_DEVICE, "Generic Network Device"),
+    (APPEARANCE_ACCESS_POINT, "Access Point"),
+    (APPEARANCE_MESH_DEVICE, "Mesh Device"),
+    (APPEARANCE_MESH_NETWORK_PROXY, "Mesh Network Proxy"),
+    (APPEARANCE_GENERIC_SENSOR, "Generic Sensor"),
+    (APPEARANCE_MOTION_SENSOR, "Motion Sensor"),
+    (APPEARANCE_AIR_QUALITY_SENSOR, "Air quality Sensor"),
+    (APPEARANCE_TEMPERATURE_SENSOR, "Temperature Sensor"),
+    (APPEARANCE_HUMIDITY_SENSOR, "Humidity Sensor"),
+    (APPEARANCE_LEAK_SENSOR, "Leak Sensor"),
+    (APPEARANCE_SMOKE_SENSOR, "Smoke Sensor"),
+    (APPEARANCE_OCCUPANCY_SENSOR, "Occupancy Sensor"),
+    (APPEARANCE_CONTACT_SENSOR, "Contact Sensor"),
+    (APPEARANCE_CARBON_MONOXIDE_SENSOR, "Carbon Monoxide Sensor"),
+    (APPEARANCE_CARBON_DIOXIDE_SENSOR, "Carbon Dioxide Sensor"),
+    (APPEARANCE_AMBIENT_LIGHT_SENSOR, "Ambient Light Sensor"),
+    (APPEARANCE_ENERGY_SENSOR, "Energy Sensor"),
+    (APPEARANCE_COLOR_LIGHT_SENSOR, "Color Light Sensor"),
+    (APPEARANCE_RAIN_SENSOR, "Rain Sensor"),
+    (APPEARANCE_FIRE_SENSOR, "Fire Sensor"),
+    (APPEARANCE_WIND_SENSOR, "Wind Sensor"),
+    (APPEARANCE_PROXIMITY_SENSOR, "Proximity Sensor"),
+    (APPEARANCE_MULTI_SENSOR, "Multi-Sensor"),
+    (APPEARANCE_FLUSH_MOUNTED_SENSOR, "Flush Mounted Sensor"),
+    (APPEARANCE_CEILING_MOUNTED_SENSOR, "Ceiling Mounted Sensor"),
+    (APPEARANCE_WALL_MOUNTED_SENSOR, "Wall Mounted Sensor"),
+    (APPEARANCE_MULTISENSOR, "Multisensor"),
+    (APPEARANCE_ENERGY_METER, "Energy Meter"),
+    (APPEARANCE_FLAME_DETECTOR, "Flame Detector"),
+    (
+        APPEARANCE_VEHICLE_TIRE_PRESSURE_SENSOR,
+        "Vehicle Tire Pressure Sensor",
+    ),
+    (APPEARANCE_GENERIC_LIGHT_FIXTURES, "Generic Light Fixtures"),
+    (APPEARANCE_WALL_LIGHT, "Wall Light"),
+    (APPEARANCE_CEILING_LIGHT, "Ceiling Light"),
+    (APPEARANCE_FLOOR_LIGHT, "Floor Light"),
+    (APPEARANCE_CABINET_LIGHT, "Cabinet Light"),
+    (APPEARANCE_DESK_LIGHT, "Desk Light"),
+    (APPEARANCE_TROFFER_LIGHT, "Troffer Light"),
+    (APPEARANCE_PENDANT_LIGHT, "Pendant Light"),
+    (APPEARANCE_IN_GROUND_LIGHT, "In-ground Light"),
+    (APPEARANCE_FLOOD_LIGHT, "Flood Light"),
+    (APPEARANCE_UNDERWATER_LIGHT, "Underwater Light"),
+    (APPEARANCE_BOLLARD_WITH_LIGHT, "Bollard with Light"),
+    (APPEARANCE_PATHWAY_LIGHT, "Pathway Light"),
+    (APPEARANCE_GARDEN_LIGHT, "Garden Light"),
+    (APPEARANCE_POLE_TOP_LIGHT, "Pole-top Light"),
+    (APPEARANCE_SPOTLIGHT, "Spotlight"),
+    (APPEARANCE_LINEAR_LIGHT, "Linear Light"),
+    (APPEARANCE_STREET_LIGHT, "Street Light"),
+    (APPEARANCE_SHELVES_LIGHT, "Shelves Light"),
+    (APPEARANCE_BAY_LIGHT, "Bay Light"),
+    (APPEARANCE_EMERGENCY_EXIT_LIGHT, "Emergency Exit Light"),
+    (APPEARANCE_LIGHT_CONTROLLER, "Light Controller"),
+    (APPEARANCE_LIGHT_DRIVER, "Light Driver"),
+    (APPEARANCE_BULB, "Bulb"),
+    (APPEARANCE_LOW_BAY_LIGHT, "Low-bay Light"),
+    (APPEARANCE_HIGH_BAY_LIGHT, "High-bay Light"),
+    (APPEARANCE_GENERIC_FAN, "Generic Fan"),
+    (APPEARANCE_CEILING_FAN, "Ceiling Fan"),
+    (APPEARANCE_AXIAL_FAN, "Axial Fan"),
+    (APPEARANCE_EXHAUST_FAN, "Exhaust Fan"),
+    (APPEARANCE_PEDESTAL_FAN, "Pedestal Fan"),
+    (APPEARANCE_DESK_FAN, "Desk Fan"),
+    (APPEARANCE_WALL_FAN, "Wall Fan"),
+    (APPEARANCE_GENERIC_HVAC, "Generic HVAC"),
+    (APPEARANCE_THERMOSTAT, "Thermostat"),
+    (APPEARANCE_HUMIDIFIER, "Humidifier"),
+    (APPEARANCE_DE_HUMIDIFIER, "De-humidifier"),
+    (APPEARANCE_HEATER, "Heater"),
+    (APPEARANCE_HVAC_RADIATOR, "Radiator"),
+    (APPEARANCE_HVAC_BOILER, "Boiler"),
+    (APPEARANCE_HVAC_HEAT_PUMP, "Heat Pump"),
+    (APPEARANCE_HVAC_INFRARED_HEATER, "Infrared Heater"),
+    (APPEARANCE_HVAC_RADIANT_PANEL_HEATER, "Radiant Panel Heater"),
+    (APPEARANCE_HVAC_FAN_HEATER, "Fan Heater"),
+    (APPEARANCE_HVAC_AIR_CURTAIN, "Air Curtain"),
+    (
+        APPEARANCE_GENERIC_AIR_CONDITIONING,
+        "Generic Air Conditioning",
+    ),
+    (APPEARANCE_GENERIC_HUMIDIFIER, "Generic Humidifier"),
+    (APPEARANCE_GENERIC_HEATING, "Generic Heating"),
+    (APPEARANCE_HEATING_RADIATOR, "Radiator"),
+    (APPEARANCE_HEATING_BOILER, "Boiler"),
+    (APPEARANCE_HEATING_HEAT_PUMP, "Heat Pump"),
+    (APPEARANCE_HEATING_INFRARED_HEATER, "Infrared Heater"),
+    (
+        APPEARANCE_HEATING_RADIANT_PANEL_HEATER,
+        "Radiant Panel Heater",
+    ),
+    (APPEARANCE_HEATING_FAN_HEATER, "Fan Heater"),
+    (APPEARANCE_HEATING_AIR_CURTAIN, "Air Curtain"),
+    (APPEARANCE_GENERIC_ACCESS_CONTROL, "Generic Access Control"),
+    (APPEARANCE_ACCESS_DOOR, "Access Door"),
+    (APPEARANCE_GARAGE_DOOR, "Garage Door"),
+    (APPEARANCE_EMERGENCY_EXIT_DOOR, "Emergency Exit Door"),
+    (APPEARANCE_ACCESS_LOCK, "Access Lock"),
+    (APPEARANCE_ELEVATOR, "Elevator"),
+    (APPEARANCE_WINDOW, "Window"),
+    (APPEARANCE_ENTRANCE_GATE, "Entrance Gate"),
+    (APPEARANCE_DOOR_LOCK, "Door Lock"),
+    (APPEARANCE_LOCKER, "Locker"),
+    (
+        APPEARANCE_GENERIC_MOTORIZED_DEVICE,
+        "Generic Motorized Device",
+    ),
+    (APPEARANCE_MOTORIZED_GATE, "Motorized Gate"),
+    (APPEARANCE_AWNING, "Awning"),
+    (APPEARANCE_BLINDS_OR_SHADES, "Blinds or Shades"),
+    (APPEARANCE_CURTAINS, "Curtains"),
+    (APPEARANCE_SCREEN, "Screen"),
+    (APPEARANCE_GENERIC_POWER_DEVICE, "Generic Power Device"),
+    (APPEARANCE_POWER_OUTLET, "Power Outlet"),
+    (APPEARANCE_POWER_STRIP, "Power Strip"),
+    (APPEARANCE_PLUG, "Plug"),
+    (APPEARANCE_POWER_SUPPLY, "Power Supply"),
+    (APPEARANCE_LED_DRIVER, "LED Driver"),
+    (APPEARANCE_FLUORESCENT_LAMP_GEAR, "Fluorescent Lamp Gear"),
+    (APPEARANCE_HID_LAMP_GEAR, "HID Lamp Gear"),
+    (APPEARANCE_CHARGE_CASE, "Charge Case"),
+    (APPEARANCE_POWER_BANK, "Power Bank"),
+    (APPEARANCE_GENERIC_LIGHT_SOURCE, "Generic Light Source"),
+    (
+        APPEARANCE_INCANDESCENT_LIGHT_BULB,
+        "Incandescent Light Bulb",
+    ),
+    (APPEARANCE_LED_LAMP, "LED Lamp"),
+    (APPEARANCE_HID_LAMP, "HID Lamp"),
+    (APPEARANCE_FLUORESCENT_LAMP, "Fluorescent Lamp"),
+    (APPEARANCE_LED_ARRAY, "LED Array"),
+    (APPEARANCE_MULTI_COLOR_LED_ARRAY, "Multi-Color LED Array"),
+    (APPEARANCE_LOW_VOLTAGE_HALOGEN, "Low voltage halogen"),
+    (
+        APPEARANCE_ORGANIC_LIGHT_EMITTING_DIODE_OLED,
+        "Organic light emitting diode (OLED)",
+    ),
+    (
+        APPEARANCE_GENERIC_WINDOW_COVERING,
+        "Generic Window Covering",
+    ),
+    (APPEARANCE_WINDOW_SHADES, "Window Shades"),
+    (APPEARANCE_WINDOW_BLINDS, "Window Blinds"),
+    (APPEARANCE_WINDOW_AWNING, "Window Awning"),
+    (APPEARANCE_WINDOW_CURTAIN, "Window Curtain"),
+    (APPEARANCE_EXTERIOR_SHUTTER, "Exterior Shutter"),
+    (APPEARANCE_EXTERIOR_SCREEN, "Exterior Screen"),
+    (APPEARANCE_GENERIC_AUDIO_SINK, "Generic Audio Sink"),
+    (APPEARANCE_STANDALONE_SPEAKER, "Standalone Speaker"),
+    (APPEARANCE_SOUNDBAR, "Soundbar"),
+    (APPEARANCE_BOOKSHELF_SPEAKER, "Bookshelf Speaker"),
+    (APPEARANCE_STANDMOUNTED_SPEAKER, "Standmounted Speaker"),
+    (APPEARANCE_SPEAKERPHONE, "Speakerphone"),
+    (APPEARANCE_GENERIC_AUDIO_SOURCE, "Generic Audio Source"),
+    (APPEARANCE_MICROPHONE, "Microphone"),
+    (APPEARANCE_ALARM, "Alarm"),
+    (APPEARANCE_BELL, "Bell"),
+    (APPEARANCE_HORN, "Horn"),
+    (APPEARANCE_BROADCASTING_DEVICE, "Broadcasting Device"),
+    (APPEARANCE_SERVICE_DESK, "Service Desk"),
+    (APPEARANCE_KIOSK, "Kiosk"),
+    (APPEARANCE_BROADCASTING_ROOM, "Broadcasting Room"),
+    (APPEARANCE_AUDITORIUM, "Auditorium"),
+    (
+        APPEARANCE_GENERIC_MOTORIZED_VEHICLE,
+        "Generic Motorized Vehicle",
+    ),
+    (APPEARANCE_CAR, "Car"),
+    (APPEARANCE_LARGE_GOODS_VEHICLE, "Large Goods Vehicle"),
+    (APPEARANCE_TWO_WHEELED_VEHICLE, "2-Wheeled Vehicle"),
+    (APPEARANCE_MOTORBIKE, "Motorbike"),
+    (APPEARANCE_SCOOTER, "Scooter"),
+    (APPEARANCE_MOPED, "Moped"),
+    (APPEARANCE_THREE_WHEELED_VEHICLE, "3-Wheeled Vehicle"),
+    (APPEARANCE_LIGHT_VEHICLE, "Light Vehicle"),
+    (APPEARANCE_QUAD_BIKE, "Quad Bike"),
+    (APPEARANCE_MINIBUS, "Minibus"),
+    (APPEARANCE_BUS, "Bus"),
+    (APPEARANCE_TROLLEY, "Trolley"),
+    (APPEARANCE_AGRICULTURAL_VEHICLE, "Agricultural Vehicle"),
+    (APPEARANCE_CAMPER_CARAVAN, "Camper / Caravan"),
+    (
+        APPEARANCE_RECREATIONAL_VEHICLE_MOTOR_HOME,
+        "Recreational Vehicle / Motor Home",
+    ),
+    (
+        APPEARANCE_GENERIC_DOMESTIC_APPLIANCE,
+        "Generic Domestic Appliance",
+    ),
+    (APPEARANCE_REFRIGERATOR, "Refrigerator"),
+    (APPEARANCE_FREEZER, "Freezer"),
+    (APPEARANCE_OVEN, "Oven"),
+    (APPEARANCE_MICROWAVE, "Microwave"),
+    (APPEARANCE_TOASTER, "Toaster"),
+    (APPEARANCE_WASHING_MACHINE, "Washing Machine"),
+    (APPEARANCE_DRYER, "Dryer"),
+    (APPEARANCE_COFFEE_MAKER, "Coffee maker"),
+    (APPEARANCE_CLOTHES_IRON, "Clothes iron"),
+    (APPEARANCE_CURLING_IRON, "Curling iron"),
+    (APPEARANCE_HAIR_DRYER, "Hair dryer"),
+    (APPEARANCE_VACUUM_CLEANER, "Vacuum cleaner"),
+    (APPEARANCE_ROBOTIC_VACUUM_CLEANER, "Robotic vacuum cleaner"),
+    (APPEARANCE_RICE_COOKER, "Rice cooker"),
+    (APPEARANCE_CLOTHES_STEAMER, "Clothes steamer"),
+    (
+        APPEARANCE_GENERIC_WEARABLE_AUDIO_DEVICE,
+        "Generic Wearable Audio Device",
+    ),
+    (APPEARANCE_EARBUD, "Earbud"),
+    (APPEARANCE_HEADSET, "Headset"),
+    (APPEARANCE_HEADPHONES, "Headphones"),
+    (APPEARANCE_NECK_BAND, "Neck Band"),
+    (APPEARANCE_GENERIC_AIRCRAFT, "Generic Aircraft"),
+    (APPEARANCE_LIGHT_AIRCRAFT, "Light Aircraft"),
+    (APPEARANCE_MICROLIGHT, "Microlight"),
+    (APPEARANCE_PARAGLIDER, "Paraglider"),
+    (
+        APPEARANCE_LARGE_PASSENGER_AIRCRAFT,
+        "Large Passenger Aircraft",
+    ),
+    (APPEARANCE_GENERIC_AV_EQUIPMENT, "Generic AV Equipment"),
+    (APPEARANCE_AMPLIFIER, "Amplifier"),
+    (APPEARANCE_RECEIVER, "Receiver"),
+    (APPEARANCE_RADIO, "Radio"),
+    (APPEARANCE_TUNER, "Tuner"),
+    (APPEARANCE_TURNTABLE, "Turntable"),
+    (APPEARANCE_CD_PLAYER, "CD Player"),
+    (APPEARANCE_DVD_PLAYER, "DVD Player"),
+    (APPEARANCE_BLURAY_PLAYER, "Bluray Player"),
+    (APPEARANCE_OPTICAL_DISC_PLAYER, "Optical Disc Player"),
+    (APPEARANCE_SET_TOP_BOX, "Set-Top Box"),
+    (
+        APPEARANCE_GENERIC_DISPLAY_EQUIPMENT,
+        "Generic Display Equipment",
+    ),
+    (APPEARANCE_TELEVISION, "Television"),
+    (APPEARANCE_MONITOR, "Monitor"),
+    (APPEARANCE_PROJECTOR, "Projector"),
+    (APPEARANCE_GENERIC_HEARING_AID, "Generic Hearing aid"),
+    (APPEARANCE_IN_EAR_HEARING_AID, "In-ear hearing aid"),
+    (APPEARANCE_BEHIND_EAR_HEARING_AID, "Behind-ear hearing aid"),
+    (APPEARANCE_COCHLEAR_IMPLANT, "Cochlear Implant"),
+    (APPEARANCE_GENERIC_GAMING, "Generic Gaming"),
+    (
+        APPEARANCE_HOME_VIDEO_GAME_CONSOLE,
+        "Home Video Game Console",
+    ),
+    (
+        APPEARANCE_PORTABLE_HANDHELD_CONSOLE,
+        "Portable handheld console",
+    ),
+    (APPEARANCE_GENERIC_SIGNAGE, "Generic Signage"),
+    (APPEARANCE_DIGITAL_SIGNAGE, "Digital Signage"),
+    (APPEARANCE_ELECTRONIC_LABEL, "Electronic Label"),
+    (APPEARANCE_GENERIC_PULSE_OXIMETER, "Generic Pulse Oximeter"),
+    (
+        APPEARANCE_FINGERTIP_PULSE_OXIMETER,
+        "Fingertip Pulse Oximeter",
+    ),
+    (
+        APPEARANCE_WRIST_WORN_PULSE_OXIMETER,
+        "Wrist Worn Pulse Oximeter",
+    ),
+    (APPEARANCE_GENERIC_WEIGHT_SCALE, "Generic Weight Scale"),
+    (
+        APPEARANCE_GENERIC_PERSONAL_MOBILITY_DEVICE,
+        "Generic Personal Mobility Device",
+    ),
+    (APPEARANCE_POWERED_WHEELCHAIR, "Powered Wheelchair"),
+    (APPEARANCE_MOBILITY_SCOOTER, "Mobility Scooter"),
+    (
+        APPEARANCE_GENERIC_CONTINUOUS_GLUCOSE_MONITOR,
+        "Generic Continuous Glucose Monitor",
+    ),
+    (APPEARANCE_GENERIC_INSULIN_PUMP, "Generic Insulin Pump"),
+    (
+        APPEARANCE_INSULIN_PUMP_DURABLE_PUMP,
+        "Insulin Pump, durable pump",
+    ),
+    (
+        APPEARANCE_INSULIN_PUMP_PATCH_PUMP,
+        "Insulin Pump, patch pump",
+    ),
+    (APPEARANCE_INSULIN_PEN, "Insulin Pen"),
+    (
+        APPEARANCE_GENERIC_MEDICATION_DELIVERY,
+        "Generic Medication Delivery",
+    ),
+    (APPEARANCE_GENERIC_SPIROMETER, "Generic Spirometer"),
+    (APPEARANCE_HANDHELD_SPIROMETER, "Handheld Spirometer"),
+    (
+        APPEARANCE_GENERIC_OUTDOOR_SPORTS_ACTIVITY,
+        "Generic Outdoor Sports Activity",
+    ),
+    (APPEARANCE_LOCATION_DISPLAY, "Location Display"),
+    (
+        APPEARANCE_LOCATION_AND_NAVIGATION_DISPLAY,
+        "Location and Navigation Display",
+    ),
+    (APPEARANCE_LOCATION_POD, "Location Pod"),
+    (
+        APPEARANCE_LOCATION_AND_NAVIGATION_POD,
+        "Location and Navigation Pod",
+    ),
+    (
+        APPEARANCE_GENERIC_INDUSTRIAL_MEASUREMENT_DEVICE,
+        "Generic Industrial Measurement Device",
+    ),
+    (APPEARANCE_TORQUE_TESTING_DEVICE, "Torque Testing Device"),
+    (APPEARANCE_CALIPER, "Caliper"),
+    (APPEARANCE_DIAL_INDICATOR, "Dial Indicator"),
+    (APPEARANCE_MICROMETER, "Micrometer"),
+    (APPEARANCE_HEIGHT_GAUGE, "Height Gauge"),
+    (APPEARANCE_FORCE_GAUGE, "Force Gauge"),
+    (
+        APPEARANCE_GENERIC_INDUSTRIAL_TOOLS,
+        "Generic Industrial Tools",
+    ),
+    (APPEARANCE_MACHINE_TOOL_HOLDER, "Machine Tool Holder"),
+    (
+        APPEARANCE_GENERIC_CLAMPING_DEVICE,
+        "Generic Clamping Device",
+    ),
+    (
+        APPEARANCE_CLAMPING_JAWS_JAW_CHUCK,
+        "Clamping Jaws/Jaw Chuck",
+    ),
+    (APPEARANCE_CLAMPING_COLLET_CHUCK, "Clamping (Collet) Chuck"),
+    (APPEARANCE_CLAMPING_MANDREL, "Clamping Mandrel"),
+    (APPEARANCE_VISE, "Vise"),
+    (
+        APPEARANCE_ZERO_POINT_CLAMPING_SYSTEM,
+        "Zero-Point Clamping System",
+    ),
+    (APPEARANCE_TORQUE_WRENCH, "Torque Wrench"),
+    (APPEARANCE_TORQUE_SCREWDRIVER, "Torque Screwdriver"),
+];
+
+impl TryFrom<&[u8]> for Appearance {
     type Error = String;
-    /// Create [`Appearance`] from [`Vec<u8>`].
+    /// Create [`Appearance`] from `u8` slice.
     ///
     /// # Examples
     ///
@@ -90,26 +1674,26 @@ impl TryFrom<&Vec<u8>> for Appearance {
     /// data.push(length);
     /// data.push(Appearance::data_type());
     /// data.append(&mut appearance.to_le_bytes().to_vec());
-    /// let result = Appearance::try_from(&data);
+    /// let result = Appearance::try_from(data.as_slice());
     /// assert!(result.is_ok());
     /// let data_type = result.unwrap();
     /// assert_eq!(length, data_type.length);
     /// assert_eq!(appearance, data_type.appearance);
     ///
     /// let data: Vec<u8> = Vec::new();
-    /// let result = Appearance::try_from(&data);
+    /// let result = Appearance::try_from(data.as_slice());
     /// assert!(result.is_err());
     /// assert_eq!(
     ///     format!("Invalid data size :{}", data.len()),
     ///     result.unwrap_err()
     /// );
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+    fn try_from(value: &[u8]) -> Result<Self, String> {
         let len = value.len();
         if len < 4 {
             return Err(format!("Invalid data size :{}", len).to_string());
         }
-        let length = value[0];
+        let length = validate_length(value, 3, 3)?;
         Ok(Self {
             length,
             appearance: u16::from_le_bytes(value[2..4].try_into().unwrap()),
@@ -117,6 +1701,40 @@ impl TryFrom<&Vec<u8>> for Appearance {
     }
 }
 
+impl TryFrom<&Vec<u8>> for Appearance {
+    type Error = String;
+    /// Create [`Appearance`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{appearance::Appearance, data_type::DataType};
+    ///
+    /// let appearance: u16 = 0x1444;
+    /// let length = 3;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(Appearance::data_type());
+    /// data.append(&mut appearance.to_le_bytes().to_vec());
+    /// let result = Appearance::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert_eq!(appearance, data_type.appearance);
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = Appearance::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        Self::try_from(value.as_slice())
+    }
+}
+
 impl Into<Vec<u8>> for Appearance {
     /// Create [`Vec<u8>`] from [`Appearance`].
     ///
@@ -151,6 +1769,28 @@ impl Into<Vec<u8>> for Appearance {
     }
 }
 
+impl fmt::Display for Appearance {
+    /// Format [`Appearance`] with assigned name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::appearance::{Appearance, APPEARANCE_HEART_RATE_BELT};
+    ///
+    /// let result = Appearance::new(APPEARANCE_HEART_RATE_BELT);
+    /// assert_eq!("Heart Rate Sensor: Heart Rate Belt", result.to_string());
+    ///
+    /// let result = Appearance::new(0x0345);
+    /// assert_eq!("Unknown Appearance (0x0345)", result.to_string());
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.name() {
+            Some(name) => write!(f, "{}", name),
+            None => write!(f, "Unknown Appearance (0x{:04x})", self.appearance),
+        }
+    }
+}
+
 impl DataType for Appearance {
     /// return `0x19`.
     ///
@@ -177,13 +1817,18 @@ impl DataType for Appearance {
 /// assert!(is_appearance(0x19));
 /// assert!(!is_appearance(0x00));
 /// ```
+#[deprecated(note = "use `AdType::of(data_type).matches::<Appearance>()` instead")]
 pub fn is_appearance(data_type: u8) -> bool {
-    Appearance::data_type() == data_type
+    AdType::of(data_type).matches::<Appearance>()
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::data_types::{appearance::*, data_type::DataType};
+    use crate::data_types::{
+        appearance::*,
+        data_type::DataType,
+        data_type_parser::{DataTypeParseResult, DataTypeParseResults},
+    };
 
     #[test]
     fn test_new() {
@@ -193,6 +1838,44 @@ mod tests {
         assert_eq!(appearance, result.appearance);
     }
 
+    #[test]
+    fn test_new_const() {
+        static APPEARANCE: Appearance = Appearance::new(0x0341);
+        assert_eq!(Appearance::new(0x0341), APPEARANCE);
+    }
+
+    #[test]
+    fn test_try_new() {
+        assert_eq!(
+            Ok(Appearance::new(APPEARANCE_GENERIC_UNKNOWN)),
+            Appearance::try_new(APPEARANCE_GENERIC_UNKNOWN)
+        );
+        assert_eq!(
+            Ok(Appearance::new(APPEARANCE_HEART_RATE_BELT)),
+            Appearance::try_new(APPEARANCE_HEART_RATE_BELT)
+        );
+        assert_eq!(
+            Err("Unassigned appearance :0x0345".to_string()),
+            Appearance::try_new(0x0345)
+        );
+        assert_eq!(
+            Err("Unassigned appearance :0xffff".to_string()),
+            Appearance::try_new(0xffff)
+        );
+    }
+
+    #[test]
+    fn test_from_category_subcategory() {
+        let result = Appearance::from_category_subcategory(0x00d, 0x01);
+        assert_eq!(3, result.length);
+        assert_eq!(APPEARANCE_HEART_RATE_BELT, result.appearance);
+        assert_eq!(0x00d, result.category());
+        assert_eq!(0x01, result.sub_category());
+
+        let result = Appearance::from_category_subcategory(0x051, 0x04);
+        assert_eq!(APPEARANCE_LOCATION_AND_NAVIGATION_POD, result.appearance);
+    }
+
     #[test]
     fn test_category() {
         let appearance: u16 = 0x1444;
@@ -219,6 +1902,59 @@ mod tests {
         assert_eq!(0x04, result.unwrap().sub_category());
     }
 
+    #[test]
+    fn test_category_name() {
+        let result = Appearance::new(APPEARANCE_HEART_RATE_BELT);
+        assert_eq!(Some("Heart Rate Sensor"), result.category_name());
+
+        let result = Appearance::new(APPEARANCE_GENERIC_UNKNOWN);
+        assert_eq!(Some("Unknown"), result.category_name());
+
+        let result = Appearance::new(0xffff);
+        assert_eq!(None, result.category_name());
+    }
+
+    #[test]
+    fn test_sub_category_name() {
+        let result = Appearance::new(APPEARANCE_HEART_RATE_BELT);
+        assert_eq!(Some("Heart Rate Belt"), result.sub_category_name());
+
+        let result = Appearance::new(0xffff);
+        assert_eq!(None, result.sub_category_name());
+    }
+
+    #[test]
+    fn test_is_assigned() {
+        assert!(Appearance::new(APPEARANCE_GENERIC_UNKNOWN).is_assigned());
+        assert!(Appearance::new(APPEARANCE_HEART_RATE_BELT).is_assigned());
+        assert!(!Appearance::new(0x0345).is_assigned());
+        assert!(!Appearance::new(0xffff).is_assigned());
+    }
+
+    #[test]
+    fn test_name() {
+        let result = Appearance::new(APPEARANCE_HEART_RATE_BELT);
+        assert_eq!(
+            Some("Heart Rate Sensor: Heart Rate Belt".to_string()),
+            result.name()
+        );
+
+        let result = Appearance::new(APPEARANCE_SPORTS_WATCH);
+        assert_eq!(Some("Watch: Sports Watch".to_string()), result.name());
+
+        let result = Appearance::new(0x0345);
+        assert_eq!(None, result.name());
+    }
+
+    #[test]
+    fn test_display() {
+        let result = Appearance::new(APPEARANCE_HEART_RATE_BELT);
+        assert_eq!("Heart Rate Sensor: Heart Rate Belt", result.to_string());
+
+        let result = Appearance::new(0x0345);
+        assert_eq!("Unknown Appearance (0x0345)", result.to_string());
+    }
+
     #[test]
     fn test_try_from() {
         let appearance: u16 = 0x1444;
@@ -243,6 +1979,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_try_from_with_trailing_data() {
+        let appearance: u16 = 0x1444;
+        let mut data: Vec<u8> = Appearance::new(appearance).into();
+        data.append(&mut vec![0x02, 0x0a, 0x7f]);
+        let result = Appearance::try_from(&data);
+        assert!(result.is_ok());
+        let data_type = result.unwrap();
+        assert_eq!(3, data_type.length);
+        assert_eq!(appearance, data_type.appearance);
+
+        let results = DataTypeParseResults::from(&data);
+        assert!(matches!(
+            results.results.first(),
+            Some(DataTypeParseResult::AppearanceResult(Ok(Appearance {
+                length: 3,
+                appearance: 0x1444,
+            })))
+        ));
+        assert!(matches!(
+            results.results.get(1),
+            Some(DataTypeParseResult::TxPowerLevelResult(Ok(_)))
+        ));
+    }
+
     #[test]
     fn test_into() {
         let appearance: u16 = 0x1444;
@@ -269,6 +2030,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_is_appearance() {
         assert!(is_appearance(0x19));
         assert!(!is_appearance(0x00));
@@ -1,6 +1,12 @@
 //! Broadcast_Code (Data Type Value: 0x2d) module.
 
-use crate::data_types::data_type::DataType;
+use crate::data_types::{ad_type::AdType, data_type::DataType};
+
+/// Broadcast_Code minimum size
+pub const BROADCAST_CODE_MIN_SIZE: usize = 4;
+
+/// Broadcast_Code maximum size
+pub const BROADCAST_CODE_MAX_SIZE: usize = 16;
 
 /// Broadcast_Code.
 #[derive(Debug, PartialEq, Clone)]
@@ -45,11 +51,89 @@ impl BroadcastCode {
             broadcast_code: broadcast_code.clone(),
         }
     }
+
+    /// Create [`BroadcastCode`] from `Broadcast_Code` with size validation (4 - 16 bytes).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::broadcast_code::BroadcastCode;
+    ///
+    /// let broadcast_code = [0x00u8; 4].to_vec();
+    /// let result = BroadcastCode::try_new(&broadcast_code);
+    /// assert_eq!(Ok(BroadcastCode::new(&broadcast_code)), result);
+    ///
+    /// let result = BroadcastCode::try_new(&[0x00u8; 3]);
+    /// assert_eq!(Err("Invalid broadcast code size :3".to_string()), result);
+    ///
+    /// let result = BroadcastCode::try_new(&[0x00u8; 17]);
+    /// assert_eq!(Err("Invalid broadcast code size :17".to_string()), result);
+    /// ```
+    pub fn try_new(broadcast_code: &[u8]) -> Result<Self, String> {
+        let len = broadcast_code.len();
+        if !(BROADCAST_CODE_MIN_SIZE..=BROADCAST_CODE_MAX_SIZE).contains(&len) {
+            return Err(format!("Invalid broadcast code size :{}", len).to_string());
+        }
+        Ok(Self::new(&broadcast_code.to_vec()))
+    }
+
+    /// Create [`BroadcastCode`] from UTF-8 passphrase (4 - 16 bytes).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::broadcast_code::BroadcastCode;
+    ///
+    /// let result = BroadcastCode::from_passphrase("Børne House");
+    /// assert!(result.is_ok());
+    /// let broadcast_code = result.unwrap();
+    /// assert_eq!("Børne House".as_bytes().to_vec(), broadcast_code.broadcast_code);
+    /// assert_eq!(
+    ///     [
+    ///         0x42, 0xc3, 0xb8, 0x72, 0x6e, 0x65, 0x20, 0x48, 0x6f, 0x75, 0x73, 0x65, 0x00, 0x00,
+    ///         0x00, 0x00
+    ///     ],
+    ///     broadcast_code.to_128bit()
+    /// );
+    ///
+    /// let result = BroadcastCode::from_passphrase("abc");
+    /// assert_eq!(Err("Invalid passphrase size :3".to_string()), result);
+    /// ```
+    pub fn from_passphrase(passphrase: &str) -> Result<Self, String> {
+        let len = passphrase.len();
+        if !(BROADCAST_CODE_MIN_SIZE..=BROADCAST_CODE_MAX_SIZE).contains(&len) {
+            return Err(format!("Invalid passphrase size :{}", len).to_string());
+        }
+        Ok(Self::new(&passphrase.as_bytes().to_vec()))
+    }
+
+    /// Get 128-bit Broadcast_Code (zero padded).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::broadcast_code::BroadcastCode;
+    ///
+    /// let result = BroadcastCode::new(&vec![0x3f, 0x42, 0x0f, 0x00]);
+    /// assert_eq!(
+    ///     [
+    ///         0x3f, 0x42, 0x0f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ///         0x00, 0x00
+    ///     ],
+    ///     result.to_128bit()
+    /// );
+    /// ```
+    pub fn to_128bit(&self) -> [u8; 16] {
+        let mut broadcast_code = [0u8; 16];
+        let len = self.broadcast_code.len().min(BROADCAST_CODE_MAX_SIZE);
+        broadcast_code[..len].copy_from_slice(&self.broadcast_code[..len]);
+        broadcast_code
+    }
 }
 
-impl TryFrom<&Vec<u8>> for BroadcastCode {
+impl TryFrom<&[u8]> for BroadcastCode {
     type Error = String;
-    /// Create [`BroadcastCode`] from [`Vec<u8>`].
+    /// Create [`BroadcastCode`] from `u8` slice.
     ///
     /// # Examples
     ///
@@ -63,7 +147,7 @@ impl TryFrom<&Vec<u8>> for BroadcastCode {
     /// data.push(BroadcastCode::data_type());
     /// data.append(&mut broadcast_code.clone());
     ///
-    /// let result = BroadcastCode::try_from(&data);
+    /// let result = BroadcastCode::try_from(data.as_slice());
     /// assert!(result.is_ok());
     /// let data_type = result.unwrap();
     /// assert_eq!(length, data_type.length);
@@ -76,7 +160,7 @@ impl TryFrom<&Vec<u8>> for BroadcastCode {
     /// data.push(BroadcastCode::data_type());
     /// data.append(&mut broadcast_code.clone());
     ///
-    /// let result = BroadcastCode::try_from(&data);
+    /// let result = BroadcastCode::try_from(data.as_slice());
     /// assert!(result.is_ok());
     /// let data_type = result.unwrap();
     /// assert_eq!(length, data_type.length);
@@ -93,26 +177,33 @@ impl TryFrom<&Vec<u8>> for BroadcastCode {
     /// data.push(BroadcastCode::data_type());
     /// data.append(&mut broadcast_code.clone());
     ///
-    /// let result = BroadcastCode::try_from(&data);
+    /// let result = BroadcastCode::try_from(data.as_slice());
     /// assert!(result.is_ok());
     /// let data_type = result.unwrap();
     /// assert_eq!(length, data_type.length);
     /// assert_eq!(broadcast_code, data_type.broadcast_code);
     ///
     /// let data: Vec<u8> = Vec::new();
-    /// let result = BroadcastCode::try_from(&data);
+    /// let result = BroadcastCode::try_from(data.as_slice());
     /// assert!(result.is_err());
     /// assert_eq!(
     ///     format!("Invalid data size :{}", data.len()),
     ///     result.unwrap_err()
     /// );
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, Self::Error> {
+    fn try_from(value: &[u8]) -> Result<Self, String> {
         let len = value.len();
         if len < 6 {
             return Err(format!("Invalid data size :{}", len).to_string());
         }
         let length = value[0];
+        if len < 1 + length as usize {
+            return Err(format!("Invalid data size :{}", len).to_string());
+        }
+        let size = (length as usize).saturating_sub(1);
+        if !(BROADCAST_CODE_MIN_SIZE..=BROADCAST_CODE_MAX_SIZE).contains(&size) {
+            return Err(format!("Invalid broadcast code size :{}", size).to_string());
+        }
         Ok(Self {
             length,
             broadcast_code: value[2..1 + length as usize].to_vec(),
@@ -120,6 +211,71 @@ impl TryFrom<&Vec<u8>> for BroadcastCode {
     }
 }
 
+impl TryFrom<&Vec<u8>> for BroadcastCode {
+    type Error = String;
+    /// Create [`BroadcastCode`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{broadcast_code::BroadcastCode, data_type::DataType};
+    ///
+    /// let broadcast_code = [0x00u8; 4].to_vec();
+    /// let length = broadcast_code.len() as u8 + 1;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(BroadcastCode::data_type());
+    /// data.append(&mut broadcast_code.clone());
+    ///
+    /// let result = BroadcastCode::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert_eq!(broadcast_code, data_type.broadcast_code);
+    ///
+    /// let broadcast_code = [0x3fu8, 0x42u8, 0x0fu8, 0x00u8].to_vec();
+    /// let length = broadcast_code.len() as u8 + 1;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(BroadcastCode::data_type());
+    /// data.append(&mut broadcast_code.clone());
+    ///
+    /// let result = BroadcastCode::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert_eq!(broadcast_code, data_type.broadcast_code);
+    ///
+    /// let broadcast_code = [
+    ///     0x00u8, 0x01u8, 0x02u8, 0x03u8, 0x04u8, 0x05u8, 0x06u8, 0x08u8, 0x09u8, 0x0au8, 0x0bu8,
+    ///     0x0cu8, 0x0du8, 0x0eu8, 0x0fu8, 0x10u8,
+    /// ]
+    /// .to_vec();
+    /// let length = broadcast_code.len() as u8 + 1;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(BroadcastCode::data_type());
+    /// data.append(&mut broadcast_code.clone());
+    ///
+    /// let result = BroadcastCode::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert_eq!(broadcast_code, data_type.broadcast_code);
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = BroadcastCode::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        Self::try_from(value.as_slice())
+    }
+}
+
 impl Into<Vec<u8>> for BroadcastCode {
     /// Create [`Vec<u8>`] from [`BroadcastCode`].
     ///
@@ -221,8 +377,9 @@ impl DataType for BroadcastCode {
 /// assert!(is_broadcast_code(0x2d));
 /// assert!(!is_broadcast_code(0x00));
 /// ```
+#[deprecated(note = "use `AdType::of(data_type).matches::<BroadcastCode>()` instead")]
 pub fn is_broadcast_code(data_type: u8) -> bool {
-    BroadcastCode::data_type() == data_type
+    AdType::of(data_type).matches::<BroadcastCode>()
 }
 
 #[cfg(test)]
@@ -305,6 +462,98 @@ mod tests {
             format!("Invalid data size :{}", data.len()),
             result.unwrap_err()
         );
+
+        let data: Vec<u8> = vec![6, BroadcastCode::data_type(), 0x00, 0x00, 0x00, 0x00];
+        let result = BroadcastCode::try_from(&data);
+        assert_eq!(Err(format!("Invalid data size :{}", data.len())), result);
+
+        let mut data: Vec<u8> = vec![0u8; 6];
+        data[0] = 4;
+        data[1] = BroadcastCode::data_type();
+        let result = BroadcastCode::try_from(&data);
+        assert_eq!(Err("Invalid broadcast code size :3".to_string()), result);
+
+        let mut data: Vec<u8> = vec![0u8; 19];
+        data[0] = data.len() as u8 - 1;
+        data[1] = BroadcastCode::data_type();
+        let result = BroadcastCode::try_from(&data);
+        assert_eq!(Err("Invalid broadcast code size :17".to_string()), result);
+    }
+
+    #[test]
+    fn test_try_new() {
+        for size in BROADCAST_CODE_MIN_SIZE..=BROADCAST_CODE_MAX_SIZE {
+            let broadcast_code = vec![0x01u8; size];
+            assert_eq!(
+                Ok(BroadcastCode::new(&broadcast_code)),
+                BroadcastCode::try_new(&broadcast_code)
+            );
+        }
+        assert_eq!(
+            Err("Invalid broadcast code size :0".to_string()),
+            BroadcastCode::try_new(&[])
+        );
+        assert_eq!(
+            Err("Invalid broadcast code size :3".to_string()),
+            BroadcastCode::try_new(&[0x00u8; 3])
+        );
+        assert_eq!(
+            Err("Invalid broadcast code size :17".to_string()),
+            BroadcastCode::try_new(&[0x00u8; 17])
+        );
+    }
+
+    #[test]
+    fn test_from_passphrase() {
+        let result = BroadcastCode::from_passphrase("1234");
+        assert_eq!(
+            Ok(BroadcastCode::new(&vec![0x31, 0x32, 0x33, 0x34])),
+            result
+        );
+
+        let result = BroadcastCode::from_passphrase("Børne House");
+        assert_eq!(
+            Ok(BroadcastCode::new(&"Børne House".as_bytes().to_vec())),
+            result
+        );
+
+        let result = BroadcastCode::from_passphrase("0123456789abcdef");
+        assert_eq!(
+            Ok(BroadcastCode::new(&"0123456789abcdef".as_bytes().to_vec())),
+            result
+        );
+
+        assert_eq!(
+            Err("Invalid passphrase size :0".to_string()),
+            BroadcastCode::from_passphrase("")
+        );
+        assert_eq!(
+            Err("Invalid passphrase size :3".to_string()),
+            BroadcastCode::from_passphrase("abc")
+        );
+        assert_eq!(
+            Err("Invalid passphrase size :17".to_string()),
+            BroadcastCode::from_passphrase("0123456789abcdefg")
+        );
+        assert_eq!(
+            Err("Invalid passphrase size :17".to_string()),
+            BroadcastCode::from_passphrase("0123456789abcdeø")
+        );
+    }
+
+    #[test]
+    fn test_to_128bit() {
+        let result = BroadcastCode::new(&vec![0x3f, 0x42, 0x0f, 0x00]);
+        let mut expected = [0u8; 16];
+        expected[..4].copy_from_slice(&[0x3f, 0x42, 0x0f, 0x00]);
+        assert_eq!(expected, result.to_128bit());
+
+        let broadcast_code = [
+            0x00u8, 0x01u8, 0x02u8, 0x03u8, 0x04u8, 0x05u8, 0x06u8, 0x08u8, 0x09u8, 0x0au8, 0x0bu8,
+            0x0cu8, 0x0du8, 0x0eu8, 0x0fu8, 0x10u8,
+        ];
+        let result = BroadcastCode::new(&broadcast_code.to_vec());
+        assert_eq!(broadcast_code, result.to_128bit());
     }
 
     #[test]
@@ -374,6 +623,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_is_broadcast_code() {
         assert!(is_broadcast_code(0x2d));
         assert!(!is_broadcast_code(0x00));
@@ -0,0 +1,313 @@
+//! Channel Map (ChM) module.
+
+/// ChM size in octets.
+pub const CH_M_SIZE: usize = 5;
+
+/// Number of data channels covered by ChM.
+pub const CH_M_CHANNEL_COUNT: usize = 37;
+
+/// ChM RFU bits (channel index 37-39) in the last octet.
+pub const CH_M_RFU_MASK: u8 = 0b1110_0000;
+
+/// Channel Map (ChM).
+///
+/// 40-bit field, channel index 0-36 are data channels and 37-39 are RFU.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Hash)]
+pub struct ChannelMap {
+    /// ChM octets (little endian)
+    pub octets: [u8; CH_M_SIZE],
+}
+
+impl ChannelMap {
+    /// Create [`ChannelMap`] from ChM octets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::channel_map::ChannelMap;
+    ///
+    /// let result = ChannelMap::new([0x01, 0x00, 0x00, 0x00, 0x10]);
+    /// assert_eq!([0x01, 0x00, 0x00, 0x00, 0x10], result.octets);
+    /// ```
+    pub const fn new(octets: [u8; CH_M_SIZE]) -> Self {
+        Self { octets }
+    }
+
+    /// Create [`ChannelMap`] from used flags of 37 or 40 channels.
+    ///
+    /// Flags after channel index 39 are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::channel_map::ChannelMap;
+    ///
+    /// let mut channels = [false; 37];
+    /// channels[0] = true;
+    /// channels[9] = true;
+    /// let result = ChannelMap::from_channels(&channels);
+    /// assert_eq!([0x01, 0x02, 0x00, 0x00, 0x00], result.octets);
+    /// ```
+    pub fn from_channels(channels: &[bool]) -> Self {
+        let mut result = Self::default();
+        for (index, used) in channels.iter().enumerate() {
+            result.set(index, *used);
+        }
+        result
+    }
+
+    /// check channel is used.
+    ///
+    /// Channel index 40 or later is always `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::channel_map::ChannelMap;
+    ///
+    /// let result = ChannelMap::new([0x01, 0x00, 0x00, 0x00, 0x10]);
+    /// assert!(result.is_used(0));
+    /// assert!(!result.is_used(1));
+    /// assert!(result.is_used(36));
+    /// assert!(!result.is_used(40));
+    /// ```
+    pub fn is_used(&self, index: usize) -> bool {
+        index < CH_M_SIZE * 8 && self.octets[index / 8] & (1 << (index % 8)) != 0
+    }
+
+    /// Set channel used or unused.
+    ///
+    /// Channel index 40 or later is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::channel_map::ChannelMap;
+    ///
+    /// let mut result = ChannelMap::default();
+    /// result.set(8, true);
+    /// assert_eq!([0x00, 0x01, 0x00, 0x00, 0x00], result.octets);
+    /// result.set(8, false);
+    /// assert_eq!(ChannelMap::default(), result);
+    /// ```
+    pub fn set(&mut self, index: usize, used: bool) {
+        if index >= CH_M_SIZE * 8 {
+            return;
+        }
+        if used {
+            self.octets[index / 8] |= 1 << (index % 8);
+        } else {
+            self.octets[index / 8] &= !(1 << (index % 8));
+        }
+    }
+
+    /// Iterate used flags of data channels (channel index 0-36).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::channel_map::ChannelMap;
+    ///
+    /// let result = ChannelMap::new([0x05, 0x00, 0x00, 0x00, 0xe0]);
+    /// let channels: Vec<bool> = result.iter().collect();
+    /// assert_eq!(37, channels.len());
+    /// assert_eq!([true, false, true, false], channels[..4]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
+        (0..CH_M_CHANNEL_COUNT).map(|index| self.is_used(index))
+    }
+
+    /// Iterate used data channel indexes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::channel_map::ChannelMap;
+    ///
+    /// let result = ChannelMap::new([0x05, 0x00, 0x00, 0x00, 0xf0]);
+    /// assert_eq!(vec![0, 2, 36], result.used_channels().collect::<Vec<u8>>());
+    /// ```
+    pub fn used_channels(&self) -> impl Iterator<Item = u8> + '_ {
+        (0..CH_M_CHANNEL_COUNT as u8).filter(|index| self.is_used(*index as usize))
+    }
+
+    /// Get number of used data channels.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::channel_map::ChannelMap;
+    ///
+    /// assert_eq!(37, ChannelMap::new([0xff, 0xff, 0xff, 0xff, 0xff]).count());
+    /// assert_eq!(0, ChannelMap::default().count());
+    /// ```
+    pub fn count(&self) -> usize {
+        self.iter().filter(|used| *used).count()
+    }
+
+    /// Get RFU bits (masked value of the last octet).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::channel_map::ChannelMap;
+    ///
+    /// assert_eq!(0xe0, ChannelMap::new([0xff, 0xff, 0xff, 0xff, 0xff]).rfu());
+    /// assert_eq!(0x00, ChannelMap::new([0xff, 0xff, 0xff, 0xff, 0x1f]).rfu());
+    /// ```
+    pub fn rfu(&self) -> u8 {
+        self.octets[CH_M_SIZE - 1] & CH_M_RFU_MASK
+    }
+
+    /// Check that RFU bits are not set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::channel_map::ChannelMap;
+    ///
+    /// assert_eq!(Ok(()), ChannelMap::from(0x1f_ffff_ffff).validate());
+    /// assert_eq!(
+    ///     Err("Invalid ChM :0x2000000000".to_string()),
+    ///     ChannelMap::from(0x20_0000_0000).validate()
+    /// );
+    /// ```
+    pub fn validate(&self) -> Result<(), String> {
+        if self.rfu() != 0 {
+            return Err(format!("Invalid ChM :{:#x}", u64::from(*self)));
+        }
+        Ok(())
+    }
+}
+
+impl From<[u8; CH_M_SIZE]> for ChannelMap {
+    /// Create [`ChannelMap`] from ChM octets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::channel_map::ChannelMap;
+    ///
+    /// let result = ChannelMap::from([0xff, 0x00, 0x00, 0x00, 0x00]);
+    /// assert_eq!(8, result.count());
+    /// ```
+    fn from(value: [u8; CH_M_SIZE]) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<ChannelMap> for [u8; CH_M_SIZE] {
+    /// Create ChM octets from [`ChannelMap`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::channel_map::ChannelMap;
+    ///
+    /// let octets: [u8; 5] = ChannelMap::from(0x1f_0000_0001).into();
+    /// assert_eq!([0x01, 0x00, 0x00, 0x00, 0x1f], octets);
+    /// ```
+    fn from(value: ChannelMap) -> Self {
+        value.octets
+    }
+}
+
+impl From<u64> for ChannelMap {
+    /// Create [`ChannelMap`] from lower 40 bits of [`u64`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::channel_map::ChannelMap;
+    ///
+    /// let result = ChannelMap::from(0xff_0000_0000_0102);
+    /// assert_eq!([0x02, 0x01, 0x00, 0x00, 0x00], result.octets);
+    /// ```
+    fn from(value: u64) -> Self {
+        let mut octets = [0u8; CH_M_SIZE];
+        octets.copy_from_slice(&value.to_le_bytes()[..CH_M_SIZE]);
+        Self::new(octets)
+    }
+}
+
+impl From<ChannelMap> for u64 {
+    /// Create [`u64`] from [`ChannelMap`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::channel_map::ChannelMap;
+    ///
+    /// assert_eq!(0x1f_0000_0001, u64::from(ChannelMap::new([0x01, 0x00, 0x00, 0x00, 0x1f])));
+    /// ```
+    fn from(value: ChannelMap) -> Self {
+        let mut bytes = [0u8; 8];
+        bytes[..CH_M_SIZE].copy_from_slice(&value.octets);
+        u64::from_le_bytes(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data_types::channel_map::*;
+
+    #[test]
+    fn test_from_channels() {
+        for i in 0..CH_M_CHANNEL_COUNT {
+            let mut channels = [false; CH_M_CHANNEL_COUNT];
+            channels[i] = true;
+            let result = ChannelMap::from_channels(&channels);
+            assert_eq!(1u64 << i, u64::from(result));
+            assert_eq!(channels.to_vec(), result.iter().collect::<Vec<bool>>());
+            assert_eq!(vec![i as u8], result.used_channels().collect::<Vec<u8>>());
+            assert_eq!(1, result.count());
+            assert_eq!(Ok(()), result.validate());
+        }
+
+        let result = ChannelMap::from_channels(&[true; 41]);
+        assert_eq!([0xff; CH_M_SIZE], result.octets);
+        assert_eq!(CH_M_CHANNEL_COUNT, result.count());
+        assert_eq!(CH_M_RFU_MASK, result.rfu());
+    }
+
+    #[test]
+    fn test_set() {
+        let mut result = ChannelMap::default();
+        for i in 0..40 {
+            result.set(i, true);
+            assert!(result.is_used(i));
+        }
+        result.set(40, true);
+        assert!(!result.is_used(40));
+        assert_eq!(0xff_ffff_ffff, u64::from(result));
+
+        result.set(37, false);
+        result.set(38, false);
+        result.set(39, false);
+        assert_eq!(0x1f_ffff_ffff, u64::from(result));
+        assert_eq!(Ok(()), result.validate());
+    }
+
+    #[test]
+    fn test_validate() {
+        for bit in [0x20u8, 0x40, 0x80] {
+            let result = ChannelMap::new([0x00, 0x00, 0x00, 0x00, bit]);
+            assert_eq!(bit, result.rfu());
+            assert_eq!(0, result.count());
+            assert_eq!(
+                Err(format!("Invalid ChM :{:#x}", (bit as u64) << 32)),
+                result.validate()
+            );
+        }
+    }
+
+    #[test]
+    fn test_into() {
+        let result = ChannelMap::from(0x12_3456_789a);
+        assert_eq!([0x9a, 0x78, 0x56, 0x34, 0x12], result.octets);
+        assert_eq!(0x12_3456_789a, u64::from(result));
+        let octets: [u8; CH_M_SIZE] = result.into();
+        assert_eq!(result, ChannelMap::from(octets));
+        assert_eq!(0, u64::from(ChannelMap::from(0xffff_ff00_0000_0000)));
+    }
+}
@@ -1,6 +1,61 @@
 //! Channel Map Update Indication (Data Type Value: 0x28) module.
 
-use crate::data_types::data_type::DataType;
+use std::fmt;
+
+use crate::data_types::{
+    ad_type::AdType,
+    channel_map::{ChannelMap, CH_M_RFU_MASK, CH_M_SIZE},
+    data_type::DataType,
+};
+
+/// Channel Map Update Indication validation error.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ChannelMapUpdateIndicationError {
+    /// data length is not `8`
+    InvalidLength(u8),
+
+    /// ChM RFU bits are set (masked value of the last octet)
+    RfuBitsSet(u8),
+}
+
+impl fmt::Display for ChannelMapUpdateIndicationError {
+    /// Format [`ChannelMapUpdateIndicationError`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::channel_map_update_indication::ChannelMapUpdateIndicationError;
+    ///
+    /// assert_eq!("Invalid length :9", ChannelMapUpdateIndicationError::InvalidLength(9).to_string());
+    /// assert_eq!("Invalid ChM RFU bits :0x20", ChannelMapUpdateIndicationError::RfuBitsSet(0x20).to_string());
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChannelMapUpdateIndicationError::InvalidLength(length) => {
+                write!(f, "Invalid length :{}", length)
+            }
+            ChannelMapUpdateIndicationError::RfuBitsSet(bits) => {
+                write!(f, "Invalid ChM RFU bits :{:#04x}", bits)
+            }
+        }
+    }
+}
+
+impl From<ChannelMapUpdateIndicationError> for String {
+    /// Create [`String`] from [`ChannelMapUpdateIndicationError`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::channel_map_update_indication::ChannelMapUpdateIndicationError;
+    ///
+    /// let error: String = ChannelMapUpdateIndicationError::InvalidLength(9).into();
+    /// assert_eq!("Invalid length :9", error);
+    /// ```
+    fn from(value: ChannelMapUpdateIndicationError) -> Self {
+        value.to_string()
+    }
+}
 
 /// Channel Map Update Indication.
 #[derive(Debug, PartialEq, Clone)]
@@ -9,7 +64,7 @@ pub struct ChannelMapUpdateIndication {
     pub length: u8,
 
     /// ChM
-    pub ch_m: Vec<bool>,
+    pub ch_m: ChannelMap,
 
     /// Instant
     pub instant: u16,
@@ -21,30 +76,127 @@ impl ChannelMapUpdateIndication {
     /// # Examples
     ///
     /// ```
-    /// use ble_data_struct::data_types::channel_map_update_indication::ChannelMapUpdateIndication;
+    /// use ble_data_struct::data_types::{
+    ///     channel_map::ChannelMap, channel_map_update_indication::ChannelMapUpdateIndication,
+    /// };
     ///
-    /// let mut ch_m = [false; 37].to_vec();
+    /// let mut ch_m = ChannelMap::default();
     /// for i in 0..37 {
-    ///     ch_m[i] = true;
-    ///     let result = ChannelMapUpdateIndication::new(&ch_m, i as u16);
+    ///     ch_m.set(i, true);
+    ///     let result = ChannelMapUpdateIndication::new(ch_m, i as u16);
     ///     assert_eq!(8, result.length);
     ///     assert_eq!(ch_m, result.ch_m);
     ///     assert_eq!(i as u16, result.instant);
-    ///     ch_m[i] = false;
+    ///     ch_m.set(i, false);
     /// }
     /// ```
-    pub fn new(ch_m: &Vec<bool>, instant: u16) -> Self {
+    pub const fn new(ch_m: ChannelMap, instant: u16) -> Self {
         Self {
             length: 8,
-            ch_m: ch_m[..37].to_vec(),
+            ch_m,
             instant,
         }
     }
+
+    /// Create [`ChannelMapUpdateIndication`] from ChM octets.
+    ///
+    /// RFU bits (channel index 37-39) are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::channel_map_update_indication::ChannelMapUpdateIndication;
+    ///
+    /// let result = ChannelMapUpdateIndication::from_channel_map(&[0xff, 0xff, 0xff, 0xff, 0xff], 0x1234);
+    /// assert_eq!(8, result.length);
+    /// assert_eq!(37, result.ch_m.count());
+    /// assert_eq!(0x1234, result.instant);
+    /// assert_eq!([0xff, 0xff, 0xff, 0xff, 0x1f], result.channel_map());
+    /// ```
+    pub fn from_channel_map(ch_m: &[u8; CH_M_SIZE], instant: u16) -> Self {
+        let mut ch_m = ChannelMap::new(*ch_m);
+        ch_m.octets[CH_M_SIZE - 1] &= !CH_M_RFU_MASK;
+        Self::new(ch_m, instant)
+    }
+
+    /// Create [`ChannelMapUpdateIndication`] from ChM octets with RFU bits check.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::channel_map_update_indication::{ChannelMapUpdateIndication, ChannelMapUpdateIndicationError};
+    ///
+    /// let result = ChannelMapUpdateIndication::try_from_channel_map(&[0x01, 0x00, 0x00, 0x00, 0x10], 0x1234);
+    /// assert!(result.is_ok());
+    /// let result = result.unwrap();
+    /// assert!(result.ch_m.is_used(0));
+    /// assert!(result.ch_m.is_used(36));
+    ///
+    /// let result = ChannelMapUpdateIndication::try_from_channel_map(&[0x01, 0x00, 0x00, 0x00, 0x20], 0x1234);
+    /// assert_eq!(Err(ChannelMapUpdateIndicationError::RfuBitsSet(0x20)), result);
+    /// ```
+    pub fn try_from_channel_map(
+        ch_m: &[u8; CH_M_SIZE],
+        instant: u16,
+    ) -> Result<Self, ChannelMapUpdateIndicationError> {
+        let rfu = ChannelMap::new(*ch_m).rfu();
+        if rfu != 0 {
+            return Err(ChannelMapUpdateIndicationError::RfuBitsSet(rfu));
+        }
+        Ok(Self::from_channel_map(ch_m, instant))
+    }
+
+    /// Get ChM octets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     channel_map::ChannelMap, channel_map_update_indication::ChannelMapUpdateIndication,
+    /// };
+    ///
+    /// let mut ch_m = ChannelMap::default();
+    /// ch_m.set(0, true);
+    /// ch_m.set(9, true);
+    /// let result = ChannelMapUpdateIndication::new(ch_m, 0);
+    /// assert_eq!([0x01, 0x02, 0x00, 0x00, 0x00], result.channel_map());
+    /// ```
+    pub fn channel_map(&self) -> [u8; CH_M_SIZE] {
+        self.ch_m.octets
+    }
+
+    /// Validate length and ChM RFU bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::channel_map_update_indication::{ChannelMapUpdateIndication, ChannelMapUpdateIndicationError};
+    ///
+    /// let result = ChannelMapUpdateIndication::from_channel_map(&[0xff, 0xff, 0xff, 0xff, 0x1f], 0x1234);
+    /// assert_eq!(Ok(()), result.validate());
+    ///
+    /// let data: Vec<u8> = vec![8, 0x28, 0xff, 0xff, 0xff, 0xff, 0xff, 0x34, 0x12];
+    /// let mut result = ChannelMapUpdateIndication::try_from(&data).unwrap();
+    /// assert_eq!(Err(ChannelMapUpdateIndicationError::RfuBitsSet(0xe0)), result.validate());
+    ///
+    /// result.length = 9;
+    /// assert_eq!(Err(ChannelMapUpdateIndicationError::InvalidLength(9)), result.validate());
+    /// ```
+    pub fn validate(&self) -> Result<(), ChannelMapUpdateIndicationError> {
+        if self.length != 8 {
+            return Err(ChannelMapUpdateIndicationError::InvalidLength(self.length));
+        }
+        let rfu = self.ch_m.rfu();
+        if rfu != 0 {
+            return Err(ChannelMapUpdateIndicationError::RfuBitsSet(rfu));
+        }
+        Ok(())
+    }
 }
 
-impl TryFrom<&Vec<u8>> for ChannelMapUpdateIndication {
+impl TryFrom<&[u8]> for ChannelMapUpdateIndication {
     type Error = String;
-    /// Create [`ChannelMapUpdateIndication`] from [`Vec<u8>`].
+    /// Create [`ChannelMapUpdateIndication`] from `u8` slice.
     ///
     /// # Examples
     ///
@@ -53,8 +205,6 @@ impl TryFrom<&Vec<u8>> for ChannelMapUpdateIndication {
     ///
     /// let mut ch_m = [0u8; 5].to_vec();
     ///
-    /// let mut ch_m = [0u8; 5].to_vec();
-    ///
     /// for i in 0..37 {
     ///     ch_m[i / 8] = 0b1 << (i % 8);
     ///
@@ -65,91 +215,114 @@ impl TryFrom<&Vec<u8>> for ChannelMapUpdateIndication {
     ///     data.append(&mut ch_m.clone());
     ///     data.append(&mut (i as u16).to_le_bytes().to_vec());
     ///
-    ///     let result = ChannelMapUpdateIndication::try_from(&data);
+    ///     let result = ChannelMapUpdateIndication::try_from(data.as_slice());
     ///     assert!(result.is_ok());
     ///     let data_type = result.unwrap();
     ///     assert_eq!(length, data_type.length);
-    ///     let bool_vec: Vec<bool> = ch_m
-    ///         .clone()
-    ///         .iter()
-    ///         .flat_map(|x| {
-    ///             let mut data: Vec<bool> = Vec::new();
-    ///             data.push((x & 0b0000_0001) != 0);
-    ///             data.push((x & 0b0000_0010) != 0);
-    ///             data.push((x & 0b0000_0100) != 0);
-    ///             data.push((x & 0b0000_1000) != 0);
-    ///             data.push((x & 0b0001_0000) != 0);
-    ///             data.push((x & 0b0010_0000) != 0);
-    ///             data.push((x & 0b0100_0000) != 0);
-    ///             data.push((x & 0b1000_0000) != 0);
-    ///             data
-    ///         })
-    ///         .collect();
-    ///
-    ///     assert_eq!(bool_vec, data_type.ch_m);
+    ///     assert_eq!(ch_m, data_type.ch_m.octets);
+    ///     assert_eq!(vec![i as u8], data_type.ch_m.used_channels().collect::<Vec<u8>>());
     ///
     ///     ch_m[i / 8] = 0u8;
     /// }
     ///
     /// let data: Vec<u8> = Vec::new();
-    /// let result = ChannelMapUpdateIndication::try_from(&data);
+    /// let result = ChannelMapUpdateIndication::try_from(data.as_slice());
     /// assert!(result.is_err());
     /// assert_eq!(
     ///     format!("Invalid data size :{}", data.len()),
     ///     result.unwrap_err()
     /// );
+    ///
+    /// let data: Vec<u8> = vec![9, 0x28, 0xff, 0xff, 0xff, 0xff, 0x1f, 0x34, 0x12, 0x00];
+    /// let result = ChannelMapUpdateIndication::try_from(data.as_slice());
+    /// assert_eq!(Err("Invalid length :9".to_string()), result);
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+    fn try_from(value: &[u8]) -> Result<Self, String> {
         let len = value.len();
         if len < 9 {
             return Err(format!("Invalid data size :{}", len).to_string());
         }
         let length = value[0];
-        let ch_m: Vec<bool> = value[2..length as usize - 1]
-            .iter()
-            .flat_map(|x| {
-                let mut data: Vec<bool> = Vec::new();
-                data.push(x & 0b0000_0001 != 0);
-                data.push(x & 0b0000_0010 != 0);
-                data.push(x & 0b0000_0100 != 0);
-                data.push(x & 0b0000_1000 != 0);
-                data.push(x & 0b0001_0000 != 0);
-                data.push(x & 0b0010_0000 != 0);
-                data.push(x & 0b0100_0000 != 0);
-                data.push(x & 0b1000_0000 != 0);
-                data
-            })
-            .collect();
+        if length != 8 {
+            return Err(ChannelMapUpdateIndicationError::InvalidLength(length).into());
+        }
         Ok(Self {
             length,
-            ch_m: ch_m.to_vec(),
+            ch_m: ChannelMap::new(value[2..7].try_into().unwrap()),
             instant: u16::from_le_bytes(value[7..9].try_into().unwrap()),
         })
     }
 }
 
+impl TryFrom<&Vec<u8>> for ChannelMapUpdateIndication {
+    type Error = String;
+    /// Create [`ChannelMapUpdateIndication`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{channel_map_update_indication::ChannelMapUpdateIndication, data_type::DataType};
+    ///
+    /// let mut ch_m = [0u8; 5].to_vec();
+    ///
+    /// for i in 0..37 {
+    ///     ch_m[i / 8] = 0b1 << (i % 8);
+    ///
+    ///     let length = 8;
+    ///     let mut data: Vec<u8> = Vec::new();
+    ///     data.push(length);
+    ///     data.push(ChannelMapUpdateIndication::data_type());
+    ///     data.append(&mut ch_m.clone());
+    ///     data.append(&mut (i as u16).to_le_bytes().to_vec());
+    ///
+    ///     let result = ChannelMapUpdateIndication::try_from(&data);
+    ///     assert!(result.is_ok());
+    ///     let data_type = result.unwrap();
+    ///     assert_eq!(length, data_type.length);
+    ///     assert_eq!(ch_m, data_type.ch_m.octets);
+    ///     assert_eq!(vec![i as u8], data_type.ch_m.used_channels().collect::<Vec<u8>>());
+    ///
+    ///     ch_m[i / 8] = 0u8;
+    /// }
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = ChannelMapUpdateIndication::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    ///
+    /// let data: Vec<u8> = vec![9, 0x28, 0xff, 0xff, 0xff, 0xff, 0x1f, 0x34, 0x12, 0x00];
+    /// let result = ChannelMapUpdateIndication::try_from(&data);
+    /// assert_eq!(Err("Invalid length :9".to_string()), result);
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        Self::try_from(value.as_slice())
+    }
+}
+
 impl Into<Vec<u8>> for ChannelMapUpdateIndication {
     /// Create [`Vec<u8>`] from [`ChannelMapUpdateIndication`].
     ///
     /// # Examples
     ///
     /// ```
-    /// use ble_data_struct::data_types::{channel_map_update_indication::ChannelMapUpdateIndication, data_type::DataType};
+    /// use ble_data_struct::data_types::{
+    ///     channel_map::ChannelMap, channel_map_update_indication::ChannelMapUpdateIndication,
+    ///     data_type::DataType,
+    /// };
     ///
-    /// let mut ch_m = [false; 37].to_vec();
+    /// let mut ch_m = ChannelMap::default();
     /// for i in 0..37 {
-    ///     ch_m[i] = true;
-    ///     let result1 = ChannelMapUpdateIndication::new(&ch_m, i as u16);
+    ///     ch_m.set(i, true);
+    ///     let result1 = ChannelMapUpdateIndication::new(ch_m, i as u16);
     ///
     ///     let mut data: Vec<u8> = Vec::new();
     ///     data.push(8);
     ///     data.push(ChannelMapUpdateIndication::data_type());
     ///     let mut u8_vec = [0u8; 5];
-    ///     for (i, element) in ch_m.iter().enumerate() {
-    ///         if *element {
-    ///             u8_vec[i / 8] = u8_vec[i / 8] | 1 << i % 8
-    ///         }
-    ///     }
+    ///     u8_vec[i / 8] = 1 << (i % 8);
     ///     data.append(&mut u8_vec.clone().to_vec());
     ///     data.append(&mut (i as u16).to_le_bytes().to_vec());
     ///
@@ -162,20 +335,14 @@ impl Into<Vec<u8>> for ChannelMapUpdateIndication {
     ///     let into_data: Vec<u8> = data_type.into();
     ///     assert_eq!(data, into_data);
     ///
-    ///     ch_m[i] = false;
+    ///     ch_m.set(i, false);
     /// }
     /// ```
     fn into(self) -> Vec<u8> {
         let mut data: Vec<u8> = Vec::new();
         data.push(self.length);
         data.push(Self::data_type());
-        let mut ch_m = [0u8; 5];
-        for (i, element) in self.ch_m.iter().enumerate() {
-            if *element {
-                ch_m[i / 8] = ch_m[i / 8] | 1 << i % 8
-            }
-        }
-        data.append(&mut ch_m.to_vec());
+        data.append(&mut self.ch_m.octets.to_vec());
         data.append(&mut self.instant.to_le_bytes().to_vec());
         return data;
     }
@@ -207,24 +374,27 @@ impl DataType for ChannelMapUpdateIndication {
 /// assert!(is_channel_map_update_indication(0x28));
 /// assert!(!is_channel_map_update_indication(0x00));
 /// ```
+#[deprecated(note = "use `AdType::of(data_type).matches::<ChannelMapUpdateIndication>()` instead")]
 pub fn is_channel_map_update_indication(data_type: u8) -> bool {
-    ChannelMapUpdateIndication::data_type() == data_type
+    AdType::of(data_type).matches::<ChannelMapUpdateIndication>()
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::data_types::{channel_map_update_indication::*, data_type::DataType};
+    use crate::data_types::{
+        channel_map::ChannelMap, channel_map_update_indication::*, data_type::DataType,
+    };
 
     #[test]
     fn test_new() {
-        let mut ch_m = [false; 37].to_vec();
+        let mut ch_m = ChannelMap::default();
         for i in 0..37 {
-            ch_m[i] = true;
-            let result = ChannelMapUpdateIndication::new(&ch_m, i as u16);
+            ch_m.set(i, true);
+            let result = ChannelMapUpdateIndication::new(ch_m, i as u16);
             assert_eq!(8, result.length);
             assert_eq!(ch_m, result.ch_m);
             assert_eq!(i as u16, result.instant);
-            ch_m[i] = false;
+            ch_m.set(i, false);
         }
     }
 
@@ -246,24 +416,8 @@ mod tests {
             assert!(result.is_ok());
             let data_type = result.unwrap();
             assert_eq!(length, data_type.length);
-            let bool_vec: Vec<bool> = ch_m
-                .clone()
-                .iter()
-                .flat_map(|x| {
-                    let mut data: Vec<bool> = Vec::new();
-                    data.push((x & 0b0000_0001) != 0);
-                    data.push((x & 0b0000_0010) != 0);
-                    data.push((x & 0b0000_0100) != 0);
-                    data.push((x & 0b0000_1000) != 0);
-                    data.push((x & 0b0001_0000) != 0);
-                    data.push((x & 0b0010_0000) != 0);
-                    data.push((x & 0b0100_0000) != 0);
-                    data.push((x & 0b1000_0000) != 0);
-                    data
-                })
-                .collect();
-
-            assert_eq!(bool_vec, data_type.ch_m);
+            let bool_vec: Vec<bool> = (0..37).map(|j| i == j).collect();
+            assert_eq!(bool_vec, data_type.ch_m.iter().collect::<Vec<bool>>());
 
             ch_m[i / 8] = 0u8;
         }
@@ -278,22 +432,109 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_try_from_invalid_length() {
+        let data: Vec<u8> = vec![9, 0x28, 0xff, 0xff, 0xff, 0xff, 0x1f, 0x34, 0x12, 0x00];
+        let result = ChannelMapUpdateIndication::try_from(&data);
+        assert_eq!(Err("Invalid length :9".to_string()), result);
+
+        let data: Vec<u8> = vec![0, 0x28, 0xff, 0xff, 0xff, 0xff, 0x1f, 0x34, 0x12];
+        let result = ChannelMapUpdateIndication::try_from(&data);
+        assert_eq!(Err("Invalid length :0".to_string()), result);
+    }
+
+    #[test]
+    fn test_from_channel_map() {
+        for i in 0..37 {
+            let mut ch_m = [0u8; 5];
+            ch_m[i / 8] = 1 << (i % 8);
+            let result = ChannelMapUpdateIndication::from_channel_map(&ch_m, i as u16);
+            let mut expected = ChannelMap::default();
+            expected.set(i, true);
+            assert_eq!(8, result.length);
+            assert_eq!(expected, result.ch_m);
+            assert_eq!(i as u16, result.instant);
+            assert_eq!(ch_m, result.channel_map());
+            assert_eq!(Ok(()), result.validate());
+        }
+
+        let result =
+            ChannelMapUpdateIndication::from_channel_map(&[0x00, 0x00, 0x00, 0x00, 0xe0], 0);
+        assert_eq!(ChannelMap::default(), result.ch_m);
+    }
+
+    #[test]
+    fn test_try_from_channel_map() {
+        let result = ChannelMapUpdateIndication::try_from_channel_map(
+            &[0xff, 0xff, 0xff, 0xff, 0x1f],
+            0x1234,
+        );
+        assert_eq!(
+            Ok(ChannelMapUpdateIndication::new(
+                ChannelMap::from_channels(&[true; 37]),
+                0x1234
+            )),
+            result
+        );
+
+        for bit in [0x20u8, 0x40, 0x80] {
+            let result =
+                ChannelMapUpdateIndication::try_from_channel_map(&[0x00, 0x00, 0x00, 0x00, bit], 0);
+            assert_eq!(
+                Err(ChannelMapUpdateIndicationError::RfuBitsSet(bit)),
+                result
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        let data: Vec<u8> = vec![8, 0x28, 0xff, 0xff, 0xff, 0xff, 0x1f, 0x34, 0x12];
+        let result = ChannelMapUpdateIndication::try_from(&data).unwrap();
+        assert_eq!(Ok(()), result.validate());
+        assert_eq!(0x1234, result.instant);
+
+        let data: Vec<u8> = vec![8, 0x28, 0xff, 0xff, 0xff, 0xff, 0x3f, 0x34, 0x12];
+        let result = ChannelMapUpdateIndication::try_from(&data).unwrap();
+        assert_eq!(
+            Err(ChannelMapUpdateIndicationError::RfuBitsSet(0x20)),
+            result.validate()
+        );
+
+        let mut result = ChannelMapUpdateIndication::new(ChannelMap::from_channels(&[true; 37]), 0);
+        result.length = 7;
+        assert_eq!(
+            Err(ChannelMapUpdateIndicationError::InvalidLength(7)),
+            result.validate()
+        );
+    }
+
+    #[test]
+    fn test_error_display() {
+        assert_eq!(
+            "Invalid length :9",
+            ChannelMapUpdateIndicationError::InvalidLength(9).to_string()
+        );
+        assert_eq!(
+            "Invalid ChM RFU bits :0xe0",
+            ChannelMapUpdateIndicationError::RfuBitsSet(0xe0).to_string()
+        );
+        let error: String = ChannelMapUpdateIndicationError::RfuBitsSet(0x20).into();
+        assert_eq!("Invalid ChM RFU bits :0x20", error);
+    }
+
     #[test]
     fn test_into() {
-        let mut ch_m = [false; 37].to_vec();
+        let mut ch_m = ChannelMap::default();
         for i in 0..37 {
-            ch_m[i] = true;
-            let result1 = ChannelMapUpdateIndication::new(&ch_m, i as u16);
+            ch_m.set(i, true);
+            let result1 = ChannelMapUpdateIndication::new(ch_m, i as u16);
 
             let mut data: Vec<u8> = Vec::new();
             data.push(8);
             data.push(ChannelMapUpdateIndication::data_type());
             let mut u8_vec = [0u8; 5];
-            for (i, element) in ch_m.iter().enumerate() {
-                if *element {
-                    u8_vec[i / 8] = u8_vec[i / 8] | 1 << i % 8
-                }
-            }
+            u8_vec[i / 8] = 1 << (i % 8);
             data.append(&mut u8_vec.clone().to_vec());
             data.append(&mut (i as u16).to_le_bytes().to_vec());
 
@@ -306,7 +547,7 @@ mod tests {
             let into_data: Vec<u8> = data_type.into();
             assert_eq!(data, into_data);
 
-            ch_m[i] = false;
+            ch_m.set(i, false);
         }
     }
 
@@ -316,6 +557,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_is_channel_map_update_indication() {
         assert!(is_channel_map_update_indication(0x28));
         assert!(!is_channel_map_update_indication(0x00));
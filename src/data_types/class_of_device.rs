@@ -1,6 +1,11 @@
 //! Class of Device (Data Type Value: 0x0d) module.
 
-use crate::data_types::data_type::DataType;
+use std::fmt;
+
+use crate::data_types::{
+    ad_type::AdType,
+    data_type::{validate_length, DataType},
+};
 
 /// Class of Device.
 #[derive(Debug, PartialEq, Clone)]
@@ -29,7 +34,7 @@ impl ClassOfDevice {
     /// assert_eq!(4, result.length);
     /// assert_eq!(class_of_device, result.class_of_device);
     /// ```
-    pub fn new(class_of_device: u32) -> Self {
+    pub const fn new(class_of_device: u32) -> Self {
         Self {
             length: 4,
             class_of_device,
@@ -95,6 +100,375 @@ impl ClassOfDevice {
     pub const fn minor_device_class(&self) -> u32 {
         self.class_of_device & CLASS_OF_DEVICE_MINOR_DEVICE_CLASS_MASK
     }
+
+    /// Major Device Class as [`MajorDeviceClass`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::class_of_device::{ClassOfDevice, MajorDeviceClass};
+    ///
+    /// let result = ClassOfDevice::new(0b00000000_00000010_00001100);
+    /// assert_eq!(MajorDeviceClass::Phone, result.major_device_class_type());
+    ///
+    /// let result = ClassOfDevice::new(0b00000000_00011111_00000000);
+    /// assert_eq!(MajorDeviceClass::Uncategorized, result.major_device_class_type());
+    /// ```
+    pub fn major_device_class_type(&self) -> MajorDeviceClass {
+        MajorDeviceClass::from((self.major_device_class() >> 8) as u8)
+    }
+
+    /// Major Service Classes names.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::class_of_device::ClassOfDevice;
+    ///
+    /// let result = ClassOfDevice::new(0b01100000_00000010_00001100);
+    /// assert_eq!(vec!["Audio", "Telephony"], result.major_service_class_names());
+    ///
+    /// let result = ClassOfDevice::new(0b00000000_00000010_00001100);
+    /// assert!(result.major_service_class_names().is_empty());
+    /// ```
+    pub fn major_service_class_names(&self) -> Vec<&'static str> {
+        MAJOR_SERVICE_CLASS_NAMES
+            .iter()
+            .filter(|(bit, _)| self.class_of_device & (1 << bit) != 0)
+            .map(|(_, name)| *name)
+            .collect()
+    }
+
+    /// Minor Device Class name.
+    ///
+    /// returns [`None`] if the Major Device Class has no Minor Device Class assignments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::class_of_device::ClassOfDevice;
+    ///
+    /// let result = ClassOfDevice::new(0b00000000_00000010_00001100);
+    /// assert_eq!(Some("Smartphone".to_string()), result.minor_device_class_name());
+    ///
+    /// let result = ClassOfDevice::new(0b00000000_00000110_10100000);
+    /// assert_eq!(Some("Printer, Camera".to_string()), result.minor_device_class_name());
+    ///
+    /// let result = ClassOfDevice::new(0b00000000_00000000_00000000);
+    /// assert_eq!(None, result.minor_device_class_name());
+    /// ```
+    pub fn minor_device_class_name(&self) -> Option<String> {
+        let minor = (self.minor_device_class() >> 2) as usize;
+        let lookup = |names: &[&'static str], index: usize| -> String {
+            names.get(index).copied().unwrap_or("Reserved").to_string()
+        };
+        match self.major_device_class_type() {
+            MajorDeviceClass::Computer => Some(lookup(&COMPUTER_MINOR_DEVICE_CLASS_NAMES, minor)),
+            MajorDeviceClass::Phone => Some(lookup(&PHONE_MINOR_DEVICE_CLASS_NAMES, minor)),
+            MajorDeviceClass::LanNetworkAccessPoint => Some(lookup(
+                &LAN_NETWORK_ACCESS_POINT_MINOR_DEVICE_CLASS_NAMES,
+                minor >> 3,
+            )),
+            MajorDeviceClass::AudioVideo => {
+                Some(lookup(&AUDIO_VIDEO_MINOR_DEVICE_CLASS_NAMES, minor))
+            }
+            MajorDeviceClass::Peripheral => {
+                let upper = lookup(&PERIPHERAL_MINOR_DEVICE_CLASS_UPPER_NAMES, minor >> 4);
+                let lower = lookup(&PERIPHERAL_MINOR_DEVICE_CLASS_LOWER_NAMES, minor & 0x0f);
+                Some(match (minor >> 4, minor & 0x0f) {
+                    (0, _) => lower,
+                    (_, 0) => upper,
+                    _ => format!("{}, {}", upper, lower),
+                })
+            }
+            MajorDeviceClass::Imaging => {
+                let names: Vec<&str> = IMAGING_MINOR_DEVICE_CLASS_NAMES
+                    .iter()
+                    .enumerate()
+                    .rev()
+                    .filter(|(bit, _)| minor & (1 << (bit + 2)) != 0)
+                    .map(|(_, name)| *name)
+                    .collect();
+                if names.is_empty() {
+                    Some("Uncategorized".to_string())
+                } else {
+                    Some(names.join(", "))
+                }
+            }
+            MajorDeviceClass::Wearable => Some(lookup(&WEARABLE_MINOR_DEVICE_CLASS_NAMES, minor)),
+            MajorDeviceClass::Toy => Some(lookup(&TOY_MINOR_DEVICE_CLASS_NAMES, minor)),
+            MajorDeviceClass::Health => Some(lookup(&HEALTH_MINOR_DEVICE_CLASS_NAMES, minor)),
+            _ => None,
+        }
+    }
+}
+
+/// Major Service Classes names (bit, name).
+pub const MAJOR_SERVICE_CLASS_NAMES: [(usize, &str); 10] = [
+    (13, "Limited Discoverable Mode"),
+    (14, "LE audio"),
+    (16, "Positioning"),
+    (17, "Networking"),
+    (18, "Rendering"),
+    (19, "Capturing"),
+    (20, "Object Transfer"),
+    (21, "Audio"),
+    (22, "Telephony"),
+    (23, "Information"),
+];
+
+/// Computer Minor Device Class names.
+pub const COMPUTER_MINOR_DEVICE_CLASS_NAMES: [&str; 8] = [
+    "Uncategorized",
+    "Desktop workstation",
+    "Server-class computer",
+    "Laptop",
+    "Handheld PC/PDA",
+    "Palm-size PC/PDA",
+    "Wearable computer",
+    "Tablet",
+];
+
+/// Phone Minor Device Class names.
+pub const PHONE_MINOR_DEVICE_CLASS_NAMES: [&str; 6] = [
+    "Uncategorized",
+    "Cellular",
+    "Cordless",
+    "Smartphone",
+    "Wired modem or voice gateway",
+    "Common ISDN access",
+];
+
+/// LAN/Network Access Point Minor Device Class names (load factor).
+pub const LAN_NETWORK_ACCESS_POINT_MINOR_DEVICE_CLASS_NAMES: [&str; 8] = [
+    "Fully available",
+    "1% to 17% utilized",
+    "17% to 33% utilized",
+    "33% to 50% utilized",
+    "50% to 67% utilized",
+    "67% to 83% utilized",
+    "83% to 99% utilized",
+    "No service available",
+];
+
+/// Audio/Video Minor Device Class names.
+pub const AUDIO_VIDEO_MINOR_DEVICE_CLASS_NAMES: [&str; 19] = [
+    "Uncategorized",
+    "Wearable Headset Device",
+    "Hands-free Device",
+    "Reserved",
+    "Microphone",
+    "Loudspeaker",
+    "Headphones",
+    "Portable Audio",
+    "Car audio",
+    "Set-top box",
+    "HiFi Audio Device",
+    "VCR",
+    "Video Camera",
+    "Camcorder",
+    "Video Monitor",
+    "Video Display and Loudspeaker",
+    "Video Conferencing",
+    "Reserved",
+    "Gaming/Toy",
+];
+
+/// Peripheral Minor Device Class names (bits 6-7).
+pub const PERIPHERAL_MINOR_DEVICE_CLASS_UPPER_NAMES: [&str; 4] = [
+    "Uncategorized",
+    "Keyboard",
+    "Pointing device",
+    "Combo keyboard/pointing device",
+];
+
+/// Peripheral Minor Device Class names (bits 2-5).
+pub const PERIPHERAL_MINOR_DEVICE_CLASS_LOWER_NAMES: [&str; 10] = [
+    "Uncategorized",
+    "Joystick",
+    "Gamepad",
+    "Remote control",
+    "Sensing device",
+    "Digitizer tablet",
+    "Card Reader",
+    "Digital Pen",
+    "Handheld scanner",
+    "Handheld gestural input device",
+];
+
+/// Imaging Minor Device Class names (bits 4-7).
+pub const IMAGING_MINOR_DEVICE_CLASS_NAMES: [&str; 4] = ["Display", "Camera", "Scanner", "Printer"];
+
+/// Wearable Minor Device Class names.
+pub const WEARABLE_MINOR_DEVICE_CLASS_NAMES: [&str; 7] = [
+    "Uncategorized",
+    "Wristwatch",
+    "Pager",
+    "Jacket",
+    "Helmet",
+    "Glasses",
+    "Pin",
+];
+
+/// Toy Minor Device Class names.
+pub const TOY_MINOR_DEVICE_CLASS_NAMES: [&str; 6] = [
+    "Uncategorized",
+    "Robot",
+    "Vehicle",
+    "Doll / Action figure",
+    "Controller",
+    "Game",
+];
+
+/// Health Minor Device Class names.
+pub const HEALTH_MINOR_DEVICE_CLASS_NAMES: [&str; 16] = [
+    "Undefined",
+    "Blood Pressure Monitor",
+    "Thermometer",
+    "Weighing Scale",
+    "Glucose Meter",
+    "Pulse Oximeter",
+    "Heart/Pulse Rate Monitor",
+    "Health Data Display",
+    "Step Counter",
+    "Body Composition Analyzer",
+    "Peak Flow Monitor",
+    "Medication Monitor",
+    "Knee Prosthesis",
+    "Ankle Prosthesis",
+    "Generic Health Manager",
+    "Personal Mobility Device",
+];
+
+/// Major Device Class.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum MajorDeviceClass {
+    /// Miscellaneous (`0b00000`).
+    Miscellaneous,
+    /// Computer (`0b00001`).
+    Computer,
+    /// Phone (`0b00010`).
+    Phone,
+    /// LAN/Network Access Point (`0b00011`).
+    LanNetworkAccessPoint,
+    /// Audio/Video (`0b00100`).
+    AudioVideo,
+    /// Peripheral (`0b00101`).
+    Peripheral,
+    /// Imaging (`0b00110`).
+    Imaging,
+    /// Wearable (`0b00111`).
+    Wearable,
+    /// Toy (`0b01000`).
+    Toy,
+    /// Health (`0b01001`).
+    Health,
+    /// Uncategorized (`0b11111`).
+    Uncategorized,
+    /// Reserved value.
+    Reserved(u8),
+}
+
+impl MajorDeviceClass {
+    /// Major Device Class name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::class_of_device::MajorDeviceClass;
+    ///
+    /// assert_eq!("Phone", MajorDeviceClass::Phone.name());
+    /// assert_eq!("Reserved", MajorDeviceClass::Reserved(0x0a).name());
+    /// ```
+    pub fn name(&self) -> &'static str {
+        match self {
+            MajorDeviceClass::Miscellaneous => "Miscellaneous",
+            MajorDeviceClass::Computer => "Computer",
+            MajorDeviceClass::Phone => "Phone",
+            MajorDeviceClass::LanNetworkAccessPoint => "LAN/Network Access Point",
+            MajorDeviceClass::AudioVideo => "Audio/Video",
+            MajorDeviceClass::Peripheral => "Peripheral",
+            MajorDeviceClass::Imaging => "Imaging",
+            MajorDeviceClass::Wearable => "Wearable",
+            MajorDeviceClass::Toy => "Toy",
+            MajorDeviceClass::Health => "Health",
+            MajorDeviceClass::Uncategorized => "Uncategorized",
+            MajorDeviceClass::Reserved(_) => "Reserved",
+        }
+    }
+}
+
+impl From<u8> for MajorDeviceClass {
+    /// Create [`MajorDeviceClass`] from 5-bit Major Device Class value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::class_of_device::MajorDeviceClass;
+    ///
+    /// assert_eq!(MajorDeviceClass::Health, MajorDeviceClass::from(0b01001));
+    /// assert_eq!(MajorDeviceClass::Reserved(0b01010), MajorDeviceClass::from(0b01010));
+    /// ```
+    fn from(value: u8) -> Self {
+        match value & 0b11111 {
+            0b00000 => MajorDeviceClass::Miscellaneous,
+            0b00001 => MajorDeviceClass::Computer,
+            0b00010 => MajorDeviceClass::Phone,
+            0b00011 => MajorDeviceClass::LanNetworkAccessPoint,
+            0b00100 => MajorDeviceClass::AudioVideo,
+            0b00101 => MajorDeviceClass::Peripheral,
+            0b00110 => MajorDeviceClass::Imaging,
+            0b00111 => MajorDeviceClass::Wearable,
+            0b01000 => MajorDeviceClass::Toy,
+            0b01001 => MajorDeviceClass::Health,
+            0b11111 => MajorDeviceClass::Uncategorized,
+            value => MajorDeviceClass::Reserved(value),
+        }
+    }
+}
+
+impl From<MajorDeviceClass> for u8 {
+    /// Create 5-bit Major Device Class value from [`MajorDeviceClass`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::class_of_device::MajorDeviceClass;
+    ///
+    /// assert_eq!(0b00010, u8::from(MajorDeviceClass::Phone));
+    /// assert_eq!(0b01010, u8::from(MajorDeviceClass::Reserved(0b01010)));
+    /// ```
+    fn from(value: MajorDeviceClass) -> Self {
+        match value {
+            MajorDeviceClass::Miscellaneous => 0b00000,
+            MajorDeviceClass::Computer => 0b00001,
+            MajorDeviceClass::Phone => 0b00010,
+            MajorDeviceClass::LanNetworkAccessPoint => 0b00011,
+            MajorDeviceClass::AudioVideo => 0b00100,
+            MajorDeviceClass::Peripheral => 0b00101,
+            MajorDeviceClass::Imaging => 0b00110,
+            MajorDeviceClass::Wearable => 0b00111,
+            MajorDeviceClass::Toy => 0b01000,
+            MajorDeviceClass::Health => 0b01001,
+            MajorDeviceClass::Uncategorized => 0b11111,
+            MajorDeviceClass::Reserved(value) => value,
+        }
+    }
+}
+
+impl fmt::Display for MajorDeviceClass {
+    /// Format [`MajorDeviceClass`] with its name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::class_of_device::MajorDeviceClass;
+    ///
+    /// assert_eq!("Audio/Video", MajorDeviceClass::AudioVideo.to_string());
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
 }
 
 /// Major Service Classes mask
@@ -106,9 +480,9 @@ pub const CLASS_OF_DEVICE_MAJOR_DEVICE_CLASS_MASK: u32 = 0b00000000_00011111_000
 /// Minor Device Class mask
 pub const CLASS_OF_DEVICE_MINOR_DEVICE_CLASS_MASK: u32 = 0b00000000_00000000_11111100;
 
-impl TryFrom<&Vec<u8>> for ClassOfDevice {
+impl TryFrom<&[u8]> for ClassOfDevice {
     type Error = String;
-    /// Create [`ClassOfDevice`] from [`Vec<u8>`].
+    /// Create [`ClassOfDevice`] from `u8` slice.
     ///
     /// # Examples
     ///
@@ -127,35 +501,73 @@ impl TryFrom<&Vec<u8>> for ClassOfDevice {
     /// data.push((class_of_device >> 8) as u8);
     /// data.push((class_of_device >> 16) as u8);
     ///
-    /// let result = ClassOfDevice::try_from(&data);
+    /// let result = ClassOfDevice::try_from(data.as_slice());
     /// assert!(result.is_ok());
     /// let data_type = result.unwrap();
     /// assert_eq!(length, data_type.length);
     /// assert_eq!(class_of_device, data_type.class_of_device);
     ///
     /// let data: Vec<u8> = Vec::new();
-    /// let result = ClassOfDevice::try_from(&data);
+    /// let result = ClassOfDevice::try_from(data.as_slice());
     /// assert!(result.is_err());
     /// assert_eq!(
     ///     format!("Invalid data size :{}", data.len()),
     ///     result.unwrap_err()
     /// );
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+    fn try_from(value: &[u8]) -> Result<Self, String> {
         let len = value.len();
         if len < 5 {
             return Err(format!("Invalid data size :{}", len).to_string());
         }
-        let mut value = value.to_vec();
-        let length = value[0];
-        value.push(0);
+        let length = validate_length(value, 4, 4)?;
         Ok(Self {
             length,
-            class_of_device: u32::from_le_bytes(value[2..6].try_into().unwrap()) & 0x00ffffff,
+            class_of_device: u32::from_le_bytes([value[2], value[3], value[4], 0]),
         })
     }
 }
 
+impl TryFrom<&Vec<u8>> for ClassOfDevice {
+    type Error = String;
+    /// Create [`ClassOfDevice`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{class_of_device::ClassOfDevice, data_type::DataType};
+    ///
+    /// let major_service_classes = 0b10000000_00000000_00000000;
+    /// let major_device_class = 0b00000000_00000001_00000000;
+    /// let minor_device_class = 0b00000000_00000000_00000100;
+    /// let class_of_device = major_service_classes | major_device_class | minor_device_class;
+    /// let length = 4;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(ClassOfDevice::data_type());
+    /// data.push(class_of_device as u8);
+    /// data.push((class_of_device >> 8) as u8);
+    /// data.push((class_of_device >> 16) as u8);
+    ///
+    /// let result = ClassOfDevice::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert_eq!(class_of_device, data_type.class_of_device);
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = ClassOfDevice::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        Self::try_from(value.as_slice())
+    }
+}
+
 impl Into<Vec<u8>> for ClassOfDevice {
     /// Create [`Vec<u8>`] from [`ClassOfDevice`].
     ///
@@ -197,6 +609,33 @@ impl Into<Vec<u8>> for ClassOfDevice {
     }
 }
 
+impl fmt::Display for ClassOfDevice {
+    /// Format [`ClassOfDevice`] as `Major / Minor; Service, ...`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::class_of_device::ClassOfDevice;
+    ///
+    /// let result = ClassOfDevice::new(0b01100000_00000010_00001100);
+    /// assert_eq!("Phone / Smartphone; Audio, Telephony", result.to_string());
+    ///
+    /// let result = ClassOfDevice::new(0b00000000_00000000_00000000);
+    /// assert_eq!("Miscellaneous", result.to_string());
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.major_device_class_type())?;
+        if let Some(minor) = self.minor_device_class_name() {
+            write!(f, " / {}", minor)?;
+        }
+        let services = self.major_service_class_names();
+        if !services.is_empty() {
+            write!(f, "; {}", services.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
 impl DataType for ClassOfDevice {
     /// return `0x0d`.
     ///
@@ -223,8 +662,9 @@ impl DataType for ClassOfDevice {
 /// assert!(is_class_of_device(0x0d));
 /// assert!(!is_class_of_device(0x00));
 /// ```
+#[deprecated(note = "use `AdType::of(data_type).matches::<ClassOfDevice>()` instead")]
 pub fn is_class_of_device(data_type: u8) -> bool {
-    ClassOfDevice::data_type() == data_type
+    AdType::of(data_type).matches::<ClassOfDevice>()
 }
 
 #[cfg(test)]
@@ -278,6 +718,109 @@ mod tests {
         assert_eq!(minor_device_class, result.minor_device_class());
     }
 
+    #[test]
+    fn test_major_device_class_type() {
+        for value in 0u8..0x20 {
+            let result = ClassOfDevice::new((value as u32) << 8);
+            let major = result.major_device_class_type();
+            assert_eq!(value, u8::from(major));
+            match value {
+                0x0a..=0x1e => assert_eq!(MajorDeviceClass::Reserved(value), major),
+                _ => assert!(!matches!(major, MajorDeviceClass::Reserved(_))),
+            }
+        }
+    }
+
+    #[test]
+    fn test_major_service_class_names() {
+        let result = ClassOfDevice::new(0b11111111_11100000_00000000);
+        assert_eq!(
+            vec![
+                "Limited Discoverable Mode",
+                "LE audio",
+                "Positioning",
+                "Networking",
+                "Rendering",
+                "Capturing",
+                "Object Transfer",
+                "Audio",
+                "Telephony",
+                "Information",
+            ],
+            result.major_service_class_names()
+        );
+
+        let result = ClassOfDevice::new(0b00000000_00011111_11111100);
+        assert!(result.major_service_class_names().is_empty());
+    }
+
+    #[test]
+    fn test_minor_device_class_name() {
+        let name = |major: u32, minor: u32| {
+            ClassOfDevice::new((major << 8) | (minor << 2)).minor_device_class_name()
+        };
+        assert_eq!(None, name(0b00000, 0x3f));
+        assert_eq!(Some("Laptop".to_string()), name(0b00001, 3));
+        assert_eq!(Some("Reserved".to_string()), name(0b00001, 8));
+        assert_eq!(Some("Smartphone".to_string()), name(0b00010, 3));
+        assert_eq!(Some("Fully available".to_string()), name(0b00011, 0b000111));
+        assert_eq!(
+            Some("No service available".to_string()),
+            name(0b00011, 0b111000)
+        );
+        assert_eq!(Some("Headphones".to_string()), name(0b00100, 6));
+        assert_eq!(Some("Gaming/Toy".to_string()), name(0b00100, 18));
+        assert_eq!(Some("Reserved".to_string()), name(0b00100, 19));
+        assert_eq!(Some("Uncategorized".to_string()), name(0b00101, 0));
+        assert_eq!(Some("Keyboard".to_string()), name(0b00101, 0b010000));
+        assert_eq!(Some("Gamepad".to_string()), name(0b00101, 0b000010));
+        assert_eq!(
+            Some("Combo keyboard/pointing device, Remote control".to_string()),
+            name(0b00101, 0b110011)
+        );
+        assert_eq!(Some("Uncategorized".to_string()), name(0b00110, 0b000000));
+        assert_eq!(Some("Display".to_string()), name(0b00110, 0b000100));
+        assert_eq!(
+            Some("Printer, Scanner, Camera, Display".to_string()),
+            name(0b00110, 0b111100)
+        );
+        assert_eq!(Some("Glasses".to_string()), name(0b00111, 5));
+        assert_eq!(Some("Game".to_string()), name(0b01000, 5));
+        assert_eq!(
+            Some("Personal Mobility Device".to_string()),
+            name(0b01001, 15)
+        );
+        assert_eq!(Some("Reserved".to_string()), name(0b01001, 16));
+        assert_eq!(None, name(0b11111, 1));
+        assert_eq!(None, name(0b01010, 1));
+    }
+
+    #[test]
+    fn test_major_device_class_name() {
+        assert_eq!("Miscellaneous", MajorDeviceClass::Miscellaneous.name());
+        assert_eq!(
+            "LAN/Network Access Point",
+            MajorDeviceClass::LanNetworkAccessPoint.to_string()
+        );
+        assert_eq!("Uncategorized", MajorDeviceClass::Uncategorized.name());
+        assert_eq!("Reserved", MajorDeviceClass::Reserved(0x1e).to_string());
+    }
+
+    #[test]
+    fn test_display() {
+        let result = ClassOfDevice::new(0b01100000_00000010_00001100);
+        assert_eq!("Phone / Smartphone; Audio, Telephony", result.to_string());
+
+        let result = ClassOfDevice::new(0b00000000_00000100_00011000);
+        assert_eq!("Audio/Video / Headphones", result.to_string());
+
+        let result = ClassOfDevice::new(0b00000000_00111111_00000000);
+        assert_eq!(
+            "Uncategorized; Limited Discoverable Mode",
+            result.to_string()
+        );
+    }
+
     #[test]
     fn test_try_from() {
         let major_service_classes = 0b10000000_00000000_00000000;
@@ -339,6 +882,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_is_class_of_device() {
         assert!(is_class_of_device(0x0d));
         assert!(!is_class_of_device(0x00));
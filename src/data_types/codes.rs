@@ -0,0 +1,346 @@
+//! Assigned EIR/AD/SRD/ACAD/OOB data type values module.
+
+/// Flags
+pub const FLAGS: u8 = 0x01;
+
+/// Incomplete List of 16-bit Service or Service Class UUIDs
+pub const INCOMPLETE_LIST_OF_16BIT_SERVICE_UUIDS: u8 = 0x02;
+
+/// Complete List of 16-bit Service or Service Class UUIDs
+pub const COMPLETE_LIST_OF_16BIT_SERVICE_UUIDS: u8 = 0x03;
+
+/// Incomplete List of 32-bit Service or Service Class UUIDs
+pub const INCOMPLETE_LIST_OF_32BIT_SERVICE_UUIDS: u8 = 0x04;
+
+/// Complete List of 32-bit Service or Service Class UUIDs
+pub const COMPLETE_LIST_OF_32BIT_SERVICE_UUIDS: u8 = 0x05;
+
+/// Incomplete List of 128-bit Service or Service Class UUIDs
+pub const INCOMPLETE_LIST_OF_128BIT_SERVICE_UUIDS: u8 = 0x06;
+
+/// Complete List of 128-bit Service or Service Class UUIDs
+pub const COMPLETE_LIST_OF_128BIT_SERVICE_UUIDS: u8 = 0x07;
+
+/// Shortened Local Name
+pub const SHORTENED_LOCAL_NAME: u8 = 0x08;
+
+/// Complete Local Name
+pub const COMPLETE_LOCAL_NAME: u8 = 0x09;
+
+/// Tx Power Level
+pub const TX_POWER_LEVEL: u8 = 0x0a;
+
+/// Class of Device
+pub const CLASS_OF_DEVICE: u8 = 0x0d;
+
+/// Simple Pairing Hash C-192
+pub const SIMPLE_PAIRING_HASH_C192: u8 = 0x0e;
+
+/// Simple Pairing Randomizer R-192
+pub const SIMPLE_PAIRING_RANDOMIZER_R192: u8 = 0x0f;
+
+/// Device ID
+pub const DEVICE_ID: u8 = 0x10;
+
+/// Security Manager TK Value
+pub const SECURITY_MANAGER_TK_VALUE: u8 = 0x10;
+
+/// Security Manager Out of Band Flags
+pub const SECURITY_MANAGER_OUT_OF_BAND_FLAGS: u8 = 0x11;
+
+/// Peripheral Connection Interval Range
+pub const PERIPHERAL_CONNECTION_INTERVAL_RANGE: u8 = 0x12;
+
+/// List of 16-bit Service Solicitation UUIDs
+pub const LIST_OF_16BIT_SERVICE_SOLICITATION_UUIDS: u8 = 0x14;
+
+/// List of 128-bit Service Solicitation UUIDs
+pub const LIST_OF_128BIT_SERVICE_SOLICITATION_UUIDS: u8 = 0x15;
+
+/// Service Data - 16-bit UUID
+pub const SERVICE_DATA_16BIT_UUID: u8 = 0x16;
+
+/// Public Target Address
+pub const PUBLIC_TARGET_ADDRESS: u8 = 0x17;
+
+/// Random Target Address
+pub const RANDOM_TARGET_ADDRESS: u8 = 0x18;
+
+/// Appearance
+pub const APPEARANCE: u8 = 0x19;
+
+/// Advertising Interval
+pub const ADVERTISING_INTERVAL: u8 = 0x1a;
+
+/// LE Bluetooth Device Address
+pub const LE_BLUETOOTH_DEVICE_ADDRESS: u8 = 0x1b;
+
+/// LE Role
+pub const LE_ROLE: u8 = 0x1c;
+
+/// Simple Pairing Hash C-256
+pub const SIMPLE_PAIRING_HASH_C256: u8 = 0x1d;
+
+/// Simple Pairing Randomizer R-256
+pub const SIMPLE_PAIRING_RANDOMIZER_R256: u8 = 0x1e;
+
+/// List of 32-bit Service Solicitation UUIDs
+pub const LIST_OF_32BIT_SERVICE_SOLICITATION_UUIDS: u8 = 0x1f;
+
+/// Service Data - 32-bit UUID
+pub const SERVICE_DATA_32BIT_UUID: u8 = 0x20;
+
+/// Service Data - 128-bit UUID
+pub const SERVICE_DATA_128BIT_UUID: u8 = 0x21;
+
+/// LE Secure Connections Confirmation Value
+pub const LE_SECURE_CONNECTIONS_CONFIRMATION_VALUE: u8 = 0x22;
+
+/// LE Secure Connections Random Value
+pub const LE_SECURE_CONNECTIONS_RANDOM_VALUE: u8 = 0x23;
+
+/// URI
+pub const URI: u8 = 0x24;
+
+/// Indoor Positioning
+pub const INDOOR_POSITIONING: u8 = 0x25;
+
+/// Transport Discovery Data
+pub const TRANSPORT_DISCOVERY_DATA: u8 = 0x26;
+
+/// LE Supported Features
+pub const LE_SUPPORTED_FEATURES: u8 = 0x27;
+
+/// Channel Map Update Indication
+pub const CHANNEL_MAP_UPDATE_INDICATION: u8 = 0x28;
+
+/// PB-ADV
+pub const PB_ADV: u8 = 0x29;
+
+/// Mesh Message
+pub const MESH_MESSAGE: u8 = 0x2a;
+
+/// Mesh Beacon
+pub const MESH_BEACON: u8 = 0x2b;
+
+/// BIGInfo
+pub const BIG_INFO: u8 = 0x2c;
+
+/// Broadcast_Code
+pub const BROADCAST_CODE: u8 = 0x2d;
+
+/// Resolvable Set Identifier
+pub const RESOLVABLE_SET_IDENTIFIER: u8 = 0x2e;
+
+/// Advertising Interval - long
+pub const ADVERTISING_INTERVAL_LONG: u8 = 0x2f;
+
+/// Broadcast_Name
+pub const BROADCAST_NAME: u8 = 0x30;
+
+/// Encrypted Advertising Data
+pub const ENCRYPTED_ADVERTISING_DATA: u8 = 0x31;
+
+/// Periodic Advertising Response Timing Information
+pub const PERIODIC_ADVERTISING_RESPONSE_TIMING_INFORMATION: u8 = 0x32;
+
+/// Electronic Shelf Label
+pub const ELECTRONIC_SHELF_LABEL: u8 = 0x34;
+
+/// 3D Information Data
+pub const THREE_D_INFORMATION_DATA: u8 = 0x3d;
+
+/// Manufacturer Specific Data
+pub const MANUFACTURER_SPECIFIC_DATA: u8 = 0xff;
+
+#[cfg(test)]
+mod tests {
+    use crate::data_types::{
+        advertising_interval::AdvertisingInterval,
+        advertising_interval_long::AdvertisingIntervalLong, appearance::Appearance,
+        channel_map_update_indication::ChannelMapUpdateIndication, class_of_device::ClassOfDevice,
+        codes::*, complete_list_of_128bit_service_uuids::CompleteListOf128BitServiceUuids,
+        complete_list_of_16bit_service_uuids::CompleteListOf16BitServiceUuids,
+        complete_list_of_32bit_service_uuids::CompleteListOf32BitServiceUuids,
+        complete_local_name::CompleteLocalName, data_type::DataType,
+        electronic_shelf_label::ElectronicShelfLabel, encrypted_data::EncryptedData, flags::Flags,
+        incomplete_list_of_128bit_service_uuids::IncompleteListOf128BitServiceUuids,
+        incomplete_list_of_16bit_service_uuids::IncompleteListOf16BitServiceUuids,
+        incomplete_list_of_32bit_service_uuids::IncompleteListOf32BitServiceUuids,
+        indoor_positioning::IndoorPositioning,
+        le_bluetooth_device_address::LeBluetoothDeviceAddress, le_role::LeRole,
+        le_supported_features::LeSupportedFeatures,
+        list_of_128bit_service_solicitation_uuids::ListOf128BitServiceSolicitationUUIDs,
+        list_of_16bit_service_solicitation_uuids::ListOf16BitServiceSolicitationUUIDs,
+        list_of_32bit_service_solicitation_uuids::ListOf32BitServiceSolicitationUUIDs,
+        manufacturer_specific_data::ManufacturerSpecificData,
+        periodic_advertising_response_timing_information::PeriodicAdvertisingResponseTimingInformation,
+        peripheral_connection_interval_range::PeripheralConnectionIntervalRange,
+        public_target_address::PublicTargetAddress, random_target_address::RandomTargetAddress,
+        service_data_128bit_uuid::ServiceData128BitUUID,
+        service_data_16bit_uuid::ServiceData16BitUUID,
+        service_data_32bit_uuid::ServiceData32BitUUID, shortened_local_name::ShortenedLocalName,
+        three_d_information_data::ThreeDInformationData,
+        transport_discovery_data::TransportDiscoveryData, tx_power_level::TxPowerLevel,
+        uniform_resource_identifier::UniformResourceIdentifier,
+    };
+
+    #[cfg(feature = "le-audio")]
+    use crate::data_types::{
+        big_info::BigInfo, broadcast_code::BroadcastCode,
+        resolvable_set_identifier::ResolvableSetIdentifier,
+    };
+
+    #[cfg(feature = "mesh")]
+    use crate::data_types::mesh_beacon::MeshBeacon;
+
+    #[cfg(feature = "pairing")]
+    use crate::data_types::{
+        le_secure_connections_confirmation_value::LeSecureConnectionsConfirmationValue,
+        le_secure_connections_random_value::LeSecureConnectionsRandomValue,
+        secure_simple_pairing_hash_c192::SecureSimplePairingHashC192,
+        secure_simple_pairing_hash_c256::SecureSimplePairingHashC256,
+        secure_simple_pairing_randomizer_r192::SecureSimplePairingRandomizerR192,
+        secure_simple_pairing_randomizer_r256::SecureSimplePairingRandomizerR256,
+        security_manager_oob::SecurityManagerOutOfBand,
+        security_manager_tk_value::SecurityManagerTkValue,
+    };
+
+    #[test]
+    fn test_data_type() {
+        assert_eq!(FLAGS, Flags::data_type());
+        assert_eq!(
+            INCOMPLETE_LIST_OF_16BIT_SERVICE_UUIDS,
+            IncompleteListOf16BitServiceUuids::data_type()
+        );
+        assert_eq!(
+            COMPLETE_LIST_OF_16BIT_SERVICE_UUIDS,
+            CompleteListOf16BitServiceUuids::data_type()
+        );
+        assert_eq!(
+            INCOMPLETE_LIST_OF_32BIT_SERVICE_UUIDS,
+            IncompleteListOf32BitServiceUuids::data_type()
+        );
+        assert_eq!(
+            COMPLETE_LIST_OF_32BIT_SERVICE_UUIDS,
+            CompleteListOf32BitServiceUuids::data_type()
+        );
+        assert_eq!(
+            INCOMPLETE_LIST_OF_128BIT_SERVICE_UUIDS,
+            IncompleteListOf128BitServiceUuids::data_type()
+        );
+        assert_eq!(
+            COMPLETE_LIST_OF_128BIT_SERVICE_UUIDS,
+            CompleteListOf128BitServiceUuids::data_type()
+        );
+        assert_eq!(SHORTENED_LOCAL_NAME, ShortenedLocalName::data_type());
+        assert_eq!(COMPLETE_LOCAL_NAME, CompleteLocalName::data_type());
+        assert_eq!(TX_POWER_LEVEL, TxPowerLevel::data_type());
+        assert_eq!(CLASS_OF_DEVICE, ClassOfDevice::data_type());
+        #[cfg(feature = "pairing")]
+        assert_eq!(
+            SIMPLE_PAIRING_HASH_C192,
+            SecureSimplePairingHashC192::data_type()
+        );
+        #[cfg(feature = "pairing")]
+        assert_eq!(
+            SIMPLE_PAIRING_RANDOMIZER_R192,
+            SecureSimplePairingRandomizerR192::data_type()
+        );
+        #[cfg(feature = "pairing")]
+        assert_eq!(
+            SECURITY_MANAGER_TK_VALUE,
+            SecurityManagerTkValue::data_type()
+        );
+        #[cfg(feature = "pairing")]
+        assert_eq!(
+            SECURITY_MANAGER_OUT_OF_BAND_FLAGS,
+            SecurityManagerOutOfBand::data_type()
+        );
+        assert_eq!(
+            PERIPHERAL_CONNECTION_INTERVAL_RANGE,
+            PeripheralConnectionIntervalRange::data_type()
+        );
+        assert_eq!(
+            LIST_OF_16BIT_SERVICE_SOLICITATION_UUIDS,
+            ListOf16BitServiceSolicitationUUIDs::data_type()
+        );
+        assert_eq!(
+            LIST_OF_128BIT_SERVICE_SOLICITATION_UUIDS,
+            ListOf128BitServiceSolicitationUUIDs::data_type()
+        );
+        assert_eq!(SERVICE_DATA_16BIT_UUID, ServiceData16BitUUID::data_type());
+        assert_eq!(PUBLIC_TARGET_ADDRESS, PublicTargetAddress::data_type());
+        assert_eq!(RANDOM_TARGET_ADDRESS, RandomTargetAddress::data_type());
+        assert_eq!(APPEARANCE, Appearance::data_type());
+        assert_eq!(ADVERTISING_INTERVAL, AdvertisingInterval::data_type());
+        assert_eq!(
+            LE_BLUETOOTH_DEVICE_ADDRESS,
+            LeBluetoothDeviceAddress::data_type()
+        );
+        assert_eq!(LE_ROLE, LeRole::data_type());
+        #[cfg(feature = "pairing")]
+        assert_eq!(
+            SIMPLE_PAIRING_HASH_C256,
+            SecureSimplePairingHashC256::data_type()
+        );
+        #[cfg(feature = "pairing")]
+        assert_eq!(
+            SIMPLE_PAIRING_RANDOMIZER_R256,
+            SecureSimplePairingRandomizerR256::data_type()
+        );
+        assert_eq!(
+            LIST_OF_32BIT_SERVICE_SOLICITATION_UUIDS,
+            ListOf32BitServiceSolicitationUUIDs::data_type()
+        );
+        assert_eq!(SERVICE_DATA_32BIT_UUID, ServiceData32BitUUID::data_type());
+        assert_eq!(SERVICE_DATA_128BIT_UUID, ServiceData128BitUUID::data_type());
+        #[cfg(feature = "pairing")]
+        assert_eq!(
+            LE_SECURE_CONNECTIONS_CONFIRMATION_VALUE,
+            LeSecureConnectionsConfirmationValue::data_type()
+        );
+        #[cfg(feature = "pairing")]
+        assert_eq!(
+            LE_SECURE_CONNECTIONS_RANDOM_VALUE,
+            LeSecureConnectionsRandomValue::data_type()
+        );
+        assert_eq!(URI, UniformResourceIdentifier::data_type());
+        assert_eq!(INDOOR_POSITIONING, IndoorPositioning::data_type());
+        assert_eq!(
+            TRANSPORT_DISCOVERY_DATA,
+            TransportDiscoveryData::data_type()
+        );
+        assert_eq!(LE_SUPPORTED_FEATURES, LeSupportedFeatures::data_type());
+        assert_eq!(
+            CHANNEL_MAP_UPDATE_INDICATION,
+            ChannelMapUpdateIndication::data_type()
+        );
+        #[cfg(feature = "mesh")]
+        assert_eq!(MESH_BEACON, MeshBeacon::data_type());
+        #[cfg(feature = "le-audio")]
+        assert_eq!(BIG_INFO, BigInfo::data_type());
+        #[cfg(feature = "le-audio")]
+        assert_eq!(BROADCAST_CODE, BroadcastCode::data_type());
+        #[cfg(feature = "le-audio")]
+        assert_eq!(
+            RESOLVABLE_SET_IDENTIFIER,
+            ResolvableSetIdentifier::data_type()
+        );
+        assert_eq!(
+            ADVERTISING_INTERVAL_LONG,
+            AdvertisingIntervalLong::data_type()
+        );
+        assert_eq!(ENCRYPTED_ADVERTISING_DATA, EncryptedData::data_type());
+        assert_eq!(
+            PERIODIC_ADVERTISING_RESPONSE_TIMING_INFORMATION,
+            PeriodicAdvertisingResponseTimingInformation::data_type()
+        );
+        assert_eq!(ELECTRONIC_SHELF_LABEL, ElectronicShelfLabel::data_type());
+        assert_eq!(THREE_D_INFORMATION_DATA, ThreeDInformationData::data_type());
+        assert_eq!(
+            MANUFACTURER_SPECIFIC_DATA,
+            ManufacturerSpecificData::data_type()
+        );
+    }
+}
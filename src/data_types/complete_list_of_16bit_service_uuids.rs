@@ -2,7 +2,13 @@
 
 use uuid::Uuid;
 
-use crate::{data_types::data_type::DataType, BASE_UUID};
+use crate::{
+    data_types::{
+        ad_type::AdType,
+        data_type::{validate_length, DataType, UuidList},
+    },
+    BASE_UUID,
+};
 
 /// Complete List of 16-bit Service Class UUIDs.
 #[derive(Debug, PartialEq, Clone)]
@@ -11,7 +17,7 @@ pub struct CompleteListOf16BitServiceUuids {
     pub length: u8,
 
     /// UUIDs
-    pub uuids: Vec<Uuid>,
+    pub uuids: UuidList,
 }
 
 impl CompleteListOf16BitServiceUuids {
@@ -30,19 +36,19 @@ impl CompleteListOf16BitServiceUuids {
     /// .to_vec();
     /// let result = CompleteListOf16BitServiceUuids::new(&uuids);
     /// assert_eq!(uuids.len() as u8 * 2 + 1, result.length);
-    /// assert_eq!(uuids, result.uuids);
+    /// assert_eq!(uuids, result.uuids.as_slice());
     /// ```
     pub fn new(uuids: &Vec<Uuid>) -> Self {
         Self {
             length: (uuids.len() * 2 + 1) as u8,
-            uuids: uuids.clone(),
+            uuids: uuids.as_slice().into(),
         }
     }
 }
 
-impl TryFrom<&Vec<u8>> for CompleteListOf16BitServiceUuids {
+impl TryFrom<&[u8]> for CompleteListOf16BitServiceUuids {
     type Error = String;
-    /// Create [`CompleteListOf16BitServiceUuids`] from [`Vec<u8>`].
+    /// Create [`CompleteListOf16BitServiceUuids`] from `u8` slice.
     ///
     /// # Examples
     ///
@@ -65,18 +71,21 @@ impl TryFrom<&Vec<u8>> for CompleteListOf16BitServiceUuids {
     /// data.push(CompleteListOf16BitServiceUuids::data_type());
     /// data.append(&mut uuid_bytes.clone());
     ///
-    /// let result = CompleteListOf16BitServiceUuids::try_from(&data);
+    /// let result = CompleteListOf16BitServiceUuids::try_from(data.as_slice());
     /// assert!(result.is_ok());
     /// let data_type = result.unwrap();
     /// assert_eq!(length, data_type.length);
-    /// assert_eq!(uuids, data_type.uuids);
+    /// assert_eq!(uuids, data_type.uuids.as_slice());
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+    fn try_from(value: &[u8]) -> Result<Self, String> {
         let len = value.len();
         if len < 4 {
             return Err(format!("Invalid data size :{}", len).to_string());
         }
-        let length = value[0];
+        let length = validate_length(value, 3, 255)?;
+        if !(length - 1).is_multiple_of(2) {
+            return Err(format!("Invalid length :{}", length).to_string());
+        }
         Ok(Self {
             length,
             uuids: value[2..2 + length as usize - 1]
@@ -93,6 +102,42 @@ impl TryFrom<&Vec<u8>> for CompleteListOf16BitServiceUuids {
     }
 }
 
+impl TryFrom<&Vec<u8>> for CompleteListOf16BitServiceUuids {
+    type Error = String;
+    /// Create [`CompleteListOf16BitServiceUuids`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{BASE_UUID, data_types::{complete_list_of_16bit_service_uuids::CompleteListOf16BitServiceUuids, data_type::DataType}};
+    /// use uuid::{uuid, Uuid};
+    ///
+    /// let uuid_bytes: Vec<u8> = [0x01u8, 0x02u8, 0x03u8, 0x04u8].to_vec();
+    /// let uuids: Vec<Uuid> = uuid_bytes
+    ///     .windows(2)
+    ///     .step_by(2)
+    ///     .map(|f| {
+    ///         let (d1, d2, d3, d4) = BASE_UUID.as_fields();
+    ///         Uuid::from_fields(d1 | ((f[0] as u32) << 0) | ((f[1] as u32) << 8), d2, d3, d4)
+    ///     })
+    ///     .collect();
+    /// let length = uuid_bytes.len() as u8 + 1;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(CompleteListOf16BitServiceUuids::data_type());
+    /// data.append(&mut uuid_bytes.clone());
+    ///
+    /// let result = CompleteListOf16BitServiceUuids::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert_eq!(uuids, data_type.uuids.as_slice());
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        Self::try_from(value.as_slice())
+    }
+}
+
 impl Into<Vec<u8>> for CompleteListOf16BitServiceUuids {
     /// Create [`Vec<u8>`] from [`CompleteListOf16BitServiceUuids`].
     ///
@@ -181,8 +226,11 @@ impl DataType for CompleteListOf16BitServiceUuids {
 /// assert!(is_complete_list_of_16bit_service_uuids(0x03));
 /// assert!(!is_complete_list_of_16bit_service_uuids(0x00));
 /// ```
+#[deprecated(
+    note = "use `AdType::of(data_type).matches::<CompleteListOf16BitServiceUuids>()` instead"
+)]
 pub fn is_complete_list_of_16bit_service_uuids(data_type: u8) -> bool {
-    CompleteListOf16BitServiceUuids::data_type() == data_type
+    AdType::of(data_type).matches::<CompleteListOf16BitServiceUuids>()
 }
 
 #[cfg(test)]
@@ -203,7 +251,7 @@ mod tests {
         .to_vec();
         let result = CompleteListOf16BitServiceUuids::new(&uuids);
         assert_eq!(uuids.len() as u8 * 2 + 1, result.length);
-        assert_eq!(uuids, result.uuids);
+        assert_eq!(uuids, result.uuids.as_slice());
     }
 
     #[test]
@@ -227,7 +275,7 @@ mod tests {
         assert!(result.is_ok());
         let data_type = result.unwrap();
         assert_eq!(length, data_type.length);
-        assert_eq!(uuids, data_type.uuids);
+        assert_eq!(uuids, data_type.uuids.as_slice());
 
         let mut data: Vec<u8> = vec![0u8; 3];
         data[0] = data.len() as u8 - 1;
@@ -274,6 +322,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_is_complete_list_of_16bit_service_uuids() {
         assert!(is_complete_list_of_16bit_service_uuids(0x03));
         assert!(!is_complete_list_of_16bit_service_uuids(0x00));
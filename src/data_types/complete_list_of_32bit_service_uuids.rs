@@ -2,7 +2,13 @@
 
 use uuid::Uuid;
 
-use crate::{data_types::data_type::DataType, BASE_UUID};
+use crate::{
+    data_types::{
+        ad_type::AdType,
+        data_type::{validate_length, DataType, UuidList},
+    },
+    BASE_UUID,
+};
 
 /// Complete List of 32-bit Service Class UUIDs.
 #[derive(Debug, PartialEq, Clone)]
@@ -11,7 +17,7 @@ pub struct CompleteListOf32BitServiceUuids {
     pub length: u8,
 
     /// UUIDs
-    pub uuids: Vec<Uuid>,
+    pub uuids: UuidList,
 }
 
 impl CompleteListOf32BitServiceUuids {
@@ -30,19 +36,19 @@ impl CompleteListOf32BitServiceUuids {
     /// .to_vec();
     /// let result = CompleteListOf32BitServiceUuids::new(&uuids);
     /// assert_eq!(uuids.len() as u8 * 4 + 1, result.length);
-    /// assert_eq!(uuids, result.uuids);
+    /// assert_eq!(uuids, result.uuids.as_slice());
     /// ```
     pub fn new(uuids: &Vec<Uuid>) -> Self {
         Self {
             length: (uuids.len() * 4 + 1) as u8,
-            uuids: uuids.clone(),
+            uuids: uuids.as_slice().into(),
         }
     }
 }
 
-impl TryFrom<&Vec<u8>> for CompleteListOf32BitServiceUuids {
+impl TryFrom<&[u8]> for CompleteListOf32BitServiceUuids {
     type Error = String;
-    /// Create [`CompleteListOf32BitServiceUuids`] from [`Vec<u8>`].
+    /// Create [`CompleteListOf32BitServiceUuids`] from `u8` slice.
     ///
     /// # Examples
     ///
@@ -69,26 +75,29 @@ impl TryFrom<&Vec<u8>> for CompleteListOf32BitServiceUuids {
     /// data.push(CompleteListOf32BitServiceUuids::data_type());
     /// data.append(&mut uuid_bytes.clone());
     ///
-    /// let result = CompleteListOf32BitServiceUuids::try_from(&data);
+    /// let result = CompleteListOf32BitServiceUuids::try_from(data.as_slice());
     /// assert!(result.is_ok());
     /// let data_type = result.unwrap();
     /// assert_eq!(length, data_type.length);
-    /// assert_eq!(uuids, data_type.uuids);
+    /// assert_eq!(uuids, data_type.uuids.as_slice());
     ///
     /// let data: Vec<u8> = Vec::new();
-    /// let result = CompleteListOf32BitServiceUuids::try_from(&data);
+    /// let result = CompleteListOf32BitServiceUuids::try_from(data.as_slice());
     /// assert!(result.is_err());
     /// assert_eq!(
     ///     format!("Invalid data size :{}", data.len()),
     ///     result.unwrap_err()
     /// );
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+    fn try_from(value: &[u8]) -> Result<Self, String> {
         let len = value.len();
         if len < 6 {
             return Err(format!("Invalid data size :{}", len).to_string());
         }
-        let length = value[0];
+        let length = validate_length(value, 5, 255)?;
+        if !(length - 1).is_multiple_of(4) {
+            return Err(format!("Invalid length :{}", length).to_string());
+        }
         Ok(Self {
             length,
             uuids: value[2..2 + length as usize - 1]
@@ -107,6 +116,54 @@ impl TryFrom<&Vec<u8>> for CompleteListOf32BitServiceUuids {
     }
 }
 
+impl TryFrom<&Vec<u8>> for CompleteListOf32BitServiceUuids {
+    type Error = String;
+    /// Create [`CompleteListOf32BitServiceUuids`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{BASE_UUID, data_types::{complete_list_of_32bit_service_uuids::CompleteListOf32BitServiceUuids, data_type::DataType}};
+    /// use uuid::{uuid, Uuid};
+    ///
+    /// let uuid_bytes: Vec<u8> = [
+    ///     0x01u8, 0x02u8, 0x03u8, 0x04u8, 0x05u8, 0x06u8, 0x07u8, 0x08u8,
+    /// ]
+    /// .to_vec();
+    /// let uuids: Vec<Uuid> = uuid_bytes
+    ///     .windows(4)
+    ///     .step_by(4)
+    ///     .map(|f| {
+    ///         let mut uuid_bytes_le: Vec<u8> = f[0..4].to_vec();
+    ///         uuid_bytes_le.append(&mut BASE_UUID.to_bytes_le()[4..].to_vec());
+    ///         Uuid::from_bytes_le(uuid_bytes_le.try_into().unwrap())
+    ///     })
+    ///     .collect();
+    /// let length = uuid_bytes.len() as u8 + 1;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(CompleteListOf32BitServiceUuids::data_type());
+    /// data.append(&mut uuid_bytes.clone());
+    ///
+    /// let result = CompleteListOf32BitServiceUuids::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert_eq!(uuids, data_type.uuids.as_slice());
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = CompleteListOf32BitServiceUuids::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        Self::try_from(value.as_slice())
+    }
+}
+
 impl Into<Vec<u8>> for CompleteListOf32BitServiceUuids {
     /// Create [`Vec<u8>`] from [`CompleteListOf32BitServiceUuids`].
     ///
@@ -188,8 +245,11 @@ impl DataType for CompleteListOf32BitServiceUuids {
 /// assert!(is_complete_list_of_32bit_service_uuids(0x05));
 /// assert!(!is_complete_list_of_32bit_service_uuids(0x00));
 /// ```
+#[deprecated(
+    note = "use `AdType::of(data_type).matches::<CompleteListOf32BitServiceUuids>()` instead"
+)]
 pub fn is_complete_list_of_32bit_service_uuids(data_type: u8) -> bool {
-    CompleteListOf32BitServiceUuids::data_type() == data_type
+    AdType::of(data_type).matches::<CompleteListOf32BitServiceUuids>()
 }
 
 #[cfg(test)]
@@ -210,7 +270,7 @@ mod tests {
         .to_vec();
         let result = CompleteListOf32BitServiceUuids::new(&uuids);
         assert_eq!(uuids.len() as u8 * 4 + 1, result.length);
-        assert_eq!(uuids, result.uuids);
+        assert_eq!(uuids, result.uuids.as_slice());
     }
 
     #[test]
@@ -238,7 +298,7 @@ mod tests {
         assert!(result.is_ok());
         let data_type = result.unwrap();
         assert_eq!(length, data_type.length);
-        assert_eq!(uuids, data_type.uuids);
+        assert_eq!(uuids, data_type.uuids.as_slice());
 
         let mut data: Vec<u8> = vec![0u8; 5];
         data[0] = data.len() as u8 - 1;
@@ -289,6 +349,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_is_complete_list_of_32bit_service_uuids() {
         assert!(is_complete_list_of_32bit_service_uuids(0x05));
         assert!(!is_complete_list_of_32bit_service_uuids(0x00));
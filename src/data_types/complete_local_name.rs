@@ -1,6 +1,12 @@
 //! Complete Local Name (Data Type Value: 0x09) module.
 
-use crate::data_types::data_type::DataType;
+use std::borrow::Cow;
+
+use crate::data_types::{
+    ad_type::AdType,
+    data_type::{validate_length, DataType},
+    parse_options::{decode_utf8, ParseOptions},
+};
 
 /// Complete Local Name.
 #[derive(Debug, PartialEq, Clone)]
@@ -10,6 +16,9 @@ pub struct CompleteLocalName {
 
     /// Complete Local Name
     pub complete_local_name: String,
+
+    /// Original name bytes
+    pub raw_name: Vec<u8>,
 }
 
 impl CompleteLocalName {
@@ -29,8 +38,75 @@ impl CompleteLocalName {
         Self {
             length: complete_local_name.as_bytes().len() as u8 + 1,
             complete_local_name: complete_local_name.to_string(),
+            raw_name: complete_local_name.as_bytes().to_vec(),
         }
     }
+
+    /// Create [`CompleteLocalName`] from `u8` slice with [`ParseOptions`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     complete_local_name::CompleteLocalName,
+    ///     data_type::DataType,
+    ///     parse_options::{ParseOptions, Utf8DecodeMode},
+    /// };
+    ///
+    /// let data: Vec<u8> = vec![5, CompleteLocalName::data_type(), 0x6e, 0x61, 0xff, 0x65];
+    ///
+    /// let result = CompleteLocalName::try_from_with_options(&data, &ParseOptions::new(Utf8DecodeMode::Lossy));
+    /// assert_eq!("na\u{fffd}e", result.unwrap().complete_local_name);
+    ///
+    /// let result = CompleteLocalName::try_from_with_options(&data, &ParseOptions::new(Utf8DecodeMode::Strict));
+    /// assert!(result.is_err());
+    /// ```
+    pub fn try_from_with_options(value: &[u8], options: &ParseOptions) -> Result<Self, String> {
+        let complete_local_name = CompleteLocalNameRef::try_from(value)?;
+        Ok(Self {
+            length: complete_local_name.length,
+            complete_local_name: decode_utf8(
+                complete_local_name.raw_name,
+                options.utf8_decode_mode,
+            )?,
+            raw_name: complete_local_name.raw_name.to_vec(),
+        })
+    }
+}
+
+impl TryFrom<&[u8]> for CompleteLocalName {
+    type Error = String;
+    /// Create [`CompleteLocalName`] from `u8` slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{complete_local_name::CompleteLocalName, data_type::DataType};
+    ///
+    /// let name = "complete_local_name".to_string();
+    /// let length = name.as_bytes().len() as u8 + 1;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(CompleteLocalName::data_type());
+    /// data.append(&mut name.to_string().into_bytes());
+    ///
+    /// let result = CompleteLocalName::try_from(data.as_slice());
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert_eq!(name, data_type.complete_local_name);
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = CompleteLocalName::try_from(data.as_slice());
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &[u8]) -> Result<Self, String> {
+        Self::try_from_with_options(value, &ParseOptions::default())
+    }
 }
 
 impl TryFrom<&Vec<u8>> for CompleteLocalName {
@@ -64,19 +140,169 @@ impl TryFrom<&Vec<u8>> for CompleteLocalName {
     /// );
     /// ```
     fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        Self::try_from(value.as_slice())
+    }
+}
+
+/// Borrowed view of [`CompleteLocalName`].
+#[derive(Debug, PartialEq, Clone)]
+pub struct CompleteLocalNameRef<'a> {
+    /// data length
+    pub length: u8,
+
+    /// Original name bytes
+    pub raw_name: &'a [u8],
+}
+
+impl<'a> CompleteLocalNameRef<'a> {
+    /// Create [`CompleteLocalNameRef`] from `utf8` without copying.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::complete_local_name::CompleteLocalNameRef;
+    ///
+    /// let name = "name";
+    /// let result = CompleteLocalNameRef::new(name);
+    /// assert_eq!(5, result.length);
+    /// assert_eq!(name.as_bytes(), result.raw_name);
+    /// ```
+    pub fn new(complete_local_name: &'a str) -> Self {
+        Self {
+            length: complete_local_name.len() as u8 + 1,
+            raw_name: complete_local_name.as_bytes(),
+        }
+    }
+
+    /// Get Complete Local Name (invalid UTF-8 sequences are replaced with `U+FFFD`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    /// use ble_data_struct::data_types::{complete_local_name::*, data_type::DataType};
+    ///
+    /// let data: Vec<u8> = vec![5, CompleteLocalName::data_type(), 0x6e, 0x61, 0x6d, 0x65];
+    /// let result = CompleteLocalNameRef::try_from(data.as_slice()).unwrap();
+    /// assert!(matches!(result.complete_local_name(), Cow::Borrowed("name")));
+    ///
+    /// let data: Vec<u8> = vec![5, CompleteLocalName::data_type(), 0x6e, 0x61, 0xff, 0x65];
+    /// let result = CompleteLocalNameRef::try_from(data.as_slice()).unwrap();
+    /// assert_eq!("na\u{fffd}e", result.complete_local_name());
+    /// ```
+    pub fn complete_local_name(&self) -> Cow<'a, str> {
+        String::from_utf8_lossy(self.raw_name)
+    }
+
+    /// Create [`CompleteLocalName`] from [`CompleteLocalNameRef`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{complete_local_name::*, data_type::DataType};
+    ///
+    /// let data: Vec<u8> = vec![5, CompleteLocalName::data_type(), 0x6e, 0x61, 0x6d, 0x65];
+    /// let result = CompleteLocalNameRef::try_from(data.as_slice()).unwrap();
+    /// assert_eq!(CompleteLocalName::new(&"name".to_string()), result.to_owned());
+    /// ```
+    pub fn to_owned(&self) -> CompleteLocalName {
+        CompleteLocalName {
+            length: self.length,
+            complete_local_name: self.complete_local_name().into_owned(),
+            raw_name: self.raw_name.to_vec(),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for CompleteLocalNameRef<'a> {
+    type Error = String;
+    /// Create [`CompleteLocalNameRef`] from `u8` slice without copying.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{complete_local_name::*, data_type::DataType};
+    ///
+    /// let data: Vec<u8> = vec![5, CompleteLocalName::data_type(), 0x6e, 0x61, 0x6d, 0x65];
+    /// let result = CompleteLocalNameRef::try_from(data.as_slice());
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(5, data_type.length);
+    /// assert_eq!("name".as_bytes(), data_type.raw_name);
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = CompleteLocalNameRef::try_from(data.as_slice());
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &'a [u8]) -> Result<Self, String> {
         let len = value.len();
         if len < 3 {
             return Err(format!("Invalid data size :{}", len).to_string());
         }
-        let length = value[0];
+        let length = validate_length(value, 1, 255)?;
         Ok(Self {
             length,
-            complete_local_name: String::from_utf8(value[2..1 + usize::from(length)].to_vec())
-                .unwrap(),
+            raw_name: &value[2..1 + usize::from(length)],
         })
     }
 }
 
+impl<'a> From<&'a CompleteLocalName> for CompleteLocalNameRef<'a> {
+    /// Create [`CompleteLocalNameRef`] from [`CompleteLocalName`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::complete_local_name::*;
+    ///
+    /// let result1 = CompleteLocalName::new(&"name".to_string());
+    /// let result2 = CompleteLocalNameRef::from(&result1);
+    /// assert_eq!(result1.length, result2.length);
+    /// assert_eq!(result1.raw_name.as_slice(), result2.raw_name);
+    ///
+    /// let mut result1 = CompleteLocalName::new(&"name".to_string());
+    /// result1.complete_local_name = "other".to_string();
+    /// let result2 = CompleteLocalNameRef::from(&result1);
+    /// assert_eq!(6, result2.length);
+    /// assert_eq!("other".as_bytes(), result2.raw_name);
+    /// ```
+    fn from(value: &'a CompleteLocalName) -> Self {
+        if String::from_utf8_lossy(&value.raw_name) == value.complete_local_name {
+            Self {
+                length: value.length,
+                raw_name: &value.raw_name,
+            }
+        } else {
+            Self::new(&value.complete_local_name)
+        }
+    }
+}
+
+impl From<CompleteLocalNameRef<'_>> for Vec<u8> {
+    /// Create [`Vec<u8>`] from [`CompleteLocalNameRef`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{complete_local_name::*, data_type::DataType};
+    ///
+    /// let data: Vec<u8> = vec![5, CompleteLocalName::data_type(), 0x6e, 0x61, 0x6d, 0x65];
+    /// let into_data: Vec<u8> = CompleteLocalNameRef::new("name").into();
+    /// assert_eq!(data, into_data);
+    /// ```
+    fn from(value: CompleteLocalNameRef<'_>) -> Self {
+        let mut data: Vec<u8> = Vec::with_capacity(2 + value.raw_name.len());
+        data.push(value.length);
+        data.push(CompleteLocalName::data_type());
+        data.extend_from_slice(value.raw_name);
+        data
+    }
+}
+
 impl Into<Vec<u8>> for CompleteLocalName {
     /// Create[`Vec<u8>`] from [`CompleteLocalName`].
     ///
@@ -101,12 +327,20 @@ impl Into<Vec<u8>> for CompleteLocalName {
     /// let data_type = result2.unwrap();
     /// let into_data: Vec<u8> = data_type.into();
     /// assert_eq!(data, into_data);
+    ///
+    /// let data: Vec<u8> = vec![5, CompleteLocalName::data_type(), 0x6e, 0x61, 0xff, 0x65];
+    /// let into_data: Vec<u8> = CompleteLocalName::try_from(&data).unwrap().into();
+    /// assert_eq!(data, into_data);
     /// ```
     fn into(self) -> Vec<u8> {
         let mut data: Vec<u8> = Vec::new();
         data.push(self.length);
         data.push(Self::data_type());
-        data.append(&mut self.complete_local_name.clone().into_bytes());
+        if String::from_utf8_lossy(&self.raw_name) == self.complete_local_name {
+            data.append(&mut self.raw_name.clone());
+        } else {
+            data.append(&mut self.complete_local_name.clone().into_bytes());
+        }
         return data;
     }
 }
@@ -137,13 +371,18 @@ impl DataType for CompleteLocalName {
 /// assert!(is_complete_local_name(0x09));
 /// assert!(!is_complete_local_name(0x00));
 /// ```
+#[deprecated(note = "use `AdType::of(data_type).matches::<CompleteLocalName>()` instead")]
 pub fn is_complete_local_name(data_type: u8) -> bool {
-    CompleteLocalName::data_type() == data_type
+    AdType::of(data_type).matches::<CompleteLocalName>()
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::data_types::{complete_local_name::*, data_type::DataType};
+    use crate::data_types::{
+        complete_local_name::*,
+        data_type::DataType,
+        parse_options::{ParseOptions, Utf8DecodeMode},
+    };
 
     #[test]
     fn test_new() {
@@ -151,6 +390,82 @@ mod tests {
         let result = CompleteLocalName::new(&name);
         assert_eq!(name.as_bytes().len() as u8 + 1, result.length);
         assert_eq!(name, result.complete_local_name);
+        assert_eq!(name.as_bytes().to_vec(), result.raw_name);
+    }
+
+    #[test]
+    fn test_ref_try_from() {
+        let data: Vec<u8> = vec![3, CompleteLocalName::data_type(), 0x6e, 0x61, 0xff];
+        let result = CompleteLocalNameRef::try_from(data.as_slice());
+        assert!(result.is_ok());
+        let data_type = result.unwrap();
+        assert_eq!(3, data_type.length);
+        assert_eq!(data[2..4].as_ptr(), data_type.raw_name.as_ptr());
+        assert_eq!("na", data_type.complete_local_name());
+        assert_eq!(
+            CompleteLocalName::try_from(&data[..4].to_vec()).unwrap(),
+            data_type.to_owned()
+        );
+
+        let data: Vec<u8> = vec![4, CompleteLocalName::data_type(), 0x6e, 0x61];
+        let result = CompleteLocalNameRef::try_from(data.as_slice());
+        assert!(result.is_err());
+        assert_eq!(
+            format!("Invalid data size :{}", data.len()),
+            result.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_ref_new() {
+        let name = "complete_local_name";
+        let result = CompleteLocalNameRef::new(name);
+        assert_eq!(name.len() as u8 + 1, result.length);
+        assert_eq!(name.as_ptr(), result.raw_name.as_ptr());
+        assert_eq!(name, result.complete_local_name());
+        assert_eq!(CompleteLocalName::new(&name.to_string()), result.to_owned());
+    }
+
+    #[test]
+    fn test_ref_from() {
+        let data: Vec<u8> = vec![5, CompleteLocalName::data_type(), 0x6e, 0x61, 0xff, 0x65];
+        let result1 = CompleteLocalName::try_from(&data).unwrap();
+        let result2 = CompleteLocalNameRef::from(&result1);
+        assert_eq!(5, result2.length);
+        assert_eq!(result1.raw_name.as_ptr(), result2.raw_name.as_ptr());
+        assert_eq!(result1, result2.to_owned());
+
+        let mut result1 = CompleteLocalName::try_from(&data).unwrap();
+        result1.complete_local_name = "other".to_string();
+        let result2 = CompleteLocalNameRef::from(&result1);
+        assert_eq!(6, result2.length);
+        assert_eq!("other".as_bytes(), result2.raw_name);
+        let data: Vec<u8> = CompleteLocalName::new(&"other".to_string()).into();
+        let into_data: Vec<u8> = result2.into();
+        assert_eq!(data, into_data);
+    }
+
+    #[test]
+    fn test_ref_into() {
+        let data: Vec<u8> = vec![5, CompleteLocalName::data_type(), 0x6e, 0x61, 0xff, 0x65];
+        let into_data: Vec<u8> = CompleteLocalNameRef::try_from(data.as_slice())
+            .unwrap()
+            .into();
+        assert_eq!(data, into_data);
+
+        let into_data: Vec<u8> = CompleteLocalNameRef::new("complete_local_name").into();
+        let into_data2: Vec<u8> = CompleteLocalName::new(&"complete_local_name".to_string()).into();
+        assert_eq!(into_data2, into_data);
+    }
+
+    #[test]
+    fn test_ref_to_owned_lossy() {
+        let data: Vec<u8> = vec![5, CompleteLocalName::data_type(), 0x6e, 0x61, 0xff, 0x65];
+        let result = CompleteLocalNameRef::try_from(data.as_slice())
+            .unwrap()
+            .to_owned();
+        assert_eq!("na\u{fffd}e", result.complete_local_name);
+        assert_eq!(vec![0x6e, 0x61, 0xff, 0x65], result.raw_name);
     }
 
     #[test]
@@ -178,6 +493,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_try_from_with_options() {
+        let data: Vec<u8> = vec![5, CompleteLocalName::data_type(), 0x6e, 0x61, 0xff, 0x65];
+
+        let result = CompleteLocalName::try_from_with_options(
+            &data,
+            &ParseOptions::new(Utf8DecodeMode::Lossy),
+        );
+        assert!(result.is_ok());
+        let data_type = result.unwrap();
+        assert_eq!(5, data_type.length);
+        assert_eq!("na\u{fffd}e", data_type.complete_local_name);
+
+        let result = CompleteLocalName::try_from_with_options(
+            &data,
+            &ParseOptions::new(Utf8DecodeMode::Strict),
+        );
+        assert_eq!(
+            Err("Invalid UTF-8 :invalid utf-8 sequence of 1 bytes from index 2".to_string()),
+            result
+        );
+
+        let data: Vec<u8> = vec![5, CompleteLocalName::data_type(), 0x6e, 0x61, 0x6d, 0x65];
+        let result = CompleteLocalName::try_from_with_options(
+            &data,
+            &ParseOptions::new(Utf8DecodeMode::Strict),
+        );
+        assert_eq!("name", result.unwrap().complete_local_name);
+    }
+
     #[test]
     fn test_into() {
         let name = "complete_local_name".to_string();
@@ -198,12 +543,30 @@ mod tests {
         assert_eq!(data, into_data);
     }
 
+    #[test]
+    fn test_into_raw_name() {
+        let data: Vec<u8> = vec![5, CompleteLocalName::data_type(), 0x6e, 0x61, 0xff, 0x65];
+        let result = CompleteLocalName::try_from(&data).unwrap();
+        assert_eq!(vec![0x6e, 0x61, 0xff, 0x65], result.raw_name);
+        let into_data: Vec<u8> = result.into();
+        assert_eq!(data, into_data);
+
+        let mut result = CompleteLocalName::try_from(&data).unwrap();
+        result.complete_local_name = "name".to_string();
+        let into_data: Vec<u8> = result.into();
+        assert_eq!(
+            vec![5, CompleteLocalName::data_type(), 0x6e, 0x61, 0x6d, 0x65],
+            into_data
+        );
+    }
+
     #[test]
     fn test_data_type() {
         assert_eq!(0x09, CompleteLocalName::data_type());
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_is_complete_local_name() {
         assert!(is_complete_local_name(0x09));
         assert!(!is_complete_local_name(0x00));
@@ -3,3 +3,85 @@ pub trait DataType {
     /// Get EIR/AD/SRD/ACAD/OOB data type
     fn data_type() -> u8;
 }
+
+/// UUID list storage (inline up to 7 UUIDs with `smallvec` feature).
+#[cfg(feature = "smallvec")]
+pub type UuidList = smallvec::SmallVec<[uuid::Uuid; 7]>;
+
+/// UUID list storage (inline up to 7 UUIDs with `smallvec` feature).
+#[cfg(not(feature = "smallvec"))]
+pub type UuidList = Vec<uuid::Uuid>;
+
+/// Additional service data storage (inline up to 27 bytes with `smallvec` feature).
+#[cfg(feature = "smallvec")]
+pub type ServiceDataBytes = smallvec::SmallVec<[u8; 27]>;
+
+/// Additional service data storage (inline up to 27 bytes with `smallvec` feature).
+#[cfg(not(feature = "smallvec"))]
+pub type ServiceDataBytes = Vec<u8>;
+
+/// Validate declared data length against the allowed range and buffer size.
+///
+/// returns declared data length.
+pub(crate) fn validate_length(value: &[u8], min: u8, max: u8) -> Result<u8, String> {
+    let len = value.len();
+    if len == 0 {
+        return Err(format!("Invalid data size :{}", len).to_string());
+    }
+    let length = value[0];
+    if length < min || length > max {
+        return Err(format!("Invalid length :{}", length).to_string());
+    }
+    if len < 1 + length as usize {
+        return Err(format!("Invalid data size :{}", len).to_string());
+    }
+    Ok(length)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data_types::data_type::*;
+
+    #[test]
+    fn test_validate_length() {
+        assert_eq!(Ok(2), validate_length(&[2, 0x0a, 0x00], 2, 2));
+        assert_eq!(Ok(2), validate_length(&[2, 0x0a, 0x00, 0x01], 1, 3));
+        assert_eq!(
+            Err("Invalid data size :0".to_string()),
+            validate_length(&[], 2, 2)
+        );
+        assert_eq!(
+            Err("Invalid length :1".to_string()),
+            validate_length(&[1, 0x0a, 0x00], 2, 2)
+        );
+        assert_eq!(
+            Err("Invalid length :3".to_string()),
+            validate_length(&[3, 0x0a, 0x00, 0x00], 2, 2)
+        );
+        assert_eq!(
+            Err("Invalid data size :3".to_string()),
+            validate_length(&[3, 0x0a, 0x00], 2, 3)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "smallvec")]
+    fn test_inline_storage() {
+        use crate::data_types::{
+            complete_list_of_16bit_service_uuids::CompleteListOf16BitServiceUuids,
+            service_data_16bit_uuid::ServiceData16BitUUID,
+        };
+        use crate::uuid_from_u16;
+
+        let mut data: Vec<u8> = vec![15, CompleteListOf16BitServiceUuids::data_type()];
+        data.append(&mut vec![0x0f, 0x18].repeat(7));
+        let result = CompleteListOf16BitServiceUuids::try_from(&data).unwrap();
+        assert_eq!(7, result.uuids.len());
+        assert!(!result.uuids.spilled());
+
+        let result = ServiceData16BitUUID::new(&uuid_from_u16(0x180f), &vec![0u8; 27]);
+        assert!(!result.additional_service_data.spilled());
+        let result = ServiceData16BitUUID::new(&uuid_from_u16(0x180f), &vec![0u8; 28]);
+        assert!(result.additional_service_data.spilled());
+    }
+}
@@ -1,82 +1,64 @@
 //! Data type parser module.
 
-use super::{
-    advertising_interval::{is_advertising_interval, AdvertisingInterval},
-    advertising_interval_long::{is_advertising_interval_long, AdvertisingIntervalLong},
-    appearance::{is_appearance, Appearance},
-    big_info::{is_big_info, BigInfo},
-    broadcast_code::{is_broadcast_code, BroadcastCode},
-    channel_map_update_indication::{is_channel_map_update_indication, ChannelMapUpdateIndication},
-    class_of_device::{is_class_of_device, ClassOfDevice},
-    complete_list_of_128bit_service_uuids::{
-        is_complete_list_of_128bit_service_uuids, CompleteListOf128BitServiceUuids,
-    },
-    complete_list_of_16bit_service_uuids::{
-        is_complete_list_of_16bit_service_uuids, CompleteListOf16BitServiceUuids,
-    },
-    complete_list_of_32bit_service_uuids::{
-        is_complete_list_of_32bit_service_uuids, CompleteListOf32BitServiceUuids,
-    },
-    complete_local_name::{is_complete_local_name, CompleteLocalName},
-    encrypted_data::{is_encrypted_data, EncryptedData},
-    flags::{is_flags, Flags},
-    incomplete_list_of_128bit_service_uuids::{
-        is_incomplete_list_of_128bit_service_uuids, IncompleteListOf128BitServiceUuids,
-    },
-    incomplete_list_of_16bit_service_uuids::{
-        is_incomplete_list_of_16bit_service_uuids, IncompleteListOf16BitServiceUuids,
-    },
-    incomplete_list_of_32bit_service_uuids::{
-        is_incomplete_list_of_32bit_service_uuids, IncompleteListOf32BitServiceUuids,
-    },
-    le_bluetooth_device_address::{is_le_bluetooth_device_address, LeBluetoothDeviceAddress},
-    le_role::{is_le_role, LeRole},
-    le_secure_connections_confirmation_value::{
-        is_le_secure_connections_confirmation_value, LeSecureConnectionsConfirmationValue,
-    },
-    le_secure_connections_random_value::{
-        is_le_secure_connections_random_value, LeSecureConnectionsRandomValue,
-    },
-    le_supported_features::{is_le_supported_features, LeSupportedFeatures},
-    list_of_128bit_service_solicitation_uuids::{
-        is_list_of_128bit_service_solicitation_uuids, ListOf128BitServiceSolicitationUUIDs,
-    },
-    list_of_16bit_service_solicitation_uuids::{
-        is_list_of_16bit_service_solicitation_uuids, ListOf16BitServiceSolicitationUUIDs,
-    },
-    list_of_32bit_service_solicitation_uuids::{
-        is_list_of_32bit_service_solicitation_uuids, ListOf32BitServiceSolicitationUUIDs,
-    },
-    manufacturer_specific_data::{is_manufacturer_specific_data, ManufacturerSpecificData},
-    periodic_advertising_response_timing_information::{
-        is_periodic_advertising_response_timing_information,
-        PeriodicAdvertisingResponseTimingInformation,
-    },
-    peripheral_connection_interval_range::{
-        is_peripheral_connection_interval_range, PeripheralConnectionIntervalRange,
-    },
-    public_target_address::{is_public_target_address, PublicTargetAddress},
-    random_target_address::{is_random_target_address, RandomTargetAddress},
-    secure_simple_pairing_hash_c192::{
-        is_secure_simple_pairing_hash_c192, SecureSimplePairingHashC192,
-    },
-    secure_simple_pairing_hash_c256::{
-        is_secure_simple_pairing_hash_c256, SecureSimplePairingHashC256,
-    },
-    secure_simple_pairing_randomizer_r192::{
-        is_secure_simple_pairing_randomizer_r192, SecureSimplePairingRandomizerR192,
+use uuid::Uuid;
+
+#[cfg(feature = "vendor")]
+use crate::beacons::eddystone::EddystoneUrl;
+use crate::{
+    service_data::{
+        battery_service::{BatteryServiceData, BATTERY_SERVICE_UUID},
+        sensor::SensorData,
     },
-    secure_simple_pairing_randomizer_r256::{
-        is_secure_simple_pairing_randomizer_r256, SecureSimplePairingRandomizerR256,
-    },
-    security_manager_oob::{is_security_manager_oob, SecurityManagerOutOfBand},
-    security_manager_tk_value::{is_security_manager_tk_value, SecurityManagerTkValue},
-    service_data_128bit_uuid::{is_service_data_128bit_uuid, ServiceData128BitUUID},
-    service_data_16bit_uuid::{is_service_data_16bit_uuid, ServiceData16BitUUID},
-    service_data_32bit_uuid::{is_service_data_32bit_uuid, ServiceData32BitUUID},
-    shortened_local_name::{is_shortened_local_name, ShortenedLocalName},
-    tx_power_level::{is_tx_power_level, TxPowerLevel},
-    uniform_resource_identifier::{is_uniform_resource_identifier, UniformResourceIdentifier},
+    uuid_from_u16,
+};
+
+use super::{
+    ad_type::AdType, advertising_interval::AdvertisingInterval,
+    advertising_interval_long::AdvertisingIntervalLong, appearance::Appearance,
+    channel_map_update_indication::ChannelMapUpdateIndication, class_of_device::ClassOfDevice,
+    complete_list_of_128bit_service_uuids::CompleteListOf128BitServiceUuids,
+    complete_list_of_16bit_service_uuids::CompleteListOf16BitServiceUuids,
+    complete_list_of_32bit_service_uuids::CompleteListOf32BitServiceUuids,
+    complete_local_name::CompleteLocalName, electronic_shelf_label::ElectronicShelfLabel,
+    encrypted_data::EncryptedData, flags::Flags,
+    incomplete_list_of_128bit_service_uuids::IncompleteListOf128BitServiceUuids,
+    incomplete_list_of_16bit_service_uuids::IncompleteListOf16BitServiceUuids,
+    incomplete_list_of_32bit_service_uuids::IncompleteListOf32BitServiceUuids,
+    indoor_positioning::IndoorPositioning, le_bluetooth_device_address::LeBluetoothDeviceAddress,
+    le_role::LeRole, le_supported_features::LeSupportedFeatures,
+    list_of_128bit_service_solicitation_uuids::ListOf128BitServiceSolicitationUUIDs,
+    list_of_16bit_service_solicitation_uuids::ListOf16BitServiceSolicitationUUIDs,
+    list_of_32bit_service_solicitation_uuids::ListOf32BitServiceSolicitationUUIDs,
+    manufacturer_specific_data::ManufacturerSpecificData, parse_options::ParseOptions,
+    periodic_advertising_response_timing_information::PeriodicAdvertisingResponseTimingInformation,
+    peripheral_connection_interval_range::PeripheralConnectionIntervalRange,
+    public_target_address::PublicTargetAddress, random_target_address::RandomTargetAddress,
+    service_data_128bit_uuid::ServiceData128BitUUID, service_data_16bit_uuid::ServiceData16BitUUID,
+    service_data_32bit_uuid::ServiceData32BitUUID, shortened_local_name::ShortenedLocalName,
+    three_d_information_data::ThreeDInformationData,
+    transport_discovery_data::TransportDiscoveryData, tx_power_level::TxPowerLevel,
+    uniform_resource_identifier::UniformResourceIdentifier,
+};
+
+#[cfg(feature = "le-audio")]
+use super::{
+    big_info::BigInfo, broadcast_code::BroadcastCode,
+    resolvable_set_identifier::ResolvableSetIdentifier,
+};
+
+#[cfg(feature = "mesh")]
+use super::mesh_beacon::MeshBeacon;
+
+#[cfg(feature = "pairing")]
+use super::{
+    le_secure_connections_confirmation_value::LeSecureConnectionsConfirmationValue,
+    le_secure_connections_random_value::LeSecureConnectionsRandomValue,
+    secure_simple_pairing_hash_c192::SecureSimplePairingHashC192,
+    secure_simple_pairing_hash_c256::SecureSimplePairingHashC256,
+    secure_simple_pairing_randomizer_r192::SecureSimplePairingRandomizerR192,
+    secure_simple_pairing_randomizer_r256::SecureSimplePairingRandomizerR256,
+    security_manager_oob::SecurityManagerOutOfBand,
+    security_manager_tk_value::SecurityManagerTkValue,
 };
 
 /// Data type parse result.
@@ -91,9 +73,11 @@ pub enum DataTypeParseResult {
     /// [`Appearance`]'s [`TryFrom::try_from`] result.
     AppearanceResult(Result<Appearance, String>),
 
+    #[cfg(feature = "le-audio")]
     /// [`BigInfo`]'s [`TryFrom::try_from`] result.
     BigInfoResult(Result<BigInfo, String>),
 
+    #[cfg(feature = "le-audio")]
     /// [`BroadcastCode`]'s [`TryFrom::try_from`] result.
     BroadcastCodeResult(Result<BroadcastCode, String>),
 
@@ -115,6 +99,9 @@ pub enum DataTypeParseResult {
     /// [`CompleteLocalName`]'s [`TryFrom::try_from`] result.
     CompleteLocalNameResult(Result<CompleteLocalName, String>),
 
+    /// [`ElectronicShelfLabel`]'s [`TryFrom::try_from`] result.
+    ElectronicShelfLabelResult(Result<ElectronicShelfLabel, String>),
+
     /// [`EncryptedData`]'s [`TryFrom::try_from`] result.
     EncryptedDataResult(Result<EncryptedData, String>),
 
@@ -130,17 +117,22 @@ pub enum DataTypeParseResult {
     /// [`IncompleteListOf32BitServiceUuids`]'s [`TryFrom::try_from`] result.
     IncompleteListOf32BitServiceUuidsResult(Result<IncompleteListOf32BitServiceUuids, String>),
 
+    /// [`IndoorPositioning`]'s [`TryFrom::try_from`] result.
+    IndoorPositioningResult(Result<IndoorPositioning, String>),
+
     /// [`LeBluetoothDeviceAddress`]'s [`TryFrom::try_from`] result.
     LeBluetoothDeviceAddressResult(Result<LeBluetoothDeviceAddress, String>),
 
     /// [`LeRole`]'s [`TryFrom::try_from`] result.
     LeRoleResult(Result<LeRole, String>),
 
+    #[cfg(feature = "pairing")]
     /// [`LeSecureConnectionsConfirmationValue`]'s [`TryFrom::try_from`] result.
     LeSecureConnectionsConfirmationValueResult(
         Result<LeSecureConnectionsConfirmationValue, String>,
     ),
 
+    #[cfg(feature = "pairing")]
     /// [`LeSecureConnectionsRandomValue`]'s [`TryFrom::try_from`] result.
     LeSecureConnectionsRandomValueResult(Result<LeSecureConnectionsRandomValue, String>),
 
@@ -166,6 +158,10 @@ pub enum DataTypeParseResult {
         Result<PeriodicAdvertisingResponseTimingInformation, String>,
     ),
 
+    #[cfg(feature = "mesh")]
+    /// [`MeshBeacon`]'s [`TryFrom::try_from`] result.
+    MeshBeaconResult(Result<MeshBeacon, String>),
+
     /// [`PeripheralConnectionIntervalRange`]'s [`TryFrom::try_from`] result.
     PeripheralConnectionIntervalRangeResult(Result<PeripheralConnectionIntervalRange, String>),
 
@@ -175,21 +171,31 @@ pub enum DataTypeParseResult {
     /// [`RandomTargetAddress`]'s [`TryFrom::try_from`] result.
     RandomTargetAddressResult(Result<RandomTargetAddress, String>),
 
+    #[cfg(feature = "le-audio")]
+    /// [`ResolvableSetIdentifier`]'s [`TryFrom::try_from`] result.
+    ResolvableSetIdentifierResult(Result<ResolvableSetIdentifier, String>),
+
+    #[cfg(feature = "pairing")]
     /// [`SecureSimplePairingHashC192`]'s [`TryFrom::try_from`] result.
     SecureSimplePairingHashC192Result(Result<SecureSimplePairingHashC192, String>),
 
+    #[cfg(feature = "pairing")]
     /// [`SecureSimplePairingHashC256`]'s [`TryFrom::try_from`] result.
     SecureSimplePairingHashC256Result(Result<SecureSimplePairingHashC256, String>),
 
+    #[cfg(feature = "pairing")]
     /// [`SecureSimplePairingRandomizerR192`]'s [`TryFrom::try_from`] result.
     SecureSimplePairingRandomizerR192Result(Result<SecureSimplePairingRandomizerR192, String>),
 
+    #[cfg(feature = "pairing")]
     /// [`SecureSimplePairingRandomizerR256`]'s [`TryFrom::try_from`] result.
     SecureSimplePairingRandomizerR256Result(Result<SecureSimplePairingRandomizerR256, String>),
 
+    #[cfg(feature = "pairing")]
     /// [`SecurityManagerOutOfBand`]'s [`TryFrom::try_from`] result.
     SecurityManagerOutOfBandResult(Result<SecurityManagerOutOfBand, String>),
 
+    #[cfg(feature = "pairing")]
     /// [`SecurityManagerTkValue`]'s [`TryFrom::try_from`] result.
     SecurityManagerTkValueResult(Result<SecurityManagerTkValue, String>),
 
@@ -205,6 +211,12 @@ pub enum DataTypeParseResult {
     /// [`ShortenedLocalName`]'s [`TryFrom::try_from`] result.
     ShortenedLocalNameResult(Result<ShortenedLocalName, String>),
 
+    /// [`ThreeDInformationData`]'s [`TryFrom::try_from`] result.
+    ThreeDInformationDataResult(Result<ThreeDInformationData, String>),
+
+    /// [`TransportDiscoveryData`]'s [`TryFrom::try_from`] result.
+    TransportDiscoveryDataResult(Result<TransportDiscoveryData, String>),
+
     /// [`TxPowerLevel`]'s [`TryFrom::try_from`] result.
     TxPowerLevelResult(Result<TxPowerLevel, String>),
 
@@ -216,6 +228,158 @@ pub enum DataTypeParseResult {
 }
 
 impl DataTypeParseResult {
+    /// Returns error message if the result is a failed or unknown data type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{advertising_interval::AdvertisingInterval, data_type_parser::DataTypeParseResult};
+    ///
+    /// let data: Vec<u8> = AdvertisingInterval::new(0x01).into();
+    /// assert_eq!(None, DataTypeParseResult::from(&data).error());
+    ///
+    /// let data: Vec<u8> = vec![0x01, 0x1a];
+    /// assert!(DataTypeParseResult::from(&data).error().is_some());
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// assert!(DataTypeParseResult::from(&data).error().is_some());
+    /// ```
+    pub fn error(&self) -> Option<&String> {
+        match self {
+            DataTypeParseResult::AdvertisingIntervalResult(Err(error)) => Some(error),
+            DataTypeParseResult::AdvertisingIntervalLongResult(Err(error)) => Some(error),
+            DataTypeParseResult::AppearanceResult(Err(error)) => Some(error),
+            #[cfg(feature = "le-audio")]
+            DataTypeParseResult::BigInfoResult(Err(error)) => Some(error),
+            #[cfg(feature = "le-audio")]
+            DataTypeParseResult::BroadcastCodeResult(Err(error)) => Some(error),
+            DataTypeParseResult::ChannelMapUpdateIndicationResult(Err(error)) => Some(error),
+            DataTypeParseResult::ClassOfDeviceResult(Err(error)) => Some(error),
+            DataTypeParseResult::CompleteListOf128BitServiceUuidsResult(Err(error)) => Some(error),
+            DataTypeParseResult::CompleteListOf16BitServiceUuidsResult(Err(error)) => Some(error),
+            DataTypeParseResult::CompleteListOf32BitServiceUuidsResult(Err(error)) => Some(error),
+            DataTypeParseResult::CompleteLocalNameResult(Err(error)) => Some(error),
+            DataTypeParseResult::ElectronicShelfLabelResult(Err(error)) => Some(error),
+            DataTypeParseResult::EncryptedDataResult(Err(error)) => Some(error),
+            DataTypeParseResult::FlagsResult(Err(error)) => Some(error),
+            DataTypeParseResult::IncompleteListOf128BitServiceUuidsResult(Err(error)) => {
+                Some(error)
+            }
+            DataTypeParseResult::IncompleteListOf16BitServiceUuidsResult(Err(error)) => Some(error),
+            DataTypeParseResult::IncompleteListOf32BitServiceUuidsResult(Err(error)) => Some(error),
+            DataTypeParseResult::IndoorPositioningResult(Err(error)) => Some(error),
+            DataTypeParseResult::LeBluetoothDeviceAddressResult(Err(error)) => Some(error),
+            DataTypeParseResult::LeRoleResult(Err(error)) => Some(error),
+            #[cfg(feature = "pairing")]
+            DataTypeParseResult::LeSecureConnectionsConfirmationValueResult(Err(error)) => {
+                Some(error)
+            }
+            #[cfg(feature = "pairing")]
+            DataTypeParseResult::LeSecureConnectionsRandomValueResult(Err(error)) => Some(error),
+            DataTypeParseResult::LeSupportedFeaturesResult(Err(error)) => Some(error),
+            DataTypeParseResult::ListOf128BitServiceSolicitationUUIDsResult(Err(error)) => {
+                Some(error)
+            }
+            DataTypeParseResult::ListOf16BitServiceSolicitationUUIDsResult(Err(error)) => {
+                Some(error)
+            }
+            DataTypeParseResult::ListOf32BitServiceSolicitationUUIDsResult(Err(error)) => {
+                Some(error)
+            }
+            DataTypeParseResult::ManufacturerSpecificDataResult(Err(error)) => Some(error),
+            DataTypeParseResult::PeriodicAdvertisingResponseTimingInformationResult(Err(error)) => {
+                Some(error)
+            }
+            #[cfg(feature = "mesh")]
+            DataTypeParseResult::MeshBeaconResult(Err(error)) => Some(error),
+            DataTypeParseResult::PeripheralConnectionIntervalRangeResult(Err(error)) => Some(error),
+            DataTypeParseResult::PublicTargetAddressResult(Err(error)) => Some(error),
+            DataTypeParseResult::RandomTargetAddressResult(Err(error)) => Some(error),
+            #[cfg(feature = "le-audio")]
+            DataTypeParseResult::ResolvableSetIdentifierResult(Err(error)) => Some(error),
+            #[cfg(feature = "pairing")]
+            DataTypeParseResult::SecureSimplePairingHashC192Result(Err(error)) => Some(error),
+            #[cfg(feature = "pairing")]
+            DataTypeParseResult::SecureSimplePairingHashC256Result(Err(error)) => Some(error),
+            #[cfg(feature = "pairing")]
+            DataTypeParseResult::SecureSimplePairingRandomizerR192Result(Err(error)) => Some(error),
+            #[cfg(feature = "pairing")]
+            DataTypeParseResult::SecureSimplePairingRandomizerR256Result(Err(error)) => Some(error),
+            #[cfg(feature = "pairing")]
+            DataTypeParseResult::SecurityManagerOutOfBandResult(Err(error)) => Some(error),
+            #[cfg(feature = "pairing")]
+            DataTypeParseResult::SecurityManagerTkValueResult(Err(error)) => Some(error),
+            DataTypeParseResult::ServiceData128BitUUIDResult(Err(error)) => Some(error),
+            DataTypeParseResult::ServiceData16BitUUIDResult(Err(error)) => Some(error),
+            DataTypeParseResult::ServiceData32BitUUIDResult(Err(error)) => Some(error),
+            DataTypeParseResult::ShortenedLocalNameResult(Err(error)) => Some(error),
+            DataTypeParseResult::ThreeDInformationDataResult(Err(error)) => Some(error),
+            DataTypeParseResult::TransportDiscoveryDataResult(Err(error)) => Some(error),
+            DataTypeParseResult::TxPowerLevelResult(Err(error)) => Some(error),
+            DataTypeParseResult::UniformResourceIdentifierResult(Err(error)) => Some(error),
+            DataTypeParseResult::AdvertisingIntervalResult(Ok(_)) => None,
+            DataTypeParseResult::AdvertisingIntervalLongResult(Ok(_)) => None,
+            DataTypeParseResult::AppearanceResult(Ok(_)) => None,
+            #[cfg(feature = "le-audio")]
+            DataTypeParseResult::BigInfoResult(Ok(_)) => None,
+            #[cfg(feature = "le-audio")]
+            DataTypeParseResult::BroadcastCodeResult(Ok(_)) => None,
+            DataTypeParseResult::ChannelMapUpdateIndicationResult(Ok(_)) => None,
+            DataTypeParseResult::ClassOfDeviceResult(Ok(_)) => None,
+            DataTypeParseResult::CompleteListOf128BitServiceUuidsResult(Ok(_)) => None,
+            DataTypeParseResult::CompleteListOf16BitServiceUuidsResult(Ok(_)) => None,
+            DataTypeParseResult::CompleteListOf32BitServiceUuidsResult(Ok(_)) => None,
+            DataTypeParseResult::CompleteLocalNameResult(Ok(_)) => None,
+            DataTypeParseResult::ElectronicShelfLabelResult(Ok(_)) => None,
+            DataTypeParseResult::EncryptedDataResult(Ok(_)) => None,
+            DataTypeParseResult::FlagsResult(Ok(_)) => None,
+            DataTypeParseResult::IncompleteListOf128BitServiceUuidsResult(Ok(_)) => None,
+            DataTypeParseResult::IncompleteListOf16BitServiceUuidsResult(Ok(_)) => None,
+            DataTypeParseResult::IncompleteListOf32BitServiceUuidsResult(Ok(_)) => None,
+            DataTypeParseResult::IndoorPositioningResult(Ok(_)) => None,
+            DataTypeParseResult::LeBluetoothDeviceAddressResult(Ok(_)) => None,
+            DataTypeParseResult::LeRoleResult(Ok(_)) => None,
+            #[cfg(feature = "pairing")]
+            DataTypeParseResult::LeSecureConnectionsConfirmationValueResult(Ok(_)) => None,
+            #[cfg(feature = "pairing")]
+            DataTypeParseResult::LeSecureConnectionsRandomValueResult(Ok(_)) => None,
+            DataTypeParseResult::LeSupportedFeaturesResult(Ok(_)) => None,
+            DataTypeParseResult::ListOf128BitServiceSolicitationUUIDsResult(Ok(_)) => None,
+            DataTypeParseResult::ListOf16BitServiceSolicitationUUIDsResult(Ok(_)) => None,
+            DataTypeParseResult::ListOf32BitServiceSolicitationUUIDsResult(Ok(_)) => None,
+            DataTypeParseResult::ManufacturerSpecificDataResult(Ok(_)) => None,
+            DataTypeParseResult::PeriodicAdvertisingResponseTimingInformationResult(Ok(_)) => None,
+            #[cfg(feature = "mesh")]
+            DataTypeParseResult::MeshBeaconResult(Ok(_)) => None,
+            DataTypeParseResult::PeripheralConnectionIntervalRangeResult(Ok(_)) => None,
+            DataTypeParseResult::PublicTargetAddressResult(Ok(_)) => None,
+            DataTypeParseResult::RandomTargetAddressResult(Ok(_)) => None,
+            #[cfg(feature = "le-audio")]
+            DataTypeParseResult::ResolvableSetIdentifierResult(Ok(_)) => None,
+            #[cfg(feature = "pairing")]
+            DataTypeParseResult::SecureSimplePairingHashC192Result(Ok(_)) => None,
+            #[cfg(feature = "pairing")]
+            DataTypeParseResult::SecureSimplePairingHashC256Result(Ok(_)) => None,
+            #[cfg(feature = "pairing")]
+            DataTypeParseResult::SecureSimplePairingRandomizerR192Result(Ok(_)) => None,
+            #[cfg(feature = "pairing")]
+            DataTypeParseResult::SecureSimplePairingRandomizerR256Result(Ok(_)) => None,
+            #[cfg(feature = "pairing")]
+            DataTypeParseResult::SecurityManagerOutOfBandResult(Ok(_)) => None,
+            #[cfg(feature = "pairing")]
+            DataTypeParseResult::SecurityManagerTkValueResult(Ok(_)) => None,
+            DataTypeParseResult::ServiceData128BitUUIDResult(Ok(_)) => None,
+            DataTypeParseResult::ServiceData16BitUUIDResult(Ok(_)) => None,
+            DataTypeParseResult::ServiceData32BitUUIDResult(Ok(_)) => None,
+            DataTypeParseResult::ShortenedLocalNameResult(Ok(_)) => None,
+            DataTypeParseResult::ThreeDInformationDataResult(Ok(_)) => None,
+            DataTypeParseResult::TransportDiscoveryDataResult(Ok(_)) => None,
+            DataTypeParseResult::TxPowerLevelResult(Ok(_)) => None,
+            DataTypeParseResult::UniformResourceIdentifierResult(Ok(_)) => None,
+            DataTypeParseResult::DataTypeParseError(error) => Some(error),
+        }
+    }
+
     /// Returns `true` if the result is [`DataTypeParseResult::AdvertisingIntervalResult`].
     ///
     /// # Examples
@@ -270,12 +434,15 @@ impl DataTypeParseResult {
         matches!(self, DataTypeParseResult::AppearanceResult(_))
     }
 
+    #[cfg(feature = "le-audio")]
     /// Returns `true` if the result is [`DataTypeParseResult::BigInfoResult`].
     ///
     /// # Examples
     ///
     /// ```
-    /// use ble_data_struct::data_types::{big_info::BigInfo, data_type_parser::DataTypeParseResult};
+    /// use ble_data_struct::data_types::{
+    ///     big_info::BigInfo, channel_map::ChannelMap, data_type_parser::DataTypeParseResult,
+    /// };
     ///
     /// let big_offset: u16 = 1;
     /// let big_offset_units: bool = true;
@@ -293,7 +460,7 @@ impl DataTypeParseResult {
     /// let sdu_interval: u32 = 13;
     /// let max_sdu: u16 = 14;
     /// let base_crc_init: u16 = 15;
-    /// let ch_m: u64 = 16;
+    /// let ch_m = ChannelMap::from(16);
     /// let phy: u8 = 17;
     /// let bis_payload_count: u64 = 18;
     /// let framing: bool = false;
@@ -333,6 +500,7 @@ impl DataTypeParseResult {
         matches!(self, DataTypeParseResult::BigInfoResult(_))
     }
 
+    #[cfg(feature = "le-audio")]
     /// Returns `true` if the result is [`DataTypeParseResult::BroadcastCodeResult`].
     ///
     /// # Examples
@@ -356,14 +524,17 @@ impl DataTypeParseResult {
     /// # Examples
     ///
     /// ```
-    /// use ble_data_struct::data_types::{channel_map_update_indication::ChannelMapUpdateIndication, data_type_parser::DataTypeParseResult};
+    /// use ble_data_struct::data_types::{
+    ///     channel_map::ChannelMap, channel_map_update_indication::ChannelMapUpdateIndication,
+    ///     data_type_parser::DataTypeParseResult,
+    /// };
     ///
-    /// let mut ch_m = [false; 37].to_vec();
+    /// let mut ch_m = ChannelMap::default();
     /// for i in 0..37 {
-    ///     ch_m[i] = true;
-    ///     let data = ChannelMapUpdateIndication::new(&ch_m, i as u16).into();
+    ///     ch_m.set(i, true);
+    ///     let data = ChannelMapUpdateIndication::new(ch_m, i as u16).into();
     ///     assert!(DataTypeParseResult::from(&data).is_channel_map_update_indication());
-    ///     ch_m[i] = false;
+    ///     ch_m.set(i, false);
     /// }
     ///
     /// let data: Vec<u8> = Vec::new();
@@ -493,6 +664,23 @@ impl DataTypeParseResult {
         matches!(self, DataTypeParseResult::CompleteLocalNameResult(_))
     }
 
+    /// Returns `true` if the result is [`DataTypeParseResult::ElectronicShelfLabelResult`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{electronic_shelf_label::*, data_type_parser::DataTypeParseResult};
+    ///
+    /// let data: Vec<u8> = ElectronicShelfLabel::new(&[1, 2, 3, 4, 5], &[6, 7], &[8, 9, 10, 11]).into();
+    /// assert!(DataTypeParseResult::from(&data).is_electronic_shelf_label());
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// assert!(!DataTypeParseResult::from(&data).is_electronic_shelf_label());
+    /// ```
+    pub fn is_electronic_shelf_label(&self) -> bool {
+        matches!(self, DataTypeParseResult::ElectronicShelfLabelResult(_))
+    }
+
     /// Returns `true` if the result is [`DataTypeParseResult::EncryptedDataResult`].
     ///
     /// # Examples
@@ -609,6 +797,23 @@ impl DataTypeParseResult {
         )
     }
 
+    /// Returns `true` if the result is [`DataTypeParseResult::IndoorPositioningResult`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{indoor_positioning::*, data_type_parser::DataTypeParseResult};
+    ///
+    /// let data: Vec<u8> = IndoorPositioning::new(None, Some(-4), None, None, None, false).into();
+    /// assert!(DataTypeParseResult::from(&data).is_indoor_positioning());
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// assert!(!DataTypeParseResult::from(&data).is_indoor_positioning());
+    /// ```
+    pub fn is_indoor_positioning(&self) -> bool {
+        matches!(self, DataTypeParseResult::IndoorPositioningResult(_))
+    }
+
     /// Returns `true` if the result is [`DataTypeParseResult::LeBluetoothDeviceAddressResult`].
     ///
     /// # Examples
@@ -646,6 +851,7 @@ impl DataTypeParseResult {
         matches!(self, DataTypeParseResult::LeRoleResult(_))
     }
 
+    #[cfg(feature = "pairing")]
     /// Returns `true` if the result is [`DataTypeParseResult::LeSecureConnectionsConfirmationValueResult`].
     ///
     /// # Examples
@@ -669,6 +875,7 @@ impl DataTypeParseResult {
         )
     }
 
+    #[cfg(feature = "pairing")]
     /// Returns `true` if the result is [`DataTypeParseResult::LeSecureConnectionsRandomValueResult`].
     ///
     /// # Examples
@@ -843,6 +1050,24 @@ impl DataTypeParseResult {
         )
     }
 
+    #[cfg(feature = "mesh")]
+    /// Returns `true` if the result is [`DataTypeParseResult::MeshBeaconResult`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{mesh_beacon::*, data_type_parser::DataTypeParseResult};
+    ///
+    /// let data = MeshBeacon::new(0x00, &[0x01u8; 18]).into();
+    /// assert!(DataTypeParseResult::from(&data).is_mesh_beacon());
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// assert!(!DataTypeParseResult::from(&data).is_mesh_beacon());
+    /// ```
+    pub fn is_mesh_beacon(&self) -> bool {
+        matches!(self, DataTypeParseResult::MeshBeaconResult(_))
+    }
+
     /// Returns `true` if the result is [`DataTypeParseResult::PeripheralConnectionIntervalRangeResult`].
     ///
     /// # Examples
@@ -917,6 +1142,25 @@ impl DataTypeParseResult {
         matches!(self, DataTypeParseResult::RandomTargetAddressResult(_))
     }
 
+    #[cfg(feature = "le-audio")]
+    /// Returns `true` if the result is [`DataTypeParseResult::ResolvableSetIdentifierResult`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{resolvable_set_identifier::*, data_type_parser::DataTypeParseResult};
+    ///
+    /// let data = ResolvableSetIdentifier::new(0x69f5631948da).into();
+    /// assert!(DataTypeParseResult::from(&data).is_resolvable_set_identifier());
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// assert!(!DataTypeParseResult::from(&data).is_resolvable_set_identifier());
+    /// ```
+    pub fn is_resolvable_set_identifier(&self) -> bool {
+        matches!(self, DataTypeParseResult::ResolvableSetIdentifierResult(_))
+    }
+
+    #[cfg(feature = "pairing")]
     /// Returns `true` if the result is [`DataTypeParseResult::SecureSimplePairingHashC192Result`].
     ///
     /// # Examples
@@ -938,6 +1182,7 @@ impl DataTypeParseResult {
         )
     }
 
+    #[cfg(feature = "pairing")]
     /// Returns `true` if the result is [`DataTypeParseResult::SecureSimplePairingHashC256Result`].
     ///
     /// # Examples
@@ -959,6 +1204,7 @@ impl DataTypeParseResult {
         )
     }
 
+    #[cfg(feature = "pairing")]
     /// Returns `true` if the result is [`DataTypeParseResult::SecureSimplePairingRandomizerR192Result`].
     ///
     /// # Examples
@@ -981,6 +1227,7 @@ impl DataTypeParseResult {
         )
     }
 
+    #[cfg(feature = "pairing")]
     /// Returns `true` if the result is [`DataTypeParseResult::SecureSimplePairingRandomizerR256Result`].
     ///
     /// # Examples
@@ -1003,6 +1250,7 @@ impl DataTypeParseResult {
         )
     }
 
+    #[cfg(feature = "pairing")]
     /// Returns `true` if the result is [`DataTypeParseResult::SecurityManagerOutOfBandResult`].
     ///
     /// # Examples
@@ -1021,6 +1269,7 @@ impl DataTypeParseResult {
         matches!(self, DataTypeParseResult::SecurityManagerOutOfBandResult(_))
     }
 
+    #[cfg(feature = "pairing")]
     /// Returns `true` if the result is [`DataTypeParseResult::SecurityManagerTkValueResult`].
     ///
     /// # Examples
@@ -1117,6 +1366,40 @@ impl DataTypeParseResult {
         matches!(self, DataTypeParseResult::ShortenedLocalNameResult(_))
     }
 
+    /// Returns `true` if the result is [`DataTypeParseResult::ThreeDInformationDataResult`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{three_d_information_data::*, data_type_parser::DataTypeParseResult};
+    ///
+    /// let data: Vec<u8> = ThreeDInformationData::new(FLAGS_ASSOCIATION_NOTIFICATION, -70).into();
+    /// assert!(DataTypeParseResult::from(&data).is_three_d_information_data());
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// assert!(!DataTypeParseResult::from(&data).is_three_d_information_data());
+    /// ```
+    pub fn is_three_d_information_data(&self) -> bool {
+        matches!(self, DataTypeParseResult::ThreeDInformationDataResult(_))
+    }
+
+    /// Returns `true` if the result is [`DataTypeParseResult::TransportDiscoveryDataResult`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{transport_discovery_data::*, data_type_parser::DataTypeParseResult};
+    ///
+    /// let data = TransportDiscoveryData::new(&[TransportBlock::new(0x02, 0x09, &[0x01, 0x02])]).into();
+    /// assert!(DataTypeParseResult::from(&data).is_transport_discovery_data());
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// assert!(!DataTypeParseResult::from(&data).is_transport_discovery_data());
+    /// ```
+    pub fn is_transport_discovery_data(&self) -> bool {
+        matches!(self, DataTypeParseResult::TransportDiscoveryDataResult(_))
+    }
+
     /// Returns `true` if the result is [`DataTypeParseResult::TxPowerLevelResult`].
     ///
     /// # Examples
@@ -1157,6 +1440,261 @@ impl DataTypeParseResult {
             DataTypeParseResult::UniformResourceIdentifierResult(_)
         )
     }
+
+    /// Create [`DataTypeParseResult`] from `u8` slice with [`ParseOptions`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     complete_local_name::CompleteLocalName,
+    ///     data_type::DataType,
+    ///     data_type_parser::DataTypeParseResult,
+    ///     parse_options::{ParseOptions, Utf8DecodeMode},
+    /// };
+    ///
+    /// let data: Vec<u8> = vec![5, CompleteLocalName::data_type(), 0x6e, 0x61, 0xff, 0x65];
+    /// let result =
+    ///     DataTypeParseResult::from_with_options(&data, &ParseOptions::new(Utf8DecodeMode::Lossy));
+    /// assert!(matches!(result, DataTypeParseResult::CompleteLocalNameResult(Ok(_))));
+    ///
+    /// let result =
+    ///     DataTypeParseResult::from_with_options(&data, &ParseOptions::new(Utf8DecodeMode::Strict));
+    /// assert!(matches!(result, DataTypeParseResult::CompleteLocalNameResult(Err(_))));
+    /// ```
+    pub fn from_with_options(value: &[u8], options: &ParseOptions) -> Self {
+        if let Some(data_type) = value.get(1) {
+            match AdType::of(*data_type) {
+                AdType::AdvertisingInterval => DataTypeParseResult::AdvertisingIntervalResult(
+                    AdvertisingInterval::try_from(value),
+                ),
+                AdType::AdvertisingIntervalLong => {
+                    DataTypeParseResult::AdvertisingIntervalLongResult(
+                        AdvertisingIntervalLong::try_from(value),
+                    )
+                }
+                AdType::Appearance => {
+                    DataTypeParseResult::AppearanceResult(Appearance::try_from(value))
+                }
+                #[cfg(feature = "le-audio")]
+                AdType::BigInfo => DataTypeParseResult::BigInfoResult(BigInfo::try_from(value)),
+                #[cfg(feature = "le-audio")]
+                AdType::BroadcastCode => {
+                    DataTypeParseResult::BroadcastCodeResult(BroadcastCode::try_from(value))
+                }
+                AdType::ChannelMapUpdateIndication => {
+                    DataTypeParseResult::ChannelMapUpdateIndicationResult(
+                        ChannelMapUpdateIndication::try_from(value),
+                    )
+                }
+                AdType::ClassOfDevice => {
+                    DataTypeParseResult::ClassOfDeviceResult(ClassOfDevice::try_from(value))
+                }
+                AdType::CompleteListOf128BitServiceUuids => {
+                    DataTypeParseResult::CompleteListOf128BitServiceUuidsResult(
+                        CompleteListOf128BitServiceUuids::try_from(value),
+                    )
+                }
+                AdType::CompleteListOf16BitServiceUuids => {
+                    DataTypeParseResult::CompleteListOf16BitServiceUuidsResult(
+                        CompleteListOf16BitServiceUuids::try_from(value),
+                    )
+                }
+                AdType::CompleteListOf32BitServiceUuids => {
+                    DataTypeParseResult::CompleteListOf32BitServiceUuidsResult(
+                        CompleteListOf32BitServiceUuids::try_from(value),
+                    )
+                }
+                AdType::CompleteLocalName => DataTypeParseResult::CompleteLocalNameResult(
+                    CompleteLocalName::try_from_with_options(value, options),
+                ),
+                AdType::ElectronicShelfLabel => DataTypeParseResult::ElectronicShelfLabelResult(
+                    ElectronicShelfLabel::try_from(value),
+                ),
+                AdType::EncryptedData => {
+                    DataTypeParseResult::EncryptedDataResult(EncryptedData::try_from(value))
+                }
+                AdType::Flags => DataTypeParseResult::FlagsResult(Flags::try_from(value)),
+                AdType::IncompleteListOf128BitServiceUuids => {
+                    DataTypeParseResult::IncompleteListOf128BitServiceUuidsResult(
+                        IncompleteListOf128BitServiceUuids::try_from(value),
+                    )
+                }
+                AdType::IncompleteListOf16BitServiceUuids => {
+                    DataTypeParseResult::IncompleteListOf16BitServiceUuidsResult(
+                        IncompleteListOf16BitServiceUuids::try_from(value),
+                    )
+                }
+                AdType::IncompleteListOf32BitServiceUuids => {
+                    DataTypeParseResult::IncompleteListOf32BitServiceUuidsResult(
+                        IncompleteListOf32BitServiceUuids::try_from(value),
+                    )
+                }
+                AdType::IndoorPositioning => {
+                    DataTypeParseResult::IndoorPositioningResult(IndoorPositioning::try_from(value))
+                }
+                AdType::LeBluetoothDeviceAddress => {
+                    DataTypeParseResult::LeBluetoothDeviceAddressResult(
+                        LeBluetoothDeviceAddress::try_from(value),
+                    )
+                }
+                AdType::LeRole => DataTypeParseResult::LeRoleResult(LeRole::try_from(value)),
+                #[cfg(feature = "pairing")]
+                AdType::LeSecureConnectionsConfirmationValue => {
+                    DataTypeParseResult::LeSecureConnectionsConfirmationValueResult(
+                        LeSecureConnectionsConfirmationValue::try_from(value),
+                    )
+                }
+                #[cfg(feature = "pairing")]
+                AdType::LeSecureConnectionsRandomValue => {
+                    DataTypeParseResult::LeSecureConnectionsRandomValueResult(
+                        LeSecureConnectionsRandomValue::try_from(value),
+                    )
+                }
+                AdType::LeSupportedFeatures => DataTypeParseResult::LeSupportedFeaturesResult(
+                    LeSupportedFeatures::try_from(value),
+                ),
+                AdType::ListOf128BitServiceSolicitationUUIDs => {
+                    DataTypeParseResult::ListOf128BitServiceSolicitationUUIDsResult(
+                        ListOf128BitServiceSolicitationUUIDs::try_from(value),
+                    )
+                }
+                AdType::ListOf16BitServiceSolicitationUUIDs => {
+                    DataTypeParseResult::ListOf16BitServiceSolicitationUUIDsResult(
+                        ListOf16BitServiceSolicitationUUIDs::try_from(value),
+                    )
+                }
+                AdType::ListOf32BitServiceSolicitationUUIDs => {
+                    DataTypeParseResult::ListOf32BitServiceSolicitationUUIDsResult(
+                        ListOf32BitServiceSolicitationUUIDs::try_from(value),
+                    )
+                }
+                AdType::ManufacturerSpecificData => {
+                    DataTypeParseResult::ManufacturerSpecificDataResult(
+                        ManufacturerSpecificData::try_from(value),
+                    )
+                }
+                AdType::PeriodicAdvertisingResponseTimingInformation => {
+                    DataTypeParseResult::PeriodicAdvertisingResponseTimingInformationResult(
+                        PeriodicAdvertisingResponseTimingInformation::try_from(value),
+                    )
+                }
+                #[cfg(feature = "mesh")]
+                AdType::MeshBeacon => {
+                    DataTypeParseResult::MeshBeaconResult(MeshBeacon::try_from(value))
+                }
+                AdType::PeripheralConnectionIntervalRange => {
+                    DataTypeParseResult::PeripheralConnectionIntervalRangeResult(
+                        PeripheralConnectionIntervalRange::try_from(value),
+                    )
+                }
+                AdType::PublicTargetAddress => DataTypeParseResult::PublicTargetAddressResult(
+                    PublicTargetAddress::try_from(value),
+                ),
+                AdType::RandomTargetAddress => DataTypeParseResult::RandomTargetAddressResult(
+                    RandomTargetAddress::try_from(value),
+                ),
+                #[cfg(feature = "le-audio")]
+                AdType::ResolvableSetIdentifier => {
+                    DataTypeParseResult::ResolvableSetIdentifierResult(
+                        ResolvableSetIdentifier::try_from(value),
+                    )
+                }
+                #[cfg(feature = "pairing")]
+                AdType::SecureSimplePairingHashC192 => {
+                    DataTypeParseResult::SecureSimplePairingHashC192Result(
+                        SecureSimplePairingHashC192::try_from(value),
+                    )
+                }
+                #[cfg(feature = "pairing")]
+                AdType::SecureSimplePairingHashC256 => {
+                    DataTypeParseResult::SecureSimplePairingHashC256Result(
+                        SecureSimplePairingHashC256::try_from(value),
+                    )
+                }
+                #[cfg(feature = "pairing")]
+                AdType::SecureSimplePairingRandomizerR192 => {
+                    DataTypeParseResult::SecureSimplePairingRandomizerR192Result(
+                        SecureSimplePairingRandomizerR192::try_from(value),
+                    )
+                }
+                #[cfg(feature = "pairing")]
+                AdType::SecureSimplePairingRandomizerR256 => {
+                    DataTypeParseResult::SecureSimplePairingRandomizerR256Result(
+                        SecureSimplePairingRandomizerR256::try_from(value),
+                    )
+                }
+                #[cfg(feature = "pairing")]
+                AdType::SecurityManagerOutOfBand => {
+                    DataTypeParseResult::SecurityManagerOutOfBandResult(
+                        SecurityManagerOutOfBand::try_from(value),
+                    )
+                }
+                #[cfg(feature = "pairing")]
+                AdType::SecurityManagerTkValue => {
+                    DataTypeParseResult::SecurityManagerTkValueResult(
+                        SecurityManagerTkValue::try_from(value),
+                    )
+                }
+                AdType::ServiceData128BitUUID => DataTypeParseResult::ServiceData128BitUUIDResult(
+                    ServiceData128BitUUID::try_from(value),
+                ),
+                AdType::ServiceData16BitUUID => DataTypeParseResult::ServiceData16BitUUIDResult(
+                    ServiceData16BitUUID::try_from(value),
+                ),
+                AdType::ServiceData32BitUUID => DataTypeParseResult::ServiceData32BitUUIDResult(
+                    ServiceData32BitUUID::try_from(value),
+                ),
+                AdType::ShortenedLocalName => DataTypeParseResult::ShortenedLocalNameResult(
+                    ShortenedLocalName::try_from_with_options(value, options),
+                ),
+                AdType::ThreeDInformationData => DataTypeParseResult::ThreeDInformationDataResult(
+                    ThreeDInformationData::try_from(value),
+                ),
+                AdType::TransportDiscoveryData => {
+                    DataTypeParseResult::TransportDiscoveryDataResult(
+                        TransportDiscoveryData::try_from(value),
+                    )
+                }
+                AdType::TxPowerLevel => {
+                    DataTypeParseResult::TxPowerLevelResult(TxPowerLevel::try_from(value))
+                }
+                AdType::UniformResourceIdentifier => {
+                    DataTypeParseResult::UniformResourceIdentifierResult(
+                        UniformResourceIdentifier::try_from(value),
+                    )
+                }
+                #[cfg(not(feature = "le-audio"))]
+                AdType::BigInfo | AdType::BroadcastCode | AdType::ResolvableSetIdentifier => {
+                    DataTypeParseResult::DataTypeParseError(format!(
+                        "Disabled data type :{}",
+                        data_type
+                    ))
+                }
+                #[cfg(not(feature = "mesh"))]
+                AdType::MeshBeacon => DataTypeParseResult::DataTypeParseError(format!(
+                    "Disabled data type :{}",
+                    data_type
+                )),
+                #[cfg(not(feature = "pairing"))]
+                AdType::LeSecureConnectionsConfirmationValue
+                | AdType::LeSecureConnectionsRandomValue
+                | AdType::SecureSimplePairingHashC192
+                | AdType::SecureSimplePairingHashC256
+                | AdType::SecureSimplePairingRandomizerR192
+                | AdType::SecureSimplePairingRandomizerR256
+                | AdType::SecurityManagerOutOfBand
+                | AdType::SecurityManagerTkValue => DataTypeParseResult::DataTypeParseError(
+                    format!("Disabled data type :{}", data_type),
+                ),
+                AdType::Unknown(data_type) => DataTypeParseResult::DataTypeParseError(
+                    format!("Unknown data type :{}", data_type).to_string(),
+                ),
+            }
+        } else {
+            DataTypeParseResult::DataTypeParseError("Invalid data size".to_string())
+        }
+    }
 }
 
 impl From<&Vec<u8>> for DataTypeParseResult {
@@ -1179,151 +1717,7 @@ impl From<&Vec<u8>> for DataTypeParseResult {
     ///     DataTypeParseResult::DataTypeParseError(_)
     /// ));
     fn from(value: &Vec<u8>) -> Self {
-        if let Some(data_type) = value.get(1) {
-            if is_advertising_interval(data_type.to_owned()) {
-                DataTypeParseResult::AdvertisingIntervalResult(AdvertisingInterval::try_from(value))
-            } else if is_advertising_interval_long(data_type.to_owned()) {
-                DataTypeParseResult::AdvertisingIntervalLongResult(
-                    AdvertisingIntervalLong::try_from(value),
-                )
-            } else if is_appearance(data_type.to_owned()) {
-                DataTypeParseResult::AppearanceResult(Appearance::try_from(value))
-            } else if is_big_info(data_type.to_owned()) {
-                DataTypeParseResult::BigInfoResult(BigInfo::try_from(value))
-            } else if is_broadcast_code(data_type.to_owned()) {
-                DataTypeParseResult::BroadcastCodeResult(BroadcastCode::try_from(value))
-            } else if is_channel_map_update_indication(data_type.to_owned()) {
-                DataTypeParseResult::ChannelMapUpdateIndicationResult(
-                    ChannelMapUpdateIndication::try_from(value),
-                )
-            } else if is_class_of_device(data_type.to_owned()) {
-                DataTypeParseResult::ClassOfDeviceResult(ClassOfDevice::try_from(value))
-            } else if is_complete_list_of_128bit_service_uuids(data_type.to_owned()) {
-                DataTypeParseResult::CompleteListOf128BitServiceUuidsResult(
-                    CompleteListOf128BitServiceUuids::try_from(value),
-                )
-            } else if is_complete_list_of_16bit_service_uuids(data_type.to_owned()) {
-                DataTypeParseResult::CompleteListOf16BitServiceUuidsResult(
-                    CompleteListOf16BitServiceUuids::try_from(value),
-                )
-            } else if is_complete_list_of_32bit_service_uuids(data_type.to_owned()) {
-                DataTypeParseResult::CompleteListOf32BitServiceUuidsResult(
-                    CompleteListOf32BitServiceUuids::try_from(value),
-                )
-            } else if is_complete_local_name(data_type.to_owned()) {
-                DataTypeParseResult::CompleteLocalNameResult(CompleteLocalName::try_from(value))
-            } else if is_encrypted_data(data_type.to_owned()) {
-                DataTypeParseResult::EncryptedDataResult(EncryptedData::try_from(value))
-            } else if is_flags(data_type.to_owned()) {
-                DataTypeParseResult::FlagsResult(Flags::try_from(value))
-            } else if is_incomplete_list_of_128bit_service_uuids(data_type.to_owned()) {
-                DataTypeParseResult::IncompleteListOf128BitServiceUuidsResult(
-                    IncompleteListOf128BitServiceUuids::try_from(value),
-                )
-            } else if is_incomplete_list_of_16bit_service_uuids(data_type.to_owned()) {
-                DataTypeParseResult::IncompleteListOf16BitServiceUuidsResult(
-                    IncompleteListOf16BitServiceUuids::try_from(value),
-                )
-            } else if is_incomplete_list_of_32bit_service_uuids(data_type.to_owned()) {
-                DataTypeParseResult::IncompleteListOf32BitServiceUuidsResult(
-                    IncompleteListOf32BitServiceUuids::try_from(value),
-                )
-            } else if is_le_bluetooth_device_address(data_type.to_owned()) {
-                DataTypeParseResult::LeBluetoothDeviceAddressResult(
-                    LeBluetoothDeviceAddress::try_from(value),
-                )
-            } else if is_le_role(data_type.to_owned()) {
-                DataTypeParseResult::LeRoleResult(LeRole::try_from(value))
-            } else if is_le_secure_connections_confirmation_value(data_type.to_owned()) {
-                DataTypeParseResult::LeSecureConnectionsConfirmationValueResult(
-                    LeSecureConnectionsConfirmationValue::try_from(value),
-                )
-            } else if is_le_secure_connections_random_value(data_type.to_owned()) {
-                DataTypeParseResult::LeSecureConnectionsRandomValueResult(
-                    LeSecureConnectionsRandomValue::try_from(value),
-                )
-            } else if is_le_supported_features(data_type.to_owned()) {
-                DataTypeParseResult::LeSupportedFeaturesResult(LeSupportedFeatures::try_from(value))
-            } else if is_list_of_128bit_service_solicitation_uuids(data_type.to_owned()) {
-                DataTypeParseResult::ListOf128BitServiceSolicitationUUIDsResult(
-                    ListOf128BitServiceSolicitationUUIDs::try_from(value),
-                )
-            } else if is_list_of_16bit_service_solicitation_uuids(data_type.to_owned()) {
-                DataTypeParseResult::ListOf16BitServiceSolicitationUUIDsResult(
-                    ListOf16BitServiceSolicitationUUIDs::try_from(value),
-                )
-            } else if is_list_of_32bit_service_solicitation_uuids(data_type.to_owned()) {
-                DataTypeParseResult::ListOf32BitServiceSolicitationUUIDsResult(
-                    ListOf32BitServiceSolicitationUUIDs::try_from(value),
-                )
-            } else if is_manufacturer_specific_data(data_type.to_owned()) {
-                DataTypeParseResult::ManufacturerSpecificDataResult(
-                    ManufacturerSpecificData::try_from(value),
-                )
-            } else if is_periodic_advertising_response_timing_information(data_type.to_owned()) {
-                DataTypeParseResult::PeriodicAdvertisingResponseTimingInformationResult(
-                    PeriodicAdvertisingResponseTimingInformation::try_from(value),
-                )
-            } else if is_peripheral_connection_interval_range(data_type.to_owned()) {
-                DataTypeParseResult::PeripheralConnectionIntervalRangeResult(
-                    PeripheralConnectionIntervalRange::try_from(value),
-                )
-            } else if is_public_target_address(data_type.to_owned()) {
-                DataTypeParseResult::PublicTargetAddressResult(PublicTargetAddress::try_from(value))
-            } else if is_random_target_address(data_type.to_owned()) {
-                DataTypeParseResult::RandomTargetAddressResult(RandomTargetAddress::try_from(value))
-            } else if is_secure_simple_pairing_hash_c192(data_type.to_owned()) {
-                DataTypeParseResult::SecureSimplePairingHashC192Result(
-                    SecureSimplePairingHashC192::try_from(value),
-                )
-            } else if is_secure_simple_pairing_hash_c256(data_type.to_owned()) {
-                DataTypeParseResult::SecureSimplePairingHashC256Result(
-                    SecureSimplePairingHashC256::try_from(value),
-                )
-            } else if is_secure_simple_pairing_randomizer_r192(data_type.to_owned()) {
-                DataTypeParseResult::SecureSimplePairingRandomizerR192Result(
-                    SecureSimplePairingRandomizerR192::try_from(value),
-                )
-            } else if is_secure_simple_pairing_randomizer_r256(data_type.to_owned()) {
-                DataTypeParseResult::SecureSimplePairingRandomizerR256Result(
-                    SecureSimplePairingRandomizerR256::try_from(value),
-                )
-            } else if is_security_manager_oob(data_type.to_owned()) {
-                DataTypeParseResult::SecurityManagerOutOfBandResult(
-                    SecurityManagerOutOfBand::try_from(value),
-                )
-            } else if is_security_manager_tk_value(data_type.to_owned()) {
-                DataTypeParseResult::SecurityManagerTkValueResult(SecurityManagerTkValue::try_from(
-                    value,
-                ))
-            } else if is_service_data_128bit_uuid(data_type.to_owned()) {
-                DataTypeParseResult::ServiceData128BitUUIDResult(ServiceData128BitUUID::try_from(
-                    value,
-                ))
-            } else if is_service_data_16bit_uuid(data_type.to_owned()) {
-                DataTypeParseResult::ServiceData16BitUUIDResult(ServiceData16BitUUID::try_from(
-                    value,
-                ))
-            } else if is_service_data_32bit_uuid(data_type.to_owned()) {
-                DataTypeParseResult::ServiceData32BitUUIDResult(ServiceData32BitUUID::try_from(
-                    value,
-                ))
-            } else if is_shortened_local_name(data_type.to_owned()) {
-                DataTypeParseResult::ShortenedLocalNameResult(ShortenedLocalName::try_from(value))
-            } else if is_tx_power_level(data_type.to_owned()) {
-                DataTypeParseResult::TxPowerLevelResult(TxPowerLevel::try_from(value))
-            } else if is_uniform_resource_identifier(data_type.to_owned()) {
-                DataTypeParseResult::UniformResourceIdentifierResult(
-                    UniformResourceIdentifier::try_from(value),
-                )
-            } else {
-                DataTypeParseResult::DataTypeParseError(
-                    format!("Unknown data type :{}", data_type).to_string(),
-                )
-            }
-        } else {
-            DataTypeParseResult::DataTypeParseError("Invalid data size".to_string())
-        }
+        Self::from_with_options(value, &ParseOptions::default())
     }
 }
 
@@ -1376,10 +1770,23 @@ impl From<&Vec<u8>> for DataTypeParseResult {
 //         }
 //     }
 // }
+/// Service UUIDs collected from Service Class UUID lists.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ServiceUuids {
+    /// Deduplicated UUIDs
+    pub uuids: Vec<Uuid>,
+
+    /// `true` if every list is a complete list
+    pub is_complete: bool,
+}
+
 /// Data types parse results.
 pub struct DataTypeParseResults {
     /// Parse results.
     pub results: Vec<DataTypeParseResult>,
+
+    /// Offset of each data type in source payload.
+    pub(crate) offsets: Vec<usize>,
 }
 
 impl DataTypeParseResults {
@@ -1405,96 +1812,745 @@ impl DataTypeParseResults {
     /// );
     /// ```
     pub fn new(results: Vec<DataTypeParseResult>) -> Self {
-        DataTypeParseResults { results }
+        DataTypeParseResults {
+            results,
+            offsets: Vec::new(),
+        }
     }
-}
 
-impl From<&Vec<Vec<u8>>> for DataTypeParseResults {
-    /// Create [`DataTypeParseResults`] from `Vec<Vec<u8>>`.
+    /// Offset of each data type in source payload.
+    ///
+    /// Empty if the results were not parsed from a payload.
     ///
     /// # Examples
     ///
     /// ```
-    /// use ble_data_struct::data_types::{advertising_interval::AdvertisingInterval, data_type_parser::{DataTypeParseResult, DataTypeParseResults}};
+    /// use ble_data_struct::data_types::{
+    ///     advertising_interval::AdvertisingInterval,
+    ///     data_type_parser::{DataTypeParseResult, DataTypeParseResults},
+    /// };
+    ///
+    /// let mut data: Vec<u8> = AdvertisingInterval::new(0x01).into();
+    /// data.append(&mut AdvertisingInterval::new(0x02).into());
+    /// let results = DataTypeParseResults::from(&data);
+    /// assert_eq!(&[0, 4], results.offsets());
     ///
+    /// let results = DataTypeParseResults::new(vec![DataTypeParseResult::DataTypeParseError(
+    ///     "error".to_string(),
+    /// )]);
+    /// assert!(results.offsets().is_empty());
+    /// ```
+    pub fn offsets(&self) -> &[usize] {
+        &self.offsets
+    }
+
+    /// Get Service UUIDs from all 16-bit, 32-bit and 128-bit Service Class UUID lists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uuid::{uuid, Uuid};
+    /// use ble_data_struct::data_types::{
+    ///     complete_list_of_16bit_service_uuids::CompleteListOf16BitServiceUuids,
+    ///     data_type_parser::DataTypeParseResults,
+    ///     incomplete_list_of_128bit_service_uuids::IncompleteListOf128BitServiceUuids,
+    /// };
+    ///
+    /// let uuid16 = uuid!("00001234-0000-1000-8000-00805F9B34FB");
+    /// let uuid128 = uuid!("00000001-a087-4fa3-add4-3b8a7d5d4921");
     /// let mut vec: Vec<Vec<u8>> = Vec::new();
-    /// let advertising_interval = 0x01;
-    /// let data: Vec<u8> = AdvertisingInterval::new(advertising_interval).into();
-    /// vec.push(data);
-    /// vec.push(vec![]);
+    /// vec.push(CompleteListOf16BitServiceUuids::new(&[uuid16, uuid16].to_vec()).into());
+    /// let results = DataTypeParseResults::from(&vec);
+    /// let service_uuids = results.service_uuids();
+    /// assert_eq!([uuid16].to_vec(), service_uuids.uuids);
+    /// assert!(service_uuids.is_complete);
     ///
+    /// vec.push(IncompleteListOf128BitServiceUuids::new(&[uuid128].to_vec()).into());
     /// let results = DataTypeParseResults::from(&vec);
-    /// assert!(matches!(
-    ///     results.results.get(0),
-    ///     Some(DataTypeParseResult::AdvertisingIntervalResult(_))
-    /// ));
-    /// assert!(matches!(
-    ///     results.results.get(1),
-    ///     Some(DataTypeParseResult::DataTypeParseError(_))
-    /// ));
-    /// assert!(matches!(results.results.get(2), None));
-    /// ```
-    fn from(value: &Vec<Vec<u8>>) -> Self {
-        Self {
-            results: value
-                .iter()
-                .map(|f| DataTypeParseResult::from(f))
-                .collect::<Vec<DataTypeParseResult>>(),
+    /// let service_uuids = results.service_uuids();
+    /// assert_eq!([uuid16, uuid128].to_vec(), service_uuids.uuids);
+    /// assert!(!service_uuids.is_complete);
+    /// ```
+    pub fn service_uuids(&self) -> ServiceUuids {
+        let mut uuids: Vec<Uuid> = Vec::new();
+        let mut has_list = false;
+        let mut is_complete = true;
+        for result in &self.results {
+            let (list, complete) = match result {
+                DataTypeParseResult::IncompleteListOf16BitServiceUuidsResult(Ok(data_type)) => {
+                    (&data_type.uuids, false)
+                }
+                DataTypeParseResult::CompleteListOf16BitServiceUuidsResult(Ok(data_type)) => {
+                    (&data_type.uuids, true)
+                }
+                DataTypeParseResult::IncompleteListOf32BitServiceUuidsResult(Ok(data_type)) => {
+                    (&data_type.uuids, false)
+                }
+                DataTypeParseResult::CompleteListOf32BitServiceUuidsResult(Ok(data_type)) => {
+                    (&data_type.uuids, true)
+                }
+                DataTypeParseResult::IncompleteListOf128BitServiceUuidsResult(Ok(data_type)) => {
+                    (&data_type.uuids, false)
+                }
+                DataTypeParseResult::CompleteListOf128BitServiceUuidsResult(Ok(data_type)) => {
+                    (&data_type.uuids, true)
+                }
+                _ => continue,
+            };
+            has_list = true;
+            is_complete &= complete;
+            for uuid in list {
+                if !uuids.contains(uuid) {
+                    uuids.push(*uuid);
+                }
+            }
+        }
+        ServiceUuids {
+            uuids,
+            is_complete: has_list && is_complete,
         }
     }
-}
 
-impl From<&Vec<u8>> for DataTypeParseResults {
-    /// Create [`DataTypeParseResults`] from [`Vec<u8>`].
+    /// check Service UUID is advertised in Service Class UUID lists or Service Data.
     ///
     /// # Examples
     ///
     /// ```
-    /// use ble_data_struct::data_types::{advertising_interval::AdvertisingInterval, data_type_parser::{DataTypeParseResult, DataTypeParseResults}};
-    ///
+    /// use uuid::{uuid, Uuid};
+    /// use ble_data_struct::{
+    ///     data_types::{
+    ///         complete_list_of_16bit_service_uuids::CompleteListOf16BitServiceUuids,
+    ///         data_type_parser::DataTypeParseResults,
+    ///         service_data_32bit_uuid::ServiceData32BitUUID,
+    ///     },
+    ///     ShortUuid,
+    /// };
+    ///
+    /// let uuid16 = uuid!("0000180f-0000-1000-8000-00805F9B34FB");
+    /// let uuid32 = uuid!("12345678-0000-1000-8000-00805F9B34FB");
     /// let mut vec: Vec<Vec<u8>> = Vec::new();
-    /// let advertising_interval = 0x01;
-    /// vec.push(AdvertisingInterval::new(advertising_interval).into());
-    /// vec.push(vec![100]);
+    /// vec.push(CompleteListOf16BitServiceUuids::new(&[uuid16].to_vec()).into());
+    /// vec.push(ServiceData32BitUUID::new(&uuid32, &[0x01u8].to_vec()).into());
+    /// let results = DataTypeParseResults::from(&vec);
+    /// assert!(results.has_service(uuid16));
+    /// assert!(results.has_service(ShortUuid::Uuid16(0x180f)));
+    /// assert!(results.has_service(ShortUuid::Uuid32(0x12345678)));
+    /// assert!(!results.has_service(ShortUuid::Uuid16(0x1234)));
+    /// ```
+    pub fn has_service<T: Into<Uuid>>(&self, uuid: T) -> bool {
+        let uuid = uuid.into();
+        self.service_uuids().uuids.contains(&uuid)
+            || self.results.iter().any(|result| match result {
+                DataTypeParseResult::ServiceData16BitUUIDResult(Ok(data_type)) => {
+                    data_type.uuid == uuid
+                }
+                DataTypeParseResult::ServiceData32BitUUIDResult(Ok(data_type)) => {
+                    data_type.uuid == uuid
+                }
+                DataTypeParseResult::ServiceData128BitUUIDResult(Ok(data_type)) => {
+                    data_type.uuid == uuid
+                }
+                _ => false,
+            })
+    }
+
+    /// Get additional service data for Service UUID from 16-bit, 32-bit and 128-bit Service Data.
     ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uuid::{uuid, Uuid};
+    /// use ble_data_struct::{
+    ///     data_types::{
+    ///         data_type_parser::DataTypeParseResults,
+    ///         service_data_16bit_uuid::ServiceData16BitUUID,
+    ///     },
+    ///     ShortUuid,
+    /// };
+    ///
+    /// let uuid16 = uuid!("0000180f-0000-1000-8000-00805F9B34FB");
+    /// let mut vec: Vec<Vec<u8>> = Vec::new();
+    /// vec.push(ServiceData16BitUUID::new(&uuid16, &[0x64u8].to_vec()).into());
     /// let results = DataTypeParseResults::from(&vec);
-    /// assert!(matches!(
-    ///     results.results.get(0),
-    ///     Some(DataTypeParseResult::AdvertisingIntervalResult(_))
-    /// ));
-    /// assert!(matches!(
-    ///     results.results.get(1),
-    ///     Some(DataTypeParseResult::DataTypeParseError(_))
-    /// ));
-    /// assert!(matches!(results.results.get(2), None));
+    /// assert_eq!(Some([0x64u8].as_slice()), results.service_data(uuid16));
+    /// assert_eq!(
+    ///     Some([0x64u8].as_slice()),
+    ///     results.service_data(ShortUuid::Uuid16(0x180f))
+    /// );
+    /// assert_eq!(None, results.service_data(ShortUuid::Uuid16(0x1234)));
+    /// ```
+    pub fn service_data<T: Into<Uuid>>(&self, uuid: T) -> Option<&[u8]> {
+        let uuid = uuid.into();
+        self.results.iter().find_map(|result| match result {
+            DataTypeParseResult::ServiceData16BitUUIDResult(Ok(data_type))
+                if data_type.uuid == uuid =>
+            {
+                Some(data_type.additional_service_data.as_slice())
+            }
+            DataTypeParseResult::ServiceData32BitUUIDResult(Ok(data_type))
+                if data_type.uuid == uuid =>
+            {
+                Some(data_type.additional_service_data.as_slice())
+            }
+            DataTypeParseResult::ServiceData128BitUUIDResult(Ok(data_type))
+                if data_type.uuid == uuid =>
+            {
+                Some(data_type.additional_service_data.as_slice())
+            }
+            _ => None,
+        })
+    }
+
+    /// Get Manufacturer Specific Data for Company Identifier Code.
+    ///
+    /// # Examples
+    ///
     /// ```
-    fn from(value: &Vec<u8>) -> Self {
-        let mut vec = Vec::new();
-        let mut index = 0;
-        let len = value.len();
-        while index < len {
-            let mut inner: Vec<u8> = Vec::new();
-            let size = value[index];
-            inner.append(&mut value[index..index + 1 + size as usize].to_vec());
-            vec.push(inner);
+    /// use ble_data_struct::data_types::{
+    ///     data_type_parser::DataTypeParseResults,
+    ///     manufacturer_specific_data::ManufacturerSpecificData,
+    /// };
+    ///
+    /// let mut vec: Vec<Vec<u8>> = Vec::new();
+    /// vec.push(ManufacturerSpecificData::new(0x004c, &[0x01u8].to_vec()).into());
+    /// vec.push(ManufacturerSpecificData::new(0x0006, &[0x02u8].to_vec()).into());
+    /// let results = DataTypeParseResults::from(&vec);
+    /// assert_eq!(Some([0x01u8].as_slice()), results.manufacturer_data(0x004c));
+    /// assert_eq!(Some([0x02u8].as_slice()), results.manufacturer_data(0x0006));
+    /// assert_eq!(None, results.manufacturer_data(0x0ca8));
+    /// ```
+    pub fn manufacturer_data(&self, company_identifier: u16) -> Option<&[u8]> {
+        self.results.iter().find_map(|result| match result {
+            DataTypeParseResult::ManufacturerSpecificDataResult(Ok(data_type))
+                if data_type.company_identifier == company_identifier =>
+            {
+                Some(data_type.manufacturer_specific_data.as_slice())
+            }
+            _ => None,
+        })
+    }
 
-            index += 1;
-            index += size as usize;
-        }
-        Self::from(&vec)
+    /// Get Battery Level (%) from Battery Service data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::{
+    ///         data_type_parser::DataTypeParseResults,
+    ///         service_data_16bit_uuid::ServiceData16BitUUID,
+    ///     },
+    ///     service_data::battery_service::BatteryServiceData,
+    /// };
+    ///
+    /// let service_data: ServiceData16BitUUID = BatteryServiceData::new(80).into();
+    /// let vec: Vec<Vec<u8>> = vec![service_data.into()];
+    /// let results = DataTypeParseResults::from(&vec);
+    /// assert_eq!(Some(80), results.battery_level());
+    ///
+    /// let vec: Vec<Vec<u8>> = Vec::new();
+    /// let results = DataTypeParseResults::from(&vec);
+    /// assert_eq!(None, results.battery_level());
+    /// ```
+    pub fn battery_level(&self) -> Option<u8> {
+        self.service_data(uuid_from_u16(BATTERY_SERVICE_UUID))
+            .and_then(|data| BatteryServiceData::try_from(&data.to_vec()).ok())
+            .map(|data| data.battery_level)
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use uuid::{uuid, Uuid};
+    /// Get sensor values from 16-bit Service Data keyed by sensor characteristic UUID.
+    ///
+    /// Service data which is not a supported sensor characteristic is skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::{
+    ///         data_type_parser::DataTypeParseResults,
+    ///         service_data_16bit_uuid::ServiceData16BitUUID,
+    ///     },
+    ///     service_data::sensor::SensorData,
+    ///     uuid_from_u16,
+    /// };
+    ///
+    /// let vec: Vec<Vec<u8>> = vec![
+    ///     ServiceData16BitUUID::new(&uuid_from_u16(0x2a6e), &vec![0x2e, 0x09]).into(),
+    ///     ServiceData16BitUUID::new(&uuid_from_u16(0x180f), &vec![0x64]).into(),
+    ///     ServiceData16BitUUID::new(&uuid_from_u16(0x2a6f), &vec![0x88, 0x13]).into(),
+    /// ];
+    /// let results = DataTypeParseResults::from(&vec);
+    /// assert_eq!(
+    ///     vec![SensorData::Temperature(23.5), SensorData::Humidity(50.0)],
+    ///     results.sensor_data()
+    /// );
+    /// ```
+    pub fn sensor_data(&self) -> Vec<SensorData> {
+        self.results
+            .iter()
+            .filter_map(|result| match result {
+                DataTypeParseResult::ServiceData16BitUUIDResult(Ok(data_type)) => {
+                    SensorData::try_from(data_type).ok()
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Get URLs from Uniform Resource Identifier and Eddystone-URL frames.
+    ///
+    /// Eddystone-URL frames are decoded only with `vendor` feature.
+    ///
+    /// Scheme and host are lowercased and duplicated URLs are removed, keeping advertised order.
+    /// URIs with unknown scheme code are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     data_type_parser::DataTypeParseResults,
+    ///     uniform_resource_identifier::UniformResourceIdentifier,
+    /// };
+    ///
+    /// let uri = UniformResourceIdentifier::from_uri("https://Example.com/Path").unwrap();
+    /// let url = UniformResourceIdentifier::from_uri("https://example.com/Path").unwrap();
+    /// let other = UniformResourceIdentifier::from_uri("http://www.bluetooth.com").unwrap();
+    /// let vec: Vec<Vec<u8>> = vec![uri.into(), url.into(), other.into()];
+    /// let results = DataTypeParseResults::from(&vec);
+    /// assert_eq!(
+    ///     vec![
+    ///         "https://example.com/Path".to_string(),
+    ///         "http://www.bluetooth.com".to_string()
+    ///     ],
+    ///     results.urls()
+    /// );
+    /// ```
+    pub fn urls(&self) -> Vec<String> {
+        let mut urls: Vec<String> = Vec::new();
+        for result in &self.results {
+            let url = match result {
+                DataTypeParseResult::UniformResourceIdentifierResult(Ok(data_type)) => {
+                    data_type.uri()
+                }
+                #[cfg(feature = "vendor")]
+                DataTypeParseResult::ServiceData16BitUUIDResult(Ok(data_type)) => {
+                    EddystoneUrl::try_from(data_type).ok().map(|data| data.url)
+                }
+                _ => None,
+            };
+            if let Some(url) = url.map(|url| normalize_url(&url)) {
+                if !urls.contains(&url) {
+                    urls.push(url);
+                }
+            }
+        }
+        urls
+    }
+
+    /// Get device name.
+    ///
+    /// Returns Complete Local Name if present, otherwise Shortened Local Name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     complete_local_name::CompleteLocalName, data_type_parser::DataTypeParseResults,
+    ///     shortened_local_name::ShortenedLocalName,
+    /// };
+    ///
+    /// let mut vec: Vec<Vec<u8>> = Vec::new();
+    /// vec.push(ShortenedLocalName::new(&"short".to_string()).into());
+    /// let results = DataTypeParseResults::from(&vec);
+    /// assert_eq!(Some("short"), results.device_name());
+    ///
+    /// vec.push(CompleteLocalName::new(&"complete".to_string()).into());
+    /// let results = DataTypeParseResults::from(&vec);
+    /// assert_eq!(Some("complete"), results.device_name());
+    ///
+    /// let vec: Vec<Vec<u8>> = Vec::new();
+    /// let results = DataTypeParseResults::from(&vec);
+    /// assert_eq!(None, results.device_name());
+    /// ```
+    pub fn device_name(&self) -> Option<&str> {
+        self.results
+            .iter()
+            .find_map(|result| match result {
+                DataTypeParseResult::CompleteLocalNameResult(Ok(data_type)) => {
+                    Some(data_type.complete_local_name.as_str())
+                }
+                _ => None,
+            })
+            .or_else(|| {
+                self.results.iter().find_map(|result| match result {
+                    DataTypeParseResult::ShortenedLocalNameResult(Ok(data_type)) => {
+                        Some(data_type.shortened_local_name.as_str())
+                    }
+                    _ => None,
+                })
+            })
+    }
+
+    /// Get errors of failed or unknown data types.
+    ///
+    /// Each item is `(index, offset, error)`. `offset` is [`None`] if the results were not created from a payload.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{advertising_interval::AdvertisingInterval, data_type_parser::DataTypeParseResults};
+    ///
+    /// let mut data: Vec<u8> = AdvertisingInterval::new(0x01).into();
+    /// data.append(&mut vec![0x01, 0x1a]);
+    /// data.append(&mut vec![0x01, 0x00]);
+    /// let results = DataTypeParseResults::from(&data);
+    /// let errors: Vec<(usize, Option<usize>, &String)> = results.errors().collect();
+    /// assert_eq!(2, errors.len());
+    /// assert_eq!(1, errors[0].0);
+    /// assert_eq!(Some(4), errors[0].1);
+    /// assert_eq!(2, errors[1].0);
+    /// assert_eq!(Some(6), errors[1].1);
+    /// ```
+    pub fn errors(&self) -> impl Iterator<Item = (usize, Option<usize>, &String)> {
+        self.results
+            .iter()
+            .enumerate()
+            .filter_map(|(index, result)| {
+                result
+                    .error()
+                    .map(|error| (index, self.offsets.get(index).copied(), error))
+            })
+    }
+
+    /// Create [`DataTypeParseResults`] from `Vec<Vec<u8>>` with [`ParseOptions`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     complete_local_name::CompleteLocalName,
+    ///     data_type::DataType,
+    ///     data_type_parser::{DataTypeParseResult, DataTypeParseResults},
+    ///     parse_options::{ParseOptions, Utf8DecodeMode},
+    /// };
+    ///
+    /// let vec: Vec<Vec<u8>> = vec![vec![5, CompleteLocalName::data_type(), 0x6e, 0x61, 0xff, 0x65]];
+    /// let results =
+    ///     DataTypeParseResults::from_with_options(&vec, &ParseOptions::new(Utf8DecodeMode::Strict));
+    /// assert!(matches!(
+    ///     results.results.get(0),
+    ///     Some(DataTypeParseResult::CompleteLocalNameResult(Err(_)))
+    /// ));
+    /// ```
+    pub fn from_with_options(value: &Vec<Vec<u8>>, options: &ParseOptions) -> Self {
+        let mut offsets = Vec::new();
+        let mut offset = 0;
+        for data in value {
+            offsets.push(offset);
+            offset += data.len();
+        }
+        Self {
+            results: value
+                .iter()
+                .map(|f| DataTypeParseResult::from_with_options(f, options))
+                .collect::<Vec<DataTypeParseResult>>(),
+            offsets,
+        }
+    }
+
+    /// Create [`DataTypeParseResults`] from advertising payload with [`ParseOptions`].
+    ///
+    /// Parsing stops at a zero length data (early termination), and a truncated last data is
+    /// reported as [`DataTypeParseResult::DataTypeParseError`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     complete_local_name::CompleteLocalName,
+    ///     data_type::DataType,
+    ///     data_type_parser::{DataTypeParseResult, DataTypeParseResults},
+    ///     parse_options::{ParseOptions, Utf8DecodeMode},
+    /// };
+    ///
+    /// let data: Vec<u8> = vec![5, CompleteLocalName::data_type(), 0x6e, 0x61, 0xff, 0x65];
+    /// let results = DataTypeParseResults::from_payload_with_options(
+    ///     &data,
+    ///     &ParseOptions::new(Utf8DecodeMode::Lossy),
+    /// );
+    /// assert_eq!(Some("na\u{fffd}e"), results.device_name());
+    ///
+    /// let results = DataTypeParseResults::from_payload_with_options(
+    ///     &data,
+    ///     &ParseOptions::new(Utf8DecodeMode::Strict),
+    /// );
+    /// assert_eq!(None, results.device_name());
+    /// ```
+    pub fn from_payload_with_options(value: &[u8], options: &ParseOptions) -> Self {
+        let mut results = Self::new(Vec::new());
+        results.append_payload(value, options);
+        results
+    }
+
+    /// Parse advertising payload and append results.
+    fn append_payload(&mut self, value: &[u8], options: &ParseOptions) {
+        let mut index = 0;
+        let len = value.len();
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("parse_payload", len).entered();
+        while index < len {
+            let size = value[index] as usize;
+            if size == 0 {
+                // early termination (padding)
+                #[cfg(feature = "tracing")]
+                tracing::trace!(offset = index, "early termination");
+                break;
+            }
+            self.offsets.push(index);
+            if index + 1 + size > len {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(
+                    offset = index,
+                    length = size,
+                    remaining = len - index,
+                    "truncated data type"
+                );
+                self.results.push(DataTypeParseResult::DataTypeParseError(
+                    format!("Invalid data size :{}", len - index).to_string(),
+                ));
+                break;
+            }
+            let result =
+                DataTypeParseResult::from_with_options(&value[index..index + 1 + size], options);
+            #[cfg(feature = "tracing")]
+            match result.error() {
+                Some(error) => tracing::warn!(
+                    offset = index,
+                    data_type = value[index + 1],
+                    length = size,
+                    error = error.as_str(),
+                    "data type parse error"
+                ),
+                None => tracing::debug!(
+                    offset = index,
+                    data_type = value[index + 1],
+                    length = size,
+                    "data type parsed"
+                ),
+            }
+            self.results.push(result);
+
+            index += 1;
+            index += size;
+        }
+    }
+}
+
+impl From<&Vec<Vec<u8>>> for DataTypeParseResults {
+    /// Create [`DataTypeParseResults`] from `Vec<Vec<u8>>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{advertising_interval::AdvertisingInterval, data_type_parser::{DataTypeParseResult, DataTypeParseResults}};
+    ///
+    /// let mut vec: Vec<Vec<u8>> = Vec::new();
+    /// let advertising_interval = 0x01;
+    /// let data: Vec<u8> = AdvertisingInterval::new(advertising_interval).into();
+    /// vec.push(data);
+    /// vec.push(vec![]);
+    ///
+    /// let results = DataTypeParseResults::from(&vec);
+    /// assert!(matches!(
+    ///     results.results.get(0),
+    ///     Some(DataTypeParseResult::AdvertisingIntervalResult(_))
+    /// ));
+    /// assert!(matches!(
+    ///     results.results.get(1),
+    ///     Some(DataTypeParseResult::DataTypeParseError(_))
+    /// ));
+    /// assert!(matches!(results.results.get(2), None));
+    /// ```
+    fn from(value: &Vec<Vec<u8>>) -> Self {
+        Self::from_with_options(value, &ParseOptions::default())
+    }
+}
+
+impl From<&Vec<u8>> for DataTypeParseResults {
+    /// Create [`DataTypeParseResults`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{advertising_interval::AdvertisingInterval, data_type_parser::{DataTypeParseResult, DataTypeParseResults}};
+    ///
+    /// let mut vec: Vec<Vec<u8>> = Vec::new();
+    /// let advertising_interval = 0x01;
+    /// vec.push(AdvertisingInterval::new(advertising_interval).into());
+    /// vec.push(vec![100]);
+    ///
+    /// let results = DataTypeParseResults::from(&vec);
+    /// assert!(matches!(
+    ///     results.results.get(0),
+    ///     Some(DataTypeParseResult::AdvertisingIntervalResult(_))
+    /// ));
+    /// assert!(matches!(
+    ///     results.results.get(1),
+    ///     Some(DataTypeParseResult::DataTypeParseError(_))
+    /// ));
+    /// assert!(matches!(results.results.get(2), None));
+    /// ```
+    fn from(value: &Vec<u8>) -> Self {
+        Self::from_payload_with_options(value, &ParseOptions::default())
+    }
+}
+
+/// Reusable advertising payload parser.
+///
+/// Results are kept between payloads, so long-running scanners don't allocate per payload.
+pub struct Parser {
+    /// Parse options
+    pub options: ParseOptions,
+
+    results: DataTypeParseResults,
+}
+
+impl Parser {
+    /// Create [`Parser`] with [`ParseOptions`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     data_type_parser::Parser,
+    ///     parse_options::{ParseOptions, Utf8DecodeMode},
+    /// };
+    ///
+    /// let parser = Parser::new(ParseOptions::new(Utf8DecodeMode::Strict));
+    /// assert_eq!(Utf8DecodeMode::Strict, parser.options.utf8_decode_mode);
+    /// assert!(parser.results().results.is_empty());
+    /// ```
+    pub fn new(options: ParseOptions) -> Self {
+        Self {
+            options,
+            results: DataTypeParseResults::new(Vec::new()),
+        }
+    }
+
+    /// Parse advertising payload, replacing previous results.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     advertising_interval::AdvertisingInterval,
+    ///     complete_local_name::CompleteLocalName,
+    ///     data_type_parser::Parser,
+    /// };
+    ///
+    /// let mut parser = Parser::default();
+    ///
+    /// let mut data: Vec<u8> = AdvertisingInterval::new(0x01).into();
+    /// data.append(&mut CompleteLocalName::new(&"name".to_string()).into());
+    /// let results = parser.parse(&data);
+    /// assert_eq!(2, results.results.len());
+    /// assert_eq!(Some("name"), results.device_name());
+    ///
+    /// let data: Vec<u8> = AdvertisingInterval::new(0x01).into();
+    /// let results = parser.parse(&data);
+    /// assert_eq!(1, results.results.len());
+    /// assert_eq!(None, results.device_name());
+    /// ```
+    pub fn parse(&mut self, payload: &[u8]) -> &DataTypeParseResults {
+        self.clear();
+        self.results.append_payload(payload, &self.options);
+        &self.results
+    }
+
+    /// Get results of last parse.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{advertising_interval::AdvertisingInterval, data_type_parser::Parser};
+    ///
+    /// let mut parser = Parser::default();
+    /// let data: Vec<u8> = AdvertisingInterval::new(0x01).into();
+    /// parser.parse(&data);
+    /// assert_eq!(1, parser.results().results.len());
+    /// ```
+    pub fn results(&self) -> &DataTypeParseResults {
+        &self.results
+    }
+
+    /// Clear results, keeping allocated capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{advertising_interval::AdvertisingInterval, data_type_parser::Parser};
+    ///
+    /// let mut parser = Parser::default();
+    /// let data: Vec<u8> = AdvertisingInterval::new(0x01).into();
+    /// parser.parse(&data);
+    /// parser.clear();
+    /// assert!(parser.results().results.is_empty());
+    /// assert!(parser.results().offsets().is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        self.results.results.clear();
+        self.results.offsets.clear();
+    }
+}
+
+impl Default for Parser {
+    /// Create [`Parser`] with default [`ParseOptions`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{data_type_parser::Parser, parse_options::ParseOptions};
+    ///
+    /// assert_eq!(ParseOptions::default(), Parser::default().options);
+    /// ```
+    fn default() -> Self {
+        Self::new(ParseOptions::default())
+    }
+}
+
+/// Lowercase URL scheme and host.
+fn normalize_url(url: &str) -> String {
+    let Some((scheme, rest)) = url.split_once(':') else {
+        return url.to_string();
+    };
+    let mut normalized = scheme.to_ascii_lowercase() + ":";
+    match rest.strip_prefix("//") {
+        Some(rest) => {
+            let index = rest.find(['/', '?', '#']).unwrap_or(rest.len());
+            normalized.push_str("//");
+            normalized.push_str(&rest[..index].to_ascii_lowercase());
+            normalized.push_str(&rest[index..]);
+        }
+        None => normalized.push_str(rest),
+    }
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::{uuid, Uuid};
+
+    use crate::{service_data::sensor::SensorData, uuid_from_u16, ShortUuid};
+
+    #[cfg(feature = "vendor")]
+    use crate::beacons::eddystone::{EddystoneUid, EddystoneUrl};
 
     use crate::data_types::{
+        ad_type::AdType,
         advertising_interval::AdvertisingInterval,
         advertising_interval_long::AdvertisingIntervalLong,
         appearance::Appearance,
-        big_info::BigInfo,
-        broadcast_code::BroadcastCode,
+        channel_map::ChannelMap,
         channel_map_update_indication::ChannelMapUpdateIndication,
         class_of_device::ClassOfDevice,
         complete_list_of_128bit_service_uuids::CompleteListOf128BitServiceUuids,
@@ -1502,15 +2558,15 @@ mod tests {
         complete_list_of_32bit_service_uuids::CompleteListOf32BitServiceUuids,
         complete_local_name::CompleteLocalName,
         data_type_parser::DataTypeParseResult,
+        electronic_shelf_label::*,
         encrypted_data::EncryptedData,
         flags::Flags,
         incomplete_list_of_128bit_service_uuids::IncompleteListOf128BitServiceUuids,
         incomplete_list_of_16bit_service_uuids::IncompleteListOf16BitServiceUuids,
         incomplete_list_of_32bit_service_uuids::IncompleteListOf32BitServiceUuids,
+        indoor_positioning::*,
         le_bluetooth_device_address::LeBluetoothDeviceAddress,
         le_role::{LeRole, ONLY_PERIPHERAL_ROLE_SUPPORTED},
-        le_secure_connections_confirmation_value::LeSecureConnectionsConfirmationValue,
-        le_secure_connections_random_value::LeSecureConnectionsRandomValue,
         le_supported_features::LeSupportedFeatures,
         list_of_128bit_service_solicitation_uuids::ListOf128BitServiceSolicitationUUIDs,
         list_of_16bit_service_solicitation_uuids::ListOf16BitServiceSolicitationUUIDs,
@@ -1520,21 +2576,42 @@ mod tests {
         peripheral_connection_interval_range::PeripheralConnectionIntervalRange,
         public_target_address::PublicTargetAddress,
         random_target_address::RandomTargetAddress,
-        secure_simple_pairing_hash_c192::SecureSimplePairingHashC192,
-        secure_simple_pairing_hash_c256::SecureSimplePairingHashC256,
-        secure_simple_pairing_randomizer_r192::SecureSimplePairingRandomizerR192,
-        secure_simple_pairing_randomizer_r256::SecureSimplePairingRandomizerR256,
-        security_manager_oob::SecurityManagerOutOfBand,
-        security_manager_tk_value::SecurityManagerTkValue,
         service_data_128bit_uuid::ServiceData128BitUUID,
         service_data_16bit_uuid::ServiceData16BitUUID,
         service_data_32bit_uuid::ServiceData32BitUUID,
         shortened_local_name::ShortenedLocalName,
+        three_d_information_data::*,
+        transport_discovery_data::*,
         tx_power_level::TxPowerLevel,
         uniform_resource_identifier::UniformResourceIdentifier,
     };
 
-    use super::DataTypeParseResults;
+    #[cfg(feature = "le-audio")]
+    use crate::data_types::{
+        big_info::BigInfo, broadcast_code::BroadcastCode, resolvable_set_identifier::*,
+    };
+
+    #[cfg(feature = "mesh")]
+    use crate::data_types::mesh_beacon::*;
+
+    #[cfg(feature = "pairing")]
+    use crate::data_types::{
+        le_secure_connections_confirmation_value::LeSecureConnectionsConfirmationValue,
+        le_secure_connections_random_value::LeSecureConnectionsRandomValue,
+        secure_simple_pairing_hash_c192::SecureSimplePairingHashC192,
+        secure_simple_pairing_hash_c256::SecureSimplePairingHashC256,
+        secure_simple_pairing_randomizer_r192::SecureSimplePairingRandomizerR192,
+        secure_simple_pairing_randomizer_r256::SecureSimplePairingRandomizerR256,
+        security_manager_oob::SecurityManagerOutOfBand,
+        security_manager_tk_value::SecurityManagerTkValue,
+    };
+
+    use super::{DataTypeParseResults, Parser};
+
+    use crate::data_types::{
+        data_type::DataType,
+        parse_options::{ParseOptions, Utf8DecodeMode},
+    };
 
     #[test]
     fn test_new() {
@@ -1573,6 +2650,7 @@ mod tests {
         assert!(!DataTypeParseResult::from(&data).is_appearance());
     }
 
+    #[cfg(feature = "le-audio")]
     #[test]
     fn test_is_big_info() {
         let big_offset: u16 = 1;
@@ -1591,7 +2669,7 @@ mod tests {
         let sdu_interval: u32 = 13;
         let max_sdu: u16 = 14;
         let base_crc_init: u16 = 15;
-        let ch_m: u64 = 16;
+        let ch_m = ChannelMap::from(16);
         let phy: u8 = 17;
         let bis_payload_count: u64 = 18;
         let framing: bool = false;
@@ -1628,6 +2706,7 @@ mod tests {
         assert!(!DataTypeParseResult::from(&data).is_big_info());
     }
 
+    #[cfg(feature = "le-audio")]
     #[test]
     fn test_is_broadcast_code() {
         let broadcast_code = [0x00u8; 4].to_vec();
@@ -1640,12 +2719,12 @@ mod tests {
 
     #[test]
     fn test_is_channel_map_update_indication() {
-        let mut ch_m = [false; 37].to_vec();
+        let mut ch_m = ChannelMap::default();
         for i in 0..37 {
-            ch_m[i] = true;
-            let data = ChannelMapUpdateIndication::new(&ch_m, i as u16).into();
+            ch_m.set(i, true);
+            let data = ChannelMapUpdateIndication::new(ch_m, i as u16).into();
             assert!(DataTypeParseResult::from(&data).is_channel_map_update_indication());
-            ch_m[i] = false;
+            ch_m.set(i, false);
         }
 
         let data: Vec<u8> = Vec::new();
@@ -1717,6 +2796,16 @@ mod tests {
         assert!(!DataTypeParseResult::from(&data).is_complete_local_name());
     }
 
+    #[test]
+    fn test_is_electronic_shelf_label() {
+        let data: Vec<u8> =
+            ElectronicShelfLabel::new(&[1, 2, 3, 4, 5], &[6, 7], &[8, 9, 10, 11]).into();
+        assert!(DataTypeParseResult::from(&data).is_electronic_shelf_label());
+
+        let data: Vec<u8> = Vec::new();
+        assert!(!DataTypeParseResult::from(&data).is_electronic_shelf_label());
+    }
+
     #[test]
     fn test_is_encrypted_data() {
         let randomizer: [u8; 5] = [1, 2, 3, 4, 5];
@@ -1781,6 +2870,15 @@ mod tests {
         assert!(!DataTypeParseResult::from(&data).is_incomplete_list_of_32bit_service_uuids());
     }
 
+    #[test]
+    fn test_is_indoor_positioning() {
+        let data: Vec<u8> = IndoorPositioning::new(None, Some(-4), None, None, None, false).into();
+        assert!(DataTypeParseResult::from(&data).is_indoor_positioning());
+
+        let data: Vec<u8> = Vec::new();
+        assert!(!DataTypeParseResult::from(&data).is_indoor_positioning());
+    }
+
     #[test]
     fn test_is_le_bluetooth_device_address() {
         let le_bluetooth_device_address = 0x0000060504030201u64;
@@ -1802,6 +2900,7 @@ mod tests {
         assert!(!DataTypeParseResult::from(&data).is_le_role());
     }
 
+    #[cfg(feature = "pairing")]
     #[test]
     fn test_is_le_secure_connections_confirmation_value() {
         let le_secure_connections_confirmation_value = 0x0102030405060708090a0b0c0d0e0f10u128;
@@ -1814,6 +2913,7 @@ mod tests {
         assert!(!DataTypeParseResult::from(&data).is_le_secure_connections_confirmation_value());
     }
 
+    #[cfg(feature = "pairing")]
     #[test]
     fn test_is_le_secure_connections_random_value() {
         let le_secure_connections_random_value = 0x0102030405060708090a0b0c0d0e0f10u128;
@@ -1918,6 +3018,45 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "mesh")]
+    #[test]
+    fn test_is_mesh_beacon() {
+        let data = MeshBeacon::new(0x00, &[0x01u8; 18]).into();
+        assert!(DataTypeParseResult::from(&data).is_mesh_beacon());
+
+        let data: Vec<u8> = Vec::new();
+        assert!(!DataTypeParseResult::from(&data).is_mesh_beacon());
+    }
+
+    #[cfg(not(feature = "le-audio"))]
+    #[test]
+    fn test_disabled_le_audio_data_type() {
+        for ad_type in [
+            AdType::BigInfo,
+            AdType::BroadcastCode,
+            AdType::ResolvableSetIdentifier,
+        ] {
+            let data: Vec<u8> = vec![0x02, ad_type.value(), 0x00];
+            assert_eq!(
+                DataTypeParseResult::DataTypeParseError(format!(
+                    "Disabled data type :{}",
+                    ad_type.value()
+                )),
+                DataTypeParseResult::from(&data)
+            );
+        }
+    }
+
+    #[cfg(not(feature = "mesh"))]
+    #[test]
+    fn test_disabled_data_type() {
+        let data: Vec<u8> = vec![0x02, AdType::MeshBeacon.value(), 0x00];
+        assert_eq!(
+            DataTypeParseResult::DataTypeParseError("Disabled data type :43".to_string()),
+            DataTypeParseResult::from(&data)
+        );
+    }
+
     #[test]
     fn test_is_peripheral_connection_interval_range() {
         let minimum_value = 0x0006u16;
@@ -1965,6 +3104,17 @@ mod tests {
         assert!(!DataTypeParseResult::from(&data).is_random_target_address());
     }
 
+    #[cfg(feature = "le-audio")]
+    #[test]
+    fn test_is_resolvable_set_identifier() {
+        let data = ResolvableSetIdentifier::new(0x69f5631948da).into();
+        assert!(DataTypeParseResult::from(&data).is_resolvable_set_identifier());
+
+        let data: Vec<u8> = Vec::new();
+        assert!(!DataTypeParseResult::from(&data).is_resolvable_set_identifier());
+    }
+
+    #[cfg(feature = "pairing")]
     #[test]
     fn test_is_secure_simple_pairing_hash_c192() {
         let secure_simple_pairing_hash_c192 = 0x0102030405060708090a0b0c0d0e0f10u128;
@@ -1975,6 +3125,7 @@ mod tests {
         assert!(!DataTypeParseResult::from(&data).is_secure_simple_pairing_hash_c192());
     }
 
+    #[cfg(feature = "pairing")]
     #[test]
     fn test_is_secure_simple_pairing_hash_c256() {
         let secure_simple_pairing_hash_c256 = 0x0102030405060708090a0b0c0d0e0f10u128;
@@ -1985,6 +3136,7 @@ mod tests {
         assert!(!DataTypeParseResult::from(&data).is_secure_simple_pairing_hash_c256());
     }
 
+    #[cfg(feature = "pairing")]
     #[test]
     fn test_is_secure_simple_pairing_randomizer_r192() {
         let secure_simple_pairing_randomizer_r192 = 0x0102030405060708090a0b0c0d0e0f10u128;
@@ -1996,6 +3148,7 @@ mod tests {
         assert!(!DataTypeParseResult::from(&data).is_secure_simple_pairing_randomizer_r192());
     }
 
+    #[cfg(feature = "pairing")]
     #[test]
     fn test_is_secure_simple_pairing_randomizer_r256() {
         let secure_simple_pairing_randomizer_r256 = 0x0102030405060708090a0b0c0d0e0f10u128;
@@ -2007,6 +3160,7 @@ mod tests {
         assert!(!DataTypeParseResult::from(&data).is_secure_simple_pairing_randomizer_r256());
     }
 
+    #[cfg(feature = "pairing")]
     #[test]
     fn test_is_security_manager_oob() {
         let security_manager_oob = [true, false, false, false, false, false, false, false];
@@ -2017,6 +3171,7 @@ mod tests {
         assert!(!DataTypeParseResult::from(&data).is_security_manager_oob());
     }
 
+    #[cfg(feature = "pairing")]
     #[test]
     fn test_is_security_manager_tk_value() {
         let security_manager_tk_value = 0x0102030405060708090a0b0c0d0e0f10u128;
@@ -2070,6 +3225,25 @@ mod tests {
         assert!(!DataTypeParseResult::from(&data).is_shortened_local_name());
     }
 
+    #[test]
+    fn test_is_three_d_information_data() {
+        let data: Vec<u8> = ThreeDInformationData::new(FLAGS_ASSOCIATION_NOTIFICATION, -70).into();
+        assert!(DataTypeParseResult::from(&data).is_three_d_information_data());
+
+        let data: Vec<u8> = Vec::new();
+        assert!(!DataTypeParseResult::from(&data).is_three_d_information_data());
+    }
+
+    #[test]
+    fn test_is_transport_discovery_data() {
+        let data =
+            TransportDiscoveryData::new(&[TransportBlock::new(0x02, 0x09, &[0x01, 0x02])]).into();
+        assert!(DataTypeParseResult::from(&data).is_transport_discovery_data());
+
+        let data: Vec<u8> = Vec::new();
+        assert!(!DataTypeParseResult::from(&data).is_transport_discovery_data());
+    }
+
     #[test]
     fn test_is_tx_power_level() {
         let tx_power_level = -127;
@@ -2108,6 +3282,19 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_result_from_all_data_types() {
+        for data_type in 0..=u8::MAX {
+            let data: Vec<u8> = vec![1, data_type];
+            let unknown = format!("Unknown data type :{}", data_type);
+            let result = DataTypeParseResult::from(&data);
+            assert_eq!(
+                matches!(AdType::of(data_type), AdType::Unknown(_)),
+                result.error() == Some(&unknown)
+            );
+        }
+    }
+
     #[test]
     fn test_results_from_vec_vec() {
         let mut vec: Vec<Vec<u8>> = Vec::new();
@@ -2146,4 +3333,469 @@ mod tests {
         ));
         assert!(matches!(results.results.get(2), None));
     }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn test_results_from_payload_tracing() {
+        use std::sync::{Arc, Mutex};
+
+        use tracing::{
+            field::{Field, Visit},
+            span, Event, Level, Metadata, Subscriber,
+        };
+
+        #[derive(Default)]
+        struct Recorder(Arc<Mutex<Vec<(Level, String)>>>);
+
+        struct MessageVisitor<'a>(&'a mut String);
+
+        impl Visit for MessageVisitor<'_> {
+            fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+                if field.name() == "message" {
+                    *self.0 = format!("{:?}", value);
+                }
+            }
+        }
+
+        impl Subscriber for Recorder {
+            fn enabled(&self, _: &Metadata<'_>) -> bool {
+                true
+            }
+            fn new_span(&self, _: &span::Attributes<'_>) -> span::Id {
+                span::Id::from_u64(1)
+            }
+            fn record(&self, _: &span::Id, _: &span::Record<'_>) {}
+            fn record_follows_from(&self, _: &span::Id, _: &span::Id) {}
+            fn event(&self, event: &Event<'_>) {
+                let mut message = String::new();
+                event.record(&mut MessageVisitor(&mut message));
+                self.0
+                    .lock()
+                    .unwrap()
+                    .push((*event.metadata().level(), message));
+            }
+            fn enter(&self, _: &span::Id) {}
+            fn exit(&self, _: &span::Id) {}
+        }
+
+        let mut data: Vec<u8> = AdvertisingInterval::new(0x01).into();
+        data.append(&mut vec![0x01, 0xfe]);
+        data.append(&mut vec![0x03, 0x0a, 0x00]);
+        let recorder = Recorder::default();
+        let events = recorder.0.clone();
+        tracing::subscriber::with_default(recorder, || {
+            let _ = DataTypeParseResults::from(&data);
+            let _ = DataTypeParseResults::from(&vec![0x00]);
+        });
+        assert_eq!(
+            vec![
+                (Level::DEBUG, "data type parsed".to_string()),
+                (Level::WARN, "data type parse error".to_string()),
+                (Level::WARN, "truncated data type".to_string()),
+                (Level::TRACE, "early termination".to_string()),
+            ],
+            *events.lock().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_results_from_payload() {
+        let mut data: Vec<u8> = AdvertisingInterval::new(0x01).into();
+        data.append(&mut vec![0x00, 0x00, 0x00]);
+        let results = DataTypeParseResults::from(&data);
+        assert_eq!(1, results.results.len());
+        assert!(matches!(
+            results.results.first(),
+            Some(DataTypeParseResult::AdvertisingIntervalResult(Ok(_)))
+        ));
+        assert_eq!(vec![0], results.offsets);
+
+        let mut data: Vec<u8> = AdvertisingInterval::new(0x01).into();
+        data.append(&mut vec![0x05, 0x09, 0x6e]);
+        let results = DataTypeParseResults::from(&data);
+        assert_eq!(2, results.results.len());
+        assert_eq!(
+            Some(&"Invalid data size :3".to_string()),
+            results.results[1].error()
+        );
+        assert_eq!(vec![0, 4], results.offsets);
+
+        let data: Vec<u8> = Vec::new();
+        let results = DataTypeParseResults::from(&data);
+        assert!(results.results.is_empty());
+        assert!(results.offsets.is_empty());
+    }
+
+    #[test]
+    fn test_parser() {
+        let mut parser = Parser::new(ParseOptions::new(Utf8DecodeMode::Strict));
+        let mut data: Vec<u8> = AdvertisingInterval::new(0x01).into();
+        data.append(&mut vec![
+            5,
+            CompleteLocalName::data_type(),
+            0x6e,
+            0x61,
+            0xff,
+            0x65,
+        ]);
+        let results = parser.parse(&data);
+        assert_eq!(2, results.results.len());
+        assert_eq!(vec![0, 4], results.offsets);
+        assert!(results.results[1].error().is_some());
+
+        let capacity = parser.results().results.capacity();
+        let data: Vec<u8> = AdvertisingInterval::new(0x02).into();
+        let results = parser.parse(&data);
+        assert_eq!(1, results.results.len());
+        assert_eq!(vec![0], results.offsets);
+        assert_eq!(capacity, parser.results().results.capacity());
+
+        parser.options = ParseOptions::new(Utf8DecodeMode::Lossy);
+        let data: Vec<u8> = vec![5, CompleteLocalName::data_type(), 0x6e, 0x61, 0xff, 0x65];
+        let results = parser.parse(&data);
+        assert_eq!(Some("na\u{fffd}e"), results.device_name());
+
+        parser.clear();
+        assert!(parser.results().results.is_empty());
+        assert!(parser.results().offsets.is_empty());
+    }
+
+    #[test]
+    fn test_result_from_with_options() {
+        let data: Vec<u8> = vec![5, CompleteLocalName::data_type(), 0x6e, 0x61, 0xff, 0x65];
+        assert!(matches!(
+            DataTypeParseResult::from_with_options(
+                &data,
+                &ParseOptions::new(Utf8DecodeMode::Lossy)
+            ),
+            DataTypeParseResult::CompleteLocalNameResult(Ok(_))
+        ));
+        assert!(matches!(
+            DataTypeParseResult::from_with_options(
+                &data,
+                &ParseOptions::new(Utf8DecodeMode::Strict)
+            ),
+            DataTypeParseResult::CompleteLocalNameResult(Err(_))
+        ));
+
+        let data: Vec<u8> = vec![5, ShortenedLocalName::data_type(), 0x6e, 0x61, 0xff, 0x65];
+        assert!(matches!(
+            DataTypeParseResult::from_with_options(
+                &data,
+                &ParseOptions::new(Utf8DecodeMode::Lossy)
+            ),
+            DataTypeParseResult::ShortenedLocalNameResult(Ok(_))
+        ));
+        assert!(matches!(
+            DataTypeParseResult::from_with_options(
+                &data,
+                &ParseOptions::new(Utf8DecodeMode::Strict)
+            ),
+            DataTypeParseResult::ShortenedLocalNameResult(Err(_))
+        ));
+    }
+
+    #[test]
+    fn test_results_from_with_options() {
+        let data: Vec<u8> = vec![
+            2,
+            TxPowerLevel::data_type(),
+            0x00,
+            5,
+            CompleteLocalName::data_type(),
+            0x6e,
+            0x61,
+            0xff,
+            0x65,
+        ];
+        let vec: Vec<Vec<u8>> = vec![data[..3].to_vec(), data[3..].to_vec()];
+
+        let results = DataTypeParseResults::from_with_options(
+            &vec,
+            &ParseOptions::new(Utf8DecodeMode::Lossy),
+        );
+        assert_eq!(Some("na\u{fffd}e"), results.device_name());
+        assert_eq!(vec![0, 3], results.offsets);
+
+        let results = DataTypeParseResults::from_with_options(
+            &vec,
+            &ParseOptions::new(Utf8DecodeMode::Strict),
+        );
+        assert_eq!(None, results.device_name());
+        assert_eq!(1, results.errors().count());
+
+        let results = DataTypeParseResults::from_payload_with_options(
+            &data,
+            &ParseOptions::new(Utf8DecodeMode::Lossy),
+        );
+        assert_eq!(Some("na\u{fffd}e"), results.device_name());
+
+        let results = DataTypeParseResults::from_payload_with_options(
+            &data,
+            &ParseOptions::new(Utf8DecodeMode::Strict),
+        );
+        assert_eq!(None, results.device_name());
+        assert_eq!(
+            vec![(
+                1,
+                Some(3),
+                &"Invalid UTF-8 :invalid utf-8 sequence of 1 bytes from index 2".to_string()
+            )],
+            results.errors().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_service_uuids() {
+        let uuid16 = uuid!("00001234-0000-1000-8000-00805F9B34FB");
+        let uuid32 = uuid!("12345678-0000-1000-8000-00805F9B34FB");
+        let uuid128 = uuid!("00000001-a087-4fa3-add4-3b8a7d5d4921");
+
+        let vec: Vec<Vec<u8>> = Vec::new();
+        let results = DataTypeParseResults::from(&vec);
+        let service_uuids = results.service_uuids();
+        assert!(service_uuids.uuids.is_empty());
+        assert!(!service_uuids.is_complete);
+
+        let vec: Vec<Vec<u8>> = vec![
+            CompleteListOf16BitServiceUuids::new(&[uuid16, uuid16].to_vec()).into(),
+            CompleteListOf32BitServiceUuids::new(&[uuid32].to_vec()).into(),
+            CompleteListOf128BitServiceUuids::new(&[uuid128].to_vec()).into(),
+        ];
+        let results = DataTypeParseResults::from(&vec);
+        let service_uuids = results.service_uuids();
+        assert_eq!([uuid16, uuid32, uuid128].to_vec(), service_uuids.uuids);
+        assert!(service_uuids.is_complete);
+
+        let vec: Vec<Vec<u8>> = vec![
+            IncompleteListOf16BitServiceUuids::new(&[uuid16].to_vec()).into(),
+            IncompleteListOf32BitServiceUuids::new(&[uuid32].to_vec()).into(),
+            IncompleteListOf128BitServiceUuids::new(&[uuid128, uuid16].to_vec()).into(),
+        ];
+        let results = DataTypeParseResults::from(&vec);
+        let service_uuids = results.service_uuids();
+        assert_eq!([uuid16, uuid32, uuid128].to_vec(), service_uuids.uuids);
+        assert!(!service_uuids.is_complete);
+    }
+
+    #[test]
+    fn test_has_service() {
+        let uuid16 = uuid!("0000180f-0000-1000-8000-00805F9B34FB");
+        let uuid32 = uuid!("12345678-0000-1000-8000-00805F9B34FB");
+        let uuid128 = uuid!("00000001-a087-4fa3-add4-3b8a7d5d4921");
+
+        let vec: Vec<Vec<u8>> = vec![
+            CompleteListOf16BitServiceUuids::new(&[uuid16].to_vec()).into(),
+            ServiceData32BitUUID::new(&uuid32, &[0x01u8].to_vec()).into(),
+        ];
+        let results = DataTypeParseResults::from(&vec);
+        assert!(results.has_service(uuid16));
+        assert!(results.has_service(ShortUuid::Uuid16(0x180f)));
+        assert!(results.has_service(uuid32));
+        assert!(results.has_service(ShortUuid::Uuid32(0x12345678)));
+        assert!(!results.has_service(uuid128));
+        assert!(!results.has_service(ShortUuid::Uuid16(0x1234)));
+
+        let vec: Vec<Vec<u8>> = vec![
+            IncompleteListOf128BitServiceUuids::new(&[uuid128].to_vec()).into(),
+            ServiceData16BitUUID::new(&uuid16, &[0x01u8].to_vec()).into(),
+        ];
+        let results = DataTypeParseResults::from(&vec);
+        assert!(results.has_service(uuid128));
+        assert!(results.has_service(ShortUuid::Uuid16(0x180f)));
+        assert!(!results.has_service(uuid32));
+    }
+
+    #[test]
+    fn test_service_data() {
+        let uuid16 = uuid!("0000180f-0000-1000-8000-00805F9B34FB");
+        let uuid32 = uuid!("12345678-0000-1000-8000-00805F9B34FB");
+        let uuid128 = uuid!("00000001-a087-4fa3-add4-3b8a7d5d4921");
+
+        let vec: Vec<Vec<u8>> = vec![
+            ServiceData16BitUUID::new(&uuid16, &[0x01u8].to_vec()).into(),
+            ServiceData32BitUUID::new(&uuid32, &[0x02u8, 0x03u8].to_vec()).into(),
+            ServiceData128BitUUID::new(&uuid128, &Vec::new()).into(),
+        ];
+        let results = DataTypeParseResults::from(&vec);
+        assert_eq!(Some([0x01u8].as_slice()), results.service_data(uuid16));
+        assert_eq!(
+            Some([0x01u8].as_slice()),
+            results.service_data(ShortUuid::Uuid16(0x180f))
+        );
+        assert_eq!(
+            Some([0x02u8, 0x03u8].as_slice()),
+            results.service_data(ShortUuid::Uuid32(0x12345678))
+        );
+        assert_eq!(Some([].as_slice()), results.service_data(uuid128));
+        assert_eq!(None, results.service_data(ShortUuid::Uuid16(0x1234)));
+    }
+
+    #[test]
+    fn test_manufacturer_data() {
+        let vec: Vec<Vec<u8>> = vec![
+            ManufacturerSpecificData::new(0x004c, &[0x01u8].to_vec()).into(),
+            ManufacturerSpecificData::new(0x0006, &[0x02u8, 0x03u8].to_vec()).into(),
+            ManufacturerSpecificData::new(0x004c, &[0x04u8].to_vec()).into(),
+        ];
+        let results = DataTypeParseResults::from(&vec);
+        assert_eq!(Some([0x01u8].as_slice()), results.manufacturer_data(0x004c));
+        assert_eq!(
+            Some([0x02u8, 0x03u8].as_slice()),
+            results.manufacturer_data(0x0006)
+        );
+        assert_eq!(None, results.manufacturer_data(0x0ca8));
+    }
+
+    #[test]
+    fn test_battery_level() {
+        let vec: Vec<Vec<u8>> = vec![
+            ServiceData16BitUUID::new(&uuid_from_u16(0x180a), &[0x32u8].to_vec()).into(),
+            ServiceData16BitUUID::new(&uuid_from_u16(0x180f), &[0x64u8].to_vec()).into(),
+        ];
+        let results = DataTypeParseResults::from(&vec);
+        assert_eq!(Some(100), results.battery_level());
+
+        let vec: Vec<Vec<u8>> =
+            vec![ServiceData16BitUUID::new(&uuid_from_u16(0x180f), &[0x65u8].to_vec()).into()];
+        let results = DataTypeParseResults::from(&vec);
+        assert_eq!(None, results.battery_level());
+    }
+
+    #[test]
+    fn test_sensor_data() {
+        let vec: Vec<Vec<u8>> = vec![
+            ServiceData16BitUUID::new(&uuid_from_u16(0x2a6e), &[0x00u8, 0x80u8].to_vec()).into(),
+            ServiceData16BitUUID::new(&uuid_from_u16(0x2a76), &[0x05u8].to_vec()).into(),
+            ServiceData16BitUUID::new(&uuid_from_u16(0x2a6d), &[0x01u8].to_vec()).into(),
+        ];
+        let results = DataTypeParseResults::from(&vec);
+        assert_eq!(vec![SensorData::UvIndex(5.0)], results.sensor_data());
+
+        let vec: Vec<Vec<u8>> = Vec::new();
+        let results = DataTypeParseResults::from(&vec);
+        assert!(results.sensor_data().is_empty());
+    }
+
+    #[cfg(feature = "vendor")]
+    #[test]
+    fn test_urls() {
+        let vec: Vec<Vec<u8>> = vec![
+            UniformResourceIdentifier::from_uri("HTTPS://WWW.Example.COM?Q=1")
+                .unwrap()
+                .into(),
+            UniformResourceIdentifier::from_uri("x-custom:Value")
+                .unwrap()
+                .into(),
+            UniformResourceIdentifier::new(&"\u{00ff}value".to_string()).into(),
+            EddystoneUrl::new(0, "https://www.example.com?Q=1")
+                .try_into()
+                .map(|data: ServiceData16BitUUID| data.into())
+                .unwrap(),
+            EddystoneUid::new(0, [0x00; 10], [0x00; 6]).into(),
+            ServiceData16BitUUID::new(&uuid_from_u16(0xfeaa), &[0x10u8].to_vec()).into(),
+        ];
+        let results = DataTypeParseResults::from(&vec);
+        assert_eq!(
+            vec![
+                "https://www.example.com?Q=1".to_string(),
+                "x-custom:Value".to_string()
+            ],
+            results.urls()
+        );
+
+        let vec: Vec<Vec<u8>> = Vec::new();
+        let results = DataTypeParseResults::from(&vec);
+        assert!(results.urls().is_empty());
+    }
+
+    #[test]
+    fn test_device_name() {
+        let vec: Vec<Vec<u8>> = vec![ShortenedLocalName::new(&"short".to_string()).into()];
+        let results = DataTypeParseResults::from(&vec);
+        assert_eq!(Some("short"), results.device_name());
+
+        let vec: Vec<Vec<u8>> = vec![
+            ShortenedLocalName::new(&"short".to_string()).into(),
+            CompleteLocalName::new(&"complete".to_string()).into(),
+        ];
+        let results = DataTypeParseResults::from(&vec);
+        assert_eq!(Some("complete"), results.device_name());
+
+        let vec: Vec<Vec<u8>> = Vec::new();
+        let results = DataTypeParseResults::from(&vec);
+        assert_eq!(None, results.device_name());
+    }
+
+    #[test]
+    fn test_from_with_options_slice() {
+        let structures: Vec<Vec<u8>> = vec![
+            AdvertisingInterval::new(0x01).into(),
+            CompleteLocalName::new(&"name".to_string()).into(),
+            Flags::new(&vec![true]).into(),
+            ManufacturerSpecificData::new(0x0ca8, &vec![0x01, 0x02]).into(),
+            TxPowerLevel::new(-1).into(),
+            vec![0x01, 0x1a],
+        ];
+        for data in structures {
+            let payload = [&[0xff][..], &data, &[0xff]].concat();
+            assert_eq!(
+                DataTypeParseResult::from(&data),
+                DataTypeParseResult::from_with_options(
+                    &payload[1..1 + data.len()],
+                    &ParseOptions::default()
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_error() {
+        let data: Vec<u8> = AdvertisingInterval::new(0x01).into();
+        assert_eq!(None, DataTypeParseResult::from(&data).error());
+
+        let data: Vec<u8> = vec![0x01, 0x1a];
+        assert!(DataTypeParseResult::from(&data).error().is_some());
+
+        let data: Vec<u8> = vec![0x01, 0x00];
+        assert!(DataTypeParseResult::from(&data).error().is_some());
+
+        let data: Vec<u8> = Vec::new();
+        assert!(DataTypeParseResult::from(&data).error().is_some());
+
+        let data: Vec<u8> = vec![0x03, 0x15, 0x01, 0x02];
+        assert!(DataTypeParseResult::from(&data).error().is_some());
+
+        let data: Vec<u8> = vec![0x03, 0x32, 0x01, 0x02];
+        assert!(DataTypeParseResult::from(&data).error().is_some());
+    }
+
+    #[cfg(feature = "pairing")]
+    #[test]
+    fn test_error_pairing() {
+        let data: Vec<u8> = vec![0x03, 0x22, 0x01, 0x02];
+        assert!(DataTypeParseResult::from(&data).error().is_some());
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut data: Vec<u8> = AdvertisingInterval::new(0x01).into();
+        data.append(&mut vec![0x01, 0x1a]);
+        data.append(&mut vec![0x01, 0x00]);
+        let results = DataTypeParseResults::from(&data);
+        let errors: Vec<(usize, Option<usize>, &String)> = results.errors().collect();
+        assert_eq!(2, errors.len());
+        assert_eq!((1, Some(4)), (errors[0].0, errors[0].1));
+        assert_eq!((2, Some(6)), (errors[1].0, errors[1].1));
+
+        let data: Vec<u8> = vec![0x03, 0x15, 0x01, 0x02, 0x03, 0x32, 0x01, 0x02];
+        let results = DataTypeParseResults::from(&data);
+        assert_eq!(2, results.errors().count());
+
+        let results = DataTypeParseResults::new(vec![DataTypeParseResult::DataTypeParseError(
+            "error".to_string(),
+        )]);
+        let errors: Vec<(usize, Option<usize>, &String)> = results.errors().collect();
+        assert_eq!(vec![(0, None, &"error".to_string())], errors);
+    }
 }
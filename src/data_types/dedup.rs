@@ -0,0 +1,85 @@
+//! Advertising payload deduplication key module.
+
+use crate::data_types::diff::ad_structures;
+
+/// FNV-1a 64-bit offset basis.
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+
+/// FNV-1a 64-bit prime.
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+fn fnv1a(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(FNV_PRIME)
+    })
+}
+
+/// Create stable deduplication key of advertising payload.
+///
+/// AD structures are sorted before hashing, as their order has no meaning, and padding after
+/// early termination is ignored. Same normalized contents always give the same key across
+/// platforms and crate versions (FNV-1a 64-bit).
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::data_types::dedup::dedup_key;
+///
+/// let flags = [0x02, 0x01, 0x06];
+/// let tx_power = [0x02, 0x0a, 0x00];
+/// let key = dedup_key(&[flags, tx_power].concat());
+/// assert_eq!(key, dedup_key(&[tx_power, flags].concat()));
+/// assert_eq!(key, dedup_key(&[&flags[..], &tx_power[..], &[0x00, 0x00]].concat()));
+/// assert_ne!(key, dedup_key(&flags));
+/// ```
+pub fn dedup_key(payload: &[u8]) -> u64 {
+    let mut structures = ad_structures(payload);
+    let consumed: usize = structures.iter().map(|structure| structure.len()).sum();
+    structures.sort_unstable();
+    let mut hash = structures
+        .iter()
+        .fold(FNV_OFFSET_BASIS, |hash, structure| fnv1a(hash, structure));
+    // truncated data after well-formed AD structures
+    let tail = &payload[consumed..];
+    if tail.first().is_some_and(|length| *length != 0) {
+        hash = fnv1a(hash, tail);
+    }
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data_types::dedup::{dedup_key, fnv1a, FNV_OFFSET_BASIS};
+
+    #[test]
+    fn test_fnv1a() {
+        assert_eq!(0xcbf29ce484222325, fnv1a(FNV_OFFSET_BASIS, b""));
+        assert_eq!(0xaf63dc4c8601ec8c, fnv1a(FNV_OFFSET_BASIS, b"a"));
+        assert_eq!(0x85944171f73967e8, fnv1a(FNV_OFFSET_BASIS, b"foobar"));
+    }
+
+    #[test]
+    fn test_dedup_key() {
+        assert_eq!(FNV_OFFSET_BASIS, dedup_key(&[]));
+        assert_eq!(FNV_OFFSET_BASIS, dedup_key(&[0x00, 0x02, 0x01, 0x06]));
+        assert_eq!(
+            fnv1a(FNV_OFFSET_BASIS, &[0x02, 0x01, 0x06]),
+            dedup_key(&[0x02, 0x01, 0x06])
+        );
+
+        // length byte keeps boundaries
+        assert_ne!(
+            dedup_key(&[0x03, 0xff, 0x4c, 0x00, 0x01, 0x01]),
+            dedup_key(&[0x02, 0xff, 0x4c, 0x02, 0x00, 0x01])
+        );
+
+        // truncated data is part of the key
+        let data = [0x02, 0x01, 0x06, 0x03, 0x09, 0x61];
+        assert_ne!(dedup_key(&data[..3]), dedup_key(&data));
+        assert_ne!(dedup_key(&data[..5]), dedup_key(&data));
+        assert_eq!(
+            fnv1a(fnv1a(FNV_OFFSET_BASIS, &data[..3]), &data[3..]),
+            dedup_key(&data)
+        );
+    }
+}
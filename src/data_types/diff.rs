@@ -0,0 +1,192 @@
+//! Advertising payload diff module.
+
+use crate::data_types::codes::{
+    MANUFACTURER_SPECIFIC_DATA, SERVICE_DATA_128BIT_UUID, SERVICE_DATA_16BIT_UUID,
+    SERVICE_DATA_32BIT_UUID,
+};
+
+/// Differences of AD structures between two payloads.
+///
+/// Each AD structure is whole bytes including length and data type.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct AdvertisementDiff {
+    /// AD structures only in new payload
+    pub added: Vec<Vec<u8>>,
+
+    /// AD structures only in old payload
+    pub removed: Vec<Vec<u8>>,
+
+    /// AD structures with same key and different contents (old, new)
+    pub changed: Vec<(Vec<u8>, Vec<u8>)>,
+}
+
+impl AdvertisementDiff {
+    /// check no differences.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::diff::{diff, AdvertisementDiff};
+    ///
+    /// assert!(AdvertisementDiff::default().is_empty());
+    /// assert!(diff(&[0x02, 0x01, 0x06], &[0x02, 0x01, 0x06]).is_empty());
+    /// assert!(!diff(&[0x02, 0x01, 0x06], &[0x02, 0x01, 0x04]).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Split payload into well-formed AD structures (including length).
+///
+/// Stops at early termination (zero length) or a truncated AD structure.
+pub(crate) fn ad_structures(value: &[u8]) -> Vec<&[u8]> {
+    let mut structures = Vec::new();
+    let mut offset = 0;
+    while offset < value.len() {
+        let length = value[offset] as usize;
+        if length == 0 || offset + 1 + length > value.len() {
+            break;
+        }
+        structures.push(&value[offset..offset + 1 + length]);
+        offset += 1 + length;
+    }
+    structures
+}
+
+/// Get matching key of AD structure (data type and data identifying the structure).
+///
+/// Service Data is keyed by UUID and Manufacturer Specific Data by Company Identifier, other data
+/// types by data type only.
+pub(crate) fn structure_key(structure: &[u8]) -> &[u8] {
+    let key_len = match structure[1] {
+        SERVICE_DATA_16BIT_UUID | MANUFACTURER_SPECIFIC_DATA => 2,
+        SERVICE_DATA_32BIT_UUID => 4,
+        SERVICE_DATA_128BIT_UUID => 16,
+        _ => 0,
+    };
+    &structure[1..structure.len().min(2 + key_len)]
+}
+
+/// Compare AD structures of two payloads from the same device.
+///
+/// AD structures are matched by data type, and additionally by UUID for Service Data and by
+/// Company Identifier for Manufacturer Specific Data. Repeated keys are matched in order, and
+/// the order of AD structures is ignored.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::{
+///     data_types::{
+///         complete_local_name::CompleteLocalName, diff::diff,
+///         manufacturer_specific_data::ManufacturerSpecificData,
+///         service_data_16bit_uuid::ServiceData16BitUUID, tx_power_level::TxPowerLevel,
+///     },
+///     uuid_from_u16,
+/// };
+///
+/// let name: Vec<u8> = CompleteLocalName::new(&"name".to_string()).into();
+/// let old_data: Vec<u8> = ManufacturerSpecificData::new(0x004c, &vec![0x01]).into();
+/// let new_data: Vec<u8> = ManufacturerSpecificData::new(0x004c, &vec![0x02]).into();
+/// let tx_power: Vec<u8> = TxPowerLevel::new(0).into();
+/// let service_data: Vec<u8> = ServiceData16BitUUID::new(&uuid_from_u16(0x180f), &vec![0x64]).into();
+///
+/// let old = [name.clone(), old_data.clone(), tx_power.clone()].concat();
+/// let new = [service_data.clone(), new_data.clone(), name].concat();
+/// let result = diff(&old, &new);
+/// assert_eq!(vec![service_data], result.added);
+/// assert_eq!(vec![tx_power], result.removed);
+/// assert_eq!(vec![(old_data, new_data)], result.changed);
+/// ```
+pub fn diff(old: &[u8], new: &[u8]) -> AdvertisementDiff {
+    let mut result = AdvertisementDiff::default();
+    let mut unmatched: Vec<Option<&[u8]>> = ad_structures(new).into_iter().map(Some).collect();
+    for old_structure in ad_structures(old) {
+        let key = structure_key(old_structure);
+        let matched = unmatched.iter_mut().find(|new_structure| {
+            new_structure.is_some_and(|new_structure| structure_key(new_structure) == key)
+        });
+        match matched.and_then(|new_structure| new_structure.take()) {
+            Some(new_structure) if new_structure == old_structure => {}
+            Some(new_structure) => result
+                .changed
+                .push((old_structure.to_vec(), new_structure.to_vec())),
+            None => result.removed.push(old_structure.to_vec()),
+        }
+    }
+    result.added = unmatched
+        .into_iter()
+        .flatten()
+        .map(|structure| structure.to_vec())
+        .collect();
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data_types::{
+            diff::{ad_structures, diff, structure_key, AdvertisementDiff},
+            service_data_128bit_uuid::ServiceData128BitUUID,
+            service_data_32bit_uuid::ServiceData32BitUUID,
+        },
+        uuid_from_u32,
+    };
+    use uuid::uuid;
+
+    #[test]
+    fn test_ad_structures() {
+        let data: Vec<u8> = vec![0x02, 0x01, 0x06, 0x01, 0x09, 0x00, 0x02, 0x0a];
+        assert_eq!(vec![&data[0..3], &data[3..5]], ad_structures(&data));
+        assert_eq!(vec![&data[0..3]], ad_structures(&data[..4]));
+        assert!(ad_structures(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_structure_key() {
+        assert_eq!(&[0x01], structure_key(&[0x02, 0x01, 0x06]));
+        assert_eq!(
+            &[0x16, 0x0f, 0x18],
+            structure_key(&[0x04, 0x16, 0x0f, 0x18, 0x64])
+        );
+        assert_eq!(&[0xff, 0x4c], structure_key(&[0x02, 0xff, 0x4c]));
+        assert_eq!(
+            &[0x20, 0x01, 0x02, 0x03, 0x04],
+            structure_key(&[0x06, 0x20, 0x01, 0x02, 0x03, 0x04, 0x05])
+        );
+        let data: Vec<u8> =
+            ServiceData128BitUUID::new(&uuid!("12345678-90ab-cdef-1234-567890abcdef"), &vec![])
+                .into();
+        assert_eq!(&data[1..], structure_key(&data));
+    }
+
+    #[test]
+    fn test_diff() {
+        assert_eq!(AdvertisementDiff::default(), diff(&[], &[]));
+
+        // order ignored
+        let data = [0x02, 0x01, 0x06, 0x02, 0x0a, 0x00];
+        let reordered = [0x02, 0x0a, 0x00, 0x02, 0x01, 0x06];
+        assert!(diff(&data, &reordered).is_empty());
+
+        // repeated keys matched in order
+        let first: Vec<u8> = ServiceData32BitUUID::new(&uuid_from_u32(0x12345678), &vec![1]).into();
+        let second: Vec<u8> =
+            ServiceData32BitUUID::new(&uuid_from_u32(0x12345678), &vec![2]).into();
+        let third: Vec<u8> = ServiceData32BitUUID::new(&uuid_from_u32(0x12345678), &vec![3]).into();
+        let result = diff(
+            &[first.clone(), second.clone()].concat(),
+            &[first.clone(), third.clone(), second.clone()].concat(),
+        );
+        assert_eq!(vec![second.clone()], result.added);
+        assert!(result.removed.is_empty());
+        assert_eq!(vec![(second.clone(), third.clone())], result.changed);
+
+        // everything replaced
+        let result = diff(&data, &[]);
+        assert_eq!(vec![data[..3].to_vec(), data[3..].to_vec()], result.removed);
+        let result = diff(&[], &data);
+        assert_eq!(vec![data[..3].to_vec(), data[3..].to_vec()], result.added);
+    }
+}
@@ -0,0 +1,636 @@
+//! Electronic Shelf Label (Data Type Value: 0x34) module.
+
+use crate::data_types::data_type::DataType;
+
+/// ESL ID for all ESLs in a group.
+pub const ESL_ID_BROADCAST: u8 = 0xff;
+
+/// Group ID max value (7 bits).
+pub const GROUP_ID_MAX: u8 = 0x7f;
+
+/// ESL Address.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct EslAddress {
+    /// Group ID (7 bits)
+    pub group_id: u8,
+
+    /// ESL ID
+    pub esl_id: u8,
+}
+
+impl EslAddress {
+    /// Create [`EslAddress`] from Parameters with Group ID range check.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::electronic_shelf_label::EslAddress;
+    ///
+    /// let result = EslAddress::try_new(0x01, 0x02);
+    /// assert_eq!(Ok(EslAddress { group_id: 0x01, esl_id: 0x02 }), result);
+    ///
+    /// let result = EslAddress::try_new(0x80, 0x02);
+    /// assert_eq!(Err("Invalid group id :128".to_string()), result);
+    /// ```
+    pub fn try_new(group_id: u8, esl_id: u8) -> Result<Self, String> {
+        if group_id > GROUP_ID_MAX {
+            return Err(format!("Invalid group id :{}", group_id).to_string());
+        }
+        Ok(Self { group_id, esl_id })
+    }
+
+    /// check broadcast ESL ID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::electronic_shelf_label::EslAddress;
+    ///
+    /// assert!(EslAddress::try_new(0x01, 0xff).unwrap().is_broadcast());
+    /// assert!(!EslAddress::try_new(0x01, 0x02).unwrap().is_broadcast());
+    /// ```
+    pub fn is_broadcast(&self) -> bool {
+        self.esl_id == ESL_ID_BROADCAST
+    }
+}
+
+impl TryFrom<u16> for EslAddress {
+    type Error = String;
+    /// Create [`EslAddress`] from 16-bit ESL Address.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::electronic_shelf_label::EslAddress;
+    ///
+    /// let result = EslAddress::try_from(0x0102u16);
+    /// assert_eq!(Ok(EslAddress { group_id: 0x01, esl_id: 0x02 }), result);
+    ///
+    /// let result = EslAddress::try_from(0x8102u16);
+    /// assert_eq!(Err("Invalid ESL address :0x8102".to_string()), result);
+    /// ```
+    fn try_from(value: u16) -> Result<Self, String> {
+        if value & 0x8000 != 0 {
+            return Err(format!("Invalid ESL address :{:#06x}", value).to_string());
+        }
+        Ok(Self {
+            group_id: (value >> 8) as u8,
+            esl_id: value as u8,
+        })
+    }
+}
+
+impl From<EslAddress> for u16 {
+    /// Create 16-bit ESL Address from [`EslAddress`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::electronic_shelf_label::EslAddress;
+    ///
+    /// let address = EslAddress::try_new(0x01, 0x02).unwrap();
+    /// assert_eq!(0x0102, u16::from(address));
+    /// ```
+    fn from(value: EslAddress) -> Self {
+        ((value.group_id as u16 & GROUP_ID_MAX as u16) << 8) | value.esl_id as u16
+    }
+}
+
+/// ESL Command.
+#[derive(Debug, PartialEq, Clone)]
+pub struct EslCommand {
+    /// Opcode
+    pub opcode: u8,
+
+    /// ESL ID
+    pub esl_id: u8,
+
+    /// Parameters after ESL ID
+    pub parameters: Vec<u8>,
+}
+
+impl EslCommand {
+    /// Create [`EslCommand`] from Parameters with parameter length check.
+    ///
+    /// The parameter length including ESL ID is `(opcode >> 4) + 1` octets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::electronic_shelf_label::EslCommand;
+    ///
+    /// let result = EslCommand::try_new(0x20, 0x02, &[0x00, 0x01]);
+    /// assert!(result.is_ok());
+    ///
+    /// let result = EslCommand::try_new(0x20, 0x02, &[0x00]);
+    /// assert_eq!(Err("Invalid ESL command parameter size :1".to_string()), result);
+    /// ```
+    pub fn try_new(opcode: u8, esl_id: u8, parameters: &[u8]) -> Result<Self, String> {
+        if parameters.len() != Self::parameter_size(opcode) {
+            return Err(
+                format!("Invalid ESL command parameter size :{}", parameters.len()).to_string(),
+            );
+        }
+        Ok(Self {
+            opcode,
+            esl_id,
+            parameters: parameters.to_vec(),
+        })
+    }
+
+    /// parameter size after ESL ID.
+    fn parameter_size(opcode: u8) -> usize {
+        (opcode >> 4) as usize
+    }
+}
+
+/// ESL Payload (decrypted Electronic Shelf Label payload).
+#[derive(Debug, PartialEq, Clone)]
+pub struct EslPayload {
+    /// Group ID
+    pub group_id: u8,
+
+    /// Commands
+    pub commands: Vec<EslCommand>,
+}
+
+impl EslPayload {
+    /// Create [`EslPayload`] from Parameters with Group ID range check.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::electronic_shelf_label::{EslCommand, EslPayload};
+    ///
+    /// let commands = vec![EslCommand::try_new(0x00, 0x02, &[]).unwrap()];
+    /// let result = EslPayload::try_new(0x01, &commands);
+    /// assert!(result.is_ok());
+    ///
+    /// let result = EslPayload::try_new(0x80, &commands);
+    /// assert_eq!(Err("Invalid group id :128".to_string()), result);
+    /// ```
+    pub fn try_new(group_id: u8, commands: &[EslCommand]) -> Result<Self, String> {
+        if group_id > GROUP_ID_MAX {
+            return Err(format!("Invalid group id :{}", group_id).to_string());
+        }
+        Ok(Self {
+            group_id,
+            commands: commands.to_vec(),
+        })
+    }
+
+    /// Get ESL Addresses of commands.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::electronic_shelf_label::{EslAddress, EslCommand, EslPayload};
+    ///
+    /// let commands = vec![
+    ///     EslCommand::try_new(0x00, 0x02, &[]).unwrap(),
+    ///     EslCommand::try_new(0x00, 0xff, &[]).unwrap(),
+    /// ];
+    /// let result = EslPayload::try_new(0x01, &commands).unwrap();
+    /// assert_eq!(
+    ///     vec![
+    ///         EslAddress::try_new(0x01, 0x02).unwrap(),
+    ///         EslAddress::try_new(0x01, 0xff).unwrap()
+    ///     ],
+    ///     result.addresses()
+    /// );
+    /// ```
+    pub fn addresses(&self) -> Vec<EslAddress> {
+        self.commands
+            .iter()
+            .map(|f| EslAddress {
+                group_id: self.group_id,
+                esl_id: f.esl_id,
+            })
+            .collect()
+    }
+}
+
+impl TryFrom<&[u8]> for EslPayload {
+    type Error = String;
+    /// Create [`EslPayload`] from decrypted payload.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::electronic_shelf_label::{EslCommand, EslPayload};
+    ///
+    /// let data: Vec<u8> = vec![0x01, 0x00, 0x02, 0x20, 0x03, 0x00, 0x01];
+    /// let result = EslPayload::try_from(data.as_slice());
+    /// assert!(result.is_ok());
+    /// let payload = result.unwrap();
+    /// assert_eq!(0x01, payload.group_id);
+    /// assert_eq!(
+    ///     vec![
+    ///         EslCommand::try_new(0x00, 0x02, &[]).unwrap(),
+    ///         EslCommand::try_new(0x20, 0x03, &[0x00, 0x01]).unwrap()
+    ///     ],
+    ///     payload.commands
+    /// );
+    ///
+    /// let data: Vec<u8> = vec![0x01, 0x20, 0x03];
+    /// let result = EslPayload::try_from(data.as_slice());
+    /// assert_eq!(Err("Invalid data size :3".to_string()), result);
+    /// ```
+    fn try_from(value: &[u8]) -> Result<Self, String> {
+        let len = value.len();
+        if len < 1 {
+            return Err(format!("Invalid data size :{}", len).to_string());
+        }
+        let group_id = value[0];
+        if group_id > GROUP_ID_MAX {
+            return Err(format!("Invalid group id :{}", group_id).to_string());
+        }
+        let mut commands: Vec<EslCommand> = Vec::new();
+        let mut index = 1;
+        while index < len {
+            let opcode = value[index];
+            let end = index + 2 + EslCommand::parameter_size(opcode);
+            if end > len {
+                return Err(format!("Invalid data size :{}", len).to_string());
+            }
+            commands.push(EslCommand {
+                opcode,
+                esl_id: value[index + 1],
+                parameters: value[index + 2..end].to_vec(),
+            });
+            index = end;
+        }
+        Ok(Self { group_id, commands })
+    }
+}
+
+impl From<EslPayload> for Vec<u8> {
+    /// Create decrypted payload from [`EslPayload`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::electronic_shelf_label::{EslCommand, EslPayload};
+    ///
+    /// let commands = vec![
+    ///     EslCommand::try_new(0x00, 0x02, &[]).unwrap(),
+    ///     EslCommand::try_new(0x20, 0x03, &[0x00, 0x01]).unwrap(),
+    /// ];
+    /// let result = EslPayload::try_new(0x01, &commands).unwrap();
+    /// let data: Vec<u8> = result.into();
+    /// assert_eq!(vec![0x01, 0x00, 0x02, 0x20, 0x03, 0x00, 0x01], data);
+    /// ```
+    fn from(value: EslPayload) -> Self {
+        let mut data: Vec<u8> = vec![value.group_id];
+        for command in value.commands {
+            data.push(command.opcode);
+            data.push(command.esl_id);
+            data.append(&mut command.parameters.clone());
+        }
+        data
+    }
+}
+
+/// Electronic Shelf Label.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ElectronicShelfLabel {
+    /// data length
+    pub length: u8,
+
+    /// Randomizer
+    pub randomizer: [u8; 5],
+
+    /// Encrypted Payload
+    pub payload: Vec<u8>,
+
+    /// MIC
+    pub mic: [u8; 4],
+}
+
+impl ElectronicShelfLabel {
+    /// Create [`ElectronicShelfLabel`] from Parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::electronic_shelf_label::ElectronicShelfLabel;
+    ///
+    /// let randomizer: [u8; 5] = [1, 2, 3, 4, 5];
+    /// let payload = [6, 7].to_vec();
+    /// let mic: [u8; 4] = [8, 9, 10, 11];
+    /// let result = ElectronicShelfLabel::new(&randomizer, &payload, &mic);
+    /// assert_eq!(12, result.length);
+    /// assert_eq!(randomizer, result.randomizer);
+    /// assert_eq!(payload, result.payload);
+    /// assert_eq!(mic, result.mic);
+    /// ```
+    pub fn new(randomizer: &[u8; 5], payload: &[u8], mic: &[u8; 4]) -> Self {
+        Self {
+            length: 10 + payload.len() as u8,
+            randomizer: *randomizer,
+            payload: payload.to_vec(),
+            mic: *mic,
+        }
+    }
+}
+
+impl TryFrom<&[u8]> for ElectronicShelfLabel {
+    type Error = String;
+    /// Create [`ElectronicShelfLabel`] from `u8` slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{electronic_shelf_label::ElectronicShelfLabel, data_type::DataType};
+    ///
+    /// let mut data: Vec<u8> = vec![12, ElectronicShelfLabel::data_type()];
+    /// data.append(&mut vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11]);
+    ///
+    /// let result = ElectronicShelfLabel::try_from(data.as_slice());
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(12, data_type.length);
+    /// assert_eq!([1, 2, 3, 4, 5], data_type.randomizer);
+    /// assert_eq!(vec![6, 7], data_type.payload);
+    /// assert_eq!([8, 9, 10, 11], data_type.mic);
+    ///
+    /// let data: Vec<u8> = vec![9, ElectronicShelfLabel::data_type(), 1, 2, 3, 4, 5, 6, 7, 8, 9];
+    /// let result = ElectronicShelfLabel::try_from(data.as_slice());
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &[u8]) -> Result<Self, String> {
+        let len = value.len();
+        if len < 11 {
+            return Err(format!("Invalid data size :{}", len).to_string());
+        }
+        let length = value[0];
+        let end = length as usize + 1;
+        if length < 10 || len < end {
+            return Err(format!("Invalid data size :{}", len).to_string());
+        }
+        Ok(Self {
+            length,
+            randomizer: value[2..7].try_into().unwrap(),
+            payload: value[7..end - 4].to_vec(),
+            mic: value[end - 4..end].try_into().unwrap(),
+        })
+    }
+}
+
+impl TryFrom<&Vec<u8>> for ElectronicShelfLabel {
+    type Error = String;
+    /// Create [`ElectronicShelfLabel`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{electronic_shelf_label::ElectronicShelfLabel, data_type::DataType};
+    ///
+    /// let mut data: Vec<u8> = vec![12, ElectronicShelfLabel::data_type()];
+    /// data.append(&mut vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11]);
+    ///
+    /// let result = ElectronicShelfLabel::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(12, data_type.length);
+    /// assert_eq!([1, 2, 3, 4, 5], data_type.randomizer);
+    /// assert_eq!(vec![6, 7], data_type.payload);
+    /// assert_eq!([8, 9, 10, 11], data_type.mic);
+    ///
+    /// let data: Vec<u8> = vec![9, ElectronicShelfLabel::data_type(), 1, 2, 3, 4, 5, 6, 7, 8, 9];
+    /// let result = ElectronicShelfLabel::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        Self::try_from(value.as_slice())
+    }
+}
+
+impl From<ElectronicShelfLabel> for Vec<u8> {
+    /// Create [`Vec<u8>`] from [`ElectronicShelfLabel`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{electronic_shelf_label::ElectronicShelfLabel, data_type::DataType};
+    ///
+    /// let result1 = ElectronicShelfLabel::new(&[1, 2, 3, 4, 5], &[6, 7], &[8, 9, 10, 11]);
+    ///
+    /// let mut data: Vec<u8> = vec![12, ElectronicShelfLabel::data_type()];
+    /// data.append(&mut vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11]);
+    ///
+    /// let into_data: Vec<u8> = result1.into();
+    /// assert_eq!(data, into_data);
+    ///
+    /// let result2 = ElectronicShelfLabel::try_from(&data);
+    /// assert!(result2.is_ok());
+    /// let data_type = result2.unwrap();
+    /// let into_data: Vec<u8> = data_type.into();
+    /// assert_eq!(data, into_data);
+    /// ```
+    fn from(value: ElectronicShelfLabel) -> Self {
+        let mut data: Vec<u8> = vec![value.length, ElectronicShelfLabel::data_type()];
+        data.append(&mut value.randomizer.to_vec());
+        data.append(&mut value.payload.clone());
+        data.append(&mut value.mic.to_vec());
+        data
+    }
+}
+
+impl DataType for ElectronicShelfLabel {
+    /// return `0x34`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{electronic_shelf_label::ElectronicShelfLabel, data_type::DataType};
+    ///
+    /// assert_eq!(0x34, ElectronicShelfLabel::data_type());
+    /// ```
+    fn data_type() -> u8 {
+        0x34
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data_types::{data_type::DataType, electronic_shelf_label::*};
+
+    #[test]
+    fn test_esl_address() {
+        assert_eq!(
+            Ok(EslAddress {
+                group_id: 0x7f,
+                esl_id: 0xff
+            }),
+            EslAddress::try_new(0x7f, 0xff)
+        );
+        assert_eq!(
+            Err("Invalid group id :128".to_string()),
+            EslAddress::try_new(0x80, 0x00)
+        );
+        assert!(EslAddress::try_new(0x00, 0xff).unwrap().is_broadcast());
+        assert!(!EslAddress::try_new(0x00, 0xfe).unwrap().is_broadcast());
+
+        assert_eq!(
+            Ok(EslAddress {
+                group_id: 0x7f,
+                esl_id: 0x01
+            }),
+            EslAddress::try_from(0x7f01u16)
+        );
+        assert_eq!(
+            Err("Invalid ESL address :0x8000".to_string()),
+            EslAddress::try_from(0x8000u16)
+        );
+        for value in [0x0000u16, 0x0102, 0x7fff] {
+            assert_eq!(value, u16::from(EslAddress::try_from(value).unwrap()));
+        }
+    }
+
+    #[test]
+    fn test_esl_command_try_new() {
+        assert!(EslCommand::try_new(0x00, 0x01, &[]).is_ok());
+        assert!(EslCommand::try_new(0x11, 0x01, &[0x00]).is_ok());
+        assert!(EslCommand::try_new(0xb0, 0x01, &[0x00; 11]).is_ok());
+        assert_eq!(
+            Err("Invalid ESL command parameter size :1".to_string()),
+            EslCommand::try_new(0x00, 0x01, &[0x00])
+        );
+        assert_eq!(
+            Err("Invalid ESL command parameter size :10".to_string()),
+            EslCommand::try_new(0xb0, 0x01, &[0x00; 10])
+        );
+    }
+
+    #[test]
+    fn test_esl_payload() {
+        let commands = vec![
+            EslCommand::try_new(0x00, 0x02, &[]).unwrap(),
+            EslCommand::try_new(0x11, 0xff, &[0x00]).unwrap(),
+        ];
+        let result = EslPayload::try_new(0x7f, &commands).unwrap();
+        assert_eq!(
+            vec![
+                EslAddress::try_new(0x7f, 0x02).unwrap(),
+                EslAddress::try_new(0x7f, 0xff).unwrap()
+            ],
+            result.addresses()
+        );
+        let data: Vec<u8> = result.clone().into();
+        assert_eq!(vec![0x7f, 0x00, 0x02, 0x11, 0xff, 0x00], data);
+        assert_eq!(Ok(result), EslPayload::try_from(data.as_slice()));
+
+        assert_eq!(
+            Err("Invalid group id :128".to_string()),
+            EslPayload::try_new(0x80, &commands)
+        );
+        assert_eq!(
+            Err("Invalid group id :128".to_string()),
+            EslPayload::try_from([0x80u8, 0x00, 0x02].as_slice())
+        );
+        assert_eq!(
+            Err("Invalid data size :0".to_string()),
+            EslPayload::try_from([0u8; 0].as_slice())
+        );
+        assert_eq!(
+            Err("Invalid data size :2".to_string()),
+            EslPayload::try_from([0x01u8, 0x00].as_slice())
+        );
+        assert_eq!(
+            Ok(EslPayload {
+                group_id: 0x01,
+                commands: Vec::new()
+            }),
+            EslPayload::try_from([0x01u8].as_slice())
+        );
+    }
+
+    #[test]
+    fn test_new() {
+        let randomizer: [u8; 5] = [1, 2, 3, 4, 5];
+        let payload = vec![6, 7];
+        let mic: [u8; 4] = [8, 9, 10, 11];
+        let result = ElectronicShelfLabel::new(&randomizer, &payload, &mic);
+        assert_eq!(12, result.length);
+        assert_eq!(randomizer, result.randomizer);
+        assert_eq!(payload, result.payload);
+        assert_eq!(mic, result.mic);
+    }
+
+    #[test]
+    fn test_try_from() {
+        let mut data: Vec<u8> = vec![12, ElectronicShelfLabel::data_type()];
+        data.append(&mut vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11]);
+
+        let result = ElectronicShelfLabel::try_from(&data);
+        assert!(result.is_ok());
+        let data_type = result.unwrap();
+        assert_eq!(12, data_type.length);
+        assert_eq!([1, 2, 3, 4, 5], data_type.randomizer);
+        assert_eq!(vec![6, 7], data_type.payload);
+        assert_eq!([8, 9, 10, 11], data_type.mic);
+
+        let mut data: Vec<u8> = vec![10, ElectronicShelfLabel::data_type()];
+        data.append(&mut vec![1, 2, 3, 4, 5, 8, 9, 10, 11]);
+        let result = ElectronicShelfLabel::try_from(&data).unwrap();
+        assert!(result.payload.is_empty());
+
+        let mut data: Vec<u8> = vec![13, ElectronicShelfLabel::data_type()];
+        data.append(&mut vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11]);
+        assert_eq!(
+            Err("Invalid data size :13".to_string()),
+            ElectronicShelfLabel::try_from(&data)
+        );
+
+        let data: Vec<u8> = vec![
+            9,
+            ElectronicShelfLabel::data_type(),
+            1,
+            2,
+            3,
+            4,
+            5,
+            6,
+            7,
+            8,
+            9,
+        ];
+        let result = ElectronicShelfLabel::try_from(&data);
+        assert!(result.is_err());
+        assert_eq!(
+            format!("Invalid data size :{}", data.len()),
+            result.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_into() {
+        let result1 = ElectronicShelfLabel::new(&[1, 2, 3, 4, 5], &[6, 7], &[8, 9, 10, 11]);
+
+        let mut data: Vec<u8> = vec![12, ElectronicShelfLabel::data_type()];
+        data.append(&mut vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11]);
+
+        let into_data: Vec<u8> = result1.into();
+        assert_eq!(data, into_data);
+
+        let result2 = ElectronicShelfLabel::try_from(&data);
+        assert!(result2.is_ok());
+        let data_type = result2.unwrap();
+        let into_data: Vec<u8> = data_type.into();
+        assert_eq!(data, into_data);
+    }
+
+    #[test]
+    fn test_data_type() {
+        assert_eq!(0x34, ElectronicShelfLabel::data_type());
+    }
+}
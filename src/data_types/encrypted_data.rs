@@ -1,6 +1,9 @@
 //! Encrypted Data (Data Type Value: 0x31) module.
 
-use crate::data_types::data_type::DataType;
+use crate::data_types::{
+    ad_type::AdType,
+    data_type::{validate_length, DataType},
+};
 
 /// Encrypted Data.
 #[derive(Debug, PartialEq, Clone)]
@@ -45,9 +48,9 @@ impl EncryptedData {
     }
 }
 
-impl TryFrom<&Vec<u8>> for EncryptedData {
+impl TryFrom<&[u8]> for EncryptedData {
     type Error = String;
-    /// Create [`EncryptedData`] from [`Vec<u8>`].
+    /// Create [`EncryptedData`] from `u8` slice.
     ///
     /// # Examples
     ///
@@ -65,7 +68,7 @@ impl TryFrom<&Vec<u8>> for EncryptedData {
     /// data.append(&mut payload.clone());
     /// data.append(&mut mic.to_vec());
     ///
-    /// let result = EncryptedData::try_from(&data);
+    /// let result = EncryptedData::try_from(data.as_slice());
     /// assert!(result.is_ok());
     /// let data_type = result.unwrap();
     /// assert_eq!(length, data_type.length);
@@ -74,26 +77,26 @@ impl TryFrom<&Vec<u8>> for EncryptedData {
     /// assert_eq!(mic, data_type.mic);
     ///
     /// let data: Vec<u8> = Vec::new();
-    /// let result = EncryptedData::try_from(&data);
+    /// let result = EncryptedData::try_from(data.as_slice());
     /// assert!(result.is_err());
     /// assert_eq!(
     ///     format!("Invalid data size :{}", data.len()),
     ///     result.unwrap_err()
     /// );
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+    fn try_from(value: &[u8]) -> Result<Self, String> {
         let len = value.len();
         if len < 11 {
             return Err(format!("Invalid data size :{}", len).to_string());
         }
-        let length = value[0];
+        let length = validate_length(value, 10, 255)?;
         let randomizer: [u8; 5];
         match value[2..7].try_into() {
             Ok(x) => randomizer = x,
             Err(x) => return Err(x.to_string()),
         }
         let mic: [u8; 4];
-        match value[value.len() - 4..].try_into() {
+        match value[length as usize - 3..1 + length as usize].try_into() {
             Ok(x) => mic = x,
             Err(x) => return Err(x.to_string()),
         }
@@ -106,6 +109,47 @@ impl TryFrom<&Vec<u8>> for EncryptedData {
     }
 }
 
+impl TryFrom<&Vec<u8>> for EncryptedData {
+    type Error = String;
+    /// Create [`EncryptedData`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{encrypted_data::EncryptedData, data_type::DataType};
+    ///
+    /// let randomizer: [u8; 5] = [1, 2, 3, 4, 5];
+    /// let payload = [6].to_vec();
+    /// let mic: [u8; 4] = [7, 8, 9, 10];
+    /// let length = 11;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(EncryptedData::data_type());
+    /// data.append(&mut randomizer.to_vec());
+    /// data.append(&mut payload.clone());
+    /// data.append(&mut mic.to_vec());
+    ///
+    /// let result = EncryptedData::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert_eq!(randomizer, data_type.randomizer);
+    /// assert_eq!(payload, data_type.payload);
+    /// assert_eq!(mic, data_type.mic);
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = EncryptedData::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        Self::try_from(value.as_slice())
+    }
+}
+
 impl Into<Vec<u8>> for EncryptedData {
     /// Create [`Vec<u8>`] from [`EncryptedData`].
     ///
@@ -173,8 +217,9 @@ impl DataType for EncryptedData {
 /// assert!(is_encrypted_data(0x31));
 /// assert!(!is_encrypted_data(0x00));
 /// ```
+#[deprecated(note = "use `AdType::of(data_type).matches::<EncryptedData>()` instead")]
 pub fn is_encrypted_data(data_type: u8) -> bool {
-    EncryptedData::data_type() == data_type
+    AdType::of(data_type).matches::<EncryptedData>()
 }
 
 #[cfg(test)]
@@ -193,6 +238,27 @@ mod tests {
         assert_eq!(mic, result.mic);
     }
 
+    #[test]
+    fn test_try_from_length_consistency() {
+        let mut data: Vec<u8> =
+            EncryptedData::new(&[1, 2, 3, 4, 5], &vec![6, 7], [8, 9, 10, 11]).into();
+        data.append(&mut vec![0x02, 0x0a, 0x00]);
+        let result = EncryptedData::try_from(&data);
+        assert!(result.is_ok());
+        let data_type = result.unwrap();
+        assert_eq!(vec![6, 7], data_type.payload);
+        assert_eq!([8, 9, 10, 11], data_type.mic);
+
+        let mut data: Vec<u8> = vec![13, EncryptedData::data_type()];
+        data.append(&mut vec![0u8; 11]);
+        let result = EncryptedData::try_from(&data);
+        assert!(result.is_err());
+        assert_eq!(
+            format!("Invalid data size :{}", data.len()),
+            result.unwrap_err()
+        );
+    }
+
     #[test]
     fn test_try_from() {
         let randomizer: [u8; 5] = [1, 2, 3, 4, 5];
@@ -255,6 +321,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_is_encrypted_data() {
         assert!(is_encrypted_data(0x31));
         assert!(!is_encrypted_data(0x00));
@@ -1,6 +1,6 @@
 //! Flags (Data Type Value: 0x01) module.
 
-use crate::data_types::data_type::DataType;
+use crate::data_types::{ad_type::AdType, data_type::DataType};
 
 /// Flags.
 #[derive(Debug, PartialEq, Clone)]
@@ -46,11 +46,165 @@ impl Flags {
     /// ```
     pub fn new(flags: &Vec<bool>) -> Self {
         Self {
-            length: (flags.len() / 8 + 1) as u8,
+            length: (flags.len().div_ceil(8) + 1) as u8,
             flags: flags.clone(),
         }
     }
 
+    /// Create [`Flags`] from raw Flags octets (all bits including reserved ones are kept).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::flags::Flags;
+    ///
+    /// let result = Flags::from_bytes(&[0b1110_0110, 0x80]);
+    /// assert_eq!(3, result.length);
+    /// assert_eq!(16, result.flags.len());
+    /// assert_eq!(vec![0b1110_0110, 0x80], result.to_bytes());
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        Self::new(
+            &bytes
+                .iter()
+                .flat_map(|x| (0..8).map(move |i| x & (1 << i) != 0))
+                .collect(),
+        )
+    }
+
+    /// Get raw Flags octets (all bits including reserved ones).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::flags::Flags;
+    ///
+    /// let result = Flags::from_bits(0b1000_0110);
+    /// assert_eq!(vec![0b1000_0110], result.to_bytes());
+    ///
+    /// let result = Flags::new(&[true, false, true].to_vec());
+    /// assert_eq!(vec![0b0000_0101], result.to_bytes());
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.flags
+            .chunks(8)
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, flag)| **flag)
+                    .fold(0u8, |bits, (i, _)| bits | (1 << i))
+            })
+            .collect()
+    }
+
+    /// Get reserved bits (bit 5-7 of the first octet and all following octets).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::flags::Flags;
+    ///
+    /// let result = Flags::from_bits(0b0000_0110);
+    /// assert!(!result.has_reserved_bits());
+    ///
+    /// let result = Flags::from_bits(0b0100_0110);
+    /// assert!(result.has_reserved_bits());
+    ///
+    /// let result = Flags::from_bytes(&[0b0000_0110, 0x01]);
+    /// assert!(result.has_reserved_bits());
+    /// ```
+    pub fn has_reserved_bits(&self) -> bool {
+        self.flags.iter().skip(5).any(|flag| *flag)
+    }
+
+    /// Create [`Flags`] from Flags bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::flags::{
+    ///     Flags, BR_EDR_NOT_SUPPORTED, LE_GENERAL_DISCOVERABLE_MODE,
+    /// };
+    ///
+    /// let result = Flags::from_bits(LE_GENERAL_DISCOVERABLE_MODE | BR_EDR_NOT_SUPPORTED);
+    /// assert_eq!(2, result.length);
+    /// assert!(result.is_le_general_discoverable());
+    /// assert!(result.is_br_edr_not_supported());
+    /// assert!(!result.is_le_limited_discoverable());
+    ///
+    /// let result = Flags::from_bits(0b1000_0000);
+    /// assert_eq!(0b1000_0000, result.bits());
+    /// ```
+    pub fn from_bits(bits: u8) -> Self {
+        Self::new(&(0..8).map(|i| bits & (1 << i) != 0).collect())
+    }
+
+    /// Get first octet of Flags as bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::flags::{
+    ///     Flags, BR_EDR_NOT_SUPPORTED, LE_GENERAL_DISCOVERABLE_MODE,
+    /// };
+    ///
+    /// let bits = LE_GENERAL_DISCOVERABLE_MODE | BR_EDR_NOT_SUPPORTED;
+    /// let result = Flags::from_bits(bits);
+    /// assert_eq!(bits, result.bits());
+    ///
+    /// let result = Flags::new(&Vec::new());
+    /// assert_eq!(0, result.bits());
+    /// ```
+    pub fn bits(&self) -> u8 {
+        self.flags
+            .iter()
+            .take(8)
+            .enumerate()
+            .filter(|(_, flag)| **flag)
+            .fold(0u8, |bits, (i, _)| bits | (1 << i))
+    }
+
+    /// check LE Limited Discoverable Mode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::flags::{Flags, LE_LIMITED_DISCOVERABLE_MODE};
+    ///
+    /// let result = Flags::from_bits(LE_LIMITED_DISCOVERABLE_MODE);
+    /// assert!(result.is_le_limited_discoverable());
+    ///
+    /// let result = Flags::from_bits(0);
+    /// assert!(!result.is_le_limited_discoverable());
+    ///
+    /// let result = Flags::new(&Vec::new());
+    /// assert!(!result.is_le_limited_discoverable());
+    /// ```
+    pub fn is_le_limited_discoverable(&self) -> bool {
+        *self.flags.first().unwrap_or(&false)
+    }
+
+    /// check LE General Discoverable Mode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::flags::{Flags, LE_GENERAL_DISCOVERABLE_MODE};
+    ///
+    /// let result = Flags::from_bits(LE_GENERAL_DISCOVERABLE_MODE);
+    /// assert!(result.is_le_general_discoverable());
+    ///
+    /// let result = Flags::from_bits(0);
+    /// assert!(!result.is_le_general_discoverable());
+    ///
+    /// let result = Flags::new(&Vec::new());
+    /// assert!(!result.is_le_general_discoverable());
+    /// ```
+    pub fn is_le_general_discoverable(&self) -> bool {
+        *self.flags.get(1).unwrap_or(&false)
+    }
+
     /// check LE Limited Discoverable Mode.
     ///
     /// # Examples
@@ -70,6 +224,7 @@ impl Flags {
     /// let result = Flags::new(&flags);
     /// assert!(!result.is_le_limited_discoverable_mode());
     /// ```
+    #[deprecated(note = "use `is_le_limited_discoverable` instead")]
     pub fn is_le_limited_discoverable_mode(&self) -> bool {
         *self.flags.get(0).unwrap_or(&false)
     }
@@ -93,6 +248,7 @@ impl Flags {
     /// let result = Flags::new(&flags);
     /// assert!(!result.is_le_general_discoverable_mode());
     /// ```
+    #[deprecated(note = "use `is_le_general_discoverable` instead")]
     pub fn is_le_general_discoverable_mode(&self) -> bool {
         *self.flags.get(1).unwrap_or(&false)
     }
@@ -142,11 +298,46 @@ impl Flags {
     pub fn is_simultaneous_controller(&self) -> bool {
         *self.flags.get(3).unwrap_or(&false)
     }
+
+    /// check Simultaneous LE and BR/EDR to Same Device Capable (Host).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::flags::{Flags, SIMULTANEOUS_LE_AND_BR_EDR_HOST};
+    ///
+    /// let result = Flags::from_bits(SIMULTANEOUS_LE_AND_BR_EDR_HOST);
+    /// assert!(result.is_simultaneous_host());
+    ///
+    /// let result = Flags::from_bits(0);
+    /// assert!(!result.is_simultaneous_host());
+    ///
+    /// let result = Flags::new(&Vec::new());
+    /// assert!(!result.is_simultaneous_host());
+    /// ```
+    pub fn is_simultaneous_host(&self) -> bool {
+        *self.flags.get(4).unwrap_or(&false)
+    }
 }
 
-impl TryFrom<&Vec<u8>> for Flags {
+/// LE Limited Discoverable Mode
+pub const LE_LIMITED_DISCOVERABLE_MODE: u8 = 0b0000_0001;
+
+/// LE General Discoverable Mode
+pub const LE_GENERAL_DISCOVERABLE_MODE: u8 = 0b0000_0010;
+
+/// BR/EDR Not Supported
+pub const BR_EDR_NOT_SUPPORTED: u8 = 0b0000_0100;
+
+/// Simultaneous LE and BR/EDR to Same Device Capable (Controller)
+pub const SIMULTANEOUS_LE_AND_BR_EDR_CONTROLLER: u8 = 0b0000_1000;
+
+/// Simultaneous LE and BR/EDR to Same Device Capable (Host)
+pub const SIMULTANEOUS_LE_AND_BR_EDR_HOST: u8 = 0b0001_0000;
+
+impl TryFrom<&[u8]> for Flags {
     type Error = String;
-    /// Create [`Flags`] from [`Vec<u8>`].
+    /// Create [`Flags`] from `u8` slice.
     ///
     /// # Examples
     ///
@@ -175,7 +366,7 @@ impl TryFrom<&Vec<u8>> for Flags {
     /// data.push(Flags::data_type());
     /// data.append(&mut flags_bytes.clone());
     ///
-    /// let result = Flags::try_from(&data);
+    /// let result = Flags::try_from(data.as_slice());
     /// assert!(result.is_ok());
     /// let data_type = result.unwrap();
     /// assert_eq!(length, data_type.length);
@@ -203,7 +394,7 @@ impl TryFrom<&Vec<u8>> for Flags {
     /// data.push(Flags::data_type());
     /// data.append(&mut flags_bytes.clone());
     ///
-    /// let result = Flags::try_from(&data);
+    /// let result = Flags::try_from(data.as_slice());
     /// assert!(result.is_ok());
     /// let data_type = result.unwrap();
     /// assert_eq!(length, data_type.length);
@@ -231,7 +422,7 @@ impl TryFrom<&Vec<u8>> for Flags {
     /// data.push(Flags::data_type());
     /// data.append(&mut flags_bytes.clone());
     ///
-    /// let result = Flags::try_from(&data);
+    /// let result = Flags::try_from(data.as_slice());
     /// assert!(result.is_ok());
     /// let data_type = result.unwrap();
     /// assert_eq!(length, data_type.length);
@@ -242,29 +433,32 @@ impl TryFrom<&Vec<u8>> for Flags {
     /// data.push(length);
     /// data.push(Flags::data_type());
     ///
-    /// let result = Flags::try_from(&data);
+    /// let result = Flags::try_from(data.as_slice());
     /// assert!(result.is_ok());
     /// let data_type = result.unwrap();
     /// assert_eq!(length, data_type.length);
     /// assert_eq!(Vec::<bool>::new(), data_type.flags);
     ///
     /// let data: Vec<u8> = Vec::new();
-    /// let result = Flags::try_from(&data);
+    /// let result = Flags::try_from(data.as_slice());
     /// assert!(result.is_err());
     /// assert_eq!(
     ///     format!("Invalid data size :{}", data.len()),
     ///     result.unwrap_err()
     /// );
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+    fn try_from(value: &[u8]) -> Result<Self, String> {
         let len = value.len();
         if len < 2 {
             return Err(format!("Invalid data size :{}", len).to_string());
         }
         let length = value[0];
+        if length == 0 || len < length as usize + 1 {
+            return Err(format!("Invalid data size :{}", len).to_string());
+        }
         Ok(Self {
             length,
-            flags: value[2..(2 + length - 1) as usize]
+            flags: value[2..length as usize + 1]
                 .iter()
                 .flat_map(|x| {
                     let mut data: Vec<bool> = Vec::new();
@@ -283,6 +477,123 @@ impl TryFrom<&Vec<u8>> for Flags {
     }
 }
 
+impl TryFrom<&Vec<u8>> for Flags {
+    type Error = String;
+    /// Create [`Flags`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{flags::Flags, data_type::DataType};
+    ///
+    /// let flags_bytes = [0b00000001u8].to_vec();
+    /// let flags: Vec<bool> = flags_bytes
+    ///     .iter()
+    ///     .flat_map(|x| {
+    ///         let mut data: Vec<bool> = Vec::new();
+    ///         data.push((x & 0b0000_0001) != 0);
+    ///         data.push((x & 0b0000_0010) != 0);
+    ///         data.push((x & 0b0000_0100) != 0);
+    ///         data.push((x & 0b0000_1000) != 0);
+    ///         data.push((x & 0b0001_0000) != 0);
+    ///         data.push((x & 0b0010_0000) != 0);
+    ///         data.push((x & 0b0100_0000) != 0);
+    ///         data.push((x & 0b1000_0000) != 0);
+    ///         data
+    ///     })
+    ///     .collect();
+    /// let length = 2;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(Flags::data_type());
+    /// data.append(&mut flags_bytes.clone());
+    ///
+    /// let result = Flags::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert_eq!(flags, data_type.flags);
+    ///
+    /// let flags_bytes = [0b00000010u8].to_vec();
+    /// let flags: Vec<bool> = flags_bytes
+    ///     .iter()
+    ///     .flat_map(|x| {
+    ///         let mut data: Vec<bool> = Vec::new();
+    ///         data.push((x & 0b0000_0001) != 0);
+    ///         data.push((x & 0b0000_0010) != 0);
+    ///         data.push((x & 0b0000_0100) != 0);
+    ///         data.push((x & 0b0000_1000) != 0);
+    ///         data.push((x & 0b0001_0000) != 0);
+    ///         data.push((x & 0b0010_0000) != 0);
+    ///         data.push((x & 0b0100_0000) != 0);
+    ///         data.push((x & 0b1000_0000) != 0);
+    ///         data
+    ///     })
+    ///     .collect();
+    /// let length = 2;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(Flags::data_type());
+    /// data.append(&mut flags_bytes.clone());
+    ///
+    /// let result = Flags::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert_eq!(flags, data_type.flags);
+    ///
+    /// let flags_bytes = [0b00000100u8].to_vec();
+    /// let flags: Vec<bool> = flags_bytes
+    ///     .iter()
+    ///     .flat_map(|x| {
+    ///         let mut data: Vec<bool> = Vec::new();
+    ///         data.push((x & 0b0000_0001) != 0);
+    ///         data.push((x & 0b0000_0010) != 0);
+    ///         data.push((x & 0b0000_0100) != 0);
+    ///         data.push((x & 0b0000_1000) != 0);
+    ///         data.push((x & 0b0001_0000) != 0);
+    ///         data.push((x & 0b0010_0000) != 0);
+    ///         data.push((x & 0b0100_0000) != 0);
+    ///         data.push((x & 0b1000_0000) != 0);
+    ///         data
+    ///     })
+    ///     .collect();
+    /// let length = 2;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(Flags::data_type());
+    /// data.append(&mut flags_bytes.clone());
+    ///
+    /// let result = Flags::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert_eq!(flags, data_type.flags);
+    ///
+    /// let length = 1;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(Flags::data_type());
+    ///
+    /// let result = Flags::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert_eq!(Vec::<bool>::new(), data_type.flags);
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = Flags::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        Self::try_from(value.as_slice())
+    }
+}
+
 impl Into<Vec<u8>> for Flags {
     /// Create [`Vec<u8>`] from [`Flags`].
     ///
@@ -437,21 +748,7 @@ impl Into<Vec<u8>> for Flags {
         data.push(self.length);
         data.push(Self::data_type());
 
-        let mut flags: Vec<u8> = self
-            .flags
-            .windows(8)
-            .step_by(8)
-            .map(|w| {
-                let mut flag = 0u8;
-                for (i, element) in w[0..8].iter().enumerate() {
-                    if *element {
-                        flag |= 1 << i;
-                    }
-                }
-                flag
-            })
-            .collect();
-        data.append(&mut flags);
+        data.append(&mut self.to_bytes());
         return data;
     }
 }
@@ -482,8 +779,9 @@ impl DataType for Flags {
 /// assert!(is_flags(0x01));
 /// assert!(!is_flags(0x00));
 /// ```
+#[deprecated(note = "use `AdType::of(data_type).matches::<Flags>()` instead")]
 pub fn is_flags(data_type: u8) -> bool {
-    Flags::data_type() == data_type
+    AdType::of(data_type).matches::<Flags>()
 }
 
 #[cfg(test)]
@@ -518,6 +816,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_is_le_limited_discoverable_mode() {
         let flags = [true, false, false, false, false, false, false, false].to_vec();
         let result = Flags::new(&flags);
@@ -533,6 +832,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_is_le_general_discoverable_mode() {
         let flags = [false, true, false, false, false, false, false, false].to_vec();
         let result = Flags::new(&flags);
@@ -577,6 +877,135 @@ mod tests {
         assert!(!result.is_simultaneous_controller());
     }
 
+    #[test]
+    fn test_from_bits() {
+        let result = Flags::from_bits(LE_GENERAL_DISCOVERABLE_MODE | BR_EDR_NOT_SUPPORTED);
+        assert_eq!(2, result.length);
+        assert_eq!(
+            [false, true, true, false, false, false, false, false].to_vec(),
+            result.flags
+        );
+
+        let result = Flags::from_bits(0b1000_0000);
+        assert_eq!(
+            [false, false, false, false, false, false, false, true].to_vec(),
+            result.flags
+        );
+    }
+
+    #[test]
+    fn test_bits() {
+        let bits = LE_GENERAL_DISCOVERABLE_MODE | BR_EDR_NOT_SUPPORTED;
+        let result = Flags::from_bits(bits);
+        assert_eq!(bits, result.bits());
+
+        let result = Flags::from_bits(0b1110_0000);
+        assert_eq!(0b1110_0000, result.bits());
+
+        let result = Flags::new(&Vec::new());
+        assert_eq!(0, result.bits());
+    }
+
+    #[test]
+    fn test_is_le_limited_discoverable() {
+        let result = Flags::from_bits(LE_LIMITED_DISCOVERABLE_MODE);
+        assert!(result.is_le_limited_discoverable());
+
+        let result = Flags::from_bits(0);
+        assert!(!result.is_le_limited_discoverable());
+
+        let result = Flags::new(&Vec::new());
+        assert!(!result.is_le_limited_discoverable());
+    }
+
+    #[test]
+    fn test_is_le_general_discoverable() {
+        let result = Flags::from_bits(LE_GENERAL_DISCOVERABLE_MODE);
+        assert!(result.is_le_general_discoverable());
+
+        let result = Flags::from_bits(0);
+        assert!(!result.is_le_general_discoverable());
+
+        let result = Flags::new(&Vec::new());
+        assert!(!result.is_le_general_discoverable());
+    }
+
+    #[test]
+    fn test_is_simultaneous_host() {
+        let result = Flags::from_bits(SIMULTANEOUS_LE_AND_BR_EDR_HOST);
+        assert!(result.is_simultaneous_host());
+
+        let result = Flags::from_bits(SIMULTANEOUS_LE_AND_BR_EDR_CONTROLLER);
+        assert!(!result.is_simultaneous_host());
+        assert!(result.is_simultaneous_controller());
+
+        let result = Flags::new(&Vec::new());
+        assert!(!result.is_simultaneous_host());
+    }
+
+    #[test]
+    fn test_from_bytes() {
+        let result = Flags::from_bytes(&[]);
+        assert_eq!(1, result.length);
+        assert!(result.flags.is_empty());
+
+        let result = Flags::from_bytes(&[0b1110_0110]);
+        assert_eq!(2, result.length);
+        assert_eq!(0b1110_0110, result.bits());
+        assert!(result.is_le_general_discoverable());
+        assert!(result.is_br_edr_not_supported());
+
+        let result = Flags::from_bytes(&[0x06, 0xff, 0x01]);
+        assert_eq!(4, result.length);
+        assert_eq!(24, result.flags.len());
+        assert_eq!(vec![0x06, 0xff, 0x01], result.to_bytes());
+    }
+
+    #[test]
+    fn test_to_bytes() {
+        assert_eq!(Vec::<u8>::new(), Flags::new(&Vec::new()).to_bytes());
+        assert_eq!(vec![0xff], Flags::from_bits(0xff).to_bytes());
+        let result = Flags::new(&[false, true, true].to_vec());
+        assert_eq!(2, result.length);
+        assert_eq!(vec![0b0000_0110], result.to_bytes());
+        let into_data: Vec<u8> = result.into();
+        assert_eq!(vec![2, Flags::data_type(), 0b0000_0110], into_data);
+    }
+
+    #[test]
+    fn test_has_reserved_bits() {
+        assert!(!Flags::from_bits(0b0001_1111).has_reserved_bits());
+        assert!(Flags::from_bits(0b0010_0000).has_reserved_bits());
+        assert!(Flags::from_bits(0b1000_0000).has_reserved_bits());
+        assert!(Flags::from_bytes(&[0x00, 0x80]).has_reserved_bits());
+        assert!(!Flags::from_bytes(&[0x06, 0x00]).has_reserved_bits());
+    }
+
+    #[test]
+    fn test_reserved_bits_round_trip() {
+        for data in [
+            vec![2, Flags::data_type(), 0b1110_0110],
+            vec![3, Flags::data_type(), 0b0000_0110, 0b1000_0001],
+            vec![4, Flags::data_type(), 0xff, 0xff, 0xff],
+        ] {
+            let result = Flags::try_from(&data).unwrap();
+            let into_data: Vec<u8> = result.into();
+            assert_eq!(data, into_data);
+        }
+
+        let data: Vec<u8> = vec![3, Flags::data_type(), 0x06];
+        assert_eq!(
+            Err("Invalid data size :3".to_string()),
+            Flags::try_from(&data)
+        );
+
+        let data: Vec<u8> = vec![0, Flags::data_type()];
+        assert_eq!(
+            Err("Invalid data size :2".to_string()),
+            Flags::try_from(&data)
+        );
+    }
+
     #[test]
     fn test_try_from() {
         let flags_bytes = [0b00000001u8].to_vec();
@@ -834,6 +1263,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_is_flags() {
         assert!(is_flags(0x01));
         assert!(!is_flags(0x00));
@@ -0,0 +1,728 @@
+//! Indoor Positioning (Data Type Value: 0x25) module.
+
+use crate::data_types::data_type::{validate_length, DataType};
+
+/// Indoor Positioning Configuration: Presence of coordinates
+pub const CONFIGURATION_COORDINATES_PRESENT: u8 = 0b0000_0001;
+
+/// Indoor Positioning Configuration: Coordinate system (`1` is local coordinate system)
+pub const CONFIGURATION_LOCAL_COORDINATES: u8 = 0b0000_0010;
+
+/// Indoor Positioning Configuration: Presence of Tx Power
+pub const CONFIGURATION_TX_POWER_PRESENT: u8 = 0b0000_0100;
+
+/// Indoor Positioning Configuration: Presence of Altitude
+pub const CONFIGURATION_ALTITUDE_PRESENT: u8 = 0b0000_1000;
+
+/// Indoor Positioning Configuration: Presence of Floor Number
+pub const CONFIGURATION_FLOOR_NUMBER_PRESENT: u8 = 0b0001_0000;
+
+/// Indoor Positioning Configuration: Presence of Uncertainty
+pub const CONFIGURATION_UNCERTAINTY_PRESENT: u8 = 0b0010_0000;
+
+/// Indoor Positioning Configuration: Location Name available in the GATT database
+pub const CONFIGURATION_LOCATION_NAME_AVAILABLE: u8 = 0b0100_0000;
+
+/// WGS84 coordinate resolution (1e-7 degrees).
+pub const WGS84_COORDINATE_RESOLUTION: f64 = 1e-7;
+
+/// Local coordinate resolution (1 decimeter).
+pub const LOCAL_COORDINATE_RESOLUTION: f64 = 0.1;
+
+/// Indoor Positioning coordinates.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum IndoorPositioningCoordinates {
+    /// WGS84 coordinate system (1e-7 degrees)
+    Wgs84 {
+        /// Latitude
+        latitude: i32,
+
+        /// Longitude
+        longitude: i32,
+    },
+
+    /// Local coordinate system (decimeters)
+    Local {
+        /// Local North Coordinate
+        north: i16,
+
+        /// Local East Coordinate
+        east: i16,
+    },
+}
+
+impl IndoorPositioningCoordinates {
+    /// Create WGS84 [`IndoorPositioningCoordinates`] from degrees.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::indoor_positioning::IndoorPositioningCoordinates;
+    ///
+    /// let result = IndoorPositioningCoordinates::from_degrees(35.6812362, 139.7671248);
+    /// assert_eq!(
+    ///     IndoorPositioningCoordinates::Wgs84 {
+    ///         latitude: 356812362,
+    ///         longitude: 1397671248
+    ///     },
+    ///     result
+    /// );
+    /// ```
+    pub fn from_degrees(latitude: f64, longitude: f64) -> Self {
+        IndoorPositioningCoordinates::Wgs84 {
+            latitude: (latitude / WGS84_COORDINATE_RESOLUTION).round() as i32,
+            longitude: (longitude / WGS84_COORDINATE_RESOLUTION).round() as i32,
+        }
+    }
+
+    /// Create local [`IndoorPositioningCoordinates`] from meters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::indoor_positioning::IndoorPositioningCoordinates;
+    ///
+    /// let result = IndoorPositioningCoordinates::from_meters(12.3, -4.5);
+    /// assert_eq!(
+    ///     IndoorPositioningCoordinates::Local {
+    ///         north: 123,
+    ///         east: -45
+    ///     },
+    ///     result
+    /// );
+    /// ```
+    pub fn from_meters(north: f64, east: f64) -> Self {
+        IndoorPositioningCoordinates::Local {
+            north: (north / LOCAL_COORDINATE_RESOLUTION).round() as i16,
+            east: (east / LOCAL_COORDINATE_RESOLUTION).round() as i16,
+        }
+    }
+
+    /// Get WGS84 latitude in degrees.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::indoor_positioning::IndoorPositioningCoordinates;
+    ///
+    /// let result = IndoorPositioningCoordinates::Wgs84 {
+    ///     latitude: 356812362,
+    ///     longitude: 1397671248,
+    /// };
+    /// assert!((35.6812362 - result.latitude_degrees().unwrap()).abs() < 1e-9);
+    ///
+    /// let result = IndoorPositioningCoordinates::Local { north: 1, east: 2 };
+    /// assert_eq!(None, result.latitude_degrees());
+    /// ```
+    pub fn latitude_degrees(&self) -> Option<f64> {
+        match self {
+            IndoorPositioningCoordinates::Wgs84 { latitude, .. } => {
+                Some(*latitude as f64 * WGS84_COORDINATE_RESOLUTION)
+            }
+            IndoorPositioningCoordinates::Local { .. } => None,
+        }
+    }
+
+    /// Get WGS84 longitude in degrees.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::indoor_positioning::IndoorPositioningCoordinates;
+    ///
+    /// let result = IndoorPositioningCoordinates::Wgs84 {
+    ///     latitude: 356812362,
+    ///     longitude: 1397671248,
+    /// };
+    /// assert!((139.7671248 - result.longitude_degrees().unwrap()).abs() < 1e-9);
+    ///
+    /// let result = IndoorPositioningCoordinates::Local { north: 1, east: 2 };
+    /// assert_eq!(None, result.longitude_degrees());
+    /// ```
+    pub fn longitude_degrees(&self) -> Option<f64> {
+        match self {
+            IndoorPositioningCoordinates::Wgs84 { longitude, .. } => {
+                Some(*longitude as f64 * WGS84_COORDINATE_RESOLUTION)
+            }
+            IndoorPositioningCoordinates::Local { .. } => None,
+        }
+    }
+
+    /// Get local north coordinate in meters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::indoor_positioning::IndoorPositioningCoordinates;
+    ///
+    /// let result = IndoorPositioningCoordinates::Local { north: 123, east: -45 };
+    /// assert!((12.3 - result.north_meters().unwrap()).abs() < 1e-9);
+    ///
+    /// let result = IndoorPositioningCoordinates::Wgs84 { latitude: 1, longitude: 2 };
+    /// assert_eq!(None, result.north_meters());
+    /// ```
+    pub fn north_meters(&self) -> Option<f64> {
+        match self {
+            IndoorPositioningCoordinates::Local { north, .. } => {
+                Some(*north as f64 * LOCAL_COORDINATE_RESOLUTION)
+            }
+            IndoorPositioningCoordinates::Wgs84 { .. } => None,
+        }
+    }
+
+    /// Get local east coordinate in meters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::indoor_positioning::IndoorPositioningCoordinates;
+    ///
+    /// let result = IndoorPositioningCoordinates::Local { north: 123, east: -45 };
+    /// assert!((-4.5 - result.east_meters().unwrap()).abs() < 1e-9);
+    ///
+    /// let result = IndoorPositioningCoordinates::Wgs84 { latitude: 1, longitude: 2 };
+    /// assert_eq!(None, result.east_meters());
+    /// ```
+    pub fn east_meters(&self) -> Option<f64> {
+        match self {
+            IndoorPositioningCoordinates::Local { east, .. } => {
+                Some(*east as f64 * LOCAL_COORDINATE_RESOLUTION)
+            }
+            IndoorPositioningCoordinates::Wgs84 { .. } => None,
+        }
+    }
+}
+
+/// Indoor Positioning.
+#[derive(Debug, PartialEq, Clone)]
+pub struct IndoorPositioning {
+    /// data length
+    pub length: u8,
+
+    /// Indoor Positioning Configuration
+    pub configuration: u8,
+
+    /// Coordinates
+    pub coordinates: Option<IndoorPositioningCoordinates>,
+
+    /// Tx Power
+    pub tx_power: Option<i8>,
+
+    /// Floor Number
+    pub floor_number: Option<u8>,
+
+    /// Altitude
+    pub altitude: Option<u16>,
+
+    /// Uncertainty
+    pub uncertainty: Option<u8>,
+}
+
+impl IndoorPositioning {
+    /// Create [`IndoorPositioning`] from Parameters.
+    ///
+    /// The presence flags of the configuration are set from the given fields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::indoor_positioning::*;
+    ///
+    /// let coordinates = IndoorPositioningCoordinates::Local { north: 123, east: -45 };
+    /// let result = IndoorPositioning::new(Some(coordinates), Some(-4), Some(22), None, None, true);
+    /// assert_eq!(8, result.length);
+    /// assert_eq!(
+    ///     CONFIGURATION_COORDINATES_PRESENT
+    ///         | CONFIGURATION_LOCAL_COORDINATES
+    ///         | CONFIGURATION_TX_POWER_PRESENT
+    ///         | CONFIGURATION_FLOOR_NUMBER_PRESENT
+    ///         | CONFIGURATION_LOCATION_NAME_AVAILABLE,
+    ///     result.configuration
+    /// );
+    /// assert_eq!(Some(coordinates), result.coordinates);
+    /// assert_eq!(Some(-4), result.tx_power);
+    /// assert_eq!(Some(22), result.floor_number);
+    /// assert_eq!(None, result.altitude);
+    /// assert_eq!(None, result.uncertainty);
+    /// ```
+    pub fn new(
+        coordinates: Option<IndoorPositioningCoordinates>,
+        tx_power: Option<i8>,
+        floor_number: Option<u8>,
+        altitude: Option<u16>,
+        uncertainty: Option<u8>,
+        location_name_available: bool,
+    ) -> Self {
+        let mut configuration = 0u8;
+        let mut length = 2u8;
+        match coordinates {
+            Some(IndoorPositioningCoordinates::Wgs84 { .. }) => {
+                configuration |= CONFIGURATION_COORDINATES_PRESENT;
+                length += 8;
+            }
+            Some(IndoorPositioningCoordinates::Local { .. }) => {
+                configuration |=
+                    CONFIGURATION_COORDINATES_PRESENT | CONFIGURATION_LOCAL_COORDINATES;
+                length += 4;
+            }
+            None => (),
+        }
+        if tx_power.is_some() {
+            configuration |= CONFIGURATION_TX_POWER_PRESENT;
+            length += 1;
+        }
+        if floor_number.is_some() {
+            configuration |= CONFIGURATION_FLOOR_NUMBER_PRESENT;
+            length += 1;
+        }
+        if altitude.is_some() {
+            configuration |= CONFIGURATION_ALTITUDE_PRESENT;
+            length += 2;
+        }
+        if uncertainty.is_some() {
+            configuration |= CONFIGURATION_UNCERTAINTY_PRESENT;
+            length += 1;
+        }
+        if location_name_available {
+            configuration |= CONFIGURATION_LOCATION_NAME_AVAILABLE;
+        }
+        Self {
+            length,
+            configuration,
+            coordinates,
+            tx_power,
+            floor_number,
+            altitude,
+            uncertainty,
+        }
+    }
+
+    /// check Location Name available in the GATT database.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::indoor_positioning::IndoorPositioning;
+    ///
+    /// let result = IndoorPositioning::new(None, None, None, None, None, true);
+    /// assert!(result.is_location_name_available());
+    ///
+    /// let result = IndoorPositioning::new(None, None, None, None, None, false);
+    /// assert!(!result.is_location_name_available());
+    /// ```
+    pub fn is_location_name_available(&self) -> bool {
+        self.configuration & CONFIGURATION_LOCATION_NAME_AVAILABLE != 0
+    }
+}
+
+impl TryFrom<&[u8]> for IndoorPositioning {
+    type Error = String;
+    /// Create [`IndoorPositioning`] from `u8` slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{indoor_positioning::*, data_type::DataType};
+    ///
+    /// let mut data: Vec<u8> = vec![11, IndoorPositioning::data_type(), 0b0000_0101];
+    /// data.append(&mut 356812362i32.to_le_bytes().to_vec());
+    /// data.append(&mut 1397671248i32.to_le_bytes().to_vec());
+    /// data.push(0xfc);
+    ///
+    /// let result = IndoorPositioning::try_from(data.as_slice());
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(11, data_type.length);
+    /// assert_eq!(
+    ///     Some(IndoorPositioningCoordinates::Wgs84 {
+    ///         latitude: 356812362,
+    ///         longitude: 1397671248
+    ///     }),
+    ///     data_type.coordinates
+    /// );
+    /// assert_eq!(Some(-4), data_type.tx_power);
+    /// assert_eq!(None, data_type.floor_number);
+    ///
+    /// let data: Vec<u8> = vec![4, IndoorPositioning::data_type(), 0b0000_0001, 0x00, 0x00];
+    /// let result = IndoorPositioning::try_from(data.as_slice());
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &[u8]) -> Result<Self, String> {
+        let len = value.len();
+        if len < 3 {
+            return Err(format!("Invalid data size :{}", len).to_string());
+        }
+        let length = validate_length(value, 2, 255)?;
+        let end = length as usize + 1;
+        let configuration = value[2];
+        let mut index = 3;
+        let mut take = |size: usize| -> Result<&[u8], String> {
+            if index + size > end {
+                return Err(format!("Invalid data size :{}", len).to_string());
+            }
+            index += size;
+            Ok(&value[index - size..index])
+        };
+        let coordinates = if configuration & CONFIGURATION_COORDINATES_PRESENT == 0 {
+            None
+        } else if configuration & CONFIGURATION_LOCAL_COORDINATES == 0 {
+            let latitude = i32::from_le_bytes(take(4)?.try_into().unwrap());
+            let longitude = i32::from_le_bytes(take(4)?.try_into().unwrap());
+            Some(IndoorPositioningCoordinates::Wgs84 {
+                latitude,
+                longitude,
+            })
+        } else {
+            let north = i16::from_le_bytes(take(2)?.try_into().unwrap());
+            let east = i16::from_le_bytes(take(2)?.try_into().unwrap());
+            Some(IndoorPositioningCoordinates::Local { north, east })
+        };
+        let tx_power = if configuration & CONFIGURATION_TX_POWER_PRESENT == 0 {
+            None
+        } else {
+            Some(take(1)?[0] as i8)
+        };
+        let floor_number = if configuration & CONFIGURATION_FLOOR_NUMBER_PRESENT == 0 {
+            None
+        } else {
+            Some(take(1)?[0])
+        };
+        let altitude = if configuration & CONFIGURATION_ALTITUDE_PRESENT == 0 {
+            None
+        } else {
+            Some(u16::from_le_bytes(take(2)?.try_into().unwrap()))
+        };
+        let uncertainty = if configuration & CONFIGURATION_UNCERTAINTY_PRESENT == 0 {
+            None
+        } else {
+            Some(take(1)?[0])
+        };
+        Ok(Self {
+            length,
+            configuration,
+            coordinates,
+            tx_power,
+            floor_number,
+            altitude,
+            uncertainty,
+        })
+    }
+}
+
+impl TryFrom<&Vec<u8>> for IndoorPositioning {
+    type Error = String;
+    /// Create [`IndoorPositioning`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{indoor_positioning::*, data_type::DataType};
+    ///
+    /// let mut data: Vec<u8> = vec![11, IndoorPositioning::data_type(), 0b0000_0101];
+    /// data.append(&mut 356812362i32.to_le_bytes().to_vec());
+    /// data.append(&mut 1397671248i32.to_le_bytes().to_vec());
+    /// data.push(0xfc);
+    ///
+    /// let result = IndoorPositioning::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(11, data_type.length);
+    /// assert_eq!(
+    ///     Some(IndoorPositioningCoordinates::Wgs84 {
+    ///         latitude: 356812362,
+    ///         longitude: 1397671248
+    ///     }),
+    ///     data_type.coordinates
+    /// );
+    /// assert_eq!(Some(-4), data_type.tx_power);
+    /// assert_eq!(None, data_type.floor_number);
+    ///
+    /// let data: Vec<u8> = vec![4, IndoorPositioning::data_type(), 0b0000_0001, 0x00, 0x00];
+    /// let result = IndoorPositioning::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        Self::try_from(value.as_slice())
+    }
+}
+
+impl From<IndoorPositioning> for Vec<u8> {
+    /// Create [`Vec<u8>`] from [`IndoorPositioning`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{indoor_positioning::*, data_type::DataType};
+    ///
+    /// let coordinates = IndoorPositioningCoordinates::Local { north: 123, east: -45 };
+    /// let result1 = IndoorPositioning::new(Some(coordinates), None, Some(22), Some(1000), Some(0x12), false);
+    ///
+    /// let mut data: Vec<u8> = vec![10, IndoorPositioning::data_type(), 0b0011_1011];
+    /// data.append(&mut 123i16.to_le_bytes().to_vec());
+    /// data.append(&mut (-45i16).to_le_bytes().to_vec());
+    /// data.push(22);
+    /// data.append(&mut 1000u16.to_le_bytes().to_vec());
+    /// data.push(0x12);
+    ///
+    /// let into_data: Vec<u8> = result1.into();
+    /// assert_eq!(data, into_data);
+    ///
+    /// let result2 = IndoorPositioning::try_from(&data);
+    /// assert!(result2.is_ok());
+    /// let data_type = result2.unwrap();
+    /// let into_data: Vec<u8> = data_type.into();
+    /// assert_eq!(data, into_data);
+    /// ```
+    fn from(value: IndoorPositioning) -> Self {
+        let mut data: Vec<u8> = Vec::new();
+        data.push(value.length);
+        data.push(IndoorPositioning::data_type());
+        data.push(value.configuration);
+        match value.coordinates {
+            Some(IndoorPositioningCoordinates::Wgs84 {
+                latitude,
+                longitude,
+            }) => {
+                data.append(&mut latitude.to_le_bytes().to_vec());
+                data.append(&mut longitude.to_le_bytes().to_vec());
+            }
+            Some(IndoorPositioningCoordinates::Local { north, east }) => {
+                data.append(&mut north.to_le_bytes().to_vec());
+                data.append(&mut east.to_le_bytes().to_vec());
+            }
+            None => (),
+        }
+        if let Some(tx_power) = value.tx_power {
+            data.push(tx_power as u8);
+        }
+        if let Some(floor_number) = value.floor_number {
+            data.push(floor_number);
+        }
+        if let Some(altitude) = value.altitude {
+            data.append(&mut altitude.to_le_bytes().to_vec());
+        }
+        if let Some(uncertainty) = value.uncertainty {
+            data.push(uncertainty);
+        }
+        data
+    }
+}
+
+impl DataType for IndoorPositioning {
+    /// return `0x25`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{indoor_positioning::IndoorPositioning, data_type::DataType};
+    ///
+    /// assert_eq!(0x25, IndoorPositioning::data_type());
+    /// ```
+    fn data_type() -> u8 {
+        0x25
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data_types::{data_type::DataType, indoor_positioning::*};
+
+    #[test]
+    fn test_coordinates() {
+        let result = IndoorPositioningCoordinates::from_degrees(35.6812362, 139.7671248);
+        assert_eq!(
+            IndoorPositioningCoordinates::Wgs84 {
+                latitude: 356812362,
+                longitude: 1397671248
+            },
+            result
+        );
+        assert!((35.6812362 - result.latitude_degrees().unwrap()).abs() < 1e-9);
+        assert!((139.7671248 - result.longitude_degrees().unwrap()).abs() < 1e-9);
+        assert_eq!(None, result.north_meters());
+        assert_eq!(None, result.east_meters());
+
+        let result = IndoorPositioningCoordinates::from_degrees(-90.0, -180.0);
+        assert_eq!(
+            IndoorPositioningCoordinates::Wgs84 {
+                latitude: -900000000,
+                longitude: -1800000000
+            },
+            result
+        );
+
+        let result = IndoorPositioningCoordinates::from_meters(12.3, -4.5);
+        assert_eq!(
+            IndoorPositioningCoordinates::Local {
+                north: 123,
+                east: -45
+            },
+            result
+        );
+        assert!((12.3 - result.north_meters().unwrap()).abs() < 1e-9);
+        assert!((-4.5 - result.east_meters().unwrap()).abs() < 1e-9);
+        assert_eq!(None, result.latitude_degrees());
+        assert_eq!(None, result.longitude_degrees());
+    }
+
+    #[test]
+    fn test_new() {
+        let result = IndoorPositioning::new(None, None, None, None, None, false);
+        assert_eq!(2, result.length);
+        assert_eq!(0, result.configuration);
+
+        let coordinates = IndoorPositioningCoordinates::Wgs84 {
+            latitude: 1,
+            longitude: 2,
+        };
+        let result = IndoorPositioning::new(
+            Some(coordinates),
+            Some(-4),
+            Some(22),
+            Some(1000),
+            Some(3),
+            true,
+        );
+        assert_eq!(15, result.length);
+        assert_eq!(0b0111_1101, result.configuration);
+        assert_eq!(Some(coordinates), result.coordinates);
+        assert_eq!(Some(-4), result.tx_power);
+        assert_eq!(Some(22), result.floor_number);
+        assert_eq!(Some(1000), result.altitude);
+        assert_eq!(Some(3), result.uncertainty);
+        assert!(result.is_location_name_available());
+
+        let coordinates = IndoorPositioningCoordinates::Local { north: 1, east: 2 };
+        let result = IndoorPositioning::new(Some(coordinates), None, None, None, None, false);
+        assert_eq!(6, result.length);
+        assert_eq!(0b0000_0011, result.configuration);
+        assert!(!result.is_location_name_available());
+    }
+
+    #[test]
+    fn test_try_from() {
+        let mut data: Vec<u8> = vec![15, IndoorPositioning::data_type(), 0b0011_1101];
+        data.append(&mut 356812362i32.to_le_bytes().to_vec());
+        data.append(&mut (-1397671248i32).to_le_bytes().to_vec());
+        data.push(0xfc);
+        data.push(22);
+        data.append(&mut 1000u16.to_le_bytes().to_vec());
+        data.push(0x12);
+
+        let result = IndoorPositioning::try_from(&data);
+        assert!(result.is_ok());
+        let data_type = result.unwrap();
+        assert_eq!(15, data_type.length);
+        assert_eq!(0b0011_1101, data_type.configuration);
+        assert_eq!(
+            Some(IndoorPositioningCoordinates::Wgs84 {
+                latitude: 356812362,
+                longitude: -1397671248
+            }),
+            data_type.coordinates
+        );
+        assert_eq!(Some(-4), data_type.tx_power);
+        assert_eq!(Some(22), data_type.floor_number);
+        assert_eq!(Some(1000), data_type.altitude);
+        assert_eq!(Some(0x12), data_type.uncertainty);
+
+        let data: Vec<u8> = vec![2, IndoorPositioning::data_type(), 0b0100_0000];
+        let result = IndoorPositioning::try_from(&data).unwrap();
+        assert_eq!(None, result.coordinates);
+        assert_eq!(None, result.tx_power);
+        assert!(result.is_location_name_available());
+
+        let data: Vec<u8> = vec![3, IndoorPositioning::data_type(), 0b0000_0100, 0xfc, 0x00];
+        let result = IndoorPositioning::try_from(&data).unwrap();
+        assert_eq!(Some(-4), result.tx_power);
+
+        let data: Vec<u8> = vec![2, IndoorPositioning::data_type(), 0b0000_0100, 0xfc];
+        let result = IndoorPositioning::try_from(&data);
+        assert_eq!(Err("Invalid data size :4".to_string()), result);
+
+        let data: Vec<u8> = vec![4, IndoorPositioning::data_type(), 0b0000_0011, 0x01, 0x00];
+        let result = IndoorPositioning::try_from(&data);
+        assert_eq!(Err("Invalid data size :5".to_string()), result);
+
+        let data: Vec<u8> = vec![1, IndoorPositioning::data_type()];
+        let result = IndoorPositioning::try_from(&data);
+        assert!(result.is_err());
+        assert_eq!(
+            format!("Invalid data size :{}", data.len()),
+            result.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_into() {
+        let coordinates = IndoorPositioningCoordinates::Local {
+            north: 123,
+            east: -45,
+        };
+        let result1 = IndoorPositioning::new(
+            Some(coordinates),
+            None,
+            Some(22),
+            Some(1000),
+            Some(0x12),
+            false,
+        );
+
+        let mut data: Vec<u8> = vec![10, IndoorPositioning::data_type(), 0b0011_1011];
+        data.append(&mut 123i16.to_le_bytes().to_vec());
+        data.append(&mut (-45i16).to_le_bytes().to_vec());
+        data.push(22);
+        data.append(&mut 1000u16.to_le_bytes().to_vec());
+        data.push(0x12);
+
+        let into_data: Vec<u8> = result1.into();
+        assert_eq!(data, into_data);
+
+        let result2 = IndoorPositioning::try_from(&data);
+        assert!(result2.is_ok());
+        let data_type = result2.unwrap();
+        let into_data: Vec<u8> = data_type.into();
+        assert_eq!(data, into_data);
+
+        let result1 = IndoorPositioning::new(
+            Some(IndoorPositioningCoordinates::Wgs84 {
+                latitude: -1,
+                longitude: 1,
+            }),
+            Some(-10),
+            None,
+            None,
+            None,
+            true,
+        );
+        let data: Vec<u8> = vec![
+            11,
+            IndoorPositioning::data_type(),
+            0b0100_0101,
+            0xff,
+            0xff,
+            0xff,
+            0xff,
+            0x01,
+            0x00,
+            0x00,
+            0x00,
+            0xf6,
+        ];
+        let into_data: Vec<u8> = result1.into();
+        assert_eq!(data, into_data);
+    }
+
+    #[test]
+    fn test_data_type() {
+        assert_eq!(0x25, IndoorPositioning::data_type());
+    }
+}
@@ -0,0 +1,81 @@
+//! Offset units module.
+
+/// Offset units of BIG_Offset_Units, AuxPtr Offset Units and SyncInfo Offset Units.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum IntervalUnits {
+    /// 30 µs (Offset Units = 0)
+    Units30Us,
+
+    /// 300 µs (Offset Units = 1)
+    Units300Us,
+}
+
+impl IntervalUnits {
+    /// Get unit size in microseconds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::interval_units::IntervalUnits;
+    ///
+    /// assert_eq!(30, IntervalUnits::Units30Us.micros());
+    /// assert_eq!(300, IntervalUnits::Units300Us.micros());
+    /// ```
+    pub fn micros(&self) -> u32 {
+        match self {
+            IntervalUnits::Units30Us => 30,
+            IntervalUnits::Units300Us => 300,
+        }
+    }
+}
+
+impl From<bool> for IntervalUnits {
+    /// Create [`IntervalUnits`] from Offset Units bit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::interval_units::IntervalUnits;
+    ///
+    /// assert_eq!(IntervalUnits::Units30Us, IntervalUnits::from(false));
+    /// assert_eq!(IntervalUnits::Units300Us, IntervalUnits::from(true));
+    /// ```
+    fn from(value: bool) -> Self {
+        if value {
+            IntervalUnits::Units300Us
+        } else {
+            IntervalUnits::Units30Us
+        }
+    }
+}
+
+impl From<IntervalUnits> for bool {
+    /// Create Offset Units bit from [`IntervalUnits`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::interval_units::IntervalUnits;
+    ///
+    /// assert!(!bool::from(IntervalUnits::Units30Us));
+    /// assert!(bool::from(IntervalUnits::Units300Us));
+    /// ```
+    fn from(value: IntervalUnits) -> Self {
+        value == IntervalUnits::Units300Us
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data_types::interval_units::IntervalUnits;
+
+    #[test]
+    fn test_interval_units() {
+        assert_eq!(30, IntervalUnits::Units30Us.micros());
+        assert_eq!(300, IntervalUnits::Units300Us.micros());
+        assert_eq!(IntervalUnits::Units30Us, IntervalUnits::from(false));
+        assert_eq!(IntervalUnits::Units300Us, IntervalUnits::from(true));
+        assert!(!bool::from(IntervalUnits::Units30Us));
+        assert!(bool::from(IntervalUnits::Units300Us));
+    }
+}
@@ -1,6 +1,12 @@
 //! LE Bluetooth Device Address (Data Type Value:0x1b) module.
 
-use crate::data_types::data_type::DataType;
+#[cfg(feature = "oui")]
+use crate::oui;
+
+use crate::data_types::{
+    ad_type::AdType,
+    data_type::{validate_length, DataType},
+};
 
 /// LE Bluetooth Device Address.
 
@@ -44,7 +50,7 @@ impl LeBluetoothDeviceAddress {
     /// );
     /// assert_eq!(address_type, result.address_type);
     /// ```
-    pub fn new(le_bluetooth_device_address: u64, address_type: bool) -> Self {
+    pub const fn new(le_bluetooth_device_address: u64, address_type: bool) -> Self {
         Self {
             length: 8,
             le_bluetooth_device_address,
@@ -72,11 +78,35 @@ impl LeBluetoothDeviceAddress {
     pub const fn is_random_address(&self) -> bool {
         self.address_type
     }
+
+    /// Get vendor name of public address from OUI.
+    ///
+    /// returns [`None`] for random address.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::le_bluetooth_device_address::LeBluetoothDeviceAddress;
+    ///
+    /// let result = LeBluetoothDeviceAddress::new(0x0000b827eb010203u64, false);
+    /// assert_eq!(Some("Raspberry Pi Foundation"), result.vendor_name());
+    ///
+    /// let result = LeBluetoothDeviceAddress::new(0x0000b827eb010203u64, true);
+    /// assert_eq!(None, result.vendor_name());
+    /// ```
+    #[cfg(feature = "oui")]
+    pub fn vendor_name(&self) -> Option<&'static str> {
+        if self.address_type {
+            None
+        } else {
+            oui::vendor_name(self.le_bluetooth_device_address)
+        }
+    }
 }
 
-impl TryFrom<&Vec<u8>> for LeBluetoothDeviceAddress {
+impl TryFrom<&[u8]> for LeBluetoothDeviceAddress {
     type Error = String;
-    /// Create [`LeBluetoothDeviceAddress`] from [`Vec<u8>`].
+    /// Create [`LeBluetoothDeviceAddress`] from `u8` slice.
     ///
     /// # Examples
     ///
@@ -92,7 +122,7 @@ impl TryFrom<&Vec<u8>> for LeBluetoothDeviceAddress {
     /// data.append(&mut le_bluetooth_device_address.clone().to_le_bytes()[..6].to_vec());
     /// data.push(u8::from(address_type));
     ///
-    /// let result = LeBluetoothDeviceAddress::try_from(&data);
+    /// let result = LeBluetoothDeviceAddress::try_from(data.as_slice());
     /// assert!(result.is_ok());
     /// let data_type = result.unwrap();
     /// assert_eq!(length, data_type.length);
@@ -111,7 +141,7 @@ impl TryFrom<&Vec<u8>> for LeBluetoothDeviceAddress {
     /// data.append(&mut le_bluetooth_device_address.clone().to_le_bytes()[..6].to_vec());
     /// data.push(u8::from(address_type));
     ///
-    /// let result = LeBluetoothDeviceAddress::try_from(&data);
+    /// let result = LeBluetoothDeviceAddress::try_from(data.as_slice());
     /// assert!(result.is_ok());
     /// let data_type = result.unwrap();
     /// assert_eq!(length, data_type.length);
@@ -122,19 +152,19 @@ impl TryFrom<&Vec<u8>> for LeBluetoothDeviceAddress {
     /// assert_eq!(address_type, data_type.address_type);
     ///
     /// let data: Vec<u8> = Vec::new();
-    /// let result = LeBluetoothDeviceAddress::try_from(&data);
+    /// let result = LeBluetoothDeviceAddress::try_from(data.as_slice());
     /// assert!(result.is_err());
     /// assert_eq!(
     ///     format!("Invalid data size :{}", data.len()),
     ///     result.unwrap_err()
     /// );
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+    fn try_from(value: &[u8]) -> Result<Self, String> {
         let len = value.len();
         if len < 8 {
             return Err(format!("Invalid data size :{}", len).to_string());
         }
-        let length = value[0];
+        let length = validate_length(value, 8, 8)?;
         let mut bytes = [0x00u8; 8];
         bytes[0] = value[2];
         bytes[1] = value[3];
@@ -150,6 +180,66 @@ impl TryFrom<&Vec<u8>> for LeBluetoothDeviceAddress {
     }
 }
 
+impl TryFrom<&Vec<u8>> for LeBluetoothDeviceAddress {
+    type Error = String;
+    /// Create [`LeBluetoothDeviceAddress`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{le_bluetooth_device_address::LeBluetoothDeviceAddress, data_type::DataType};
+    ///
+    /// let le_bluetooth_device_address = 0x0000060504030201u64;
+    /// let address_type = false;
+    /// let length = 8;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(LeBluetoothDeviceAddress::data_type());
+    /// data.append(&mut le_bluetooth_device_address.clone().to_le_bytes()[..6].to_vec());
+    /// data.push(u8::from(address_type));
+    ///
+    /// let result = LeBluetoothDeviceAddress::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert_eq!(
+    ///     le_bluetooth_device_address,
+    ///     data_type.le_bluetooth_device_address
+    /// );
+    /// assert_eq!(address_type, data_type.address_type);
+    ///
+    /// let le_bluetooth_device_address = 0x0000060504030201u64;
+    /// let address_type = true;
+    /// let length = 8;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(LeBluetoothDeviceAddress::data_type());
+    /// data.append(&mut le_bluetooth_device_address.clone().to_le_bytes()[..6].to_vec());
+    /// data.push(u8::from(address_type));
+    ///
+    /// let result = LeBluetoothDeviceAddress::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert_eq!(
+    ///     le_bluetooth_device_address,
+    ///     data_type.le_bluetooth_device_address
+    /// );
+    /// assert_eq!(address_type, data_type.address_type);
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = LeBluetoothDeviceAddress::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        Self::try_from(value.as_slice())
+    }
+}
+
 /// Address type
 /// (0 = Public Address, 1 = Random Address)
 ///
@@ -219,8 +309,9 @@ impl DataType for LeBluetoothDeviceAddress {
 /// assert!(is_le_bluetooth_device_address(0x1b));
 /// assert!(!is_le_bluetooth_device_address(0x00));
 /// ```
+#[deprecated(note = "use `AdType::of(data_type).matches::<LeBluetoothDeviceAddress>()` instead")]
 pub fn is_le_bluetooth_device_address(data_type: u8) -> bool {
-    LeBluetoothDeviceAddress::data_type() == data_type
+    AdType::of(data_type).matches::<LeBluetoothDeviceAddress>()
 }
 
 #[cfg(test)]
@@ -263,6 +354,45 @@ mod tests {
         assert_eq!(address_type, result.is_random_address());
     }
 
+    #[cfg(feature = "oui")]
+    #[test]
+    fn test_vendor_name() {
+        let result = LeBluetoothDeviceAddress::new(0x0000dca632aabbccu64, false);
+        assert_eq!(Some("Raspberry Pi Trading Ltd"), result.vendor_name());
+
+        let result = LeBluetoothDeviceAddress::new(0x0000000000aabbccu64, false);
+        assert_eq!(None, result.vendor_name());
+
+        let result = LeBluetoothDeviceAddress::new(0x0000dca632aabbccu64, true);
+        assert_eq!(None, result.vendor_name());
+    }
+
+    #[test]
+    fn test_try_from_length_consistency() {
+        let data: Vec<u8> = vec![8, LeBluetoothDeviceAddress::data_type(), 1, 2, 3, 4, 5, 6];
+        let result = LeBluetoothDeviceAddress::try_from(&data);
+        assert!(result.is_err());
+        assert_eq!(
+            format!("Invalid data size :{}", data.len()),
+            result.unwrap_err()
+        );
+
+        let data: Vec<u8> = vec![
+            7,
+            LeBluetoothDeviceAddress::data_type(),
+            1,
+            2,
+            3,
+            4,
+            5,
+            6,
+            0,
+        ];
+        let result = LeBluetoothDeviceAddress::try_from(&data);
+        assert!(result.is_err());
+        assert_eq!("Invalid length :7", result.unwrap_err());
+    }
+
     #[test]
     fn test_try_from() {
         let le_bluetooth_device_address = 0x0000060504030201u64;
@@ -342,6 +472,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_is_le_bluetooth_device_address() {
         assert!(is_le_bluetooth_device_address(0x1b));
         assert!(!is_le_bluetooth_device_address(0x00));
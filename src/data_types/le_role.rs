@@ -1,6 +1,9 @@
 //! LE Role (Data Type Value: 0x1c) module.
 
-use crate::data_types::data_type::DataType;
+use crate::data_types::{
+    ad_type::AdType,
+    data_type::{validate_length, DataType},
+};
 
 /// LE Role.
 #[derive(Debug, PartialEq, Clone)]
@@ -40,7 +43,7 @@ impl LeRole {
     /// assert_eq!(2, result.length);
     /// assert_eq!(le_role, result.le_role);
     /// ```
-    pub fn new(le_role: u8) -> Self {
+    pub const fn new(le_role: u8) -> Self {
         Self { length: 2, le_role }
     }
 
@@ -165,9 +168,9 @@ pub const PERIPHERAL_ROLE_PREFERRED_FOR_CONNECTION_STABLISHMENT: u8 = 0x02;
 /// Peripheral and Central Role supported, Central Role preferred for connection establishment
 pub const CENTRAL_ROLE_PREFERRED_FOR_CONNECTION_STABLISHMENT: u8 = 0x03;
 
-impl TryFrom<&Vec<u8>> for LeRole {
+impl TryFrom<&[u8]> for LeRole {
     type Error = String;
-    /// Create [LE Role] from [`Vec<u8>`].
+    /// Create [`LeRole`] from `u8` slice.
     ///
     /// # Examples
     ///
@@ -181,7 +184,7 @@ impl TryFrom<&Vec<u8>> for LeRole {
     /// data.push(LeRole::data_type());
     /// data.push(le_role);
     ///
-    /// let result = LeRole::try_from(&data);
+    /// let result = LeRole::try_from(data.as_slice());
     /// assert!(result.is_ok());
     /// let data_type = result.unwrap();
     /// assert_eq!(length, data_type.length);
@@ -194,7 +197,7 @@ impl TryFrom<&Vec<u8>> for LeRole {
     /// data.push(LeRole::data_type());
     /// data.push(le_role);
     ///
-    /// let result = LeRole::try_from(&data);
+    /// let result = LeRole::try_from(data.as_slice());
     /// assert!(result.is_ok());
     /// let data_type = result.unwrap();
     /// assert_eq!(length, data_type.length);
@@ -207,7 +210,7 @@ impl TryFrom<&Vec<u8>> for LeRole {
     /// data.push(LeRole::data_type());
     /// data.push(le_role);
     ///
-    /// let result = LeRole::try_from(&data);
+    /// let result = LeRole::try_from(data.as_slice());
     /// assert!(result.is_ok());
     /// let data_type = result.unwrap();
     /// assert_eq!(length, data_type.length);
@@ -220,26 +223,26 @@ impl TryFrom<&Vec<u8>> for LeRole {
     /// data.push(LeRole::data_type());
     /// data.push(le_role);
     ///
-    /// let result = LeRole::try_from(&data);
+    /// let result = LeRole::try_from(data.as_slice());
     /// assert!(result.is_ok());
     /// let data_type = result.unwrap();
     /// assert_eq!(length, data_type.length);
     /// assert_eq!(le_role, data_type.le_role);
     ///
     /// let data: Vec<u8> = Vec::new();
-    /// let result = LeRole::try_from(&data);
+    /// let result = LeRole::try_from(data.as_slice());
     /// assert!(result.is_err());
     /// assert_eq!(
     ///     format!("Invalid data size :{}", data.len()),
     ///     result.unwrap_err()
     /// );
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+    fn try_from(value: &[u8]) -> Result<Self, String> {
         let len = value.len();
         if len < 3 {
             return Err(format!("Invalid data size :{}", len).to_string());
         }
-        let length = value[0];
+        let length = validate_length(value, 2, 2)?;
         Ok(Self {
             length,
             le_role: value[2],
@@ -247,6 +250,80 @@ impl TryFrom<&Vec<u8>> for LeRole {
     }
 }
 
+impl TryFrom<&Vec<u8>> for LeRole {
+    type Error = String;
+    /// Create [LE Role] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{le_role::*, data_type::DataType};
+    ///
+    /// let le_role = ONLY_PERIPHERAL_ROLE_SUPPORTED;
+    /// let length = 2;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(LeRole::data_type());
+    /// data.push(le_role);
+    ///
+    /// let result = LeRole::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert_eq!(le_role, data_type.le_role);
+    ///
+    /// let le_role = ONLY_CENTRAL_ROLE_SUPPORTED;
+    /// let length = 2;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(LeRole::data_type());
+    /// data.push(le_role);
+    ///
+    /// let result = LeRole::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert_eq!(le_role, data_type.le_role);
+    ///
+    /// let le_role = PERIPHERAL_ROLE_PREFERRED_FOR_CONNECTION_STABLISHMENT;
+    /// let length = 2;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(LeRole::data_type());
+    /// data.push(le_role);
+    ///
+    /// let result = LeRole::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert_eq!(le_role, data_type.le_role);
+    ///
+    /// let le_role = CENTRAL_ROLE_PREFERRED_FOR_CONNECTION_STABLISHMENT;
+    /// let length = 2;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(LeRole::data_type());
+    /// data.push(le_role);
+    ///
+    /// let result = LeRole::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert_eq!(le_role, data_type.le_role);
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = LeRole::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        Self::try_from(value.as_slice())
+    }
+}
+
 impl Into<Vec<u8>> for LeRole {
     /// Create [`Vec<u8>`] from [LeRole].
     ///
@@ -362,8 +439,9 @@ impl DataType for LeRole {
 /// assert!(is_le_role(0x1c));
 /// assert!(!is_le_role(0x00));
 /// ```
+#[deprecated(note = "use `AdType::of(data_type).matches::<LeRole>()` instead")]
 pub fn is_le_role(data_type: u8) -> bool {
-    LeRole::data_type() == data_type
+    AdType::of(data_type).matches::<LeRole>()
 }
 
 #[cfg(test)]
@@ -393,6 +471,12 @@ mod tests {
         assert_eq!(le_role, result.le_role);
     }
 
+    #[test]
+    fn test_new_const() {
+        const LE_ROLE: LeRole = LeRole::new(ONLY_PERIPHERAL_ROLE_SUPPORTED);
+        assert_eq!(LeRole::new(ONLY_PERIPHERAL_ROLE_SUPPORTED), LE_ROLE);
+    }
+
     #[test]
     fn test_is_only_peripheral_role_supported() {
         let le_role = ONLY_PERIPHERAL_ROLE_SUPPORTED;
@@ -614,6 +698,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_is_le_role() {
         assert!(is_le_role(0x1c));
         assert!(!is_le_role(0x00));
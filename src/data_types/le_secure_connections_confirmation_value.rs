@@ -1,6 +1,9 @@
 //! LE Secure Connections Confirmation Value (Data Type Value: 0x22) module.
 
-use crate::data_types::data_type::DataType;
+use crate::data_types::{
+    ad_type::AdType,
+    data_type::{validate_length, DataType},
+};
 
 /// LE Secure Connections Confirmation Value.
 
@@ -26,7 +29,7 @@ impl LeSecureConnectionsConfirmationValue {
     /// assert_eq!(17, result.length);
     /// assert_eq!(le_secure_connections_confirmation_value, result.le_secure_connections_confirmation_value);
     /// ```
-    pub fn new(le_secure_connections_confirmation_value: u128) -> Self {
+    pub const fn new(le_secure_connections_confirmation_value: u128) -> Self {
         Self {
             length: 17,
             le_secure_connections_confirmation_value,
@@ -34,9 +37,9 @@ impl LeSecureConnectionsConfirmationValue {
     }
 }
 
-impl TryFrom<&Vec<u8>> for LeSecureConnectionsConfirmationValue {
+impl TryFrom<&[u8]> for LeSecureConnectionsConfirmationValue {
     type Error = String;
-    /// Create [`LeSecureConnectionsConfirmationValue`] from [`Vec<u8>`].
+    /// Create [`LeSecureConnectionsConfirmationValue`] from `u8` slice.
     ///
     /// # Examples
     ///
@@ -54,7 +57,7 @@ impl TryFrom<&Vec<u8>> for LeSecureConnectionsConfirmationValue {
     ///         .to_vec(),
     /// );
     ///
-    /// let result = LeSecureConnectionsConfirmationValue::try_from(&data);
+    /// let result = LeSecureConnectionsConfirmationValue::try_from(data.as_slice());
     /// assert!(result.is_ok());
     /// let data_type = result.unwrap();
     /// assert_eq!(length, data_type.length);
@@ -64,19 +67,19 @@ impl TryFrom<&Vec<u8>> for LeSecureConnectionsConfirmationValue {
     /// );
     ///
     /// let data: Vec<u8> = Vec::new();
-    /// let result = LeSecureConnectionsConfirmationValue::try_from(&data);
+    /// let result = LeSecureConnectionsConfirmationValue::try_from(data.as_slice());
     /// assert!(result.is_err());
     /// assert_eq!(
     ///     format!("Invalid data size :{}", data.len()),
     ///     result.unwrap_err()
     /// );
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+    fn try_from(value: &[u8]) -> Result<Self, String> {
         let len = value.len();
         if len < 18 {
             return Err(format!("Invalid data size :{}", len).to_string());
         }
-        let length = value[0];
+        let length = validate_length(value, 17, 17)?;
         Ok(Self {
             length,
             le_secure_connections_confirmation_value: u128::from_le_bytes(
@@ -86,6 +89,48 @@ impl TryFrom<&Vec<u8>> for LeSecureConnectionsConfirmationValue {
     }
 }
 
+impl TryFrom<&Vec<u8>> for LeSecureConnectionsConfirmationValue {
+    type Error = String;
+    /// Create [`LeSecureConnectionsConfirmationValue`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{BASE_UUID, data_types::{le_secure_connections_confirmation_value::LeSecureConnectionsConfirmationValue, data_type::DataType}};
+    ///
+    /// let le_secure_connections_confirmation_value = 0x0102030405060708090a0b0c0d0e0f10u128;
+    /// let length = 17;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(LeSecureConnectionsConfirmationValue::data_type());
+    /// data.append(
+    ///     &mut le_secure_connections_confirmation_value
+    ///         .to_le_bytes()
+    ///         .to_vec(),
+    /// );
+    ///
+    /// let result = LeSecureConnectionsConfirmationValue::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert_eq!(
+    ///     le_secure_connections_confirmation_value,
+    ///     data_type.le_secure_connections_confirmation_value
+    /// );
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = LeSecureConnectionsConfirmationValue::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        Self::try_from(value.as_slice())
+    }
+}
+
 impl Into<Vec<u8>> for LeSecureConnectionsConfirmationValue {
     /// Create [`Vec<u8>`] from [`LeSecureConnectionsConfirmationValue`].
     ///
@@ -157,8 +202,11 @@ impl DataType for LeSecureConnectionsConfirmationValue {
 /// assert!(is_le_secure_connections_confirmation_value(0x22));
 /// assert!(!is_le_secure_connections_confirmation_value(0x00));
 /// ```
+#[deprecated(
+    note = "use `AdType::of(data_type).matches::<LeSecureConnectionsConfirmationValue>()` instead"
+)]
 pub fn is_le_secure_connections_confirmation_value(data_type: u8) -> bool {
-    LeSecureConnectionsConfirmationValue::data_type() == data_type
+    AdType::of(data_type).matches::<LeSecureConnectionsConfirmationValue>()
 }
 
 #[cfg(test)]
@@ -241,6 +289,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_is_le_secure_connections_confirmation_value() {
         assert!(is_le_secure_connections_confirmation_value(0x22));
         assert!(!is_le_secure_connections_confirmation_value(0x00));
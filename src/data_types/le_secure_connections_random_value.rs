@@ -1,6 +1,9 @@
 //! LE Secure Connections Random Value (Data Type Value: 0x23) module.
 
-use crate::data_types::data_type::DataType;
+use crate::data_types::{
+    ad_type::AdType,
+    data_type::{validate_length, DataType},
+};
 
 /// LE Secure Connections Random Value.
 
@@ -26,7 +29,7 @@ impl LeSecureConnectionsRandomValue {
     /// assert_eq!(17, result.length);
     /// assert_eq!(le_secure_connections_random_value, result.le_secure_connections_random_value);
     /// ```
-    pub fn new(le_secure_connections_random_value: u128) -> Self {
+    pub const fn new(le_secure_connections_random_value: u128) -> Self {
         Self {
             length: 17,
             le_secure_connections_random_value,
@@ -34,9 +37,9 @@ impl LeSecureConnectionsRandomValue {
     }
 }
 
-impl TryFrom<&Vec<u8>> for LeSecureConnectionsRandomValue {
+impl TryFrom<&[u8]> for LeSecureConnectionsRandomValue {
     type Error = String;
-    /// Create [`LeSecureConnectionsRandomValue`] from [`Vec<u8>`].
+    /// Create [`LeSecureConnectionsRandomValue`] from `u8` slice.
     ///
     /// # Examples
     ///
@@ -50,7 +53,7 @@ impl TryFrom<&Vec<u8>> for LeSecureConnectionsRandomValue {
     /// data.push(LeSecureConnectionsRandomValue::data_type());
     /// data.append(&mut le_secure_connections_random_value.to_le_bytes().to_vec());
     ///
-    /// let result = LeSecureConnectionsRandomValue::try_from(&data);
+    /// let result = LeSecureConnectionsRandomValue::try_from(data.as_slice());
     /// assert!(result.is_ok());
     /// let data_type = result.unwrap();
     /// assert_eq!(length, data_type.length);
@@ -60,19 +63,19 @@ impl TryFrom<&Vec<u8>> for LeSecureConnectionsRandomValue {
     /// );
     ///
     /// let data: Vec<u8> = Vec::new();
-    /// let result = LeSecureConnectionsRandomValue::try_from(&data);
+    /// let result = LeSecureConnectionsRandomValue::try_from(data.as_slice());
     /// assert!(result.is_err());
     /// assert_eq!(
     ///     format!("Invalid data size :{}", data.len()),
     ///     result.unwrap_err()
     /// );
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+    fn try_from(value: &[u8]) -> Result<Self, String> {
         let len = value.len();
         if len < 18 {
             return Err(format!("Invalid data size :{}", len).to_string());
         }
-        let length = value[0];
+        let length = validate_length(value, 17, 17)?;
         Ok(Self {
             length,
             le_secure_connections_random_value: u128::from_le_bytes(
@@ -82,6 +85,44 @@ impl TryFrom<&Vec<u8>> for LeSecureConnectionsRandomValue {
     }
 }
 
+impl TryFrom<&Vec<u8>> for LeSecureConnectionsRandomValue {
+    type Error = String;
+    /// Create [`LeSecureConnectionsRandomValue`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{BASE_UUID, data_types::{le_secure_connections_random_value::LeSecureConnectionsRandomValue, data_type::DataType}};
+    ///
+    /// let le_secure_connections_random_value = 0x0102030405060708090a0b0c0d0e0f10u128;
+    /// let length = 17;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(LeSecureConnectionsRandomValue::data_type());
+    /// data.append(&mut le_secure_connections_random_value.to_le_bytes().to_vec());
+    ///
+    /// let result = LeSecureConnectionsRandomValue::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert_eq!(
+    ///     le_secure_connections_random_value,
+    ///     data_type.le_secure_connections_random_value
+    /// );
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = LeSecureConnectionsRandomValue::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        Self::try_from(value.as_slice())
+    }
+}
+
 impl Into<Vec<u8>> for LeSecureConnectionsRandomValue {
     /// Create [`Vec<u8>`] from [`LeSecureConnectionsRandomValue`].
     ///
@@ -148,8 +189,11 @@ impl DataType for LeSecureConnectionsRandomValue {
 /// assert!(is_le_secure_connections_random_value(0x23));
 /// assert!(!is_le_secure_connections_random_value(0x00));
 /// ```
+#[deprecated(
+    note = "use `AdType::of(data_type).matches::<LeSecureConnectionsRandomValue>()` instead"
+)]
 pub fn is_le_secure_connections_random_value(data_type: u8) -> bool {
-    LeSecureConnectionsRandomValue::data_type() == data_type
+    AdType::of(data_type).matches::<LeSecureConnectionsRandomValue>()
 }
 
 #[cfg(test)]
@@ -222,6 +266,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_is_le_secure_connections_random_value() {
         assert!(is_le_secure_connections_random_value(0x23));
         assert!(!is_le_secure_connections_random_value(0x00));
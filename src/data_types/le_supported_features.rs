@@ -1,6 +1,77 @@
 //! LE Supported Features (Data Type Value: 0x27) module.
 
-use crate::data_types::data_type::DataType;
+use crate::data_types::{
+    ad_type::AdType,
+    data_type::{validate_length, DataType},
+};
+
+/// LE Feature names (indexed by bit number).
+const LE_FEATURE_NAMES: [&str; 49] = [
+    "LE Encryption",
+    "Connection Parameters Request Procedure",
+    "Extended Reject Indication",
+    "Peripheral-initiated Features Exchange",
+    "LE Ping",
+    "LE Data Packet Length Extension",
+    "LL Privacy",
+    "Extended Scanning Filter Policies",
+    "LE 2M PHY",
+    "Stable Modulation Index - Transmitter",
+    "Stable Modulation Index - Receiver",
+    "LE Coded PHY",
+    "LE Extended Advertising",
+    "LE Periodic Advertising",
+    "Channel Selection Algorithm #2",
+    "LE Power Class 1",
+    "Minimum Number of Used Channels Procedure",
+    "Connection CTE Request",
+    "Connection CTE Response",
+    "Connectionless CTE Transmitter",
+    "Connectionless CTE Receiver",
+    "Antenna Switching During CTE Transmission (AoD)",
+    "Antenna Switching During CTE Reception (AoA)",
+    "Receiving Constant Tone Extensions",
+    "Periodic Advertising Sync Transfer - Sender",
+    "Periodic Advertising Sync Transfer - Recipient",
+    "Sleep Clock Accuracy Updates",
+    "Remote Public Key Validation",
+    "Connected Isochronous Stream - Central",
+    "Connected Isochronous Stream - Peripheral",
+    "Isochronous Broadcaster",
+    "Synchronized Receiver",
+    "Connected Isochronous Stream (Host Support)",
+    "LE Power Control Request",
+    "LE Power Control Request",
+    "LE Path Loss Monitoring",
+    "Periodic Advertising ADI support",
+    "Connection Subrating",
+    "Connection Subrating (Host Support)",
+    "Channel Classification",
+    "Advertising Coding Selection",
+    "Advertising Coding Selection (Host Support)",
+    "Decision-Based Advertising Filtering",
+    "Periodic Advertising with Responses - Advertiser",
+    "Periodic Advertising with Responses - Scanner",
+    "Unsegmented Framed Mode",
+    "Channel Sounding",
+    "Channel Sounding (Host Support)",
+    "Channel Sounding Tone Quality Indication",
+];
+
+/// Get LE Feature name from bit number.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::data_types::le_supported_features::le_feature_name;
+///
+/// assert_eq!(Some("LE 2M PHY"), le_feature_name(8));
+/// assert_eq!(Some("Isochronous Broadcaster"), le_feature_name(30));
+/// assert_eq!(None, le_feature_name(63));
+/// ```
+pub fn le_feature_name(bit: usize) -> Option<&'static str> {
+    LE_FEATURE_NAMES.get(bit).copied()
+}
 
 /// LE Supported Features.
 #[derive(Debug, PartialEq, Clone)]
@@ -698,6 +769,22 @@ impl LeSupportedFeatures {
         *self.le_supported_features.get(41).unwrap_or(&false)
     }
 
+    /// check Decision-Based Advertising Filtering Feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::le_supported_features::LeSupportedFeatures;
+    ///
+    /// let mut le_supported_features = [false; 56].to_vec();
+    /// le_supported_features[42] = true;
+    /// let result = LeSupportedFeatures::new(&le_supported_features);
+    /// assert!(result.is_decision_based_advertising_filtering_supported());
+    /// ```
+    pub fn is_decision_based_advertising_filtering_supported(&self) -> bool {
+        *self.le_supported_features.get(42).unwrap_or(&false)
+    }
+
     /// check Periodic Advertising with Responses - Advertiser Feature.
     ///
     /// # Examples
@@ -729,11 +816,106 @@ impl LeSupportedFeatures {
     pub fn is_periodic_advertising_with_responses_scanner_supported(&self) -> bool {
         *self.le_supported_features.get(44).unwrap_or(&false)
     }
+
+    /// check Unsegmented Framed Mode Feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::le_supported_features::LeSupportedFeatures;
+    ///
+    /// let mut le_supported_features = [false; 56].to_vec();
+    /// le_supported_features[45] = true;
+    /// let result = LeSupportedFeatures::new(&le_supported_features);
+    /// assert!(result.is_unsegmented_framed_mode_supported());
+    /// ```
+    pub fn is_unsegmented_framed_mode_supported(&self) -> bool {
+        *self.le_supported_features.get(45).unwrap_or(&false)
+    }
+
+    /// check Channel Sounding Feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::le_supported_features::LeSupportedFeatures;
+    ///
+    /// let mut le_supported_features = [false; 56].to_vec();
+    /// le_supported_features[46] = true;
+    /// let result = LeSupportedFeatures::new(&le_supported_features);
+    /// assert!(result.is_channel_sounding_supported());
+    /// ```
+    pub fn is_channel_sounding_supported(&self) -> bool {
+        *self.le_supported_features.get(46).unwrap_or(&false)
+    }
+
+    /// check Channel Sounding (Host Support) Feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::le_supported_features::LeSupportedFeatures;
+    ///
+    /// let mut le_supported_features = [false; 56].to_vec();
+    /// le_supported_features[47] = true;
+    /// let result = LeSupportedFeatures::new(&le_supported_features);
+    /// assert!(result.is_channel_sounding_host_support_supported());
+    /// ```
+    pub fn is_channel_sounding_host_support_supported(&self) -> bool {
+        *self.le_supported_features.get(47).unwrap_or(&false)
+    }
+
+    /// check Channel Sounding Tone Quality Indication Feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::le_supported_features::LeSupportedFeatures;
+    ///
+    /// let mut le_supported_features = [false; 56].to_vec();
+    /// le_supported_features[48] = true;
+    /// let result = LeSupportedFeatures::new(&le_supported_features);
+    /// assert!(result.is_channel_sounding_tone_quality_indication_supported());
+    /// ```
+    pub fn is_channel_sounding_tone_quality_indication_supported(&self) -> bool {
+        *self.le_supported_features.get(48).unwrap_or(&false)
+    }
+
+    /// Get supported (set) feature bits with names.
+    ///
+    /// Bits not defined in [`le_feature_name`] are returned with `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::le_supported_features::LeSupportedFeatures;
+    ///
+    /// let mut le_supported_features = [false; 64].to_vec();
+    /// le_supported_features[8] = true;
+    /// le_supported_features[12] = true;
+    /// le_supported_features[60] = true;
+    /// let result = LeSupportedFeatures::new(&le_supported_features);
+    /// assert_eq!(
+    ///     vec![
+    ///         (8, Some("LE 2M PHY")),
+    ///         (12, Some("LE Extended Advertising")),
+    ///         (60, None)
+    ///     ],
+    ///     result.supported_features().collect::<Vec<_>>()
+    /// );
+    /// ```
+    pub fn supported_features(&self) -> impl Iterator<Item = (usize, Option<&'static str>)> + '_ {
+        self.le_supported_features
+            .iter()
+            .enumerate()
+            .filter(|(_, supported)| **supported)
+            .map(|(bit, _)| (bit, le_feature_name(bit)))
+    }
 }
 
-impl TryFrom<&Vec<u8>> for LeSupportedFeatures {
+impl TryFrom<&[u8]> for LeSupportedFeatures {
     type Error = String;
-    /// Create [`LeSupportedFeatures`] from [`Vec<u8>`].
+    /// Create [`LeSupportedFeatures`] from `u8` slice.
     ///
     /// # Examples
     ///
@@ -751,7 +933,7 @@ impl TryFrom<&Vec<u8>> for LeSupportedFeatures {
     ///     data.push(LeSupportedFeatures::data_type());
     ///     data.append(&mut le_supported_features.clone());
     ///
-    ///     let result = LeSupportedFeatures::try_from(&data);
+    ///     let result = LeSupportedFeatures::try_from(data.as_slice());
     ///     assert!(result.is_ok());
     ///     let data_type = result.unwrap();
     ///     assert_eq!(length, data_type.length);
@@ -778,20 +960,20 @@ impl TryFrom<&Vec<u8>> for LeSupportedFeatures {
     /// }
     ///
     /// let data: Vec<u8> = Vec::new();
-    /// let result = LeSupportedFeatures::try_from(&data);
+    /// let result = LeSupportedFeatures::try_from(data.as_slice());
     /// assert!(result.is_err());
     /// assert_eq!(
     ///     format!("Invalid data size :{}", data.len()),
     ///     result.unwrap_err()
     /// );
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+    fn try_from(value: &[u8]) -> Result<Self, String> {
         let len = value.len();
         if len < 7 {
             return Err(format!("Invalid data size :{}", len).to_string());
         }
-        let length = value[0];
-        let le_supported_features: Vec<bool> = value[2..]
+        let length = validate_length(value, 1, 255)?;
+        let le_supported_features: Vec<bool> = value[2..1 + length as usize]
             .iter()
             .flat_map(|x| {
                 let mut data: Vec<bool> = Vec::new();
@@ -813,6 +995,65 @@ impl TryFrom<&Vec<u8>> for LeSupportedFeatures {
     }
 }
 
+impl TryFrom<&Vec<u8>> for LeSupportedFeatures {
+    type Error = String;
+    /// Create [`LeSupportedFeatures`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{le_supported_features::LeSupportedFeatures, data_type::DataType};
+    ///
+    /// let mut le_supported_features = [0u8; 6].to_vec();
+    ///
+    /// for i in 0..44 {
+    ///     le_supported_features[i / 8] = 0b1 << (i % 8);
+    ///
+    ///     let length = le_supported_features.len() as u8 + 1;
+    ///     let mut data: Vec<u8> = Vec::new();
+    ///     data.push(length);
+    ///     data.push(LeSupportedFeatures::data_type());
+    ///     data.append(&mut le_supported_features.clone());
+    ///
+    ///     let result = LeSupportedFeatures::try_from(&data);
+    ///     assert!(result.is_ok());
+    ///     let data_type = result.unwrap();
+    ///     assert_eq!(length, data_type.length);
+    ///     let bool_vec: Vec<bool> = le_supported_features
+    ///         .clone()
+    ///         .iter()
+    ///         .flat_map(|x| {
+    ///             let mut data: Vec<bool> = Vec::new();
+    ///             data.push((x & 0b0000_0001) != 0);
+    ///             data.push((x & 0b0000_0010) != 0);
+    ///             data.push((x & 0b0000_0100) != 0);
+    ///             data.push((x & 0b0000_1000) != 0);
+    ///             data.push((x & 0b0001_0000) != 0);
+    ///             data.push((x & 0b0010_0000) != 0);
+    ///             data.push((x & 0b0100_0000) != 0);
+    ///             data.push((x & 0b1000_0000) != 0);
+    ///             data
+    ///         })
+    ///         .collect();
+    ///
+    ///     assert_eq!(bool_vec, data_type.le_supported_features);
+    ///
+    ///     le_supported_features[i / 8] = 0u8;
+    /// }
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = LeSupportedFeatures::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        Self::try_from(value.as_slice())
+    }
+}
+
 impl Into<Vec<u8>> for LeSupportedFeatures {
     /// Create [`Vec<u8>`] from [`LeSupportedFeatures`].
     ///
@@ -901,8 +1142,9 @@ impl DataType for LeSupportedFeatures {
 /// assert!(is_le_supported_features(0x27));
 /// assert!(!is_le_supported_features(0x00));
 /// ```
+#[deprecated(note = "use `AdType::of(data_type).matches::<LeSupportedFeatures>()` instead")]
 pub fn is_le_supported_features(data_type: u8) -> bool {
-    LeSupportedFeatures::data_type() == data_type
+    AdType::of(data_type).matches::<LeSupportedFeatures>()
 }
 
 #[cfg(test)]
@@ -1255,6 +1497,17 @@ mod tests {
         assert!(result.is_advertising_coding_selection_host_support_supported());
     }
 
+    #[test]
+    fn test_is_decision_based_advertising_filtering_supported() {
+        let mut le_supported_features = [false; 56].to_vec();
+        le_supported_features[42] = true;
+        let result = LeSupportedFeatures::new(&le_supported_features);
+        assert!(result.is_decision_based_advertising_filtering_supported());
+        le_supported_features[42] = false;
+        let result = LeSupportedFeatures::new(&le_supported_features);
+        assert!(!result.is_decision_based_advertising_filtering_supported());
+    }
+
     #[test]
     fn test_is_periodic_advertising_with_responses_advertiser_supported() {
         let mut le_supported_features = [false; 48].to_vec();
@@ -1271,6 +1524,113 @@ mod tests {
         assert!(result.is_periodic_advertising_with_responses_scanner_supported());
     }
 
+    #[test]
+    fn test_is_unsegmented_framed_mode_supported() {
+        let mut le_supported_features = [false; 56].to_vec();
+        le_supported_features[45] = true;
+        let result = LeSupportedFeatures::new(&le_supported_features);
+        assert!(result.is_unsegmented_framed_mode_supported());
+        le_supported_features[45] = false;
+        let result = LeSupportedFeatures::new(&le_supported_features);
+        assert!(!result.is_unsegmented_framed_mode_supported());
+    }
+
+    #[test]
+    fn test_is_channel_sounding_supported() {
+        let mut le_supported_features = [false; 56].to_vec();
+        le_supported_features[46] = true;
+        let result = LeSupportedFeatures::new(&le_supported_features);
+        assert!(result.is_channel_sounding_supported());
+        le_supported_features[46] = false;
+        let result = LeSupportedFeatures::new(&le_supported_features);
+        assert!(!result.is_channel_sounding_supported());
+    }
+
+    #[test]
+    fn test_is_channel_sounding_host_support_supported() {
+        let mut le_supported_features = [false; 56].to_vec();
+        le_supported_features[47] = true;
+        let result = LeSupportedFeatures::new(&le_supported_features);
+        assert!(result.is_channel_sounding_host_support_supported());
+        le_supported_features[47] = false;
+        let result = LeSupportedFeatures::new(&le_supported_features);
+        assert!(!result.is_channel_sounding_host_support_supported());
+    }
+
+    #[test]
+    fn test_is_channel_sounding_tone_quality_indication_supported() {
+        let mut le_supported_features = [false; 56].to_vec();
+        le_supported_features[48] = true;
+        let result = LeSupportedFeatures::new(&le_supported_features);
+        assert!(result.is_channel_sounding_tone_quality_indication_supported());
+        le_supported_features[48] = false;
+        let result = LeSupportedFeatures::new(&le_supported_features);
+        assert!(!result.is_channel_sounding_tone_quality_indication_supported());
+    }
+
+    #[test]
+    fn test_le_feature_name() {
+        assert_eq!(Some("LE Encryption"), le_feature_name(0));
+        assert_eq!(Some("LE Coded PHY"), le_feature_name(11));
+        assert_eq!(Some("LE Power Control Request"), le_feature_name(34));
+        assert_eq!(
+            Some("Channel Sounding Tone Quality Indication"),
+            le_feature_name(48)
+        );
+        assert_eq!(None, le_feature_name(49));
+    }
+
+    #[test]
+    fn test_supported_features() {
+        let result = LeSupportedFeatures::new(&[false; 48].to_vec());
+        assert_eq!(0, result.supported_features().count());
+
+        let result = LeSupportedFeatures::new(&[true; 56].to_vec());
+        let features: Vec<(usize, Option<&str>)> = result.supported_features().collect();
+        assert_eq!(56, features.len());
+        for (bit, name) in features {
+            assert_eq!(le_feature_name(bit), name);
+        }
+
+        let data: Vec<u8> = vec![
+            7,
+            LeSupportedFeatures::data_type(),
+            0x00,
+            0b0011_1000,
+            0x00,
+            0x00,
+            0x00,
+            0x00,
+        ];
+        let result = LeSupportedFeatures::try_from(&data).unwrap();
+        assert_eq!(
+            vec![
+                (11, Some("LE Coded PHY")),
+                (12, Some("LE Extended Advertising")),
+                (13, Some("LE Periodic Advertising"))
+            ],
+            result.supported_features().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_try_from_length_consistency() {
+        let data: Vec<u8> = vec![
+            2,
+            LeSupportedFeatures::data_type(),
+            0x01,
+            0x02,
+            0x0a,
+            0x00,
+            0x00,
+        ];
+        let result = LeSupportedFeatures::try_from(&data);
+        assert!(result.is_ok());
+        let data_type = result.unwrap();
+        assert_eq!(8, data_type.le_supported_features.len());
+        assert!(data_type.le_supported_features[0]);
+    }
+
     #[test]
     fn test_try_from() {
         let mut le_supported_features = [0u8; 6].to_vec();
@@ -1357,6 +1717,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_is_le_supported_features() {
         assert!(is_le_supported_features(0x27));
         assert!(!is_le_supported_features(0x00));
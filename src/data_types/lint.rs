@@ -0,0 +1,480 @@
+//! Advertising payload conformance lint module.
+
+use std::fmt;
+
+use crate::data_types::codes::{
+    COMPLETE_LOCAL_NAME, FLAGS, MANUFACTURER_SPECIFIC_DATA, SERVICE_DATA_128BIT_UUID,
+    SERVICE_DATA_16BIT_UUID, SERVICE_DATA_32BIT_UUID, SHORTENED_LOCAL_NAME,
+};
+
+/// Maximum legacy advertising / scan response data size.
+pub const LEGACY_ADVERTISING_DATA_MAX: usize = 31;
+
+/// Maximum extended advertising / scan response data size.
+pub const EXTENDED_ADVERTISING_DATA_MAX: usize = 1650;
+
+/// Data types allowed to appear more than once in a payload.
+const REPEATABLE_DATA_TYPES: [u8; 4] = [
+    SERVICE_DATA_16BIT_UUID,
+    SERVICE_DATA_32BIT_UUID,
+    SERVICE_DATA_128BIT_UUID,
+    MANUFACTURER_SPECIFIC_DATA,
+];
+
+/// Advertising context of the linted payload.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct LintContext {
+    /// `true` if the advertising is connectable
+    pub connectable: bool,
+
+    /// `true` if the advertising uses extended advertising PDUs
+    pub extended: bool,
+
+    /// Scan response data sent together with the payload
+    pub scan_response: Option<Vec<u8>>,
+}
+
+impl LintContext {
+    /// Create [`LintContext`] from Parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::lint::LintContext;
+    ///
+    /// let scan_response: Vec<u8> = vec![0x02, 0x0a, 0x00];
+    /// let result = LintContext::new(true, false, Some(&scan_response));
+    /// assert!(result.connectable);
+    /// assert!(!result.extended);
+    /// assert_eq!(Some(scan_response), result.scan_response);
+    /// ```
+    pub fn new(connectable: bool, extended: bool, scan_response: Option<&[u8]>) -> Self {
+        Self {
+            connectable,
+            extended,
+            scan_response: scan_response.map(|value| value.to_vec()),
+        }
+    }
+
+    /// Maximum data size of each payload.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::lint::{LintContext, EXTENDED_ADVERTISING_DATA_MAX, LEGACY_ADVERTISING_DATA_MAX};
+    ///
+    /// assert_eq!(LEGACY_ADVERTISING_DATA_MAX, LintContext::new(false, false, None).max_data_size());
+    /// assert_eq!(EXTENDED_ADVERTISING_DATA_MAX, LintContext::new(false, true, None).max_data_size());
+    /// ```
+    pub fn max_data_size(&self) -> usize {
+        if self.extended {
+            EXTENDED_ADVERTISING_DATA_MAX
+        } else {
+            LEGACY_ADVERTISING_DATA_MAX
+        }
+    }
+}
+
+/// Payload the finding belongs to.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum LintPayload {
+    /// Advertising data.
+    Advertising,
+
+    /// Scan response data.
+    ScanResponse,
+}
+
+impl fmt::Display for LintPayload {
+    /// Format [`LintPayload`] with short PDU name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::lint::LintPayload;
+    ///
+    /// assert_eq!("ADV", LintPayload::Advertising.to_string());
+    /// assert_eq!("SCAN_RSP", LintPayload::ScanResponse.to_string());
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LintPayload::Advertising => f.write_str("ADV"),
+            LintPayload::ScanResponse => f.write_str("SCAN_RSP"),
+        }
+    }
+}
+
+/// Lint finding.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum LintFinding {
+    /// Connectable advertising without Flags.
+    MissingFlags,
+
+    /// Flags in scan response data.
+    FlagsInScanResponse {
+        /// offset of the data type
+        offset: usize,
+    },
+
+    /// Local name in both advertising and scan response data.
+    NameInBothPayloads,
+
+    /// Payload larger than allowed for the advertising type.
+    OversizePayload {
+        /// payload
+        payload: LintPayload,
+        /// payload size
+        size: usize,
+        /// maximum size
+        max: usize,
+    },
+
+    /// Data type length exceeds the end of payload.
+    OversizeStructure {
+        /// payload
+        payload: LintPayload,
+        /// offset of the data type
+        offset: usize,
+        /// data type length
+        length: u8,
+    },
+
+    /// Same data type appears more than once in a payload.
+    DuplicateDataType {
+        /// payload
+        payload: LintPayload,
+        /// offset of the duplicated data type
+        offset: usize,
+        /// data type value
+        data_type: u8,
+    },
+}
+
+impl fmt::Display for LintFinding {
+    /// Format [`LintFinding`] as a single line message.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::lint::{LintFinding, LintPayload};
+    ///
+    /// assert_eq!(
+    ///     "Flags missing in connectable advertising",
+    ///     LintFinding::MissingFlags.to_string()
+    /// );
+    /// assert_eq!(
+    ///     "ADV :Duplicate data type 0x09 at 5",
+    ///     LintFinding::DuplicateDataType {
+    ///         payload: LintPayload::Advertising,
+    ///         offset: 5,
+    ///         data_type: 0x09,
+    ///     }
+    ///     .to_string()
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LintFinding::MissingFlags => write!(f, "Flags missing in connectable advertising"),
+            LintFinding::FlagsInScanResponse { offset } => {
+                write!(f, "SCAN_RSP :Flags not allowed at {}", offset)
+            }
+            LintFinding::NameInBothPayloads => write!(f, "Local name in both ADV and SCAN_RSP"),
+            LintFinding::OversizePayload { payload, size, max } => {
+                write!(f, "{} :Payload size {} exceeds {}", payload, size, max)
+            }
+            LintFinding::OversizeStructure {
+                payload,
+                offset,
+                length,
+            } => write!(
+                f,
+                "{} :Data type length {} at {} exceeds payload",
+                payload, length, offset
+            ),
+            LintFinding::DuplicateDataType {
+                payload,
+                offset,
+                data_type,
+            } => write!(
+                f,
+                "{} :Duplicate data type {:#04x} at {}",
+                payload, data_type, offset
+            ),
+        }
+    }
+}
+
+/// Lint single payload and return `(offset, data type)` of each well-formed data type.
+fn lint_payload(
+    value: &[u8],
+    payload: LintPayload,
+    max: usize,
+    findings: &mut Vec<LintFinding>,
+) -> Vec<(usize, u8)> {
+    if value.len() > max {
+        findings.push(LintFinding::OversizePayload {
+            payload,
+            size: value.len(),
+            max,
+        });
+    }
+    let mut data_types: Vec<(usize, u8)> = Vec::new();
+    let mut offset = 0;
+    while offset < value.len() {
+        let length = value[offset];
+        if length == 0 {
+            // early termination (padding)
+            break;
+        }
+        if offset + 1 + length as usize > value.len() {
+            findings.push(LintFinding::OversizeStructure {
+                payload,
+                offset,
+                length,
+            });
+            break;
+        }
+        let data_type = value[offset + 1];
+        if !REPEATABLE_DATA_TYPES.contains(&data_type)
+            && data_types.iter().any(|(_, seen)| *seen == data_type)
+        {
+            findings.push(LintFinding::DuplicateDataType {
+                payload,
+                offset,
+                data_type,
+            });
+        }
+        data_types.push((offset, data_type));
+        offset += 1 + length as usize;
+    }
+    data_types
+}
+
+/// Lint advertising payload with [`LintContext`].
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::data_types::{
+///     complete_local_name::CompleteLocalName,
+///     flags::Flags,
+///     lint::{lint, LintContext, LintFinding},
+/// };
+///
+/// let name: Vec<u8> = CompleteLocalName::new(&"name".to_string()).into();
+/// let mut payload: Vec<u8> = Flags::new(&vec![true]).into();
+/// payload.append(&mut name.clone());
+/// assert!(lint(&payload, &LintContext::new(true, false, None)).is_empty());
+///
+/// let findings = lint(&name, &LintContext::new(true, false, Some(&name)));
+/// assert_eq!(
+///     vec![LintFinding::MissingFlags, LintFinding::NameInBothPayloads],
+///     findings
+/// );
+/// ```
+pub fn lint(payload: &[u8], context: &LintContext) -> Vec<LintFinding> {
+    let mut findings = Vec::new();
+    let max = context.max_data_size();
+    let advertising = lint_payload(payload, LintPayload::Advertising, max, &mut findings);
+    if context.connectable && !advertising.iter().any(|(_, data_type)| *data_type == FLAGS) {
+        findings.push(LintFinding::MissingFlags);
+    }
+
+    if let Some(scan_response) = &context.scan_response {
+        let scan_response =
+            lint_payload(scan_response, LintPayload::ScanResponse, max, &mut findings);
+        scan_response
+            .iter()
+            .filter(|(_, data_type)| *data_type == FLAGS)
+            .for_each(|(offset, _)| {
+                findings.push(LintFinding::FlagsInScanResponse { offset: *offset })
+            });
+
+        let has_name = |data_types: &Vec<(usize, u8)>| {
+            data_types.iter().any(|(_, data_type)| {
+                *data_type == COMPLETE_LOCAL_NAME || *data_type == SHORTENED_LOCAL_NAME
+            })
+        };
+        if has_name(&advertising) && has_name(&scan_response) {
+            findings.push(LintFinding::NameInBothPayloads);
+        }
+    }
+    findings
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data_types::{
+        complete_local_name::CompleteLocalName, data_type::DataType, flags::Flags, lint::*,
+        manufacturer_specific_data::ManufacturerSpecificData,
+        shortened_local_name::ShortenedLocalName, tx_power_level::TxPowerLevel,
+    };
+
+    fn flags() -> Vec<u8> {
+        Flags::new(&vec![false, true]).into()
+    }
+
+    #[test]
+    fn test_context_new() {
+        let result = LintContext::new(false, true, None);
+        assert!(!result.connectable);
+        assert!(result.extended);
+        assert_eq!(None, result.scan_response);
+        assert_eq!(LintContext::default(), LintContext::new(false, false, None));
+    }
+
+    #[test]
+    fn test_context_max_data_size() {
+        assert_eq!(31, LintContext::new(true, false, None).max_data_size());
+        assert_eq!(1650, LintContext::new(true, true, None).max_data_size());
+    }
+
+    #[test]
+    fn test_lint_empty() {
+        assert!(lint(&[], &LintContext::new(false, false, None)).is_empty());
+        assert_eq!(
+            vec![LintFinding::MissingFlags],
+            lint(&[], &LintContext::new(true, false, None))
+        );
+    }
+
+    #[test]
+    fn test_lint_missing_flags() {
+        let payload: Vec<u8> = TxPowerLevel::new(0).into();
+        assert!(lint(&payload, &LintContext::new(false, false, None)).is_empty());
+        assert_eq!(
+            vec![LintFinding::MissingFlags],
+            lint(&payload, &LintContext::new(true, false, None))
+        );
+        assert!(lint(&flags(), &LintContext::new(true, false, None)).is_empty());
+    }
+
+    #[test]
+    fn test_lint_flags_in_scan_response() {
+        let mut scan_response: Vec<u8> = TxPowerLevel::new(0).into();
+        scan_response.append(&mut flags());
+        assert_eq!(
+            vec![LintFinding::FlagsInScanResponse { offset: 3 }],
+            lint(
+                &flags(),
+                &LintContext::new(true, false, Some(&scan_response))
+            )
+        );
+    }
+
+    #[test]
+    fn test_lint_name_in_both_payloads() {
+        let mut payload = flags();
+        payload.append(&mut ShortenedLocalName::new(&"na".to_string()).into());
+        let scan_response: Vec<u8> = CompleteLocalName::new(&"name".to_string()).into();
+        assert_eq!(
+            vec![LintFinding::NameInBothPayloads],
+            lint(
+                &payload,
+                &LintContext::new(true, false, Some(&scan_response))
+            )
+        );
+        assert!(lint(
+            &flags(),
+            &LintContext::new(true, false, Some(&scan_response))
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn test_lint_oversize_payload() {
+        let mut payload = flags();
+        payload.append(&mut ManufacturerSpecificData::new(0x0001, &vec![0u8; 25]).into());
+        assert_eq!(32, payload.len());
+        assert_eq!(
+            vec![LintFinding::OversizePayload {
+                payload: LintPayload::Advertising,
+                size: 32,
+                max: 31,
+            }],
+            lint(&payload, &LintContext::new(true, false, None))
+        );
+        assert!(lint(&payload, &LintContext::new(true, true, None)).is_empty());
+
+        let scan_response: Vec<u8> = ManufacturerSpecificData::new(0x0001, &vec![0u8; 28]).into();
+        assert_eq!(
+            vec![LintFinding::OversizePayload {
+                payload: LintPayload::ScanResponse,
+                size: 32,
+                max: 31,
+            }],
+            lint(
+                &flags(),
+                &LintContext::new(true, false, Some(&scan_response))
+            )
+        );
+    }
+
+    #[test]
+    fn test_lint_oversize_structure() {
+        let mut payload = flags();
+        payload.append(&mut vec![0x05, TxPowerLevel::data_type(), 0x00]);
+        assert_eq!(
+            vec![LintFinding::OversizeStructure {
+                payload: LintPayload::Advertising,
+                offset: 3,
+                length: 5,
+            }],
+            lint(&payload, &LintContext::new(true, false, None))
+        );
+
+        let mut payload = flags();
+        payload.append(&mut vec![0x00, 0x00, 0x00]);
+        assert!(lint(&payload, &LintContext::new(true, false, None)).is_empty());
+    }
+
+    #[test]
+    fn test_lint_duplicate_data_type() {
+        let mut payload = flags();
+        payload.append(&mut TxPowerLevel::new(0).into());
+        payload.append(&mut TxPowerLevel::new(1).into());
+        assert_eq!(
+            vec![LintFinding::DuplicateDataType {
+                payload: LintPayload::Advertising,
+                offset: 6,
+                data_type: TxPowerLevel::data_type(),
+            }],
+            lint(&payload, &LintContext::new(true, false, None))
+        );
+
+        let mut payload = flags();
+        payload.append(&mut ManufacturerSpecificData::new(0x0001, &vec![]).into());
+        payload.append(&mut ManufacturerSpecificData::new(0x0002, &vec![]).into());
+        assert!(lint(&payload, &LintContext::new(true, false, None)).is_empty());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            "SCAN_RSP :Flags not allowed at 3",
+            LintFinding::FlagsInScanResponse { offset: 3 }.to_string()
+        );
+        assert_eq!(
+            "Local name in both ADV and SCAN_RSP",
+            LintFinding::NameInBothPayloads.to_string()
+        );
+        assert_eq!(
+            "ADV :Payload size 32 exceeds 31",
+            LintFinding::OversizePayload {
+                payload: LintPayload::Advertising,
+                size: 32,
+                max: 31
+            }
+            .to_string()
+        );
+        assert_eq!(
+            "SCAN_RSP :Data type length 5 at 3 exceeds payload",
+            LintFinding::OversizeStructure {
+                payload: LintPayload::ScanResponse,
+                offset: 3,
+                length: 5
+            }
+            .to_string()
+        );
+    }
+}
@@ -2,7 +2,10 @@
 
 use uuid::Uuid;
 
-use crate::data_types::data_type::DataType;
+use crate::data_types::{
+    ad_type::AdType,
+    data_type::{validate_length, DataType, UuidList},
+};
 
 /// List of 128-bit Service Solicitation UUIDs.
 #[derive(Debug, PartialEq, Clone)]
@@ -11,7 +14,7 @@ pub struct ListOf128BitServiceSolicitationUUIDs {
     pub length: u8,
 
     /// UUIDs
-    pub uuids: Vec<Uuid>,
+    pub uuids: UuidList,
 }
 
 impl ListOf128BitServiceSolicitationUUIDs {
@@ -30,19 +33,19 @@ impl ListOf128BitServiceSolicitationUUIDs {
     /// .to_vec();
     /// let result = ListOf128BitServiceSolicitationUUIDs::new(&uuids);
     /// assert_eq!(uuids.len() as u8 * 16 + 1, result.length);
-    /// assert_eq!(uuids, result.uuids);
+    /// assert_eq!(uuids, result.uuids.as_slice());
     /// ```
     pub fn new(uuids: &Vec<Uuid>) -> Self {
         Self {
             length: (uuids.len() * 16 + 1) as u8,
-            uuids: uuids.clone(),
+            uuids: uuids.as_slice().into(),
         }
     }
 }
 
-impl TryFrom<&Vec<u8>> for ListOf128BitServiceSolicitationUUIDs {
+impl TryFrom<&[u8]> for ListOf128BitServiceSolicitationUUIDs {
     type Error = String;
-    /// Create [`ListOf128BitServiceSolicitationUUIDs`] from [`Vec<u8>`].
+    /// Create [`ListOf128BitServiceSolicitationUUIDs`] from `u8` slice.
     ///
     /// # Examples
     ///
@@ -65,27 +68,30 @@ impl TryFrom<&Vec<u8>> for ListOf128BitServiceSolicitationUUIDs {
     /// data.push(ListOf128BitServiceSolicitationUUIDs::data_type());
     /// data.append(&mut uuid_bytes.clone());
     ///
-    /// let result = ListOf128BitServiceSolicitationUUIDs::try_from(&data);
+    /// let result = ListOf128BitServiceSolicitationUUIDs::try_from(data.as_slice());
     /// assert!(result.is_ok());
     /// let data_type = result.unwrap();
     /// assert_eq!(length, data_type.length);
-    /// assert_eq!(uuids, data_type.uuids);
+    /// assert_eq!(uuids, data_type.uuids.as_slice());
     ///
     /// let mut data: Vec<u8> = vec![0u8; 17];
     /// data[0] = data.len() as u8 - 1;
-    /// let result = ListOf128BitServiceSolicitationUUIDs::try_from(&data);
+    /// let result = ListOf128BitServiceSolicitationUUIDs::try_from(data.as_slice());
     /// assert!(result.is_err());
     /// assert_eq!(
     ///     format!("Invalid data size :{}", data.len()),
     ///     result.unwrap_err()
     /// );
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+    fn try_from(value: &[u8]) -> Result<Self, String> {
         let len = value.len();
         if len < 18 {
             return Err(format!("Invalid data size :{}", len).to_string());
         }
-        let length = value[0];
+        let length = validate_length(value, 17, 255)?;
+        if !(length - 1).is_multiple_of(16) {
+            return Err(format!("Invalid length :{}", length).to_string());
+        }
         Ok(Self {
             length,
             uuids: value[2..2 + length as usize - 1]
@@ -97,6 +103,51 @@ impl TryFrom<&Vec<u8>> for ListOf128BitServiceSolicitationUUIDs {
     }
 }
 
+impl TryFrom<&Vec<u8>> for ListOf128BitServiceSolicitationUUIDs {
+    type Error = String;
+    /// Create [`ListOf128BitServiceSolicitationUUIDs`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{BASE_UUID, data_types::{list_of_128bit_service_solicitation_uuids::ListOf128BitServiceSolicitationUUIDs, data_type::DataType}};
+    /// use uuid::{uuid, Uuid};
+    ///
+    /// let uuids = [
+    ///     uuid!("00000001-0000-1000-8000-00805F9B34FB"),
+    ///     uuid!("00000002-0000-1000-8000-00805F9B34FB"),
+    /// ]
+    /// .to_vec();
+    /// let mut uuid_bytes: Vec<u8> = Vec::new();
+    /// uuid_bytes.append(&mut uuids[0].as_u128().to_le_bytes().to_vec());
+    /// uuid_bytes.append(&mut uuids[1].as_u128().to_le_bytes().to_vec());
+    ///
+    /// let length = uuid_bytes.len() as u8 + 1;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(ListOf128BitServiceSolicitationUUIDs::data_type());
+    /// data.append(&mut uuid_bytes.clone());
+    ///
+    /// let result = ListOf128BitServiceSolicitationUUIDs::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert_eq!(uuids, data_type.uuids.as_slice());
+    ///
+    /// let mut data: Vec<u8> = vec![0u8; 17];
+    /// data[0] = data.len() as u8 - 1;
+    /// let result = ListOf128BitServiceSolicitationUUIDs::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        Self::try_from(value.as_slice())
+    }
+}
+
 impl Into<Vec<u8>> for ListOf128BitServiceSolicitationUUIDs {
     /// Create [`Vec<u8>`] from [`ListOf128BitServiceSolicitationUUIDs`].
     ///
@@ -173,8 +224,11 @@ impl DataType for ListOf128BitServiceSolicitationUUIDs {
 /// assert!(is_list_of_128bit_service_solicitation_uuids(0x15));
 /// assert!(!is_list_of_128bit_service_solicitation_uuids(0x00));
 /// ```
+#[deprecated(
+    note = "use `AdType::of(data_type).matches::<ListOf128BitServiceSolicitationUUIDs>()` instead"
+)]
 pub fn is_list_of_128bit_service_solicitation_uuids(data_type: u8) -> bool {
-    ListOf128BitServiceSolicitationUUIDs::data_type() == data_type
+    AdType::of(data_type).matches::<ListOf128BitServiceSolicitationUUIDs>()
 }
 
 #[cfg(test)]
@@ -192,7 +246,7 @@ mod tests {
         .to_vec();
         let result = ListOf128BitServiceSolicitationUUIDs::new(&uuids);
         assert_eq!(uuids.len() as u8 * 16 + 1, result.length);
-        assert_eq!(uuids, result.uuids);
+        assert_eq!(uuids, result.uuids.as_slice());
     }
 
     #[test]
@@ -216,7 +270,7 @@ mod tests {
         assert!(result.is_ok());
         let data_type = result.unwrap();
         assert_eq!(length, data_type.length);
-        assert_eq!(uuids, data_type.uuids);
+        assert_eq!(uuids, data_type.uuids.as_slice());
 
         let mut data: Vec<u8> = vec![0u8; 17];
         data[0] = data.len() as u8 - 1;
@@ -262,6 +316,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_is_list_of_128bit_service_solicitation_uuids() {
         assert!(is_list_of_128bit_service_solicitation_uuids(0x15));
         assert!(!is_list_of_128bit_service_solicitation_uuids(0x00));
@@ -2,7 +2,13 @@
 
 use uuid::Uuid;
 
-use crate::{data_types::data_type::DataType, BASE_UUID};
+use crate::{
+    data_types::{
+        ad_type::AdType,
+        data_type::{validate_length, DataType, UuidList},
+    },
+    BASE_UUID,
+};
 
 /// List of 16-bit Service Solicitation UUIDs.
 #[derive(Debug, PartialEq, Clone)]
@@ -11,7 +17,7 @@ pub struct ListOf16BitServiceSolicitationUUIDs {
     pub length: u8,
 
     /// UUIDs
-    pub uuids: Vec<Uuid>,
+    pub uuids: UuidList,
 }
 
 impl ListOf16BitServiceSolicitationUUIDs {
@@ -30,19 +36,19 @@ impl ListOf16BitServiceSolicitationUUIDs {
     /// .to_vec();
     /// let result = ListOf16BitServiceSolicitationUUIDs::new(&uuids);
     /// assert_eq!(uuids.len() as u8 * 2 + 1, result.length);
-    /// assert_eq!(uuids, result.uuids);
+    /// assert_eq!(uuids, result.uuids.as_slice());
     /// ```
     pub fn new(uuids: &Vec<Uuid>) -> Self {
         Self {
             length: (uuids.len() * 2 + 1) as u8,
-            uuids: uuids.clone(),
+            uuids: uuids.as_slice().into(),
         }
     }
 }
 
-impl TryFrom<&Vec<u8>> for ListOf16BitServiceSolicitationUUIDs {
+impl TryFrom<&[u8]> for ListOf16BitServiceSolicitationUUIDs {
     type Error = String;
-    /// Create [ListOf16BitServiceSolicitationUUIDs] from [`Vec<u8>`].
+    /// Create [`ListOf16BitServiceSolicitationUUIDs`] from `u8` slice.
     ///
     /// # Examples
     ///
@@ -65,26 +71,29 @@ impl TryFrom<&Vec<u8>> for ListOf16BitServiceSolicitationUUIDs {
     /// data.push(ListOf16BitServiceSolicitationUUIDs::data_type());
     /// data.append(&mut uuid_bytes.clone());
     ///
-    /// let result = ListOf16BitServiceSolicitationUUIDs::try_from(&data);
+    /// let result = ListOf16BitServiceSolicitationUUIDs::try_from(data.as_slice());
     /// assert!(result.is_ok());
     /// let data_type: ListOf16BitServiceSolicitationUUIDs = result.unwrap();
     /// assert_eq!(length, data_type.length);
-    /// assert_eq!(uuids, data_type.uuids);
+    /// assert_eq!(uuids, data_type.uuids.as_slice());
     ///
     /// let data: Vec<u8> = Vec::new();
-    /// let result = ListOf16BitServiceSolicitationUUIDs::try_from(&data);
+    /// let result = ListOf16BitServiceSolicitationUUIDs::try_from(data.as_slice());
     /// assert!(result.is_err());
     /// assert_eq!(
     ///     format!("Invalid data size :{}", data.len()),
     ///     result.unwrap_err()
     /// );
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+    fn try_from(value: &[u8]) -> Result<Self, String> {
         let len = value.len();
         if len < 4 {
             return Err(format!("Invalid data size :{}", len).to_string());
         }
-        let length = value[0];
+        let length = validate_length(value, 3, 255)?;
+        if !(length - 1).is_multiple_of(2) {
+            return Err(format!("Invalid length :{}", length).to_string());
+        }
         Ok(Self {
             length,
             uuids: value[2..2 + length as usize - 1]
@@ -101,6 +110,50 @@ impl TryFrom<&Vec<u8>> for ListOf16BitServiceSolicitationUUIDs {
     }
 }
 
+impl TryFrom<&Vec<u8>> for ListOf16BitServiceSolicitationUUIDs {
+    type Error = String;
+    /// Create [ListOf16BitServiceSolicitationUUIDs] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{BASE_UUID, data_types::{list_of_16bit_service_solicitation_uuids::ListOf16BitServiceSolicitationUUIDs, data_type::DataType}};
+    /// use uuid::{uuid, Uuid};
+    ///
+    /// let uuid_bytes: Vec<u8> = [0x01u8, 0x02u8, 0x03u8, 0x04u8].to_vec();
+    /// let uuids: Vec<Uuid> = uuid_bytes
+    ///     .windows(2)
+    ///     .step_by(2)
+    ///     .map(|f| {
+    ///         let (d1, d2, d3, d4) = BASE_UUID.as_fields();
+    ///         Uuid::from_fields(d1 | ((f[0] as u32) << 0) | ((f[1] as u32) << 8), d2, d3, d4)
+    ///     })
+    ///     .collect();
+    /// let length = uuid_bytes.len() as u8 + 1;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(ListOf16BitServiceSolicitationUUIDs::data_type());
+    /// data.append(&mut uuid_bytes.clone());
+    ///
+    /// let result = ListOf16BitServiceSolicitationUUIDs::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type: ListOf16BitServiceSolicitationUUIDs = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert_eq!(uuids, data_type.uuids.as_slice());
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = ListOf16BitServiceSolicitationUUIDs::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        Self::try_from(value.as_slice())
+    }
+}
+
 impl Into<Vec<u8>> for ListOf16BitServiceSolicitationUUIDs {
     /// Create [`Vec<u8>`] from [ListOf16BitServiceSolicitationUUIDs].
     ///
@@ -178,8 +231,11 @@ impl DataType for ListOf16BitServiceSolicitationUUIDs {
 /// assert!(is_list_of_16bit_service_solicitation_uuids(0x14));
 /// assert!(!is_list_of_16bit_service_solicitation_uuids(0x00));
 /// ```
+#[deprecated(
+    note = "use `AdType::of(data_type).matches::<ListOf16BitServiceSolicitationUUIDs>()` instead"
+)]
 pub fn is_list_of_16bit_service_solicitation_uuids(data_type: u8) -> bool {
-    ListOf16BitServiceSolicitationUUIDs::data_type() == data_type
+    AdType::of(data_type).matches::<ListOf16BitServiceSolicitationUUIDs>()
 }
 
 #[cfg(test)]
@@ -200,7 +256,7 @@ mod tests {
         .to_vec();
         let result = ListOf16BitServiceSolicitationUUIDs::new(&uuids);
         assert_eq!(uuids.len() as u8 * 2 + 1, result.length);
-        assert_eq!(uuids, result.uuids);
+        assert_eq!(uuids, result.uuids.as_slice());
     }
 
     #[test]
@@ -224,7 +280,7 @@ mod tests {
         assert!(result.is_ok());
         let data_type: ListOf16BitServiceSolicitationUUIDs = result.unwrap();
         assert_eq!(length, data_type.length);
-        assert_eq!(uuids, data_type.uuids);
+        assert_eq!(uuids, data_type.uuids.as_slice());
 
         let mut data: Vec<u8> = vec![0u8; 3];
         data[0] = data.len() as u8 - 1;
@@ -271,6 +327,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_is_list_of_16bit_service_solicitation_uuids() {
         assert!(is_list_of_16bit_service_solicitation_uuids(0x14));
         assert!(!is_list_of_16bit_service_solicitation_uuids(0x00));
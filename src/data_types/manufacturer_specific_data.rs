@@ -1,6 +1,9 @@
 //! Manufacturer Specific Data (Data Type Value: 0xff) module.
 
-use crate::data_types::data_type::DataType;
+use crate::data_types::{
+    ad_type::AdType,
+    data_type::{validate_length, DataType},
+};
 
 /// Manufacturer Specific Data.
 
@@ -43,6 +46,51 @@ impl ManufacturerSpecificData {
     }
 }
 
+impl TryFrom<&[u8]> for ManufacturerSpecificData {
+    type Error = String;
+    /// Create [`ManufacturerSpecificData`] from `u8` slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{manufacturer_specific_data::ManufacturerSpecificData, data_type::DataType};
+    ///
+    /// let company_identifier = 0x0ca8u16;
+    /// let manufacturer_specific_data = [0x03u8].to_vec();
+    /// let length = manufacturer_specific_data.len() as u8 + 3;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(ManufacturerSpecificData::data_type());
+    /// data.append(&mut u16::to_le_bytes(company_identifier).try_into().unwrap());
+    /// data.append(&mut manufacturer_specific_data.clone());
+    ///
+    /// let result = ManufacturerSpecificData::try_from(data.as_slice());
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert_eq!(company_identifier, data_type.company_identifier);
+    /// assert_eq!(
+    ///     manufacturer_specific_data,
+    ///     data_type.manufacturer_specific_data
+    /// );
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = ManufacturerSpecificData::try_from(data.as_slice());
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &[u8]) -> Result<Self, String> {
+        let len = value.len();
+        if len < 4 {
+            return Err(format!("Invalid data size :{}", len).to_string());
+        }
+        Ok(ManufacturerSpecificDataRef::try_from(value)?.to_owned())
+    }
+}
+
 impl TryFrom<&Vec<u8>> for ManufacturerSpecificData {
     type Error = String;
     /// Create [`ManufacturerSpecificData`] from [`Vec<u8>`].
@@ -80,15 +128,82 @@ impl TryFrom<&Vec<u8>> for ManufacturerSpecificData {
     /// );
     /// ```
     fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        Self::try_from(value.as_slice())
+    }
+}
+
+/// Borrowed view of [`ManufacturerSpecificData`].
+#[derive(Debug, PartialEq, Clone)]
+pub struct ManufacturerSpecificDataRef<'a> {
+    /// data length
+    pub length: u8,
+
+    /// Company Identifier Code
+    pub company_identifier: u16,
+
+    /// Manufacturer Specific Data
+    pub manufacturer_specific_data: &'a [u8],
+}
+
+impl ManufacturerSpecificDataRef<'_> {
+    /// Create [`ManufacturerSpecificData`] from [`ManufacturerSpecificDataRef`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{data_type::DataType, manufacturer_specific_data::*};
+    ///
+    /// let data: Vec<u8> = vec![5, ManufacturerSpecificData::data_type(), 0xa8, 0x0c, 0x01, 0x02];
+    /// let result = ManufacturerSpecificDataRef::try_from(data.as_slice()).unwrap();
+    /// assert_eq!(
+    ///     ManufacturerSpecificData::new(0x0ca8, &vec![0x01, 0x02]),
+    ///     result.to_owned()
+    /// );
+    /// ```
+    pub fn to_owned(&self) -> ManufacturerSpecificData {
+        ManufacturerSpecificData {
+            length: self.length,
+            company_identifier: self.company_identifier,
+            manufacturer_specific_data: self.manufacturer_specific_data.to_vec(),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for ManufacturerSpecificDataRef<'a> {
+    type Error = String;
+    /// Create [`ManufacturerSpecificDataRef`] from `u8` slice without copying.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{data_type::DataType, manufacturer_specific_data::*};
+    ///
+    /// let data: Vec<u8> = vec![5, ManufacturerSpecificData::data_type(), 0xa8, 0x0c, 0x01, 0x02];
+    /// let result = ManufacturerSpecificDataRef::try_from(data.as_slice());
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(5, data_type.length);
+    /// assert_eq!(0x0ca8, data_type.company_identifier);
+    /// assert_eq!(&data[4..], data_type.manufacturer_specific_data);
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = ManufacturerSpecificDataRef::try_from(data.as_slice());
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &'a [u8]) -> Result<Self, String> {
         let len = value.len();
         if len < 4 {
             return Err(format!("Invalid data size :{}", len).to_string());
         }
-        let length = value[0];
+        let length = validate_length(value, 3, 255)?;
         Ok(Self {
             length,
             company_identifier: u16::from_le_bytes(value[2..4].try_into().unwrap()),
-            manufacturer_specific_data: value[4..1 + length as usize].to_vec(),
+            manufacturer_specific_data: &value[4..1 + length as usize],
         })
     }
 }
@@ -158,8 +273,9 @@ impl DataType for ManufacturerSpecificData {
 /// assert!(is_manufacturer_specific_data(0xff));
 /// assert!(!is_manufacturer_specific_data(0x00));
 /// ```
+#[deprecated(note = "use `AdType::of(data_type).matches::<ManufacturerSpecificData>()` instead")]
 pub fn is_manufacturer_specific_data(data_type: u8) -> bool {
-    ManufacturerSpecificData::data_type() == data_type
+    AdType::of(data_type).matches::<ManufacturerSpecificData>()
 }
 
 #[cfg(test)]
@@ -180,6 +296,62 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_try_from_length_consistency() {
+        let data: Vec<u8> = vec![6, ManufacturerSpecificData::data_type(), 0x01, 0x02, 0x03];
+        let result = ManufacturerSpecificData::try_from(&data);
+        assert!(result.is_err());
+        assert_eq!(
+            format!("Invalid data size :{}", data.len()),
+            result.unwrap_err()
+        );
+
+        let data: Vec<u8> = vec![2, ManufacturerSpecificData::data_type(), 0x01, 0x02];
+        let result = ManufacturerSpecificData::try_from(&data);
+        assert!(result.is_err());
+        assert_eq!("Invalid length :2", result.unwrap_err());
+    }
+
+    #[test]
+    fn test_ref_try_from() {
+        let data: Vec<u8> = vec![
+            4,
+            ManufacturerSpecificData::data_type(),
+            0x01,
+            0x02,
+            0x03,
+            0xff,
+        ];
+        let result = ManufacturerSpecificDataRef::try_from(data.as_slice());
+        assert!(result.is_ok());
+        let data_type = result.unwrap();
+        assert_eq!(4, data_type.length);
+        assert_eq!(0x0201, data_type.company_identifier);
+        assert_eq!(&[0x03u8], data_type.manufacturer_specific_data);
+        assert_eq!(
+            data_type.manufacturer_specific_data.as_ptr(),
+            data[4..].as_ptr()
+        );
+
+        let data: Vec<u8> = vec![4, ManufacturerSpecificData::data_type(), 0x01, 0x02];
+        let result = ManufacturerSpecificDataRef::try_from(data.as_slice());
+        assert!(result.is_err());
+        assert_eq!(
+            format!("Invalid data size :{}", data.len()),
+            result.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_ref_to_owned() {
+        let data: Vec<u8> = ManufacturerSpecificData::new(0x0ca8, &vec![0x01, 0x02, 0x03]).into();
+        let result = ManufacturerSpecificDataRef::try_from(data.as_slice()).unwrap();
+        assert_eq!(
+            ManufacturerSpecificData::try_from(&data).unwrap(),
+            result.to_owned()
+        );
+    }
+
     #[test]
     fn test_try_from() {
         let company_identifier = 0x0ca8u16;
@@ -241,6 +413,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_is_manufacturer_specific_data() {
         assert!(is_manufacturer_specific_data(0xff));
         assert!(!is_manufacturer_specific_data(0x00));
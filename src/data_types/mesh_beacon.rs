@@ -0,0 +1,812 @@
+//! Mesh Beacon (Data Type Value: 0x2b) module.
+
+use uuid::Uuid;
+
+#[cfg(feature = "crypto")]
+use crate::{
+    crypto::{aes_cmac, s1},
+    data_types::uniform_resource_identifier::UniformResourceIdentifier,
+};
+
+use crate::data_types::data_type::DataType;
+
+/// Beacon Type: Unprovisioned Device beacon
+pub const BEACON_TYPE_UNPROVISIONED_DEVICE: u8 = 0x00;
+
+/// Beacon Type: Secure Network beacon
+pub const BEACON_TYPE_SECURE_NETWORK: u8 = 0x01;
+
+/// Secure Network beacon Flags: Key Refresh Flag
+pub const FLAGS_KEY_REFRESH: u8 = 0x01;
+
+/// Secure Network beacon Flags: IV Update Flag
+pub const FLAGS_IV_UPDATE: u8 = 0x02;
+
+/// OOB Information: Other
+pub const OOB_OTHER: u16 = 0x0001;
+
+/// OOB Information: Electronic / URI
+pub const OOB_ELECTRONIC_URI: u16 = 0x0002;
+
+/// OOB Information: 2D machine-readable code
+pub const OOB_2D_MACHINE_READABLE_CODE: u16 = 0x0004;
+
+/// OOB Information: Bar code
+pub const OOB_BAR_CODE: u16 = 0x0008;
+
+/// OOB Information: Near Field Communication (NFC)
+pub const OOB_NFC: u16 = 0x0010;
+
+/// OOB Information: Number
+pub const OOB_NUMBER: u16 = 0x0020;
+
+/// OOB Information: String
+pub const OOB_STRING: u16 = 0x0040;
+
+/// OOB Information: Support for certificate-based provisioning
+pub const OOB_CERTIFICATE_BASED_PROVISIONING: u16 = 0x0080;
+
+/// OOB Information: Support for provisioning records
+pub const OOB_PROVISIONING_RECORDS: u16 = 0x0100;
+
+/// OOB Information: On box
+pub const OOB_ON_BOX: u16 = 0x0800;
+
+/// OOB Information: Inside box
+pub const OOB_INSIDE_BOX: u16 = 0x1000;
+
+/// OOB Information: On piece of paper
+pub const OOB_ON_PIECE_OF_PAPER: u16 = 0x2000;
+
+/// OOB Information: Inside manual
+pub const OOB_INSIDE_MANUAL: u16 = 0x4000;
+
+/// OOB Information: On device
+pub const OOB_ON_DEVICE: u16 = 0x8000;
+
+/// Mesh Beacon.
+#[derive(Debug, PartialEq, Clone)]
+pub struct MeshBeacon {
+    /// data length
+    pub length: u8,
+
+    /// Beacon Type
+    pub beacon_type: u8,
+
+    /// Beacon Data
+    pub beacon_data: Vec<u8>,
+}
+
+impl MeshBeacon {
+    /// Create [`MeshBeacon`] from Parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::mesh_beacon::MeshBeacon;
+    ///
+    /// let beacon_data = [0x01u8; 18].to_vec();
+    /// let result = MeshBeacon::new(0x00, &beacon_data);
+    /// assert_eq!(20, result.length);
+    /// assert_eq!(0x00, result.beacon_type);
+    /// assert_eq!(beacon_data, result.beacon_data);
+    /// ```
+    pub fn new(beacon_type: u8, beacon_data: &[u8]) -> Self {
+        Self {
+            length: 2 + beacon_data.len() as u8,
+            beacon_type,
+            beacon_data: beacon_data.to_vec(),
+        }
+    }
+}
+
+impl TryFrom<&[u8]> for MeshBeacon {
+    type Error = String;
+    /// Create [`MeshBeacon`] from `u8` slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{mesh_beacon::MeshBeacon, data_type::DataType};
+    ///
+    /// let beacon_data = [0x01u8; 18].to_vec();
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(20);
+    /// data.push(MeshBeacon::data_type());
+    /// data.push(0x00);
+    /// data.append(&mut beacon_data.clone());
+    ///
+    /// let result = MeshBeacon::try_from(data.as_slice());
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(20, data_type.length);
+    /// assert_eq!(0x00, data_type.beacon_type);
+    /// assert_eq!(beacon_data, data_type.beacon_data);
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = MeshBeacon::try_from(data.as_slice());
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &[u8]) -> Result<Self, String> {
+        let len = value.len();
+        if len < 3 || len < 1 + value[0] as usize || value[0] < 2 {
+            return Err(format!("Invalid data size :{}", len).to_string());
+        }
+        let length = value[0];
+        Ok(Self {
+            length,
+            beacon_type: value[2],
+            beacon_data: value[3..1 + length as usize].to_vec(),
+        })
+    }
+}
+
+impl TryFrom<&Vec<u8>> for MeshBeacon {
+    type Error = String;
+    /// Create [`MeshBeacon`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{mesh_beacon::MeshBeacon, data_type::DataType};
+    ///
+    /// let beacon_data = [0x01u8; 18].to_vec();
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(20);
+    /// data.push(MeshBeacon::data_type());
+    /// data.push(0x00);
+    /// data.append(&mut beacon_data.clone());
+    ///
+    /// let result = MeshBeacon::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(20, data_type.length);
+    /// assert_eq!(0x00, data_type.beacon_type);
+    /// assert_eq!(beacon_data, data_type.beacon_data);
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = MeshBeacon::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        Self::try_from(value.as_slice())
+    }
+}
+
+impl From<MeshBeacon> for Vec<u8> {
+    /// Create [`Vec<u8>`] from [`MeshBeacon`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{mesh_beacon::MeshBeacon, data_type::DataType};
+    ///
+    /// let beacon_data = [0x01u8; 18].to_vec();
+    /// let result1 = MeshBeacon::new(0x00, &beacon_data);
+    ///
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(20);
+    /// data.push(MeshBeacon::data_type());
+    /// data.push(0x00);
+    /// data.append(&mut beacon_data.clone());
+    ///
+    /// let into_data: Vec<u8> = result1.into();
+    /// assert_eq!(data, into_data);
+    ///
+    /// let result2 = MeshBeacon::try_from(&data);
+    /// assert!(result2.is_ok());
+    /// let data_type = result2.unwrap();
+    /// let into_data: Vec<u8> = data_type.into();
+    /// assert_eq!(data, into_data);
+    /// ```
+    fn from(value: MeshBeacon) -> Self {
+        let mut data: Vec<u8> = Vec::new();
+        data.push(value.length);
+        data.push(MeshBeacon::data_type());
+        data.push(value.beacon_type);
+        data.append(&mut value.beacon_data.clone());
+        data
+    }
+}
+
+impl DataType for MeshBeacon {
+    /// return `0x2b`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{mesh_beacon::MeshBeacon, data_type::DataType};
+    ///
+    /// assert_eq!(0x2b, MeshBeacon::data_type());
+    /// ```
+    fn data_type() -> u8 {
+        0x2b
+    }
+}
+
+/// Unprovisioned Device beacon.
+///
+/// The URI Hash identifies the companion [`UniformResourceIdentifier`](crate::data_types::uniform_resource_identifier::UniformResourceIdentifier) advertisement.
+#[derive(Debug, PartialEq, Clone)]
+pub struct UnprovisionedDeviceBeacon {
+    /// Device UUID
+    pub device_uuid: Uuid,
+
+    /// OOB Information
+    pub oob_information: u16,
+
+    /// URI Hash
+    pub uri_hash: Option<[u8; 4]>,
+}
+
+impl UnprovisionedDeviceBeacon {
+    /// Create [`UnprovisionedDeviceBeacon`] from Parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uuid::uuid;
+    /// use ble_data_struct::data_types::mesh_beacon::{UnprovisionedDeviceBeacon, OOB_ELECTRONIC_URI};
+    ///
+    /// let device_uuid = uuid!("70cf7c97-32a3-45b6-9149-4810d2e9cbf4");
+    /// let result =
+    ///     UnprovisionedDeviceBeacon::new(device_uuid, OOB_ELECTRONIC_URI, Some([0xd9, 0x74, 0x78, 0xb3]));
+    /// assert_eq!(device_uuid, result.device_uuid);
+    /// assert_eq!(OOB_ELECTRONIC_URI, result.oob_information);
+    /// assert_eq!(Some([0xd9, 0x74, 0x78, 0xb3]), result.uri_hash);
+    /// ```
+    pub fn new(device_uuid: Uuid, oob_information: u16, uri_hash: Option<[u8; 4]>) -> Self {
+        Self {
+            device_uuid,
+            oob_information,
+            uri_hash,
+        }
+    }
+
+    /// check OOB Information bit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uuid::uuid;
+    /// use ble_data_struct::data_types::mesh_beacon::{
+    ///     UnprovisionedDeviceBeacon, OOB_ELECTRONIC_URI, OOB_NUMBER, OOB_ON_BOX,
+    /// };
+    ///
+    /// let device_uuid = uuid!("70cf7c97-32a3-45b6-9149-4810d2e9cbf4");
+    /// let result = UnprovisionedDeviceBeacon::new(device_uuid, OOB_ELECTRONIC_URI | OOB_ON_BOX, None);
+    /// assert!(result.has_oob_information(OOB_ELECTRONIC_URI));
+    /// assert!(result.has_oob_information(OOB_ON_BOX));
+    /// assert!(!result.has_oob_information(OOB_NUMBER));
+    /// ```
+    pub fn has_oob_information(&self, oob_information: u16) -> bool {
+        self.oob_information & oob_information == oob_information
+    }
+
+    /// check URI Hash matches [`UniformResourceIdentifier`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uuid::uuid;
+    /// use ble_data_struct::data_types::{
+    ///     mesh_beacon::UnprovisionedDeviceBeacon,
+    ///     uniform_resource_identifier::UniformResourceIdentifier,
+    /// };
+    ///
+    /// let device_uuid = uuid!("70cf7c97-32a3-45b6-9149-4810d2e9cbf4");
+    /// let uri = UniformResourceIdentifier::new(
+    ///     &"\u{0017}//www.example.com/mesh/products/light-switch-v3".to_string(),
+    /// );
+    /// let result = UnprovisionedDeviceBeacon::new(device_uuid, 0x0000, Some([0xd9, 0x74, 0x78, 0xb3]));
+    /// assert!(result.matches_uri(&uri));
+    ///
+    /// let result = UnprovisionedDeviceBeacon::new(device_uuid, 0x0000, None);
+    /// assert!(!result.matches_uri(&uri));
+    /// ```
+    #[cfg(feature = "crypto")]
+    pub fn matches_uri(&self, uri: &UniformResourceIdentifier) -> bool {
+        let data: Vec<u8> = uri.clone().into();
+        match self.uri_hash {
+            Some(uri_hash) => s1(&data[2..])[..4] == uri_hash,
+            None => false,
+        }
+    }
+}
+
+impl TryFrom<&MeshBeacon> for UnprovisionedDeviceBeacon {
+    type Error = String;
+    /// Create [`UnprovisionedDeviceBeacon`] from [`MeshBeacon`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uuid::uuid;
+    /// use ble_data_struct::data_types::mesh_beacon::{MeshBeacon, UnprovisionedDeviceBeacon};
+    ///
+    /// let device_uuid = uuid!("70cf7c97-32a3-45b6-9149-4810d2e9cbf4");
+    /// let mut beacon_data = device_uuid.as_bytes().to_vec();
+    /// beacon_data.append(&mut vec![0x40, 0x20]);
+    /// let result = UnprovisionedDeviceBeacon::try_from(&MeshBeacon::new(0x00, &beacon_data));
+    /// assert_eq!(
+    ///     Ok(UnprovisionedDeviceBeacon::new(device_uuid, 0x4020, None)),
+    ///     result
+    /// );
+    ///
+    /// beacon_data.append(&mut vec![0xd9, 0x74, 0x78, 0xb3]);
+    /// let result = UnprovisionedDeviceBeacon::try_from(&MeshBeacon::new(0x00, &beacon_data));
+    /// assert_eq!(
+    ///     Ok(UnprovisionedDeviceBeacon::new(device_uuid, 0x4020, Some([0xd9, 0x74, 0x78, 0xb3]))),
+    ///     result
+    /// );
+    ///
+    /// let result = UnprovisionedDeviceBeacon::try_from(&MeshBeacon::new(0x01, &beacon_data));
+    /// assert!(result.is_err());
+    /// ```
+    fn try_from(value: &MeshBeacon) -> Result<Self, String> {
+        if value.beacon_type != BEACON_TYPE_UNPROVISIONED_DEVICE {
+            return Err(format!("Invalid beacon type :{}", value.beacon_type).to_string());
+        }
+        let data = &value.beacon_data;
+        let len = data.len();
+        if len != 18 && len != 22 {
+            return Err(format!("Invalid data size :{}", len).to_string());
+        }
+        Ok(Self {
+            device_uuid: Uuid::from_bytes(data[0..16].try_into().unwrap()),
+            oob_information: u16::from_be_bytes(data[16..18].try_into().unwrap()),
+            uri_hash: if len == 22 {
+                Some(data[18..22].try_into().unwrap())
+            } else {
+                None
+            },
+        })
+    }
+}
+
+impl From<UnprovisionedDeviceBeacon> for MeshBeacon {
+    /// Create [`MeshBeacon`] from [`UnprovisionedDeviceBeacon`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uuid::uuid;
+    /// use ble_data_struct::data_types::mesh_beacon::{MeshBeacon, UnprovisionedDeviceBeacon};
+    ///
+    /// let device_uuid = uuid!("70cf7c97-32a3-45b6-9149-4810d2e9cbf4");
+    /// let mut beacon_data = device_uuid.as_bytes().to_vec();
+    /// beacon_data.append(&mut vec![0x40, 0x20, 0xd9, 0x74, 0x78, 0xb3]);
+    ///
+    /// let result: MeshBeacon =
+    ///     UnprovisionedDeviceBeacon::new(device_uuid, 0x4020, Some([0xd9, 0x74, 0x78, 0xb3])).into();
+    /// assert_eq!(MeshBeacon::new(0x00, &beacon_data), result);
+    /// ```
+    fn from(value: UnprovisionedDeviceBeacon) -> Self {
+        let mut beacon_data = value.device_uuid.as_bytes().to_vec();
+        beacon_data.append(&mut value.oob_information.to_be_bytes().to_vec());
+        if let Some(uri_hash) = value.uri_hash {
+            beacon_data.append(&mut uri_hash.to_vec());
+        }
+        MeshBeacon::new(BEACON_TYPE_UNPROVISIONED_DEVICE, &beacon_data)
+    }
+}
+
+/// Secure Network beacon.
+#[derive(Debug, PartialEq, Clone)]
+pub struct SecureNetworkBeacon {
+    /// Flags
+    pub flags: u8,
+
+    /// Network ID
+    pub network_id: [u8; 8],
+
+    /// IV Index
+    pub iv_index: u32,
+
+    /// Authentication Value
+    pub authentication_value: [u8; 8],
+}
+
+impl SecureNetworkBeacon {
+    /// Create [`SecureNetworkBeacon`] from Parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::mesh_beacon::SecureNetworkBeacon;
+    ///
+    /// let network_id = [0x3e, 0xca, 0xff, 0x67, 0x2f, 0x67, 0x33, 0x70];
+    /// let authentication_value = [0x8e, 0xa2, 0x61, 0x58, 0x2f, 0x36, 0x4f, 0x6f];
+    /// let result = SecureNetworkBeacon::new(0x00, network_id, 0x12345678, authentication_value);
+    /// assert_eq!(0x00, result.flags);
+    /// assert_eq!(network_id, result.network_id);
+    /// assert_eq!(0x12345678, result.iv_index);
+    /// assert_eq!(authentication_value, result.authentication_value);
+    /// ```
+    pub fn new(
+        flags: u8,
+        network_id: [u8; 8],
+        iv_index: u32,
+        authentication_value: [u8; 8],
+    ) -> Self {
+        Self {
+            flags,
+            network_id,
+            iv_index,
+            authentication_value,
+        }
+    }
+
+    /// check Key Refresh Flag.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::mesh_beacon::{SecureNetworkBeacon, FLAGS_KEY_REFRESH};
+    ///
+    /// let result = SecureNetworkBeacon::new(FLAGS_KEY_REFRESH, [0u8; 8], 0, [0u8; 8]);
+    /// assert!(result.is_key_refresh());
+    ///
+    /// let result = SecureNetworkBeacon::new(0x00, [0u8; 8], 0, [0u8; 8]);
+    /// assert!(!result.is_key_refresh());
+    /// ```
+    pub fn is_key_refresh(&self) -> bool {
+        self.flags & FLAGS_KEY_REFRESH != 0
+    }
+
+    /// check IV Update Flag.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::mesh_beacon::{SecureNetworkBeacon, FLAGS_IV_UPDATE};
+    ///
+    /// let result = SecureNetworkBeacon::new(FLAGS_IV_UPDATE, [0u8; 8], 0, [0u8; 8]);
+    /// assert!(result.is_iv_update());
+    ///
+    /// let result = SecureNetworkBeacon::new(0x00, [0u8; 8], 0, [0u8; 8]);
+    /// assert!(!result.is_iv_update());
+    /// ```
+    pub fn is_iv_update(&self) -> bool {
+        self.flags & FLAGS_IV_UPDATE != 0
+    }
+
+    /// check Authentication Value with Beacon Key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::mesh_beacon::SecureNetworkBeacon;
+    ///
+    /// let beacon_key = [
+    ///     0x54, 0x23, 0xd9, 0x67, 0xda, 0x63, 0x9a, 0x99, 0xcb, 0x02, 0x23, 0x1a, 0x83, 0xf7, 0xd2,
+    ///     0x54,
+    /// ];
+    /// let network_id = [0x3e, 0xca, 0xff, 0x67, 0x2f, 0x67, 0x33, 0x70];
+    /// let result = SecureNetworkBeacon::new(
+    ///     0x00,
+    ///     network_id,
+    ///     0x12345678,
+    ///     [0x8e, 0xa2, 0x61, 0x58, 0x2f, 0x36, 0x4f, 0x6f],
+    /// );
+    /// assert!(result.verify(&beacon_key));
+    ///
+    /// let result = SecureNetworkBeacon::new(0x00, network_id, 0x12345678, [0u8; 8]);
+    /// assert!(!result.verify(&beacon_key));
+    /// ```
+    #[cfg(feature = "crypto")]
+    pub fn verify(&self, beacon_key: &[u8; 16]) -> bool {
+        let mut data: Vec<u8> = Vec::new();
+        data.push(self.flags);
+        data.append(&mut self.network_id.to_vec());
+        data.append(&mut self.iv_index.to_be_bytes().to_vec());
+        aes_cmac(beacon_key, &data)[..8] == self.authentication_value
+    }
+}
+
+impl TryFrom<&MeshBeacon> for SecureNetworkBeacon {
+    type Error = String;
+    /// Create [`SecureNetworkBeacon`] from [`MeshBeacon`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::mesh_beacon::{MeshBeacon, SecureNetworkBeacon};
+    ///
+    /// let beacon_data = vec![
+    ///     0x00, 0x3e, 0xca, 0xff, 0x67, 0x2f, 0x67, 0x33, 0x70, 0x12, 0x34, 0x56, 0x78, 0x8e, 0xa2,
+    ///     0x61, 0x58, 0x2f, 0x36, 0x4f, 0x6f,
+    /// ];
+    /// let result = SecureNetworkBeacon::try_from(&MeshBeacon::new(0x01, &beacon_data));
+    /// assert_eq!(
+    ///     Ok(SecureNetworkBeacon::new(
+    ///         0x00,
+    ///         [0x3e, 0xca, 0xff, 0x67, 0x2f, 0x67, 0x33, 0x70],
+    ///         0x12345678,
+    ///         [0x8e, 0xa2, 0x61, 0x58, 0x2f, 0x36, 0x4f, 0x6f],
+    ///     )),
+    ///     result
+    /// );
+    ///
+    /// let result = SecureNetworkBeacon::try_from(&MeshBeacon::new(0x00, &beacon_data));
+    /// assert!(result.is_err());
+    /// ```
+    fn try_from(value: &MeshBeacon) -> Result<Self, String> {
+        if value.beacon_type != BEACON_TYPE_SECURE_NETWORK {
+            return Err(format!("Invalid beacon type :{}", value.beacon_type).to_string());
+        }
+        let data = &value.beacon_data;
+        let len = data.len();
+        if len != 21 {
+            return Err(format!("Invalid data size :{}", len).to_string());
+        }
+        Ok(Self {
+            flags: data[0],
+            network_id: data[1..9].try_into().unwrap(),
+            iv_index: u32::from_be_bytes(data[9..13].try_into().unwrap()),
+            authentication_value: data[13..21].try_into().unwrap(),
+        })
+    }
+}
+
+impl From<SecureNetworkBeacon> for MeshBeacon {
+    /// Create [`MeshBeacon`] from [`SecureNetworkBeacon`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::mesh_beacon::{MeshBeacon, SecureNetworkBeacon};
+    ///
+    /// let beacon_data = vec![
+    ///     0x00, 0x3e, 0xca, 0xff, 0x67, 0x2f, 0x67, 0x33, 0x70, 0x12, 0x34, 0x56, 0x78, 0x8e, 0xa2,
+    ///     0x61, 0x58, 0x2f, 0x36, 0x4f, 0x6f,
+    /// ];
+    /// let result: MeshBeacon = SecureNetworkBeacon::new(
+    ///     0x00,
+    ///     [0x3e, 0xca, 0xff, 0x67, 0x2f, 0x67, 0x33, 0x70],
+    ///     0x12345678,
+    ///     [0x8e, 0xa2, 0x61, 0x58, 0x2f, 0x36, 0x4f, 0x6f],
+    /// )
+    /// .into();
+    /// assert_eq!(MeshBeacon::new(0x01, &beacon_data), result);
+    /// ```
+    fn from(value: SecureNetworkBeacon) -> Self {
+        let mut beacon_data: Vec<u8> = Vec::new();
+        beacon_data.push(value.flags);
+        beacon_data.append(&mut value.network_id.to_vec());
+        beacon_data.append(&mut value.iv_index.to_be_bytes().to_vec());
+        beacon_data.append(&mut value.authentication_value.to_vec());
+        MeshBeacon::new(BEACON_TYPE_SECURE_NETWORK, &beacon_data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::{uuid, Uuid};
+
+    use crate::data_types::{data_type::DataType, mesh_beacon::*};
+
+    const DEVICE_UUID: Uuid = uuid!("70cf7c97-32a3-45b6-9149-4810d2e9cbf4");
+
+    const NETWORK_ID: [u8; 8] = [0x3e, 0xca, 0xff, 0x67, 0x2f, 0x67, 0x33, 0x70];
+
+    const AUTHENTICATION_VALUE: [u8; 8] = [0x8e, 0xa2, 0x61, 0x58, 0x2f, 0x36, 0x4f, 0x6f];
+
+    const SECURE_NETWORK_BEACON_DATA: [u8; 21] = [
+        0x00, 0x3e, 0xca, 0xff, 0x67, 0x2f, 0x67, 0x33, 0x70, 0x12, 0x34, 0x56, 0x78, 0x8e, 0xa2,
+        0x61, 0x58, 0x2f, 0x36, 0x4f, 0x6f,
+    ];
+
+    #[test]
+    fn test_new() {
+        let beacon_data = [0x01u8; 18].to_vec();
+        let result = MeshBeacon::new(0x00, &beacon_data);
+        assert_eq!(20, result.length);
+        assert_eq!(0x00, result.beacon_type);
+        assert_eq!(beacon_data, result.beacon_data);
+    }
+
+    #[test]
+    fn test_try_from() {
+        let beacon_data = [0x01u8; 18].to_vec();
+        let mut data: Vec<u8> = vec![20, MeshBeacon::data_type(), 0x00];
+        data.append(&mut beacon_data.clone());
+
+        let result = MeshBeacon::try_from(&data);
+        assert!(result.is_ok());
+        let data_type = result.unwrap();
+        assert_eq!(20, data_type.length);
+        assert_eq!(0x00, data_type.beacon_type);
+        assert_eq!(beacon_data, data_type.beacon_data);
+
+        let data: Vec<u8> = vec![20, MeshBeacon::data_type(), 0x00];
+        let result = MeshBeacon::try_from(&data);
+        assert!(result.is_err());
+
+        let data: Vec<u8> = vec![0u8; 2];
+        let result = MeshBeacon::try_from(&data);
+        assert!(result.is_err());
+        assert_eq!(
+            format!("Invalid data size :{}", data.len()),
+            result.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_into() {
+        let beacon_data = [0x01u8; 18].to_vec();
+        let result1 = MeshBeacon::new(0x00, &beacon_data);
+
+        let mut data: Vec<u8> = vec![20, MeshBeacon::data_type(), 0x00];
+        data.append(&mut beacon_data.clone());
+
+        let into_data: Vec<u8> = result1.into();
+        assert_eq!(data, into_data);
+
+        let result2 = MeshBeacon::try_from(&data);
+        assert!(result2.is_ok());
+        let into_data: Vec<u8> = result2.unwrap().into();
+        assert_eq!(data, into_data);
+    }
+
+    #[test]
+    fn test_data_type() {
+        assert_eq!(0x2b, MeshBeacon::data_type());
+    }
+
+    #[test]
+    fn test_unprovisioned_device_beacon_new() {
+        let result = UnprovisionedDeviceBeacon::new(
+            DEVICE_UUID,
+            OOB_ELECTRONIC_URI,
+            Some([0xd9, 0x74, 0x78, 0xb3]),
+        );
+        assert_eq!(DEVICE_UUID, result.device_uuid);
+        assert_eq!(OOB_ELECTRONIC_URI, result.oob_information);
+        assert_eq!(Some([0xd9, 0x74, 0x78, 0xb3]), result.uri_hash);
+    }
+
+    #[test]
+    fn test_unprovisioned_device_beacon_has_oob_information() {
+        let result =
+            UnprovisionedDeviceBeacon::new(DEVICE_UUID, OOB_ELECTRONIC_URI | OOB_ON_BOX, None);
+        assert!(result.has_oob_information(OOB_ELECTRONIC_URI));
+        assert!(result.has_oob_information(OOB_ON_BOX));
+        assert!(result.has_oob_information(OOB_ELECTRONIC_URI | OOB_ON_BOX));
+        assert!(!result.has_oob_information(OOB_NUMBER));
+        assert!(!result.has_oob_information(OOB_OTHER | OOB_ON_BOX));
+    }
+
+    #[test]
+    fn test_unprovisioned_device_beacon_try_from() {
+        let mut beacon_data = DEVICE_UUID.as_bytes().to_vec();
+        beacon_data.append(&mut vec![0x40, 0x20]);
+        assert_eq!(
+            Ok(UnprovisionedDeviceBeacon::new(DEVICE_UUID, 0x4020, None)),
+            UnprovisionedDeviceBeacon::try_from(&MeshBeacon::new(0x00, &beacon_data))
+        );
+
+        beacon_data.append(&mut vec![0xd9, 0x74, 0x78, 0xb3]);
+        assert_eq!(
+            Ok(UnprovisionedDeviceBeacon::new(
+                DEVICE_UUID,
+                0x4020,
+                Some([0xd9, 0x74, 0x78, 0xb3])
+            )),
+            UnprovisionedDeviceBeacon::try_from(&MeshBeacon::new(0x00, &beacon_data))
+        );
+
+        assert!(UnprovisionedDeviceBeacon::try_from(&MeshBeacon::new(0x01, &beacon_data)).is_err());
+        assert!(
+            UnprovisionedDeviceBeacon::try_from(&MeshBeacon::new(0x00, &beacon_data[..20]))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_unprovisioned_device_beacon_into() {
+        let mut beacon_data = DEVICE_UUID.as_bytes().to_vec();
+        beacon_data.append(&mut vec![0x40, 0x20]);
+        let result: MeshBeacon = UnprovisionedDeviceBeacon::new(DEVICE_UUID, 0x4020, None).into();
+        assert_eq!(MeshBeacon::new(0x00, &beacon_data), result);
+    }
+
+    #[cfg(feature = "crypto")]
+    #[test]
+    fn test_unprovisioned_device_beacon_matches_uri() {
+        use crate::data_types::uniform_resource_identifier::UniformResourceIdentifier;
+
+        let uri = UniformResourceIdentifier::new(
+            &"\u{0017}//www.example.com/mesh/products/light-switch-v3".to_string(),
+        );
+        let result =
+            UnprovisionedDeviceBeacon::new(DEVICE_UUID, 0x0000, Some([0xd9, 0x74, 0x78, 0xb3]));
+        assert!(result.matches_uri(&uri));
+
+        let result =
+            UnprovisionedDeviceBeacon::new(DEVICE_UUID, 0x0000, Some([0x00, 0x74, 0x78, 0xb3]));
+        assert!(!result.matches_uri(&uri));
+
+        let result = UnprovisionedDeviceBeacon::new(DEVICE_UUID, 0x0000, None);
+        assert!(!result.matches_uri(&uri));
+    }
+
+    #[test]
+    fn test_secure_network_beacon_new() {
+        let result = SecureNetworkBeacon::new(0x00, NETWORK_ID, 0x12345678, AUTHENTICATION_VALUE);
+        assert_eq!(0x00, result.flags);
+        assert_eq!(NETWORK_ID, result.network_id);
+        assert_eq!(0x12345678, result.iv_index);
+        assert_eq!(AUTHENTICATION_VALUE, result.authentication_value);
+    }
+
+    #[test]
+    fn test_secure_network_beacon_flags() {
+        let result = SecureNetworkBeacon::new(0x00, NETWORK_ID, 0, AUTHENTICATION_VALUE);
+        assert!(!result.is_key_refresh());
+        assert!(!result.is_iv_update());
+
+        let result = SecureNetworkBeacon::new(
+            FLAGS_KEY_REFRESH | FLAGS_IV_UPDATE,
+            NETWORK_ID,
+            0,
+            AUTHENTICATION_VALUE,
+        );
+        assert!(result.is_key_refresh());
+        assert!(result.is_iv_update());
+    }
+
+    #[test]
+    fn test_secure_network_beacon_try_from() {
+        assert_eq!(
+            Ok(SecureNetworkBeacon::new(
+                0x00,
+                NETWORK_ID,
+                0x12345678,
+                AUTHENTICATION_VALUE
+            )),
+            SecureNetworkBeacon::try_from(&MeshBeacon::new(0x01, &SECURE_NETWORK_BEACON_DATA))
+        );
+        assert!(
+            SecureNetworkBeacon::try_from(&MeshBeacon::new(0x00, &SECURE_NETWORK_BEACON_DATA))
+                .is_err()
+        );
+        assert!(SecureNetworkBeacon::try_from(&MeshBeacon::new(
+            0x01,
+            &SECURE_NETWORK_BEACON_DATA[..20]
+        ))
+        .is_err());
+    }
+
+    #[test]
+    fn test_secure_network_beacon_into() {
+        let result: MeshBeacon =
+            SecureNetworkBeacon::new(0x00, NETWORK_ID, 0x12345678, AUTHENTICATION_VALUE).into();
+        assert_eq!(MeshBeacon::new(0x01, &SECURE_NETWORK_BEACON_DATA), result);
+    }
+
+    #[cfg(feature = "crypto")]
+    #[test]
+    fn test_secure_network_beacon_verify() {
+        let beacon_key = [
+            0x54, 0x23, 0xd9, 0x67, 0xda, 0x63, 0x9a, 0x99, 0xcb, 0x02, 0x23, 0x1a, 0x83, 0xf7,
+            0xd2, 0x54,
+        ];
+        let result = SecureNetworkBeacon::new(0x00, NETWORK_ID, 0x12345678, AUTHENTICATION_VALUE);
+        assert!(result.verify(&beacon_key));
+
+        let result = SecureNetworkBeacon::new(0x02, NETWORK_ID, 0x12345678, AUTHENTICATION_VALUE);
+        assert!(!result.verify(&beacon_key));
+
+        let result = SecureNetworkBeacon::new(0x00, NETWORK_ID, 0x12345679, AUTHENTICATION_VALUE);
+        assert!(!result.verify(&beacon_key));
+    }
+}
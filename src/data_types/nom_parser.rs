@@ -0,0 +1,197 @@
+//! nom based advertising payload parser module.
+//!
+//! Produces the same [`DataTypeParseResult`] / [`DataTypeParseResults`] as
+//! [`DataTypeParseResults::from_payload_with_options`], built from streaming `nom` combinators.
+
+use nom::{
+    combinator::{consumed, verify},
+    multi::length_data,
+    number::streaming::u8,
+    IResult,
+};
+
+use crate::data_types::{
+    data_type_parser::{DataTypeParseResult, DataTypeParseResults},
+    parse_options::ParseOptions,
+};
+
+/// Parse one AD structure (length, data type and data).
+///
+/// Output is the whole AD structure including length. Zero length (early termination) is
+/// [`nom::Err::Error`], and truncated input is [`nom::Err::Incomplete`] with the number of missing bytes.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::data_types::nom_parser::ad_structure;
+/// use nom::{Err, Needed};
+///
+/// let data: Vec<u8> = vec![0x02, 0x0a, 0x00, 0x02, 0x01, 0x06];
+/// assert_eq!(
+///     Ok((&data[3..], &data[..3])),
+///     ad_structure(&data)
+/// );
+///
+/// assert_eq!(
+///     Err(Err::Incomplete(Needed::new(1))),
+///     ad_structure(&[0x03, 0x09, 0x61])
+/// );
+/// assert!(matches!(ad_structure(&[0x00, 0x00]), Err(Err::Error(_))));
+/// ```
+pub fn ad_structure(input: &[u8]) -> IResult<&[u8], &[u8]> {
+    let (remaining, (structure, _)) =
+        consumed(length_data(verify(u8, |length: &u8| *length != 0)))(input)?;
+    Ok((remaining, structure))
+}
+
+/// Create AD structure parser which outputs [`DataTypeParseResult`] with [`ParseOptions`].
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::data_types::{
+///     data_type_parser::DataTypeParseResult,
+///     nom_parser::data_type,
+///     parse_options::ParseOptions,
+///     tx_power_level::TxPowerLevel,
+/// };
+///
+/// let data: Vec<u8> = vec![0x02, 0x0a, 0xfc, 0x02, 0x01, 0x06];
+/// let mut parser = data_type(ParseOptions::default());
+/// let (remaining, result) = parser(&data).unwrap();
+/// assert_eq!(&data[3..], remaining);
+/// assert_eq!(
+///     DataTypeParseResult::TxPowerLevelResult(Ok(TxPowerLevel::new(-4))),
+///     result
+/// );
+/// ```
+pub fn data_type(
+    options: ParseOptions,
+) -> impl FnMut(&[u8]) -> IResult<&[u8], DataTypeParseResult> {
+    move |input: &[u8]| {
+        let (remaining, structure) = ad_structure(input)?;
+        Ok((
+            remaining,
+            DataTypeParseResult::from_with_options(structure, &options),
+        ))
+    }
+}
+
+/// Create [`DataTypeParseResults`] from advertising payload with [`ParseOptions`].
+///
+/// Same results and offsets as [`DataTypeParseResults::from_payload_with_options`].
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::data_types::{
+///     data_type_parser::{DataTypeParseResult, DataTypeParseResults},
+///     nom_parser::results_from_payload,
+///     parse_options::ParseOptions,
+/// };
+///
+/// let data: Vec<u8> = vec![0x02, 0x01, 0x06, 0x05, 0x09, 0x61];
+/// let results = results_from_payload(&data, &ParseOptions::default());
+/// assert_eq!(&[0, 3], results.offsets());
+/// assert!(matches!(
+///     results.results[1],
+///     DataTypeParseResult::DataTypeParseError(_)
+/// ));
+///
+/// let expected = DataTypeParseResults::from(&data);
+/// assert_eq!(expected.results, results.results);
+/// assert_eq!(expected.offsets(), results.offsets());
+/// ```
+pub fn results_from_payload(input: &[u8], options: &ParseOptions) -> DataTypeParseResults {
+    let mut results = DataTypeParseResults::new(Vec::new());
+    let mut parser = data_type(*options);
+    let mut remaining = input;
+    loop {
+        let offset = input.len() - remaining.len();
+        match parser(remaining) {
+            Ok((next, result)) => {
+                results.offsets.push(offset);
+                results.results.push(result);
+                remaining = next;
+            }
+            Err(nom::Err::Incomplete(_)) if !remaining.is_empty() => {
+                results.offsets.push(offset);
+                results
+                    .results
+                    .push(DataTypeParseResult::DataTypeParseError(format!(
+                        "Invalid data size :{}",
+                        remaining.len()
+                    )));
+                break;
+            }
+            // end of payload or early termination (padding)
+            Err(_) => break,
+        }
+    }
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::{Err, Needed};
+
+    use crate::data_types::{
+        complete_local_name::CompleteLocalName,
+        data_type_parser::{DataTypeParseResult, DataTypeParseResults},
+        flags::Flags,
+        nom_parser::{ad_structure, data_type, results_from_payload},
+        parse_options::{ParseOptions, Utf8DecodeMode},
+    };
+
+    #[test]
+    fn test_ad_structure() {
+        let data: Vec<u8> = vec![0x01, 0xff, 0x02, 0x01];
+        assert_eq!(Ok((&data[2..], &data[..2])), ad_structure(&data));
+        assert_eq!(
+            Err(Err::Incomplete(Needed::new(1))),
+            ad_structure(&data[2..])
+        );
+        assert_eq!(Err(Err::Incomplete(Needed::new(1))), ad_structure(&[]));
+        assert!(matches!(ad_structure(&[0x00]), Err(Err::Error(_))));
+    }
+
+    #[test]
+    fn test_data_type() {
+        let data: Vec<u8> = vec![0x03, 0x09, 0x61, 0xff];
+        let mut parser = data_type(ParseOptions::new(Utf8DecodeMode::Lossy));
+        let (remaining, result) = parser(&data).unwrap();
+        assert!(remaining.is_empty());
+        match result {
+            DataTypeParseResult::CompleteLocalNameResult(Ok(data_type)) => {
+                assert_eq!("a\u{fffd}", data_type.complete_local_name)
+            }
+            _ => panic!("{:?}", result),
+        }
+
+        let mut parser = data_type(ParseOptions::new(Utf8DecodeMode::Strict));
+        assert!(parser(&data).unwrap().1.error().is_some());
+        assert_eq!(Err(Err::Incomplete(Needed::new(1))), parser(&data[..3]));
+    }
+
+    #[test]
+    fn test_results_from_payload() {
+        let mut flags: Vec<u8> = Flags::new(&vec![true, true]).into();
+        let cases: Vec<Vec<u8>> = vec![
+            vec![],
+            flags.clone(),
+            [flags.clone(), vec![0x00, 0x00, 0x00]].concat(),
+            [flags.clone(), vec![0x02, 0x0a]].concat(),
+            [flags.clone(), vec![0x01, 0x00, 0x01, 0xfe]].concat(),
+            {
+                flags.append(&mut CompleteLocalName::new(&"name".to_string()).into());
+                flags
+            },
+        ];
+        for data in cases {
+            let expected = DataTypeParseResults::from(&data);
+            let results = results_from_payload(&data, &ParseOptions::default());
+            assert_eq!(expected.results, results.results, "{:?}", data);
+            assert_eq!(expected.offsets, results.offsets, "{:?}", data);
+        }
+    }
+}
@@ -0,0 +1,199 @@
+//! Advertising payload normalization module.
+
+use crate::data_types::{
+    codes::{COMPLETE_LOCAL_NAME, SHORTENED_LOCAL_NAME},
+    diff::ad_structures,
+};
+
+/// Split payload into (names, other AD structures, truncated data).
+///
+/// Names and other AD structures are sorted, and padding after early termination is dropped.
+fn split(payload: &[u8]) -> (Vec<&[u8]>, Vec<&[u8]>, &[u8]) {
+    let structures = ad_structures(payload);
+    let consumed: usize = structures.iter().map(|structure| structure.len()).sum();
+    let (mut names, mut others): (Vec<&[u8]>, Vec<&[u8]>) =
+        structures.into_iter().partition(|structure| {
+            structure[1] == COMPLETE_LOCAL_NAME || structure[1] == SHORTENED_LOCAL_NAME
+        });
+    names.sort_unstable_by_key(|structure| &structure[2..]);
+    others.sort_unstable();
+    let tail = &payload[consumed..];
+    if tail.first().is_some_and(|length| *length != 0) {
+        (names, others, tail)
+    } else {
+        (names, others, &[])
+    }
+}
+
+/// check two name AD structures are the same name.
+///
+/// Shortened Local Name matches Complete Local Name starting with it.
+fn name_matches(a: &[u8], b: &[u8]) -> bool {
+    match (a[1], b[1]) {
+        (SHORTENED_LOCAL_NAME, COMPLETE_LOCAL_NAME) => b[2..].starts_with(&a[2..]),
+        (COMPLETE_LOCAL_NAME, SHORTENED_LOCAL_NAME) => a[2..].starts_with(&b[2..]),
+        _ => a == b,
+    }
+}
+
+/// Normalize advertising payload.
+///
+/// AD structures are sorted, padding after early termination is dropped, and Shortened Local
+/// Name is rewritten as Complete Local Name. Truncated data after the last well-formed AD
+/// structure is kept at the end.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::data_types::{
+///     complete_local_name::CompleteLocalName, normalize::normalize,
+///     shortened_local_name::ShortenedLocalName,
+/// };
+///
+/// let tx_power = [0x02, 0x0a, 0x00];
+/// let shortened: Vec<u8> = ShortenedLocalName::new(&"name".to_string()).into();
+/// let complete: Vec<u8> = CompleteLocalName::new(&"name".to_string()).into();
+/// assert_eq!(
+///     [&tx_power[..], &complete].concat(),
+///     normalize(&[&shortened[..], &tx_power, &[0x00, 0x00]].concat())
+/// );
+/// ```
+pub fn normalize(payload: &[u8]) -> Vec<u8> {
+    let (names, others, tail) = split(payload);
+    let mut structures: Vec<Vec<u8>> = others.iter().map(|structure| structure.to_vec()).collect();
+    structures.extend(names.iter().map(|structure| {
+        let mut structure = structure.to_vec();
+        structure[1] = COMPLETE_LOCAL_NAME;
+        structure
+    }));
+    structures.sort_unstable();
+    let mut data = structures.concat();
+    data.extend_from_slice(tail);
+    data
+}
+
+/// Compare two advertising payloads semantically.
+///
+/// The order of AD structures and padding after early termination are ignored, and Shortened
+/// Local Name is equal to Complete Local Name starting with it.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::data_types::{
+///     complete_local_name::CompleteLocalName, normalize::semantically_equal,
+///     shortened_local_name::ShortenedLocalName,
+/// };
+///
+/// let tx_power = [0x02, 0x0a, 0x00];
+/// let shortened: Vec<u8> = ShortenedLocalName::new(&"na".to_string()).into();
+/// let complete: Vec<u8> = CompleteLocalName::new(&"name".to_string()).into();
+/// assert!(semantically_equal(
+///     &[&shortened[..], &tx_power].concat(),
+///     &[&tx_power[..], &complete].concat()
+/// ));
+///
+/// let other: Vec<u8> = CompleteLocalName::new(&"other".to_string()).into();
+/// assert!(!semantically_equal(&complete, &other));
+/// ```
+pub fn semantically_equal(a: &[u8], b: &[u8]) -> bool {
+    let (a_names, a_others, a_tail) = split(a);
+    let (b_names, b_others, b_tail) = split(b);
+    a_others == b_others
+        && a_tail == b_tail
+        && a_names.len() == b_names.len()
+        && a_names
+            .iter()
+            .zip(b_names.iter())
+            .all(|(a, b)| name_matches(a, b))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data_types::{
+        complete_local_name::CompleteLocalName,
+        normalize::{name_matches, normalize, semantically_equal, split},
+        shortened_local_name::ShortenedLocalName,
+    };
+
+    const FLAGS: [u8; 3] = [0x02, 0x01, 0x06];
+    const TX_POWER: [u8; 3] = [0x02, 0x0a, 0x00];
+
+    #[test]
+    fn test_split() {
+        let name: Vec<u8> = CompleteLocalName::new(&"name".to_string()).into();
+        let data = [&TX_POWER[..], &name, &FLAGS, &[0x00, 0x00]].concat();
+        let (names, others, tail) = split(&data);
+        assert_eq!(vec![&name[..]], names);
+        assert_eq!(vec![&FLAGS[..], &TX_POWER[..]], others);
+        assert!(tail.is_empty());
+
+        let data = [&FLAGS[..], &[0x05, 0x09, 0x61]].concat();
+        let (names, others, tail) = split(&data);
+        assert!(names.is_empty());
+        assert_eq!(vec![&FLAGS[..]], others);
+        assert_eq!(&[0x05, 0x09, 0x61], tail);
+    }
+
+    #[test]
+    fn test_name_matches() {
+        let shortened: Vec<u8> = ShortenedLocalName::new(&"na".to_string()).into();
+        let complete: Vec<u8> = CompleteLocalName::new(&"name".to_string()).into();
+        let other: Vec<u8> = CompleteLocalName::new(&"na".to_string()).into();
+        assert!(name_matches(&shortened, &complete));
+        assert!(name_matches(&complete, &shortened));
+        assert!(name_matches(&complete, &complete));
+        assert!(name_matches(&shortened, &shortened));
+        assert!(name_matches(&shortened, &other));
+        assert!(!name_matches(&complete, &other));
+
+        let shortened: Vec<u8> = ShortenedLocalName::new(&"ne".to_string()).into();
+        assert!(!name_matches(&shortened, &complete));
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert!(normalize(&[]).is_empty());
+        assert!(normalize(&[0x00, 0x00]).is_empty());
+
+        let shortened: Vec<u8> = ShortenedLocalName::new(&"name".to_string()).into();
+        let complete: Vec<u8> = CompleteLocalName::new(&"name".to_string()).into();
+        let data = [&shortened[..], &TX_POWER, &FLAGS].concat();
+        let normalized = [&FLAGS[..], &TX_POWER, &complete].concat();
+        assert_eq!(normalized, normalize(&data));
+        assert_eq!(normalized, normalize(&normalized));
+
+        // truncated data kept at the end
+        let data = [&TX_POWER[..], &FLAGS, &[0x05, 0x09]].concat();
+        assert_eq!(
+            [&FLAGS[..], &TX_POWER, &[0x05, 0x09]].concat(),
+            normalize(&data)
+        );
+    }
+
+    #[test]
+    fn test_semantically_equal() {
+        assert!(semantically_equal(&[], &[]));
+        assert!(semantically_equal(&[], &[0x00]));
+        assert!(semantically_equal(
+            &[FLAGS, TX_POWER].concat(),
+            &[&TX_POWER[..], &FLAGS, &[0x00]].concat()
+        ));
+        assert!(!semantically_equal(&FLAGS, &[FLAGS, TX_POWER].concat()));
+        assert!(!semantically_equal(&FLAGS, &[&FLAGS[..], &[0x02]].concat()));
+
+        let shortened: Vec<u8> = ShortenedLocalName::new(&"na".to_string()).into();
+        let complete: Vec<u8> = CompleteLocalName::new(&"name".to_string()).into();
+        assert!(semantically_equal(
+            &[&FLAGS[..], &shortened].concat(),
+            &[&complete[..], &FLAGS].concat()
+        ));
+        assert!(!semantically_equal(
+            &FLAGS,
+            &[&FLAGS[..], &complete].concat()
+        ));
+
+        let other: Vec<u8> = ShortenedLocalName::new(&"ne".to_string()).into();
+        assert!(!semantically_equal(&other, &complete));
+    }
+}
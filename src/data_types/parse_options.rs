@@ -0,0 +1,94 @@
+//! Parse options module.
+
+/// UTF-8 decode mode.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum Utf8DecodeMode {
+    /// Replace invalid UTF-8 sequences with `U+FFFD`.
+    #[default]
+    Lossy,
+
+    /// Return error on invalid UTF-8 sequences.
+    Strict,
+}
+
+/// Parse options.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct ParseOptions {
+    /// UTF-8 decode mode for local names
+    pub utf8_decode_mode: Utf8DecodeMode,
+}
+
+impl ParseOptions {
+    /// Create [`ParseOptions`] from Parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::parse_options::{ParseOptions, Utf8DecodeMode};
+    ///
+    /// let result = ParseOptions::new(Utf8DecodeMode::Strict);
+    /// assert_eq!(Utf8DecodeMode::Strict, result.utf8_decode_mode);
+    ///
+    /// let result = ParseOptions::default();
+    /// assert_eq!(Utf8DecodeMode::Lossy, result.utf8_decode_mode);
+    /// ```
+    pub const fn new(utf8_decode_mode: Utf8DecodeMode) -> Self {
+        Self { utf8_decode_mode }
+    }
+}
+
+/// Decode UTF-8 bytes with [`Utf8DecodeMode`].
+pub(crate) fn decode_utf8(
+    value: &[u8],
+    utf8_decode_mode: Utf8DecodeMode,
+) -> Result<String, String> {
+    match utf8_decode_mode {
+        Utf8DecodeMode::Lossy => Ok(String::from_utf8_lossy(value).to_string()),
+        Utf8DecodeMode::Strict => match std::str::from_utf8(value) {
+            Ok(value) => Ok(value.to_string()),
+            Err(error) => Err(format!("Invalid UTF-8 :{}", error).to_string()),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data_types::parse_options::*;
+
+    #[test]
+    fn test_new() {
+        let result = ParseOptions::new(Utf8DecodeMode::Strict);
+        assert_eq!(Utf8DecodeMode::Strict, result.utf8_decode_mode);
+
+        let result = ParseOptions::new(Utf8DecodeMode::Lossy);
+        assert_eq!(Utf8DecodeMode::Lossy, result.utf8_decode_mode);
+    }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(
+            ParseOptions::new(Utf8DecodeMode::Lossy),
+            ParseOptions::default()
+        );
+    }
+
+    #[test]
+    fn test_decode_utf8() {
+        assert_eq!(
+            Ok("name".to_string()),
+            decode_utf8("name".as_bytes(), Utf8DecodeMode::Lossy)
+        );
+        assert_eq!(
+            Ok("name".to_string()),
+            decode_utf8("name".as_bytes(), Utf8DecodeMode::Strict)
+        );
+        assert_eq!(
+            Ok("na\u{fffd}me".to_string()),
+            decode_utf8(&[0x6e, 0x61, 0xff, 0x6d, 0x65], Utf8DecodeMode::Lossy)
+        );
+        assert_eq!(
+            Err("Invalid UTF-8 :invalid utf-8 sequence of 1 bytes from index 2".to_string()),
+            decode_utf8(&[0x6e, 0x61, 0xff, 0x6d, 0x65], Utf8DecodeMode::Strict)
+        );
+    }
+}
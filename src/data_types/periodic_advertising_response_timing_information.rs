@@ -1,6 +1,17 @@
 //! Peripheral Connection Interval Range (Data Type Value: 0x32) module.
 
-use crate::data_types::data_type::DataType;
+use std::time::Duration;
+
+use crate::data_types::{
+    ad_type::AdType,
+    data_type::{validate_length, DataType},
+};
+
+/// subeventInterval / responseSlotDelay unit (microseconds)
+pub const UNIT_1250_US: u64 = 1250;
+
+/// responseSlotSpacing unit (microseconds)
+pub const UNIT_125_US: u64 = 125;
 
 /// Peripheral Connection Interval Range.
 #[derive(Debug, PartialEq, Clone)]
@@ -51,7 +62,7 @@ impl PeriodicAdvertisingResponseTimingInformation {
     /// assert_eq!(response_slot_delay, result.response_slot_delay);
     /// assert_eq!(response_slot_spacing, result.response_slot_spacing);
     /// ```
-    pub fn new(
+    pub const fn new(
         rsp_aa: &[u8; 4],
         num_subevents: u8,
         subevent_interval: u8,
@@ -60,18 +71,63 @@ impl PeriodicAdvertisingResponseTimingInformation {
     ) -> Self {
         Self {
             length: 9,
-            rsp_aa: rsp_aa.clone(),
+            rsp_aa: *rsp_aa,
             num_subevents,
             subevent_interval,
             response_slot_delay,
             response_slot_spacing,
         }
     }
+
+    /// get subeventInterval as [`Duration`] (1.25 ms units).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use ble_data_struct::data_types::periodic_advertising_response_timing_information::PeriodicAdvertisingResponseTimingInformation;
+    ///
+    /// let result = PeriodicAdvertisingResponseTimingInformation::new(&[1, 2, 3, 4], 6, 7, 8, 9);
+    /// assert_eq!(Duration::from_micros(8750), result.subevent_interval_duration());
+    /// ```
+    pub fn subevent_interval_duration(&self) -> Duration {
+        Duration::from_micros(self.subevent_interval as u64 * UNIT_1250_US)
+    }
+
+    /// get responseSlotDelay as [`Duration`] (1.25 ms units).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use ble_data_struct::data_types::periodic_advertising_response_timing_information::PeriodicAdvertisingResponseTimingInformation;
+    ///
+    /// let result = PeriodicAdvertisingResponseTimingInformation::new(&[1, 2, 3, 4], 6, 7, 8, 9);
+    /// assert_eq!(Duration::from_millis(10), result.response_slot_delay_duration());
+    /// ```
+    pub fn response_slot_delay_duration(&self) -> Duration {
+        Duration::from_micros(self.response_slot_delay as u64 * UNIT_1250_US)
+    }
+
+    /// get responseSlotSpacing as [`Duration`] (0.125 ms units).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use ble_data_struct::data_types::periodic_advertising_response_timing_information::PeriodicAdvertisingResponseTimingInformation;
+    ///
+    /// let result = PeriodicAdvertisingResponseTimingInformation::new(&[1, 2, 3, 4], 6, 7, 8, 9);
+    /// assert_eq!(Duration::from_micros(1125), result.response_slot_spacing_duration());
+    /// ```
+    pub fn response_slot_spacing_duration(&self) -> Duration {
+        Duration::from_micros(self.response_slot_spacing as u64 * UNIT_125_US)
+    }
 }
 
-impl TryFrom<&Vec<u8>> for PeriodicAdvertisingResponseTimingInformation {
+impl TryFrom<&[u8]> for PeriodicAdvertisingResponseTimingInformation {
     type Error = String;
-    /// Create [`PeriodicAdvertisingResponseTimingInformation`] from [`Vec<u8>`].
+    /// Create [`PeriodicAdvertisingResponseTimingInformation`] from `u8` slice.
     ///
     /// # Examples
     ///
@@ -93,7 +149,7 @@ impl TryFrom<&Vec<u8>> for PeriodicAdvertisingResponseTimingInformation {
     /// data.push(response_slot_delay);
     /// data.push(response_slot_spacing);
     ///
-    /// let result = PeriodicAdvertisingResponseTimingInformation::try_from(&data);
+    /// let result = PeriodicAdvertisingResponseTimingInformation::try_from(data.as_slice());
     /// assert!(result.is_ok());
     /// let data_type = result.unwrap();
     /// assert_eq!(length, data_type.length);
@@ -104,19 +160,19 @@ impl TryFrom<&Vec<u8>> for PeriodicAdvertisingResponseTimingInformation {
     /// assert_eq!(response_slot_spacing, data_type.response_slot_spacing);
     ///
     /// let data: Vec<u8> = Vec::new();
-    /// let result = PeriodicAdvertisingResponseTimingInformation::try_from(&data);
+    /// let result = PeriodicAdvertisingResponseTimingInformation::try_from(data.as_slice());
     /// assert!(result.is_err());
     /// assert_eq!(
     ///     format!("Invalid data size :{}", data.len()),
     ///     result.unwrap_err()
     /// );
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+    fn try_from(value: &[u8]) -> Result<Self, String> {
         let len = value.len();
         if len < 10 {
             return Err(format!("Invalid data size :{}", len).to_string());
         }
-        let length = value[0];
+        let length = validate_length(value, 9, 9)?;
         Ok(Self {
             length,
             rsp_aa: value[2..6].try_into().unwrap(),
@@ -128,6 +184,53 @@ impl TryFrom<&Vec<u8>> for PeriodicAdvertisingResponseTimingInformation {
     }
 }
 
+impl TryFrom<&Vec<u8>> for PeriodicAdvertisingResponseTimingInformation {
+    type Error = String;
+    /// Create [`PeriodicAdvertisingResponseTimingInformation`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{periodic_advertising_response_timing_information::PeriodicAdvertisingResponseTimingInformation, data_type::DataType};
+    ///
+    /// let rsp_aa: [u8; 4] = [1, 2, 3, 4];
+    /// let num_subevents = 6u8;
+    /// let subevent_interval = 7u8;
+    /// let response_slot_delay = 8u8;
+    /// let response_slot_spacing = 9u8;
+    /// let length = 9;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(PeriodicAdvertisingResponseTimingInformation::data_type());
+    /// data.append(&mut rsp_aa.to_vec());
+    /// data.push(num_subevents);
+    /// data.push(subevent_interval);
+    /// data.push(response_slot_delay);
+    /// data.push(response_slot_spacing);
+    ///
+    /// let result = PeriodicAdvertisingResponseTimingInformation::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert_eq!(rsp_aa, data_type.rsp_aa);
+    /// assert_eq!(num_subevents, data_type.num_subevents);
+    /// assert_eq!(subevent_interval, data_type.subevent_interval);
+    /// assert_eq!(response_slot_delay, data_type.response_slot_delay);
+    /// assert_eq!(response_slot_spacing, data_type.response_slot_spacing);
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = PeriodicAdvertisingResponseTimingInformation::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        Self::try_from(value.as_slice())
+    }
+}
+
 impl Into<Vec<u8>> for PeriodicAdvertisingResponseTimingInformation {
     /// Create [`Vec<u8>`] from [`PeriodicAdvertisingResponseTimingInformation`].
     ///
@@ -206,12 +309,17 @@ impl DataType for PeriodicAdvertisingResponseTimingInformation {
 /// assert!(is_periodic_advertising_response_timing_information(0x32));
 /// assert!(!is_periodic_advertising_response_timing_information(0x00));
 /// ```
+#[deprecated(
+    note = "use `AdType::of(data_type).matches::<PeriodicAdvertisingResponseTimingInformation>()` instead"
+)]
 pub fn is_periodic_advertising_response_timing_information(data_type: u8) -> bool {
-    PeriodicAdvertisingResponseTimingInformation::data_type() == data_type
+    AdType::of(data_type).matches::<PeriodicAdvertisingResponseTimingInformation>()
 }
 
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
+
     use crate::data_types::{
         data_type::DataType, periodic_advertising_response_timing_information::*,
     };
@@ -238,6 +346,51 @@ mod tests {
         assert_eq!(response_slot_spacing, result.response_slot_spacing);
     }
 
+    #[test]
+    fn test_subevent_interval_duration() {
+        let result = PeriodicAdvertisingResponseTimingInformation::new(&[1, 2, 3, 4], 6, 0, 8, 9);
+        assert_eq!(Duration::ZERO, result.subevent_interval_duration());
+
+        let result =
+            PeriodicAdvertisingResponseTimingInformation::new(&[1, 2, 3, 4], 6, 0xff, 8, 9);
+        assert_eq!(
+            Duration::from_micros(318_750),
+            result.subevent_interval_duration()
+        );
+    }
+
+    #[test]
+    fn test_response_slot_delay_duration() {
+        let result = PeriodicAdvertisingResponseTimingInformation::new(&[1, 2, 3, 4], 6, 7, 1, 9);
+        assert_eq!(
+            Duration::from_micros(1250),
+            result.response_slot_delay_duration()
+        );
+
+        let result =
+            PeriodicAdvertisingResponseTimingInformation::new(&[1, 2, 3, 4], 6, 7, 0xfe, 9);
+        assert_eq!(
+            Duration::from_micros(317_500),
+            result.response_slot_delay_duration()
+        );
+    }
+
+    #[test]
+    fn test_response_slot_spacing_duration() {
+        let result = PeriodicAdvertisingResponseTimingInformation::new(&[1, 2, 3, 4], 6, 7, 8, 2);
+        assert_eq!(
+            Duration::from_micros(250),
+            result.response_slot_spacing_duration()
+        );
+
+        let result =
+            PeriodicAdvertisingResponseTimingInformation::new(&[1, 2, 3, 4], 6, 7, 8, 0xff);
+        assert_eq!(
+            Duration::from_micros(31_875),
+            result.response_slot_spacing_duration()
+        );
+    }
+
     #[test]
     fn test_try_from() {
         let rsp_aa: [u8; 4] = [1, 2, 3, 4];
@@ -318,6 +471,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_is_periodic_advertising_response_timing_information() {
         assert!(is_periodic_advertising_response_timing_information(0x32));
         assert!(!is_periodic_advertising_response_timing_information(0x00));
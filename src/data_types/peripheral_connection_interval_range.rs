@@ -1,6 +1,9 @@
 //! Peripheral Connection Interval Range (Data Type Value: 0x12) module.
 
-use crate::data_types::data_type::DataType;
+use crate::data_types::{
+    ad_type::AdType,
+    data_type::{validate_length, DataType},
+};
 
 /// Peripheral Connection Interval Range.
 
@@ -31,7 +34,7 @@ impl PeripheralConnectionIntervalRange {
     /// assert_eq!(minimum_value, result.minimum_value);
     /// assert_eq!(maximum_value, result.maximum_value);
     /// ```
-    pub fn new(minimum_value: u16, maximum_value: u16) -> Self {
+    pub const fn new(minimum_value: u16, maximum_value: u16) -> Self {
         Self {
             length: 5,
             minimum_value,
@@ -140,9 +143,9 @@ pub const CONNECTION_INTERVAL_RANGE: f32 = 1.25;
 /// no specific minimum / maximum values
 pub const CONNECTION_INTERVAL_NO_SPECIFIC_VALUE: u16 = 0xffff;
 
-impl TryFrom<&Vec<u8>> for PeripheralConnectionIntervalRange {
+impl TryFrom<&[u8]> for PeripheralConnectionIntervalRange {
     type Error = String;
-    /// Create [`PeripheralConnectionIntervalRange`] from [`Vec<u8>`].
+    /// Create [`PeripheralConnectionIntervalRange`] from `u8` slice.
     ///
     /// # Examples
     ///
@@ -158,7 +161,7 @@ impl TryFrom<&Vec<u8>> for PeripheralConnectionIntervalRange {
     /// data.append(&mut minimum_value.to_le_bytes().to_vec());
     /// data.append(&mut maximum_value.to_le_bytes().to_vec());
     ///
-    /// let result = PeripheralConnectionIntervalRange::try_from(&data);
+    /// let result = PeripheralConnectionIntervalRange::try_from(data.as_slice());
     /// assert!(result.is_ok());
     /// let data_type = result.unwrap();
     /// assert_eq!(length, data_type.length);
@@ -166,19 +169,19 @@ impl TryFrom<&Vec<u8>> for PeripheralConnectionIntervalRange {
     /// assert_eq!(maximum_value, data_type.maximum_value);
     ///
     /// let data: Vec<u8> = Vec::new();
-    /// let result = PeripheralConnectionIntervalRange::try_from(&data);
+    /// let result = PeripheralConnectionIntervalRange::try_from(data.as_slice());
     /// assert!(result.is_err());
     /// assert_eq!(
     ///     format!("Invalid data size :{}", data.len()),
     ///     result.unwrap_err()
     /// );
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+    fn try_from(value: &[u8]) -> Result<Self, String> {
         let len = value.len();
         if len < 6 {
             return Err(format!("Invalid data size :{}", len).to_string());
         }
-        let length = value[0];
+        let length = validate_length(value, 5, 5)?;
         Ok(Self {
             length,
             minimum_value: u16::from_le_bytes(value[2..4].try_into().unwrap()),
@@ -187,6 +190,44 @@ impl TryFrom<&Vec<u8>> for PeripheralConnectionIntervalRange {
     }
 }
 
+impl TryFrom<&Vec<u8>> for PeripheralConnectionIntervalRange {
+    type Error = String;
+    /// Create [`PeripheralConnectionIntervalRange`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{peripheral_connection_interval_range::PeripheralConnectionIntervalRange, data_type::DataType};
+    ///
+    /// let minimum_value = 0x0006u16;
+    /// let maximum_value = 0x0C80u16;
+    /// let length = 5;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(PeripheralConnectionIntervalRange::data_type());
+    /// data.append(&mut minimum_value.to_le_bytes().to_vec());
+    /// data.append(&mut maximum_value.to_le_bytes().to_vec());
+    ///
+    /// let result = PeripheralConnectionIntervalRange::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert_eq!(minimum_value, data_type.minimum_value);
+    /// assert_eq!(maximum_value, data_type.maximum_value);
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = PeripheralConnectionIntervalRange::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        Self::try_from(value.as_slice())
+    }
+}
+
 impl Into<Vec<u8>> for PeripheralConnectionIntervalRange {
     /// Create [`Vec<u8>`] from [`PeripheralConnectionIntervalRange`].
     ///
@@ -250,8 +291,11 @@ impl DataType for PeripheralConnectionIntervalRange {
 /// assert!(is_peripheral_connection_interval_range(0x12));
 /// assert!(!is_peripheral_connection_interval_range(0x00));
 /// ```
+#[deprecated(
+    note = "use `AdType::of(data_type).matches::<PeripheralConnectionIntervalRange>()` instead"
+)]
 pub fn is_peripheral_connection_interval_range(data_type: u8) -> bool {
-    PeripheralConnectionIntervalRange::data_type() == data_type
+    AdType::of(data_type).matches::<PeripheralConnectionIntervalRange>()
 }
 
 #[cfg(test)]
@@ -372,6 +416,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_is_peripheral_connection_interval_range() {
         assert!(is_peripheral_connection_interval_range(0x12));
         assert!(!is_peripheral_connection_interval_range(0x00));
@@ -1,6 +1,12 @@
 //! Public Target Address (Data Type Value:0x17) module.
 
-use crate::data_types::data_type::DataType;
+#[cfg(feature = "oui")]
+use crate::oui;
+
+use crate::data_types::{
+    ad_type::AdType,
+    data_type::{validate_length, DataType},
+};
 
 /// Public Target Address.
 
@@ -39,11 +45,69 @@ impl PublicTargetAddress {
             public_target_address: public_target_address.clone(),
         }
     }
+
+    /// Returns an iterator over the addresses.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::public_target_address::PublicTargetAddress;
+    ///
+    /// let result = PublicTargetAddress::new(&vec![0x0000060504030201u64, 0x00000c0b0a090807u64]);
+    /// let mut iter = result.iter();
+    /// assert_eq!(Some(&0x0000060504030201u64), iter.next());
+    /// assert_eq!(Some(&0x00000c0b0a090807u64), iter.next());
+    /// assert_eq!(None, iter.next());
+    /// ```
+    pub fn iter(&self) -> std::slice::Iter<'_, u64> {
+        self.public_target_address.iter()
+    }
+
+    /// Get vendor names of each Public Target Address from OUI.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::public_target_address::PublicTargetAddress;
+    ///
+    /// let result = PublicTargetAddress::new(&vec![0x0000b827eb010203u64, 0x0000000000010203u64]);
+    /// assert_eq!(
+    ///     vec![Some("Raspberry Pi Foundation"), None],
+    ///     result.vendor_names()
+    /// );
+    /// ```
+    #[cfg(feature = "oui")]
+    pub fn vendor_names(&self) -> Vec<Option<&'static str>> {
+        self.public_target_address
+            .iter()
+            .map(|address| oui::vendor_name(*address))
+            .collect()
+    }
 }
 
-impl TryFrom<&Vec<u8>> for PublicTargetAddress {
+impl<'a> IntoIterator for &'a PublicTargetAddress {
+    type Item = &'a u64;
+    type IntoIter = std::slice::Iter<'a, u64>;
+
+    /// Create iterator over the addresses of [`PublicTargetAddress`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::public_target_address::PublicTargetAddress;
+    ///
+    /// let result = PublicTargetAddress::new(&vec![0x0000060504030201u64, 0x00000c0b0a090807u64]);
+    /// let addresses: Vec<u64> = (&result).into_iter().copied().collect();
+    /// assert_eq!(result.public_target_address, addresses);
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl TryFrom<&[u8]> for PublicTargetAddress {
     type Error = String;
-    /// Create [`PublicTargetAddress`] from [`Vec<u8>`].
+    /// Create [`PublicTargetAddress`] from `u8` slice.
     ///
     /// # Examples
     ///
@@ -75,26 +139,34 @@ impl TryFrom<&Vec<u8>> for PublicTargetAddress {
     /// data.push(PublicTargetAddress::data_type());
     /// data.append(&mut public_target_address_bytes.clone());
     ///
-    /// let result = PublicTargetAddress::try_from(&data);
+    /// let result = PublicTargetAddress::try_from(data.as_slice());
     /// assert!(result.is_ok());
     /// let data_type = result.unwrap();
     /// assert_eq!(length, data_type.length);
     /// assert_eq!(public_target_address, data_type.public_target_address);
     ///
     /// let data: Vec<u8> = Vec::new();
-    /// let result = PublicTargetAddress::try_from(&data);
+    /// let result = PublicTargetAddress::try_from(data.as_slice());
     /// assert!(result.is_err());
     /// assert_eq!(
     ///     format!("Invalid data size :{}", data.len()),
     ///     result.unwrap_err()
     /// );
+    ///
+    /// let data: Vec<u8> = vec![8, PublicTargetAddress::data_type(), 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07];
+    /// let result = PublicTargetAddress::try_from(data.as_slice());
+    /// assert!(result.is_err());
+    /// assert_eq!("Invalid length :8", result.unwrap_err());
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+    fn try_from(value: &[u8]) -> Result<Self, String> {
         let len = value.len();
         if len < 8 {
             return Err(format!("Invalid data size :{}", len).to_string());
         }
-        let length = value[0];
+        let length = validate_length(value, 7, 255)?;
+        if !(length - 1).is_multiple_of(6) {
+            return Err(format!("Invalid length :{}", length).to_string());
+        }
         Ok(Self {
             length,
             public_target_address: value[2..2 + length as usize - 1]
@@ -115,6 +187,64 @@ impl TryFrom<&Vec<u8>> for PublicTargetAddress {
     }
 }
 
+impl TryFrom<&Vec<u8>> for PublicTargetAddress {
+    type Error = String;
+    /// Create [`PublicTargetAddress`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{public_target_address::PublicTargetAddress, data_type::DataType};
+    ///
+    /// let public_target_address_bytes = [
+    ///     0x01u8, 0x02u8, 0x03u8, 0x04u8, 0x05u8, 0x06u8, 0x07u8, 0x08u8, 0x09u8, 0x0au8, 0x0bu8,
+    ///     0x0cu8,
+    /// ]
+    /// .to_vec();
+    /// let public_target_address: Vec<u64> = public_target_address_bytes
+    ///     .windows(6)
+    ///     .step_by(6)
+    ///     .map(|f| {
+    ///         let mut bytes = [0x00u8; 8];
+    ///         bytes[0] = f[0];
+    ///         bytes[1] = f[1];
+    ///         bytes[2] = f[2];
+    ///         bytes[3] = f[3];
+    ///         bytes[4] = f[4];
+    ///         bytes[5] = f[5];
+    ///         u64::from_le_bytes(bytes)
+    ///     })
+    ///     .collect();
+    /// let length = public_target_address_bytes.len() as u8 + 1;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(PublicTargetAddress::data_type());
+    /// data.append(&mut public_target_address_bytes.clone());
+    ///
+    /// let result = PublicTargetAddress::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert_eq!(public_target_address, data_type.public_target_address);
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = PublicTargetAddress::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    ///
+    /// let data: Vec<u8> = vec![8, PublicTargetAddress::data_type(), 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07];
+    /// let result = PublicTargetAddress::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!("Invalid length :8", result.unwrap_err());
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        Self::try_from(value.as_slice())
+    }
+}
+
 impl Into<Vec<u8>> for PublicTargetAddress {
     /// Create [`Vec<u8>`] from [`PublicTargetAddress`].
     ///
@@ -202,8 +332,9 @@ impl DataType for PublicTargetAddress {
 /// assert!(is_public_target_address(0x17));
 /// assert!(!is_public_target_address(0x00));
 /// ```
+#[deprecated(note = "use `AdType::of(data_type).matches::<PublicTargetAddress>()` instead")]
 pub fn is_public_target_address(data_type: u8) -> bool {
-    PublicTargetAddress::data_type() == data_type
+    AdType::of(data_type).matches::<PublicTargetAddress>()
 }
 
 #[cfg(test)]
@@ -226,6 +357,48 @@ mod tests {
         assert_eq!(public_target_address, result.public_target_address);
     }
 
+    #[cfg(feature = "oui")]
+    #[test]
+    fn test_vendor_names() {
+        let result = PublicTargetAddress::new(&Vec::new());
+        assert!(result.vendor_names().is_empty());
+
+        let result = PublicTargetAddress::new(&vec![0x0000240ac4010203u64, 0x0000ffffff010203u64]);
+        assert_eq!(vec![Some("Espressif Inc."), None], result.vendor_names());
+    }
+
+    #[test]
+    fn test_iter() {
+        let result = PublicTargetAddress::new(&Vec::new());
+        assert_eq!(None, result.iter().next());
+
+        let public_target_address: Vec<u64> = vec![0x0000060504030201u64, 0x00000c0b0a090807u64];
+        let result = PublicTargetAddress::new(&public_target_address);
+        assert_eq!(
+            public_target_address,
+            result.iter().copied().collect::<Vec<u64>>()
+        );
+
+        let mut count = 0;
+        for address in &result {
+            assert_eq!(public_target_address[count], *address);
+            count += 1;
+        }
+        assert_eq!(2, count);
+    }
+
+    #[test]
+    fn test_try_from_invalid_length() {
+        for length in [1u8, 6, 8, 12, 14] {
+            let mut data: Vec<u8> = vec![length, PublicTargetAddress::data_type()];
+            data.append(&mut vec![0x00u8; length as usize - 1].to_vec());
+            data.resize(data.len().max(8), 0x00);
+            let result = PublicTargetAddress::try_from(&data);
+            assert!(result.is_err());
+            assert_eq!(format!("Invalid length :{}", length), result.unwrap_err());
+        }
+    }
+
     #[test]
     fn test_try_from() {
         let public_target_address_bytes = [
@@ -258,7 +431,7 @@ mod tests {
         let data_type = result.unwrap();
         assert_eq!(length, data_type.length);
         assert_eq!(public_target_address, data_type.public_target_address);
-        
+
         let mut data: Vec<u8> = vec![0u8; 7];
         data[0] = data.len() as u8 - 1;
         let result = PublicTargetAddress::try_from(&data);
@@ -314,6 +487,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_is_public_target_address() {
         assert!(is_public_target_address(0x17));
         assert!(!is_public_target_address(0x00));
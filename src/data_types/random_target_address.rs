@@ -1,6 +1,9 @@
 //! Random Target Address (Data Type Value:0x18) module.
 
-use crate::data_types::data_type::DataType;
+use crate::data_types::{
+    ad_type::AdType,
+    data_type::{validate_length, DataType},
+};
 
 /// Random Target Address.
 #[derive(Debug, PartialEq, Clone)]
@@ -38,11 +41,48 @@ impl RandomTargetAddress {
             random_target_address: random_target_address.clone(),
         }
     }
+
+    /// Returns an iterator over the addresses.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::random_target_address::RandomTargetAddress;
+    ///
+    /// let result = RandomTargetAddress::new(&vec![0x0000060504030201u64, 0x00000c0b0a090807u64]);
+    /// let mut iter = result.iter();
+    /// assert_eq!(Some(&0x0000060504030201u64), iter.next());
+    /// assert_eq!(Some(&0x00000c0b0a090807u64), iter.next());
+    /// assert_eq!(None, iter.next());
+    /// ```
+    pub fn iter(&self) -> std::slice::Iter<'_, u64> {
+        self.random_target_address.iter()
+    }
 }
 
-impl TryFrom<&Vec<u8>> for RandomTargetAddress {
+impl<'a> IntoIterator for &'a RandomTargetAddress {
+    type Item = &'a u64;
+    type IntoIter = std::slice::Iter<'a, u64>;
+
+    /// Create iterator over the addresses of [`RandomTargetAddress`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::random_target_address::RandomTargetAddress;
+    ///
+    /// let result = RandomTargetAddress::new(&vec![0x0000060504030201u64, 0x00000c0b0a090807u64]);
+    /// let addresses: Vec<u64> = (&result).into_iter().copied().collect();
+    /// assert_eq!(result.random_target_address, addresses);
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl TryFrom<&[u8]> for RandomTargetAddress {
     type Error = String;
-    /// Create [`RandomTargetAddress`] from [`Vec<u8>`].
+    /// Create [`RandomTargetAddress`] from `u8` slice.
     ///
     /// # Examples
     ///
@@ -74,26 +114,34 @@ impl TryFrom<&Vec<u8>> for RandomTargetAddress {
     /// data.push(RandomTargetAddress::data_type());
     /// data.append(&mut random_target_address_bytes.clone());
     ///
-    /// let result = RandomTargetAddress::try_from(&data);
+    /// let result = RandomTargetAddress::try_from(data.as_slice());
     /// assert!(result.is_ok());
     /// let data_type = result.unwrap();
     /// assert_eq!(length, data_type.length);
     /// assert_eq!(random_target_address, data_type.random_target_address);
     ///
     /// let data: Vec<u8> = Vec::new();
-    /// let result = RandomTargetAddress::try_from(&data);
+    /// let result = RandomTargetAddress::try_from(data.as_slice());
     /// assert!(result.is_err());
     /// assert_eq!(
     ///     format!("Invalid data size :{}", data.len()),
     ///     result.unwrap_err()
     /// );
+    ///
+    /// let data: Vec<u8> = vec![8, RandomTargetAddress::data_type(), 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07];
+    /// let result = RandomTargetAddress::try_from(data.as_slice());
+    /// assert!(result.is_err());
+    /// assert_eq!("Invalid length :8", result.unwrap_err());
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+    fn try_from(value: &[u8]) -> Result<Self, String> {
         let len = value.len();
         if len < 8 {
             return Err(format!("Invalid data size :{}", len).to_string());
         }
-        let length = value[0];
+        let length = validate_length(value, 7, 255)?;
+        if !(length - 1).is_multiple_of(6) {
+            return Err(format!("Invalid length :{}", length).to_string());
+        }
         Ok(Self {
             length,
             random_target_address: value[2..2 + length as usize - 1]
@@ -114,6 +162,64 @@ impl TryFrom<&Vec<u8>> for RandomTargetAddress {
     }
 }
 
+impl TryFrom<&Vec<u8>> for RandomTargetAddress {
+    type Error = String;
+    /// Create [`RandomTargetAddress`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{random_target_address::RandomTargetAddress, data_type::DataType};
+    ///
+    /// let random_target_address_bytes = [
+    ///     0x01u8, 0x02u8, 0x03u8, 0x04u8, 0x05u8, 0x06u8, 0x07u8, 0x08u8, 0x09u8, 0x0au8, 0x0bu8,
+    ///     0x0cu8,
+    /// ]
+    /// .to_vec();
+    /// let random_target_address: Vec<u64> = random_target_address_bytes
+    ///     .windows(6)
+    ///     .step_by(6)
+    ///     .map(|f| {
+    ///         let mut bytes = [0x00u8; 8];
+    ///         bytes[0] = f[0];
+    ///         bytes[1] = f[1];
+    ///         bytes[2] = f[2];
+    ///         bytes[3] = f[3];
+    ///         bytes[4] = f[4];
+    ///         bytes[5] = f[5];
+    ///         u64::from_le_bytes(bytes)
+    ///     })
+    ///     .collect();
+    /// let length = random_target_address_bytes.len() as u8 + 1;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(RandomTargetAddress::data_type());
+    /// data.append(&mut random_target_address_bytes.clone());
+    ///
+    /// let result = RandomTargetAddress::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert_eq!(random_target_address, data_type.random_target_address);
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = RandomTargetAddress::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    ///
+    /// let data: Vec<u8> = vec![8, RandomTargetAddress::data_type(), 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07];
+    /// let result = RandomTargetAddress::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!("Invalid length :8", result.unwrap_err());
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        Self::try_from(value.as_slice())
+    }
+}
+
 impl Into<Vec<u8>> for RandomTargetAddress {
     /// Create [`Vec<u8>`] from [`RandomTargetAddress`].
     ///
@@ -201,8 +307,9 @@ impl DataType for RandomTargetAddress {
 /// assert!(is_random_target_address(0x18));
 /// assert!(!is_random_target_address(0x00));
 /// ```
+#[deprecated(note = "use `AdType::of(data_type).matches::<RandomTargetAddress>()` instead")]
 pub fn is_random_target_address(data_type: u8) -> bool {
-    RandomTargetAddress::data_type() == data_type
+    AdType::of(data_type).matches::<RandomTargetAddress>()
 }
 
 #[cfg(test)]
@@ -225,6 +332,38 @@ mod tests {
         assert_eq!(random_target_address, result.random_target_address);
     }
 
+    #[test]
+    fn test_iter() {
+        let result = RandomTargetAddress::new(&Vec::new());
+        assert_eq!(None, result.iter().next());
+
+        let random_target_address: Vec<u64> = vec![0x0000060504030201u64, 0x00000c0b0a090807u64];
+        let result = RandomTargetAddress::new(&random_target_address);
+        assert_eq!(
+            random_target_address,
+            result.iter().copied().collect::<Vec<u64>>()
+        );
+
+        let mut count = 0;
+        for address in &result {
+            assert_eq!(random_target_address[count], *address);
+            count += 1;
+        }
+        assert_eq!(2, count);
+    }
+
+    #[test]
+    fn test_try_from_invalid_length() {
+        for length in [1u8, 6, 8, 12, 14] {
+            let mut data: Vec<u8> = vec![length, RandomTargetAddress::data_type()];
+            data.append(&mut vec![0x00u8; length as usize - 1].to_vec());
+            data.resize(data.len().max(8), 0x00);
+            let result = RandomTargetAddress::try_from(&data);
+            assert!(result.is_err());
+            assert_eq!(format!("Invalid length :{}", length), result.unwrap_err());
+        }
+    }
+
     #[test]
     fn test_try_from() {
         let random_target_address_bytes = [
@@ -313,6 +452,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_is_random_target_address() {
         assert!(is_random_target_address(0x18));
         assert!(!is_random_target_address(0x00));
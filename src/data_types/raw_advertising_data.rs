@@ -0,0 +1,423 @@
+//! Raw advertising payload editor module.
+
+use crate::data_types::{
+    diff::ad_structures,
+    lint::{EXTENDED_ADVERTISING_DATA_MAX, LEGACY_ADVERTISING_DATA_MAX},
+};
+
+/// Raw advertising payload editor.
+///
+/// Replaces, inserts or removes single AD structure in place, without parsing and rebuilding
+/// other AD structures. Bytes after the last well-formed AD structure (early termination
+/// padding or truncated data) are kept as is.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct RawAdvertisingData {
+    data: Vec<u8>,
+
+    /// Maximum payload size
+    pub max_data_size: usize,
+}
+
+impl RawAdvertisingData {
+    /// Create [`RawAdvertisingData`] from Parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::raw_advertising_data::RawAdvertisingData;
+    ///
+    /// let result = RawAdvertisingData::new(&[0x02, 0x01, 0x06], 31);
+    /// assert!(result.is_ok());
+    /// let raw = result.unwrap();
+    /// assert_eq!(31, raw.max_data_size);
+    /// assert_eq!(&[0x02, 0x01, 0x06], raw.as_slice());
+    ///
+    /// let result = RawAdvertisingData::new(&[0x00; 32], 31);
+    /// assert!(result.is_err());
+    /// assert_eq!("Invalid data size :32", result.unwrap_err());
+    /// ```
+    pub fn new(data: &[u8], max_data_size: usize) -> Result<Self, String> {
+        if data.len() > max_data_size {
+            return Err(format!("Invalid data size :{}", data.len()));
+        }
+        Ok(Self {
+            data: data.to_vec(),
+            max_data_size,
+        })
+    }
+
+    /// Create [`RawAdvertisingData`] with legacy advertising budget (31 bytes).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     lint::LEGACY_ADVERTISING_DATA_MAX, raw_advertising_data::RawAdvertisingData,
+    /// };
+    ///
+    /// let raw = RawAdvertisingData::legacy(&[0x02, 0x01, 0x06]).unwrap();
+    /// assert_eq!(LEGACY_ADVERTISING_DATA_MAX, raw.max_data_size);
+    /// ```
+    pub fn legacy(data: &[u8]) -> Result<Self, String> {
+        Self::new(data, LEGACY_ADVERTISING_DATA_MAX)
+    }
+
+    /// Create [`RawAdvertisingData`] with extended advertising budget (1650 bytes).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     lint::EXTENDED_ADVERTISING_DATA_MAX, raw_advertising_data::RawAdvertisingData,
+    /// };
+    ///
+    /// let raw = RawAdvertisingData::extended(&[0x02, 0x01, 0x06]).unwrap();
+    /// assert_eq!(EXTENDED_ADVERTISING_DATA_MAX, raw.max_data_size);
+    /// ```
+    pub fn extended(data: &[u8]) -> Result<Self, String> {
+        Self::new(data, EXTENDED_ADVERTISING_DATA_MAX)
+    }
+
+    /// Get payload bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::raw_advertising_data::RawAdvertisingData;
+    ///
+    /// let raw = RawAdvertisingData::legacy(&[0x02, 0x0a, 0x00]).unwrap();
+    /// assert_eq!(&[0x02, 0x0a, 0x00], raw.as_slice());
+    /// ```
+    pub fn as_slice(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Get number of well-formed AD structures.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::raw_advertising_data::RawAdvertisingData;
+    ///
+    /// let raw = RawAdvertisingData::legacy(&[0x02, 0x01, 0x06, 0x02, 0x0a, 0x00, 0x00]).unwrap();
+    /// assert_eq!(2, raw.len());
+    /// assert!(!raw.is_empty());
+    /// ```
+    pub fn len(&self) -> usize {
+        ad_structures(&self.data).len()
+    }
+
+    /// check no well-formed AD structures.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::raw_advertising_data::RawAdvertisingData;
+    ///
+    /// assert!(RawAdvertisingData::legacy(&[]).unwrap().is_empty());
+    /// assert!(RawAdvertisingData::legacy(&[0x00, 0x00]).unwrap().is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get AD structure (including length) at index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::raw_advertising_data::RawAdvertisingData;
+    ///
+    /// let raw = RawAdvertisingData::legacy(&[0x02, 0x01, 0x06, 0x02, 0x0a, 0x00]).unwrap();
+    /// assert_eq!(Some(&[0x02, 0x0a, 0x00][..]), raw.get(1));
+    /// assert_eq!(None, raw.get(2));
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&[u8]> {
+        ad_structures(&self.data).get(index).copied()
+    }
+
+    /// Get index of the first AD structure with data type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     flags::Flags, raw_advertising_data::RawAdvertisingData, tx_power_level::TxPowerLevel,
+    ///     data_type::DataType,
+    /// };
+    ///
+    /// let raw = RawAdvertisingData::legacy(&[0x02, 0x01, 0x06, 0x02, 0x0a, 0x00]).unwrap();
+    /// assert_eq!(Some(0), raw.position(Flags::data_type()));
+    /// assert_eq!(Some(1), raw.position(TxPowerLevel::data_type()));
+    /// assert_eq!(None, raw.position(0xff));
+    /// ```
+    pub fn position(&self, data_type: u8) -> Option<usize> {
+        ad_structures(&self.data)
+            .iter()
+            .position(|structure| structure[1] == data_type)
+    }
+
+    /// Replace AD structure at index.
+    ///
+    /// Following bytes are shifted, and the payload is unchanged on error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     complete_local_name::CompleteLocalName, raw_advertising_data::RawAdvertisingData,
+    /// };
+    ///
+    /// let mut raw = RawAdvertisingData::legacy(&[0x02, 0x01, 0x06, 0x02, 0x0a, 0x00]).unwrap();
+    /// let name: Vec<u8> = CompleteLocalName::new(&"name".to_string()).into();
+    /// assert!(raw.replace(0, &name).is_ok());
+    /// assert_eq!([&name[..], &[0x02, 0x0a, 0x00]].concat(), raw.as_slice());
+    ///
+    /// let name: Vec<u8> = CompleteLocalName::new(&"a".repeat(27)).into();
+    /// let result = raw.replace(0, &name);
+    /// assert!(result.is_err());
+    /// assert_eq!("Invalid payload size :32", result.unwrap_err());
+    /// ```
+    pub fn replace(&mut self, index: usize, structure: &[u8]) -> Result<(), String> {
+        validate_structure(structure)?;
+        let (offset, length) = self.range(index)?;
+        self.splice(offset, length, structure)
+    }
+
+    /// Insert AD structure at index.
+    ///
+    /// Index equal to [`RawAdvertisingData::len`] appends after the last AD structure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     raw_advertising_data::RawAdvertisingData, tx_power_level::TxPowerLevel,
+    /// };
+    ///
+    /// let mut raw = RawAdvertisingData::legacy(&[0x02, 0x01, 0x06]).unwrap();
+    /// let tx_power: Vec<u8> = TxPowerLevel::new(-4).into();
+    /// assert!(raw.insert(1, &tx_power).is_ok());
+    /// assert_eq!(&[0x02, 0x01, 0x06, 0x02, 0x0a, 0xfc], raw.as_slice());
+    ///
+    /// let result = raw.insert(3, &tx_power);
+    /// assert!(result.is_err());
+    /// assert_eq!("Invalid index :3", result.unwrap_err());
+    /// ```
+    pub fn insert(&mut self, index: usize, structure: &[u8]) -> Result<(), String> {
+        validate_structure(structure)?;
+        let structures = ad_structures(&self.data);
+        let offset = match index {
+            index if index < structures.len() => self.offset(index),
+            index if index == structures.len() => {
+                structures.iter().map(|structure| structure.len()).sum()
+            }
+            _ => return Err(format!("Invalid index :{}", index)),
+        };
+        self.splice(offset, 0, structure)
+    }
+
+    /// Remove AD structure at index and return it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::raw_advertising_data::RawAdvertisingData;
+    ///
+    /// let mut raw = RawAdvertisingData::legacy(&[0x02, 0x01, 0x06, 0x02, 0x0a, 0x00]).unwrap();
+    /// assert_eq!(Ok(vec![0x02, 0x01, 0x06]), raw.remove(0));
+    /// assert_eq!(&[0x02, 0x0a, 0x00], raw.as_slice());
+    /// assert_eq!(Err("Invalid index :1".to_string()), raw.remove(1));
+    /// ```
+    pub fn remove(&mut self, index: usize) -> Result<Vec<u8>, String> {
+        let (offset, length) = self.range(index)?;
+        Ok(self.data.drain(offset..offset + length).collect())
+    }
+
+    /// Get offset of AD structure at index (index must be valid).
+    fn offset(&self, index: usize) -> usize {
+        ad_structures(&self.data)[..index]
+            .iter()
+            .map(|structure| structure.len())
+            .sum()
+    }
+
+    /// Get `(offset, size)` of AD structure at index.
+    fn range(&self, index: usize) -> Result<(usize, usize), String> {
+        match self.get(index) {
+            Some(structure) => Ok((self.offset(index), structure.len())),
+            None => Err(format!("Invalid index :{}", index)),
+        }
+    }
+
+    /// Replace `length` bytes at `offset` with `structure` if the result fits the budget.
+    fn splice(&mut self, offset: usize, length: usize, structure: &[u8]) -> Result<(), String> {
+        let size = self.data.len() - length + structure.len();
+        if size > self.max_data_size {
+            return Err(format!("Invalid payload size :{}", size));
+        }
+        self.data
+            .splice(offset..offset + length, structure.iter().copied());
+        Ok(())
+    }
+}
+
+impl From<RawAdvertisingData> for Vec<u8> {
+    /// Create [`Vec<u8>`] from [`RawAdvertisingData`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::raw_advertising_data::RawAdvertisingData;
+    ///
+    /// let raw = RawAdvertisingData::legacy(&[0x02, 0x01, 0x06]).unwrap();
+    /// let data: Vec<u8> = raw.into();
+    /// assert_eq!(vec![0x02, 0x01, 0x06], data);
+    /// ```
+    fn from(value: RawAdvertisingData) -> Self {
+        value.data
+    }
+}
+
+/// Check single well-formed AD structure (including length).
+fn validate_structure(structure: &[u8]) -> Result<(), String> {
+    match structure.first() {
+        Some(length) if *length != 0 && *length as usize + 1 == structure.len() => Ok(()),
+        _ => Err(format!("Invalid data size :{}", structure.len())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data_types::{
+        complete_local_name::CompleteLocalName,
+        flags::Flags,
+        lint::{EXTENDED_ADVERTISING_DATA_MAX, LEGACY_ADVERTISING_DATA_MAX},
+        raw_advertising_data::{validate_structure, RawAdvertisingData},
+        tx_power_level::TxPowerLevel,
+    };
+
+    const FLAGS: [u8; 3] = [0x02, 0x01, 0x06];
+    const TX_POWER: [u8; 3] = [0x02, 0x0a, 0x00];
+
+    #[test]
+    fn test_new() {
+        let raw = RawAdvertisingData::new(&FLAGS, 3).unwrap();
+        assert_eq!(3, raw.max_data_size);
+        assert_eq!(&FLAGS, raw.as_slice());
+
+        let result = RawAdvertisingData::new(&FLAGS, 2);
+        assert_eq!(Err("Invalid data size :3".to_string()), result);
+
+        let raw = RawAdvertisingData::legacy(&[]).unwrap();
+        assert_eq!(LEGACY_ADVERTISING_DATA_MAX, raw.max_data_size);
+        assert!(RawAdvertisingData::legacy(&[0x00; 32]).is_err());
+
+        let raw = RawAdvertisingData::extended(&[0x00; 32]).unwrap();
+        assert_eq!(EXTENDED_ADVERTISING_DATA_MAX, raw.max_data_size);
+    }
+
+    #[test]
+    fn test_structures() {
+        let data = [&FLAGS[..], &TX_POWER[..], &[0x05, 0x09]].concat();
+        let raw = RawAdvertisingData::legacy(&data).unwrap();
+        assert_eq!(2, raw.len());
+        assert_eq!(Some(&FLAGS[..]), raw.get(0));
+        assert_eq!(Some(&TX_POWER[..]), raw.get(1));
+        assert_eq!(None, raw.get(2));
+        assert_eq!(Some(1), raw.position(0x0a));
+        assert_eq!(None, raw.position(0x09));
+    }
+
+    #[test]
+    fn test_replace() {
+        let data = [&FLAGS[..], &TX_POWER[..], &[0x00, 0x00]].concat();
+        let mut raw = RawAdvertisingData::legacy(&data).unwrap();
+        let name: Vec<u8> = CompleteLocalName::new(&"name".to_string()).into();
+        assert!(raw.replace(0, &name).is_ok());
+        assert_eq!(
+            [&name[..], &TX_POWER[..], &[0x00, 0x00]].concat(),
+            raw.as_slice()
+        );
+
+        // shrink
+        let tx_power: Vec<u8> = TxPowerLevel::new(-4).into();
+        assert!(raw.replace(0, &tx_power).is_ok());
+        assert_eq!(
+            [&tx_power[..], &TX_POWER[..], &[0x00, 0x00]].concat(),
+            raw.as_slice()
+        );
+
+        // budget: 3 + 3 + 2 - 3 + 24 = 29, 3 + 3 + 2 - 3 + 27 = 32
+        assert!(raw
+            .replace(0, &[&[23, 0x09][..], &[0x61; 22]].concat())
+            .is_ok());
+        assert_eq!(29, raw.as_slice().len());
+        let before = raw.clone();
+        assert_eq!(
+            Err("Invalid payload size :32".to_string()),
+            raw.replace(0, &[&[26, 0x09][..], &[0x61; 25]].concat())
+        );
+        assert_eq!(before, raw);
+
+        assert_eq!(Err("Invalid index :2".to_string()), raw.replace(2, &FLAGS));
+        assert_eq!(
+            Err("Invalid data size :2".to_string()),
+            raw.replace(0, &[0x02, 0x01])
+        );
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut raw = RawAdvertisingData::legacy(&[]).unwrap();
+        assert!(raw.insert(0, &TX_POWER).is_ok());
+        assert!(raw.insert(0, &FLAGS).is_ok());
+        assert_eq!([FLAGS, TX_POWER].concat(), raw.as_slice());
+
+        // append before padding
+        let data = [&FLAGS[..], &[0x00]].concat();
+        let mut raw = RawAdvertisingData::legacy(&data).unwrap();
+        assert!(raw.insert(1, &TX_POWER).is_ok());
+        assert_eq!(
+            [&FLAGS[..], &TX_POWER[..], &[0x00]].concat(),
+            raw.as_slice()
+        );
+
+        let mut raw = RawAdvertisingData::new(&FLAGS, 5).unwrap();
+        assert_eq!(
+            Err("Invalid payload size :6".to_string()),
+            raw.insert(1, &TX_POWER)
+        );
+        assert_eq!(
+            Err("Invalid index :2".to_string()),
+            raw.insert(2, &TX_POWER)
+        );
+        assert_eq!(Err("Invalid data size :0".to_string()), raw.insert(0, &[]));
+        assert_eq!(&FLAGS, raw.as_slice());
+    }
+
+    #[test]
+    fn test_remove() {
+        let flags: Vec<u8> = Flags::new(&vec![true]).into();
+        let data = [&flags[..], &TX_POWER[..], &FLAGS[..]].concat();
+        let mut raw = RawAdvertisingData::legacy(&data).unwrap();
+        assert_eq!(Ok(TX_POWER.to_vec()), raw.remove(1));
+        assert_eq!([&flags[..], &FLAGS[..]].concat(), raw.as_slice());
+        assert_eq!(Ok(FLAGS.to_vec()), raw.remove(1));
+        assert_eq!(Err("Invalid index :1".to_string()), raw.remove(1));
+        assert_eq!(Ok(flags), raw.remove(0));
+        assert!(raw.is_empty());
+        let data: Vec<u8> = raw.into();
+        assert!(data.is_empty());
+    }
+
+    #[test]
+    fn test_validate_structure() {
+        assert!(validate_structure(&FLAGS).is_ok());
+        assert!(validate_structure(&[0x01, 0x09]).is_ok());
+        assert!(validate_structure(&[]).is_err());
+        assert!(validate_structure(&[0x00]).is_err());
+        assert!(validate_structure(&[0x02, 0x01]).is_err());
+        assert!(validate_structure(&[0x01, 0x01, 0x06]).is_err());
+    }
+}
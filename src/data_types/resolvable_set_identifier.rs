@@ -0,0 +1,324 @@
+//! Resolvable Set Identifier (Data Type Value: 0x2e) module.
+
+#[cfg(feature = "crypto")]
+use crate::crypto::sih;
+
+use crate::data_types::data_type::{validate_length, DataType};
+
+/// Resolvable Set Identifier.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ResolvableSetIdentifier {
+    /// data length
+    pub length: u8,
+
+    /// Resolvable Set Identifier (48 bit)
+    pub resolvable_set_identifier: u64,
+}
+
+impl ResolvableSetIdentifier {
+    /// Create [`ResolvableSetIdentifier`] from `Resolvable Set Identifier`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::resolvable_set_identifier::ResolvableSetIdentifier;
+    ///
+    /// let resolvable_set_identifier = 0x69f5631948dau64;
+    /// let result = ResolvableSetIdentifier::new(resolvable_set_identifier);
+    /// assert_eq!(7, result.length);
+    /// assert_eq!(resolvable_set_identifier, result.resolvable_set_identifier);
+    /// ```
+    pub const fn new(resolvable_set_identifier: u64) -> Self {
+        Self {
+            length: 7,
+            resolvable_set_identifier,
+        }
+    }
+
+    /// hash (24 bit).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::resolvable_set_identifier::ResolvableSetIdentifier;
+    ///
+    /// let result = ResolvableSetIdentifier::new(0x69f5631948da);
+    /// assert_eq!(0x1948da, result.hash());
+    /// ```
+    pub fn hash(&self) -> u32 {
+        (self.resolvable_set_identifier & 0xffffff) as u32
+    }
+
+    /// prand (24 bit).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::resolvable_set_identifier::ResolvableSetIdentifier;
+    ///
+    /// let result = ResolvableSetIdentifier::new(0x69f5631948da);
+    /// assert_eq!(0x69f563, result.prand());
+    /// ```
+    pub fn prand(&self) -> u32 {
+        ((self.resolvable_set_identifier >> 24) & 0xffffff) as u32
+    }
+
+    /// check hash with SIRK (most significant octet first).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::resolvable_set_identifier::ResolvableSetIdentifier;
+    ///
+    /// let sirk = [
+    ///     0x45, 0x7d, 0x7d, 0x09, 0x21, 0xa1, 0xfd, 0x22, 0xce, 0xcd, 0x8c, 0x86, 0xdd, 0x72, 0xcc,
+    ///     0xcd,
+    /// ];
+    /// let result = ResolvableSetIdentifier::new(0x69f5631948da);
+    /// assert!(result.verify_with_sirk(&sirk));
+    ///
+    /// let result = ResolvableSetIdentifier::new(0x69f5631948db);
+    /// assert!(!result.verify_with_sirk(&sirk));
+    /// ```
+    #[cfg(feature = "crypto")]
+    pub fn verify_with_sirk(&self, sirk: &[u8; 16]) -> bool {
+        sih(sirk, self.prand()) == self.hash()
+    }
+}
+
+impl TryFrom<&[u8]> for ResolvableSetIdentifier {
+    type Error = String;
+    /// Create [`ResolvableSetIdentifier`] from `u8` slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{resolvable_set_identifier::ResolvableSetIdentifier, data_type::DataType};
+    ///
+    /// let resolvable_set_identifier = 0x69f5631948dau64;
+    /// let length = 7;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(ResolvableSetIdentifier::data_type());
+    /// data.append(&mut resolvable_set_identifier.to_le_bytes()[..6].to_vec());
+    ///
+    /// let result = ResolvableSetIdentifier::try_from(data.as_slice());
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert_eq!(resolvable_set_identifier, data_type.resolvable_set_identifier);
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = ResolvableSetIdentifier::try_from(data.as_slice());
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &[u8]) -> Result<Self, String> {
+        let len = value.len();
+        if len < 8 {
+            return Err(format!("Invalid data size :{}", len).to_string());
+        }
+        let length = validate_length(value, 7, 7)?;
+        let mut bytes = [0u8; 8];
+        bytes[..6].copy_from_slice(&value[2..8]);
+        Ok(Self {
+            length,
+            resolvable_set_identifier: u64::from_le_bytes(bytes),
+        })
+    }
+}
+
+impl TryFrom<&Vec<u8>> for ResolvableSetIdentifier {
+    type Error = String;
+    /// Create [`ResolvableSetIdentifier`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{resolvable_set_identifier::ResolvableSetIdentifier, data_type::DataType};
+    ///
+    /// let resolvable_set_identifier = 0x69f5631948dau64;
+    /// let length = 7;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(ResolvableSetIdentifier::data_type());
+    /// data.append(&mut resolvable_set_identifier.to_le_bytes()[..6].to_vec());
+    ///
+    /// let result = ResolvableSetIdentifier::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert_eq!(resolvable_set_identifier, data_type.resolvable_set_identifier);
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = ResolvableSetIdentifier::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        Self::try_from(value.as_slice())
+    }
+}
+
+impl From<ResolvableSetIdentifier> for Vec<u8> {
+    /// Create [`Vec<u8>`] from [`ResolvableSetIdentifier`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{resolvable_set_identifier::ResolvableSetIdentifier, data_type::DataType};
+    ///
+    /// let resolvable_set_identifier = 0x69f5631948dau64;
+    /// let result1 = ResolvableSetIdentifier::new(resolvable_set_identifier);
+    ///
+    /// let length = 7;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(ResolvableSetIdentifier::data_type());
+    /// data.append(&mut resolvable_set_identifier.to_le_bytes()[..6].to_vec());
+    ///
+    /// let into_data: Vec<u8> = result1.into();
+    /// assert_eq!(data, into_data);
+    ///
+    /// let result2 = ResolvableSetIdentifier::try_from(&data);
+    /// assert!(result2.is_ok());
+    /// let data_type = result2.unwrap();
+    /// let into_data: Vec<u8> = data_type.into();
+    /// assert_eq!(data, into_data);
+    /// ```
+    fn from(value: ResolvableSetIdentifier) -> Self {
+        let mut data: Vec<u8> = Vec::new();
+        data.push(value.length);
+        data.push(ResolvableSetIdentifier::data_type());
+        data.append(&mut value.resolvable_set_identifier.to_le_bytes()[..6].to_vec());
+        data
+    }
+}
+
+impl DataType for ResolvableSetIdentifier {
+    /// return `0x2e`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{resolvable_set_identifier::ResolvableSetIdentifier, data_type::DataType};
+    ///
+    /// assert_eq!(0x2e, ResolvableSetIdentifier::data_type());
+    /// ```
+    fn data_type() -> u8 {
+        0x2e
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data_types::{data_type::DataType, resolvable_set_identifier::*};
+
+    #[test]
+    fn test_new() {
+        let resolvable_set_identifier = 0x69f5631948dau64;
+        let result = ResolvableSetIdentifier::new(resolvable_set_identifier);
+        assert_eq!(7, result.length);
+        assert_eq!(resolvable_set_identifier, result.resolvable_set_identifier);
+    }
+
+    #[test]
+    fn test_hash() {
+        assert_eq!(
+            0x1948da,
+            ResolvableSetIdentifier::new(0x69f5631948da).hash()
+        );
+    }
+
+    #[test]
+    fn test_prand() {
+        assert_eq!(
+            0x69f563,
+            ResolvableSetIdentifier::new(0x69f5631948da).prand()
+        );
+    }
+
+    #[test]
+    fn test_try_from() {
+        let resolvable_set_identifier = 0x69f5631948dau64;
+        let length = 7;
+        let data: Vec<u8> = vec![
+            length,
+            ResolvableSetIdentifier::data_type(),
+            0xda,
+            0x48,
+            0x19,
+            0x63,
+            0xf5,
+            0x69,
+        ];
+
+        let result = ResolvableSetIdentifier::try_from(&data);
+        assert!(result.is_ok());
+        let data_type = result.unwrap();
+        assert_eq!(length, data_type.length);
+        assert_eq!(
+            resolvable_set_identifier,
+            data_type.resolvable_set_identifier
+        );
+
+        let mut data: Vec<u8> = vec![0u8; 7];
+        data[0] = data.len() as u8 - 1;
+        let result = ResolvableSetIdentifier::try_from(&data);
+        assert!(result.is_err());
+        assert_eq!(
+            format!("Invalid data size :{}", data.len()),
+            result.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_into() {
+        let resolvable_set_identifier = 0x69f5631948dau64;
+        let result1 = ResolvableSetIdentifier::new(resolvable_set_identifier);
+
+        let length = 7;
+        let data: Vec<u8> = vec![
+            length,
+            ResolvableSetIdentifier::data_type(),
+            0xda,
+            0x48,
+            0x19,
+            0x63,
+            0xf5,
+            0x69,
+        ];
+
+        let into_data: Vec<u8> = result1.into();
+        assert_eq!(data, into_data);
+
+        let result2 = ResolvableSetIdentifier::try_from(&data);
+        assert!(result2.is_ok());
+        let into_data: Vec<u8> = result2.unwrap().into();
+        assert_eq!(data, into_data);
+    }
+
+    #[test]
+    fn test_data_type() {
+        assert_eq!(0x2e, ResolvableSetIdentifier::data_type());
+    }
+
+    #[cfg(feature = "crypto")]
+    #[test]
+    fn test_verify_with_sirk() {
+        let sirk = [
+            0x45, 0x7d, 0x7d, 0x09, 0x21, 0xa1, 0xfd, 0x22, 0xce, 0xcd, 0x8c, 0x86, 0xdd, 0x72,
+            0xcc, 0xcd,
+        ];
+        assert!(ResolvableSetIdentifier::new(0x69f5631948da).verify_with_sirk(&sirk));
+        assert!(!ResolvableSetIdentifier::new(0x69f5631948db).verify_with_sirk(&sirk));
+        assert!(!ResolvableSetIdentifier::new(0x69f5641948da).verify_with_sirk(&sirk));
+        assert!(!ResolvableSetIdentifier::new(0x69f5631948da).verify_with_sirk(&[0u8; 16]));
+    }
+}
@@ -1,6 +1,9 @@
 //! Secure Simple Pairing Randomizer R-192 (Data Type Value: 0x0f) module.
 
-use crate::data_types::data_type::DataType;
+use crate::data_types::{
+    ad_type::AdType,
+    data_type::{validate_length, DataType},
+};
 
 /// Secure Simple Pairing Randomizer R-192.
 #[derive(Debug, PartialEq, Clone)]
@@ -25,7 +28,7 @@ impl SecureSimplePairingRandomizerR192 {
     /// assert_eq!(17, result.length);
     /// assert_eq!(secure_simple_pairing_randomizer_r192, result.secure_simple_pairing_randomizer_r192);
     /// ```
-    pub fn new(secure_simple_pairing_randomizer_r192: u128) -> Self {
+    pub const fn new(secure_simple_pairing_randomizer_r192: u128) -> Self {
         Self {
             length: 17,
             secure_simple_pairing_randomizer_r192,
@@ -33,9 +36,9 @@ impl SecureSimplePairingRandomizerR192 {
     }
 }
 
-impl TryFrom<&Vec<u8>> for SecureSimplePairingRandomizerR192 {
+impl TryFrom<&[u8]> for SecureSimplePairingRandomizerR192 {
     type Error = String;
-    /// Create [`SecureSimplePairingRandomizerR192`] from [`Vec<u8>`].
+    /// Create [`SecureSimplePairingRandomizerR192`] from `u8` slice.
     ///
     /// # Examples
     ///
@@ -49,7 +52,7 @@ impl TryFrom<&Vec<u8>> for SecureSimplePairingRandomizerR192 {
     /// data.push(SecureSimplePairingRandomizerR192::data_type());
     /// data.append(&mut secure_simple_pairing_randomizer_r192.to_le_bytes().to_vec());
     ///
-    /// let result = SecureSimplePairingRandomizerR192::try_from(&data);
+    /// let result = SecureSimplePairingRandomizerR192::try_from(data.as_slice());
     /// assert!(result.is_ok());
     /// let data_type = result.unwrap();
     /// assert_eq!(length, data_type.length);
@@ -59,19 +62,19 @@ impl TryFrom<&Vec<u8>> for SecureSimplePairingRandomizerR192 {
     /// );
     ///
     /// let data: Vec<u8> = Vec::new();
-    /// let result = SecureSimplePairingRandomizerR192::try_from(&data);
+    /// let result = SecureSimplePairingRandomizerR192::try_from(data.as_slice());
     /// assert!(result.is_err());
     /// assert_eq!(
     ///     format!("Invalid data size :{}", data.len()),
     ///     result.unwrap_err()
     /// );
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+    fn try_from(value: &[u8]) -> Result<Self, String> {
         let len = value.len();
         if len < 18 {
             return Err(format!("Invalid data size :{}", len).to_string());
         }
-        let length = value[0];
+        let length = validate_length(value, 17, 17)?;
         Ok(Self {
             length,
             secure_simple_pairing_randomizer_r192: u128::from_le_bytes(
@@ -81,6 +84,44 @@ impl TryFrom<&Vec<u8>> for SecureSimplePairingRandomizerR192 {
     }
 }
 
+impl TryFrom<&Vec<u8>> for SecureSimplePairingRandomizerR192 {
+    type Error = String;
+    /// Create [`SecureSimplePairingRandomizerR192`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{BASE_UUID, data_types::{secure_simple_pairing_randomizer_r192::SecureSimplePairingRandomizerR192, data_type::DataType}};
+    ///
+    /// let secure_simple_pairing_randomizer_r192 = 0x0102030405060708090a0b0c0d0e0f10u128;
+    /// let length = 17;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(SecureSimplePairingRandomizerR192::data_type());
+    /// data.append(&mut secure_simple_pairing_randomizer_r192.to_le_bytes().to_vec());
+    ///
+    /// let result = SecureSimplePairingRandomizerR192::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert_eq!(
+    ///     secure_simple_pairing_randomizer_r192,
+    ///     data_type.secure_simple_pairing_randomizer_r192
+    /// );
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = SecureSimplePairingRandomizerR192::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        Self::try_from(value.as_slice())
+    }
+}
+
 impl Into<Vec<u8>> for SecureSimplePairingRandomizerR192 {
     /// Create [`Vec<u8>`] from [SecureSimplePairingRandomizerR192].
     ///
@@ -147,8 +188,11 @@ impl DataType for SecureSimplePairingRandomizerR192 {
 /// assert!(is_secure_simple_pairing_randomizer_r192(0x0f));
 /// assert!(!is_secure_simple_pairing_randomizer_r192(0x00));
 /// ```
+#[deprecated(
+    note = "use `AdType::of(data_type).matches::<SecureSimplePairingRandomizerR192>()` instead"
+)]
 pub fn is_secure_simple_pairing_randomizer_r192(data_type: u8) -> bool {
-    SecureSimplePairingRandomizerR192::data_type() == data_type
+    AdType::of(data_type).matches::<SecureSimplePairingRandomizerR192>()
 }
 
 #[cfg(test)]
@@ -221,6 +265,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_is_secure_simple_pairing_randomizer_r192() {
         assert!(is_secure_simple_pairing_randomizer_r192(0x0f));
         assert!(!is_secure_simple_pairing_randomizer_r192(0x00));
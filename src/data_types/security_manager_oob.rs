@@ -1,6 +1,9 @@
 //! Security Manager Out of Band (Data Type Value: 0x11) module.
 
-use crate::data_types::data_type::DataType;
+use crate::data_types::{
+    ad_type::AdType,
+    data_type::{validate_length, DataType},
+};
 
 /// Security Manager Out of Band.
 #[derive(Debug, PartialEq, Clone)]
@@ -35,10 +38,10 @@ impl SecurityManagerOutOfBand {
     /// assert_eq!(2, result.length);
     /// assert_eq!(security_manager_oob, result.security_manager_oob);
     /// ```
-    pub fn new(security_manager_oob: &[bool; 8]) -> Self {
+    pub const fn new(security_manager_oob: &[bool; 8]) -> Self {
         Self {
             length: 2,
-            security_manager_oob: security_manager_oob.clone(),
+            security_manager_oob: *security_manager_oob,
         }
     }
 
@@ -124,9 +127,9 @@ pub const SECURITY_MANAGER_LE_SUPPORTED: u8 = 0b00000010u8;
 /// (0 = Public Address, 1 = Random Address)
 pub const SECURITY_MANAGER_ADDRESS_TYPE: u8 = 0b00001000u8;
 
-impl TryFrom<&Vec<u8>> for SecurityManagerOutOfBand {
+impl TryFrom<&[u8]> for SecurityManagerOutOfBand {
     type Error = String;
-    /// Create [`SecurityManagerOutOfBand`] from [`Vec<u8>`].
+    /// Create [`SecurityManagerOutOfBand`] from `u8` slice.
     ///
     /// # Examples
     ///
@@ -142,7 +145,7 @@ impl TryFrom<&Vec<u8>> for SecurityManagerOutOfBand {
     /// data.push(SecurityManagerOutOfBand::data_type());
     /// data.push(security_manager_oob_byte);
     ///
-    /// let result = SecurityManagerOutOfBand::try_from(&data);
+    /// let result = SecurityManagerOutOfBand::try_from(data.as_slice());
     /// assert!(result.is_ok());
     /// let data_type = result.unwrap();
     /// assert_eq!(length, data_type.length);
@@ -157,7 +160,7 @@ impl TryFrom<&Vec<u8>> for SecurityManagerOutOfBand {
     /// data.push(SecurityManagerOutOfBand::data_type());
     /// data.push(security_manager_oob_byte);
     ///
-    /// let result = SecurityManagerOutOfBand::try_from(&data);
+    /// let result = SecurityManagerOutOfBand::try_from(data.as_slice());
     /// assert!(result.is_ok());
     /// let data_type = result.unwrap();
     /// assert_eq!(length, data_type.length);
@@ -166,32 +169,32 @@ impl TryFrom<&Vec<u8>> for SecurityManagerOutOfBand {
     /// let security_manager_oob_byte = SECURITY_MANAGER_ADDRESS_TYPE;
     /// let mut security_manager_oob = [false; 8];
     /// security_manager_oob[3] = true;
-    /// let length = 4;
+    /// let length = 2;
     /// let mut data: Vec<u8> = Vec::new();
     /// data.push(length);
     /// data.push(SecurityManagerOutOfBand::data_type());
     /// data.push(security_manager_oob_byte);
     ///
-    /// let result = SecurityManagerOutOfBand::try_from(&data);
+    /// let result = SecurityManagerOutOfBand::try_from(data.as_slice());
     /// assert!(result.is_ok());
     /// let data_type = result.unwrap();
     /// assert_eq!(length, data_type.length);
     /// assert_eq!(security_manager_oob, data_type.security_manager_oob);
     ///
     /// let data: Vec<u8> = Vec::new();
-    /// let result = SecurityManagerOutOfBand::try_from(&data);
+    /// let result = SecurityManagerOutOfBand::try_from(data.as_slice());
     /// assert!(result.is_err());
     /// assert_eq!(
     ///     format!("Invalid data size :{}", data.len()),
     ///     result.unwrap_err()
     /// );
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+    fn try_from(value: &[u8]) -> Result<Self, String> {
         let len = value.len();
         if len < 3 {
             return Err(format!("Invalid data size :{}", len).to_string());
         }
-        let length = value[0];
+        let length = validate_length(value, 2, 2)?;
         let mut security_manager_oob = [false; 8];
         security_manager_oob[0] = value[2] & SECURITY_MANAGER_OUT_OF_BAND_FLAG_OOB_FLAGS != 0;
         security_manager_oob[1] = value[2] & SECURITY_MANAGER_LE_SUPPORTED != 0;
@@ -203,6 +206,73 @@ impl TryFrom<&Vec<u8>> for SecurityManagerOutOfBand {
     }
 }
 
+impl TryFrom<&Vec<u8>> for SecurityManagerOutOfBand {
+    type Error = String;
+    /// Create [`SecurityManagerOutOfBand`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{security_manager_oob::*, data_type::DataType};
+    ///
+    /// let security_manager_oob_byte = SECURITY_MANAGER_OUT_OF_BAND_FLAG_OOB_FLAGS;
+    /// let mut security_manager_oob = [false; 8];
+    /// security_manager_oob[0] = true;
+    /// let length = 2;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(SecurityManagerOutOfBand::data_type());
+    /// data.push(security_manager_oob_byte);
+    ///
+    /// let result = SecurityManagerOutOfBand::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert_eq!(security_manager_oob, data_type.security_manager_oob);
+    ///
+    /// let security_manager_oob_byte = SECURITY_MANAGER_LE_SUPPORTED;
+    /// let mut security_manager_oob = [false; 8];
+    /// security_manager_oob[1] = true;
+    /// let length = 2;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(SecurityManagerOutOfBand::data_type());
+    /// data.push(security_manager_oob_byte);
+    ///
+    /// let result = SecurityManagerOutOfBand::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert_eq!(security_manager_oob, data_type.security_manager_oob);
+    ///
+    /// let security_manager_oob_byte = SECURITY_MANAGER_ADDRESS_TYPE;
+    /// let mut security_manager_oob = [false; 8];
+    /// security_manager_oob[3] = true;
+    /// let length = 2;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(SecurityManagerOutOfBand::data_type());
+    /// data.push(security_manager_oob_byte);
+    ///
+    /// let result = SecurityManagerOutOfBand::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert_eq!(security_manager_oob, data_type.security_manager_oob);
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = SecurityManagerOutOfBand::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        Self::try_from(value.as_slice())
+    }
+}
+
 impl Into<Vec<u8>> for SecurityManagerOutOfBand {
     /// Create [`Vec<u8>`] from [`SecurityManagerOutOfBand`].
     ///
@@ -341,8 +411,9 @@ impl DataType for SecurityManagerOutOfBand {
 /// assert!(is_security_manager_oob(0x11));
 /// assert!(!is_security_manager_oob(0x00));
 /// ```
+#[deprecated(note = "use `AdType::of(data_type).matches::<SecurityManagerOutOfBand>()` instead")]
 pub fn is_security_manager_oob(data_type: u8) -> bool {
-    SecurityManagerOutOfBand::data_type() == data_type
+    AdType::of(data_type).matches::<SecurityManagerOutOfBand>()
 }
 
 #[cfg(test)]
@@ -447,7 +518,7 @@ mod tests {
         let security_manager_oob_byte = SECURITY_MANAGER_ADDRESS_TYPE;
         let mut security_manager_oob = [false; 8];
         security_manager_oob[3] = true;
-        let length = 4;
+        let length = 2;
         let mut data: Vec<u8> = Vec::new();
         data.push(length);
         data.push(SecurityManagerOutOfBand::data_type());
@@ -562,6 +633,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_is_security_manager_oob() {
         assert!(is_security_manager_oob(0x11));
         assert!(!is_security_manager_oob(0x00));
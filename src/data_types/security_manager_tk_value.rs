@@ -1,6 +1,9 @@
 //! Security Manager TK Value (Data Type Value: 0x10) module.
 
-use crate::data_types::data_type::DataType;
+use crate::data_types::{
+    ad_type::AdType,
+    data_type::{validate_length, DataType},
+};
 
 /// Security Manager TK Value.
 #[derive(Debug, PartialEq, Clone)]
@@ -25,7 +28,7 @@ impl SecurityManagerTkValue {
     /// assert_eq!(17, result.length);
     /// assert_eq!(security_manager_tk_value, result.security_manager_tk_value);
     /// ```
-    pub fn new(security_manager_tk_value: u128) -> Self {
+    pub const fn new(security_manager_tk_value: u128) -> Self {
         Self {
             length: 17,
             security_manager_tk_value,
@@ -33,9 +36,9 @@ impl SecurityManagerTkValue {
     }
 }
 
-impl TryFrom<&Vec<u8>> for SecurityManagerTkValue {
+impl TryFrom<&[u8]> for SecurityManagerTkValue {
     type Error = String;
-    /// Create [`SecurityManagerTkValue`] from [`Vec<u8>`].
+    /// Create [`SecurityManagerTkValue`] from `u8` slice.
     ///
     /// # Examples
     ///
@@ -49,7 +52,7 @@ impl TryFrom<&Vec<u8>> for SecurityManagerTkValue {
     /// data.push(SecurityManagerTkValue::data_type());
     /// data.append(&mut security_manager_tk_value.to_le_bytes().to_vec());
     ///
-    /// let result = SecurityManagerTkValue::try_from(&data);
+    /// let result = SecurityManagerTkValue::try_from(data.as_slice());
     /// assert!(result.is_ok());
     /// let data_type = result.unwrap();
     /// assert_eq!(length, data_type.length);
@@ -59,19 +62,19 @@ impl TryFrom<&Vec<u8>> for SecurityManagerTkValue {
     /// );
     ///
     /// let data: Vec<u8> = Vec::new();
-    /// let result = SecurityManagerTkValue::try_from(&data);
+    /// let result = SecurityManagerTkValue::try_from(data.as_slice());
     /// assert!(result.is_err());
     /// assert_eq!(
     ///     format!("Invalid data size :{}", data.len()),
     ///     result.unwrap_err()
     /// );
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+    fn try_from(value: &[u8]) -> Result<Self, String> {
         let len = value.len();
         if len < 18 {
             return Err(format!("Invalid data size :{}", len).to_string());
         }
-        let length = value[0];
+        let length = validate_length(value, 17, 17)?;
         Ok(Self {
             length,
             security_manager_tk_value: u128::from_le_bytes(value[2..18].try_into().unwrap()),
@@ -79,6 +82,44 @@ impl TryFrom<&Vec<u8>> for SecurityManagerTkValue {
     }
 }
 
+impl TryFrom<&Vec<u8>> for SecurityManagerTkValue {
+    type Error = String;
+    /// Create [`SecurityManagerTkValue`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{BASE_UUID, data_types::{security_manager_tk_value::SecurityManagerTkValue, data_type::DataType}};
+    ///
+    /// let security_manager_tk_value = 0x0102030405060708090a0b0c0d0e0f10u128;
+    /// let length = 17;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(SecurityManagerTkValue::data_type());
+    /// data.append(&mut security_manager_tk_value.to_le_bytes().to_vec());
+    ///
+    /// let result = SecurityManagerTkValue::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert_eq!(
+    ///     security_manager_tk_value,
+    ///     data_type.security_manager_tk_value
+    /// );
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = SecurityManagerTkValue::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        Self::try_from(value.as_slice())
+    }
+}
+
 impl Into<Vec<u8>> for SecurityManagerTkValue {
     /// Create [`Vec<u8>`] from [`SecurityManagerTkValue`].
     ///
@@ -140,8 +181,9 @@ impl DataType for SecurityManagerTkValue {
 /// assert!(is_security_manager_tk_value(0x10));
 /// assert!(!is_security_manager_tk_value(0x00));
 /// ```
+#[deprecated(note = "use `AdType::of(data_type).matches::<SecurityManagerTkValue>()` instead")]
 pub fn is_security_manager_tk_value(data_type: u8) -> bool {
-    SecurityManagerTkValue::data_type() == data_type
+    AdType::of(data_type).matches::<SecurityManagerTkValue>()
 }
 
 #[cfg(test)]
@@ -211,6 +253,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_is_security_manager_tk_value() {
         assert!(is_security_manager_tk_value(0x10));
         assert!(!is_security_manager_tk_value(0x00));
@@ -2,7 +2,10 @@
 
 use uuid::Uuid;
 
-use crate::data_types::data_type::DataType;
+use crate::data_types::{
+    ad_type::AdType,
+    data_type::{validate_length, DataType, ServiceDataBytes},
+};
 
 /// Service Data - 128-bit UUID.
 #[derive(Debug, PartialEq, Clone)]
@@ -14,7 +17,7 @@ pub struct ServiceData128BitUUID {
     pub uuid: Uuid,
 
     /// Additional service data
-    pub additional_service_data: Vec<u8>,
+    pub additional_service_data: ServiceDataBytes,
 }
 
 impl ServiceData128BitUUID {
@@ -31,17 +34,58 @@ impl ServiceData128BitUUID {
     /// let result = ServiceData128BitUUID::new(&uuid, &additional_service_data);
     /// assert_eq!(additional_service_data.len() as u8 + 17, result.length);
     /// assert_eq!(uuid, result.uuid);
-    /// assert_eq!(additional_service_data, result.additional_service_data);
+    /// assert_eq!(additional_service_data, result.additional_service_data.as_slice());
     /// ```
     pub fn new(uuid: &Uuid, additional_service_data: &Vec<u8>) -> Self {
         Self {
             length: 17 + additional_service_data.len() as u8,
             uuid: uuid.clone(),
-            additional_service_data: additional_service_data.clone(),
+            additional_service_data: additional_service_data.as_slice().into(),
         }
     }
 }
 
+impl TryFrom<&[u8]> for ServiceData128BitUUID {
+    type Error = String;
+    /// Create [`ServiceData128BitUUID`] from `u8` slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{BASE_UUID, data_types::{service_data_128bit_uuid::ServiceData128BitUUID, data_type::DataType}};
+    /// use uuid::{uuid, Uuid};
+    ///
+    /// let uuid = uuid!("00000001-0000-1000-8000-00805F9B34FB");
+    /// let uuid_bytes: Vec<u8> = uuid.as_u128().to_le_bytes().to_vec().to_vec();
+    /// let additional_service_data = [0x11u8].to_vec();
+    /// let length = additional_service_data.len() as u8 + 17;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(ServiceData128BitUUID::data_type());
+    /// data.append(&mut uuid_bytes.clone());
+    /// data.append(&mut additional_service_data.clone());
+    ///
+    /// let result = ServiceData128BitUUID::try_from(data.as_slice());
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert_eq!(uuid, data_type.uuid);
+    /// assert_eq!(additional_service_data, data_type.additional_service_data.as_slice());
+    ///
+    /// let mut data: Vec<u8> = vec![0u8; 17];
+    /// data[0] = data.len() as u8 - 1;
+    /// let result = ServiceData128BitUUID::try_from(data.as_slice());
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &[u8]) -> Result<Self, String> {
+        Ok(ServiceData128BitUUIDRef::try_from(value)?.to_owned())
+    }
+}
+
 impl TryFrom<&Vec<u8>> for ServiceData128BitUUID {
     type Error = String;
     /// Create [`ServiceData128BitUUID`] from [`Vec<u8>`].
@@ -67,7 +111,7 @@ impl TryFrom<&Vec<u8>> for ServiceData128BitUUID {
     /// let data_type = result.unwrap();
     /// assert_eq!(length, data_type.length);
     /// assert_eq!(uuid, data_type.uuid);
-    /// assert_eq!(additional_service_data, data_type.additional_service_data);
+    /// assert_eq!(additional_service_data, data_type.additional_service_data.as_slice());
     ///
     /// let mut data: Vec<u8> = vec![0u8; 17];
     /// data[0] = data.len() as u8 - 1;
@@ -79,15 +123,82 @@ impl TryFrom<&Vec<u8>> for ServiceData128BitUUID {
     /// );
     /// ```
     fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        Self::try_from(value.as_slice())
+    }
+}
+
+/// Borrowed view of [`ServiceData128BitUUID`].
+#[derive(Debug, PartialEq, Clone)]
+pub struct ServiceData128BitUUIDRef<'a> {
+    /// data length
+    pub length: u8,
+
+    /// UUID
+    pub uuid: Uuid,
+
+    /// Additional service data
+    pub additional_service_data: &'a [u8],
+}
+
+impl ServiceData128BitUUIDRef<'_> {
+    /// Create [`ServiceData128BitUUID`] from [`ServiceData128BitUUIDRef`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{data_type::DataType, service_data_128bit_uuid::*};
+    ///
+    /// let mut data: Vec<u8> = vec![19, ServiceData128BitUUID::data_type()];
+    /// data.append(&mut vec![0x00u8; 16]);
+    /// data.append(&mut vec![0x01, 0x02]);
+    /// let result = ServiceData128BitUUIDRef::try_from(data.as_slice()).unwrap();
+    /// assert_eq!(ServiceData128BitUUID::try_from(&data).unwrap(), result.to_owned());
+    /// ```
+    pub fn to_owned(&self) -> ServiceData128BitUUID {
+        ServiceData128BitUUID {
+            length: self.length,
+            uuid: self.uuid,
+            additional_service_data: self.additional_service_data.into(),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for ServiceData128BitUUIDRef<'a> {
+    type Error = String;
+    /// Create [`ServiceData128BitUUIDRef`] from `u8` slice without copying.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{data_type::DataType, service_data_128bit_uuid::*};
+    ///
+    /// let mut data: Vec<u8> = vec![19, ServiceData128BitUUID::data_type()];
+    /// data.append(&mut vec![0x00u8; 16]);
+    /// data.append(&mut vec![0x01, 0x02]);
+    /// let result = ServiceData128BitUUIDRef::try_from(data.as_slice());
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(19, data_type.length);
+    /// assert_eq!(&[0x01u8, 0x02u8], data_type.additional_service_data);
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = ServiceData128BitUUIDRef::try_from(data.as_slice());
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &'a [u8]) -> Result<Self, String> {
         let len = value.len();
         if len < 18 {
             return Err(format!("Invalid data size :{}", len).to_string());
         }
-        let length = value[0];
+        let length = validate_length(value, 17, 255)?;
         Ok(Self {
             length,
             uuid: Uuid::from_u128(u128::from_le_bytes(value[2..18].try_into().unwrap())),
-            additional_service_data: value[18..1 + length as usize].to_vec(),
+            additional_service_data: &value[18..1 + length as usize],
         })
     }
 }
@@ -127,7 +238,7 @@ impl Into<Vec<u8>> for ServiceData128BitUUID {
         data.push(self.length);
         data.push(Self::data_type());
         data.append(&mut self.uuid.as_u128().to_le_bytes().to_vec());
-        data.append(&mut self.additional_service_data.clone());
+        data.extend_from_slice(&self.additional_service_data);
         return data;
     }
 }
@@ -158,8 +269,9 @@ impl DataType for ServiceData128BitUUID {
 /// assert!(is_service_data_128bit_uuid(0x21));
 /// assert!(!is_service_data_128bit_uuid(0x00));
 /// ```
+#[deprecated(note = "use `AdType::of(data_type).matches::<ServiceData128BitUUID>()` instead")]
 pub fn is_service_data_128bit_uuid(data_type: u8) -> bool {
-    ServiceData128BitUUID::data_type() == data_type
+    AdType::of(data_type).matches::<ServiceData128BitUUID>()
 }
 
 #[cfg(test)]
@@ -175,7 +287,41 @@ mod tests {
         let result = ServiceData128BitUUID::new(&uuid, &additional_service_data);
         assert_eq!(additional_service_data.len() as u8 + 17, result.length);
         assert_eq!(uuid, result.uuid);
-        assert_eq!(additional_service_data, result.additional_service_data);
+        assert_eq!(
+            additional_service_data,
+            result.additional_service_data.as_slice()
+        );
+    }
+
+    #[test]
+    fn test_ref_try_from() {
+        let mut data: Vec<u8> = vec![18, ServiceData128BitUUID::data_type()];
+        data.append(&mut vec![0x00u8; 16]);
+        data.push(0x01);
+        data.push(0xff);
+        let result = ServiceData128BitUUIDRef::try_from(data.as_slice());
+        assert!(result.is_ok());
+        let data_type = result.unwrap();
+        assert_eq!(18, data_type.length);
+        assert_eq!(&[0x01u8], data_type.additional_service_data);
+        assert_eq!(
+            data_type.additional_service_data.as_ptr(),
+            data[18..].as_ptr()
+        );
+        assert_eq!(
+            ServiceData128BitUUID::try_from(&data).unwrap(),
+            data_type.to_owned()
+        );
+
+        let mut data: Vec<u8> = vec![19, ServiceData128BitUUID::data_type()];
+        data.append(&mut vec![0x00u8; 16]);
+        data.push(0x01);
+        let result = ServiceData128BitUUIDRef::try_from(data.as_slice());
+        assert!(result.is_err());
+        assert_eq!(
+            format!("Invalid data size :{}", data.len()),
+            result.unwrap_err()
+        );
     }
 
     #[test]
@@ -195,7 +341,10 @@ mod tests {
         let data_type = result.unwrap();
         assert_eq!(length, data_type.length);
         assert_eq!(uuid, data_type.uuid);
-        assert_eq!(additional_service_data, data_type.additional_service_data);
+        assert_eq!(
+            additional_service_data,
+            data_type.additional_service_data.as_slice()
+        );
 
         let mut data: Vec<u8> = vec![0u8; 17];
         data[0] = data.len() as u8 - 1;
@@ -237,6 +386,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_is_service_data_128bit_uuid() {
         assert!(is_service_data_128bit_uuid(0x21));
         assert!(!is_service_data_128bit_uuid(0x00));
@@ -2,7 +2,13 @@
 
 use uuid::Uuid;
 
-use crate::{data_types::data_type::DataType, BASE_UUID};
+use crate::{
+    data_types::{
+        ad_type::AdType,
+        data_type::{validate_length, DataType, ServiceDataBytes},
+    },
+    BASE_UUID,
+};
 
 /// Service Data - 16-bit UUID.
 #[derive(Debug, PartialEq, Clone)]
@@ -14,7 +20,7 @@ pub struct ServiceData16BitUUID {
     pub uuid: Uuid,
 
     /// Additional service data
-    pub additional_service_data: Vec<u8>,
+    pub additional_service_data: ServiceDataBytes,
 }
 
 impl ServiceData16BitUUID {
@@ -31,17 +37,63 @@ impl ServiceData16BitUUID {
     /// let result = ServiceData16BitUUID::new(&uuid, &additional_service_data);
     /// assert_eq!(additional_service_data.len() as u8 + 3, result.length);
     /// assert_eq!(uuid, result.uuid);
-    /// assert_eq!(additional_service_data, result.additional_service_data);
+    /// assert_eq!(additional_service_data, result.additional_service_data.as_slice());
     /// ```
     pub fn new(uuid: &Uuid, additional_service_data: &Vec<u8>) -> Self {
         Self {
             length: 3 + additional_service_data.len() as u8,
             uuid: uuid.clone(),
-            additional_service_data: additional_service_data.clone(),
+            additional_service_data: additional_service_data.as_slice().into(),
         }
     }
 }
 
+impl TryFrom<&[u8]> for ServiceData16BitUUID {
+    type Error = String;
+    /// Create [`ServiceData16BitUUID`] from `u8` slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{BASE_UUID, data_types::{service_data_16bit_uuid::ServiceData16BitUUID, data_type::DataType}};
+    /// use uuid::{uuid, Uuid};
+    ///
+    /// let uuid_bytes: Vec<u8> = [0x01u8, 0x02u8].to_vec();
+    /// let (d1, d2, d3, d4) = BASE_UUID.as_fields();
+    /// let uuid = Uuid::from_fields(
+    ///     d1 | ((uuid_bytes[0] as u32) << 0) | ((uuid_bytes[1] as u32) << 8),
+    ///     d2,
+    ///     d3,
+    ///     d4,
+    /// );
+    /// let additional_service_data = [0x03u8].to_vec();
+    /// let length = additional_service_data.len() as u8 + 3;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(ServiceData16BitUUID::data_type());
+    /// data.append(&mut uuid_bytes.clone());
+    /// data.append(&mut additional_service_data.clone());
+    ///
+    /// let result = ServiceData16BitUUID::try_from(data.as_slice());
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert_eq!(uuid, data_type.uuid);
+    /// assert_eq!(additional_service_data, data_type.additional_service_data.as_slice());
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = ServiceData16BitUUID::try_from(data.as_slice());
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &[u8]) -> Result<Self, String> {
+        Ok(ServiceData16BitUUIDRef::try_from(value)?.to_owned())
+    }
+}
+
 impl TryFrom<&Vec<u8>> for ServiceData16BitUUID {
     type Error = String;
     /// Create [`ServiceData16BitUUID`] from [`Vec<u8>`].
@@ -73,7 +125,7 @@ impl TryFrom<&Vec<u8>> for ServiceData16BitUUID {
     /// let data_type = result.unwrap();
     /// assert_eq!(length, data_type.length);
     /// assert_eq!(uuid, data_type.uuid);
-    /// assert_eq!(additional_service_data, data_type.additional_service_data);
+    /// assert_eq!(additional_service_data, data_type.additional_service_data.as_slice());
     ///
     /// let data: Vec<u8> = Vec::new();
     /// let result = ServiceData16BitUUID::try_from(&data);
@@ -84,18 +136,85 @@ impl TryFrom<&Vec<u8>> for ServiceData16BitUUID {
     /// );
     /// ```
     fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        Self::try_from(value.as_slice())
+    }
+}
+
+/// Borrowed view of [`ServiceData16BitUUID`].
+#[derive(Debug, PartialEq, Clone)]
+pub struct ServiceData16BitUUIDRef<'a> {
+    /// data length
+    pub length: u8,
+
+    /// UUID
+    pub uuid: Uuid,
+
+    /// Additional service data
+    pub additional_service_data: &'a [u8],
+}
+
+impl ServiceData16BitUUIDRef<'_> {
+    /// Create [`ServiceData16BitUUID`] from [`ServiceData16BitUUIDRef`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{data_type::DataType, service_data_16bit_uuid::*};
+    ///
+    /// let mut data: Vec<u8> = vec![5, ServiceData16BitUUID::data_type()];
+    /// data.append(&mut vec![0x00u8; 2]);
+    /// data.append(&mut vec![0x01, 0x02]);
+    /// let result = ServiceData16BitUUIDRef::try_from(data.as_slice()).unwrap();
+    /// assert_eq!(ServiceData16BitUUID::try_from(&data).unwrap(), result.to_owned());
+    /// ```
+    pub fn to_owned(&self) -> ServiceData16BitUUID {
+        ServiceData16BitUUID {
+            length: self.length,
+            uuid: self.uuid,
+            additional_service_data: self.additional_service_data.into(),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for ServiceData16BitUUIDRef<'a> {
+    type Error = String;
+    /// Create [`ServiceData16BitUUIDRef`] from `u8` slice without copying.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{data_type::DataType, service_data_16bit_uuid::*};
+    ///
+    /// let mut data: Vec<u8> = vec![5, ServiceData16BitUUID::data_type()];
+    /// data.append(&mut vec![0x00u8; 2]);
+    /// data.append(&mut vec![0x01, 0x02]);
+    /// let result = ServiceData16BitUUIDRef::try_from(data.as_slice());
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(5, data_type.length);
+    /// assert_eq!(&[0x01u8, 0x02u8], data_type.additional_service_data);
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = ServiceData16BitUUIDRef::try_from(data.as_slice());
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &'a [u8]) -> Result<Self, String> {
         let len = value.len();
         if len < 4 {
             return Err(format!("Invalid data size :{}", len).to_string());
         }
-        let length = value[0];
+        let length = validate_length(value, 3, 255)?;
         let mut bytes = BASE_UUID.to_bytes_le();
         bytes[0] = value[2];
         bytes[1] = value[3];
         Ok(Self {
             length,
             uuid: Uuid::from_bytes_le(bytes),
-            additional_service_data: value[4..1 + length as usize].to_vec(),
+            additional_service_data: &value[4..1 + length as usize],
         })
     }
 }
@@ -141,7 +260,7 @@ impl Into<Vec<u8>> for ServiceData16BitUUID {
         data.push(self.length);
         data.push(Self::data_type());
         data.append(&mut self.uuid.to_bytes_le()[..2].to_vec());
-        data.append(&mut self.additional_service_data.clone());
+        data.extend_from_slice(&self.additional_service_data);
         return data;
     }
 }
@@ -172,8 +291,9 @@ impl DataType for ServiceData16BitUUID {
 /// assert!(is_service_data_16bit_uuid(0x16));
 /// assert!(!is_service_data_16bit_uuid(0x00));
 /// ```
+#[deprecated(note = "use `AdType::of(data_type).matches::<ServiceData16BitUUID>()` instead")]
 pub fn is_service_data_16bit_uuid(data_type: u8) -> bool {
-    ServiceData16BitUUID::data_type() == data_type
+    AdType::of(data_type).matches::<ServiceData16BitUUID>()
 }
 
 #[cfg(test)]
@@ -192,7 +312,41 @@ mod tests {
         let result = ServiceData16BitUUID::new(&uuid, &additional_service_data);
         assert_eq!(additional_service_data.len() as u8 + 3, result.length);
         assert_eq!(uuid, result.uuid);
-        assert_eq!(additional_service_data, result.additional_service_data);
+        assert_eq!(
+            additional_service_data,
+            result.additional_service_data.as_slice()
+        );
+    }
+
+    #[test]
+    fn test_ref_try_from() {
+        let mut data: Vec<u8> = vec![4, ServiceData16BitUUID::data_type()];
+        data.append(&mut vec![0x00u8; 2]);
+        data.push(0x01);
+        data.push(0xff);
+        let result = ServiceData16BitUUIDRef::try_from(data.as_slice());
+        assert!(result.is_ok());
+        let data_type = result.unwrap();
+        assert_eq!(4, data_type.length);
+        assert_eq!(&[0x01u8], data_type.additional_service_data);
+        assert_eq!(
+            data_type.additional_service_data.as_ptr(),
+            data[4..].as_ptr()
+        );
+        assert_eq!(
+            ServiceData16BitUUID::try_from(&data).unwrap(),
+            data_type.to_owned()
+        );
+
+        let mut data: Vec<u8> = vec![5, ServiceData16BitUUID::data_type()];
+        data.append(&mut vec![0x00u8; 2]);
+        data.push(0x01);
+        let result = ServiceData16BitUUIDRef::try_from(data.as_slice());
+        assert!(result.is_err());
+        assert_eq!(
+            format!("Invalid data size :{}", data.len()),
+            result.unwrap_err()
+        );
     }
 
     #[test]
@@ -218,7 +372,10 @@ mod tests {
         let data_type = result.unwrap();
         assert_eq!(length, data_type.length);
         assert_eq!(uuid, data_type.uuid);
-        assert_eq!(additional_service_data, data_type.additional_service_data);
+        assert_eq!(
+            additional_service_data,
+            data_type.additional_service_data.as_slice()
+        );
 
         let mut data: Vec<u8> = vec![0u8; 3];
         data[0] = data.len() as u8 - 1;
@@ -266,6 +423,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_is_service_data_16bit_uuid() {
         assert!(is_service_data_16bit_uuid(0x16));
         assert!(!is_service_data_16bit_uuid(0x00));
@@ -2,7 +2,13 @@
 
 use uuid::Uuid;
 
-use crate::{data_types::data_type::DataType, BASE_UUID};
+use crate::{
+    data_types::{
+        ad_type::AdType,
+        data_type::{validate_length, DataType, ServiceDataBytes},
+    },
+    BASE_UUID,
+};
 
 /// Service Data - 32-bit UUID.
 #[derive(Debug, PartialEq, Clone)]
@@ -14,7 +20,7 @@ pub struct ServiceData32BitUUID {
     pub uuid: Uuid,
 
     /// Additional service data
-    pub additional_service_data: Vec<u8>,
+    pub additional_service_data: ServiceDataBytes,
 }
 
 impl ServiceData32BitUUID {
@@ -31,17 +37,59 @@ impl ServiceData32BitUUID {
     /// let result = ServiceData32BitUUID::new(&uuid, &additional_service_data);
     /// assert_eq!(additional_service_data.len() as u8 + 5, result.length);
     /// assert_eq!(uuid, result.uuid);
-    /// assert_eq!(additional_service_data, result.additional_service_data);
+    /// assert_eq!(additional_service_data, result.additional_service_data.as_slice());
     /// ```
     pub fn new(uuid: &Uuid, additional_service_data: &Vec<u8>) -> Self {
         Self {
             length: 5 + additional_service_data.len() as u8,
             uuid: uuid.clone(),
-            additional_service_data: additional_service_data.clone(),
+            additional_service_data: additional_service_data.as_slice().into(),
         }
     }
 }
 
+impl TryFrom<&[u8]> for ServiceData32BitUUID {
+    type Error = String;
+    /// Create [`ServiceData32BitUUID`] from `u8` slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{BASE_UUID, data_types::{service_data_32bit_uuid::ServiceData32BitUUID, data_type::DataType}};
+    /// use uuid::{uuid, Uuid};
+    ///
+    /// let uuid_bytes: Vec<u8> = [0x01u8, 0x02u8, 0x03u8, 0x04u8].to_vec();
+    /// let mut uuid_bytes_le: Vec<u8> = uuid_bytes.to_vec();
+    /// uuid_bytes_le.append(&mut BASE_UUID.to_bytes_le()[4..].to_vec());
+    /// let uuid = Uuid::from_bytes_le(uuid_bytes_le.try_into().unwrap());
+    /// let additional_service_data = [0x05u8].to_vec();
+    /// let length = additional_service_data.len() as u8 + 5;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(ServiceData32BitUUID::data_type());
+    /// data.append(&mut uuid_bytes.clone());
+    /// data.append(&mut additional_service_data.clone());
+    ///
+    /// let result = ServiceData32BitUUID::try_from(data.as_slice());
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert_eq!(uuid, data_type.uuid);
+    /// assert_eq!(additional_service_data, data_type.additional_service_data.as_slice());
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = ServiceData32BitUUID::try_from(data.as_slice());
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &[u8]) -> Result<Self, String> {
+        Ok(ServiceData32BitUUIDRef::try_from(value)?.to_owned())
+    }
+}
+
 impl TryFrom<&Vec<u8>> for ServiceData32BitUUID {
     type Error = String;
     /// Create [`ServiceData32BitUUID`] from [`Vec<u8>`].
@@ -69,7 +117,7 @@ impl TryFrom<&Vec<u8>> for ServiceData32BitUUID {
     /// let data_type = result.unwrap();
     /// assert_eq!(length, data_type.length);
     /// assert_eq!(uuid, data_type.uuid);
-    /// assert_eq!(additional_service_data, data_type.additional_service_data);
+    /// assert_eq!(additional_service_data, data_type.additional_service_data.as_slice());
     ///
     /// let data: Vec<u8> = Vec::new();
     /// let result = ServiceData32BitUUID::try_from(&data);
@@ -80,11 +128,78 @@ impl TryFrom<&Vec<u8>> for ServiceData32BitUUID {
     /// );
     /// ```
     fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        Self::try_from(value.as_slice())
+    }
+}
+
+/// Borrowed view of [`ServiceData32BitUUID`].
+#[derive(Debug, PartialEq, Clone)]
+pub struct ServiceData32BitUUIDRef<'a> {
+    /// data length
+    pub length: u8,
+
+    /// UUID
+    pub uuid: Uuid,
+
+    /// Additional service data
+    pub additional_service_data: &'a [u8],
+}
+
+impl ServiceData32BitUUIDRef<'_> {
+    /// Create [`ServiceData32BitUUID`] from [`ServiceData32BitUUIDRef`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{data_type::DataType, service_data_32bit_uuid::*};
+    ///
+    /// let mut data: Vec<u8> = vec![7, ServiceData32BitUUID::data_type()];
+    /// data.append(&mut vec![0x00u8; 4]);
+    /// data.append(&mut vec![0x01, 0x02]);
+    /// let result = ServiceData32BitUUIDRef::try_from(data.as_slice()).unwrap();
+    /// assert_eq!(ServiceData32BitUUID::try_from(&data).unwrap(), result.to_owned());
+    /// ```
+    pub fn to_owned(&self) -> ServiceData32BitUUID {
+        ServiceData32BitUUID {
+            length: self.length,
+            uuid: self.uuid,
+            additional_service_data: self.additional_service_data.into(),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for ServiceData32BitUUIDRef<'a> {
+    type Error = String;
+    /// Create [`ServiceData32BitUUIDRef`] from `u8` slice without copying.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{data_type::DataType, service_data_32bit_uuid::*};
+    ///
+    /// let mut data: Vec<u8> = vec![7, ServiceData32BitUUID::data_type()];
+    /// data.append(&mut vec![0x00u8; 4]);
+    /// data.append(&mut vec![0x01, 0x02]);
+    /// let result = ServiceData32BitUUIDRef::try_from(data.as_slice());
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(7, data_type.length);
+    /// assert_eq!(&[0x01u8, 0x02u8], data_type.additional_service_data);
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = ServiceData32BitUUIDRef::try_from(data.as_slice());
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &'a [u8]) -> Result<Self, String> {
         let len: usize = value.len();
         if len < 6 {
             return Err(format!("Invalid data size :{}", len).to_string());
         }
-        let length = value[0];
+        let length = validate_length(value, 5, 255)?;
         let mut bytes = BASE_UUID.to_bytes_le();
         bytes[0] = value[2];
         bytes[1] = value[3];
@@ -93,7 +208,7 @@ impl TryFrom<&Vec<u8>> for ServiceData32BitUUID {
         Ok(Self {
             length,
             uuid: Uuid::from_bytes_le(bytes),
-            additional_service_data: value[6..1 + length as usize].to_vec(),
+            additional_service_data: &value[6..1 + length as usize],
         })
     }
 }
@@ -135,7 +250,7 @@ impl Into<Vec<u8>> for ServiceData32BitUUID {
         data.push(self.length);
         data.push(Self::data_type());
         data.append(&mut self.uuid.to_bytes_le()[..4].to_vec());
-        data.append(&mut self.additional_service_data.clone());
+        data.extend_from_slice(&self.additional_service_data);
         return data;
     }
 }
@@ -166,8 +281,9 @@ impl DataType for ServiceData32BitUUID {
 /// assert!(is_service_data_32bit_uuid(0x20));
 /// assert!(!is_service_data_32bit_uuid(0x00));
 /// ```
+#[deprecated(note = "use `AdType::of(data_type).matches::<ServiceData32BitUUID>()` instead")]
 pub fn is_service_data_32bit_uuid(data_type: u8) -> bool {
-    ServiceData32BitUUID::data_type() == data_type
+    AdType::of(data_type).matches::<ServiceData32BitUUID>()
 }
 
 #[cfg(test)]
@@ -186,7 +302,41 @@ mod tests {
         let result = ServiceData32BitUUID::new(&uuid, &additional_service_data);
         assert_eq!(additional_service_data.len() as u8 + 5, result.length);
         assert_eq!(uuid, result.uuid);
-        assert_eq!(additional_service_data, result.additional_service_data);
+        assert_eq!(
+            additional_service_data,
+            result.additional_service_data.as_slice()
+        );
+    }
+
+    #[test]
+    fn test_ref_try_from() {
+        let mut data: Vec<u8> = vec![6, ServiceData32BitUUID::data_type()];
+        data.append(&mut vec![0x00u8; 4]);
+        data.push(0x01);
+        data.push(0xff);
+        let result = ServiceData32BitUUIDRef::try_from(data.as_slice());
+        assert!(result.is_ok());
+        let data_type = result.unwrap();
+        assert_eq!(6, data_type.length);
+        assert_eq!(&[0x01u8], data_type.additional_service_data);
+        assert_eq!(
+            data_type.additional_service_data.as_ptr(),
+            data[6..].as_ptr()
+        );
+        assert_eq!(
+            ServiceData32BitUUID::try_from(&data).unwrap(),
+            data_type.to_owned()
+        );
+
+        let mut data: Vec<u8> = vec![7, ServiceData32BitUUID::data_type()];
+        data.append(&mut vec![0x00u8; 4]);
+        data.push(0x01);
+        let result = ServiceData32BitUUIDRef::try_from(data.as_slice());
+        assert!(result.is_err());
+        assert_eq!(
+            format!("Invalid data size :{}", data.len()),
+            result.unwrap_err()
+        );
     }
 
     #[test]
@@ -208,7 +358,10 @@ mod tests {
         let data_type = result.unwrap();
         assert_eq!(length, data_type.length);
         assert_eq!(uuid, data_type.uuid);
-        assert_eq!(additional_service_data, data_type.additional_service_data);
+        assert_eq!(
+            additional_service_data,
+            data_type.additional_service_data.as_slice()
+        );
 
         let mut data: Vec<u8> = vec![0u8; 5];
         data[0] = data.len() as u8 - 1;
@@ -252,6 +405,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_is_service_data_32bit_uuid() {
         assert!(is_service_data_32bit_uuid(0x20));
         assert!(!is_service_data_32bit_uuid(0x00));
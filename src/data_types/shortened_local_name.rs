@@ -1,6 +1,12 @@
 //! Shortened Local Name (Data Type Value: 0x08) module.
 
-use crate::data_types::data_type::DataType;
+use std::borrow::Cow;
+
+use crate::data_types::{
+    ad_type::AdType,
+    data_type::{validate_length, DataType},
+    parse_options::{decode_utf8, ParseOptions},
+};
 
 /// Shortened Local Name.
 
@@ -11,6 +17,9 @@ pub struct ShortenedLocalName {
 
     /// Shortened Local Name
     pub shortened_local_name: String,
+
+    /// Original name bytes
+    pub raw_name: Vec<u8>,
 }
 
 impl ShortenedLocalName {
@@ -30,8 +39,75 @@ impl ShortenedLocalName {
         Self {
             length: shortened_local_name.as_bytes().len() as u8 + 1,
             shortened_local_name: shortened_local_name.to_string(),
+            raw_name: shortened_local_name.as_bytes().to_vec(),
         }
     }
+
+    /// Create [`ShortenedLocalName`] from `u8` slice with [`ParseOptions`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     shortened_local_name::ShortenedLocalName,
+    ///     data_type::DataType,
+    ///     parse_options::{ParseOptions, Utf8DecodeMode},
+    /// };
+    ///
+    /// let data: Vec<u8> = vec![5, ShortenedLocalName::data_type(), 0x6e, 0x61, 0xff, 0x65];
+    ///
+    /// let result = ShortenedLocalName::try_from_with_options(&data, &ParseOptions::new(Utf8DecodeMode::Lossy));
+    /// assert_eq!("na\u{fffd}e", result.unwrap().shortened_local_name);
+    ///
+    /// let result = ShortenedLocalName::try_from_with_options(&data, &ParseOptions::new(Utf8DecodeMode::Strict));
+    /// assert!(result.is_err());
+    /// ```
+    pub fn try_from_with_options(value: &[u8], options: &ParseOptions) -> Result<Self, String> {
+        let shortened_local_name = ShortenedLocalNameRef::try_from(value)?;
+        Ok(Self {
+            length: shortened_local_name.length,
+            shortened_local_name: decode_utf8(
+                shortened_local_name.raw_name,
+                options.utf8_decode_mode,
+            )?,
+            raw_name: shortened_local_name.raw_name.to_vec(),
+        })
+    }
+}
+
+impl TryFrom<&[u8]> for ShortenedLocalName {
+    type Error = String;
+    /// Create [`ShortenedLocalName`] from `u8` slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{shortened_local_name::ShortenedLocalName, data_type::DataType};
+    ///
+    /// let name = "shortened_local_name".to_string();
+    /// let length = name.as_bytes().len() as u8 + 1;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(ShortenedLocalName::data_type());
+    /// data.append(&mut name.to_string().into_bytes());
+    ///
+    /// let result = ShortenedLocalName::try_from(data.as_slice());
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert_eq!(name, data_type.shortened_local_name);
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = ShortenedLocalName::try_from(data.as_slice());
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &[u8]) -> Result<Self, String> {
+        Self::try_from_with_options(value, &ParseOptions::default())
+    }
 }
 
 impl TryFrom<&Vec<u8>> for ShortenedLocalName {
@@ -65,19 +141,169 @@ impl TryFrom<&Vec<u8>> for ShortenedLocalName {
     /// );
     /// ```
     fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        Self::try_from(value.as_slice())
+    }
+}
+
+/// Borrowed view of [`ShortenedLocalName`].
+#[derive(Debug, PartialEq, Clone)]
+pub struct ShortenedLocalNameRef<'a> {
+    /// data length
+    pub length: u8,
+
+    /// Original name bytes
+    pub raw_name: &'a [u8],
+}
+
+impl<'a> ShortenedLocalNameRef<'a> {
+    /// Create [`ShortenedLocalNameRef`] from `utf8` without copying.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::shortened_local_name::ShortenedLocalNameRef;
+    ///
+    /// let name = "name";
+    /// let result = ShortenedLocalNameRef::new(name);
+    /// assert_eq!(5, result.length);
+    /// assert_eq!(name.as_bytes(), result.raw_name);
+    /// ```
+    pub fn new(shortened_local_name: &'a str) -> Self {
+        Self {
+            length: shortened_local_name.len() as u8 + 1,
+            raw_name: shortened_local_name.as_bytes(),
+        }
+    }
+
+    /// Get Shortened Local Name (invalid UTF-8 sequences are replaced with `U+FFFD`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    /// use ble_data_struct::data_types::{shortened_local_name::*, data_type::DataType};
+    ///
+    /// let data: Vec<u8> = vec![5, ShortenedLocalName::data_type(), 0x6e, 0x61, 0x6d, 0x65];
+    /// let result = ShortenedLocalNameRef::try_from(data.as_slice()).unwrap();
+    /// assert!(matches!(result.shortened_local_name(), Cow::Borrowed("name")));
+    ///
+    /// let data: Vec<u8> = vec![5, ShortenedLocalName::data_type(), 0x6e, 0x61, 0xff, 0x65];
+    /// let result = ShortenedLocalNameRef::try_from(data.as_slice()).unwrap();
+    /// assert_eq!("na\u{fffd}e", result.shortened_local_name());
+    /// ```
+    pub fn shortened_local_name(&self) -> Cow<'a, str> {
+        String::from_utf8_lossy(self.raw_name)
+    }
+
+    /// Create [`ShortenedLocalName`] from [`ShortenedLocalNameRef`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{shortened_local_name::*, data_type::DataType};
+    ///
+    /// let data: Vec<u8> = vec![5, ShortenedLocalName::data_type(), 0x6e, 0x61, 0x6d, 0x65];
+    /// let result = ShortenedLocalNameRef::try_from(data.as_slice()).unwrap();
+    /// assert_eq!(ShortenedLocalName::new(&"name".to_string()), result.to_owned());
+    /// ```
+    pub fn to_owned(&self) -> ShortenedLocalName {
+        ShortenedLocalName {
+            length: self.length,
+            shortened_local_name: self.shortened_local_name().into_owned(),
+            raw_name: self.raw_name.to_vec(),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for ShortenedLocalNameRef<'a> {
+    type Error = String;
+    /// Create [`ShortenedLocalNameRef`] from `u8` slice without copying.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{shortened_local_name::*, data_type::DataType};
+    ///
+    /// let data: Vec<u8> = vec![5, ShortenedLocalName::data_type(), 0x6e, 0x61, 0x6d, 0x65];
+    /// let result = ShortenedLocalNameRef::try_from(data.as_slice());
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(5, data_type.length);
+    /// assert_eq!("name".as_bytes(), data_type.raw_name);
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = ShortenedLocalNameRef::try_from(data.as_slice());
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &'a [u8]) -> Result<Self, String> {
         let len = value.len();
         if len < 3 {
             return Err(format!("Invalid data size :{}", len).to_string());
         }
-        let length = value[0];
+        let length = validate_length(value, 1, 255)?;
         Ok(Self {
             length,
-            shortened_local_name: String::from_utf8(value[2..1 + usize::from(length)].to_vec())
-                .unwrap(),
+            raw_name: &value[2..1 + usize::from(length)],
         })
     }
 }
 
+impl<'a> From<&'a ShortenedLocalName> for ShortenedLocalNameRef<'a> {
+    /// Create [`ShortenedLocalNameRef`] from [`ShortenedLocalName`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::shortened_local_name::*;
+    ///
+    /// let result1 = ShortenedLocalName::new(&"name".to_string());
+    /// let result2 = ShortenedLocalNameRef::from(&result1);
+    /// assert_eq!(result1.length, result2.length);
+    /// assert_eq!(result1.raw_name.as_slice(), result2.raw_name);
+    ///
+    /// let mut result1 = ShortenedLocalName::new(&"name".to_string());
+    /// result1.shortened_local_name = "other".to_string();
+    /// let result2 = ShortenedLocalNameRef::from(&result1);
+    /// assert_eq!(6, result2.length);
+    /// assert_eq!("other".as_bytes(), result2.raw_name);
+    /// ```
+    fn from(value: &'a ShortenedLocalName) -> Self {
+        if String::from_utf8_lossy(&value.raw_name) == value.shortened_local_name {
+            Self {
+                length: value.length,
+                raw_name: &value.raw_name,
+            }
+        } else {
+            Self::new(&value.shortened_local_name)
+        }
+    }
+}
+
+impl From<ShortenedLocalNameRef<'_>> for Vec<u8> {
+    /// Create [`Vec<u8>`] from [`ShortenedLocalNameRef`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{shortened_local_name::*, data_type::DataType};
+    ///
+    /// let data: Vec<u8> = vec![5, ShortenedLocalName::data_type(), 0x6e, 0x61, 0x6d, 0x65];
+    /// let into_data: Vec<u8> = ShortenedLocalNameRef::new("name").into();
+    /// assert_eq!(data, into_data);
+    /// ```
+    fn from(value: ShortenedLocalNameRef<'_>) -> Self {
+        let mut data: Vec<u8> = Vec::with_capacity(2 + value.raw_name.len());
+        data.push(value.length);
+        data.push(ShortenedLocalName::data_type());
+        data.extend_from_slice(value.raw_name);
+        data
+    }
+}
+
 impl Into<Vec<u8>> for ShortenedLocalName {
     /// Create [`Vec<u8>`] from [`ShortenedLocalName`].
     ///
@@ -102,12 +328,20 @@ impl Into<Vec<u8>> for ShortenedLocalName {
     /// let data_type = result2.unwrap();
     /// let into_data: Vec<u8> = data_type.into();
     /// assert_eq!(data, into_data);
+    ///
+    /// let data: Vec<u8> = vec![5, ShortenedLocalName::data_type(), 0x6e, 0x61, 0xff, 0x65];
+    /// let into_data: Vec<u8> = ShortenedLocalName::try_from(&data).unwrap().into();
+    /// assert_eq!(data, into_data);
     /// ```
     fn into(self) -> Vec<u8> {
         let mut data: Vec<u8> = Vec::new();
         data.push(self.length);
         data.push(Self::data_type());
-        data.append(&mut self.shortened_local_name.clone().into_bytes());
+        if String::from_utf8_lossy(&self.raw_name) == self.shortened_local_name {
+            data.append(&mut self.raw_name.clone());
+        } else {
+            data.append(&mut self.shortened_local_name.clone().into_bytes());
+        }
         return data;
     }
 }
@@ -138,13 +372,18 @@ impl DataType for ShortenedLocalName {
 /// assert!(is_shortened_local_name(0x08));
 /// assert!(!is_shortened_local_name(0x00));
 /// ```
+#[deprecated(note = "use `AdType::of(data_type).matches::<ShortenedLocalName>()` instead")]
 pub fn is_shortened_local_name(data_type: u8) -> bool {
-    ShortenedLocalName::data_type() == data_type
+    AdType::of(data_type).matches::<ShortenedLocalName>()
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::data_types::{data_type::DataType, shortened_local_name::*};
+    use crate::data_types::{
+        data_type::DataType,
+        parse_options::{ParseOptions, Utf8DecodeMode},
+        shortened_local_name::*,
+    };
 
     #[test]
     fn test_new() {
@@ -152,6 +391,86 @@ mod tests {
         let result = ShortenedLocalName::new(&name);
         assert_eq!(name.as_bytes().len() as u8 + 1, result.length);
         assert_eq!(name, result.shortened_local_name);
+        assert_eq!(name.as_bytes().to_vec(), result.raw_name);
+    }
+
+    #[test]
+    fn test_ref_try_from() {
+        let data: Vec<u8> = vec![3, ShortenedLocalName::data_type(), 0x6e, 0x61, 0xff];
+        let result = ShortenedLocalNameRef::try_from(data.as_slice());
+        assert!(result.is_ok());
+        let data_type = result.unwrap();
+        assert_eq!(3, data_type.length);
+        assert_eq!(data[2..4].as_ptr(), data_type.raw_name.as_ptr());
+        assert_eq!("na", data_type.shortened_local_name());
+        assert_eq!(
+            ShortenedLocalName::try_from(&data[..4].to_vec()).unwrap(),
+            data_type.to_owned()
+        );
+
+        let data: Vec<u8> = vec![4, ShortenedLocalName::data_type(), 0x6e, 0x61];
+        let result = ShortenedLocalNameRef::try_from(data.as_slice());
+        assert!(result.is_err());
+        assert_eq!(
+            format!("Invalid data size :{}", data.len()),
+            result.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_ref_new() {
+        let name = "shortened_local_name";
+        let result = ShortenedLocalNameRef::new(name);
+        assert_eq!(name.len() as u8 + 1, result.length);
+        assert_eq!(name.as_ptr(), result.raw_name.as_ptr());
+        assert_eq!(name, result.shortened_local_name());
+        assert_eq!(
+            ShortenedLocalName::new(&name.to_string()),
+            result.to_owned()
+        );
+    }
+
+    #[test]
+    fn test_ref_from() {
+        let data: Vec<u8> = vec![5, ShortenedLocalName::data_type(), 0x6e, 0x61, 0xff, 0x65];
+        let result1 = ShortenedLocalName::try_from(&data).unwrap();
+        let result2 = ShortenedLocalNameRef::from(&result1);
+        assert_eq!(5, result2.length);
+        assert_eq!(result1.raw_name.as_ptr(), result2.raw_name.as_ptr());
+        assert_eq!(result1, result2.to_owned());
+
+        let mut result1 = ShortenedLocalName::try_from(&data).unwrap();
+        result1.shortened_local_name = "other".to_string();
+        let result2 = ShortenedLocalNameRef::from(&result1);
+        assert_eq!(6, result2.length);
+        assert_eq!("other".as_bytes(), result2.raw_name);
+        let data: Vec<u8> = ShortenedLocalName::new(&"other".to_string()).into();
+        let into_data: Vec<u8> = result2.into();
+        assert_eq!(data, into_data);
+    }
+
+    #[test]
+    fn test_ref_into() {
+        let data: Vec<u8> = vec![5, ShortenedLocalName::data_type(), 0x6e, 0x61, 0xff, 0x65];
+        let into_data: Vec<u8> = ShortenedLocalNameRef::try_from(data.as_slice())
+            .unwrap()
+            .into();
+        assert_eq!(data, into_data);
+
+        let into_data: Vec<u8> = ShortenedLocalNameRef::new("shortened_local_name").into();
+        let into_data2: Vec<u8> =
+            ShortenedLocalName::new(&"shortened_local_name".to_string()).into();
+        assert_eq!(into_data2, into_data);
+    }
+
+    #[test]
+    fn test_ref_to_owned_lossy() {
+        let data: Vec<u8> = vec![5, ShortenedLocalName::data_type(), 0x6e, 0x61, 0xff, 0x65];
+        let result = ShortenedLocalNameRef::try_from(data.as_slice())
+            .unwrap()
+            .to_owned();
+        assert_eq!("na\u{fffd}e", result.shortened_local_name);
+        assert_eq!(vec![0x6e, 0x61, 0xff, 0x65], result.raw_name);
     }
 
     #[test]
@@ -179,6 +498,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_try_from_with_options() {
+        let data: Vec<u8> = vec![5, ShortenedLocalName::data_type(), 0x6e, 0x61, 0xff, 0x65];
+
+        let result = ShortenedLocalName::try_from_with_options(
+            &data,
+            &ParseOptions::new(Utf8DecodeMode::Lossy),
+        );
+        assert!(result.is_ok());
+        let data_type = result.unwrap();
+        assert_eq!(5, data_type.length);
+        assert_eq!("na\u{fffd}e", data_type.shortened_local_name);
+
+        let result = ShortenedLocalName::try_from_with_options(
+            &data,
+            &ParseOptions::new(Utf8DecodeMode::Strict),
+        );
+        assert_eq!(
+            Err("Invalid UTF-8 :invalid utf-8 sequence of 1 bytes from index 2".to_string()),
+            result
+        );
+
+        let data: Vec<u8> = vec![5, ShortenedLocalName::data_type(), 0x6e, 0x61, 0x6d, 0x65];
+        let result = ShortenedLocalName::try_from_with_options(
+            &data,
+            &ParseOptions::new(Utf8DecodeMode::Strict),
+        );
+        assert_eq!("name", result.unwrap().shortened_local_name);
+    }
+
     #[test]
     fn test_into() {
         let name = "shortened_local_name".to_string();
@@ -199,12 +548,30 @@ mod tests {
         assert_eq!(data, into_data);
     }
 
+    #[test]
+    fn test_into_raw_name() {
+        let data: Vec<u8> = vec![5, ShortenedLocalName::data_type(), 0x6e, 0x61, 0xff, 0x65];
+        let result = ShortenedLocalName::try_from(&data).unwrap();
+        assert_eq!(vec![0x6e, 0x61, 0xff, 0x65], result.raw_name);
+        let into_data: Vec<u8> = result.into();
+        assert_eq!(data, into_data);
+
+        let mut result = ShortenedLocalName::try_from(&data).unwrap();
+        result.shortened_local_name = "name".to_string();
+        let into_data: Vec<u8> = result.into();
+        assert_eq!(
+            vec![5, ShortenedLocalName::data_type(), 0x6e, 0x61, 0x6d, 0x65],
+            into_data
+        );
+    }
+
     #[test]
     fn test_data_type() {
         assert_eq!(0x08, ShortenedLocalName::data_type());
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_is_shortened_local_name() {
         assert!(is_shortened_local_name(0x08));
         assert!(!is_shortened_local_name(0x00));
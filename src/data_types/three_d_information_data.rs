@@ -0,0 +1,315 @@
+//! 3D Information Data (Data Type Value: 0x3d) module.
+
+use crate::data_types::data_type::{validate_length, DataType};
+
+/// 3D Information Data Flags: Association Notification
+pub const FLAGS_ASSOCIATION_NOTIFICATION: u8 = 0b0000_0001;
+
+/// 3D Information Data Flags: Battery Level Reporting
+pub const FLAGS_BATTERY_LEVEL_REPORTING: u8 = 0b0000_0010;
+
+/// 3D Information Data Flags: Send Battery Level Report on Start-up Synchronization
+pub const FLAGS_SEND_BATTERY_LEVEL_ON_STARTUP: u8 = 0b0000_0100;
+
+/// 3D Information Data Flags: Factory Test Mode
+pub const FLAGS_FACTORY_TEST_MODE: u8 = 0b1000_0000;
+
+/// 3D Information Data.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ThreeDInformationData {
+    /// data length
+    pub length: u8,
+
+    /// Flags
+    pub flags: u8,
+
+    /// Path Loss Threshold (dBm)
+    pub path_loss_threshold: i8,
+}
+
+impl ThreeDInformationData {
+    /// Create [`ThreeDInformationData`] from Parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::three_d_information_data::*;
+    ///
+    /// let result = ThreeDInformationData::new(FLAGS_ASSOCIATION_NOTIFICATION, -70);
+    /// assert_eq!(3, result.length);
+    /// assert_eq!(FLAGS_ASSOCIATION_NOTIFICATION, result.flags);
+    /// assert_eq!(-70, result.path_loss_threshold);
+    /// ```
+    pub const fn new(flags: u8, path_loss_threshold: i8) -> Self {
+        Self {
+            length: 3,
+            flags,
+            path_loss_threshold,
+        }
+    }
+
+    /// check Association Notification.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::three_d_information_data::*;
+    ///
+    /// let result = ThreeDInformationData::new(FLAGS_ASSOCIATION_NOTIFICATION, 0);
+    /// assert!(result.is_association_notification_supported());
+    ///
+    /// let result = ThreeDInformationData::new(0, 0);
+    /// assert!(!result.is_association_notification_supported());
+    /// ```
+    pub fn is_association_notification_supported(&self) -> bool {
+        self.flags & FLAGS_ASSOCIATION_NOTIFICATION != 0
+    }
+
+    /// check Battery Level Reporting.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::three_d_information_data::*;
+    ///
+    /// let result = ThreeDInformationData::new(FLAGS_BATTERY_LEVEL_REPORTING, 0);
+    /// assert!(result.is_battery_level_reporting_supported());
+    ///
+    /// let result = ThreeDInformationData::new(0, 0);
+    /// assert!(!result.is_battery_level_reporting_supported());
+    /// ```
+    pub fn is_battery_level_reporting_supported(&self) -> bool {
+        self.flags & FLAGS_BATTERY_LEVEL_REPORTING != 0
+    }
+
+    /// check Send Battery Level Report on Start-up Synchronization.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::three_d_information_data::*;
+    ///
+    /// let result = ThreeDInformationData::new(FLAGS_SEND_BATTERY_LEVEL_ON_STARTUP, 0);
+    /// assert!(result.is_send_battery_level_on_startup());
+    ///
+    /// let result = ThreeDInformationData::new(0, 0);
+    /// assert!(!result.is_send_battery_level_on_startup());
+    /// ```
+    pub fn is_send_battery_level_on_startup(&self) -> bool {
+        self.flags & FLAGS_SEND_BATTERY_LEVEL_ON_STARTUP != 0
+    }
+
+    /// check Factory Test Mode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::three_d_information_data::*;
+    ///
+    /// let result = ThreeDInformationData::new(FLAGS_FACTORY_TEST_MODE, 0);
+    /// assert!(result.is_factory_test_mode());
+    ///
+    /// let result = ThreeDInformationData::new(0, 0);
+    /// assert!(!result.is_factory_test_mode());
+    /// ```
+    pub fn is_factory_test_mode(&self) -> bool {
+        self.flags & FLAGS_FACTORY_TEST_MODE != 0
+    }
+}
+
+impl TryFrom<&[u8]> for ThreeDInformationData {
+    type Error = String;
+    /// Create [`ThreeDInformationData`] from `u8` slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{three_d_information_data::*, data_type::DataType};
+    ///
+    /// let data: Vec<u8> = vec![3, ThreeDInformationData::data_type(), 0b1000_0011, 0xba];
+    /// let result = ThreeDInformationData::try_from(data.as_slice());
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(3, data_type.length);
+    /// assert!(data_type.is_association_notification_supported());
+    /// assert!(data_type.is_battery_level_reporting_supported());
+    /// assert!(!data_type.is_send_battery_level_on_startup());
+    /// assert!(data_type.is_factory_test_mode());
+    /// assert_eq!(-70, data_type.path_loss_threshold);
+    ///
+    /// let data: Vec<u8> = vec![2, ThreeDInformationData::data_type(), 0x00];
+    /// let result = ThreeDInformationData::try_from(data.as_slice());
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &[u8]) -> Result<Self, String> {
+        let len = value.len();
+        if len < 4 {
+            return Err(format!("Invalid data size :{}", len).to_string());
+        }
+        Ok(Self {
+            length: validate_length(value, 3, 3)?,
+            flags: value[2],
+            path_loss_threshold: value[3] as i8,
+        })
+    }
+}
+
+impl TryFrom<&Vec<u8>> for ThreeDInformationData {
+    type Error = String;
+    /// Create [`ThreeDInformationData`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{three_d_information_data::*, data_type::DataType};
+    ///
+    /// let data: Vec<u8> = vec![3, ThreeDInformationData::data_type(), 0b1000_0011, 0xba];
+    /// let result = ThreeDInformationData::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(3, data_type.length);
+    /// assert!(data_type.is_association_notification_supported());
+    /// assert!(data_type.is_battery_level_reporting_supported());
+    /// assert!(!data_type.is_send_battery_level_on_startup());
+    /// assert!(data_type.is_factory_test_mode());
+    /// assert_eq!(-70, data_type.path_loss_threshold);
+    ///
+    /// let data: Vec<u8> = vec![2, ThreeDInformationData::data_type(), 0x00];
+    /// let result = ThreeDInformationData::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        Self::try_from(value.as_slice())
+    }
+}
+
+impl From<ThreeDInformationData> for Vec<u8> {
+    /// Create [`Vec<u8>`] from [`ThreeDInformationData`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{three_d_information_data::*, data_type::DataType};
+    ///
+    /// let result1 = ThreeDInformationData::new(FLAGS_BATTERY_LEVEL_REPORTING, -70);
+    ///
+    /// let data: Vec<u8> = vec![3, ThreeDInformationData::data_type(), 0b0000_0010, 0xba];
+    /// let into_data: Vec<u8> = result1.into();
+    /// assert_eq!(data, into_data);
+    ///
+    /// let result2 = ThreeDInformationData::try_from(&data);
+    /// assert!(result2.is_ok());
+    /// let data_type = result2.unwrap();
+    /// let into_data: Vec<u8> = data_type.into();
+    /// assert_eq!(data, into_data);
+    /// ```
+    fn from(value: ThreeDInformationData) -> Self {
+        vec![
+            value.length,
+            ThreeDInformationData::data_type(),
+            value.flags,
+            value.path_loss_threshold as u8,
+        ]
+    }
+}
+
+impl DataType for ThreeDInformationData {
+    /// return `0x3d`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{three_d_information_data::ThreeDInformationData, data_type::DataType};
+    ///
+    /// assert_eq!(0x3d, ThreeDInformationData::data_type());
+    /// ```
+    fn data_type() -> u8 {
+        0x3d
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data_types::{data_type::DataType, three_d_information_data::*};
+
+    #[test]
+    fn test_new() {
+        let result = ThreeDInformationData::new(0xff, i8::MIN);
+        assert_eq!(3, result.length);
+        assert_eq!(0xff, result.flags);
+        assert_eq!(i8::MIN, result.path_loss_threshold);
+    }
+
+    #[test]
+    fn test_flags() {
+        let result = ThreeDInformationData::new(0, 0);
+        assert!(!result.is_association_notification_supported());
+        assert!(!result.is_battery_level_reporting_supported());
+        assert!(!result.is_send_battery_level_on_startup());
+        assert!(!result.is_factory_test_mode());
+
+        let result = ThreeDInformationData::new(0b0111_1000, 0);
+        assert!(!result.is_association_notification_supported());
+        assert!(!result.is_battery_level_reporting_supported());
+        assert!(!result.is_send_battery_level_on_startup());
+        assert!(!result.is_factory_test_mode());
+
+        let result = ThreeDInformationData::new(0xff, 0);
+        assert!(result.is_association_notification_supported());
+        assert!(result.is_battery_level_reporting_supported());
+        assert!(result.is_send_battery_level_on_startup());
+        assert!(result.is_factory_test_mode());
+    }
+
+    #[test]
+    fn test_try_from() {
+        let data: Vec<u8> = vec![3, ThreeDInformationData::data_type(), 0b0000_0101, 0x7f];
+        let result = ThreeDInformationData::try_from(&data);
+        assert!(result.is_ok());
+        let data_type = result.unwrap();
+        assert_eq!(3, data_type.length);
+        assert_eq!(0b0000_0101, data_type.flags);
+        assert!(data_type.is_association_notification_supported());
+        assert!(data_type.is_send_battery_level_on_startup());
+        assert_eq!(127, data_type.path_loss_threshold);
+
+        let data: Vec<u8> = vec![3, ThreeDInformationData::data_type(), 0x00, 0x80];
+        let result = ThreeDInformationData::try_from(&data).unwrap();
+        assert_eq!(-128, result.path_loss_threshold);
+
+        let data: Vec<u8> = vec![2, ThreeDInformationData::data_type(), 0x00];
+        let result = ThreeDInformationData::try_from(&data);
+        assert!(result.is_err());
+        assert_eq!(
+            format!("Invalid data size :{}", data.len()),
+            result.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_into() {
+        let result1 = ThreeDInformationData::new(FLAGS_FACTORY_TEST_MODE, -1);
+        let data: Vec<u8> = vec![3, ThreeDInformationData::data_type(), 0x80, 0xff];
+        let into_data: Vec<u8> = result1.into();
+        assert_eq!(data, into_data);
+
+        let result2 = ThreeDInformationData::try_from(&data);
+        assert!(result2.is_ok());
+        let data_type = result2.unwrap();
+        let into_data: Vec<u8> = data_type.into();
+        assert_eq!(data, into_data);
+    }
+
+    #[test]
+    fn test_data_type() {
+        assert_eq!(0x3d, ThreeDInformationData::data_type());
+    }
+}
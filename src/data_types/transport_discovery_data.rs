@@ -0,0 +1,831 @@
+//! Transport Discovery Data (Data Type Value: 0x26) module.
+
+use crate::data_types::data_type::DataType;
+
+/// Organization ID: Bluetooth SIG
+pub const ORGANIZATION_ID_BLUETOOTH_SIG: u8 = 0x01;
+
+/// Organization ID: Wi-Fi Alliance Service Advertisement
+pub const ORGANIZATION_ID_WIFI_ALLIANCE: u8 = 0x02;
+
+/// TDS Flags: Role mask
+pub const TDS_FLAGS_ROLE_MASK: u8 = 0b0000_0011;
+
+/// TDS Flags: Transport Data Incomplete
+pub const TDS_FLAGS_TRANSPORT_DATA_INCOMPLETE: u8 = 0b0000_0100;
+
+/// TDS Flags: Transport State mask
+pub const TDS_FLAGS_TRANSPORT_STATE_MASK: u8 = 0b0001_1000;
+
+/// Transport State: Off
+pub const TRANSPORT_STATE_OFF: u8 = 0x00;
+
+/// Transport State: On
+pub const TRANSPORT_STATE_ON: u8 = 0x01;
+
+/// Transport State: Temporarily Unavailable
+pub const TRANSPORT_STATE_TEMPORARILY_UNAVAILABLE: u8 = 0x02;
+
+/// Organization ID.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum OrganizationId {
+    /// Bluetooth SIG
+    BluetoothSig,
+
+    /// Wi-Fi Alliance Service Advertisement
+    WifiAlliance,
+
+    /// Other (reserved or not supported) Organization ID
+    Other(u8),
+}
+
+impl From<u8> for OrganizationId {
+    /// Create [`OrganizationId`] from [`u8`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::transport_discovery_data::OrganizationId;
+    ///
+    /// assert_eq!(OrganizationId::BluetoothSig, OrganizationId::from(0x01));
+    /// assert_eq!(OrganizationId::WifiAlliance, OrganizationId::from(0x02));
+    /// assert_eq!(OrganizationId::Other(0x00), OrganizationId::from(0x00));
+    /// ```
+    fn from(value: u8) -> Self {
+        match value {
+            ORGANIZATION_ID_BLUETOOTH_SIG => OrganizationId::BluetoothSig,
+            ORGANIZATION_ID_WIFI_ALLIANCE => OrganizationId::WifiAlliance,
+            _ => OrganizationId::Other(value),
+        }
+    }
+}
+
+impl From<OrganizationId> for u8 {
+    /// Create [`u8`] from [`OrganizationId`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::transport_discovery_data::OrganizationId;
+    ///
+    /// assert_eq!(0x01, u8::from(OrganizationId::BluetoothSig));
+    /// assert_eq!(0x02, u8::from(OrganizationId::WifiAlliance));
+    /// assert_eq!(0x10, u8::from(OrganizationId::Other(0x10)));
+    /// ```
+    fn from(value: OrganizationId) -> Self {
+        match value {
+            OrganizationId::BluetoothSig => ORGANIZATION_ID_BLUETOOTH_SIG,
+            OrganizationId::WifiAlliance => ORGANIZATION_ID_WIFI_ALLIANCE,
+            OrganizationId::Other(value) => value,
+        }
+    }
+}
+
+/// TDS Flags Role.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TdsRole {
+    /// Not Specified
+    NotSpecified,
+
+    /// Seeker Only
+    SeekerOnly,
+
+    /// Provider Only
+    ProviderOnly,
+
+    /// Both Seeker and Provider
+    SeekerAndProvider,
+}
+
+impl From<u8> for TdsRole {
+    /// Create [`TdsRole`] from TDS Flags.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::transport_discovery_data::TdsRole;
+    ///
+    /// assert_eq!(TdsRole::NotSpecified, TdsRole::from(0b00));
+    /// assert_eq!(TdsRole::SeekerOnly, TdsRole::from(0b01));
+    /// assert_eq!(TdsRole::ProviderOnly, TdsRole::from(0b10));
+    /// assert_eq!(TdsRole::SeekerAndProvider, TdsRole::from(0b1111));
+    /// ```
+    fn from(value: u8) -> Self {
+        match value & TDS_FLAGS_ROLE_MASK {
+            0b01 => TdsRole::SeekerOnly,
+            0b10 => TdsRole::ProviderOnly,
+            0b11 => TdsRole::SeekerAndProvider,
+            _ => TdsRole::NotSpecified,
+        }
+    }
+}
+
+/// Organization specific Transport Block.
+pub trait OrganizationTransportBlock: for<'a> TryFrom<&'a TransportBlock, Error = String> {
+    /// Organization ID handled by the implementation.
+    fn organization_id() -> u8;
+}
+
+/// Transport Block.
+#[derive(Debug, PartialEq, Clone)]
+pub struct TransportBlock {
+    /// Organization ID
+    pub organization_id: u8,
+
+    /// TDS Flags
+    pub tds_flags: u8,
+
+    /// Transport Data
+    pub transport_data: Vec<u8>,
+}
+
+impl TransportBlock {
+    /// Create [`TransportBlock`] from Parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::transport_discovery_data::TransportBlock;
+    ///
+    /// let result = TransportBlock::new(0x02, 0x09, &[0x01, 0x02]);
+    /// assert_eq!(0x02, result.organization_id);
+    /// assert_eq!(0x09, result.tds_flags);
+    /// assert_eq!(vec![0x01, 0x02], result.transport_data);
+    /// ```
+    pub fn new(organization_id: u8, tds_flags: u8, transport_data: &[u8]) -> Self {
+        Self {
+            organization_id,
+            tds_flags,
+            transport_data: transport_data.to_vec(),
+        }
+    }
+
+    /// Get Organization ID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::transport_discovery_data::{OrganizationId, TransportBlock};
+    ///
+    /// let result = TransportBlock::new(0x02, 0x09, &[0x01, 0x02]);
+    /// assert_eq!(OrganizationId::WifiAlliance, result.organization());
+    /// ```
+    pub fn organization(&self) -> OrganizationId {
+        OrganizationId::from(self.organization_id)
+    }
+
+    /// Get TDS Flags Role.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::transport_discovery_data::{TdsRole, TransportBlock};
+    ///
+    /// let result = TransportBlock::new(0x02, 0x09, &[0x01, 0x02]);
+    /// assert_eq!(TdsRole::SeekerOnly, result.role());
+    /// ```
+    pub fn role(&self) -> TdsRole {
+        TdsRole::from(self.tds_flags)
+    }
+
+    /// check TDS Flags Transport Data Incomplete.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::transport_discovery_data::TransportBlock;
+    ///
+    /// let result = TransportBlock::new(0x02, 0x0d, &[0x01, 0x02]);
+    /// assert!(result.is_transport_data_incomplete());
+    ///
+    /// let result = TransportBlock::new(0x02, 0x09, &[0x01, 0x02]);
+    /// assert!(!result.is_transport_data_incomplete());
+    /// ```
+    pub fn is_transport_data_incomplete(&self) -> bool {
+        self.tds_flags & TDS_FLAGS_TRANSPORT_DATA_INCOMPLETE != 0
+    }
+
+    /// Get TDS Flags Transport State.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::transport_discovery_data::{TransportBlock, TRANSPORT_STATE_ON};
+    ///
+    /// let result = TransportBlock::new(0x02, 0x09, &[0x01, 0x02]);
+    /// assert_eq!(TRANSPORT_STATE_ON, result.transport_state());
+    /// ```
+    pub fn transport_state(&self) -> u8 {
+        (self.tds_flags & TDS_FLAGS_TRANSPORT_STATE_MASK) >> 3
+    }
+
+    /// Decode Transport Data with organization specific [`OrganizationTransportBlock`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::transport_discovery_data::{
+    ///     TransportBlock, WifiAllianceTransportBlock, TRANSPORT_STATE_ON,
+    /// };
+    ///
+    /// let result = TransportBlock::new(0x02, 0x09, &[0x01, 0x02]);
+    /// let wifi_alliance = result.decode::<WifiAllianceTransportBlock>();
+    /// assert_eq!(Some(Ok(TRANSPORT_STATE_ON)), wifi_alliance.map(|f| f.map(|f| f.transport_state)));
+    ///
+    /// let result = TransportBlock::new(0x01, 0x09, &[0x01, 0x02]);
+    /// assert_eq!(None, result.decode::<WifiAllianceTransportBlock>());
+    /// ```
+    pub fn decode<T: OrganizationTransportBlock>(&self) -> Option<Result<T, String>> {
+        if self.organization_id == T::organization_id() {
+            Some(T::try_from(self))
+        } else {
+            None
+        }
+    }
+}
+
+/// Wi-Fi Alliance Transport Block.
+#[derive(Debug, PartialEq, Clone)]
+pub struct WifiAllianceTransportBlock {
+    /// Transport State
+    pub transport_state: u8,
+
+    /// Transport Data
+    pub transport_data: Vec<u8>,
+}
+
+impl TryFrom<&TransportBlock> for WifiAllianceTransportBlock {
+    type Error = String;
+    /// Create [`WifiAllianceTransportBlock`] from [`TransportBlock`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::transport_discovery_data::{
+    ///     TransportBlock, WifiAllianceTransportBlock, TRANSPORT_STATE_ON,
+    /// };
+    ///
+    /// let block = TransportBlock::new(0x02, 0x09, &[0x01, 0x02]);
+    /// let result = WifiAllianceTransportBlock::try_from(&block);
+    /// assert!(result.is_ok());
+    /// let wifi_alliance = result.unwrap();
+    /// assert_eq!(TRANSPORT_STATE_ON, wifi_alliance.transport_state);
+    /// assert_eq!(vec![0x01, 0x02], wifi_alliance.transport_data);
+    ///
+    /// let block = TransportBlock::new(0x01, 0x09, &[0x01, 0x02]);
+    /// let result = WifiAllianceTransportBlock::try_from(&block);
+    /// assert!(result.is_err());
+    /// ```
+    fn try_from(value: &TransportBlock) -> Result<Self, String> {
+        if value.organization_id != ORGANIZATION_ID_WIFI_ALLIANCE {
+            return Err(format!("Invalid organization id :{}", value.organization_id).to_string());
+        }
+        Ok(Self {
+            transport_state: (value.tds_flags >> 3) & 0b11,
+            transport_data: value.transport_data.clone(),
+        })
+    }
+}
+
+impl OrganizationTransportBlock for WifiAllianceTransportBlock {
+    /// return `0x02`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::transport_discovery_data::{
+    ///     OrganizationTransportBlock, WifiAllianceTransportBlock,
+    /// };
+    ///
+    /// assert_eq!(0x02, WifiAllianceTransportBlock::organization_id());
+    /// ```
+    fn organization_id() -> u8 {
+        ORGANIZATION_ID_WIFI_ALLIANCE
+    }
+}
+
+/// Transport Discovery Data.
+#[derive(Debug, PartialEq, Clone)]
+pub struct TransportDiscoveryData {
+    /// data length
+    pub length: u8,
+
+    /// Transport Blocks
+    pub transport_blocks: Vec<TransportBlock>,
+}
+
+impl TransportDiscoveryData {
+    /// Create [`TransportDiscoveryData`] from [`TransportBlock`] list.
+    ///
+    /// Blocks over 254 bytes do not fit in one data type and wrap `length`.
+    /// Use [`TransportDiscoveryData::try_new`] to reject them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::transport_discovery_data::{TransportBlock, TransportDiscoveryData};
+    ///
+    /// let transport_blocks = vec![
+    ///     TransportBlock::new(0x02, 0x09, &[0x01, 0x02]),
+    ///     TransportBlock::new(0x01, 0x01, &[]),
+    /// ];
+    /// let result = TransportDiscoveryData::new(&transport_blocks);
+    /// assert_eq!(9, result.length);
+    /// assert_eq!(transport_blocks, result.transport_blocks);
+    /// ```
+    pub fn new(transport_blocks: &[TransportBlock]) -> Self {
+        Self {
+            length: Self::data_length(transport_blocks) as u8,
+            transport_blocks: transport_blocks.to_vec(),
+        }
+    }
+
+    /// Create [`TransportDiscoveryData`] from [`TransportBlock`] list with size check.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::transport_discovery_data::{TransportBlock, TransportDiscoveryData};
+    ///
+    /// let transport_blocks = vec![TransportBlock::new(0x02, 0x09, &[0x01, 0x02])];
+    /// let result = TransportDiscoveryData::try_new(&transport_blocks);
+    /// assert_eq!(Ok(TransportDiscoveryData::new(&transport_blocks)), result);
+    ///
+    /// let transport_blocks = vec![TransportBlock::new(0x02, 0x09, &[0x00; 252])];
+    /// let result = TransportDiscoveryData::try_new(&transport_blocks);
+    /// assert_eq!(Err("Invalid transport blocks size :255".to_string()), result);
+    /// ```
+    pub fn try_new(transport_blocks: &[TransportBlock]) -> Result<Self, String> {
+        let length = Self::data_length(transport_blocks);
+        if length > u8::MAX as usize {
+            return Err(format!("Invalid transport blocks size :{}", length - 1).to_string());
+        }
+        Ok(Self::new(transport_blocks))
+    }
+
+    fn data_length(transport_blocks: &[TransportBlock]) -> usize {
+        1 + transport_blocks
+            .iter()
+            .map(|f| 3 + f.transport_data.len())
+            .sum::<usize>()
+    }
+
+    /// Get Wi-Fi Alliance Transport Blocks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::transport_discovery_data::{
+    ///     TransportBlock, TransportDiscoveryData, TRANSPORT_STATE_ON,
+    /// };
+    ///
+    /// let result = TransportDiscoveryData::new(&[
+    ///     TransportBlock::new(0x02, 0x09, &[0x01, 0x02]),
+    ///     TransportBlock::new(0x01, 0x01, &[]),
+    /// ]);
+    /// let wifi_alliance = result.wifi_alliance_transport_blocks();
+    /// assert_eq!(1, wifi_alliance.len());
+    /// assert_eq!(TRANSPORT_STATE_ON, wifi_alliance[0].transport_state);
+    /// ```
+    pub fn wifi_alliance_transport_blocks(&self) -> Vec<WifiAllianceTransportBlock> {
+        self.organization_transport_blocks()
+    }
+
+    /// Get organization specific Transport Blocks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::transport_discovery_data::{
+    ///     TransportBlock, TransportDiscoveryData, WifiAllianceTransportBlock, TRANSPORT_STATE_ON,
+    /// };
+    ///
+    /// let result = TransportDiscoveryData::new(&[
+    ///     TransportBlock::new(0x02, 0x09, &[0x01, 0x02]),
+    ///     TransportBlock::new(0x01, 0x01, &[]),
+    /// ]);
+    /// let wifi_alliance: Vec<WifiAllianceTransportBlock> = result.organization_transport_blocks();
+    /// assert_eq!(1, wifi_alliance.len());
+    /// assert_eq!(TRANSPORT_STATE_ON, wifi_alliance[0].transport_state);
+    /// ```
+    pub fn organization_transport_blocks<T: OrganizationTransportBlock>(&self) -> Vec<T> {
+        self.transport_blocks
+            .iter()
+            .filter_map(|f| f.decode::<T>())
+            .filter_map(|f| f.ok())
+            .collect()
+    }
+}
+
+impl TryFrom<&[u8]> for TransportDiscoveryData {
+    type Error = String;
+    /// Create [`TransportDiscoveryData`] from `u8` slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{transport_discovery_data::{TransportBlock, TransportDiscoveryData}, data_type::DataType};
+    ///
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(9);
+    /// data.push(TransportDiscoveryData::data_type());
+    /// data.append(&mut vec![0x02, 0x09, 0x02, 0x01, 0x02]);
+    /// data.append(&mut vec![0x01, 0x01, 0x00]);
+    ///
+    /// let result = TransportDiscoveryData::try_from(data.as_slice());
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(9, data_type.length);
+    /// assert_eq!(
+    ///     vec![
+    ///         TransportBlock::new(0x02, 0x09, &[0x01, 0x02]),
+    ///         TransportBlock::new(0x01, 0x01, &[]),
+    ///     ],
+    ///     data_type.transport_blocks
+    /// );
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = TransportDiscoveryData::try_from(data.as_slice());
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &[u8]) -> Result<Self, String> {
+        let len = value.len();
+        if len < 5 {
+            return Err(format!("Invalid data size :{}", len).to_string());
+        }
+        let length = value[0];
+        if len < 1 + length as usize {
+            return Err(format!("Invalid data size :{}", len).to_string());
+        }
+        let mut transport_blocks: Vec<TransportBlock> = Vec::new();
+        let mut index = 2;
+        while index < 1 + length as usize {
+            if index + 3 > 1 + length as usize {
+                return Err(format!("Invalid transport block :{}", index).to_string());
+            }
+            let transport_data_length = value[index + 2] as usize;
+            let end = index + 3 + transport_data_length;
+            if end > 1 + length as usize {
+                return Err(format!("Invalid transport block :{}", index).to_string());
+            }
+            transport_blocks.push(TransportBlock::new(
+                value[index],
+                value[index + 1],
+                &value[index + 3..end],
+            ));
+            index = end;
+        }
+        Ok(Self {
+            length,
+            transport_blocks,
+        })
+    }
+}
+
+impl TryFrom<&Vec<u8>> for TransportDiscoveryData {
+    type Error = String;
+    /// Create [`TransportDiscoveryData`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{transport_discovery_data::{TransportBlock, TransportDiscoveryData}, data_type::DataType};
+    ///
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(9);
+    /// data.push(TransportDiscoveryData::data_type());
+    /// data.append(&mut vec![0x02, 0x09, 0x02, 0x01, 0x02]);
+    /// data.append(&mut vec![0x01, 0x01, 0x00]);
+    ///
+    /// let result = TransportDiscoveryData::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(9, data_type.length);
+    /// assert_eq!(
+    ///     vec![
+    ///         TransportBlock::new(0x02, 0x09, &[0x01, 0x02]),
+    ///         TransportBlock::new(0x01, 0x01, &[]),
+    ///     ],
+    ///     data_type.transport_blocks
+    /// );
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = TransportDiscoveryData::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        Self::try_from(value.as_slice())
+    }
+}
+
+impl From<TransportDiscoveryData> for Vec<u8> {
+    /// Create [`Vec<u8>`] from [`TransportDiscoveryData`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{transport_discovery_data::{TransportBlock, TransportDiscoveryData}, data_type::DataType};
+    ///
+    /// let result1 = TransportDiscoveryData::new(&[
+    ///     TransportBlock::new(0x02, 0x09, &[0x01, 0x02]),
+    ///     TransportBlock::new(0x01, 0x01, &[]),
+    /// ]);
+    ///
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(9);
+    /// data.push(TransportDiscoveryData::data_type());
+    /// data.append(&mut vec![0x02, 0x09, 0x02, 0x01, 0x02]);
+    /// data.append(&mut vec![0x01, 0x01, 0x00]);
+    ///
+    /// let into_data: Vec<u8> = result1.into();
+    /// assert_eq!(data, into_data);
+    ///
+    /// let result2 = TransportDiscoveryData::try_from(&data);
+    /// assert!(result2.is_ok());
+    /// let data_type = result2.unwrap();
+    /// let into_data: Vec<u8> = data_type.into();
+    /// assert_eq!(data, into_data);
+    /// ```
+    fn from(value: TransportDiscoveryData) -> Self {
+        let mut data: Vec<u8> = Vec::new();
+        data.push(value.length);
+        data.push(TransportDiscoveryData::data_type());
+        for transport_block in value.transport_blocks {
+            data.push(transport_block.organization_id);
+            data.push(transport_block.tds_flags);
+            data.push(transport_block.transport_data.len() as u8);
+            data.append(&mut transport_block.transport_data.clone());
+        }
+        data
+    }
+}
+
+impl DataType for TransportDiscoveryData {
+    /// return `0x26`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{transport_discovery_data::TransportDiscoveryData, data_type::DataType};
+    ///
+    /// assert_eq!(0x26, TransportDiscoveryData::data_type());
+    /// ```
+    fn data_type() -> u8 {
+        0x26
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data_types::{data_type::DataType, transport_discovery_data::*};
+
+    #[test]
+    fn test_transport_block_new() {
+        let result = TransportBlock::new(0x02, 0x09, &[0x01, 0x02]);
+        assert_eq!(0x02, result.organization_id);
+        assert_eq!(0x09, result.tds_flags);
+        assert_eq!(vec![0x01, 0x02], result.transport_data);
+    }
+
+    #[test]
+    fn test_organization_id() {
+        assert_eq!(OrganizationId::BluetoothSig, OrganizationId::from(0x01));
+        assert_eq!(OrganizationId::WifiAlliance, OrganizationId::from(0x02));
+        assert_eq!(OrganizationId::Other(0x00), OrganizationId::from(0x00));
+        assert_eq!(OrganizationId::Other(0xff), OrganizationId::from(0xff));
+        for value in 0..=u8::MAX {
+            assert_eq!(value, u8::from(OrganizationId::from(value)));
+        }
+    }
+
+    #[test]
+    fn test_tds_role() {
+        assert_eq!(TdsRole::NotSpecified, TdsRole::from(0b0000_0000));
+        assert_eq!(TdsRole::SeekerOnly, TdsRole::from(0b0000_0001));
+        assert_eq!(TdsRole::ProviderOnly, TdsRole::from(0b0000_0010));
+        assert_eq!(TdsRole::SeekerAndProvider, TdsRole::from(0b0000_0011));
+        assert_eq!(TdsRole::NotSpecified, TdsRole::from(0b1111_1100));
+    }
+
+    #[test]
+    fn test_transport_block_flags() {
+        let result = TransportBlock::new(0x01, 0b0001_0110, &[]);
+        assert_eq!(OrganizationId::BluetoothSig, result.organization());
+        assert_eq!(TdsRole::ProviderOnly, result.role());
+        assert!(result.is_transport_data_incomplete());
+        assert_eq!(
+            TRANSPORT_STATE_TEMPORARILY_UNAVAILABLE,
+            result.transport_state()
+        );
+
+        let result = TransportBlock::new(0x02, 0b0000_1011, &[]);
+        assert_eq!(OrganizationId::WifiAlliance, result.organization());
+        assert_eq!(TdsRole::SeekerAndProvider, result.role());
+        assert!(!result.is_transport_data_incomplete());
+        assert_eq!(TRANSPORT_STATE_ON, result.transport_state());
+    }
+
+    #[test]
+    fn test_transport_block_decode() {
+        assert_eq!(0x02, WifiAllianceTransportBlock::organization_id());
+
+        let block = TransportBlock::new(0x02, 0x09, &[0x01, 0x02]);
+        assert_eq!(
+            Some(Ok(WifiAllianceTransportBlock {
+                transport_state: TRANSPORT_STATE_ON,
+                transport_data: vec![0x01, 0x02]
+            })),
+            block.decode::<WifiAllianceTransportBlock>()
+        );
+
+        let block = TransportBlock::new(0x01, 0x09, &[0x01, 0x02]);
+        assert_eq!(None, block.decode::<WifiAllianceTransportBlock>());
+    }
+
+    #[test]
+    fn test_wifi_alliance_transport_block_try_from() {
+        let block = TransportBlock::new(0x02, 0x09, &[0x01, 0x02]);
+        let result = WifiAllianceTransportBlock::try_from(&block);
+        assert_eq!(
+            Ok(WifiAllianceTransportBlock {
+                transport_state: TRANSPORT_STATE_ON,
+                transport_data: vec![0x01, 0x02]
+            }),
+            result
+        );
+
+        let block = TransportBlock::new(0x02, 0x10, &[]);
+        let result = WifiAllianceTransportBlock::try_from(&block).unwrap();
+        assert_eq!(
+            TRANSPORT_STATE_TEMPORARILY_UNAVAILABLE,
+            result.transport_state
+        );
+
+        let block = TransportBlock::new(0x02, 0x00, &[]);
+        let result = WifiAllianceTransportBlock::try_from(&block).unwrap();
+        assert_eq!(TRANSPORT_STATE_OFF, result.transport_state);
+
+        let block = TransportBlock::new(0x01, 0x09, &[0x01, 0x02]);
+        assert!(WifiAllianceTransportBlock::try_from(&block).is_err());
+    }
+
+    #[test]
+    fn test_new() {
+        let transport_blocks = vec![
+            TransportBlock::new(0x02, 0x09, &[0x01, 0x02]),
+            TransportBlock::new(0x01, 0x01, &[]),
+        ];
+        let result = TransportDiscoveryData::new(&transport_blocks);
+        assert_eq!(9, result.length);
+        assert_eq!(transport_blocks, result.transport_blocks);
+    }
+
+    #[test]
+    fn test_new_oversize() {
+        let transport_blocks = vec![TransportBlock::new(0x02, 0x09, &[0x00; 252])];
+        let result = TransportDiscoveryData::new(&transport_blocks);
+        assert_eq!(0, result.length);
+    }
+
+    #[test]
+    fn test_try_new() {
+        let transport_blocks = vec![TransportBlock::new(0x02, 0x09, &[0x00; 251])];
+        let result = TransportDiscoveryData::try_new(&transport_blocks);
+        assert_eq!(Ok(TransportDiscoveryData::new(&transport_blocks)), result);
+        assert_eq!(255, result.unwrap().length);
+
+        let transport_blocks = vec![
+            TransportBlock::new(0x02, 0x09, &[0x00; 200]),
+            TransportBlock::new(0x01, 0x01, &[0x00; 52]),
+        ];
+        let result = TransportDiscoveryData::try_new(&transport_blocks);
+        assert_eq!(
+            Err("Invalid transport blocks size :258".to_string()),
+            result
+        );
+    }
+
+    #[test]
+    fn test_wifi_alliance_transport_blocks() {
+        let result = TransportDiscoveryData::new(&[
+            TransportBlock::new(0x02, 0x09, &[0x01, 0x02]),
+            TransportBlock::new(0x01, 0x01, &[]),
+            TransportBlock::new(0x02, 0x00, &[]),
+        ]);
+        let wifi_alliance = result.wifi_alliance_transport_blocks();
+        assert_eq!(2, wifi_alliance.len());
+        assert_eq!(TRANSPORT_STATE_ON, wifi_alliance[0].transport_state);
+        assert_eq!(vec![0x01, 0x02], wifi_alliance[0].transport_data);
+        assert_eq!(TRANSPORT_STATE_OFF, wifi_alliance[1].transport_state);
+    }
+
+    #[test]
+    fn test_organization_transport_blocks() {
+        let result = TransportDiscoveryData::new(&[
+            TransportBlock::new(0x01, 0x01, &[]),
+            TransportBlock::new(0x02, 0x00, &[0x03]),
+        ]);
+        let wifi_alliance: Vec<WifiAllianceTransportBlock> = result.organization_transport_blocks();
+        assert_eq!(result.wifi_alliance_transport_blocks(), wifi_alliance);
+        assert_eq!(1, wifi_alliance.len());
+        assert_eq!(vec![0x03], wifi_alliance[0].transport_data);
+    }
+
+    #[test]
+    fn test_try_from() {
+        let data: Vec<u8> = vec![
+            9,
+            TransportDiscoveryData::data_type(),
+            0x02,
+            0x09,
+            0x02,
+            0x01,
+            0x02,
+            0x01,
+            0x01,
+            0x00,
+        ];
+        let result = TransportDiscoveryData::try_from(&data);
+        assert!(result.is_ok());
+        let data_type = result.unwrap();
+        assert_eq!(9, data_type.length);
+        assert_eq!(
+            vec![
+                TransportBlock::new(0x02, 0x09, &[0x01, 0x02]),
+                TransportBlock::new(0x01, 0x01, &[]),
+            ],
+            data_type.transport_blocks
+        );
+
+        let data: Vec<u8> = vec![
+            6,
+            TransportDiscoveryData::data_type(),
+            0x02,
+            0x09,
+            0x03,
+            0x01,
+            0x02,
+        ];
+        let result = TransportDiscoveryData::try_from(&data);
+        assert!(result.is_err());
+
+        let data: Vec<u8> = vec![
+            5,
+            TransportDiscoveryData::data_type(),
+            0x02,
+            0x09,
+            0x00,
+            0x01,
+        ];
+        let result = TransportDiscoveryData::try_from(&data);
+        assert!(result.is_err());
+
+        let data: Vec<u8> = vec![0u8; 4];
+        let result = TransportDiscoveryData::try_from(&data);
+        assert!(result.is_err());
+        assert_eq!(
+            format!("Invalid data size :{}", data.len()),
+            result.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_into() {
+        let result1 = TransportDiscoveryData::new(&[
+            TransportBlock::new(0x02, 0x09, &[0x01, 0x02]),
+            TransportBlock::new(0x01, 0x01, &[]),
+        ]);
+        let data: Vec<u8> = vec![
+            9,
+            TransportDiscoveryData::data_type(),
+            0x02,
+            0x09,
+            0x02,
+            0x01,
+            0x02,
+            0x01,
+            0x01,
+            0x00,
+        ];
+        let into_data: Vec<u8> = result1.into();
+        assert_eq!(data, into_data);
+
+        let result2 = TransportDiscoveryData::try_from(&data);
+        assert!(result2.is_ok());
+        let into_data: Vec<u8> = result2.unwrap().into();
+        assert_eq!(data, into_data);
+    }
+
+    #[test]
+    fn test_data_type() {
+        assert_eq!(0x26, TransportDiscoveryData::data_type());
+    }
+}
@@ -1,6 +1,11 @@
 //! Tx Power Level (Data Type Value: 0x0a) module.
 
-use crate::data_types::data_type::DataType;
+use std::fmt;
+
+use crate::data_types::{
+    ad_type::AdType,
+    data_type::{validate_length, DataType},
+};
 
 /// Tx Power Level.
 #[derive(Debug, PartialEq, Clone)]
@@ -8,7 +13,7 @@ pub struct TxPowerLevel {
     /// data length
     pub length: u8,
 
-    /// Tx Power Level
+    /// Tx Power Level (dBm)
     pub tx_power_level: i8,
 }
 
@@ -30,7 +35,7 @@ impl TxPowerLevel {
     /// assert_eq!(2, result.length);
     /// assert_eq!(tx_power_level, result.tx_power_level);
     /// ```
-    pub fn new(tx_power_level: i8) -> Self {
+    pub const fn new(tx_power_level: i8) -> Self {
         Self {
             length: 2,
             tx_power_level,
@@ -38,9 +43,9 @@ impl TxPowerLevel {
     }
 }
 
-impl TryFrom<&Vec<u8>> for TxPowerLevel {
+impl TryFrom<&[u8]> for TxPowerLevel {
     type Error = String;
-    /// Create [`TxPowerLevel`] from [`Vec<u8>`].
+    /// Create [`TxPowerLevel`] from `u8` slice.
     ///
     /// # Examples
     ///
@@ -54,7 +59,7 @@ impl TryFrom<&Vec<u8>> for TxPowerLevel {
     /// data.push(TxPowerLevel::data_type());
     /// data.push(tx_power_level as u8);
     ///
-    /// let result = TxPowerLevel::try_from(&data);
+    /// let result = TxPowerLevel::try_from(data.as_slice());
     /// assert!(result.is_ok());
     /// let data_type = result.unwrap();
     /// assert_eq!(length, data_type.length);
@@ -67,26 +72,26 @@ impl TryFrom<&Vec<u8>> for TxPowerLevel {
     /// data.push(TxPowerLevel::data_type());
     /// data.push(tx_power_level as u8);
     ///
-    /// let result = TxPowerLevel::try_from(&data);
+    /// let result = TxPowerLevel::try_from(data.as_slice());
     /// assert!(result.is_ok());
     /// let data_type = result.unwrap();
     /// assert_eq!(length, data_type.length);
     /// assert_eq!(tx_power_level, data_type.tx_power_level);
     ///
     /// let data: Vec<u8> = Vec::new();
-    /// let result = TxPowerLevel::try_from(&data);
+    /// let result = TxPowerLevel::try_from(data.as_slice());
     /// assert!(result.is_err());
     /// assert_eq!(
     ///     format!("Invalid data size :{}", data.len()),
     ///     result.unwrap_err()
     /// );
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+    fn try_from(value: &[u8]) -> Result<Self, String> {
         let len = value.len();
         if len < 3 {
             return Err(format!("Invalid data size :{}", len).to_string());
         }
-        let length = value[0];
+        let length = validate_length(value, 2, 2)?;
         Ok(Self {
             length,
             tx_power_level: value[2] as i8,
@@ -94,6 +99,54 @@ impl TryFrom<&Vec<u8>> for TxPowerLevel {
     }
 }
 
+impl TryFrom<&Vec<u8>> for TxPowerLevel {
+    type Error = String;
+    /// Create [`TxPowerLevel`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{tx_power_level::TxPowerLevel, data_type::DataType};
+    ///
+    /// let tx_power_level = -127;
+    /// let length = 2;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(TxPowerLevel::data_type());
+    /// data.push(tx_power_level as u8);
+    ///
+    /// let result = TxPowerLevel::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert_eq!(tx_power_level, data_type.tx_power_level);
+    ///
+    /// let tx_power_level = 127;
+    /// let length = 2;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(TxPowerLevel::data_type());
+    /// data.push(tx_power_level as u8);
+    ///
+    /// let result = TxPowerLevel::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert_eq!(tx_power_level, data_type.tx_power_level);
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = TxPowerLevel::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        Self::try_from(value.as_slice())
+    }
+}
+
 impl Into<Vec<u8>> for TxPowerLevel {
     /// Create [`Vec<u8>`] from [`TxPowerLevel`].
     ///
@@ -145,6 +198,23 @@ impl Into<Vec<u8>> for TxPowerLevel {
     }
 }
 
+impl fmt::Display for TxPowerLevel {
+    /// Format [`TxPowerLevel`] with `dBm` suffix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::tx_power_level::TxPowerLevel;
+    ///
+    /// assert_eq!("-127 dBm", TxPowerLevel::new(-127).to_string());
+    /// assert_eq!("0 dBm", TxPowerLevel::new(0).to_string());
+    /// assert_eq!("127 dBm", TxPowerLevel::new(127).to_string());
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} dBm", self.tx_power_level)
+    }
+}
+
 impl DataType for TxPowerLevel {
     /// return `0x0a`.
     ///
@@ -171,8 +241,9 @@ impl DataType for TxPowerLevel {
 /// assert!(is_tx_power_level(0x0a));
 /// assert!(!is_tx_power_level(0x00));
 /// ```
+#[deprecated(note = "use `AdType::of(data_type).matches::<TxPowerLevel>()` instead")]
 pub fn is_tx_power_level(data_type: u8) -> bool {
-    TxPowerLevel::data_type() == data_type
+    AdType::of(data_type).matches::<TxPowerLevel>()
 }
 
 #[cfg(test)]
@@ -192,6 +263,12 @@ mod tests {
         assert_eq!(tx_power_level, result.tx_power_level);
     }
 
+    #[test]
+    fn test_new_const() {
+        const TX_POWER_LEVEL: TxPowerLevel = TxPowerLevel::new(-4);
+        assert_eq!(TxPowerLevel::new(-4), TX_POWER_LEVEL);
+    }
+
     #[test]
     fn test_try_from() {
         let tx_power_level = -127;
@@ -267,12 +344,35 @@ mod tests {
         assert_eq!(data, into_data);
     }
 
+    #[test]
+    fn test_two_complement() {
+        let data: Vec<u8> = [2, TxPowerLevel::data_type(), 0x80].to_vec();
+        let result = TxPowerLevel::try_from(&data).unwrap();
+        assert_eq!(-128, result.tx_power_level);
+        let into_data: Vec<u8> = result.into();
+        assert_eq!(data, into_data);
+
+        let data: Vec<u8> = [2, TxPowerLevel::data_type(), 0xf4].to_vec();
+        let result = TxPowerLevel::try_from(&data).unwrap();
+        assert_eq!(-12, result.tx_power_level);
+        let into_data: Vec<u8> = result.into();
+        assert_eq!(data, into_data);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!("-127 dBm", TxPowerLevel::new(-127).to_string());
+        assert_eq!("0 dBm", TxPowerLevel::new(0).to_string());
+        assert_eq!("127 dBm", TxPowerLevel::new(127).to_string());
+    }
+
     #[test]
     fn test_data_type() {
         assert_eq!(0x0a, TxPowerLevel::data_type());
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_is_tx_power_level() {
         assert!(is_tx_power_level(0x0a));
         assert!(!is_tx_power_level(0x00));
@@ -1,6 +1,240 @@
 //! Uniform Resource Identifier (Data Type Value: 0x24) module.
 
-use crate::data_types::data_type::DataType;
+use crate::data_types::{
+    ad_type::AdType,
+    data_type::{validate_length, DataType},
+};
+
+/// URI scheme name supplement (Assigned Numbers 2.7).
+const URI_SCHEME_NAMES: [(char, &str); 182] = [
+    ('\u{0001}', ""),
+    ('\u{0002}', "aaa:"),
+    ('\u{0003}', "aaas:"),
+    ('\u{0004}', "about:"),
+    ('\u{0005}', "acap:"),
+    ('\u{0006}', "acct:"),
+    ('\u{0007}', "cap:"),
+    ('\u{0008}', "cid:"),
+    ('\u{0009}', "coap:"),
+    ('\u{000a}', "coaps:"),
+    ('\u{000b}', "crid:"),
+    ('\u{000c}', "data:"),
+    ('\u{000d}', "dav:"),
+    ('\u{000e}', "dict:"),
+    ('\u{000f}', "dns:"),
+    ('\u{0010}', "file:"),
+    ('\u{0011}', "ftp:"),
+    ('\u{0012}', "geo:"),
+    ('\u{0013}', "go:"),
+    ('\u{0014}', "gopher:"),
+    ('\u{0015}', "h323:"),
+    ('\u{0016}', "http:"),
+    ('\u{0017}', "https:"),
+    ('\u{0018}', "iax:"),
+    ('\u{0019}', "icap:"),
+    ('\u{001a}', "im:"),
+    ('\u{001b}', "imap:"),
+    ('\u{001c}', "info:"),
+    ('\u{001d}', "ipp:"),
+    ('\u{001e}', "ipps:"),
+    ('\u{001f}', "iris:"),
+    ('\u{0020}', "iris.beep:"),
+    ('\u{0021}', "iris.xpc:"),
+    ('\u{0022}', "iris.xpcs:"),
+    ('\u{0023}', "iris.lwz:"),
+    ('\u{0024}', "jabber:"),
+    ('\u{0025}', "ldap:"),
+    ('\u{0026}', "mailto:"),
+    ('\u{0027}', "mid:"),
+    ('\u{0028}', "msrp:"),
+    ('\u{0029}', "msrps:"),
+    ('\u{002a}', "mtqp:"),
+    ('\u{002b}', "mupdate:"),
+    ('\u{002c}', "news:"),
+    ('\u{002d}', "nfs:"),
+    ('\u{002e}', "ni:"),
+    ('\u{002f}', "nih:"),
+    ('\u{0030}', "nntp:"),
+    ('\u{0031}', "opaquelocktoken:"),
+    ('\u{0032}', "pop:"),
+    ('\u{0033}', "pres:"),
+    ('\u{0034}', "reload:"),
+    ('\u{0035}', "rtsp:"),
+    ('\u{0036}', "rtsps:"),
+    ('\u{0037}', "rtspu:"),
+    ('\u{0038}', "service:"),
+    ('\u{0039}', "session:"),
+    ('\u{003a}', "shttp:"),
+    ('\u{003b}', "sieve:"),
+    ('\u{003c}', "sip:"),
+    ('\u{003d}', "sips:"),
+    ('\u{003e}', "sms:"),
+    ('\u{003f}', "snmp:"),
+    ('\u{0040}', "soap.beep:"),
+    ('\u{0041}', "soap.beeps:"),
+    ('\u{0042}', "stun:"),
+    ('\u{0043}', "stuns:"),
+    ('\u{0044}', "tag:"),
+    ('\u{0045}', "tel:"),
+    ('\u{0046}', "telnet:"),
+    ('\u{0047}', "tftp:"),
+    ('\u{0048}', "thismessage:"),
+    ('\u{0049}', "tn3270:"),
+    ('\u{004a}', "tip:"),
+    ('\u{004b}', "turn:"),
+    ('\u{004c}', "turns:"),
+    ('\u{004d}', "tv:"),
+    ('\u{004e}', "urn:"),
+    ('\u{004f}', "vemmi:"),
+    ('\u{0050}', "ws:"),
+    ('\u{0051}', "wss:"),
+    ('\u{0052}', "xcon:"),
+    ('\u{0053}', "xcon-userid:"),
+    ('\u{0054}', "xmlrpc.beep:"),
+    ('\u{0055}', "xmlrpc.beeps:"),
+    ('\u{0056}', "xmpp:"),
+    ('\u{0057}', "z39.50r:"),
+    ('\u{0058}', "z39.50s:"),
+    ('\u{0059}', "acr:"),
+    ('\u{005a}', "adiumxtra:"),
+    ('\u{005b}', "afp:"),
+    ('\u{005c}', "afs:"),
+    ('\u{005d}', "aim:"),
+    ('\u{005e}', "apt:"),
+    ('\u{005f}', "attachment:"),
+    ('\u{0060}', "aw:"),
+    ('\u{0061}', "barion:"),
+    ('\u{0062}', "beshare:"),
+    ('\u{0063}', "bitcoin:"),
+    ('\u{0064}', "bolo:"),
+    ('\u{0065}', "callto:"),
+    ('\u{0066}', "chrome:"),
+    ('\u{0067}', "chrome-extension:"),
+    ('\u{0068}', "com-eventbrite-attendee:"),
+    ('\u{0069}', "content:"),
+    ('\u{006a}', "cvs:"),
+    ('\u{006b}', "dlna-playsingle:"),
+    ('\u{006c}', "dlna-playcontainer:"),
+    ('\u{006d}', "dtn:"),
+    ('\u{006e}', "dvb:"),
+    ('\u{006f}', "ed2k:"),
+    ('\u{0070}', "facetime:"),
+    ('\u{0071}', "feed:"),
+    ('\u{0072}', "feedready:"),
+    ('\u{0073}', "finger:"),
+    ('\u{0074}', "fish:"),
+    ('\u{0075}', "gg:"),
+    ('\u{0076}', "git:"),
+    ('\u{0077}', "gizmoproject:"),
+    ('\u{0078}', "gtalk:"),
+    ('\u{0079}', "ham:"),
+    ('\u{007a}', "hcp:"),
+    ('\u{007b}', "icon:"),
+    ('\u{007c}', "ipn:"),
+    ('\u{007d}', "irc:"),
+    ('\u{007e}', "irc6:"),
+    ('\u{007f}', "ircs:"),
+    ('\u{0080}', "itms:"),
+    ('\u{0081}', "jar:"),
+    ('\u{0082}', "jms:"),
+    ('\u{0083}', "keyparc:"),
+    ('\u{0084}', "lastfm:"),
+    ('\u{0085}', "ldaps:"),
+    ('\u{0086}', "magnet:"),
+    ('\u{0087}', "maps:"),
+    ('\u{0088}', "market:"),
+    ('\u{0089}', "message:"),
+    ('\u{008a}', "mms:"),
+    ('\u{008b}', "ms-help:"),
+    ('\u{008c}', "ms-settings-power:"),
+    ('\u{008d}', "msnim:"),
+    ('\u{008e}', "mumble:"),
+    ('\u{008f}', "mvn:"),
+    ('\u{0090}', "notes:"),
+    ('\u{0091}', "oid:"),
+    ('\u{0092}', "palm:"),
+    ('\u{0093}', "paparazzi:"),
+    ('\u{0094}', "pkcs11:"),
+    ('\u{0095}', "platform:"),
+    ('\u{0096}', "proxy:"),
+    ('\u{0097}', "psyc:"),
+    ('\u{0098}', "query:"),
+    ('\u{0099}', "res:"),
+    ('\u{009a}', "resource:"),
+    ('\u{009b}', "rmi:"),
+    ('\u{009c}', "rsync:"),
+    ('\u{009d}', "rtmp:"),
+    ('\u{009e}', "secondlife:"),
+    ('\u{009f}', "sftp:"),
+    ('\u{00a0}', "sgn:"),
+    ('\u{00a1}', "skype:"),
+    ('\u{00a2}', "smb:"),
+    ('\u{00a3}', "soldat:"),
+    ('\u{00a4}', "spotify:"),
+    ('\u{00a5}', "ssh:"),
+    ('\u{00a6}', "steam:"),
+    ('\u{00a7}', "svn:"),
+    ('\u{00a8}', "teamspeak:"),
+    ('\u{00a9}', "things:"),
+    ('\u{00aa}', "udp:"),
+    ('\u{00ab}', "unreal:"),
+    ('\u{00ac}', "ut2004:"),
+    ('\u{00ad}', "ventrilo:"),
+    ('\u{00ae}', "view-source:"),
+    ('\u{00af}', "webcal:"),
+    ('\u{00b0}', "wtai:"),
+    ('\u{00b1}', "wyciwyg:"),
+    ('\u{00b2}', "xfire:"),
+    ('\u{00b3}', "xri:"),
+    ('\u{00b4}', "ymsgr:"),
+    ('\u{00b5}', "example:"),
+    ('\u{00b6}', "ms-settings-cloudstorage:"),
+];
+
+/// Empty scheme name (whole URI follows the scheme code point).
+pub const URI_SCHEME_EMPTY: char = '\u{0001}';
+
+/// Get URI scheme name from scheme code point.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::data_types::uniform_resource_identifier::uri_scheme_name;
+///
+/// assert_eq!(Some(""), uri_scheme_name('\u{0001}'));
+/// assert_eq!(Some("http:"), uri_scheme_name('\u{0016}'));
+/// assert_eq!(Some("https:"), uri_scheme_name('\u{0017}'));
+/// assert_eq!(None, uri_scheme_name('\u{0000}'));
+/// assert_eq!(None, uri_scheme_name('\u{00ff}'));
+/// ```
+pub fn uri_scheme_name(scheme: char) -> Option<&'static str> {
+    URI_SCHEME_NAMES
+        .binary_search_by(|(code, _)| code.cmp(&scheme))
+        .ok()
+        .map(|index| URI_SCHEME_NAMES[index].1)
+}
+
+/// Get URI scheme code point from scheme name (including `:`).
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::data_types::uniform_resource_identifier::uri_scheme_code;
+///
+/// assert_eq!(Some('\u{0016}'), uri_scheme_code("http:"));
+/// assert_eq!(Some('\u{0017}'), uri_scheme_code("https:"));
+/// assert_eq!(None, uri_scheme_code("unknown:"));
+/// assert_eq!(None, uri_scheme_code(""));
+/// ```
+pub fn uri_scheme_code(name: &str) -> Option<char> {
+    if name.is_empty() {
+        return None;
+    }
+    URI_SCHEME_NAMES
+        .iter()
+        .find(|(_, scheme_name)| *scheme_name == name)
+        .map(|(code, _)| *code)
+}
 
 /// Uniform Resource Identifier.
 #[derive(Debug, PartialEq, Clone)]
@@ -40,17 +274,126 @@ impl UniformResourceIdentifier {
     /// assert_eq!(body, result.uniform_resource_identifier);
     /// ```
     pub fn new(uniform_resource_identifier: &String) -> Self {
+        let scheme = uniform_resource_identifier.chars().next().unwrap();
         Self {
             length: uniform_resource_identifier.as_bytes().len() as u8 + 1,
-            scheme: uniform_resource_identifier.chars().next().unwrap(),
-            uniform_resource_identifier: uniform_resource_identifier.split_at(1).1.to_string(),
+            scheme,
+            uniform_resource_identifier: uniform_resource_identifier
+                .split_at(scheme.len_utf8())
+                .1
+                .to_string(),
         }
     }
+
+    /// Create [`UniformResourceIdentifier`] from plain URI text.
+    ///
+    /// A registered scheme is compressed into its code point, an unregistered one is kept
+    /// in the body behind the empty scheme (`0x01`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::uniform_resource_identifier::UniformResourceIdentifier;
+    ///
+    /// let result = UniformResourceIdentifier::from_uri("https://www.bluetooth.com").unwrap();
+    /// assert_eq!('\u{0017}', result.scheme);
+    /// assert_eq!("//www.bluetooth.com", result.uniform_resource_identifier);
+    /// assert_eq!(21, result.length);
+    ///
+    /// let result = UniformResourceIdentifier::from_uri("x-custom:value").unwrap();
+    /// assert_eq!('\u{0001}', result.scheme);
+    /// assert_eq!("x-custom:value", result.uniform_resource_identifier);
+    ///
+    /// assert_eq!(
+    ///     Err("Invalid URI scheme :www.bluetooth.com".to_string()),
+    ///     UniformResourceIdentifier::from_uri("www.bluetooth.com")
+    /// );
+    /// ```
+    pub fn from_uri(uri: &str) -> Result<Self, String> {
+        let scheme_end = uri
+            .find(':')
+            .ok_or(format!("Invalid URI scheme :{}", uri).to_string())?;
+        let scheme_name = &uri[..scheme_end];
+        let mut chars = scheme_name.chars();
+        let valid = chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+            && chars.all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '-' || c == '.');
+        if !valid {
+            return Err(format!("Invalid URI scheme :{}", uri).to_string());
+        }
+        let (scheme, body) = match uri_scheme_code(&uri[..scheme_end + 1]) {
+            Some(scheme) => (scheme, &uri[scheme_end + 1..]),
+            None => (URI_SCHEME_EMPTY, uri),
+        };
+        let size = scheme.len_utf8() + body.len();
+        if size > u8::MAX as usize - 1 {
+            return Err(format!("Invalid URI size :{}", size).to_string());
+        }
+        Ok(Self {
+            length: size as u8 + 1,
+            scheme,
+            uniform_resource_identifier: body.to_string(),
+        })
+    }
+
+    /// Get URI scheme name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::uniform_resource_identifier::UniformResourceIdentifier;
+    ///
+    /// let result = UniformResourceIdentifier::from_uri("https://www.bluetooth.com").unwrap();
+    /// assert_eq!(Some("https:"), result.scheme());
+    ///
+    /// let result = UniformResourceIdentifier::from_uri("x-custom:value").unwrap();
+    /// assert_eq!(Some(""), result.scheme());
+    ///
+    /// let result = UniformResourceIdentifier::new(&"\u{00ff}value".to_string());
+    /// assert_eq!(None, result.scheme());
+    /// ```
+    pub fn scheme(&self) -> Option<&'static str> {
+        uri_scheme_name(self.scheme)
+    }
+
+    /// Get URI text after the scheme.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::uniform_resource_identifier::UniformResourceIdentifier;
+    ///
+    /// let result = UniformResourceIdentifier::from_uri("https://www.bluetooth.com").unwrap();
+    /// assert_eq!("//www.bluetooth.com", result.remainder());
+    /// ```
+    pub fn remainder(&self) -> &str {
+        &self.uniform_resource_identifier
+    }
+
+    /// Get full URI text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::uniform_resource_identifier::UniformResourceIdentifier;
+    ///
+    /// let result = UniformResourceIdentifier::from_uri("https://www.bluetooth.com").unwrap();
+    /// assert_eq!(Some("https://www.bluetooth.com".to_string()), result.uri());
+    ///
+    /// let result = UniformResourceIdentifier::from_uri("x-custom:value").unwrap();
+    /// assert_eq!(Some("x-custom:value".to_string()), result.uri());
+    ///
+    /// let result = UniformResourceIdentifier::new(&"\u{00ff}value".to_string());
+    /// assert_eq!(None, result.uri());
+    /// ```
+    pub fn uri(&self) -> Option<String> {
+        self.scheme()
+            .map(|scheme| scheme.to_string() + &self.uniform_resource_identifier)
+    }
 }
 
-impl TryFrom<&Vec<u8>> for UniformResourceIdentifier {
+impl TryFrom<&[u8]> for UniformResourceIdentifier {
     type Error = String;
-    /// Create [`UniformResourceIdentifier`] from [`Vec<u8>`].
+    /// Create [`UniformResourceIdentifier`] from `u8` slice.
     ///
     /// # Examples
     ///
@@ -66,7 +409,7 @@ impl TryFrom<&Vec<u8>> for UniformResourceIdentifier {
     /// data.push(UniformResourceIdentifier::data_type());
     /// data.append(&mut uri.to_string().into_bytes());
     ///
-    /// let result = UniformResourceIdentifier::try_from(&data);
+    /// let result = UniformResourceIdentifier::try_from(data.as_slice());
     /// assert!(result.is_ok());
     /// let data_type = result.unwrap();
     /// assert_eq!(length, data_type.length);
@@ -82,7 +425,7 @@ impl TryFrom<&Vec<u8>> for UniformResourceIdentifier {
     /// data.push(UniformResourceIdentifier::data_type());
     /// data.append(&mut uri.to_string().into_bytes());
     ///
-    /// let result = UniformResourceIdentifier::try_from(&data);
+    /// let result = UniformResourceIdentifier::try_from(data.as_slice());
     /// assert!(result.is_ok());
     /// let data_type = result.unwrap();
     /// assert_eq!(length, data_type.length);
@@ -90,29 +433,90 @@ impl TryFrom<&Vec<u8>> for UniformResourceIdentifier {
     /// assert_eq!(body, data_type.uniform_resource_identifier);
     ///
     /// let data: Vec<u8> = Vec::new();
-    /// let result = UniformResourceIdentifier::try_from(&data);
+    /// let result = UniformResourceIdentifier::try_from(data.as_slice());
     /// assert!(result.is_err());
     /// assert_eq!(
     ///     format!("Invalid data size :{}", data.len()),
     ///     result.unwrap_err()
     /// );
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+    fn try_from(value: &[u8]) -> Result<Self, String> {
         let len = value.len();
         if len < 4 {
             return Err(format!("Invalid data size :{}", len).to_string());
         }
-        let length = value[0];
+        let length = validate_length(value, 2, 255)?;
         let uniform_resource_identifier =
-            String::from_utf8(value[2..1 + usize::from(length)].to_vec()).unwrap();
+            match String::from_utf8(value[2..1 + usize::from(length)].to_vec()) {
+                Ok(uniform_resource_identifier) => uniform_resource_identifier,
+                Err(error) => return Err(format!("Invalid UTF-8 :{}", error).to_string()),
+            };
+        let scheme = uniform_resource_identifier.chars().next().unwrap();
         Ok(Self {
             length,
-            scheme: uniform_resource_identifier.chars().next().unwrap(),
-            uniform_resource_identifier: uniform_resource_identifier.split_at(1).1.to_string(),
+            scheme,
+            uniform_resource_identifier: uniform_resource_identifier
+                .split_at(scheme.len_utf8())
+                .1
+                .to_string(),
         })
     }
 }
 
+impl TryFrom<&Vec<u8>> for UniformResourceIdentifier {
+    type Error = String;
+    /// Create [`UniformResourceIdentifier`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{uniform_resource_identifier::UniformResourceIdentifier, data_type::DataType};
+    ///
+    /// let scheme = '\u{0016}';
+    /// let body = "uniform_resource_identifier";
+    /// let uri = scheme.to_string() + body;
+    /// let length = uri.as_bytes().len() as u8 + 1;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(UniformResourceIdentifier::data_type());
+    /// data.append(&mut uri.to_string().into_bytes());
+    ///
+    /// let result = UniformResourceIdentifier::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert_eq!(scheme, data_type.scheme);
+    /// assert_eq!(body, data_type.uniform_resource_identifier);
+    ///
+    /// let scheme = '\u{0001}';
+    /// let body = "empty:uniform_resource_identifier";
+    /// let uri = scheme.to_string() + body;
+    /// let length = uri.as_bytes().len() as u8 + 1;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(UniformResourceIdentifier::data_type());
+    /// data.append(&mut uri.to_string().into_bytes());
+    ///
+    /// let result = UniformResourceIdentifier::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert_eq!(scheme, data_type.scheme);
+    /// assert_eq!(body, data_type.uniform_resource_identifier);
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = UniformResourceIdentifier::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        Self::try_from(value.as_slice())
+    }
+}
+
 impl Into<Vec<u8>> for UniformResourceIdentifier {
     /// Create [`Vec<u8>`] from [`UniformResourceIdentifier`].
     ///
@@ -197,8 +601,9 @@ impl DataType for UniformResourceIdentifier {
 /// assert!(is_uniform_resource_identifier(0x24));
 /// assert!(!is_uniform_resource_identifier(0x00));
 /// ```
+#[deprecated(note = "use `AdType::of(data_type).matches::<UniformResourceIdentifier>()` instead")]
 pub fn is_uniform_resource_identifier(data_type: u8) -> bool {
-    UniformResourceIdentifier::data_type() == data_type
+    AdType::of(data_type).matches::<UniformResourceIdentifier>()
 }
 
 #[cfg(test)]
@@ -224,6 +629,108 @@ mod tests {
         assert_eq!(body, result.uniform_resource_identifier);
     }
 
+    #[test]
+    fn test_uri_scheme_name() {
+        for (i, (code, _)) in URI_SCHEME_NAMES.iter().enumerate() {
+            assert_eq!(i as u32 + 1, *code as u32);
+        }
+        assert_eq!(Some(""), uri_scheme_name('\u{0001}'));
+        assert_eq!(Some("aaa:"), uri_scheme_name('\u{0002}'));
+        assert_eq!(Some("http:"), uri_scheme_name('\u{0016}'));
+        assert_eq!(Some("https:"), uri_scheme_name('\u{0017}'));
+        assert_eq!(
+            Some("ms-settings-cloudstorage:"),
+            uri_scheme_name('\u{00b6}')
+        );
+        assert_eq!(None, uri_scheme_name('\u{0000}'));
+        assert_eq!(None, uri_scheme_name('\u{00b7}'));
+    }
+
+    #[test]
+    fn test_uri_scheme_code() {
+        assert_eq!(Some('\u{0016}'), uri_scheme_code("http:"));
+        assert_eq!(Some('\u{0017}'), uri_scheme_code("https:"));
+        assert_eq!(
+            Some('\u{00b6}'),
+            uri_scheme_code("ms-settings-cloudstorage:")
+        );
+        assert_eq!(None, uri_scheme_code("http"));
+        assert_eq!(None, uri_scheme_code(""));
+    }
+
+    #[test]
+    fn test_from_uri() {
+        let result = UniformResourceIdentifier::from_uri("https://www.bluetooth.com").unwrap();
+        assert_eq!(21, result.length);
+        assert_eq!('\u{0017}', result.scheme);
+        assert_eq!("//www.bluetooth.com", result.uniform_resource_identifier);
+        let data: Vec<u8> = result.clone().into();
+        assert_eq!(22, data.len());
+        assert_eq!(0x17, data[2]);
+        assert_eq!(Ok(result), UniformResourceIdentifier::try_from(&data));
+
+        let result = UniformResourceIdentifier::from_uri("example:value").unwrap();
+        assert_eq!('\u{00b5}', result.scheme);
+        let data: Vec<u8> = result.clone().into();
+        assert_eq!(vec![0xc2, 0xb5], data[2..4].to_vec());
+        assert_eq!(result.length as usize + 1, data.len());
+        assert_eq!(Ok(result), UniformResourceIdentifier::try_from(&data));
+
+        let result = UniformResourceIdentifier::from_uri("x-custom:value").unwrap();
+        assert_eq!(URI_SCHEME_EMPTY, result.scheme);
+        assert_eq!("x-custom:value", result.uniform_resource_identifier);
+
+        assert_eq!(
+            Err("Invalid URI scheme :value".to_string()),
+            UniformResourceIdentifier::from_uri("value")
+        );
+        assert_eq!(
+            Err("Invalid URI scheme ::value".to_string()),
+            UniformResourceIdentifier::from_uri(":value")
+        );
+        assert_eq!(
+            Err("Invalid URI scheme :1http:value".to_string()),
+            UniformResourceIdentifier::from_uri("1http:value")
+        );
+
+        let uri = "http:".to_string() + &"a".repeat(253);
+        assert!(UniformResourceIdentifier::from_uri(&uri).is_ok());
+        let uri = "http:".to_string() + &"a".repeat(254);
+        assert_eq!(
+            Err("Invalid URI size :255".to_string()),
+            UniformResourceIdentifier::from_uri(&uri)
+        );
+    }
+
+    #[test]
+    fn test_scheme_remainder() {
+        let result = UniformResourceIdentifier::from_uri("https://www.bluetooth.com").unwrap();
+        assert_eq!(Some("https:"), result.scheme());
+        assert_eq!("//www.bluetooth.com", result.remainder());
+        assert_eq!(Some("https://www.bluetooth.com".to_string()), result.uri());
+
+        let result = UniformResourceIdentifier::new(&"\u{00ff}value".to_string());
+        assert_eq!(None, result.scheme());
+        assert_eq!("value", result.remainder());
+        assert_eq!(None, result.uri());
+    }
+
+    #[test]
+    fn test_try_from_length_consistency() {
+        let data: Vec<u8> = vec![5, UniformResourceIdentifier::data_type(), 0x16, 0x2f];
+        let result = UniformResourceIdentifier::try_from(&data);
+        assert!(result.is_err());
+        assert_eq!(
+            format!("Invalid data size :{}", data.len()),
+            result.unwrap_err()
+        );
+
+        let data: Vec<u8> = vec![3, UniformResourceIdentifier::data_type(), 0x16, 0xff];
+        let result = UniformResourceIdentifier::try_from(&data);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().starts_with("Invalid UTF-8 :"));
+    }
+
     #[test]
     fn test_try_from() {
         let scheme = '\u{0016}';
@@ -317,6 +824,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_is_uniform_resource_identifier() {
         assert!(is_uniform_resource_identifier(0x24));
         assert!(!is_uniform_resource_identifier(0x00));
@@ -0,0 +1,162 @@
+//! ADI (AdvDataInfo extended header field) module.
+
+/// ADI field size in octets.
+pub const ADI_SIZE: usize = 2;
+
+/// Advertising Data ID max value (12 bits).
+pub const DID_MAX: u16 = 0x0fff;
+
+/// Advertising Set ID max value (4 bits).
+pub const SID_MAX: u8 = 0x0f;
+
+/// ADI (AdvDataInfo).
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Adi {
+    /// Advertising Data ID
+    pub did: u16,
+
+    /// Advertising Set ID
+    pub sid: u8,
+}
+
+impl Adi {
+    /// Create [`Adi`] from Parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::extended_header::adi::Adi;
+    ///
+    /// let result = Adi::new(0x123, 4);
+    /// assert_eq!(0x123, result.did);
+    /// assert_eq!(4, result.sid);
+    /// ```
+    pub const fn new(did: u16, sid: u8) -> Self {
+        Self { did, sid }
+    }
+
+    /// Check that every field fits in its bit width.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::extended_header::adi::Adi;
+    ///
+    /// assert_eq!(Ok(()), Adi::new(0x123, 4).validate());
+    /// assert_eq!(Err("Invalid DID :4096".to_string()), Adi::new(0x1000, 4).validate());
+    /// assert_eq!(Err("Invalid SID :16".to_string()), Adi::new(0x123, 16).validate());
+    /// ```
+    pub fn validate(&self) -> Result<(), String> {
+        if self.did > DID_MAX {
+            return Err(format!("Invalid DID :{}", self.did));
+        }
+        if self.sid > SID_MAX {
+            return Err(format!("Invalid SID :{}", self.sid));
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<&Vec<u8>> for Adi {
+    type Error = String;
+    /// Create [`Adi`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::extended_header::adi::Adi;
+    ///
+    /// let data: Vec<u8> = vec![0x23, 0x41];
+    /// let result = Adi::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let adi = result.unwrap();
+    /// assert_eq!(0x123, adi.did);
+    /// assert_eq!(4, adi.sid);
+    ///
+    /// let data: Vec<u8> = vec![0x23];
+    /// let result = Adi::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        let len = value.len();
+        if len < ADI_SIZE {
+            return Err(format!("Invalid data size :{}", len));
+        }
+        let adi = u16::from_le_bytes([value[0], value[1]]);
+        Ok(Self {
+            did: adi & DID_MAX,
+            sid: (adi >> 12) as u8,
+        })
+    }
+}
+
+impl From<Adi> for Vec<u8> {
+    /// Create [`Vec<u8>`] from [`Adi`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::extended_header::adi::Adi;
+    ///
+    /// let result1 = Adi::new(0x123, 4);
+    ///
+    /// let data: Vec<u8> = vec![0x23, 0x41];
+    /// let into_data: Vec<u8> = result1.into();
+    /// assert_eq!(data, into_data);
+    ///
+    /// let result2 = Adi::try_from(&data);
+    /// assert!(result2.is_ok());
+    /// let into_data: Vec<u8> = result2.unwrap().into();
+    /// assert_eq!(data, into_data);
+    /// ```
+    fn from(value: Adi) -> Self {
+        let adi = (value.did & DID_MAX) | ((value.sid as u16 & SID_MAX as u16) << 12);
+        adi.to_le_bytes().to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::extended_header::adi::*;
+
+    #[test]
+    fn test_new() {
+        let result = Adi::new(DID_MAX, SID_MAX);
+        assert_eq!(DID_MAX, result.did);
+        assert_eq!(SID_MAX, result.sid);
+        assert_eq!(Ok(()), result.validate());
+    }
+
+    #[test]
+    fn test_try_from() {
+        let data: Vec<u8> = vec![0xff, 0xff, 0x00];
+        let result = Adi::try_from(&data);
+        assert_eq!(Ok(Adi::new(DID_MAX, SID_MAX)), result);
+
+        let data: Vec<u8> = vec![0x00, 0x00];
+        assert_eq!(Ok(Adi::new(0, 0)), Adi::try_from(&data));
+
+        let data: Vec<u8> = Vec::new();
+        assert_eq!(
+            Err("Invalid data size :0".to_string()),
+            Adi::try_from(&data)
+        );
+    }
+
+    #[test]
+    fn test_into() {
+        let data: Vec<u8> = Adi::new(0x0fff, 0).into();
+        assert_eq!(vec![0xff, 0x0f], data);
+
+        let data: Vec<u8> = Adi::new(0, 0x0f).into();
+        assert_eq!(vec![0x00, 0xf0], data);
+
+        // out of range fields are masked
+        let data: Vec<u8> = Adi::new(0xffff, 0xff).into();
+        assert_eq!(vec![0xff, 0xff], data);
+    }
+}
@@ -0,0 +1,291 @@
+//! AuxPtr (extended header field) module.
+
+use crate::data_types::interval_units::IntervalUnits;
+
+/// AuxPtr field size in octets.
+pub const AUX_PTR_SIZE: usize = 3;
+
+/// Channel Index max value.
+pub const CHANNEL_INDEX_MAX: u8 = 39;
+
+/// AUX Offset max value (13 bits).
+pub const AUX_OFFSET_MAX: u16 = 0x1fff;
+
+/// AUX PHY: LE 1M
+pub const AUX_PHY_LE_1M: u8 = 0;
+
+/// AUX PHY: LE 2M
+pub const AUX_PHY_LE_2M: u8 = 1;
+
+/// AUX PHY: LE Coded
+pub const AUX_PHY_LE_CODED: u8 = 2;
+
+/// AuxPtr.
+#[derive(Debug, PartialEq, Clone)]
+pub struct AuxPtr {
+    /// Channel Index
+    pub channel_index: u8,
+
+    /// CA (`true`: 0 ppm to 50 ppm, `false`: 51 ppm to 500 ppm)
+    pub ca: bool,
+
+    /// Offset Units
+    pub offset_units: bool,
+
+    /// AUX Offset
+    pub aux_offset: u16,
+
+    /// AUX PHY
+    pub aux_phy: u8,
+}
+
+impl AuxPtr {
+    /// Create [`AuxPtr`] from Parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::extended_header::aux_ptr::{AuxPtr, AUX_PHY_LE_2M};
+    ///
+    /// let result = AuxPtr::new(12, true, false, 100, AUX_PHY_LE_2M);
+    /// assert_eq!(12, result.channel_index);
+    /// assert!(result.ca);
+    /// assert!(!result.offset_units);
+    /// assert_eq!(100, result.aux_offset);
+    /// assert_eq!(AUX_PHY_LE_2M, result.aux_phy);
+    /// ```
+    pub const fn new(
+        channel_index: u8,
+        ca: bool,
+        offset_units: bool,
+        aux_offset: u16,
+        aux_phy: u8,
+    ) -> Self {
+        Self {
+            channel_index,
+            ca,
+            offset_units,
+            aux_offset,
+            aux_phy,
+        }
+    }
+
+    /// Check that every field is in range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::extended_header::aux_ptr::AuxPtr;
+    ///
+    /// let result = AuxPtr::new(12, true, false, 100, 0);
+    /// assert_eq!(Ok(()), result.validate());
+    ///
+    /// let result = AuxPtr::new(40, true, false, 100, 0);
+    /// assert_eq!(Err("Invalid Channel Index :40".to_string()), result.validate());
+    /// ```
+    pub fn validate(&self) -> Result<(), String> {
+        if self.channel_index > CHANNEL_INDEX_MAX {
+            return Err(format!("Invalid Channel Index :{}", self.channel_index));
+        }
+        if self.aux_offset > AUX_OFFSET_MAX {
+            return Err(format!("Invalid AUX Offset :{}", self.aux_offset));
+        }
+        if self.aux_phy > AUX_PHY_LE_CODED {
+            return Err(format!("Invalid AUX PHY :{}", self.aux_phy));
+        }
+        Ok(())
+    }
+
+    /// Get Offset Units as [`IntervalUnits`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::interval_units::IntervalUnits, extended_header::aux_ptr::AuxPtr,
+    /// };
+    ///
+    /// let result = AuxPtr::new(12, true, true, 100, 0);
+    /// assert_eq!(IntervalUnits::Units300Us, result.offset_units());
+    /// ```
+    pub fn offset_units(&self) -> IntervalUnits {
+        IntervalUnits::from(self.offset_units)
+    }
+
+    /// Get AUX Offset in microseconds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::extended_header::aux_ptr::AuxPtr;
+    ///
+    /// let result = AuxPtr::new(12, true, false, 100, 0);
+    /// assert_eq!(3000, result.aux_offset_micros());
+    ///
+    /// let result = AuxPtr::new(12, true, true, 100, 0);
+    /// assert_eq!(30000, result.aux_offset_micros());
+    /// ```
+    pub fn aux_offset_micros(&self) -> u32 {
+        self.aux_offset as u32 * self.offset_units().micros()
+    }
+}
+
+impl TryFrom<&Vec<u8>> for AuxPtr {
+    type Error = String;
+    /// Create [`AuxPtr`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::extended_header::aux_ptr::{AuxPtr, AUX_PHY_LE_CODED};
+    ///
+    /// let data: Vec<u8> = vec![0x4c, 0x64, 0x40];
+    /// let result = AuxPtr::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let aux_ptr = result.unwrap();
+    /// assert_eq!(12, aux_ptr.channel_index);
+    /// assert!(aux_ptr.ca);
+    /// assert!(!aux_ptr.offset_units);
+    /// assert_eq!(100, aux_ptr.aux_offset);
+    /// assert_eq!(AUX_PHY_LE_CODED, aux_ptr.aux_phy);
+    ///
+    /// let data: Vec<u8> = vec![0x4c, 0x64];
+    /// let result = AuxPtr::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        let len = value.len();
+        if len < AUX_PTR_SIZE {
+            return Err(format!("Invalid data size :{}", len));
+        }
+        let offset_and_phy = u16::from_le_bytes([value[1], value[2]]);
+        Ok(Self {
+            channel_index: value[0] & 0b0011_1111,
+            ca: value[0] & 0b0100_0000 != 0,
+            offset_units: value[0] & 0b1000_0000 != 0,
+            aux_offset: offset_and_phy & AUX_OFFSET_MAX,
+            aux_phy: (offset_and_phy >> 13) as u8,
+        })
+    }
+}
+
+impl From<AuxPtr> for Vec<u8> {
+    /// Create [`Vec<u8>`] from [`AuxPtr`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::extended_header::aux_ptr::{AuxPtr, AUX_PHY_LE_CODED};
+    ///
+    /// let result1 = AuxPtr::new(12, true, false, 100, AUX_PHY_LE_CODED);
+    ///
+    /// let data: Vec<u8> = vec![0x4c, 0x64, 0x40];
+    /// let into_data: Vec<u8> = result1.into();
+    /// assert_eq!(data, into_data);
+    ///
+    /// let result2 = AuxPtr::try_from(&data);
+    /// assert!(result2.is_ok());
+    /// let into_data: Vec<u8> = result2.unwrap().into();
+    /// assert_eq!(data, into_data);
+    /// ```
+    fn from(value: AuxPtr) -> Self {
+        let mut first = value.channel_index & 0b0011_1111;
+        if value.ca {
+            first |= 0b0100_0000;
+        }
+        if value.offset_units {
+            first |= 0b1000_0000;
+        }
+        let offset_and_phy = (value.aux_offset & AUX_OFFSET_MAX) | ((value.aux_phy as u16) << 13);
+        let mut data = vec![first];
+        data.extend_from_slice(&offset_and_phy.to_le_bytes());
+        data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{data_types::interval_units::IntervalUnits, extended_header::aux_ptr::*};
+
+    #[test]
+    fn test_new() {
+        let result = AuxPtr::new(
+            CHANNEL_INDEX_MAX,
+            false,
+            true,
+            AUX_OFFSET_MAX,
+            AUX_PHY_LE_1M,
+        );
+        assert_eq!(CHANNEL_INDEX_MAX, result.channel_index);
+        assert!(!result.ca);
+        assert!(result.offset_units);
+        assert_eq!(AUX_OFFSET_MAX, result.aux_offset);
+        assert_eq!(AUX_PHY_LE_1M, result.aux_phy);
+        assert_eq!(Ok(()), result.validate());
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(
+            Err("Invalid AUX Offset :8192".to_string()),
+            AuxPtr::new(0, false, false, 0x2000, 0).validate()
+        );
+        assert_eq!(
+            Err("Invalid AUX PHY :3".to_string()),
+            AuxPtr::new(0, false, false, 0, 3).validate()
+        );
+    }
+
+    #[test]
+    fn test_offset() {
+        let result = AuxPtr::new(0, false, false, AUX_OFFSET_MAX, 0);
+        assert_eq!(IntervalUnits::Units30Us, result.offset_units());
+        assert_eq!(8191 * 30, result.aux_offset_micros());
+
+        let result = AuxPtr::new(0, false, true, AUX_OFFSET_MAX, 0);
+        assert_eq!(8191 * 300, result.aux_offset_micros());
+    }
+
+    #[test]
+    fn test_try_from() {
+        let data: Vec<u8> = vec![0xff, 0xff, 0xff, 0x00];
+        let result = AuxPtr::try_from(&data);
+        assert!(result.is_ok());
+        let aux_ptr = result.unwrap();
+        assert_eq!(0x3f, aux_ptr.channel_index);
+        assert!(aux_ptr.ca);
+        assert!(aux_ptr.offset_units);
+        assert_eq!(AUX_OFFSET_MAX, aux_ptr.aux_offset);
+        assert_eq!(7, aux_ptr.aux_phy);
+        assert!(aux_ptr.validate().is_err());
+
+        let into_data: Vec<u8> = aux_ptr.into();
+        assert_eq!(data[..AUX_PTR_SIZE].to_vec(), into_data);
+
+        let data: Vec<u8> = vec![0x01, 0x01, 0x20];
+        let aux_ptr = AuxPtr::try_from(&data).unwrap();
+        assert_eq!(1, aux_ptr.channel_index);
+        assert_eq!(1, aux_ptr.aux_offset);
+        assert_eq!(AUX_PHY_LE_2M, aux_ptr.aux_phy);
+
+        let data: Vec<u8> = Vec::new();
+        assert_eq!(
+            Err("Invalid data size :0".to_string()),
+            AuxPtr::try_from(&data)
+        );
+    }
+
+    #[test]
+    fn test_into() {
+        let data: Vec<u8> = AuxPtr::new(0, false, false, 0, 0).into();
+        assert_eq!(vec![0u8; AUX_PTR_SIZE], data);
+
+        // out of range fields are masked
+        let data: Vec<u8> = AuxPtr::new(0xff, false, false, 0xffff, 0).into();
+        assert_eq!(vec![0x3f, 0xff, 0x1f], data);
+    }
+}
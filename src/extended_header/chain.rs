@@ -0,0 +1,218 @@
+//! Extended advertising chain reassembly module.
+
+use crate::{data_types::lint::EXTENDED_ADVERTISING_DATA_MAX, extended_header::adi::Adi};
+
+/// Reassembler of AUX_ADV_IND / AUX_SCAN_RSP followed by AUX_CHAIN_IND PDUs.
+///
+/// If ADI changes mid-chain, the advertiser changed the data. The pending chain is discarded and
+/// a new chain starts from the PDU with the new ADI.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct ChainReassembler {
+    adi: Option<Adi>,
+
+    data: Vec<u8>,
+
+    in_progress: bool,
+
+    discarded: usize,
+}
+
+impl ChainReassembler {
+    /// Create empty [`ChainReassembler`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::extended_header::chain::ChainReassembler;
+    ///
+    /// let result = ChainReassembler::new();
+    /// assert!(!result.is_in_progress());
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// check chain has pending PDUs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::extended_header::{adi::Adi, chain::ChainReassembler};
+    ///
+    /// let mut reassembler = ChainReassembler::new();
+    /// reassembler.push(Some(&Adi::new(1, 0)), &[0x02, 0x01], true);
+    /// assert!(reassembler.is_in_progress());
+    /// ```
+    pub fn is_in_progress(&self) -> bool {
+        self.in_progress
+    }
+
+    /// Number of pending chains discarded because ADI changed mid-chain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::extended_header::{adi::Adi, chain::ChainReassembler};
+    ///
+    /// let mut reassembler = ChainReassembler::new();
+    /// reassembler.push(Some(&Adi::new(1, 0)), &[0x02, 0x01], true);
+    /// assert_eq!(0, reassembler.discarded());
+    ///
+    /// reassembler.push(Some(&Adi::new(2, 0)), &[0x02, 0x01, 0x06], false);
+    /// assert_eq!(1, reassembler.discarded());
+    /// ```
+    pub fn discarded(&self) -> usize {
+        self.discarded
+    }
+
+    /// Add AdvData of a PDU, and return reassembled data after the last PDU.
+    ///
+    /// `more` is `true` if the PDU has AuxPtr (more AUX_CHAIN_IND follow). If ADI differs from the
+    /// first PDU, the pending chain is discarded (counted by [`ChainReassembler::discarded`]) and
+    /// this PDU starts a new chain. Returns error and discards the chain if data exceeds 1650
+    /// octets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::extended_header::{adi::Adi, chain::ChainReassembler};
+    ///
+    /// let mut reassembler = ChainReassembler::new();
+    /// let adi = Adi::new(1, 0);
+    /// assert_eq!(Ok(None), reassembler.push(Some(&adi), &[0x02, 0x01], true));
+    /// assert_eq!(Ok(Some(vec![0x02, 0x01, 0x06])), reassembler.push(Some(&adi), &[0x06], false));
+    ///
+    /// assert_eq!(Ok(None), reassembler.push(Some(&adi), &[0x02, 0x01], true));
+    /// assert_eq!(
+    ///     Ok(Some(vec![0x02, 0x01, 0x06])),
+    ///     reassembler.push(Some(&Adi::new(2, 0)), &[0x02, 0x01, 0x06], false)
+    /// );
+    /// assert_eq!(1, reassembler.discarded());
+    /// assert!(!reassembler.is_in_progress());
+    /// ```
+    pub fn push(
+        &mut self,
+        adi: Option<&Adi>,
+        data: &[u8],
+        more: bool,
+    ) -> Result<Option<Vec<u8>>, String> {
+        if self.in_progress && self.adi.as_ref() != adi {
+            self.reset();
+            self.discarded += 1;
+        }
+        if !self.in_progress {
+            self.adi = adi.cloned();
+            self.in_progress = true;
+        }
+        if self.data.len() + data.len() > EXTENDED_ADVERTISING_DATA_MAX {
+            let len = self.data.len() + data.len();
+            self.reset();
+            return Err(format!("Invalid data size :{}", len));
+        }
+        self.data.extend_from_slice(data);
+        if more {
+            return Ok(None);
+        }
+        let data = std::mem::take(&mut self.data);
+        self.reset();
+        Ok(Some(data))
+    }
+
+    /// Discard pending PDUs (e.g. AUX_CHAIN_IND was not received).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::extended_header::{adi::Adi, chain::ChainReassembler};
+    ///
+    /// let mut reassembler = ChainReassembler::new();
+    /// reassembler.push(Some(&Adi::new(1, 0)), &[0x02, 0x01], true);
+    /// reassembler.reset();
+    /// assert!(!reassembler.is_in_progress());
+    /// ```
+    pub fn reset(&mut self) {
+        self.adi = None;
+        self.data.clear();
+        self.in_progress = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::extended_header::{adi::Adi, chain::ChainReassembler};
+
+    #[test]
+    fn test_push() {
+        let mut reassembler = ChainReassembler::new();
+        assert_eq!(Ok(Some(vec![])), reassembler.push(None, &[], false));
+        assert!(!reassembler.is_in_progress());
+
+        // without ADI
+        assert_eq!(Ok(None), reassembler.push(None, &[0x01], true));
+        assert_eq!(Ok(None), reassembler.push(None, &[0x02], true));
+        assert_eq!(
+            Ok(Some(vec![0x01, 0x02, 0x03])),
+            reassembler.push(None, &[0x03], false)
+        );
+
+        // ADI appears mid-chain
+        let adi = Adi::new(0x123, 1);
+        assert_eq!(Ok(None), reassembler.push(None, &[0x01], true));
+        assert_eq!(
+            Ok(Some(vec![0x02])),
+            reassembler.push(Some(&adi), &[0x02], false)
+        );
+        assert_eq!(1, reassembler.discarded());
+
+        // SID differs
+        assert_eq!(Ok(None), reassembler.push(Some(&adi), &[0x01], true));
+        assert_eq!(
+            Ok(None),
+            reassembler.push(Some(&Adi::new(0x123, 2)), &[0x02], true)
+        );
+        assert_eq!(2, reassembler.discarded());
+        assert_eq!(
+            Ok(Some(vec![0x02, 0x03])),
+            reassembler.push(Some(&Adi::new(0x123, 2)), &[0x03], false)
+        );
+        assert_eq!(2, reassembler.discarded());
+    }
+
+    #[test]
+    fn test_push_adi_changed() {
+        let mut reassembler = ChainReassembler::new();
+        let adi1 = Adi::new(0x123, 1);
+        let adi2 = Adi::new(0x124, 1);
+        let part = [0x02, 0x01];
+        let full = [0x02, 0x01, 0x06, 0x03, 0x09, 0x61, 0x62];
+        assert_eq!(Ok(None), reassembler.push(Some(&adi1), &part, true));
+        assert_eq!(
+            Ok(Some(full.to_vec())),
+            reassembler.push(Some(&adi2), &full, false)
+        );
+        assert_eq!(1, reassembler.discarded());
+        assert!(!reassembler.is_in_progress());
+    }
+
+    #[test]
+    fn test_push_oversize() {
+        let mut reassembler = ChainReassembler::new();
+        let adi = Adi::new(1, 1);
+        for _ in 0..6 {
+            assert_eq!(Ok(None), reassembler.push(Some(&adi), &[0u8; 250], true));
+        }
+        assert_eq!(
+            Ok(Some(vec![0u8; 1650])),
+            reassembler.push(Some(&adi), &[0u8; 150], false)
+        );
+
+        for _ in 0..6 {
+            assert_eq!(Ok(None), reassembler.push(Some(&adi), &[0u8; 250], true));
+        }
+        assert_eq!(
+            Err("Invalid data size :1651".to_string()),
+            reassembler.push(Some(&adi), &[0u8; 151], true)
+        );
+        assert!(!reassembler.is_in_progress());
+    }
+}
@@ -0,0 +1,498 @@
+//! SyncInfo (AUX_ADV_IND extended header field) module.
+
+use crate::data_types::{channel_map::ChannelMap, interval_units::IntervalUnits};
+
+/// SyncInfo field size in octets.
+pub const SYNC_INFO_SIZE: usize = 18;
+
+/// Sync Packet Offset max value (13 bits).
+pub const SYNC_PACKET_OFFSET_MAX: u16 = 0x1fff;
+
+/// ChM max value (37 bits).
+pub const CH_M_MAX: u64 = 0x1f_ffff_ffff;
+
+/// SCA max value (3 bits).
+pub const SCA_MAX: u8 = 0x07;
+
+/// CRCInit max value (24 bits).
+pub const CRC_INIT_MAX: u32 = 0x00ff_ffff;
+
+/// Offset added when Offset Adjust is set (µs).
+pub const OFFSET_ADJUST_MICROS: u32 = 2_457_600;
+
+/// SyncInfo.
+#[derive(Debug, PartialEq, Clone)]
+pub struct SyncInfo {
+    /// Sync Packet Offset
+    pub sync_packet_offset: u16,
+
+    /// Offset Units
+    pub offset_units: bool,
+
+    /// Offset Adjust
+    pub offset_adjust: bool,
+
+    /// RFU
+    pub rfu: bool,
+
+    /// Interval (1.25 ms units)
+    pub interval: u16,
+
+    /// ChM
+    pub ch_m: ChannelMap,
+
+    /// SCA
+    pub sca: u8,
+
+    /// AA
+    pub access_address: u32,
+
+    /// CRCInit
+    pub crc_init: u32,
+
+    /// Event Counter
+    pub event_counter: u16,
+}
+
+impl SyncInfo {
+    /// Create [`SyncInfo`] from Parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::channel_map::ChannelMap, extended_header::sync_info::SyncInfo,
+    /// };
+    ///
+    /// let result = SyncInfo::new(1, true, false, false, 2, ChannelMap::from(3), 4, 5, 6, 7);
+    /// assert_eq!(1, result.sync_packet_offset);
+    /// assert!(result.offset_units);
+    /// assert!(!result.offset_adjust);
+    /// assert!(!result.rfu);
+    /// assert_eq!(2, result.interval);
+    /// assert_eq!(ChannelMap::from(3), result.ch_m);
+    /// assert_eq!(4, result.sca);
+    /// assert_eq!(5, result.access_address);
+    /// assert_eq!(6, result.crc_init);
+    /// assert_eq!(7, result.event_counter);
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub const fn new(
+        sync_packet_offset: u16,
+        offset_units: bool,
+        offset_adjust: bool,
+        rfu: bool,
+        interval: u16,
+        ch_m: ChannelMap,
+        sca: u8,
+        access_address: u32,
+        crc_init: u32,
+        event_counter: u16,
+    ) -> Self {
+        Self {
+            sync_packet_offset,
+            offset_units,
+            offset_adjust,
+            rfu,
+            interval,
+            ch_m,
+            sca,
+            access_address,
+            crc_init,
+            event_counter,
+        }
+    }
+
+    /// Check that every field fits in its bit width.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::channel_map::ChannelMap, extended_header::sync_info::SyncInfo,
+    /// };
+    ///
+    /// let mut result = SyncInfo::new(1, true, false, false, 2, ChannelMap::from(3), 4, 5, 6, 7);
+    /// assert_eq!(Ok(()), result.validate());
+    ///
+    /// result.sca = 8;
+    /// assert_eq!(Err("Invalid SCA :8".to_string()), result.validate());
+    /// ```
+    pub fn validate(&self) -> Result<(), String> {
+        if self.sync_packet_offset > SYNC_PACKET_OFFSET_MAX {
+            return Err(format!(
+                "Invalid Sync Packet Offset :{}",
+                self.sync_packet_offset
+            ));
+        }
+        self.ch_m.validate()?;
+        if self.sca > SCA_MAX {
+            return Err(format!("Invalid SCA :{}", self.sca));
+        }
+        if self.crc_init > CRC_INIT_MAX {
+            return Err(format!("Invalid CRCInit :{:#x}", self.crc_init));
+        }
+        Ok(())
+    }
+
+    /// Get Offset Units as [`IntervalUnits`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::{interval_units::IntervalUnits, channel_map::ChannelMap},
+    ///     extended_header::sync_info::SyncInfo,
+    /// };
+    ///
+    /// let result = SyncInfo::new(1, false, false, false, 2, ChannelMap::from(3), 4, 5, 6, 7);
+    /// assert_eq!(IntervalUnits::Units30Us, result.offset_units());
+    /// ```
+    pub fn offset_units(&self) -> IntervalUnits {
+        IntervalUnits::from(self.offset_units)
+    }
+
+    /// Get Sync Packet Offset in microseconds, including Offset Adjust.
+    ///
+    /// Returns [`None`] if Sync Packet Offset is `0` (offset too large to represent).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::channel_map::ChannelMap, extended_header::sync_info::SyncInfo,
+    /// };
+    ///
+    /// let result = SyncInfo::new(100, true, false, false, 2, ChannelMap::from(3), 4, 5, 6, 7);
+    /// assert_eq!(Some(30_000), result.sync_packet_offset_micros());
+    ///
+    /// let result = SyncInfo::new(100, true, true, false, 2, ChannelMap::from(3), 4, 5, 6, 7);
+    /// assert_eq!(Some(2_487_600), result.sync_packet_offset_micros());
+    ///
+    /// let result = SyncInfo::new(0, false, false, false, 2, ChannelMap::from(3), 4, 5, 6, 7);
+    /// assert_eq!(None, result.sync_packet_offset_micros());
+    /// ```
+    pub fn sync_packet_offset_micros(&self) -> Option<u32> {
+        if self.sync_packet_offset == 0 {
+            return None;
+        }
+        let adjust = if self.offset_adjust {
+            OFFSET_ADJUST_MICROS
+        } else {
+            0
+        };
+        Some(self.sync_packet_offset as u32 * self.offset_units().micros() + adjust)
+    }
+
+    /// Get periodic advertising interval in microseconds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::channel_map::ChannelMap, extended_header::sync_info::SyncInfo,
+    /// };
+    ///
+    /// let result = SyncInfo::new(1, false, false, false, 80, ChannelMap::from(3), 4, 5, 6, 7);
+    /// assert_eq!(100_000, result.interval_micros());
+    /// ```
+    pub fn interval_micros(&self) -> u32 {
+        self.interval as u32 * 1250
+    }
+}
+
+impl TryFrom<&Vec<u8>> for SyncInfo {
+    type Error = String;
+    /// Create [`SyncInfo`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::channel_map::ChannelMap, extended_header::sync_info::SyncInfo,
+    /// };
+    ///
+    /// let data: Vec<u8> = vec![
+    ///     0x64, 0x60, // Sync Packet Offset 100, Offset Units 1, Offset Adjust 1
+    ///     0x50, 0x00, // Interval
+    ///     0xff, 0xff, 0xff, 0xff, 0xbf, // ChM, SCA 5
+    ///     0x78, 0x56, 0x34, 0x12, // AA
+    ///     0x55, 0x55, 0x55, // CRCInit
+    ///     0x01, 0x00, // Event Counter
+    /// ];
+    /// let result = SyncInfo::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let sync_info = result.unwrap();
+    /// assert_eq!(100, sync_info.sync_packet_offset);
+    /// assert!(sync_info.offset_units);
+    /// assert!(sync_info.offset_adjust);
+    /// assert!(!sync_info.rfu);
+    /// assert_eq!(80, sync_info.interval);
+    /// assert_eq!(ChannelMap::from(0x1f_ffff_ffff), sync_info.ch_m);
+    /// assert_eq!(5, sync_info.sca);
+    /// assert_eq!(0x12345678, sync_info.access_address);
+    /// assert_eq!(0x555555, sync_info.crc_init);
+    /// assert_eq!(1, sync_info.event_counter);
+    ///
+    /// let data: Vec<u8> = vec![0u8; 17];
+    /// let result = SyncInfo::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        let len = value.len();
+        if len < SYNC_INFO_SIZE {
+            return Err(format!("Invalid data size :{}", len));
+        }
+        let offset = u16::from_le_bytes([value[0], value[1]]);
+        let mut ch_m_bytes = [0u8; 8];
+        ch_m_bytes[..5].copy_from_slice(&value[4..9]);
+        let ch_m_and_sca = u64::from_le_bytes(ch_m_bytes);
+        Ok(Self {
+            sync_packet_offset: offset & SYNC_PACKET_OFFSET_MAX,
+            offset_units: offset & 0x2000 != 0,
+            offset_adjust: offset & 0x4000 != 0,
+            rfu: offset & 0x8000 != 0,
+            interval: u16::from_le_bytes([value[2], value[3]]),
+            ch_m: ChannelMap::from(ch_m_and_sca & CH_M_MAX),
+            sca: (ch_m_and_sca >> 37) as u8,
+            access_address: u32::from_le_bytes([value[9], value[10], value[11], value[12]]),
+            crc_init: u32::from_le_bytes([value[13], value[14], value[15], 0]),
+            event_counter: u16::from_le_bytes([value[16], value[17]]),
+        })
+    }
+}
+
+impl From<SyncInfo> for Vec<u8> {
+    /// Create [`Vec<u8>`] from [`SyncInfo`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::channel_map::ChannelMap, extended_header::sync_info::SyncInfo,
+    /// };
+    ///
+    /// let result1 = SyncInfo::new(
+    ///     100, true, true, false, 80, ChannelMap::from(0x1f_ffff_ffff), 5, 0x12345678, 0x555555, 1,
+    /// );
+    ///
+    /// let data: Vec<u8> = vec![
+    ///     0x64, 0x60, 0x50, 0x00, 0xff, 0xff, 0xff, 0xff, 0xbf, 0x78, 0x56, 0x34, 0x12, 0x55, 0x55,
+    ///     0x55, 0x01, 0x00,
+    /// ];
+    /// let into_data: Vec<u8> = result1.into();
+    /// assert_eq!(data, into_data);
+    ///
+    /// let result2 = SyncInfo::try_from(&data);
+    /// assert!(result2.is_ok());
+    /// let into_data: Vec<u8> = result2.unwrap().into();
+    /// assert_eq!(data, into_data);
+    /// ```
+    fn from(value: SyncInfo) -> Self {
+        let mut offset = value.sync_packet_offset & SYNC_PACKET_OFFSET_MAX;
+        if value.offset_units {
+            offset |= 0x2000;
+        }
+        if value.offset_adjust {
+            offset |= 0x4000;
+        }
+        if value.rfu {
+            offset |= 0x8000;
+        }
+        let ch_m_and_sca =
+            (u64::from(value.ch_m) & CH_M_MAX) | ((value.sca as u64 & SCA_MAX as u64) << 37);
+        let mut data: Vec<u8> = Vec::with_capacity(SYNC_INFO_SIZE);
+        data.extend_from_slice(&offset.to_le_bytes());
+        data.extend_from_slice(&value.interval.to_le_bytes());
+        data.extend_from_slice(&ch_m_and_sca.to_le_bytes()[..5]);
+        data.extend_from_slice(&value.access_address.to_le_bytes());
+        data.extend_from_slice(&value.crc_init.to_le_bytes()[..3]);
+        data.extend_from_slice(&value.event_counter.to_le_bytes());
+        data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data_types::{channel_map::ChannelMap, interval_units::IntervalUnits},
+        extended_header::sync_info::*,
+    };
+
+    #[test]
+    fn test_new() {
+        let result = SyncInfo::new(
+            SYNC_PACKET_OFFSET_MAX,
+            false,
+            true,
+            true,
+            u16::MAX,
+            ChannelMap::from(CH_M_MAX),
+            SCA_MAX,
+            u32::MAX,
+            CRC_INIT_MAX,
+            u16::MAX,
+        );
+        assert_eq!(SYNC_PACKET_OFFSET_MAX, result.sync_packet_offset);
+        assert!(!result.offset_units);
+        assert!(result.offset_adjust);
+        assert!(result.rfu);
+        assert_eq!(u16::MAX, result.interval);
+        assert_eq!(ChannelMap::from(CH_M_MAX), result.ch_m);
+        assert_eq!(SCA_MAX, result.sca);
+        assert_eq!(u32::MAX, result.access_address);
+        assert_eq!(CRC_INIT_MAX, result.crc_init);
+        assert_eq!(u16::MAX, result.event_counter);
+        assert_eq!(Ok(()), result.validate());
+    }
+
+    #[test]
+    fn test_validate() {
+        let result = SyncInfo::new(
+            0x2000,
+            false,
+            false,
+            false,
+            0,
+            ChannelMap::default(),
+            0,
+            0,
+            0,
+            0,
+        );
+        assert_eq!(
+            Err("Invalid Sync Packet Offset :8192".to_string()),
+            result.validate()
+        );
+        let result = SyncInfo::new(
+            0,
+            false,
+            false,
+            false,
+            0,
+            ChannelMap::from(CH_M_MAX + 1),
+            0,
+            0,
+            0,
+            0,
+        );
+        assert_eq!(
+            Err("Invalid ChM :0x2000000000".to_string()),
+            result.validate()
+        );
+        let result = SyncInfo::new(
+            0,
+            false,
+            false,
+            false,
+            0,
+            ChannelMap::default(),
+            0,
+            0,
+            CRC_INIT_MAX + 1,
+            0,
+        );
+        assert_eq!(
+            Err("Invalid CRCInit :0x1000000".to_string()),
+            result.validate()
+        );
+    }
+
+    #[test]
+    fn test_offset() {
+        let result = SyncInfo::new(
+            SYNC_PACKET_OFFSET_MAX,
+            false,
+            false,
+            false,
+            6,
+            ChannelMap::default(),
+            0,
+            0,
+            0,
+            0,
+        );
+        assert_eq!(IntervalUnits::Units30Us, result.offset_units());
+        assert_eq!(Some(8191 * 30), result.sync_packet_offset_micros());
+        assert_eq!(7500, result.interval_micros());
+
+        let result = SyncInfo::new(
+            SYNC_PACKET_OFFSET_MAX,
+            true,
+            true,
+            false,
+            6,
+            ChannelMap::default(),
+            0,
+            0,
+            0,
+            0,
+        );
+        assert_eq!(IntervalUnits::Units300Us, result.offset_units());
+        assert_eq!(
+            Some(8191 * 300 + OFFSET_ADJUST_MICROS),
+            result.sync_packet_offset_micros()
+        );
+    }
+
+    #[test]
+    fn test_try_from() {
+        let data: Vec<u8> = vec![
+            0xff, 0xff, 0x34, 0x12, 0x01, 0x02, 0x03, 0x04, 0xe5, 0xef, 0xbe, 0xad, 0xde, 0x01,
+            0x02, 0x03, 0x04, 0x05, 0xff,
+        ];
+        let result = SyncInfo::try_from(&data);
+        assert!(result.is_ok());
+        let sync_info = result.unwrap();
+        assert_eq!(SYNC_PACKET_OFFSET_MAX, sync_info.sync_packet_offset);
+        assert!(sync_info.offset_units);
+        assert!(sync_info.offset_adjust);
+        assert!(sync_info.rfu);
+        assert_eq!(0x1234, sync_info.interval);
+        assert_eq!(ChannelMap::from(0x05_0403_0201), sync_info.ch_m);
+        assert_eq!(7, sync_info.sca);
+        assert_eq!(0xdeadbeef, sync_info.access_address);
+        assert_eq!(0x030201, sync_info.crc_init);
+        assert_eq!(0x0504, sync_info.event_counter);
+
+        let into_data: Vec<u8> = sync_info.into();
+        assert_eq!(data[..SYNC_INFO_SIZE].to_vec(), into_data);
+
+        let data: Vec<u8> = Vec::new();
+        let result = SyncInfo::try_from(&data);
+        assert_eq!(Err("Invalid data size :0".to_string()), result);
+    }
+
+    #[test]
+    fn test_into() {
+        let result = SyncInfo::new(0, false, false, false, 0, ChannelMap::default(), 0, 0, 0, 0);
+        let data: Vec<u8> = result.into();
+        assert_eq!(vec![0u8; SYNC_INFO_SIZE], data);
+
+        // out of range fields are masked
+        let result = SyncInfo::new(
+            0xffff,
+            false,
+            false,
+            false,
+            0,
+            ChannelMap::from(u64::MAX),
+            0xff,
+            0,
+            u32::MAX,
+            0,
+        );
+        let data: Vec<u8> = result.into();
+        assert_eq!(
+            vec![
+                0xff, 0x1f, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0xff,
+                0xff, 0xff, 0x00, 0x00
+            ],
+            data
+        );
+    }
+}
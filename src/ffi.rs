@@ -0,0 +1,336 @@
+//! C FFI module.
+//!
+//! Ownership rules:
+//!
+//! - [`BleAdStructure::data`] borrows from the payload passed to [`ble_data_struct_parse`], so the
+//!   payload must outlive the returned array.
+//! - Arrays returned by [`ble_data_struct_parse`] must be released with
+//!   [`ble_data_struct_free_structures`].
+//! - Payloads returned by [`ble_data_struct_serialize`] must be released with
+//!   [`ble_data_struct_free_payload`].
+
+use std::{
+    ffi::{c_char, CString},
+    ptr, slice,
+};
+
+use crate::data_types::data_type_parser::DataTypeParseResults;
+
+/// One AD structure of advertising payload.
+#[repr(C)]
+#[derive(Debug)]
+pub struct BleAdStructure {
+    /// Data type (AD type)
+    pub data_type: u8,
+
+    /// Offset of this AD structure in source payload
+    pub offset: usize,
+
+    /// Data (without length and data type)
+    pub data: *const u8,
+
+    /// Data length
+    pub data_len: usize,
+
+    /// Parse error message (NUL terminated), or null if parsed successfully
+    pub error: *mut c_char,
+}
+
+/// Parse advertising payload into [`BleAdStructure`] array.
+///
+/// Parsing stops at a zero length byte (padding). A truncated last AD structure is returned with
+/// its available data and an error message.
+///
+/// Returns null and sets `out_len` to `0` if `payload` contains no AD structure.
+///
+/// # Safety
+///
+/// `payload` must be valid for reads of `payload_len` bytes (or null if `payload_len` is `0`),
+/// and `out_len` must be a valid pointer.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::{
+///     data_types::{data_type::DataType, tx_power_level::TxPowerLevel},
+///     ffi::{ble_data_struct_free_structures, ble_data_struct_parse},
+/// };
+///
+/// let data: Vec<u8> = TxPowerLevel::new(-4).into();
+/// let mut len = 0usize;
+/// unsafe {
+///     let structures = ble_data_struct_parse(data.as_ptr(), data.len(), &mut len);
+///     assert_eq!(1, len);
+///     assert_eq!(TxPowerLevel::data_type(), (*structures).data_type);
+///     assert_eq!(1, (*structures).data_len);
+///     assert_eq!(0xfc, *(*structures).data);
+///     assert!((*structures).error.is_null());
+///     ble_data_struct_free_structures(structures, len);
+/// }
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn ble_data_struct_parse(
+    payload: *const u8,
+    payload_len: usize,
+    out_len: *mut usize,
+) -> *mut BleAdStructure {
+    let value: &[u8] = if payload.is_null() || payload_len == 0 {
+        &[]
+    } else {
+        slice::from_raw_parts(payload, payload_len)
+    };
+    let results = DataTypeParseResults::from_payload_with_options(value, &Default::default());
+
+    let mut structures: Vec<BleAdStructure> = Vec::with_capacity(results.results.len());
+    for (index, result) in results.results.iter().enumerate() {
+        let offset = results.offsets[index];
+        let end = (offset + 1 + value[offset] as usize).min(value.len());
+        let data = value.get(offset + 2..end).unwrap_or_default();
+        structures.push(BleAdStructure {
+            data_type: value.get(offset + 1).copied().unwrap_or_default(),
+            offset,
+            data: data.as_ptr(),
+            data_len: data.len(),
+            error: result
+                .error()
+                .and_then(|error| CString::new(error.as_str()).ok())
+                .map_or(ptr::null_mut(), CString::into_raw),
+        });
+    }
+
+    *out_len = structures.len();
+    if structures.is_empty() {
+        return ptr::null_mut();
+    }
+    Box::into_raw(structures.into_boxed_slice()) as *mut BleAdStructure
+}
+
+/// Release [`BleAdStructure`] array returned by [`ble_data_struct_parse`].
+///
+/// # Safety
+///
+/// `structures` and `len` must be a pair returned by [`ble_data_struct_parse`] (null is ignored),
+/// and must not be used after this call.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::ffi::{ble_data_struct_free_structures, ble_data_struct_parse};
+///
+/// let data: Vec<u8> = vec![0x02, 0x0a];
+/// let mut len = 0usize;
+/// unsafe {
+///     let structures = ble_data_struct_parse(data.as_ptr(), data.len(), &mut len);
+///     assert_eq!(1, len);
+///     assert!(!(*structures).error.is_null());
+///     ble_data_struct_free_structures(structures, len);
+/// }
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn ble_data_struct_free_structures(
+    structures: *mut BleAdStructure,
+    len: usize,
+) {
+    if structures.is_null() {
+        return;
+    }
+    let structures = Box::from_raw(ptr::slice_from_raw_parts_mut(structures, len));
+    for structure in structures.iter() {
+        if !structure.error.is_null() {
+            drop(CString::from_raw(structure.error));
+        }
+    }
+}
+
+/// Create advertising payload from [`BleAdStructure`] array.
+///
+/// [`BleAdStructure::offset`] and [`BleAdStructure::error`] are ignored, so parsed structures can
+/// be serialized again.
+///
+/// Returns null and sets `out_len` to `0` if `len` is `0` or any data is longer than 254 bytes.
+///
+/// # Safety
+///
+/// `structures` must be valid for reads of `len` items (or null if `len` is `0`), each
+/// [`BleAdStructure::data`] must be valid for reads of [`BleAdStructure::data_len`] bytes, and
+/// `out_len` must be a valid pointer.
+///
+/// # Examples
+///
+/// ```
+/// use std::{ptr, slice};
+///
+/// use ble_data_struct::{
+///     data_types::{data_type::DataType, tx_power_level::TxPowerLevel},
+///     ffi::{ble_data_struct_free_payload, ble_data_struct_serialize, BleAdStructure},
+/// };
+///
+/// let data = [0xfcu8];
+/// let structure = BleAdStructure {
+///     data_type: TxPowerLevel::data_type(),
+///     offset: 0,
+///     data: data.as_ptr(),
+///     data_len: data.len(),
+///     error: ptr::null_mut(),
+/// };
+/// let mut len = 0usize;
+/// unsafe {
+///     let payload = ble_data_struct_serialize(&structure, 1, &mut len);
+///     let expected: Vec<u8> = TxPowerLevel::new(-4).into();
+///     assert_eq!(expected.as_slice(), slice::from_raw_parts(payload, len));
+///     ble_data_struct_free_payload(payload, len);
+/// }
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn ble_data_struct_serialize(
+    structures: *const BleAdStructure,
+    len: usize,
+    out_len: *mut usize,
+) -> *mut u8 {
+    *out_len = 0;
+    if structures.is_null() || len == 0 {
+        return ptr::null_mut();
+    }
+
+    let mut payload: Vec<u8> = Vec::new();
+    for structure in slice::from_raw_parts(structures, len) {
+        if structure.data_len > 254 {
+            return ptr::null_mut();
+        }
+        payload.push(1 + structure.data_len as u8);
+        payload.push(structure.data_type);
+        if structure.data_len > 0 {
+            payload.extend_from_slice(slice::from_raw_parts(structure.data, structure.data_len));
+        }
+    }
+
+    *out_len = payload.len();
+    Box::into_raw(payload.into_boxed_slice()) as *mut u8
+}
+
+/// Release payload returned by [`ble_data_struct_serialize`].
+///
+/// # Safety
+///
+/// `payload` and `len` must be a pair returned by [`ble_data_struct_serialize`] (null is ignored),
+/// and must not be used after this call.
+///
+/// # Examples
+///
+/// ```
+/// use std::ptr;
+///
+/// use ble_data_struct::ffi::{ble_data_struct_free_payload, ble_data_struct_serialize};
+///
+/// let mut len = 0usize;
+/// unsafe {
+///     let payload = ble_data_struct_serialize(ptr::null(), 0, &mut len);
+///     assert!(payload.is_null());
+///     assert_eq!(0, len);
+///     ble_data_struct_free_payload(payload, len);
+/// }
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn ble_data_struct_free_payload(payload: *mut u8, len: usize) {
+    if !payload.is_null() {
+        drop(Box::from_raw(ptr::slice_from_raw_parts_mut(payload, len)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{ffi::CStr, ptr, slice};
+
+    use crate::{
+        data_types::{
+            advertising_interval::AdvertisingInterval, complete_local_name::CompleteLocalName,
+            data_type::DataType,
+        },
+        ffi::{
+            ble_data_struct_free_payload, ble_data_struct_free_structures, ble_data_struct_parse,
+            ble_data_struct_serialize, BleAdStructure,
+        },
+    };
+
+    #[test]
+    fn test_parse() {
+        let mut data: Vec<u8> = AdvertisingInterval::new(1).into();
+        data.append(&mut CompleteLocalName::new(&"name".to_string()).into());
+        data.append(&mut vec![0x01, 0xfe]);
+        data.append(&mut vec![0x05, 0x09, 0x00]);
+        let mut len = 0usize;
+        unsafe {
+            let structures = ble_data_struct_parse(data.as_ptr(), data.len(), &mut len);
+            assert_eq!(4, len);
+            let structures_slice = slice::from_raw_parts(structures, len);
+
+            assert_eq!(
+                AdvertisingInterval::data_type(),
+                structures_slice[0].data_type
+            );
+            assert_eq!(0, structures_slice[0].offset);
+            assert!(structures_slice[0].error.is_null());
+
+            assert_eq!(
+                CompleteLocalName::data_type(),
+                structures_slice[1].data_type
+            );
+            assert_eq!(4, structures_slice[1].offset);
+            assert_eq!(
+                b"name",
+                slice::from_raw_parts(structures_slice[1].data, structures_slice[1].data_len)
+            );
+
+            assert_eq!(0xfe, structures_slice[2].data_type);
+            assert_eq!(0, structures_slice[2].data_len);
+            assert_eq!(
+                "Unknown data type :254",
+                CStr::from_ptr(structures_slice[2].error).to_str().unwrap()
+            );
+
+            assert_eq!(0x09, structures_slice[3].data_type);
+            assert_eq!(
+                [0x00u8],
+                slice::from_raw_parts(structures_slice[3].data, structures_slice[3].data_len)
+            );
+            assert_eq!(
+                "Invalid data size :3",
+                CStr::from_ptr(structures_slice[3].error).to_str().unwrap()
+            );
+            ble_data_struct_free_structures(structures, len);
+
+            let structures = ble_data_struct_parse(ptr::null(), 0, &mut len);
+            assert!(structures.is_null());
+            assert_eq!(0, len);
+            ble_data_struct_free_structures(structures, len);
+        }
+    }
+
+    #[test]
+    fn test_serialize() {
+        let mut data: Vec<u8> = AdvertisingInterval::new(1).into();
+        data.append(&mut CompleteLocalName::new(&"name".to_string()).into());
+        data.append(&mut vec![0x01, 0xfe]);
+        let mut len = 0usize;
+        let mut payload_len = 0usize;
+        unsafe {
+            let structures = ble_data_struct_parse(data.as_ptr(), data.len(), &mut len);
+            let payload = ble_data_struct_serialize(structures, len, &mut payload_len);
+            assert_eq!(data.as_slice(), slice::from_raw_parts(payload, payload_len));
+            ble_data_struct_free_payload(payload, payload_len);
+            ble_data_struct_free_structures(structures, len);
+
+            let long_data = [0u8; 255];
+            let structure = BleAdStructure {
+                data_type: 0xff,
+                offset: 0,
+                data: long_data.as_ptr(),
+                data_len: long_data.len(),
+                error: ptr::null_mut(),
+            };
+            let payload = ble_data_struct_serialize(&structure, 1, &mut payload_len);
+            assert!(payload.is_null());
+            assert_eq!(0, payload_len);
+        }
+    }
+}
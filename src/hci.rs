@@ -0,0 +1,571 @@
+//! HCI command parameter module.
+
+use crate::data_types::lint::{EXTENDED_ADVERTISING_DATA_MAX, LEGACY_ADVERTISING_DATA_MAX};
+
+/// HCI LE Set Advertising Data command opcode.
+pub const LE_SET_ADVERTISING_DATA: u16 = 0x2008;
+
+/// HCI LE Set Scan Response Data command opcode.
+pub const LE_SET_SCAN_RESPONSE_DATA: u16 = 0x2009;
+
+/// HCI LE Set Extended Advertising Data command opcode.
+pub const LE_SET_EXTENDED_ADVERTISING_DATA: u16 = 0x2037;
+
+/// HCI LE Set Extended Scan Response Data command opcode.
+pub const LE_SET_EXTENDED_SCAN_RESPONSE_DATA: u16 = 0x2038;
+
+/// HCI LE Set Advertising Data / LE Set Scan Response Data parameter size.
+pub const LE_SET_ADVERTISING_DATA_PARAMETER_SIZE: usize = 1 + LEGACY_ADVERTISING_DATA_MAX;
+
+/// Maximum Advertising_Data_Length of HCI LE Set Extended Advertising Data.
+pub const EXTENDED_ADVERTISING_FRAGMENT_MAX: usize = 251;
+
+/// Fragment_Preference: Controller may fragment all data.
+pub const FRAGMENT_PREFERENCE_MAY_FRAGMENT: u8 = 0x00;
+
+/// Fragment_Preference: Controller should not fragment or should minimize fragmentation.
+pub const FRAGMENT_PREFERENCE_SHOULD_NOT_FRAGMENT: u8 = 0x01;
+
+/// HCI LE Set Extended Advertising Data Operation.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Operation {
+    /// Intermediate fragment of fragmented data (0x00)
+    Intermediate,
+
+    /// First fragment of fragmented data (0x01)
+    First,
+
+    /// Last fragment of fragmented data (0x02)
+    Last,
+
+    /// Complete data (0x03)
+    Complete,
+
+    /// Unchanged data, just update the Advertising DID (0x04)
+    UnchangedData,
+}
+
+impl TryFrom<u8> for Operation {
+    type Error = String;
+    /// Create [`Operation`] from Operation value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::Operation;
+    ///
+    /// assert_eq!(Ok(Operation::Intermediate), Operation::try_from(0x00));
+    /// assert_eq!(Ok(Operation::First), Operation::try_from(0x01));
+    /// assert_eq!(Ok(Operation::Last), Operation::try_from(0x02));
+    /// assert_eq!(Ok(Operation::Complete), Operation::try_from(0x03));
+    /// assert_eq!(Ok(Operation::UnchangedData), Operation::try_from(0x04));
+    /// assert_eq!(Err("Invalid operation :5".to_string()), Operation::try_from(0x05));
+    /// ```
+    fn try_from(value: u8) -> Result<Self, String> {
+        match value {
+            0x00 => Ok(Operation::Intermediate),
+            0x01 => Ok(Operation::First),
+            0x02 => Ok(Operation::Last),
+            0x03 => Ok(Operation::Complete),
+            0x04 => Ok(Operation::UnchangedData),
+            _ => Err(format!("Invalid operation :{}", value)),
+        }
+    }
+}
+
+impl From<Operation> for u8 {
+    /// Create Operation value from [`Operation`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::Operation;
+    ///
+    /// assert_eq!(0x00, u8::from(Operation::Intermediate));
+    /// assert_eq!(0x03, u8::from(Operation::Complete));
+    /// ```
+    fn from(value: Operation) -> Self {
+        match value {
+            Operation::Intermediate => 0x00,
+            Operation::First => 0x01,
+            Operation::Last => 0x02,
+            Operation::Complete => 0x03,
+            Operation::UnchangedData => 0x04,
+        }
+    }
+}
+
+/// HCI LE Set Extended Advertising Data / LE Set Extended Scan Response Data parameters.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ExtendedAdvertisingDataFragment {
+    /// Advertising_Handle
+    pub advertising_handle: u8,
+
+    /// Operation
+    pub operation: Operation,
+
+    /// Fragment_Preference
+    pub fragment_preference: u8,
+
+    /// Advertising_Data
+    pub data: Vec<u8>,
+}
+
+impl TryFrom<&Vec<u8>> for ExtendedAdvertisingDataFragment {
+    type Error = String;
+    /// Create [`ExtendedAdvertisingDataFragment`] from command parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::{ExtendedAdvertisingDataFragment, Operation};
+    ///
+    /// let parameters: Vec<u8> = vec![0x01, 0x03, 0x01, 0x03, 0x02, 0x01, 0x06];
+    /// let result = ExtendedAdvertisingDataFragment::try_from(&parameters);
+    /// assert!(result.is_ok());
+    /// let fragment = result.unwrap();
+    /// assert_eq!(0x01, fragment.advertising_handle);
+    /// assert_eq!(Operation::Complete, fragment.operation);
+    /// assert_eq!(0x01, fragment.fragment_preference);
+    /// assert_eq!(vec![0x02, 0x01, 0x06], fragment.data);
+    ///
+    /// let parameters: Vec<u8> = vec![0x01, 0x03, 0x01, 0x04, 0x02, 0x01, 0x06];
+    /// let result = ExtendedAdvertisingDataFragment::try_from(&parameters);
+    /// assert_eq!(
+    ///     Err(format!("Invalid data size :{}", parameters.len())),
+    ///     result
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        let len = value.len();
+        if len < 4
+            || len != 4 + value[3] as usize
+            || value[3] as usize > EXTENDED_ADVERTISING_FRAGMENT_MAX
+        {
+            return Err(format!("Invalid data size :{}", len));
+        }
+        Ok(Self {
+            advertising_handle: value[0],
+            operation: Operation::try_from(value[1])?,
+            fragment_preference: value[2],
+            data: value[4..].to_vec(),
+        })
+    }
+}
+
+impl From<ExtendedAdvertisingDataFragment> for Vec<u8> {
+    /// Create command parameters from [`ExtendedAdvertisingDataFragment`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::{ExtendedAdvertisingDataFragment, Operation};
+    ///
+    /// let fragment = ExtendedAdvertisingDataFragment {
+    ///     advertising_handle: 0x01,
+    ///     operation: Operation::First,
+    ///     fragment_preference: 0x01,
+    ///     data: vec![0x02, 0x01, 0x06],
+    /// };
+    /// let parameters: Vec<u8> = fragment.into();
+    /// assert_eq!(vec![0x01, 0x01, 0x01, 0x03, 0x02, 0x01, 0x06], parameters);
+    /// ```
+    fn from(value: ExtendedAdvertisingDataFragment) -> Self {
+        let mut data = vec![
+            value.advertising_handle,
+            value.operation.into(),
+            value.fragment_preference,
+            value.data.len() as u8,
+        ];
+        data.extend_from_slice(&value.data);
+        data
+    }
+}
+
+/// Create HCI LE Set Advertising Data / LE Set Scan Response Data parameters from advertising payload.
+///
+/// First byte is significant data length, followed by the payload zero-padded to 31 bytes.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::{
+///     data_types::complete_local_name::CompleteLocalName,
+///     hci::le_set_advertising_data_parameters,
+/// };
+///
+/// let data: Vec<u8> = CompleteLocalName::new(&"name".to_string()).into();
+/// let result = le_set_advertising_data_parameters(&data);
+/// assert!(result.is_ok());
+/// let parameters = result.unwrap();
+/// assert_eq!(32, parameters.len());
+/// assert_eq!(6, parameters[0]);
+/// assert_eq!(data, parameters[1..7]);
+/// assert!(parameters[7..].iter().all(|byte| *byte == 0));
+///
+/// let data = vec![0u8; 32];
+/// let result = le_set_advertising_data_parameters(&data);
+/// assert_eq!(Err(format!("Invalid data size :{}", data.len())), result);
+/// ```
+pub fn le_set_advertising_data_parameters(
+    data: &[u8],
+) -> Result<[u8; LE_SET_ADVERTISING_DATA_PARAMETER_SIZE], String> {
+    let len = data.len();
+    if len > LEGACY_ADVERTISING_DATA_MAX {
+        return Err(format!("Invalid data size :{}", len));
+    }
+    let mut parameters = [0u8; LE_SET_ADVERTISING_DATA_PARAMETER_SIZE];
+    parameters[0] = len as u8;
+    parameters[1..1 + len].copy_from_slice(data);
+    Ok(parameters)
+}
+
+/// Get advertising payload from HCI LE Set Advertising Data / LE Set Scan Response Data parameters.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::hci::{
+///     advertising_data_from_parameters, le_set_advertising_data_parameters,
+/// };
+///
+/// let data: Vec<u8> = vec![0x02, 0x01, 0x06];
+/// let parameters = le_set_advertising_data_parameters(&data).unwrap();
+/// assert_eq!(Ok(data), advertising_data_from_parameters(&parameters));
+///
+/// let parameters = [0u8; 3];
+/// assert_eq!(
+///     Err(format!("Invalid data size :{}", parameters.len())),
+///     advertising_data_from_parameters(&parameters)
+/// );
+/// ```
+pub fn advertising_data_from_parameters(parameters: &[u8]) -> Result<Vec<u8>, String> {
+    let len = parameters.len();
+    if len != LE_SET_ADVERTISING_DATA_PARAMETER_SIZE {
+        return Err(format!("Invalid data size :{}", len));
+    }
+    let significant = parameters[0] as usize;
+    if significant > LEGACY_ADVERTISING_DATA_MAX {
+        return Err(format!("Invalid significant data length :{}", significant));
+    }
+    Ok(parameters[1..1 + significant].to_vec())
+}
+
+/// Split extended advertising payload into HCI LE Set Extended Advertising Data fragments.
+///
+/// Each fragment has at most `max_fragment_length` (1 to 251) bytes and
+/// [`FRAGMENT_PREFERENCE_SHOULD_NOT_FRAGMENT`]. Empty payload is one [`Operation::Complete`] fragment.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::hci::{fragment_extended_advertising_data, Operation};
+///
+/// let data = vec![0xffu8; 300];
+/// let result = fragment_extended_advertising_data(0x01, &data, 251);
+/// assert!(result.is_ok());
+/// let fragments = result.unwrap();
+/// assert_eq!(2, fragments.len());
+/// assert_eq!(Operation::First, fragments[0].operation);
+/// assert_eq!(251, fragments[0].data.len());
+/// assert_eq!(Operation::Last, fragments[1].operation);
+/// assert_eq!(49, fragments[1].data.len());
+///
+/// let fragments = fragment_extended_advertising_data(0x01, &data[..31], 251).unwrap();
+/// assert_eq!(1, fragments.len());
+/// assert_eq!(Operation::Complete, fragments[0].operation);
+///
+/// let result = fragment_extended_advertising_data(0x01, &data, 0);
+/// assert_eq!(Err("Invalid max fragment length :0".to_string()), result);
+/// ```
+pub fn fragment_extended_advertising_data(
+    advertising_handle: u8,
+    data: &[u8],
+    max_fragment_length: usize,
+) -> Result<Vec<ExtendedAdvertisingDataFragment>, String> {
+    if max_fragment_length == 0 || max_fragment_length > EXTENDED_ADVERTISING_FRAGMENT_MAX {
+        return Err(format!(
+            "Invalid max fragment length :{}",
+            max_fragment_length
+        ));
+    }
+    let len = data.len();
+    if len > EXTENDED_ADVERTISING_DATA_MAX {
+        return Err(format!("Invalid data size :{}", len));
+    }
+    let fragment = |operation: Operation, data: &[u8]| ExtendedAdvertisingDataFragment {
+        advertising_handle,
+        operation,
+        fragment_preference: FRAGMENT_PREFERENCE_SHOULD_NOT_FRAGMENT,
+        data: data.to_vec(),
+    };
+    if len <= max_fragment_length {
+        return Ok(vec![fragment(Operation::Complete, data)]);
+    }
+    let chunks: Vec<&[u8]> = data.chunks(max_fragment_length).collect();
+    let last = chunks.len() - 1;
+    Ok(chunks
+        .into_iter()
+        .enumerate()
+        .map(|(index, chunk)| {
+            let operation = match index {
+                0 => Operation::First,
+                index if index == last => Operation::Last,
+                _ => Operation::Intermediate,
+            };
+            fragment(operation, chunk)
+        })
+        .collect())
+}
+
+/// Reassemble extended advertising payload from HCI LE Set Extended Advertising Data fragments.
+///
+/// Fragments must be one [`Operation::Complete`], or [`Operation::First`], any
+/// [`Operation::Intermediate`] and [`Operation::Last`] for the same Advertising_Handle.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::hci::{
+///     fragment_extended_advertising_data, reassemble_extended_advertising_data,
+/// };
+///
+/// let data: Vec<u8> = (0..=255).collect();
+/// let fragments = fragment_extended_advertising_data(0x00, &data, 100).unwrap();
+/// assert_eq!(3, fragments.len());
+/// assert_eq!(Ok(data), reassemble_extended_advertising_data(&fragments));
+///
+/// assert_eq!(
+///     Err("Invalid operation sequence :Last at 0".to_string()),
+///     reassemble_extended_advertising_data(&fragments[2..])
+/// );
+/// ```
+pub fn reassemble_extended_advertising_data(
+    fragments: &[ExtendedAdvertisingDataFragment],
+) -> Result<Vec<u8>, String> {
+    let Some(first) = fragments.first() else {
+        return Err("No fragments".to_string());
+    };
+    let last = fragments.len() - 1;
+    let mut data: Vec<u8> = Vec::new();
+    for (index, fragment) in fragments.iter().enumerate() {
+        if fragment.advertising_handle != first.advertising_handle {
+            return Err(format!(
+                "Invalid advertising handle :{} at {}",
+                fragment.advertising_handle, index
+            ));
+        }
+        let valid = match fragment.operation {
+            Operation::Complete => last == 0,
+            Operation::First => index == 0 && last > 0,
+            Operation::Intermediate => index > 0 && index < last,
+            Operation::Last => index > 0 && index == last,
+            Operation::UnchangedData => false,
+        };
+        if !valid {
+            return Err(format!(
+                "Invalid operation sequence :{:?} at {}",
+                fragment.operation, index
+            ));
+        }
+        data.extend_from_slice(&fragment.data);
+    }
+    Ok(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        bluez::BlueZAdvertisement,
+        hci::{
+            advertising_data_from_parameters, fragment_extended_advertising_data,
+            le_set_advertising_data_parameters, reassemble_extended_advertising_data,
+            ExtendedAdvertisingDataFragment, Operation, LE_SET_ADVERTISING_DATA_PARAMETER_SIZE,
+        },
+    };
+
+    #[test]
+    fn test_le_set_advertising_data_parameters() {
+        let result = le_set_advertising_data_parameters(&[]);
+        assert_eq!(Ok([0u8; LE_SET_ADVERTISING_DATA_PARAMETER_SIZE]), result);
+
+        let advertisement = BlueZAdvertisement {
+            local_name: Some("a".repeat(29)),
+            ..Default::default()
+        };
+        let data: Vec<u8> = (&advertisement).into();
+        assert_eq!(31, data.len());
+        let parameters = le_set_advertising_data_parameters(&data).unwrap();
+        assert_eq!(31, parameters[0]);
+        assert_eq!(data, parameters[1..]);
+
+        let data = vec![0xffu8; 32];
+        assert_eq!(
+            Err("Invalid data size :32".to_string()),
+            le_set_advertising_data_parameters(&data)
+        );
+    }
+
+    #[test]
+    fn test_advertising_data_from_parameters() {
+        let mut parameters = [0u8; LE_SET_ADVERTISING_DATA_PARAMETER_SIZE];
+        assert_eq!(Ok(vec![]), advertising_data_from_parameters(&parameters));
+
+        parameters[0] = 2;
+        parameters[1] = 0x01;
+        parameters[2] = 0x02;
+        parameters[3] = 0x03;
+        assert_eq!(
+            Ok(vec![0x01, 0x02]),
+            advertising_data_from_parameters(&parameters)
+        );
+
+        parameters[0] = 32;
+        assert_eq!(
+            Err("Invalid significant data length :32".to_string()),
+            advertising_data_from_parameters(&parameters)
+        );
+
+        assert_eq!(
+            Err("Invalid data size :33".to_string()),
+            advertising_data_from_parameters(&[0u8; 33])
+        );
+    }
+
+    #[test]
+    fn test_operation() {
+        for value in 0x00..=0x04u8 {
+            assert_eq!(value, u8::from(Operation::try_from(value).unwrap()));
+        }
+        assert_eq!(
+            Err("Invalid operation :255".to_string()),
+            Operation::try_from(0xff)
+        );
+    }
+
+    #[test]
+    fn test_fragment_try_from() {
+        let parameters: Vec<u8> = vec![0x02, 0x00, 0x00, 0x00];
+        let fragment = ExtendedAdvertisingDataFragment::try_from(&parameters).unwrap();
+        assert_eq!(Operation::Intermediate, fragment.operation);
+        assert!(fragment.data.is_empty());
+        let into_data: Vec<u8> = fragment.into();
+        assert_eq!(parameters, into_data);
+
+        let mut parameters: Vec<u8> = vec![0x00, 0x02, 0x00, 252];
+        parameters.append(&mut vec![0u8; 252]);
+        assert_eq!(
+            Err("Invalid data size :256".to_string()),
+            ExtendedAdvertisingDataFragment::try_from(&parameters)
+        );
+
+        let parameters: Vec<u8> = vec![0x00, 0x05, 0x00, 0x00];
+        assert_eq!(
+            Err("Invalid operation :5".to_string()),
+            ExtendedAdvertisingDataFragment::try_from(&parameters)
+        );
+
+        let parameters: Vec<u8> = vec![0x00, 0x03, 0x00];
+        assert_eq!(
+            Err("Invalid data size :3".to_string()),
+            ExtendedAdvertisingDataFragment::try_from(&parameters)
+        );
+    }
+
+    #[test]
+    fn test_fragment_extended_advertising_data() {
+        let fragments = fragment_extended_advertising_data(0x03, &[], 251).unwrap();
+        assert_eq!(1, fragments.len());
+        assert_eq!(Operation::Complete, fragments[0].operation);
+        assert_eq!(0x03, fragments[0].advertising_handle);
+        assert!(fragments[0].data.is_empty());
+
+        let data: Vec<u8> = (0..1650).map(|value| value as u8).collect();
+        let fragments = fragment_extended_advertising_data(0x03, &data, 251).unwrap();
+        assert_eq!(7, fragments.len());
+        assert_eq!(
+            vec![
+                Operation::First,
+                Operation::Intermediate,
+                Operation::Intermediate,
+                Operation::Intermediate,
+                Operation::Intermediate,
+                Operation::Intermediate,
+                Operation::Last
+            ],
+            fragments
+                .iter()
+                .map(|fragment| fragment.operation)
+                .collect::<Vec<Operation>>()
+        );
+        assert!(fragments
+            .iter()
+            .all(|fragment| fragment.advertising_handle == 0x03
+                && fragment.fragment_preference == 0x01
+                && fragment.data.len() <= 251));
+        assert_eq!(
+            Ok(data.clone()),
+            reassemble_extended_advertising_data(&fragments)
+        );
+
+        let fragments = fragment_extended_advertising_data(0x03, &data[..2], 1).unwrap();
+        assert_eq!(
+            vec![Operation::First, Operation::Last],
+            fragments
+                .iter()
+                .map(|fragment| fragment.operation)
+                .collect::<Vec<Operation>>()
+        );
+
+        assert_eq!(
+            Err("Invalid max fragment length :252".to_string()),
+            fragment_extended_advertising_data(0x03, &data, 252)
+        );
+        assert_eq!(
+            Err("Invalid data size :1651".to_string()),
+            fragment_extended_advertising_data(0x03, &[0u8; 1651], 251)
+        );
+    }
+
+    #[test]
+    fn test_reassemble_extended_advertising_data() {
+        assert_eq!(
+            Err("No fragments".to_string()),
+            reassemble_extended_advertising_data(&[])
+        );
+
+        let data = vec![0x01u8, 0x02, 0x03];
+        let fragments = fragment_extended_advertising_data(0x00, &data, 1).unwrap();
+        assert_eq!(Ok(data), reassemble_extended_advertising_data(&fragments));
+
+        let mut wrong_handle = fragments.clone();
+        wrong_handle[1].advertising_handle = 0x01;
+        assert_eq!(
+            Err("Invalid advertising handle :1 at 1".to_string()),
+            reassemble_extended_advertising_data(&wrong_handle)
+        );
+
+        assert_eq!(
+            Err("Invalid operation sequence :Intermediate at 1".to_string()),
+            reassemble_extended_advertising_data(&fragments[..2])
+        );
+        assert_eq!(
+            Err("Invalid operation sequence :Intermediate at 0".to_string()),
+            reassemble_extended_advertising_data(&fragments[1..])
+        );
+
+        let mut unchanged = fragments[..1].to_vec();
+        unchanged[0].operation = Operation::UnchangedData;
+        assert_eq!(
+            Err("Invalid operation sequence :UnchangedData at 0".to_string()),
+            reassemble_extended_advertising_data(&unchanged)
+        );
+
+        let mut complete = fragments.clone();
+        complete[0].operation = Operation::Complete;
+        assert_eq!(
+            Err("Invalid operation sequence :Complete at 0".to_string()),
+            reassemble_extended_advertising_data(&complete)
+        );
+    }
+}
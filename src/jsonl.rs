@@ -0,0 +1,431 @@
+//! JSON-lines scan recording module.
+//!
+//! One JSON object per line, one line per received advertising payload.
+//!
+//! ```text
+//! {"version":1,"timestamp":1700000000000000,"address":"01:02:03:04:05:06","rssi":-60,"payload":"020106",...}
+//! ```
+//!
+//! | key | value |
+//! | --- | --- |
+//! | `version` | format version ([`JSONL_VERSION`]) |
+//! | `timestamp` | receive time (microseconds, caller defined epoch) |
+//! | `address` | advertiser address or `null` |
+//! | `rssi` | RSSI (dBm) or `null` |
+//! | `payload` | advertising payload (hex) |
+//! | `truncated` | `true` if the controller truncated the payload |
+//! | `local_name` | device name or `null` |
+//! | `tx_power` | Tx Power Level (dBm) or `null` |
+//! | `service_uuids` | Service UUIDs |
+//! | `manufacturer_data` | Manufacturer Specific Data (`"0x004c": hex`) |
+//! | `service_data` | Service Data (`uuid: hex`) |
+//! | `structures` | `offset`, `data_type`, `data` (hex) and `error` of each AD structure |
+//!
+//! Only `timestamp`, `address`, `rssi`, `payload` and `truncated` are read back, other keys are
+//! derived from `payload` when writing. Missing `truncated` is read as `false`.
+
+use std::io::{BufRead, Write};
+
+use serde_json::{json, Map, Value};
+
+use crate::{
+    bluez::BlueZAdvertisement,
+    data_types::data_type_parser::DataTypeParseResults,
+    replay::{parse_hex, to_hex, ScanRecord},
+};
+
+/// JSON-lines format version.
+pub const JSONL_VERSION: u64 = 1;
+
+impl ScanRecord {
+    /// Create JSON object with decoded structures.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::replay::ScanRecord;
+    ///
+    /// let result = ScanRecord::new(1, None, None, &[0x02, 0x0a, 0xfc]).to_json();
+    /// assert_eq!(1, result["timestamp"]);
+    /// assert_eq!("020afc", result["payload"]);
+    /// assert_eq!(-4, result["tx_power"]);
+    /// assert_eq!(0x0a, result["structures"][0]["data_type"]);
+    /// ```
+    pub fn to_json(&self) -> Value {
+        let payload = &self.payload;
+        let results = DataTypeParseResults::from_payload_with_options(payload, &Default::default());
+        let structures: Vec<Value> = results
+            .results
+            .iter()
+            .zip(results.offsets.iter())
+            .map(|(result, offset)| {
+                let end = (offset + 1 + payload[*offset] as usize).min(payload.len());
+                json!({
+                    "offset": offset,
+                    "data_type": payload.get(offset + 1),
+                    "data": to_hex(payload.get(offset + 2..end).unwrap_or_default()),
+                    "error": result.error(),
+                })
+            })
+            .collect();
+
+        let bluez = BlueZAdvertisement::from(&results);
+        let manufacturer_data: Map<String, Value> = bluez
+            .manufacturer_data
+            .iter()
+            .map(|(company_identifier, data)| {
+                (
+                    format!("0x{:04x}", company_identifier),
+                    Value::from(to_hex(data)),
+                )
+            })
+            .collect();
+        let service_data: Map<String, Value> = bluez
+            .service_data
+            .iter()
+            .map(|(uuid, data)| (uuid.to_string(), Value::from(to_hex(data))))
+            .collect();
+        let service_uuids: Vec<String> = bluez
+            .service_uuids
+            .iter()
+            .map(|uuid| uuid.to_string())
+            .collect();
+        json!({
+            "version": JSONL_VERSION,
+            "timestamp": self.timestamp,
+            "address": self.address,
+            "rssi": self.rssi,
+            "payload": to_hex(payload),
+            "truncated": self.truncated,
+            "local_name": bluez.local_name,
+            "tx_power": bluez.tx_power,
+            "service_uuids": service_uuids,
+            "manufacturer_data": manufacturer_data,
+            "service_data": service_data,
+            "structures": structures,
+        })
+    }
+}
+
+impl TryFrom<&Value> for ScanRecord {
+    type Error = String;
+    /// Create [`ScanRecord`] from JSON object.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::replay::ScanRecord;
+    /// use serde_json::json;
+    ///
+    /// let value = json!({"timestamp": 1, "address": null, "rssi": -60, "payload": "020106"});
+    /// let result = ScanRecord::try_from(&value);
+    /// assert_eq!(Ok(ScanRecord::new(1, None, Some(-60), &[0x02, 0x01, 0x06])), result);
+    ///
+    /// let value = json!({"version": 2, "timestamp": 1, "payload": ""});
+    /// let result = ScanRecord::try_from(&value);
+    /// assert_eq!(Err("Invalid version :2".to_string()), result);
+    /// ```
+    fn try_from(value: &Value) -> Result<Self, String> {
+        let object = value.as_object().ok_or("Invalid record :not an object")?;
+        let get = |key: &str| object.get(key).filter(|value| !value.is_null());
+        if let Some(version) = get("version") {
+            if version.as_u64() != Some(JSONL_VERSION) {
+                return Err(format!("Invalid version :{}", version));
+            }
+        }
+        let timestamp = match get("timestamp") {
+            Some(timestamp) => timestamp
+                .as_u64()
+                .ok_or(format!("Invalid timestamp :{}", timestamp))?,
+            None => return Err("Invalid record :no timestamp".to_string()),
+        };
+        let address = match get("address") {
+            Some(address) => Some(
+                address
+                    .as_str()
+                    .ok_or(format!("Invalid address :{}", address))?
+                    .to_string(),
+            ),
+            None => None,
+        };
+        let rssi = match get("rssi") {
+            Some(rssi) => Some(
+                rssi.as_i64()
+                    .and_then(|rssi| i8::try_from(rssi).ok())
+                    .ok_or(format!("Invalid rssi :{}", rssi))?,
+            ),
+            None => None,
+        };
+        let payload = match get("payload") {
+            Some(payload) => parse_hex(
+                payload
+                    .as_str()
+                    .ok_or(format!("Invalid payload :{}", payload))?,
+            )?,
+            None => return Err("Invalid record :no payload".to_string()),
+        };
+        let truncated = match get("truncated") {
+            Some(truncated) => truncated
+                .as_bool()
+                .ok_or(format!("Invalid truncated :{}", truncated))?,
+            None => false,
+        };
+        Ok(Self {
+            timestamp,
+            address,
+            rssi,
+            payload,
+            truncated,
+        })
+    }
+}
+
+/// JSON-lines writer of [`ScanRecord`].
+pub struct JsonLinesWriter<W> {
+    inner: W,
+}
+
+impl<W: Write> JsonLinesWriter<W> {
+    /// Create [`JsonLinesWriter`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::jsonl::JsonLinesWriter;
+    ///
+    /// let writer = JsonLinesWriter::new(Vec::new());
+    /// assert!(writer.into_inner().is_empty());
+    /// ```
+    pub fn new(inner: W) -> Self {
+        Self { inner }
+    }
+
+    /// Write one [`ScanRecord`] as one line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{jsonl::JsonLinesWriter, replay::ScanRecord};
+    ///
+    /// let mut writer = JsonLinesWriter::new(Vec::new());
+    /// assert!(writer.write(&ScanRecord::new(1, None, None, &[0x02, 0x01, 0x06])).is_ok());
+    /// assert!(writer.write(&ScanRecord::new(2, None, None, &[])).is_ok());
+    /// let text = String::from_utf8(writer.into_inner()).unwrap();
+    /// assert_eq!(2, text.lines().count());
+    /// assert!(text.ends_with('\n'));
+    /// ```
+    pub fn write(&mut self, record: &ScanRecord) -> Result<(), String> {
+        writeln!(self.inner, "{}", record.to_json()).map_err(|error| error.to_string())
+    }
+
+    /// Flush inner writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::jsonl::JsonLinesWriter;
+    ///
+    /// let mut writer = JsonLinesWriter::new(Vec::new());
+    /// assert!(writer.flush().is_ok());
+    /// ```
+    pub fn flush(&mut self) -> Result<(), String> {
+        self.inner.flush().map_err(|error| error.to_string())
+    }
+
+    /// Get inner writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::jsonl::JsonLinesWriter;
+    ///
+    /// let writer = JsonLinesWriter::new(vec![0x01u8]);
+    /// assert_eq!(vec![0x01u8], writer.into_inner());
+    /// ```
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+/// JSON-lines reader of [`ScanRecord`].
+///
+/// Blank lines are skipped, and errors are prefixed with the line number.
+pub struct JsonLinesReader<R> {
+    inner: R,
+    line: usize,
+}
+
+impl<R: BufRead> JsonLinesReader<R> {
+    /// Create [`JsonLinesReader`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{jsonl::JsonLinesReader, replay::ScanRecord};
+    ///
+    /// let text = "{\"timestamp\":1,\"payload\":\"020106\"}\n\n{\"timestamp\":2}\n";
+    /// let mut reader = JsonLinesReader::new(text.as_bytes());
+    /// assert_eq!(
+    ///     Some(Ok(ScanRecord::new(1, None, None, &[0x02, 0x01, 0x06]))),
+    ///     reader.next()
+    /// );
+    /// assert_eq!(
+    ///     Some(Err("line 3 :Invalid record :no payload".to_string())),
+    ///     reader.next()
+    /// );
+    /// assert_eq!(None, reader.next());
+    /// ```
+    pub fn new(inner: R) -> Self {
+        Self { inner, line: 0 }
+    }
+
+    /// Get inner reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::jsonl::JsonLinesReader;
+    ///
+    /// let reader = JsonLinesReader::new("".as_bytes());
+    /// assert!(reader.into_inner().is_empty());
+    /// ```
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: BufRead> Iterator for JsonLinesReader<R> {
+    type Item = Result<ScanRecord, String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut text = String::new();
+        loop {
+            text.clear();
+            self.line += 1;
+            match self.inner.read_line(&mut text) {
+                Ok(0) => return None,
+                Ok(_) if text.trim().is_empty() => continue,
+                Ok(_) => break,
+                Err(error) => return Some(Err(format!("line {} :{}", self.line, error))),
+            }
+        }
+        Some(
+            serde_json::from_str::<Value>(&text)
+                .map_err(|error| error.to_string())
+                .and_then(|value| ScanRecord::try_from(&value))
+                .map_err(|error| format!("line {} :{}", self.line, error)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::{
+        jsonl::{JsonLinesReader, JsonLinesWriter},
+        replay::ScanRecord,
+    };
+
+    #[test]
+    fn test_to_json() {
+        let record = ScanRecord::new(
+            1_700_000_000_000_000,
+            Some("01:02:03:04:05:06"),
+            Some(-60),
+            &[0x04, 0xff, 0x4c, 0x00, 0x01, 0x02, 0x0a],
+        );
+        assert_eq!(
+            json!({
+                "version": 1,
+                "timestamp": 1_700_000_000_000_000u64,
+                "address": "01:02:03:04:05:06",
+                "rssi": -60,
+                "payload": "04ff4c0001020a",
+                "truncated": false,
+                "local_name": null,
+                "tx_power": null,
+                "service_uuids": [],
+                "manufacturer_data": {"0x004c": "01"},
+                "service_data": {},
+                "structures": [
+                    {"offset": 0, "data_type": 0xff, "data": "4c0001", "error": null},
+                    {"offset": 5, "data_type": 0x0a, "data": "", "error": "Invalid data size :2"},
+                ],
+            }),
+            record.to_json()
+        );
+    }
+
+    #[test]
+    fn test_try_from() {
+        let value = json!({"timestamp": 0, "payload": ""});
+        assert_eq!(Ok(ScanRecord::default()), ScanRecord::try_from(&value));
+
+        let errors = [
+            (json!([]), "Invalid record :not an object"),
+            (json!({"version": "1"}), "Invalid version :\"1\""),
+            (json!({"payload": ""}), "Invalid record :no timestamp"),
+            (json!({"timestamp": -1}), "Invalid timestamp :-1"),
+            (json!({"timestamp": 0}), "Invalid record :no payload"),
+            (
+                json!({"timestamp": 0, "address": 1, "payload": ""}),
+                "Invalid address :1",
+            ),
+            (
+                json!({"timestamp": 0, "rssi": -129, "payload": ""}),
+                "Invalid rssi :-129",
+            ),
+            (json!({"timestamp": 0, "payload": 1}), "Invalid payload :1"),
+            (
+                json!({"timestamp": 0, "payload": "020"}),
+                "Invalid hex length :3",
+            ),
+            (json!({"timestamp": 0, "payload": "0g"}), "Invalid hex :0g"),
+            (
+                json!({"timestamp": 0, "payload": "", "truncated": 1}),
+                "Invalid truncated :1",
+            ),
+        ];
+        for (value, error) in errors {
+            assert_eq!(Err(error.to_string()), ScanRecord::try_from(&value));
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let records = vec![
+            ScanRecord::new(
+                1,
+                Some("01:02:03:04:05:06"),
+                Some(-127),
+                &[0x02, 0x01, 0x06],
+            ),
+            ScanRecord::new(2, None, Some(20), &[0x02, 0x0a]),
+            ScanRecord::new(u64::MAX, None, None, &[]),
+            ScanRecord {
+                truncated: true,
+                ..ScanRecord::new(3, None, None, &[0x02, 0x01])
+            },
+        ];
+        let mut writer = JsonLinesWriter::new(Vec::new());
+        for record in &records {
+            writer.write(record).unwrap();
+        }
+        writer.flush().unwrap();
+        let data = writer.into_inner();
+
+        let reader = JsonLinesReader::new(data.as_slice());
+        let result: Result<Vec<ScanRecord>, String> = reader.collect();
+        assert_eq!(Ok(records), result);
+    }
+
+    #[test]
+    fn test_reader_error() {
+        let text = "\n{\n";
+        let mut reader = JsonLinesReader::new(text.as_bytes());
+        let result = reader.next().unwrap();
+        assert!(result.unwrap_err().starts_with("line 2 :"));
+        assert_eq!(None, reader.next());
+    }
+}
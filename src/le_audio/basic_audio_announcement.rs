@@ -0,0 +1,520 @@
+//! Basic Audio Announcement (BASE) module.
+
+use crate::{
+    data_types::service_data_16bit_uuid::ServiceData16BitUUID,
+    le_audio::ltv::{read_ltvs, write_ltvs, Ltv},
+    uuid_from_u16,
+};
+
+/// Basic Audio Announcement Service UUID
+pub const BASIC_AUDIO_ANNOUNCEMENT_SERVICE_UUID: u16 = 0x1851;
+
+/// Coding Format: LC3
+pub const CODING_FORMAT_LC3: u8 = 0x06;
+
+/// Coding Format: Vendor Specific
+pub const CODING_FORMAT_VENDOR_SPECIFIC: u8 = 0xff;
+
+/// Codec ID.
+#[derive(Debug, PartialEq, Clone)]
+pub struct CodecId {
+    /// Coding Format
+    pub coding_format: u8,
+
+    /// Company ID
+    pub company_id: u16,
+
+    /// Vendor-specific codec ID
+    pub vendor_specific_codec_id: u16,
+}
+
+impl CodecId {
+    /// Create [`CodecId`] from Parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::le_audio::basic_audio_announcement::CodecId;
+    ///
+    /// let result = CodecId::new(0x06, 0x0000, 0x0000);
+    /// assert_eq!(0x06, result.coding_format);
+    /// assert_eq!(0x0000, result.company_id);
+    /// assert_eq!(0x0000, result.vendor_specific_codec_id);
+    /// ```
+    pub fn new(coding_format: u8, company_id: u16, vendor_specific_codec_id: u16) -> Self {
+        Self {
+            coding_format,
+            company_id,
+            vendor_specific_codec_id,
+        }
+    }
+}
+
+/// BIS in BASE Level 3.
+#[derive(Debug, PartialEq, Clone)]
+pub struct BaseBis {
+    /// BIS_index
+    pub bis_index: u8,
+
+    /// Codec_Specific_Configuration
+    pub codec_specific_configuration: Vec<Ltv>,
+}
+
+impl BaseBis {
+    /// Create [`BaseBis`] from Parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::le_audio::{basic_audio_announcement::BaseBis, ltv::Ltv};
+    ///
+    /// let codec_specific_configuration = vec![Ltv::new(0x03, &[0x01, 0x00, 0x00, 0x00])];
+    /// let result = BaseBis::new(1, &codec_specific_configuration);
+    /// assert_eq!(1, result.bis_index);
+    /// assert_eq!(codec_specific_configuration, result.codec_specific_configuration);
+    /// ```
+    pub fn new(bis_index: u8, codec_specific_configuration: &[Ltv]) -> Self {
+        Self {
+            bis_index,
+            codec_specific_configuration: codec_specific_configuration.to_vec(),
+        }
+    }
+}
+
+/// Subgroup in BASE Level 2.
+#[derive(Debug, PartialEq, Clone)]
+pub struct BaseSubgroup {
+    /// Codec_ID
+    pub codec_id: CodecId,
+
+    /// Codec_Specific_Configuration
+    pub codec_specific_configuration: Vec<Ltv>,
+
+    /// Metadata
+    pub metadata: Vec<Ltv>,
+
+    /// BIS list
+    pub bises: Vec<BaseBis>,
+}
+
+impl BaseSubgroup {
+    /// Create [`BaseSubgroup`] from Parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::le_audio::{
+    ///     basic_audio_announcement::{BaseBis, BaseSubgroup, CodecId},
+    ///     ltv::Ltv,
+    /// };
+    ///
+    /// let codec_specific_configuration = vec![Ltv::new(0x01, &[0x08])];
+    /// let metadata = vec![Ltv::new(0x02, &[0x04, 0x00])];
+    /// let bises = vec![BaseBis::new(1, &[])];
+    /// let result = BaseSubgroup::new(
+    ///     CodecId::new(0x06, 0x0000, 0x0000),
+    ///     &codec_specific_configuration,
+    ///     &metadata,
+    ///     &bises,
+    /// );
+    /// assert_eq!(CodecId::new(0x06, 0x0000, 0x0000), result.codec_id);
+    /// assert_eq!(codec_specific_configuration, result.codec_specific_configuration);
+    /// assert_eq!(metadata, result.metadata);
+    /// assert_eq!(bises, result.bises);
+    /// ```
+    pub fn new(
+        codec_id: CodecId,
+        codec_specific_configuration: &[Ltv],
+        metadata: &[Ltv],
+        bises: &[BaseBis],
+    ) -> Self {
+        Self {
+            codec_id,
+            codec_specific_configuration: codec_specific_configuration.to_vec(),
+            metadata: metadata.to_vec(),
+            bises: bises.to_vec(),
+        }
+    }
+
+    /// BIS_index list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::le_audio::basic_audio_announcement::{BaseBis, BaseSubgroup, CodecId};
+    ///
+    /// let bises = vec![BaseBis::new(1, &[]), BaseBis::new(2, &[])];
+    /// let result = BaseSubgroup::new(CodecId::new(0x06, 0x0000, 0x0000), &[], &[], &bises);
+    /// assert_eq!(vec![1, 2], result.bis_indexes());
+    /// ```
+    pub fn bis_indexes(&self) -> Vec<u8> {
+        self.bises.iter().map(|bis| bis.bis_index).collect()
+    }
+}
+
+/// Basic Audio Announcement (BASE).
+#[derive(Debug, PartialEq, Clone)]
+pub struct BasicAudioAnnouncement {
+    /// Presentation_Delay (microseconds, 24 bit)
+    pub presentation_delay: u32,
+
+    /// Subgroup list
+    pub subgroups: Vec<BaseSubgroup>,
+}
+
+impl BasicAudioAnnouncement {
+    /// Create [`BasicAudioAnnouncement`] from Parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::le_audio::basic_audio_announcement::{
+    ///     BaseBis, BaseSubgroup, BasicAudioAnnouncement, CodecId,
+    /// };
+    ///
+    /// let subgroups = vec![BaseSubgroup::new(
+    ///     CodecId::new(0x06, 0x0000, 0x0000),
+    ///     &[],
+    ///     &[],
+    ///     &[BaseBis::new(1, &[])],
+    /// )];
+    /// let result = BasicAudioAnnouncement::new(40000, &subgroups);
+    /// assert_eq!(40000, result.presentation_delay);
+    /// assert_eq!(subgroups, result.subgroups);
+    /// ```
+    pub fn new(presentation_delay: u32, subgroups: &[BaseSubgroup]) -> Self {
+        Self {
+            presentation_delay,
+            subgroups: subgroups.to_vec(),
+        }
+    }
+}
+
+impl TryFrom<&Vec<u8>> for BasicAudioAnnouncement {
+    type Error = String;
+    /// Create [`BasicAudioAnnouncement`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::le_audio::{
+    ///     basic_audio_announcement::{BaseBis, BaseSubgroup, BasicAudioAnnouncement, CodecId},
+    ///     ltv::Ltv,
+    /// };
+    ///
+    /// let data: Vec<u8> = vec![
+    ///     0x40, 0x9c, 0x00, 0x01, 0x01, 0x06, 0x00, 0x00, 0x00, 0x00, 0x03, 0x02, 0x01, 0x08, 0x04,
+    ///     0x03, 0x02, 0x04, 0x00, 0x01, 0x06, 0x05, 0x03, 0x01, 0x00, 0x00, 0x00,
+    /// ];
+    /// let result = BasicAudioAnnouncement::try_from(&data);
+    /// assert_eq!(
+    ///     Ok(BasicAudioAnnouncement::new(
+    ///         40000,
+    ///         &[BaseSubgroup::new(
+    ///             CodecId::new(0x06, 0x0000, 0x0000),
+    ///             &[Ltv::new(0x01, &[0x08])],
+    ///             &[Ltv::new(0x02, &[0x04, 0x00])],
+    ///             &[BaseBis::new(1, &[Ltv::new(0x03, &[0x01, 0x00, 0x00, 0x00])])],
+    ///         )],
+    ///     )),
+    ///     result
+    /// );
+    ///
+    /// let data: Vec<u8> = vec![0x40, 0x9c, 0x00, 0x01];
+    /// let result = BasicAudioAnnouncement::try_from(&data);
+    /// assert!(result.is_err());
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        let len = value.len();
+        if len < 4 {
+            return Err(format!("Invalid data size :{}", len).to_string());
+        }
+        let presentation_delay = u32::from_le_bytes([value[0], value[1], value[2], 0]);
+        let num_subgroups = value[3];
+        let mut index = 4;
+        let mut subgroups: Vec<BaseSubgroup> = Vec::new();
+        for _ in 0..num_subgroups {
+            if index + 6 > len {
+                return Err(format!("Invalid data size :{}", len).to_string());
+            }
+            let num_bis = value[index];
+            let codec_id = CodecId::new(
+                value[index + 1],
+                u16::from_le_bytes(value[index + 2..index + 4].try_into().unwrap()),
+                u16::from_le_bytes(value[index + 4..index + 6].try_into().unwrap()),
+            );
+            index += 6;
+            let codec_specific_configuration = read_ltvs(value, &mut index)?;
+            let metadata = read_ltvs(value, &mut index)?;
+            let mut bises: Vec<BaseBis> = Vec::new();
+            for _ in 0..num_bis {
+                if index >= len {
+                    return Err(format!("Invalid data size :{}", len).to_string());
+                }
+                let bis_index = value[index];
+                index += 1;
+                bises.push(BaseBis::new(bis_index, &read_ltvs(value, &mut index)?));
+            }
+            subgroups.push(BaseSubgroup {
+                codec_id,
+                codec_specific_configuration,
+                metadata,
+                bises,
+            });
+        }
+        Ok(Self {
+            presentation_delay,
+            subgroups,
+        })
+    }
+}
+
+impl TryFrom<&ServiceData16BitUUID> for BasicAudioAnnouncement {
+    type Error = String;
+    /// Create [`BasicAudioAnnouncement`] from [`ServiceData16BitUUID`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::service_data_16bit_uuid::ServiceData16BitUUID,
+    ///     le_audio::basic_audio_announcement::BasicAudioAnnouncement, uuid_from_u16,
+    /// };
+    ///
+    /// let data: Vec<u8> = vec![0x40, 0x9c, 0x00, 0x00];
+    /// let service_data = ServiceData16BitUUID::new(&uuid_from_u16(0x1851), &data);
+    /// let result = BasicAudioAnnouncement::try_from(&service_data);
+    /// assert_eq!(Ok(BasicAudioAnnouncement::new(40000, &[])), result);
+    ///
+    /// let service_data = ServiceData16BitUUID::new(&uuid_from_u16(0x180f), &data);
+    /// let result = BasicAudioAnnouncement::try_from(&service_data);
+    /// assert!(result.is_err());
+    /// ```
+    fn try_from(value: &ServiceData16BitUUID) -> Result<Self, String> {
+        if value.uuid != uuid_from_u16(BASIC_AUDIO_ANNOUNCEMENT_SERVICE_UUID) {
+            return Err(format!("Invalid UUID :{}", value.uuid).to_string());
+        }
+        Self::try_from(&value.additional_service_data.to_vec())
+    }
+}
+
+impl From<BasicAudioAnnouncement> for Vec<u8> {
+    /// Create [`Vec<u8>`] from [`BasicAudioAnnouncement`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::le_audio::{
+    ///     basic_audio_announcement::{BaseBis, BaseSubgroup, BasicAudioAnnouncement, CodecId},
+    ///     ltv::Ltv,
+    /// };
+    ///
+    /// let result: Vec<u8> = BasicAudioAnnouncement::new(
+    ///     40000,
+    ///     &[BaseSubgroup::new(
+    ///         CodecId::new(0x06, 0x0000, 0x0000),
+    ///         &[Ltv::new(0x01, &[0x08])],
+    ///         &[Ltv::new(0x02, &[0x04, 0x00])],
+    ///         &[BaseBis::new(1, &[Ltv::new(0x03, &[0x01, 0x00, 0x00, 0x00])])],
+    ///     )],
+    /// )
+    /// .into();
+    /// assert_eq!(
+    ///     vec![
+    ///         0x40, 0x9c, 0x00, 0x01, 0x01, 0x06, 0x00, 0x00, 0x00, 0x00, 0x03, 0x02, 0x01, 0x08,
+    ///         0x04, 0x03, 0x02, 0x04, 0x00, 0x01, 0x06, 0x05, 0x03, 0x01, 0x00, 0x00, 0x00,
+    ///     ],
+    ///     result
+    /// );
+    /// ```
+    fn from(value: BasicAudioAnnouncement) -> Self {
+        let mut data: Vec<u8> = Vec::new();
+        data.append(&mut value.presentation_delay.to_le_bytes()[..3].to_vec());
+        data.push(value.subgroups.len() as u8);
+        for subgroup in &value.subgroups {
+            data.push(subgroup.bises.len() as u8);
+            data.push(subgroup.codec_id.coding_format);
+            data.append(&mut subgroup.codec_id.company_id.to_le_bytes().to_vec());
+            data.append(
+                &mut subgroup
+                    .codec_id
+                    .vendor_specific_codec_id
+                    .to_le_bytes()
+                    .to_vec(),
+            );
+            write_ltvs(&mut data, &subgroup.codec_specific_configuration);
+            write_ltvs(&mut data, &subgroup.metadata);
+            for bis in &subgroup.bises {
+                data.push(bis.bis_index);
+                write_ltvs(&mut data, &bis.codec_specific_configuration);
+            }
+        }
+        data
+    }
+}
+
+impl From<BasicAudioAnnouncement> for ServiceData16BitUUID {
+    /// Create [`ServiceData16BitUUID`] from [`BasicAudioAnnouncement`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::service_data_16bit_uuid::ServiceData16BitUUID,
+    ///     le_audio::basic_audio_announcement::BasicAudioAnnouncement, uuid_from_u16,
+    /// };
+    ///
+    /// let result: ServiceData16BitUUID = BasicAudioAnnouncement::new(40000, &[]).into();
+    /// assert_eq!(uuid_from_u16(0x1851), result.uuid);
+    /// assert_eq!(vec![0x40, 0x9c, 0x00, 0x00], result.additional_service_data.as_slice());
+    /// ```
+    fn from(value: BasicAudioAnnouncement) -> Self {
+        let data: Vec<u8> = value.into();
+        ServiceData16BitUUID::new(&uuid_from_u16(BASIC_AUDIO_ANNOUNCEMENT_SERVICE_UUID), &data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data_types::service_data_16bit_uuid::ServiceData16BitUUID,
+        le_audio::{basic_audio_announcement::*, ltv::*},
+        uuid_from_u16,
+    };
+
+    const DATA: [u8; 42] = [
+        0x40, 0x9c, 0x00, 0x01, 0x02, 0x06, 0x00, 0x00, 0x00, 0x00, 0x0a, 0x02, 0x01, 0x03, 0x02,
+        0x02, 0x01, 0x03, 0x04, 0x28, 0x00, 0x04, 0x03, 0x02, 0x04, 0x00, 0x01, 0x06, 0x05, 0x03,
+        0x01, 0x00, 0x00, 0x00, 0x02, 0x06, 0x05, 0x03, 0x02, 0x00, 0x00, 0x00,
+    ];
+
+    fn base() -> BasicAudioAnnouncement {
+        BasicAudioAnnouncement::new(
+            40000,
+            &[BaseSubgroup::new(
+                CodecId::new(CODING_FORMAT_LC3, 0x0000, 0x0000),
+                &[
+                    Ltv::new(CODEC_CONFIGURATION_SAMPLING_FREQUENCY, &[0x03]),
+                    Ltv::new(CODEC_CONFIGURATION_FRAME_DURATION, &[0x01]),
+                    Ltv::new(CODEC_CONFIGURATION_OCTETS_PER_CODEC_FRAME, &[0x28, 0x00]),
+                ],
+                &[Ltv::new(METADATA_STREAMING_AUDIO_CONTEXTS, &[0x04, 0x00])],
+                &[
+                    BaseBis::new(
+                        1,
+                        &[Ltv::new(
+                            CODEC_CONFIGURATION_AUDIO_CHANNEL_ALLOCATION,
+                            &[0x01, 0x00, 0x00, 0x00],
+                        )],
+                    ),
+                    BaseBis::new(
+                        2,
+                        &[Ltv::new(
+                            CODEC_CONFIGURATION_AUDIO_CHANNEL_ALLOCATION,
+                            &[0x02, 0x00, 0x00, 0x00],
+                        )],
+                    ),
+                ],
+            )],
+        )
+    }
+
+    #[test]
+    fn test_codec_id_new() {
+        let result = CodecId::new(CODING_FORMAT_VENDOR_SPECIFIC, 0x0102, 0x0304);
+        assert_eq!(CODING_FORMAT_VENDOR_SPECIFIC, result.coding_format);
+        assert_eq!(0x0102, result.company_id);
+        assert_eq!(0x0304, result.vendor_specific_codec_id);
+    }
+
+    #[test]
+    fn test_base_bis_new() {
+        let codec_specific_configuration = vec![Ltv::new(0x03, &[0x01, 0x00, 0x00, 0x00])];
+        let result = BaseBis::new(1, &codec_specific_configuration);
+        assert_eq!(1, result.bis_index);
+        assert_eq!(
+            codec_specific_configuration,
+            result.codec_specific_configuration
+        );
+    }
+
+    #[test]
+    fn test_base_subgroup_new() {
+        let codec_specific_configuration = vec![Ltv::new(0x01, &[0x08])];
+        let metadata = vec![Ltv::new(0x02, &[0x04, 0x00])];
+        let bises = vec![BaseBis::new(1, &[])];
+        let result = BaseSubgroup::new(
+            CodecId::new(0x06, 0x0000, 0x0000),
+            &codec_specific_configuration,
+            &metadata,
+            &bises,
+        );
+        assert_eq!(CodecId::new(0x06, 0x0000, 0x0000), result.codec_id);
+        assert_eq!(
+            codec_specific_configuration,
+            result.codec_specific_configuration
+        );
+        assert_eq!(metadata, result.metadata);
+        assert_eq!(bises, result.bises);
+    }
+
+    #[test]
+    fn test_base_subgroup_bis_indexes() {
+        assert_eq!(vec![1, 2], base().subgroups[0].bis_indexes());
+    }
+
+    #[test]
+    fn test_new() {
+        let result = BasicAudioAnnouncement::new(40000, &base().subgroups);
+        assert_eq!(40000, result.presentation_delay);
+        assert_eq!(base().subgroups, result.subgroups);
+    }
+
+    #[test]
+    fn test_try_from() {
+        assert_eq!(Ok(base()), BasicAudioAnnouncement::try_from(&DATA.to_vec()));
+
+        let data: Vec<u8> = vec![0xff, 0xff, 0xff, 0x00];
+        assert_eq!(
+            Ok(BasicAudioAnnouncement::new(0xffffff, &[])),
+            BasicAudioAnnouncement::try_from(&data)
+        );
+
+        for len in [0, 3, 4, 9, 10, 21, 26, 27, 34, 41] {
+            let data = DATA[..len].to_vec();
+            assert_eq!(
+                Err(format!("Invalid data size :{}", len)),
+                BasicAudioAnnouncement::try_from(&data)
+            );
+        }
+
+        let mut data = DATA.to_vec();
+        data[11] = 0x04;
+        assert_eq!(
+            Err("Invalid LTV length :4".to_string()),
+            BasicAudioAnnouncement::try_from(&data)
+        );
+    }
+
+    #[test]
+    fn test_try_from_service_data() {
+        let service_data = ServiceData16BitUUID::new(&uuid_from_u16(0x1851), &DATA.to_vec());
+        assert_eq!(Ok(base()), BasicAudioAnnouncement::try_from(&service_data));
+
+        let service_data = ServiceData16BitUUID::new(&uuid_from_u16(0x1856), &DATA.to_vec());
+        assert!(BasicAudioAnnouncement::try_from(&service_data).is_err());
+    }
+
+    #[test]
+    fn test_into() {
+        let result: Vec<u8> = base().into();
+        assert_eq!(DATA.to_vec(), result);
+    }
+
+    #[test]
+    fn test_into_service_data() {
+        let result: ServiceData16BitUUID = base().into();
+        assert_eq!(uuid_from_u16(0x1851), result.uuid);
+        assert_eq!(DATA.to_vec(), result.additional_service_data.as_slice());
+    }
+}
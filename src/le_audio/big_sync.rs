@@ -0,0 +1,302 @@
+//! BIG synchronization configuration module.
+
+use crate::data_types::{big_info::BigInfo, broadcast_code::BroadcastCode};
+
+/// Derive Access Address of BIS (`bis` 1 to Num_BIS) or BIG Control (`bis` 0) from SeedAccessAddress.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::le_audio::big_sync::bis_access_address;
+///
+/// assert_eq!(0x12345678 ^ 0x02a4_0000, bis_access_address(0x12345678, 0));
+/// assert_eq!(0x12345678 ^ 0xfd06_0000, bis_access_address(0x12345678, 1));
+/// ```
+pub fn bis_access_address(seed_access_address: u32, bis: u8) -> u32 {
+    // diversifier D = ((35 * n) + 42) MOD 128
+    let d = (35 * bis as u32 + 42) % 128;
+    let bit = |index: u32| (d >> index) & 1;
+    // DW (MSB first) = D0 D0 D0 D0 D0 D0 D1 D6 D1 0 D5 D4 0 D3 D2 0, followed by 16 zero bits
+    let mut dw: u32 = if bit(0) == 1 { 0xfc00_0000 } else { 0 };
+    dw |= bit(1) << 25;
+    dw |= bit(6) << 24;
+    dw |= bit(1) << 23;
+    dw |= bit(5) << 21;
+    dw |= bit(4) << 20;
+    dw |= bit(3) << 18;
+    dw |= bit(2) << 17;
+    seed_access_address ^ dw
+}
+
+/// Derive CRC initialization value of BIS (`bis` 1 to Num_BIS) or BIG Control (`bis` 0) from BaseCRCInit.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::le_audio::big_sync::bis_crc_init;
+///
+/// assert_eq!(0x123400, bis_crc_init(0x1234, 0));
+/// assert_eq!(0x123401, bis_crc_init(0x1234, 1));
+/// ```
+pub fn bis_crc_init(base_crc_init: u16, bis: u8) -> u32 {
+    ((base_crc_init as u32) << 8) | bis as u32
+}
+
+/// Parameters to synchronize to a BIG, derived from [`BigInfo`].
+#[derive(Debug, PartialEq, Clone)]
+pub struct BigSyncConfig {
+    /// BIGInfo
+    pub big_info: BigInfo,
+
+    /// Access Address of BIG Control logical link
+    pub control_access_address: u32,
+
+    /// Access Address of each BIS (BIS 1 to Num_BIS)
+    pub bis_access_addresses: Vec<u32>,
+
+    /// CRC initialization value of BIG Control logical link
+    pub control_crc_init: u32,
+
+    /// CRC initialization value of each BIS (BIS 1 to Num_BIS)
+    pub bis_crc_inits: Vec<u32>,
+
+    /// Broadcast_Code (128-bit, zero padded) of encrypted BIG
+    pub broadcast_code: Option<[u8; 16]>,
+}
+
+impl BigSyncConfig {
+    /// Create [`BigSyncConfig`] from [`BigInfo`] and [`BroadcastCode`].
+    ///
+    /// [`BroadcastCode`] is required for encrypted BIG and ignored otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::{big_info::BigInfo, broadcast_code::BroadcastCode, channel_map::ChannelMap},
+    ///     le_audio::big_sync::{bis_access_address, BigSyncConfig},
+    /// };
+    ///
+    /// let big_info = BigInfo::new(
+    ///     1, true, 8, 2, 4, 2, 6, 0, 8, 2, 40, 0, 0x12345678, 10000, 40, 0x1234,
+    ///     ChannelMap::from(0x1f_ffff_ffff), 1,
+    ///     0, false, None, None,
+    /// );
+    /// let result = BigSyncConfig::new(&big_info, None);
+    /// assert!(result.is_ok());
+    /// let config = result.unwrap();
+    /// assert_eq!(bis_access_address(0x12345678, 0), config.control_access_address);
+    /// assert_eq!(
+    ///     vec![
+    ///         bis_access_address(0x12345678, 1),
+    ///         bis_access_address(0x12345678, 2)
+    ///     ],
+    ///     config.bis_access_addresses
+    /// );
+    /// assert_eq!(0x123400, config.control_crc_init);
+    /// assert_eq!(vec![0x123401, 0x123402], config.bis_crc_inits);
+    /// assert_eq!(None, config.broadcast_code);
+    ///
+    /// let big_info = BigInfo::new(
+    ///     1, true, 8, 2, 4, 2, 6, 0, 8, 2, 40, 0, 0x12345678, 10000, 40, 0x1234,
+    ///     ChannelMap::from(0x1f_ffff_ffff), 1,
+    ///     0, false, Some([0; 8]), Some([0; 16]),
+    /// );
+    /// let result = BigSyncConfig::new(&big_info, None);
+    /// assert_eq!(Err("Broadcast_Code required for encrypted BIG".to_string()), result);
+    ///
+    /// let broadcast_code = BroadcastCode::from_passphrase("code").unwrap();
+    /// let result = BigSyncConfig::new(&big_info, Some(&broadcast_code));
+    /// assert_eq!(Some(broadcast_code.to_128bit()), result.unwrap().broadcast_code);
+    /// ```
+    pub fn new(big_info: &BigInfo, broadcast_code: Option<&BroadcastCode>) -> Result<Self, String> {
+        big_info.validate()?;
+        let broadcast_code = if big_info.is_encrypted() {
+            match broadcast_code {
+                Some(broadcast_code) => Some(broadcast_code.to_128bit()),
+                None => return Err("Broadcast_Code required for encrypted BIG".to_string()),
+            }
+        } else {
+            None
+        };
+        Ok(Self {
+            big_info: big_info.clone(),
+            control_access_address: bis_access_address(big_info.seed_access_address, 0),
+            bis_access_addresses: (1..=big_info.num_bis)
+                .map(|bis| bis_access_address(big_info.seed_access_address, bis))
+                .collect(),
+            control_crc_init: bis_crc_init(big_info.base_crc_init, 0),
+            bis_crc_inits: (1..=big_info.num_bis)
+                .map(|bis| bis_crc_init(big_info.base_crc_init, bis))
+                .collect(),
+            broadcast_code,
+        })
+    }
+
+    /// Get ISO_Interval in microseconds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::{big_info::BigInfo, channel_map::ChannelMap},
+    ///     le_audio::big_sync::BigSyncConfig,
+    /// };
+    ///
+    /// let big_info = BigInfo::new(
+    ///     1, true, 8, 2, 4, 2, 6, 0, 8, 2, 40, 0, 0x12345678, 10000, 40, 0x1234,
+    ///     ChannelMap::from(0x1f_ffff_ffff), 1,
+    ///     0, false, None, None,
+    /// );
+    /// let config = BigSyncConfig::new(&big_info, None).unwrap();
+    /// assert_eq!(10_000, config.iso_interval_micros());
+    /// ```
+    pub fn iso_interval_micros(&self) -> u32 {
+        self.big_info.iso_interval as u32 * 1250
+    }
+
+    /// Get BIG anchor point offset from the AUX_SYNC_IND in microseconds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::{big_info::BigInfo, channel_map::ChannelMap},
+    ///     le_audio::big_sync::BigSyncConfig,
+    /// };
+    ///
+    /// let big_info = BigInfo::new(
+    ///     10, true, 8, 2, 4, 2, 6, 0, 8, 2, 40, 0, 0x12345678, 10000, 40, 0x1234,
+    ///     ChannelMap::from(0x1f_ffff_ffff), 1,
+    ///     0, false, None, None,
+    /// );
+    /// let config = BigSyncConfig::new(&big_info, None).unwrap();
+    /// assert_eq!(3000, config.big_offset_micros());
+    /// ```
+    pub fn big_offset_micros(&self) -> u32 {
+        self.big_info.big_offset_micros()
+    }
+
+    /// check encrypted BIG.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::{big_info::BigInfo, channel_map::ChannelMap},
+    ///     le_audio::big_sync::BigSyncConfig,
+    /// };
+    ///
+    /// let big_info = BigInfo::new(
+    ///     10, true, 8, 2, 4, 2, 6, 0, 8, 2, 40, 0, 0x12345678, 10000, 40, 0x1234,
+    ///     ChannelMap::from(0x1f_ffff_ffff), 1,
+    ///     0, false, None, None,
+    /// );
+    /// let config = BigSyncConfig::new(&big_info, None).unwrap();
+    /// assert!(!config.is_encrypted());
+    /// ```
+    pub fn is_encrypted(&self) -> bool {
+        self.broadcast_code.is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data_types::{big_info::BigInfo, broadcast_code::BroadcastCode, channel_map::ChannelMap},
+        le_audio::big_sync::{bis_access_address, bis_crc_init, BigSyncConfig},
+    };
+
+    fn big_info(num_bis: u8, encrypted: bool) -> BigInfo {
+        BigInfo::new(
+            1,
+            false,
+            8,
+            num_bis,
+            4,
+            2,
+            6,
+            0,
+            8,
+            2,
+            40,
+            0,
+            0x8e89bed6,
+            10000,
+            40,
+            0x1234,
+            ChannelMap::from(0x1f_ffff_ffff),
+            1,
+            0,
+            false,
+            encrypted.then_some([0; 8]),
+            encrypted.then_some([0; 16]),
+        )
+    }
+
+    #[test]
+    fn test_bis_access_address() {
+        assert_eq!(0x02a4_0000, bis_access_address(0, 0));
+        assert_eq!(0xfd06_0000, bis_access_address(0, 1));
+        // D = 112 (0b111_0000)
+        assert_eq!(0x0130_0000, bis_access_address(0, 2));
+        // D repeats with period 128
+        assert_eq!(bis_access_address(0, 0), bis_access_address(0, 128));
+        // lower 16 bits are seed
+        for bis in 0..=31 {
+            assert_eq!(0xbed6, bis_access_address(0x8e89bed6, bis) & 0xffff);
+        }
+        // all BIS in a BIG have distinct Access Address
+        let mut addresses: Vec<u32> = (0..=31).map(|bis| bis_access_address(0, bis)).collect();
+        addresses.sort();
+        addresses.dedup();
+        assert_eq!(32, addresses.len());
+    }
+
+    #[test]
+    fn test_bis_crc_init() {
+        assert_eq!(0x000000, bis_crc_init(0x0000, 0));
+        assert_eq!(0xffff1f, bis_crc_init(0xffff, 31));
+        assert_eq!(0x5555ff, bis_crc_init(0x5555, 0xff));
+    }
+
+    #[test]
+    fn test_new() {
+        let config = BigSyncConfig::new(&big_info(0, false), None).unwrap();
+        assert!(config.bis_access_addresses.is_empty());
+        assert!(config.bis_crc_inits.is_empty());
+        assert_eq!(0x123400, config.control_crc_init);
+
+        let config = BigSyncConfig::new(
+            &big_info(31, false),
+            Some(&BroadcastCode::from_passphrase("ignored").unwrap()),
+        )
+        .unwrap();
+        assert_eq!(31, config.bis_access_addresses.len());
+        assert_eq!(
+            bis_access_address(0x8e89bed6, 31),
+            config.bis_access_addresses[30]
+        );
+        assert_eq!(31, config.bis_crc_inits.len());
+        assert_eq!(0x12341f, config.bis_crc_inits[30]);
+        assert!(!config.is_encrypted());
+        assert_eq!(big_info(31, false), config.big_info);
+        assert_eq!(10_000, config.iso_interval_micros());
+        assert_eq!(30, config.big_offset_micros());
+
+        let config = BigSyncConfig::new(
+            &big_info(1, true),
+            Some(&BroadcastCode::new(&vec![0x01; 16])),
+        )
+        .unwrap();
+        assert_eq!(Some([0x01; 16]), config.broadcast_code);
+        assert!(config.is_encrypted());
+
+        let mut invalid = big_info(1, false);
+        invalid.num_bis = 32;
+        assert_eq!(
+            Err("Invalid Num_BIS :32".to_string()),
+            BigSyncConfig::new(&invalid, None)
+        );
+    }
+}
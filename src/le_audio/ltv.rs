@@ -0,0 +1,172 @@
+//! Length-Type-Value structure module.
+
+/// Codec Specific Configuration Type: Sampling_Frequency
+pub const CODEC_CONFIGURATION_SAMPLING_FREQUENCY: u8 = 0x01;
+
+/// Codec Specific Configuration Type: Frame_Duration
+pub const CODEC_CONFIGURATION_FRAME_DURATION: u8 = 0x02;
+
+/// Codec Specific Configuration Type: Audio_Channel_Allocation
+pub const CODEC_CONFIGURATION_AUDIO_CHANNEL_ALLOCATION: u8 = 0x03;
+
+/// Codec Specific Configuration Type: Octets_Per_Codec_Frame
+pub const CODEC_CONFIGURATION_OCTETS_PER_CODEC_FRAME: u8 = 0x04;
+
+/// Codec Specific Configuration Type: Codec_Frame_Blocks_Per_SDU
+pub const CODEC_CONFIGURATION_CODEC_FRAME_BLOCKS_PER_SDU: u8 = 0x05;
+
+/// Metadata Type: Streaming_Audio_Contexts
+pub const METADATA_STREAMING_AUDIO_CONTEXTS: u8 = 0x02;
+
+/// Metadata Type: Program_Info
+pub const METADATA_PROGRAM_INFO: u8 = 0x03;
+
+/// Metadata Type: Language
+pub const METADATA_LANGUAGE: u8 = 0x04;
+
+/// Length-Type-Value structure.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Ltv {
+    /// Type
+    pub ltv_type: u8,
+
+    /// Value
+    pub value: Vec<u8>,
+}
+
+impl Ltv {
+    /// Create [`Ltv`] from Parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::le_audio::ltv::Ltv;
+    ///
+    /// let result = Ltv::new(0x01, &[0x08]);
+    /// assert_eq!(0x01, result.ltv_type);
+    /// assert_eq!(vec![0x08], result.value);
+    /// ```
+    pub fn new(ltv_type: u8, value: &[u8]) -> Self {
+        Self {
+            ltv_type,
+            value: value.to_vec(),
+        }
+    }
+}
+
+/// Decode [`Ltv`] list.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::le_audio::ltv::{decode_ltvs, Ltv};
+///
+/// let result = decode_ltvs(&[0x02, 0x01, 0x08, 0x03, 0x04, 0x28, 0x00]);
+/// assert_eq!(
+///     Ok(vec![Ltv::new(0x01, &[0x08]), Ltv::new(0x04, &[0x28, 0x00])]),
+///     result
+/// );
+///
+/// let result = decode_ltvs(&[0x03, 0x01, 0x08]);
+/// assert!(result.is_err());
+/// ```
+pub fn decode_ltvs(data: &[u8]) -> Result<Vec<Ltv>, String> {
+    let mut ltvs: Vec<Ltv> = Vec::new();
+    let mut index = 0;
+    while index < data.len() {
+        let length = data[index] as usize;
+        if length == 0 || index + 1 + length > data.len() {
+            return Err(format!("Invalid LTV length :{}", length).to_string());
+        }
+        ltvs.push(Ltv::new(
+            data[index + 1],
+            &data[index + 2..index + 1 + length],
+        ));
+        index += 1 + length;
+    }
+    Ok(ltvs)
+}
+
+/// Encode [`Ltv`] list.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::le_audio::ltv::{encode_ltvs, Ltv};
+///
+/// let result = encode_ltvs(&[Ltv::new(0x01, &[0x08]), Ltv::new(0x04, &[0x28, 0x00])]);
+/// assert_eq!(vec![0x02, 0x01, 0x08, 0x03, 0x04, 0x28, 0x00], result);
+/// ```
+pub fn encode_ltvs(ltvs: &[Ltv]) -> Vec<u8> {
+    let mut data: Vec<u8> = Vec::new();
+    for ltv in ltvs {
+        data.push(1 + ltv.value.len() as u8);
+        data.push(ltv.ltv_type);
+        data.append(&mut ltv.value.clone());
+    }
+    data
+}
+
+/// read length prefixed LTV list.
+pub(crate) fn read_ltvs(data: &[u8], index: &mut usize) -> Result<Vec<Ltv>, String> {
+    let len = data.len();
+    if *index >= len || *index + 1 + data[*index] as usize > len {
+        return Err(format!("Invalid data size :{}", len).to_string());
+    }
+    let length = data[*index] as usize;
+    let ltvs = decode_ltvs(&data[*index + 1..*index + 1 + length])?;
+    *index += 1 + length;
+    Ok(ltvs)
+}
+
+/// write length prefixed LTV list.
+pub(crate) fn write_ltvs(data: &mut Vec<u8>, ltvs: &[Ltv]) {
+    let mut ltv_data = encode_ltvs(ltvs);
+    data.push(ltv_data.len() as u8);
+    data.append(&mut ltv_data);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::le_audio::ltv::*;
+
+    #[test]
+    fn test_new() {
+        let result = Ltv::new(0x01, &[0x08]);
+        assert_eq!(0x01, result.ltv_type);
+        assert_eq!(vec![0x08], result.value);
+    }
+
+    #[test]
+    fn test_decode_ltvs() {
+        assert_eq!(Ok(vec![]), decode_ltvs(&[]));
+        assert_eq!(
+            Ok(vec![
+                Ltv::new(CODEC_CONFIGURATION_SAMPLING_FREQUENCY, &[0x08]),
+                Ltv::new(CODEC_CONFIGURATION_OCTETS_PER_CODEC_FRAME, &[0x28, 0x00])
+            ]),
+            decode_ltvs(&[0x02, 0x01, 0x08, 0x03, 0x04, 0x28, 0x00])
+        );
+        assert_eq!(Ok(vec![Ltv::new(0x7f, &[])]), decode_ltvs(&[0x01, 0x7f]));
+        assert_eq!(
+            Err("Invalid LTV length :3".to_string()),
+            decode_ltvs(&[0x03, 0x01, 0x08])
+        );
+        assert_eq!(
+            Err("Invalid LTV length :0".to_string()),
+            decode_ltvs(&[0x00])
+        );
+    }
+
+    #[test]
+    fn test_encode_ltvs() {
+        assert_eq!(Vec::<u8>::new(), encode_ltvs(&[]));
+        assert_eq!(
+            vec![0x02, 0x01, 0x08, 0x03, 0x04, 0x28, 0x00],
+            encode_ltvs(&[
+                Ltv::new(CODEC_CONFIGURATION_SAMPLING_FREQUENCY, &[0x08]),
+                Ltv::new(CODEC_CONFIGURATION_OCTETS_PER_CODEC_FRAME, &[0x28, 0x00])
+            ])
+        );
+    }
+}
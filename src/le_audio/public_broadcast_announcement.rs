@@ -0,0 +1,305 @@
+//! Public Broadcast Announcement module.
+
+use crate::{
+    data_types::service_data_16bit_uuid::ServiceData16BitUUID,
+    le_audio::ltv::{read_ltvs, write_ltvs, Ltv},
+    uuid_from_u16,
+};
+
+/// Public Broadcast Announcement Service UUID
+pub const PUBLIC_BROADCAST_ANNOUNCEMENT_SERVICE_UUID: u16 = 0x1856;
+
+/// Public Broadcast Announcement Features: Encryption
+pub const FEATURES_ENCRYPTION: u8 = 0b0000_0001;
+
+/// Public Broadcast Announcement Features: Standard Quality Public Broadcast Audio
+pub const FEATURES_STANDARD_QUALITY: u8 = 0b0000_0010;
+
+/// Public Broadcast Announcement Features: High Quality Public Broadcast Audio
+pub const FEATURES_HIGH_QUALITY: u8 = 0b0000_0100;
+
+/// Public Broadcast Announcement.
+#[derive(Debug, PartialEq, Clone)]
+pub struct PublicBroadcastAnnouncement {
+    /// Public Broadcast Announcement Features
+    pub features: u8,
+
+    /// Metadata
+    pub metadata: Vec<Ltv>,
+}
+
+impl PublicBroadcastAnnouncement {
+    /// Create [`PublicBroadcastAnnouncement`] from Parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::le_audio::{
+    ///     ltv::Ltv, public_broadcast_announcement::PublicBroadcastAnnouncement,
+    /// };
+    ///
+    /// let metadata = vec![Ltv::new(0x03, "music".as_bytes())];
+    /// let result = PublicBroadcastAnnouncement::new(0x03, &metadata);
+    /// assert_eq!(0x03, result.features);
+    /// assert_eq!(metadata, result.metadata);
+    /// ```
+    pub fn new(features: u8, metadata: &[Ltv]) -> Self {
+        Self {
+            features,
+            metadata: metadata.to_vec(),
+        }
+    }
+
+    /// check Encryption feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::le_audio::public_broadcast_announcement::{
+    ///     PublicBroadcastAnnouncement, FEATURES_ENCRYPTION,
+    /// };
+    ///
+    /// assert!(PublicBroadcastAnnouncement::new(FEATURES_ENCRYPTION, &[]).is_encrypted());
+    /// assert!(!PublicBroadcastAnnouncement::new(0x00, &[]).is_encrypted());
+    /// ```
+    pub fn is_encrypted(&self) -> bool {
+        self.features & FEATURES_ENCRYPTION != 0
+    }
+
+    /// check Standard Quality Public Broadcast Audio feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::le_audio::public_broadcast_announcement::{
+    ///     PublicBroadcastAnnouncement, FEATURES_STANDARD_QUALITY,
+    /// };
+    ///
+    /// assert!(PublicBroadcastAnnouncement::new(FEATURES_STANDARD_QUALITY, &[]).is_standard_quality());
+    /// assert!(!PublicBroadcastAnnouncement::new(0x00, &[]).is_standard_quality());
+    /// ```
+    pub fn is_standard_quality(&self) -> bool {
+        self.features & FEATURES_STANDARD_QUALITY != 0
+    }
+
+    /// check High Quality Public Broadcast Audio feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::le_audio::public_broadcast_announcement::{
+    ///     PublicBroadcastAnnouncement, FEATURES_HIGH_QUALITY,
+    /// };
+    ///
+    /// assert!(PublicBroadcastAnnouncement::new(FEATURES_HIGH_QUALITY, &[]).is_high_quality());
+    /// assert!(!PublicBroadcastAnnouncement::new(0x00, &[]).is_high_quality());
+    /// ```
+    pub fn is_high_quality(&self) -> bool {
+        self.features & FEATURES_HIGH_QUALITY != 0
+    }
+}
+
+impl TryFrom<&Vec<u8>> for PublicBroadcastAnnouncement {
+    type Error = String;
+    /// Create [`PublicBroadcastAnnouncement`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::le_audio::{
+    ///     ltv::Ltv, public_broadcast_announcement::PublicBroadcastAnnouncement,
+    /// };
+    ///
+    /// let data: Vec<u8> = vec![0x02, 0x04, 0x03, 0x02, 0x04, 0x00];
+    /// let result = PublicBroadcastAnnouncement::try_from(&data);
+    /// assert_eq!(
+    ///     Ok(PublicBroadcastAnnouncement::new(0x02, &[Ltv::new(0x02, &[0x04, 0x00])])),
+    ///     result
+    /// );
+    ///
+    /// let data: Vec<u8> = vec![0x02];
+    /// let result = PublicBroadcastAnnouncement::try_from(&data);
+    /// assert!(result.is_err());
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        let len = value.len();
+        if len < 2 {
+            return Err(format!("Invalid data size :{}", len).to_string());
+        }
+        let mut index = 1;
+        let metadata = read_ltvs(value, &mut index)?;
+        Ok(Self {
+            features: value[0],
+            metadata,
+        })
+    }
+}
+
+impl TryFrom<&ServiceData16BitUUID> for PublicBroadcastAnnouncement {
+    type Error = String;
+    /// Create [`PublicBroadcastAnnouncement`] from [`ServiceData16BitUUID`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::service_data_16bit_uuid::ServiceData16BitUUID,
+    ///     le_audio::public_broadcast_announcement::PublicBroadcastAnnouncement, uuid_from_u16,
+    /// };
+    ///
+    /// let data: Vec<u8> = vec![0x02, 0x00];
+    /// let service_data = ServiceData16BitUUID::new(&uuid_from_u16(0x1856), &data);
+    /// let result = PublicBroadcastAnnouncement::try_from(&service_data);
+    /// assert_eq!(Ok(PublicBroadcastAnnouncement::new(0x02, &[])), result);
+    ///
+    /// let service_data = ServiceData16BitUUID::new(&uuid_from_u16(0x1851), &data);
+    /// let result = PublicBroadcastAnnouncement::try_from(&service_data);
+    /// assert!(result.is_err());
+    /// ```
+    fn try_from(value: &ServiceData16BitUUID) -> Result<Self, String> {
+        if value.uuid != uuid_from_u16(PUBLIC_BROADCAST_ANNOUNCEMENT_SERVICE_UUID) {
+            return Err(format!("Invalid UUID :{}", value.uuid).to_string());
+        }
+        Self::try_from(&value.additional_service_data.to_vec())
+    }
+}
+
+impl From<PublicBroadcastAnnouncement> for Vec<u8> {
+    /// Create [`Vec<u8>`] from [`PublicBroadcastAnnouncement`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::le_audio::{
+    ///     ltv::Ltv, public_broadcast_announcement::PublicBroadcastAnnouncement,
+    /// };
+    ///
+    /// let result: Vec<u8> =
+    ///     PublicBroadcastAnnouncement::new(0x02, &[Ltv::new(0x02, &[0x04, 0x00])]).into();
+    /// assert_eq!(vec![0x02, 0x04, 0x03, 0x02, 0x04, 0x00], result);
+    /// ```
+    fn from(value: PublicBroadcastAnnouncement) -> Self {
+        let mut data: Vec<u8> = Vec::new();
+        data.push(value.features);
+        write_ltvs(&mut data, &value.metadata);
+        data
+    }
+}
+
+impl From<PublicBroadcastAnnouncement> for ServiceData16BitUUID {
+    /// Create [`ServiceData16BitUUID`] from [`PublicBroadcastAnnouncement`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::service_data_16bit_uuid::ServiceData16BitUUID,
+    ///     le_audio::public_broadcast_announcement::PublicBroadcastAnnouncement, uuid_from_u16,
+    /// };
+    ///
+    /// let result: ServiceData16BitUUID = PublicBroadcastAnnouncement::new(0x02, &[]).into();
+    /// assert_eq!(uuid_from_u16(0x1856), result.uuid);
+    /// assert_eq!(vec![0x02, 0x00], result.additional_service_data.as_slice());
+    /// ```
+    fn from(value: PublicBroadcastAnnouncement) -> Self {
+        let data: Vec<u8> = value.into();
+        ServiceData16BitUUID::new(
+            &uuid_from_u16(PUBLIC_BROADCAST_ANNOUNCEMENT_SERVICE_UUID),
+            &data,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data_types::service_data_16bit_uuid::ServiceData16BitUUID,
+        le_audio::{ltv::*, public_broadcast_announcement::*},
+        uuid_from_u16,
+    };
+
+    const DATA: [u8; 13] = [
+        0x03, 0x0b, 0x03, 0x02, 0x04, 0x00, 0x06, 0x03, 0x6d, 0x75, 0x73, 0x69, 0x63,
+    ];
+
+    fn pba() -> PublicBroadcastAnnouncement {
+        PublicBroadcastAnnouncement::new(
+            FEATURES_ENCRYPTION | FEATURES_STANDARD_QUALITY,
+            &[
+                Ltv::new(METADATA_STREAMING_AUDIO_CONTEXTS, &[0x04, 0x00]),
+                Ltv::new(METADATA_PROGRAM_INFO, "music".as_bytes()),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_new() {
+        let metadata = vec![Ltv::new(METADATA_PROGRAM_INFO, "music".as_bytes())];
+        let result = PublicBroadcastAnnouncement::new(0x03, &metadata);
+        assert_eq!(0x03, result.features);
+        assert_eq!(metadata, result.metadata);
+    }
+
+    #[test]
+    fn test_features() {
+        let result = PublicBroadcastAnnouncement::new(0x00, &[]);
+        assert!(!result.is_encrypted());
+        assert!(!result.is_standard_quality());
+        assert!(!result.is_high_quality());
+
+        let result = PublicBroadcastAnnouncement::new(
+            FEATURES_ENCRYPTION | FEATURES_STANDARD_QUALITY | FEATURES_HIGH_QUALITY,
+            &[],
+        );
+        assert!(result.is_encrypted());
+        assert!(result.is_standard_quality());
+        assert!(result.is_high_quality());
+    }
+
+    #[test]
+    fn test_try_from() {
+        assert_eq!(
+            Ok(pba()),
+            PublicBroadcastAnnouncement::try_from(&DATA.to_vec())
+        );
+
+        for len in [0, 1, 12] {
+            let data = DATA[..len].to_vec();
+            assert_eq!(
+                Err(format!("Invalid data size :{}", len)),
+                PublicBroadcastAnnouncement::try_from(&data)
+            );
+        }
+
+        let data: Vec<u8> = vec![0x00, 0x02, 0x03, 0x02];
+        assert_eq!(
+            Err("Invalid LTV length :3".to_string()),
+            PublicBroadcastAnnouncement::try_from(&data)
+        );
+    }
+
+    #[test]
+    fn test_try_from_service_data() {
+        let service_data = ServiceData16BitUUID::new(&uuid_from_u16(0x1856), &DATA.to_vec());
+        assert_eq!(
+            Ok(pba()),
+            PublicBroadcastAnnouncement::try_from(&service_data)
+        );
+
+        let service_data = ServiceData16BitUUID::new(&uuid_from_u16(0x1851), &DATA.to_vec());
+        assert!(PublicBroadcastAnnouncement::try_from(&service_data).is_err());
+    }
+
+    #[test]
+    fn test_into() {
+        let result: Vec<u8> = pba().into();
+        assert_eq!(DATA.to_vec(), result);
+    }
+
+    #[test]
+    fn test_into_service_data() {
+        let result: ServiceData16BitUUID = pba().into();
+        assert_eq!(uuid_from_u16(0x1856), result.uuid);
+        assert_eq!(DATA.to_vec(), result.additional_service_data.as_slice());
+    }
+}
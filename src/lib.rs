@@ -1,47 +1,149 @@
 //! BLE data struct.
+//!
+//! # Features
+//!
+//! Data type parsers are grouped by cargo features, all enabled by default:
+//!
+//! - `core`: Core Specification Supplement data types, extended header fields and payload
+//!   parsing. Always built; use `default-features = false, features = ["core"]` to build only
+//!   this group. Encrypted Data is core, as Encrypted Advertising Data is a Core Specification
+//!   feature used by any profile (e.g. ESL).
+//! - `pairing`: OOB pairing data (Simple Pairing Hash/Randomizer, Security Manager TK Value and
+//!   OOB Flags, LE Secure Connections Confirmation/Random Value).
+//! - `mesh`: Mesh Beacon.
+//! - `le-audio`: BIGInfo, Broadcast_Code, Resolvable Set Identifier and the `le_audio` module.
+//! - `vendor`: the `beacons` module.
+//!
+//! With a group disabled, its data types parse to a "Disabled data type" error.
+pub mod airtime;
+
+#[cfg(feature = "arbitrary")]
+pub mod arbitrary;
+
+#[cfg(feature = "vendor")]
+pub mod beacons {
+    //! beacon module.
+    pub mod altbeacon;
+    pub mod eddystone;
+    pub mod find_my;
+    pub mod govee;
+    pub mod ibeacon;
+    pub mod manufacturer_data;
+    pub mod microsoft_cdp;
+    pub mod ruuvi;
+}
+
+pub mod bluez;
+
+#[cfg(feature = "crypto")]
+pub mod crypto;
+
+#[cfg(feature = "ffi")]
+pub mod ffi;
+
+pub mod hci;
+
+#[cfg(feature = "json")]
+pub mod jsonl;
+
+#[cfg(feature = "uniffi")]
+pub mod mobile;
+
+#[cfg(feature = "oui")]
+pub mod oui;
+
+pub mod peer;
+
+pub mod pretty;
+
+#[cfg(feature = "python")]
+pub mod python;
+
+pub mod replay;
+
+pub mod rssi;
+
+#[cfg(feature = "async")]
+pub mod stream;
+
+pub mod test_vectors;
+
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
 pub mod data_types {
     //! EIR/AD/SRD/ACAD/OOB module.
+    pub mod ad_type;
     pub mod advertising_interval;
     pub mod advertising_interval_long;
     pub mod appearance;
+    #[cfg(feature = "le-audio")]
     pub mod big_info;
+    #[cfg(feature = "le-audio")]
     pub mod broadcast_code;
+    pub mod channel_map;
     pub mod channel_map_update_indication;
     pub mod class_of_device;
+    pub mod codes;
     pub mod complete_list_of_128bit_service_uuids;
     pub mod complete_list_of_16bit_service_uuids;
     pub mod complete_list_of_32bit_service_uuids;
     pub mod complete_local_name;
     pub mod data_type;
     pub mod data_type_parser;
+    pub mod dedup;
+    pub mod diff;
+    pub mod electronic_shelf_label;
     pub mod encrypted_data;
     pub mod flags;
     pub mod incomplete_list_of_128bit_service_uuids;
     pub mod incomplete_list_of_16bit_service_uuids;
     pub mod incomplete_list_of_32bit_service_uuids;
+    pub mod indoor_positioning;
+    pub mod interval_units;
     pub mod le_bluetooth_device_address;
     pub mod le_role;
+    #[cfg(feature = "pairing")]
     pub mod le_secure_connections_confirmation_value;
+    #[cfg(feature = "pairing")]
     pub mod le_secure_connections_random_value;
     pub mod le_supported_features;
+    pub mod lint;
     pub mod list_of_128bit_service_solicitation_uuids;
     pub mod list_of_16bit_service_solicitation_uuids;
     pub mod list_of_32bit_service_solicitation_uuids;
     pub mod manufacturer_specific_data;
+    #[cfg(feature = "mesh")]
+    pub mod mesh_beacon;
+    #[cfg(feature = "nom")]
+    pub mod nom_parser;
+    pub mod normalize;
+    pub mod parse_options;
     pub mod periodic_advertising_response_timing_information;
     pub mod peripheral_connection_interval_range;
     pub mod public_target_address;
     pub mod random_target_address;
+    pub mod raw_advertising_data;
+    #[cfg(feature = "le-audio")]
+    pub mod resolvable_set_identifier;
+    #[cfg(feature = "pairing")]
     pub mod secure_simple_pairing_hash_c192;
+    #[cfg(feature = "pairing")]
     pub mod secure_simple_pairing_hash_c256;
+    #[cfg(feature = "pairing")]
     pub mod secure_simple_pairing_randomizer_r192;
+    #[cfg(feature = "pairing")]
     pub mod secure_simple_pairing_randomizer_r256;
+    #[cfg(feature = "pairing")]
     pub mod security_manager_oob;
+    #[cfg(feature = "pairing")]
     pub mod security_manager_tk_value;
     pub mod service_data_128bit_uuid;
     pub mod service_data_16bit_uuid;
     pub mod service_data_32bit_uuid;
     pub mod shortened_local_name;
+    pub mod three_d_information_data;
+    pub mod transport_discovery_data;
     pub mod tx_power_level;
     pub mod uniform_resource_identifier;
 }
@@ -56,8 +158,41 @@ pub mod descriptors {
     pub mod characteristic_aggregate_format;
 }
 
+pub mod extended_header {
+    //! Extended advertising header field module.
+    pub mod adi;
+    pub mod aux_ptr;
+    pub mod chain;
+    pub mod sync_info;
+}
+
+#[cfg(feature = "le-audio")]
+pub mod le_audio {
+    //! LE Audio module.
+    pub mod basic_audio_announcement;
+    pub mod big_sync;
+    pub mod ltv;
+    pub mod public_broadcast_announcement;
+}
+
+pub mod service_data {
+    //! Service data decoder module.
+    pub mod battery_service;
+    pub mod dispatch;
+    pub mod improv;
+    pub mod matter;
+    pub mod sensor;
+}
+
+pub mod uuids {
+    //! UUID registry module.
+    pub mod characteristic;
+    pub mod descriptor;
+    pub mod member_service;
+}
+
 /// for Windows
-#[cfg(target_os = "windows")]
+#[cfg(all(target_os = "windows", feature = "windows"))]
 pub mod windows {
     pub mod data_types {
         pub mod windows_data_type_parser;
@@ -67,6 +202,12 @@ pub mod windows {
 
 use uuid::{uuid, Uuid};
 
+#[cfg(feature = "uniffi")]
+use mobile::*;
+
+#[cfg(feature = "uniffi")]
+uniffi::include_scaffolding!("ble_data_struct");
+
 /// BASE UUID.
 ///
 /// 00000000-0000-1000-8000-00805F9B34FB
@@ -108,6 +249,93 @@ pub fn uuid_from_u32(value: u32) -> Uuid {
     Uuid::from_fields(d1 | value, d2, d3, d4)
 }
 
+/// Shortened form of a Bluetooth Base UUID alias.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum ShortUuid {
+    /// 16-bit UUID.
+    Uuid16(u16),
+
+    /// 32-bit UUID.
+    Uuid32(u32),
+}
+
+impl From<ShortUuid> for Uuid {
+    /// Create [`Uuid`] from [`ShortUuid`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::ShortUuid;
+    /// use uuid::{uuid, Uuid};
+    ///
+    /// assert_eq!(
+    ///     uuid!("00001234-0000-1000-8000-00805F9B34FB"),
+    ///     Uuid::from(ShortUuid::Uuid16(0x1234))
+    /// );
+    /// assert_eq!(
+    ///     uuid!("12345678-0000-1000-8000-00805F9B34FB"),
+    ///     Uuid::from(ShortUuid::Uuid32(0x12345678))
+    /// );
+    /// ```
+    fn from(value: ShortUuid) -> Self {
+        match value {
+            ShortUuid::Uuid16(value) => uuid_from_u16(value),
+            ShortUuid::Uuid32(value) => uuid_from_u32(value),
+        }
+    }
+}
+
+/// Check [`Uuid`] is an alias of the Bluetooth Base UUID.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::is_base_uuid_alias;
+/// use uuid::uuid;
+///
+/// assert!(is_base_uuid_alias(&uuid!("00001234-0000-1000-8000-00805F9B34FB")));
+/// assert!(is_base_uuid_alias(&uuid!("12345678-0000-1000-8000-00805F9B34FB")));
+/// assert!(!is_base_uuid_alias(&uuid!("00001234-0000-1000-8000-00805F9B34FC")));
+/// ```
+pub fn is_base_uuid_alias(uuid: &Uuid) -> bool {
+    uuid.as_u128() & 0x00000000_FFFF_FFFF_FFFF_FFFFFFFFFFFF == BASE_UUID.as_u128()
+}
+
+/// Get the shortest form of [`Uuid`].
+///
+/// Returns [`None`] when `uuid` is not an alias of the Bluetooth Base UUID.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::{short_form, ShortUuid};
+/// use uuid::uuid;
+///
+/// assert_eq!(
+///     Some(ShortUuid::Uuid16(0x1234)),
+///     short_form(&uuid!("00001234-0000-1000-8000-00805F9B34FB"))
+/// );
+/// assert_eq!(
+///     Some(ShortUuid::Uuid32(0x12345678)),
+///     short_form(&uuid!("12345678-0000-1000-8000-00805F9B34FB"))
+/// );
+/// assert_eq!(
+///     None,
+///     short_form(&uuid!("00001234-0000-1000-8000-00805F9B34FC"))
+/// );
+/// ```
+pub fn short_form(uuid: &Uuid) -> Option<ShortUuid> {
+    if !is_base_uuid_alias(uuid) {
+        return None;
+    }
+    let value = uuid.as_fields().0;
+    if value <= u16::MAX as u32 {
+        Some(ShortUuid::Uuid16(value as u16))
+    } else {
+        Some(ShortUuid::Uuid32(value))
+    }
+}
+
 /// Trait for Assigned 16bit-UUID.
 pub trait Uuid16bit {
     /// Assigned 16bit-UUID
@@ -116,8 +344,8 @@ pub trait Uuid16bit {
 
 #[cfg(test)]
 mod tests {
-    use crate::{uuid_from_u16, uuid_from_u32};
-    use uuid::uuid;
+    use crate::{is_base_uuid_alias, short_form, uuid_from_u16, uuid_from_u32, ShortUuid};
+    use uuid::{uuid, Uuid};
 
     #[test]
     fn test_uuid_from_u16() {
@@ -134,4 +362,45 @@ mod tests {
             uuid_from_u32(0x12345678)
         );
     }
+
+    #[test]
+    fn test_short_uuid_into_uuid() {
+        assert_eq!(
+            uuid!("00001234-0000-1000-8000-00805F9B34FB"),
+            Uuid::from(ShortUuid::Uuid16(0x1234))
+        );
+        assert_eq!(
+            uuid!("12345678-0000-1000-8000-00805F9B34FB"),
+            Uuid::from(ShortUuid::Uuid32(0x12345678))
+        );
+    }
+
+    #[test]
+    fn test_is_base_uuid_alias() {
+        assert!(is_base_uuid_alias(&uuid!(
+            "00001234-0000-1000-8000-00805F9B34FB"
+        )));
+        assert!(is_base_uuid_alias(&uuid!(
+            "12345678-0000-1000-8000-00805F9B34FB"
+        )));
+        assert!(!is_base_uuid_alias(&uuid!(
+            "00001234-0000-1000-8000-00805F9B34FC"
+        )));
+    }
+
+    #[test]
+    fn test_short_form() {
+        assert_eq!(
+            Some(ShortUuid::Uuid16(0x1234)),
+            short_form(&uuid!("00001234-0000-1000-8000-00805F9B34FB"))
+        );
+        assert_eq!(
+            Some(ShortUuid::Uuid32(0x12345678)),
+            short_form(&uuid!("12345678-0000-1000-8000-00805F9B34FB"))
+        );
+        assert_eq!(
+            None,
+            short_form(&uuid!("00001234-0000-1000-8000-00805F9B34FC"))
+        );
+    }
 }
@@ -0,0 +1,391 @@
+//! uniffi (Kotlin / Swift) module.
+//!
+//! Interface definition is `src/ble_data_struct.udl`.
+
+use std::fmt::{Display, Formatter};
+
+use uuid::Uuid;
+
+use crate::{bluez::BlueZAdvertisement, data_types::data_type_parser::DataTypeParseResults};
+
+/// One AD structure of advertising payload.
+#[derive(Debug, PartialEq, Clone)]
+pub struct AdStructure {
+    /// Data type (AD type)
+    pub data_type: u8,
+
+    /// Offset of this AD structure in source payload
+    pub offset: u32,
+
+    /// Data (without length and data type)
+    pub data: Vec<u8>,
+
+    /// Parse error message
+    pub error: Option<String>,
+}
+
+/// Manufacturer Specific Data.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ManufacturerData {
+    /// Company Identifier
+    pub company_identifier: u16,
+
+    /// Manufacturer Specific Data
+    pub data: Vec<u8>,
+}
+
+/// Service Data.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ServiceData {
+    /// UUID
+    pub uuid: String,
+
+    /// Additional Service Data
+    pub data: Vec<u8>,
+}
+
+/// Advertisement summary, same content as [`BlueZAdvertisement`].
+#[derive(Debug, PartialEq, Clone)]
+pub struct Advertisement {
+    /// Manufacturer Specific Data
+    pub manufacturer_data: Vec<ManufacturerData>,
+
+    /// Service Data
+    pub service_data: Vec<ServiceData>,
+
+    /// Service UUIDs
+    pub service_uuids: Vec<String>,
+
+    /// Local Name
+    pub local_name: Option<String>,
+
+    /// Tx Power Level
+    pub tx_power: Option<i8>,
+}
+
+/// uniffi error.
+#[derive(Debug, PartialEq, Clone)]
+pub enum BleDataStructError {
+    /// Invalid input data
+    InvalidData {
+        /// Error message
+        message: String,
+    },
+}
+
+impl Display for BleDataStructError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BleDataStructError::InvalidData { message } => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for BleDataStructError {}
+
+/// Parse advertising payload into [`AdStructure`] list.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::{
+///     data_types::{data_type::DataType, tx_power_level::TxPowerLevel},
+///     mobile::{parse, AdStructure},
+/// };
+///
+/// let data: Vec<u8> = TxPowerLevel::new(-4).into();
+/// let result = parse(data);
+/// assert_eq!(
+///     vec![AdStructure {
+///         data_type: TxPowerLevel::data_type(),
+///         offset: 0,
+///         data: vec![0xfc],
+///         error: None,
+///     }],
+///     result
+/// );
+/// ```
+pub fn parse(payload: Vec<u8>) -> Vec<AdStructure> {
+    let results = DataTypeParseResults::from_payload_with_options(&payload, &Default::default());
+    results
+        .results
+        .iter()
+        .zip(results.offsets.iter())
+        .map(|(result, &offset)| {
+            let end = (offset + 1 + payload[offset] as usize).min(payload.len());
+            AdStructure {
+                data_type: payload.get(offset + 1).copied().unwrap_or_default(),
+                offset: offset as u32,
+                data: payload.get(offset + 2..end).unwrap_or_default().to_vec(),
+                error: result.error().cloned(),
+            }
+        })
+        .collect()
+}
+
+/// Create advertising payload from [`AdStructure`] list.
+///
+/// [`AdStructure::offset`] and [`AdStructure::error`] are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::{
+///     data_types::{data_type::DataType, tx_power_level::TxPowerLevel},
+///     mobile::{serialize, AdStructure},
+/// };
+///
+/// let structure = AdStructure {
+///     data_type: TxPowerLevel::data_type(),
+///     offset: 0,
+///     data: vec![0xfc],
+///     error: None,
+/// };
+/// let expected: Vec<u8> = TxPowerLevel::new(-4).into();
+/// assert_eq!(Ok(expected), serialize(vec![structure.clone()]));
+///
+/// let structure = AdStructure {
+///     data: vec![0; 255],
+///     ..structure
+/// };
+/// assert!(serialize(vec![structure]).is_err());
+/// ```
+pub fn serialize(structures: Vec<AdStructure>) -> Result<Vec<u8>, BleDataStructError> {
+    let mut payload: Vec<u8> = Vec::new();
+    for structure in structures {
+        if structure.data.len() > 254 {
+            return Err(BleDataStructError::InvalidData {
+                message: format!("Invalid data size :{}", structure.data.len()),
+            });
+        }
+        payload.push(1 + structure.data.len() as u8);
+        payload.push(structure.data_type);
+        payload.extend_from_slice(&structure.data);
+    }
+    Ok(payload)
+}
+
+/// Parse advertising payload into [`Advertisement`].
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::{
+///     data_types::complete_local_name::CompleteLocalName, mobile::parse_advertisement,
+/// };
+///
+/// let data: Vec<u8> = CompleteLocalName::new(&"name".to_string()).into();
+/// let result = parse_advertisement(data);
+/// assert_eq!(Some("name".to_string()), result.local_name);
+/// assert!(result.manufacturer_data.is_empty());
+/// ```
+pub fn parse_advertisement(payload: Vec<u8>) -> Advertisement {
+    let advertisement = BlueZAdvertisement::from(&DataTypeParseResults::from_payload_with_options(
+        &payload,
+        &Default::default(),
+    ));
+    Advertisement {
+        manufacturer_data: advertisement
+            .manufacturer_data
+            .into_iter()
+            .map(|(company_identifier, data)| ManufacturerData {
+                company_identifier,
+                data,
+            })
+            .collect(),
+        service_data: advertisement
+            .service_data
+            .into_iter()
+            .map(|(uuid, data)| ServiceData {
+                uuid: uuid.to_string(),
+                data,
+            })
+            .collect(),
+        service_uuids: advertisement
+            .service_uuids
+            .iter()
+            .map(|uuid| uuid.to_string())
+            .collect(),
+        local_name: advertisement.local_name,
+        tx_power: advertisement.tx_power,
+    }
+}
+
+/// Create advertising payload from [`Advertisement`].
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::{
+///     data_types::manufacturer_specific_data::ManufacturerSpecificData,
+///     mobile::{build_advertisement, Advertisement, ManufacturerData},
+/// };
+///
+/// let advertisement = Advertisement {
+///     manufacturer_data: vec![ManufacturerData {
+///         company_identifier: 0x004c,
+///         data: vec![0x01],
+///     }],
+///     service_data: Vec::new(),
+///     service_uuids: Vec::new(),
+///     local_name: None,
+///     tx_power: None,
+/// };
+/// let expected: Vec<u8> = ManufacturerSpecificData::new(0x004c, &vec![0x01]).into();
+/// assert_eq!(Ok(expected), build_advertisement(advertisement.clone()));
+///
+/// let advertisement = Advertisement {
+///     service_uuids: vec!["invalid".to_string()],
+///     ..advertisement
+/// };
+/// assert!(build_advertisement(advertisement).is_err());
+/// ```
+pub fn build_advertisement(advertisement: Advertisement) -> Result<Vec<u8>, BleDataStructError> {
+    let parse_uuid = |uuid: &str| {
+        Uuid::parse_str(uuid).map_err(|_| BleDataStructError::InvalidData {
+            message: format!("Invalid UUID :{}", uuid),
+        })
+    };
+    let mut value = BlueZAdvertisement {
+        local_name: advertisement.local_name,
+        tx_power: advertisement.tx_power,
+        ..Default::default()
+    };
+    for manufacturer_data in advertisement.manufacturer_data {
+        value
+            .manufacturer_data
+            .insert(manufacturer_data.company_identifier, manufacturer_data.data);
+    }
+    for service_data in advertisement.service_data {
+        value
+            .service_data
+            .insert(parse_uuid(&service_data.uuid)?, service_data.data);
+    }
+    for uuid in &advertisement.service_uuids {
+        value.service_uuids.push(parse_uuid(uuid)?);
+    }
+    Ok((&value).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data_types::{
+            complete_local_name::CompleteLocalName, data_type::DataType,
+            manufacturer_specific_data::ManufacturerSpecificData,
+            service_data_16bit_uuid::ServiceData16BitUUID,
+        },
+        mobile::{
+            build_advertisement, parse, parse_advertisement, serialize, AdStructure, Advertisement,
+            BleDataStructError, ManufacturerData, ServiceData,
+        },
+        uuid_from_u16,
+    };
+
+    #[test]
+    fn test_parse() {
+        let mut data: Vec<u8> = CompleteLocalName::new(&"name".to_string()).into();
+        data.append(&mut vec![0x01, 0xfe]);
+        data.append(&mut vec![0x03, 0x09, 0x00]);
+        let result = parse(data.clone());
+        assert_eq!(
+            vec![
+                AdStructure {
+                    data_type: CompleteLocalName::data_type(),
+                    offset: 0,
+                    data: b"name".to_vec(),
+                    error: None,
+                },
+                AdStructure {
+                    data_type: 0xfe,
+                    offset: 6,
+                    data: Vec::new(),
+                    error: Some("Unknown data type :254".to_string()),
+                },
+                AdStructure {
+                    data_type: 0x09,
+                    offset: 8,
+                    data: vec![0x00],
+                    error: Some("Invalid data size :3".to_string()),
+                },
+            ],
+            result
+        );
+        assert!(parse(Vec::new()).is_empty());
+    }
+
+    #[test]
+    fn test_serialize() {
+        let mut data: Vec<u8> = CompleteLocalName::new(&"name".to_string()).into();
+        data.append(&mut vec![0x01, 0xfe]);
+        assert_eq!(Ok(data.clone()), serialize(parse(data)));
+        assert_eq!(Ok(Vec::new()), serialize(Vec::new()));
+
+        let result = serialize(vec![AdStructure {
+            data_type: 0xff,
+            offset: 0,
+            data: vec![0; 255],
+            error: None,
+        }]);
+        assert_eq!(
+            Err(BleDataStructError::InvalidData {
+                message: "Invalid data size :255".to_string()
+            }),
+            result
+        );
+    }
+
+    #[test]
+    fn test_parse_advertisement() {
+        let mut data: Vec<u8> = CompleteLocalName::new(&"name".to_string()).into();
+        data.append(&mut ServiceData16BitUUID::new(&uuid_from_u16(0x180f), &vec![0x64]).into());
+        data.append(&mut ManufacturerSpecificData::new(0x004c, &vec![0x01]).into());
+        let result = parse_advertisement(data.clone());
+        assert_eq!(
+            Advertisement {
+                manufacturer_data: vec![ManufacturerData {
+                    company_identifier: 0x004c,
+                    data: vec![0x01],
+                }],
+                service_data: vec![ServiceData {
+                    uuid: "0000180f-0000-1000-8000-00805f9b34fb".to_string(),
+                    data: vec![0x64],
+                }],
+                service_uuids: Vec::new(),
+                local_name: Some("name".to_string()),
+                tx_power: None,
+            },
+            result
+        );
+        assert_eq!(Ok(data), build_advertisement(result));
+    }
+
+    #[test]
+    fn test_build_advertisement() {
+        let advertisement = Advertisement {
+            manufacturer_data: Vec::new(),
+            service_data: Vec::new(),
+            service_uuids: vec!["0000180f-0000-1000-8000-00805f9b34fb".to_string()],
+            local_name: None,
+            tx_power: Some(-4),
+        };
+        assert_eq!(
+            Ok(vec![0x03, 0x03, 0x0f, 0x18, 0x02, 0x0a, 0xfc]),
+            build_advertisement(advertisement.clone())
+        );
+
+        let result = build_advertisement(Advertisement {
+            service_data: vec![ServiceData {
+                uuid: "180f".to_string(),
+                data: Vec::new(),
+            }],
+            ..advertisement
+        });
+        assert_eq!(
+            Err(BleDataStructError::InvalidData {
+                message: "Invalid UUID :180f".to_string()
+            }),
+            result
+        );
+    }
+}
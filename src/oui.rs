@@ -0,0 +1,94 @@
+//! IEEE Organizationally Unique Identifier (MAC address prefix) vendor module.
+
+/// OUI vendor names (commonly seen in BLE devices).
+const OUI_NAMES: [(u32, &str); 30] = [
+    (0x00025b, "Cambridge Silicon Radio"),
+    (0x000393, "Apple, Inc."),
+    (0x0009bf, "Nintendo Co., Ltd."),
+    (0x000a95, "Apple, Inc."),
+    (0x000b57, "Silicon Laboratories"),
+    (0x001018, "Broadcom"),
+    (0x00124b, "Texas Instruments"),
+    (0x0017ab, "Nintendo Co., Ltd."),
+    (0x001b63, "Apple, Inc."),
+    (0x002500, "Apple, Inc."),
+    (0x0050f2, "Microsoft Corp."),
+    (0x00a050, "Cypress Semiconductor"),
+    (0x0452c7, "Bose Corporation"),
+    (0x240ac4, "Espressif Inc."),
+    (0x246f28, "Espressif Inc."),
+    (0x30aea4, "Espressif Inc."),
+    (0x3c5ab4, "Google, Inc."),
+    (0x3c71bf, "Espressif Inc."),
+    (0x546c0e, "Texas Instruments"),
+    (0x80eaca, "Dialog Semiconductor Hellas SA"),
+    (0x842e14, "Silicon Laboratories"),
+    (0x84f3eb, "Espressif Inc."),
+    (0x90fd9f, "Silicon Laboratories"),
+    (0xa4c138, "Telink Semiconductor (Taipei) Co. Ltd."),
+    (0xa4cf12, "Espressif Inc."),
+    (0xb0b448, "Texas Instruments"),
+    (0xb827eb, "Raspberry Pi Foundation"),
+    (0xdca632, "Raspberry Pi Trading Ltd"),
+    (0xe45f01, "Raspberry Pi Trading Ltd"),
+    (0xf4f5d8, "Google, Inc."),
+];
+
+/// Get vendor name of OUI (24bit).
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::oui::oui_vendor_name;
+///
+/// assert_eq!(Some("Raspberry Pi Foundation"), oui_vendor_name(0xb827eb));
+/// assert_eq!(None, oui_vendor_name(0x000000));
+/// ```
+pub fn oui_vendor_name(oui: u32) -> Option<&'static str> {
+    OUI_NAMES
+        .binary_search_by_key(&oui, |&(key, _)| key)
+        .ok()
+        .map(|index| OUI_NAMES[index].1)
+}
+
+/// Get vendor name of 48bit public device address.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::oui::vendor_name;
+///
+/// assert_eq!(Some("Raspberry Pi Foundation"), vendor_name(0x0000b827eb010203));
+/// assert_eq!(None, vendor_name(0x0000000000010203));
+/// ```
+pub fn vendor_name(address: u64) -> Option<&'static str> {
+    oui_vendor_name(((address >> 24) & 0xffffff) as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::oui::*;
+
+    #[test]
+    fn test_oui_names_sorted() {
+        assert!(OUI_NAMES.windows(2).all(|w| w[0].0 < w[1].0));
+        assert!(OUI_NAMES.iter().all(|(oui, _)| *oui <= 0xffffff));
+    }
+
+    #[test]
+    fn test_oui_vendor_name() {
+        assert_eq!(Some("Cambridge Silicon Radio"), oui_vendor_name(0x00025b));
+        assert_eq!(Some("Apple, Inc."), oui_vendor_name(0x000393));
+        assert_eq!(Some("Espressif Inc."), oui_vendor_name(0x240ac4));
+        assert_eq!(Some("Google, Inc."), oui_vendor_name(0xf4f5d8));
+        assert_eq!(None, oui_vendor_name(0x000001));
+        assert_eq!(None, oui_vendor_name(0xffffff));
+    }
+
+    #[test]
+    fn test_vendor_name() {
+        assert_eq!(Some("Texas Instruments"), vendor_name(0x0000546c0e000000));
+        assert_eq!(Some("Texas Instruments"), vendor_name(0xffff546c0effffff));
+        assert_eq!(None, vendor_name(0x0000000000546c0e));
+    }
+}
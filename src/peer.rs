@@ -0,0 +1,590 @@
+//! Peer aggregation module.
+
+use std::{
+    collections::{BTreeMap, VecDeque},
+    time::Instant,
+};
+
+use crate::{
+    bluez::BlueZAdvertisement,
+    data_types::{data_type_parser::DataTypeParseResults, parse_options::ParseOptions},
+    rssi::{proximity_zone, ExponentialMovingAverage, ProximityZone, RssiFilter},
+};
+
+/// Default RSSI history size of [`DeviceCache`].
+pub const DEFAULT_RSSI_HISTORY_SIZE: usize = 16;
+
+/// Advertising report type.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ReportType {
+    /// Advertising data (ADV_IND, ADV_NONCONN_IND, ADV_EXT_IND, ...)
+    Advertising,
+
+    /// Scan response data (SCAN_RSP, AUX_SCAN_RSP)
+    ScanResponse,
+}
+
+/// Advertisements of one peer accumulated over time.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Peer {
+    /// Last advertising data
+    pub advertising_data: Vec<u8>,
+
+    /// Last scan response data
+    pub scan_response_data: Vec<u8>,
+
+    /// First report timestamp
+    pub first_seen: Instant,
+
+    /// Last report timestamp
+    pub last_seen: Instant,
+
+    /// RSSI history (timestamp, RSSI), oldest first
+    pub rssi_history: VecDeque<(Instant, i8)>,
+
+    /// Number of reports
+    pub report_count: usize,
+}
+
+impl Peer {
+    /// Create [`Peer`] without reports.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::peer::Peer;
+    /// use std::time::Instant;
+    ///
+    /// let now = Instant::now();
+    /// let result = Peer::new(now);
+    /// assert!(result.advertising_data.is_empty());
+    /// assert!(result.scan_response_data.is_empty());
+    /// assert_eq!(now, result.first_seen);
+    /// assert_eq!(now, result.last_seen);
+    /// assert!(result.rssi_history.is_empty());
+    /// assert_eq!(0, result.report_count);
+    /// ```
+    pub fn new(timestamp: Instant) -> Self {
+        Self {
+            advertising_data: Vec::new(),
+            scan_response_data: Vec::new(),
+            first_seen: timestamp,
+            last_seen: timestamp,
+            rssi_history: VecDeque::new(),
+            report_count: 0,
+        }
+    }
+
+    /// Add report, keeping at most `rssi_history_size` RSSI values.
+    ///
+    /// `rssi` is [`None`] if not available (HCI value `127`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::peer::{Peer, ReportType};
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let now = Instant::now();
+    /// let mut peer = Peer::new(now);
+    /// peer.update(ReportType::Advertising, &[0x02, 0x01, 0x06], Some(-60), now, 2);
+    /// peer.update(ReportType::ScanResponse, &[0x02, 0x0a, 0x00], None, now + Duration::from_secs(1), 2);
+    /// assert_eq!(vec![0x02, 0x01, 0x06], peer.advertising_data);
+    /// assert_eq!(vec![0x02, 0x0a, 0x00], peer.scan_response_data);
+    /// assert_eq!(now + Duration::from_secs(1), peer.last_seen);
+    /// assert_eq!(Some(-60), peer.last_rssi());
+    /// assert_eq!(2, peer.report_count);
+    /// ```
+    pub fn update(
+        &mut self,
+        report_type: ReportType,
+        payload: &[u8],
+        rssi: Option<i8>,
+        timestamp: Instant,
+        rssi_history_size: usize,
+    ) {
+        let data = match report_type {
+            ReportType::Advertising => &mut self.advertising_data,
+            ReportType::ScanResponse => &mut self.scan_response_data,
+        };
+        data.clear();
+        data.extend_from_slice(payload);
+        self.last_seen = self.last_seen.max(timestamp);
+        if let Some(rssi) = rssi {
+            self.rssi_history.push_back((timestamp, rssi));
+            while self.rssi_history.len() > rssi_history_size {
+                self.rssi_history.pop_front();
+            }
+        }
+        self.report_count += 1;
+    }
+
+    /// Get last RSSI.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::peer::{Peer, ReportType};
+    /// use std::time::Instant;
+    ///
+    /// let now = Instant::now();
+    /// let mut peer = Peer::new(now);
+    /// assert_eq!(None, peer.last_rssi());
+    ///
+    /// peer.update(ReportType::Advertising, &[], Some(-70), now, 16);
+    /// assert_eq!(Some(-70), peer.last_rssi());
+    /// ```
+    pub fn last_rssi(&self) -> Option<i8> {
+        self.rssi_history.back().map(|(_, rssi)| *rssi)
+    }
+
+    /// Get RSSI history filtered with [`RssiFilter`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     peer::{Peer, ReportType},
+    ///     rssi::{ExponentialMovingAverage, KalmanFilter},
+    /// };
+    /// use std::time::Instant;
+    ///
+    /// let now = Instant::now();
+    /// let mut peer = Peer::new(now);
+    /// assert_eq!(None, peer.smoothed_rssi(KalmanFilter::default()));
+    ///
+    /// peer.update(ReportType::Advertising, &[], Some(-60), now, 16);
+    /// peer.update(ReportType::Advertising, &[], Some(-80), now, 16);
+    /// assert_eq!(Some(-70.0), peer.smoothed_rssi(ExponentialMovingAverage::new(0.5)));
+    /// ```
+    pub fn smoothed_rssi<F: RssiFilter>(&self, mut filter: F) -> Option<f64> {
+        for (_, rssi) in &self.rssi_history {
+            filter.update(*rssi);
+        }
+        filter.value()
+    }
+
+    /// Get [`ProximityZone`] from advertised Tx Power Level and RSSI history smoothed with
+    /// default [`ExponentialMovingAverage`].
+    ///
+    /// Returns [`None`] if Tx Power Level is not advertised or no RSSI was reported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     peer::{Peer, ReportType},
+    ///     rssi::ProximityZone,
+    /// };
+    /// use std::time::Instant;
+    ///
+    /// let now = Instant::now();
+    /// let mut peer = Peer::new(now);
+    /// peer.update(ReportType::Advertising, &[0x02, 0x0a, 0x00], None, now, 16);
+    /// assert_eq!(None, peer.proximity_zone());
+    ///
+    /// peer.update(ReportType::Advertising, &[0x02, 0x0a, 0x00], Some(-80), now, 16);
+    /// assert_eq!(Some(ProximityZone::Far), peer.proximity_zone());
+    /// ```
+    pub fn proximity_zone(&self) -> Option<ProximityZone> {
+        let tx_power = self.advertisement().tx_power?;
+        let rssi = self.smoothed_rssi(ExponentialMovingAverage::default())?;
+        Some(proximity_zone(tx_power, rssi))
+    }
+
+    /// Parse advertising data and scan response data into one [`DataTypeParseResults`].
+    ///
+    /// Offsets of scan response data start after advertising data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::{
+    ///         complete_local_name::CompleteLocalName, flags::Flags, parse_options::ParseOptions,
+    ///     },
+    ///     peer::{Peer, ReportType},
+    /// };
+    /// use std::time::Instant;
+    ///
+    /// let now = Instant::now();
+    /// let mut peer = Peer::new(now);
+    /// let data: Vec<u8> = Flags::new(&vec![false, true]).into();
+    /// peer.update(ReportType::Advertising, &data, None, now, 16);
+    /// let data: Vec<u8> = CompleteLocalName::new(&"name".to_string()).into();
+    /// peer.update(ReportType::ScanResponse, &data, None, now, 16);
+    ///
+    /// let results = peer.results(&ParseOptions::default());
+    /// assert_eq!(2, results.results.len());
+    /// assert_eq!(&[0, 3], results.offsets());
+    /// assert_eq!(Some("name"), results.device_name());
+    /// ```
+    pub fn results(&self, options: &ParseOptions) -> DataTypeParseResults {
+        let mut results =
+            DataTypeParseResults::from_payload_with_options(&self.advertising_data, options);
+        let scan_response =
+            DataTypeParseResults::from_payload_with_options(&self.scan_response_data, options);
+        let base = self.advertising_data.len();
+        results.results.extend(scan_response.results);
+        results
+            .offsets
+            .extend(scan_response.offsets.iter().map(|offset| base + offset));
+        results
+    }
+
+    /// Get unified [`BlueZAdvertisement`] of advertising data and scan response data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::manufacturer_specific_data::ManufacturerSpecificData,
+    ///     peer::{Peer, ReportType},
+    /// };
+    /// use std::time::Instant;
+    ///
+    /// let now = Instant::now();
+    /// let mut peer = Peer::new(now);
+    /// peer.update(ReportType::Advertising, &[0x02, 0x0a, 0xfc], None, now, 16);
+    /// let data: Vec<u8> = ManufacturerSpecificData::new(0x004c, &vec![0x01]).into();
+    /// peer.update(ReportType::ScanResponse, &data, None, now, 16);
+    ///
+    /// let advertisement = peer.advertisement();
+    /// assert_eq!(Some(-4), advertisement.tx_power);
+    /// assert_eq!(Some(&vec![0x01]), advertisement.manufacturer_data.get(&0x004c));
+    /// ```
+    pub fn advertisement(&self) -> BlueZAdvertisement {
+        BlueZAdvertisement::from(&self.results(&ParseOptions::default()))
+    }
+}
+
+/// Peers keyed by address.
+///
+/// Address type is up to the caller (`u64`, `[u8; 6]`, `String`, ...).
+#[derive(Debug, PartialEq, Clone)]
+pub struct DeviceCache<A> {
+    peers: BTreeMap<A, Peer>,
+
+    /// RSSI history size of each peer
+    pub rssi_history_size: usize,
+}
+
+impl<A: Ord> DeviceCache<A> {
+    /// Create empty [`DeviceCache`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::peer::DeviceCache;
+    ///
+    /// let result: DeviceCache<u64> = DeviceCache::new(8);
+    /// assert_eq!(8, result.rssi_history_size);
+    /// assert!(result.is_empty());
+    /// ```
+    pub fn new(rssi_history_size: usize) -> Self {
+        Self {
+            peers: BTreeMap::new(),
+            rssi_history_size,
+        }
+    }
+
+    /// Add report of address, and return updated [`Peer`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::peer::{DeviceCache, ReportType};
+    /// use std::time::Instant;
+    ///
+    /// let now = Instant::now();
+    /// let mut cache: DeviceCache<[u8; 6]> = DeviceCache::default();
+    /// let address = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06];
+    /// cache.update(address, ReportType::Advertising, &[0x02, 0x0a, 0x00], Some(-50), now);
+    /// let peer = cache.update(address, ReportType::Advertising, &[0x02, 0x0a, 0x04], Some(-52), now);
+    /// assert_eq!(2, peer.report_count);
+    /// assert_eq!(Some(4), peer.advertisement().tx_power);
+    /// assert_eq!(1, cache.len());
+    /// ```
+    pub fn update(
+        &mut self,
+        address: A,
+        report_type: ReportType,
+        payload: &[u8],
+        rssi: Option<i8>,
+        timestamp: Instant,
+    ) -> &Peer {
+        let peer = self
+            .peers
+            .entry(address)
+            .or_insert_with(|| Peer::new(timestamp));
+        peer.update(
+            report_type,
+            payload,
+            rssi,
+            timestamp,
+            self.rssi_history_size,
+        );
+        peer
+    }
+
+    /// Get [`Peer`] of address.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::peer::{DeviceCache, ReportType};
+    /// use std::time::Instant;
+    ///
+    /// let mut cache: DeviceCache<u64> = DeviceCache::default();
+    /// cache.update(1, ReportType::Advertising, &[], None, Instant::now());
+    /// assert!(cache.get(&1).is_some());
+    /// assert!(cache.get(&2).is_none());
+    /// ```
+    pub fn get(&self, address: &A) -> Option<&Peer> {
+        self.peers.get(address)
+    }
+
+    /// Get all peers in address order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::peer::{DeviceCache, ReportType};
+    /// use std::time::Instant;
+    ///
+    /// let now = Instant::now();
+    /// let mut cache: DeviceCache<u64> = DeviceCache::default();
+    /// cache.update(2, ReportType::Advertising, &[], None, now);
+    /// cache.update(1, ReportType::Advertising, &[], None, now);
+    /// let addresses: Vec<&u64> = cache.peers().map(|(address, _)| address).collect();
+    /// assert_eq!(vec![&1, &2], addresses);
+    /// ```
+    pub fn peers(&self) -> impl Iterator<Item = (&A, &Peer)> {
+        self.peers.iter()
+    }
+
+    /// Remove peers last seen before `timestamp`, and return number of removed peers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::peer::{DeviceCache, ReportType};
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let now = Instant::now();
+    /// let mut cache: DeviceCache<u64> = DeviceCache::default();
+    /// cache.update(1, ReportType::Advertising, &[], None, now);
+    /// cache.update(2, ReportType::Advertising, &[], None, now + Duration::from_secs(10));
+    /// assert_eq!(1, cache.remove_older_than(now + Duration::from_secs(5)));
+    /// assert!(cache.get(&1).is_none());
+    /// assert!(cache.get(&2).is_some());
+    /// ```
+    pub fn remove_older_than(&mut self, timestamp: Instant) -> usize {
+        let len = self.peers.len();
+        self.peers.retain(|_, peer| peer.last_seen >= timestamp);
+        len - self.peers.len()
+    }
+
+    /// Get number of peers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::peer::DeviceCache;
+    ///
+    /// let cache: DeviceCache<u64> = DeviceCache::default();
+    /// assert_eq!(0, cache.len());
+    /// ```
+    pub fn len(&self) -> usize {
+        self.peers.len()
+    }
+
+    /// check no peers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::peer::DeviceCache;
+    ///
+    /// let cache: DeviceCache<u64> = DeviceCache::default();
+    /// assert!(cache.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.peers.is_empty()
+    }
+}
+
+impl<A: Ord> Default for DeviceCache<A> {
+    /// Create empty [`DeviceCache`] with [`DEFAULT_RSSI_HISTORY_SIZE`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::peer::{DeviceCache, DEFAULT_RSSI_HISTORY_SIZE};
+    ///
+    /// let result: DeviceCache<u64> = DeviceCache::default();
+    /// assert_eq!(DEFAULT_RSSI_HISTORY_SIZE, result.rssi_history_size);
+    /// ```
+    fn default() -> Self {
+        Self::new(DEFAULT_RSSI_HISTORY_SIZE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use crate::{
+        data_types::{
+            complete_local_name::CompleteLocalName, data_type_parser::DataTypeParseResult,
+            parse_options::ParseOptions, shortened_local_name::ShortenedLocalName,
+        },
+        peer::{DeviceCache, Peer, ReportType},
+        rssi::{ExponentialMovingAverage, KalmanFilter, ProximityZone},
+    };
+
+    #[test]
+    fn test_peer_update() {
+        let now = Instant::now();
+        let mut peer = Peer::new(now);
+        for index in 0..5 {
+            peer.update(
+                ReportType::Advertising,
+                &[0x02, 0x0a, index],
+                Some(-(index as i8)),
+                now + Duration::from_secs(index as u64),
+                3,
+            );
+        }
+        assert_eq!(vec![0x02, 0x0a, 0x04], peer.advertising_data);
+        assert!(peer.scan_response_data.is_empty());
+        assert_eq!(now, peer.first_seen);
+        assert_eq!(now + Duration::from_secs(4), peer.last_seen);
+        assert_eq!(
+            vec![-2, -3, -4],
+            peer.rssi_history
+                .iter()
+                .map(|(_, rssi)| *rssi)
+                .collect::<Vec<i8>>()
+        );
+        assert_eq!(5, peer.report_count);
+
+        // out of order report does not move last_seen back
+        peer.update(ReportType::ScanResponse, &[], None, now, 3);
+        assert_eq!(now + Duration::from_secs(4), peer.last_seen);
+        assert_eq!(3, peer.rssi_history.len());
+        assert_eq!(6, peer.report_count);
+    }
+
+    #[test]
+    fn test_peer_results() {
+        let now = Instant::now();
+        let mut peer = Peer::new(now);
+        assert!(peer.results(&ParseOptions::default()).results.is_empty());
+
+        // truncated advertising data does not consume scan response data
+        peer.update(
+            ReportType::Advertising,
+            &[0x02, 0x0a, 0x00, 0x05, 0x08],
+            None,
+            now,
+            16,
+        );
+        let mut data: Vec<u8> = CompleteLocalName::new(&"complete".to_string()).into();
+        data.push(0x00);
+        peer.update(ReportType::ScanResponse, &data, None, now, 16);
+        let results = peer.results(&ParseOptions::default());
+        assert_eq!(vec![0, 3, 5], results.offsets);
+        assert!(matches!(
+            results.results[1],
+            DataTypeParseResult::DataTypeParseError(_)
+        ));
+        assert_eq!(Some("complete"), results.device_name());
+
+        let data: Vec<u8> = ShortenedLocalName::new(&"short".to_string()).into();
+        peer.update(ReportType::Advertising, &data, None, now, 16);
+        let advertisement = peer.advertisement();
+        assert_eq!(Some("complete".to_string()), advertisement.local_name);
+        assert_eq!(None, advertisement.tx_power);
+    }
+
+    #[test]
+    fn test_peer_smoothed_rssi() {
+        let now = Instant::now();
+        let mut peer = Peer::new(now);
+        assert_eq!(
+            None,
+            peer.smoothed_rssi(ExponentialMovingAverage::default())
+        );
+        for rssi in [-40, -60, -40, -60] {
+            peer.update(ReportType::Advertising, &[], Some(rssi), now, 16);
+        }
+        assert_eq!(
+            Some(-60.0),
+            peer.smoothed_rssi(ExponentialMovingAverage::new(1.0))
+        );
+        let value = peer.smoothed_rssi(KalmanFilter::default()).unwrap();
+        assert!(-60.0 < value && value < -40.0);
+    }
+
+    #[test]
+    fn test_peer_proximity_zone() {
+        let now = Instant::now();
+        let mut peer = Peer::new(now);
+        assert_eq!(None, peer.proximity_zone());
+
+        peer.update(ReportType::Advertising, &[], Some(-30), now, 16);
+        assert_eq!(None, peer.proximity_zone());
+
+        // Tx Power Level in scan response
+        peer.update(ReportType::ScanResponse, &[0x02, 0x0a, 0x00], None, now, 16);
+        assert_eq!(Some(ProximityZone::Immediate), peer.proximity_zone());
+
+        peer.update(ReportType::Advertising, &[], Some(-60), now, 16);
+        assert_eq!(Some(ProximityZone::Near), peer.proximity_zone());
+    }
+
+    #[test]
+    fn test_device_cache() {
+        let now = Instant::now();
+        let mut cache: DeviceCache<String> = DeviceCache::new(1);
+        assert!(cache.is_empty());
+
+        cache.update(
+            "a".to_string(),
+            ReportType::Advertising,
+            &[],
+            Some(-10),
+            now,
+        );
+        cache.update(
+            "b".to_string(),
+            ReportType::Advertising,
+            &[],
+            Some(-20),
+            now + Duration::from_secs(1),
+        );
+        let peer = cache.update(
+            "a".to_string(),
+            ReportType::ScanResponse,
+            &[],
+            Some(-30),
+            now + Duration::from_secs(2),
+        );
+        assert_eq!(1, peer.rssi_history.len());
+        assert_eq!(Some(-30), peer.last_rssi());
+        assert_eq!(2, cache.len());
+
+        assert_eq!(0, cache.remove_older_than(now));
+        assert_eq!(1, cache.remove_older_than(now + Duration::from_secs(2)));
+        assert_eq!(
+            vec!["a"],
+            cache
+                .peers()
+                .map(|(address, _)| address.as_str())
+                .collect::<Vec<&str>>()
+        );
+        assert_eq!(1, cache.remove_older_than(now + Duration::from_secs(3)));
+        assert!(cache.is_empty());
+    }
+}
@@ -0,0 +1,306 @@
+//! Wireshark style text dump module.
+//!
+//! ```text
+//! Advertising Data (10 bytes)
+//!     [0] Flags
+//!         Length: 2
+//!         Type: Flags (0x01)
+//!         Raw: 020106
+//!         LE Limited Discoverable Mode: false
+//!         LE General Discoverable Mode: true
+//!         BR/EDR Not Supported: true
+//!         Simultaneous LE and BR/EDR (Controller): false
+//!         Simultaneous LE and BR/EDR (Host): false
+//!     [3] Tx Power Level
+//!         Length: 2
+//!         Type: Tx Power Level (0x0a)
+//!         Raw: 020afc
+//!         Power Level: -4 dBm
+//!     ...
+//! ```
+
+use std::fmt::Write;
+
+use crate::data_types::{
+    ad_type::AdType,
+    data_type_parser::{DataTypeParseResult, DataTypeParseResults},
+    parse_options::ParseOptions,
+};
+
+/// Indent of one tree level.
+pub const INDENT: &str = "    ";
+
+/// Dump advertising payload as indented multi-line text.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::pretty::pretty;
+///
+/// let result = pretty(&[0x02, 0x0a, 0xfc]);
+/// assert_eq!(
+///     "Advertising Data (3 bytes)\n    [0] Tx Power Level\n        Length: 2\n        Type: Tx Power Level (0x0a)\n        Raw: 020afc\n        Power Level: -4 dBm\n",
+///     result
+/// );
+/// ```
+pub fn pretty(payload: &[u8]) -> String {
+    pretty_with_options(payload, &ParseOptions::default())
+}
+
+/// Dump advertising payload as indented multi-line text with [`ParseOptions`].
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::{
+///     data_types::parse_options::{ParseOptions, Utf8DecodeMode},
+///     pretty::pretty_with_options,
+/// };
+///
+/// let result = pretty_with_options(
+///     &[0x03, 0x09, 0x61, 0xff],
+///     &ParseOptions::new(Utf8DecodeMode::Lossy),
+/// );
+/// assert!(result.contains("        Name: a\u{fffd}\n"));
+/// ```
+pub fn pretty_with_options(payload: &[u8], options: &ParseOptions) -> String {
+    let results = DataTypeParseResults::from_payload_with_options(payload, options);
+    let mut text = format!("Advertising Data ({} bytes)\n", payload.len());
+    for (result, offset) in results.results.iter().zip(results.offsets.iter()) {
+        let length = payload[*offset];
+        let end = (offset + 1 + length as usize).min(payload.len());
+        let structure = &payload[*offset..end];
+        let ad_type = structure.get(1).map(|data_type| AdType::of(*data_type));
+        let name = ad_type.map(|ad_type| ad_type.name()).unwrap_or("Unknown");
+        let _ = writeln!(text, "{}[{}] {}", INDENT, offset, name);
+
+        let indent = INDENT.repeat(2);
+        let _ = writeln!(text, "{}Length: {}", indent, length);
+        if let Some(ad_type) = ad_type {
+            let _ = writeln!(text, "{}Type: {} (0x{:02x})", indent, name, ad_type.value());
+        }
+        let _ = writeln!(text, "{}Raw: {}", indent, to_hex(structure));
+        for (field, value) in fields(result) {
+            let _ = writeln!(text, "{}{}: {}", indent, field, value);
+        }
+    }
+    text
+}
+
+/// Get decoded field names and values.
+fn fields(result: &DataTypeParseResult) -> Vec<(&'static str, String)> {
+    if let Some(error) = result.error() {
+        return vec![("Error", error.clone())];
+    }
+    match result {
+        DataTypeParseResult::FlagsResult(Ok(data_type)) => vec![
+            (
+                "LE Limited Discoverable Mode",
+                data_type.is_le_limited_discoverable().to_string(),
+            ),
+            (
+                "LE General Discoverable Mode",
+                data_type.is_le_general_discoverable().to_string(),
+            ),
+            (
+                "BR/EDR Not Supported",
+                data_type.is_br_edr_not_supported().to_string(),
+            ),
+            (
+                "Simultaneous LE and BR/EDR (Controller)",
+                data_type.is_simultaneous_controller().to_string(),
+            ),
+            (
+                "Simultaneous LE and BR/EDR (Host)",
+                data_type.is_simultaneous_host().to_string(),
+            ),
+        ],
+        DataTypeParseResult::IncompleteListOf16BitServiceUuidsResult(Ok(data_type)) => {
+            uuids(&data_type.uuids)
+        }
+        DataTypeParseResult::CompleteListOf16BitServiceUuidsResult(Ok(data_type)) => {
+            uuids(&data_type.uuids)
+        }
+        DataTypeParseResult::IncompleteListOf32BitServiceUuidsResult(Ok(data_type)) => {
+            uuids(&data_type.uuids)
+        }
+        DataTypeParseResult::CompleteListOf32BitServiceUuidsResult(Ok(data_type)) => {
+            uuids(&data_type.uuids)
+        }
+        DataTypeParseResult::IncompleteListOf128BitServiceUuidsResult(Ok(data_type)) => {
+            uuids(&data_type.uuids)
+        }
+        DataTypeParseResult::CompleteListOf128BitServiceUuidsResult(Ok(data_type)) => {
+            uuids(&data_type.uuids)
+        }
+        DataTypeParseResult::ListOf16BitServiceSolicitationUUIDsResult(Ok(data_type)) => {
+            uuids(&data_type.uuids)
+        }
+        DataTypeParseResult::ListOf32BitServiceSolicitationUUIDsResult(Ok(data_type)) => {
+            uuids(&data_type.uuids)
+        }
+        DataTypeParseResult::ListOf128BitServiceSolicitationUUIDsResult(Ok(data_type)) => {
+            uuids(&data_type.uuids)
+        }
+        DataTypeParseResult::ShortenedLocalNameResult(Ok(data_type)) => {
+            vec![("Name", data_type.shortened_local_name.clone())]
+        }
+        DataTypeParseResult::CompleteLocalNameResult(Ok(data_type)) => {
+            vec![("Name", data_type.complete_local_name.clone())]
+        }
+        DataTypeParseResult::TxPowerLevelResult(Ok(data_type)) => {
+            vec![("Power Level", data_type.to_string())]
+        }
+        DataTypeParseResult::ClassOfDeviceResult(Ok(data_type)) => {
+            vec![("Class of Device", data_type.to_string())]
+        }
+        DataTypeParseResult::PeripheralConnectionIntervalRangeResult(Ok(data_type)) => vec![
+            (
+                "Minimum",
+                format!("{} ms", data_type.minimum_value_millis()),
+            ),
+            (
+                "Maximum",
+                format!("{} ms", data_type.maximum_value_millis()),
+            ),
+        ],
+        DataTypeParseResult::AppearanceResult(Ok(data_type)) => {
+            vec![("Appearance", data_type.to_string())]
+        }
+        DataTypeParseResult::AdvertisingIntervalResult(Ok(data_type)) => vec![(
+            "Advertising Interval",
+            format!("{} ms", data_type.advertising_interval_millis()),
+        )],
+        DataTypeParseResult::AdvertisingIntervalLongResult(Ok(data_type)) => vec![(
+            "Advertising Interval",
+            format!("{} ms", data_type.advertising_interval_long_millis()),
+        )],
+        DataTypeParseResult::LeRoleResult(Ok(data_type)) => {
+            vec![("LE Role", format!("0x{:02x}", data_type.le_role))]
+        }
+        DataTypeParseResult::ServiceData16BitUUIDResult(Ok(data_type)) => vec![
+            ("UUID", data_type.uuid.to_string()),
+            ("Data", to_hex(&data_type.additional_service_data)),
+        ],
+        DataTypeParseResult::ServiceData32BitUUIDResult(Ok(data_type)) => vec![
+            ("UUID", data_type.uuid.to_string()),
+            ("Data", to_hex(&data_type.additional_service_data)),
+        ],
+        DataTypeParseResult::ServiceData128BitUUIDResult(Ok(data_type)) => vec![
+            ("UUID", data_type.uuid.to_string()),
+            ("Data", to_hex(&data_type.additional_service_data)),
+        ],
+        DataTypeParseResult::UniformResourceIdentifierResult(Ok(data_type)) => vec![(
+            "URI",
+            data_type
+                .uri()
+                .unwrap_or_else(|| data_type.remainder().to_string()),
+        )],
+        DataTypeParseResult::ManufacturerSpecificDataResult(Ok(data_type)) => vec![
+            (
+                "Company ID",
+                format!("0x{:04x}", data_type.company_identifier),
+            ),
+            ("Data", to_hex(&data_type.manufacturer_specific_data)),
+        ],
+        _ => Vec::new(),
+    }
+}
+
+fn uuids(uuids: &[uuid::Uuid]) -> Vec<(&'static str, String)> {
+    uuids
+        .iter()
+        .map(|uuid| ("UUID", uuid.to_string()))
+        .collect()
+}
+
+fn to_hex(value: &[u8]) -> String {
+    value.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data_types::{
+            appearance::Appearance, complete_list_of_16bit_service_uuids::*,
+            service_data_16bit_uuid::ServiceData16BitUUID,
+            uniform_resource_identifier::UniformResourceIdentifier,
+        },
+        pretty::pretty,
+        uuid_from_u16,
+    };
+
+    #[test]
+    fn test_pretty_flags() {
+        let result = pretty(&[0x02, 0x01, 0x06]);
+        assert_eq!(
+            concat!(
+                "Advertising Data (3 bytes)\n",
+                "    [0] Flags\n",
+                "        Length: 2\n",
+                "        Type: Flags (0x01)\n",
+                "        Raw: 020106\n",
+                "        LE Limited Discoverable Mode: false\n",
+                "        LE General Discoverable Mode: true\n",
+                "        BR/EDR Not Supported: true\n",
+                "        Simultaneous LE and BR/EDR (Controller): false\n",
+                "        Simultaneous LE and BR/EDR (Host): false\n",
+            ),
+            result
+        );
+    }
+
+    #[test]
+    fn test_pretty_fields() {
+        let mut data: Vec<u8> = vec![0x04, 0xff, 0x4c, 0x00, 0x01];
+        data.append(
+            &mut CompleteListOf16BitServiceUuids::new(&vec![
+                uuid_from_u16(0x180f),
+                uuid_from_u16(0x180a),
+            ])
+            .into(),
+        );
+        data.append(&mut Appearance::new(0x0341).into());
+        data.append(&mut ServiceData16BitUUID::new(&uuid_from_u16(0x180f), &vec![0x64]).into());
+        data.append(
+            &mut UniformResourceIdentifier::from_uri("https://example.com")
+                .unwrap()
+                .into(),
+        );
+        let result = pretty(&data);
+        assert!(result.starts_with(&format!("Advertising Data ({} bytes)\n", data.len())));
+        assert!(result.contains("    [0] Manufacturer Specific Data\n"));
+        assert!(result.contains("        Company ID: 0x004c\n        Data: 01\n"));
+        assert!(result.contains("    [5] Complete List of 16-bit Service Class UUIDs\n"));
+        assert!(result.contains(concat!(
+            "        UUID: 0000180f-0000-1000-8000-00805f9b34fb\n",
+            "        UUID: 0000180a-0000-1000-8000-00805f9b34fb\n"
+        )));
+        assert!(result.contains("        Appearance: Heart Rate Sensor: Heart Rate Belt\n"));
+        assert!(result.contains("        Raw: 04160f1864\n"));
+        assert!(result.contains("        URI: https://example.com\n"));
+    }
+
+    #[test]
+    fn test_pretty_error() {
+        let result = pretty(&[0x01, 0x0a, 0x02, 0x00, 0x00, 0x05, 0x09]);
+        assert_eq!(
+            concat!(
+                "Advertising Data (7 bytes)\n",
+                "    [0] Tx Power Level\n",
+                "        Length: 1\n",
+                "        Type: Tx Power Level (0x0a)\n",
+                "        Raw: 010a\n",
+                "        Error: Invalid data size :2\n",
+                "    [2] Unknown\n",
+                "        Length: 2\n",
+                "        Type: Unknown (0x00)\n",
+                "        Raw: 020000\n",
+                "        Error: Unknown data type :0\n",
+            ),
+            &result[..result.find("    [5]").unwrap_or(result.len())]
+        );
+
+        assert_eq!("Advertising Data (0 bytes)\n", pretty(&[]));
+    }
+}
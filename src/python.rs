@@ -0,0 +1,412 @@
+//! PyO3 (Python) module.
+//!
+//! Build the extension module with `maturin` (enabling the `python` feature), then:
+//!
+//! ```python
+//! import ble_data_struct
+//!
+//! for data_type in ble_data_struct.parse(bytes.fromhex("020106 03030f18")):
+//!     print(data_type["data_type"], data_type["value"])
+//! ```
+
+use pyo3::{
+    prelude::*,
+    types::{PyBytes, PyDict, PyList},
+};
+use uuid::Uuid;
+
+use crate::data_types::data_type_parser::{DataTypeParseResult, DataTypeParseResults};
+
+/// Flags.
+#[pyclass(
+    name = "Flags",
+    module = "ble_data_struct",
+    frozen,
+    get_all,
+    from_py_object
+)]
+#[derive(Debug, PartialEq, Clone)]
+pub struct PyFlags {
+    /// Flags bits
+    pub flags: u8,
+}
+
+/// Appearance.
+#[pyclass(
+    name = "Appearance",
+    module = "ble_data_struct",
+    frozen,
+    get_all,
+    from_py_object
+)]
+#[derive(Debug, PartialEq, Clone)]
+pub struct PyAppearance {
+    /// Appearance
+    pub appearance: u16,
+}
+
+/// Tx Power Level.
+#[pyclass(
+    name = "TxPowerLevel",
+    module = "ble_data_struct",
+    frozen,
+    get_all,
+    from_py_object
+)]
+#[derive(Debug, PartialEq, Clone)]
+pub struct PyTxPowerLevel {
+    /// Tx Power Level (dBm)
+    pub tx_power_level: i8,
+}
+
+/// Complete Local Name.
+#[pyclass(
+    name = "CompleteLocalName",
+    module = "ble_data_struct",
+    frozen,
+    get_all,
+    from_py_object
+)]
+#[derive(Debug, PartialEq, Clone)]
+pub struct PyCompleteLocalName {
+    /// Complete Local Name
+    pub complete_local_name: String,
+}
+
+/// Shortened Local Name.
+#[pyclass(
+    name = "ShortenedLocalName",
+    module = "ble_data_struct",
+    frozen,
+    get_all,
+    from_py_object
+)]
+#[derive(Debug, PartialEq, Clone)]
+pub struct PyShortenedLocalName {
+    /// Shortened Local Name
+    pub shortened_local_name: String,
+}
+
+/// Manufacturer Specific Data.
+#[pyclass(
+    name = "ManufacturerSpecificData",
+    module = "ble_data_struct",
+    frozen,
+    get_all,
+    from_py_object
+)]
+#[derive(Debug, PartialEq, Clone)]
+pub struct PyManufacturerSpecificData {
+    /// Company Identifier
+    pub company_identifier: u16,
+
+    /// Manufacturer Specific Data
+    pub manufacturer_specific_data: Vec<u8>,
+}
+
+/// Service Data (16-bit, 32-bit and 128-bit UUID).
+#[pyclass(
+    name = "ServiceData",
+    module = "ble_data_struct",
+    frozen,
+    get_all,
+    from_py_object
+)]
+#[derive(Debug, PartialEq, Clone)]
+pub struct PyServiceData {
+    /// UUID
+    pub uuid: String,
+
+    /// Additional Service Data
+    pub additional_service_data: Vec<u8>,
+}
+
+/// Complete / Incomplete List of 16-bit, 32-bit and 128-bit Service Class UUIDs.
+#[pyclass(
+    name = "ServiceUuids",
+    module = "ble_data_struct",
+    frozen,
+    get_all,
+    from_py_object
+)]
+#[derive(Debug, PartialEq, Clone)]
+pub struct PyServiceUuids {
+    /// UUIDs
+    pub uuids: Vec<String>,
+
+    /// `True` if complete list
+    pub is_complete: bool,
+}
+
+/// Parse advertising payload into `list[dict]`.
+///
+/// Each dict has `data_type` (`int`), `offset` (`int`), `data` (`bytes`, without length and data
+/// type), `error` (`str` or `None`) and `value` (typed class of main data types, or `None`).
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::{data_types::tx_power_level::TxPowerLevel, python::parse};
+/// use pyo3::{prelude::*, types::PyBytes};
+///
+/// let data: Vec<u8> = TxPowerLevel::new(-4).into();
+/// Python::initialize();
+/// Python::attach(|py| {
+///     let result = parse(py, &data).unwrap();
+///     assert_eq!(1, result.len());
+///     let value = result.get_item(0).unwrap().get_item("value").unwrap();
+///     let tx_power_level: i8 = value.getattr("tx_power_level").unwrap().extract().unwrap();
+///     assert_eq!(-4, tx_power_level);
+/// });
+/// ```
+#[pyfunction]
+pub fn parse<'py>(py: Python<'py>, data: &[u8]) -> PyResult<Bound<'py, PyList>> {
+    let results = DataTypeParseResults::from_payload_with_options(data, &Default::default());
+    let list = PyList::empty(py);
+    for (index, result) in results.results.iter().enumerate() {
+        let offset = results.offsets[index];
+        let end = (offset + 1 + data[offset] as usize).min(data.len());
+        let dict = PyDict::new(py);
+        dict.set_item("data_type", data.get(offset + 1))?;
+        dict.set_item("offset", offset)?;
+        dict.set_item(
+            "data",
+            PyBytes::new(py, data.get(offset + 2..end).unwrap_or_default()),
+        )?;
+        dict.set_item("error", result.error())?;
+        dict.set_item("value", to_value(py, result)?)?;
+        list.append(dict)?;
+    }
+    Ok(list)
+}
+
+fn to_value<'py>(
+    py: Python<'py>,
+    result: &DataTypeParseResult,
+) -> PyResult<Option<Bound<'py, PyAny>>> {
+    let service_uuids = |uuids: &[Uuid], is_complete: bool| PyServiceUuids {
+        uuids: uuids.iter().map(|uuid| uuid.to_string()).collect(),
+        is_complete,
+    };
+    let service_data = |uuid: &Uuid, additional_service_data: &[u8]| PyServiceData {
+        uuid: uuid.to_string(),
+        additional_service_data: additional_service_data.to_vec(),
+    };
+    let value = match result {
+        DataTypeParseResult::FlagsResult(Ok(data_type)) => Bound::new(
+            py,
+            PyFlags {
+                flags: data_type.bits(),
+            },
+        )?
+        .into_any(),
+        DataTypeParseResult::AppearanceResult(Ok(data_type)) => Bound::new(
+            py,
+            PyAppearance {
+                appearance: data_type.appearance,
+            },
+        )?
+        .into_any(),
+        DataTypeParseResult::TxPowerLevelResult(Ok(data_type)) => Bound::new(
+            py,
+            PyTxPowerLevel {
+                tx_power_level: data_type.tx_power_level,
+            },
+        )?
+        .into_any(),
+        DataTypeParseResult::CompleteLocalNameResult(Ok(data_type)) => Bound::new(
+            py,
+            PyCompleteLocalName {
+                complete_local_name: data_type.complete_local_name.clone(),
+            },
+        )?
+        .into_any(),
+        DataTypeParseResult::ShortenedLocalNameResult(Ok(data_type)) => Bound::new(
+            py,
+            PyShortenedLocalName {
+                shortened_local_name: data_type.shortened_local_name.clone(),
+            },
+        )?
+        .into_any(),
+        DataTypeParseResult::ManufacturerSpecificDataResult(Ok(data_type)) => Bound::new(
+            py,
+            PyManufacturerSpecificData {
+                company_identifier: data_type.company_identifier,
+                manufacturer_specific_data: data_type.manufacturer_specific_data.clone(),
+            },
+        )?
+        .into_any(),
+        DataTypeParseResult::ServiceData16BitUUIDResult(Ok(data_type)) => Bound::new(
+            py,
+            service_data(&data_type.uuid, &data_type.additional_service_data),
+        )?
+        .into_any(),
+        DataTypeParseResult::ServiceData32BitUUIDResult(Ok(data_type)) => Bound::new(
+            py,
+            service_data(&data_type.uuid, &data_type.additional_service_data),
+        )?
+        .into_any(),
+        DataTypeParseResult::ServiceData128BitUUIDResult(Ok(data_type)) => Bound::new(
+            py,
+            service_data(&data_type.uuid, &data_type.additional_service_data),
+        )?
+        .into_any(),
+        DataTypeParseResult::IncompleteListOf16BitServiceUuidsResult(Ok(data_type)) => {
+            Bound::new(py, service_uuids(&data_type.uuids, false))?.into_any()
+        }
+        DataTypeParseResult::CompleteListOf16BitServiceUuidsResult(Ok(data_type)) => {
+            Bound::new(py, service_uuids(&data_type.uuids, true))?.into_any()
+        }
+        DataTypeParseResult::IncompleteListOf32BitServiceUuidsResult(Ok(data_type)) => {
+            Bound::new(py, service_uuids(&data_type.uuids, false))?.into_any()
+        }
+        DataTypeParseResult::CompleteListOf32BitServiceUuidsResult(Ok(data_type)) => {
+            Bound::new(py, service_uuids(&data_type.uuids, true))?.into_any()
+        }
+        DataTypeParseResult::IncompleteListOf128BitServiceUuidsResult(Ok(data_type)) => {
+            Bound::new(py, service_uuids(&data_type.uuids, false))?.into_any()
+        }
+        DataTypeParseResult::CompleteListOf128BitServiceUuidsResult(Ok(data_type)) => {
+            Bound::new(py, service_uuids(&data_type.uuids, true))?.into_any()
+        }
+        _ => return Ok(None),
+    };
+    Ok(Some(value))
+}
+
+/// Python module `ble_data_struct`.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::python::ble_data_struct;
+/// use pyo3::{prelude::*, types::PyModule};
+///
+/// Python::initialize();
+/// Python::attach(|py| {
+///     let module = PyModule::new(py, "ble_data_struct").unwrap();
+///     ble_data_struct(&module).unwrap();
+///     assert!(module.hasattr("parse").unwrap());
+///     assert!(module.hasattr("TxPowerLevel").unwrap());
+/// });
+/// ```
+#[pymodule]
+pub fn ble_data_struct(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_function(wrap_pyfunction!(parse, module)?)?;
+    module.add_class::<PyFlags>()?;
+    module.add_class::<PyAppearance>()?;
+    module.add_class::<PyTxPowerLevel>()?;
+    module.add_class::<PyCompleteLocalName>()?;
+    module.add_class::<PyShortenedLocalName>()?;
+    module.add_class::<PyManufacturerSpecificData>()?;
+    module.add_class::<PyServiceData>()?;
+    module.add_class::<PyServiceUuids>()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use pyo3::{prelude::*, types::PyModule};
+
+    use crate::{
+        data_types::{
+            complete_list_of_16bit_service_uuids::CompleteListOf16BitServiceUuids,
+            complete_local_name::CompleteLocalName, data_type::DataType, flags::Flags,
+            manufacturer_specific_data::ManufacturerSpecificData,
+            service_data_16bit_uuid::ServiceData16BitUUID,
+        },
+        python::{
+            ble_data_struct, parse, PyCompleteLocalName, PyFlags, PyManufacturerSpecificData,
+            PyServiceData, PyServiceUuids,
+        },
+        uuid_from_u16,
+    };
+
+    #[test]
+    fn test_parse() {
+        let mut data: Vec<u8> = Flags::from_bits(0x06).into();
+        data.append(&mut CompleteListOf16BitServiceUuids::new(&vec![uuid_from_u16(0x180f)]).into());
+        data.append(&mut CompleteLocalName::new(&"name".to_string()).into());
+        data.append(&mut ServiceData16BitUUID::new(&uuid_from_u16(0x180f), &vec![0x64]).into());
+        data.append(&mut ManufacturerSpecificData::new(0x004c, &vec![0x01, 0x02]).into());
+        data.append(&mut vec![0x02, 0x0a]);
+
+        Python::initialize();
+        Python::attach(|py| {
+            let result = parse(py, &data).unwrap();
+            assert_eq!(6, result.len());
+            let item =
+                |index: usize, key: &str| result.get_item(index).unwrap().get_item(key).unwrap();
+
+            assert_eq!(
+                Flags::data_type(),
+                item(0, "data_type").extract::<u8>().unwrap()
+            );
+            assert_eq!(0, item(0, "offset").extract::<usize>().unwrap());
+            assert_eq!(vec![0x06], item(0, "data").extract::<Vec<u8>>().unwrap());
+            assert!(item(0, "error").is_none());
+            assert_eq!(
+                PyFlags { flags: 0x06 },
+                item(0, "value").extract::<PyFlags>().unwrap()
+            );
+            assert_eq!(
+                PyServiceUuids {
+                    uuids: vec![uuid_from_u16(0x180f).to_string()],
+                    is_complete: true
+                },
+                item(1, "value").extract::<PyServiceUuids>().unwrap()
+            );
+            assert_eq!(
+                PyCompleteLocalName {
+                    complete_local_name: "name".to_string()
+                },
+                item(2, "value").extract::<PyCompleteLocalName>().unwrap()
+            );
+            assert_eq!(
+                PyServiceData {
+                    uuid: uuid_from_u16(0x180f).to_string(),
+                    additional_service_data: vec![0x64]
+                },
+                item(3, "value").extract::<PyServiceData>().unwrap()
+            );
+            assert_eq!(
+                PyManufacturerSpecificData {
+                    company_identifier: 0x004c,
+                    manufacturer_specific_data: vec![0x01, 0x02]
+                },
+                item(4, "value")
+                    .extract::<PyManufacturerSpecificData>()
+                    .unwrap()
+            );
+            assert_eq!(
+                "Invalid data size :2",
+                item(5, "error").extract::<String>().unwrap()
+            );
+            assert!(item(5, "value").is_none());
+
+            let result = parse(py, &[]).unwrap();
+            assert!(result.is_empty());
+        });
+    }
+
+    #[test]
+    fn test_module() {
+        let data: Vec<u8> = CompleteLocalName::new(&"name".to_string()).into();
+        Python::initialize();
+        Python::attach(|py| {
+            let module = PyModule::new(py, "ble_data_struct").unwrap();
+            ble_data_struct(&module).unwrap();
+            let locals = pyo3::types::PyDict::new(py);
+            locals.set_item("ble_data_struct", module).unwrap();
+            locals.set_item("data", data).unwrap();
+            let result = py
+                .eval(
+                    c"ble_data_struct.parse(bytes(data))[0]['value'].complete_local_name",
+                    None,
+                    Some(&locals),
+                )
+                .unwrap();
+            assert_eq!("name", result.extract::<String>().unwrap());
+        });
+    }
+}
@@ -0,0 +1,868 @@
+//! btsnoop / scan log replay module.
+//!
+//! Reads recorded advertising reports as [`ScanRecord`] list, to replay them through the parser.
+//!
+//! - btsnoop file (`btmon -w`, Android HCI snoop log): LE Advertising Report and LE Extended
+//!   Advertising Report events. Extended report fragments are joined by [`ReportReassembler`].
+//! - scan log text: one `timestamp address rssi payload` line per advertising report, `-` for
+//!   unknown address or RSSI, `#` comments. Payload may be omitted for empty payload, and
+//!   `truncated` follows the payload of truncated report.
+//!
+//! ```text
+//! # timestamp address rssi payload
+//! 1700000000000000 01:02:03:04:05:06 -60 020106020afc
+//! 1700000000100000 - - 020106
+//! 1700000000200000 - - 020106 truncated
+//! ```
+
+use std::{collections::HashMap, fmt};
+
+use crate::data_types::{
+    data_type_parser::DataTypeParseResults, lint::EXTENDED_ADVERTISING_DATA_MAX,
+    parse_options::ParseOptions,
+};
+
+/// btsnoop datalink type: HCI un-encapsulated
+pub const BTSNOOP_HCI_UNENCAPSULATED: u32 = 1001;
+
+/// btsnoop datalink type: HCI UART (H4)
+pub const BTSNOOP_HCI_UART: u32 = 1002;
+
+/// btsnoop datalink type: Linux monitor (`btmon -w`)
+pub const BTSNOOP_LINUX_MONITOR: u32 = 2001;
+
+/// Linux monitor opcode: Event packet
+pub const MONITOR_EVENT_PACKET: u16 = 0x0003;
+
+/// btsnoop timestamp (microseconds from 0000-01-01) of Unix epoch, same as BlueZ.
+pub const BTSNOOP_UNIX_EPOCH: u64 = 0x00e0_3ab4_4a67_6000 - 946_684_800_000_000;
+
+/// LE Extended Advertising Report Data_Status: Complete
+pub const DATA_STATUS_COMPLETE: u8 = 0b00;
+
+/// LE Extended Advertising Report Data_Status: Incomplete, more data to come
+pub const DATA_STATUS_INCOMPLETE_MORE: u8 = 0b01;
+
+/// LE Extended Advertising Report Data_Status: Incomplete, data truncated, no more to come
+pub const DATA_STATUS_INCOMPLETE_TRUNCATED: u8 = 0b10;
+
+/// One received advertising payload.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct ScanRecord {
+    /// Receive time (microseconds)
+    pub timestamp: u64,
+
+    /// Advertiser address
+    pub address: Option<String>,
+
+    /// RSSI (dBm)
+    pub rssi: Option<i8>,
+
+    /// Advertising payload
+    pub payload: Vec<u8>,
+
+    /// `true` if the controller truncated the payload
+    pub truncated: bool,
+}
+
+impl ScanRecord {
+    /// Create [`ScanRecord`] from Parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::replay::ScanRecord;
+    ///
+    /// let result = ScanRecord::new(1, Some("01:02:03:04:05:06"), Some(-60), &[0x02, 0x01, 0x06]);
+    /// assert_eq!(1, result.timestamp);
+    /// assert_eq!(Some("01:02:03:04:05:06".to_string()), result.address);
+    /// assert_eq!(Some(-60), result.rssi);
+    /// assert_eq!(vec![0x02, 0x01, 0x06], result.payload);
+    /// assert!(!result.truncated);
+    /// ```
+    pub fn new(timestamp: u64, address: Option<&str>, rssi: Option<i8>, payload: &[u8]) -> Self {
+        Self {
+            timestamp,
+            address: address.map(|address| address.to_string()),
+            rssi,
+            payload: payload.to_vec(),
+            truncated: false,
+        }
+    }
+
+    /// Parse advertising payload.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::replay::ScanRecord;
+    ///
+    /// let result = ScanRecord::new(0, None, None, &[0x05, 0x09, 0x74, 0x65, 0x6d, 0x70]);
+    /// assert_eq!(Some("temp"), result.results().device_name());
+    /// ```
+    pub fn results(&self) -> DataTypeParseResults {
+        DataTypeParseResults::from_payload_with_options(&self.payload, &ParseOptions::default())
+    }
+}
+
+impl fmt::Display for ScanRecord {
+    /// Format [`ScanRecord`] as scan log line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::replay::ScanRecord;
+    ///
+    /// let result = ScanRecord::new(1, Some("01:02:03:04:05:06"), Some(-60), &[0x02, 0x01, 0x06]);
+    /// assert_eq!("1 01:02:03:04:05:06 -60 020106", result.to_string());
+    ///
+    /// let result = ScanRecord::new(2, None, None, &[]);
+    /// assert_eq!("2 - - ", result.to_string());
+    ///
+    /// let mut result = ScanRecord::new(3, None, None, &[0x02, 0x01]);
+    /// result.truncated = true;
+    /// assert_eq!("3 - - 0201 truncated", result.to_string());
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} {}",
+            self.timestamp,
+            self.address.as_deref().unwrap_or("-"),
+            self.rssi
+                .map(|rssi| rssi.to_string())
+                .unwrap_or("-".to_string()),
+            to_hex(&self.payload)
+        )?;
+        if self.truncated {
+            write!(f, " truncated")?;
+        }
+        Ok(())
+    }
+}
+
+/// Parse scan log text.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::replay::{parse_scan_log, ScanRecord};
+///
+/// let text = "
+/// ## timestamp address rssi payload
+/// 1 01:02:03:04:05:06 -60 020106
+/// 2 - - 020afc
+/// 3 - - 0201 truncated
+/// ";
+/// let result = parse_scan_log(text);
+/// let mut truncated = ScanRecord::new(3, None, None, &[0x02, 0x01]);
+/// truncated.truncated = true;
+/// assert_eq!(
+///     Ok(vec![
+///         ScanRecord::new(1, Some("01:02:03:04:05:06"), Some(-60), &[0x02, 0x01, 0x06]),
+///         ScanRecord::new(2, None, None, &[0x02, 0x0a, 0xfc]),
+///         truncated,
+///     ]),
+///     result
+/// );
+///
+/// let result = parse_scan_log("1 -");
+/// assert_eq!(Err("line 1 :Invalid line :1 -".to_string()), result);
+/// ```
+pub fn parse_scan_log(text: &str) -> Result<Vec<ScanRecord>, String> {
+    let mut records: Vec<ScanRecord> = Vec::new();
+    for (index, line) in text.lines().enumerate() {
+        let error = |message: String| format!("line {} :{}", index + 1, message);
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let mut fields: Vec<&str> = line.split_whitespace().collect();
+        let truncated = fields.len() > 3 && fields.last() == Some(&"truncated");
+        if truncated {
+            fields.pop();
+        }
+        let (timestamp, address, rssi, payload) = match fields[..] {
+            [timestamp, address, rssi, payload] => (timestamp, address, rssi, payload),
+            [timestamp, address, rssi] => (timestamp, address, rssi, ""),
+            _ => return Err(error(format!("Invalid line :{}", line))),
+        };
+        records.push(ScanRecord {
+            timestamp: timestamp
+                .parse()
+                .map_err(|_| error(format!("Invalid timestamp :{}", timestamp)))?,
+            address: match address {
+                "-" => None,
+                address => Some(address.to_string()),
+            },
+            rssi: match rssi {
+                "-" => None,
+                rssi => Some(
+                    rssi.parse()
+                        .map_err(|_| error(format!("Invalid rssi :{}", rssi)))?,
+                ),
+            },
+            payload: parse_hex(payload).map_err(error)?,
+            truncated,
+        });
+    }
+    Ok(records)
+}
+
+/// Read advertising reports from btsnoop file content.
+///
+/// Returns btsnoop record number (1-based) and [`ScanRecord`] of each advertising report.
+/// Timestamp is converted to microseconds from Unix epoch. Fragments of LE Extended Advertising
+/// Report are joined, and returned with the record number of the last fragment.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::replay::{read_btsnoop, ScanRecord, BTSNOOP_UNIX_EPOCH};
+///
+/// // LE Advertising Report event
+/// let event: Vec<u8> = vec![
+///     0x3e, 0x0f, 0x02, 0x01, 0x00, 0x00, 0x06, 0x05, 0x04, 0x03, 0x02, 0x01, 0x03, 0x02, 0x01,
+///     0x06, 0xc4,
+/// ];
+/// let mut data: Vec<u8> = b"btsnoop\0".to_vec();
+/// data.extend_from_slice(&1u32.to_be_bytes());
+/// data.extend_from_slice(&1001u32.to_be_bytes());
+/// data.extend_from_slice(&(event.len() as u32).to_be_bytes());
+/// data.extend_from_slice(&(event.len() as u32).to_be_bytes());
+/// data.extend_from_slice(&0x03u32.to_be_bytes());
+/// data.extend_from_slice(&0u32.to_be_bytes());
+/// data.extend_from_slice(&(BTSNOOP_UNIX_EPOCH + 1).to_be_bytes());
+/// data.extend_from_slice(&event);
+///
+/// let result = read_btsnoop(&data);
+/// assert_eq!(
+///     Ok(vec![(
+///         1,
+///         ScanRecord::new(1, Some("01:02:03:04:05:06"), Some(-60), &[0x02, 0x01, 0x06])
+///     )]),
+///     result
+/// );
+///
+/// assert_eq!(Err("Invalid btsnoop header".to_string()), read_btsnoop(b"snoop"));
+/// ```
+pub fn read_btsnoop(data: &[u8]) -> Result<Vec<(usize, ScanRecord)>, String> {
+    if data.len() < 16 || &data[0..8] != b"btsnoop\0" {
+        return Err("Invalid btsnoop header".to_string());
+    }
+    let datalink = u32::from_be_bytes([data[12], data[13], data[14], data[15]]);
+    if ![
+        BTSNOOP_HCI_UNENCAPSULATED,
+        BTSNOOP_HCI_UART,
+        BTSNOOP_LINUX_MONITOR,
+    ]
+    .contains(&datalink)
+    {
+        return Err(format!("Unsupported datalink type :{}", datalink));
+    }
+
+    let mut records: Vec<(usize, ScanRecord)> = Vec::new();
+    let mut reassembler = ReportReassembler::new();
+    let mut index = 16;
+    let mut record = 0;
+    while index + 24 <= data.len() {
+        record += 1;
+        let header = &data[index..index + 24];
+        let included_length =
+            u32::from_be_bytes([header[4], header[5], header[6], header[7]]) as usize;
+        let flags = u32::from_be_bytes([header[8], header[9], header[10], header[11]]);
+        let timestamp = u64::from_be_bytes([
+            header[16], header[17], header[18], header[19], header[20], header[21], header[22],
+            header[23],
+        ]);
+        index += 24;
+        if index + included_length > data.len() {
+            return Err(format!("Truncated record :{}", record));
+        }
+        let packet = &data[index..index + included_length];
+        index += included_length;
+
+        let event = match datalink {
+            BTSNOOP_HCI_UART => packet.strip_prefix(&[0x04]),
+            // flags: adapter index (upper 16 bit) and opcode (lower 16 bit)
+            BTSNOOP_LINUX_MONITOR if flags as u16 == MONITOR_EVENT_PACKET => Some(packet),
+            // flags bit 1: command / event
+            BTSNOOP_HCI_UNENCAPSULATED if flags & 0b10 != 0 => Some(packet),
+            _ => None,
+        };
+        if let Some(event) = event {
+            for report in advertising_reports(event) {
+                if let Some(report) = reassembler.push(report) {
+                    records.push((
+                        record,
+                        ScanRecord {
+                            timestamp: timestamp.saturating_sub(BTSNOOP_UNIX_EPOCH),
+                            truncated: report.is_truncated(),
+                            address: Some(report.address),
+                            rssi: Some(report.rssi),
+                            payload: report.data,
+                        },
+                    ));
+                }
+            }
+        }
+    }
+    Ok(records)
+}
+
+/// One advertising report of LE Advertising Report / LE Extended Advertising Report event.
+#[derive(Debug, PartialEq, Clone)]
+pub struct AdvertisingReport {
+    /// Advertiser address
+    pub address: String,
+
+    /// RSSI (dBm)
+    pub rssi: i8,
+
+    /// Advertising_SID (`None` for LE Advertising Report or no ADI)
+    pub sid: Option<u8>,
+
+    /// Data_Status ([`DATA_STATUS_COMPLETE`] for LE Advertising Report)
+    pub data_status: u8,
+
+    /// Advertising data
+    pub data: Vec<u8>,
+}
+
+impl AdvertisingReport {
+    /// check data is truncated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::replay::{
+    ///     AdvertisingReport, DATA_STATUS_COMPLETE, DATA_STATUS_INCOMPLETE_TRUNCATED,
+    /// };
+    ///
+    /// let mut result = AdvertisingReport {
+    ///     address: "01:02:03:04:05:06".to_string(),
+    ///     rssi: -60,
+    ///     sid: None,
+    ///     data_status: DATA_STATUS_COMPLETE,
+    ///     data: vec![0x02, 0x01, 0x06],
+    /// };
+    /// assert!(!result.is_truncated());
+    ///
+    /// result.data_status = DATA_STATUS_INCOMPLETE_TRUNCATED;
+    /// assert!(result.is_truncated());
+    /// ```
+    pub fn is_truncated(&self) -> bool {
+        self.data_status == DATA_STATUS_INCOMPLETE_TRUNCATED
+    }
+}
+
+/// Get [`AdvertisingReport`] list from LE Advertising Report / LE Extended Advertising Report
+/// event.
+///
+/// Reports are returned as received, use [`ReportReassembler`] to join the fragments of LE
+/// Extended Advertising Report.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::replay::{advertising_reports, AdvertisingReport, DATA_STATUS_COMPLETE};
+///
+/// let event: Vec<u8> = vec![
+///     0x3e, 0x0f, 0x02, 0x01, 0x00, 0x00, 0x06, 0x05, 0x04, 0x03, 0x02, 0x01, 0x03, 0x02, 0x01,
+///     0x06, 0xc4,
+/// ];
+/// assert_eq!(
+///     vec![AdvertisingReport {
+///         address: "01:02:03:04:05:06".to_string(),
+///         rssi: -60,
+///         sid: None,
+///         data_status: DATA_STATUS_COMPLETE,
+///         data: vec![0x02, 0x01, 0x06],
+///     }],
+///     advertising_reports(&event)
+/// );
+///
+/// assert!(advertising_reports(&[0x0e, 0x04, 0x01, 0x00]).is_empty());
+/// ```
+pub fn advertising_reports(event: &[u8]) -> Vec<AdvertisingReport> {
+    let mut reports: Vec<AdvertisingReport> = Vec::new();
+    // LE Meta event
+    if event.len() < 4 || event[0] != 0x3e {
+        return reports;
+    }
+    let extended = match event[2] {
+        // LE Advertising Report: Event_Type, Address_Type, Address, Data_Length, Data, RSSI
+        0x02 => false,
+        // LE Extended Advertising Report: Event_Type(2), Address_Type, Address, Primary_PHY,
+        // Secondary_PHY, Advertising_SID, TX_Power, RSSI, ..., Data_Length, Data
+        0x0d => true,
+        _ => return reports,
+    };
+    let (address_offset, data_length_offset) = if extended { (3, 23) } else { (2, 8) };
+    let mut index = 4;
+    for _ in 0..event[3] {
+        if index + data_length_offset >= event.len() {
+            break;
+        }
+        let data_length = event[index + data_length_offset] as usize;
+        let data_start = index + data_length_offset + 1;
+        let end = data_start + data_length + if extended { 0 } else { 1 };
+        if end > event.len() {
+            break;
+        }
+        let address = event[index + address_offset..index + address_offset + 6]
+            .iter()
+            .rev()
+            .map(|byte| format!("{:02X}", byte))
+            .collect::<Vec<String>>()
+            .join(":");
+        let (rssi, sid, data_status) = if extended {
+            // Event_Type bit 5-6: Data_Status, Advertising_SID 0xff: no ADI
+            let sid = event[index + 11];
+            (
+                event[index + 13],
+                (sid != 0xff).then_some(sid),
+                (event[index] >> 5) & 0b11,
+            )
+        } else {
+            (event[end - 1], None, DATA_STATUS_COMPLETE)
+        };
+        reports.push(AdvertisingReport {
+            address,
+            rssi: rssi as i8,
+            sid,
+            data_status,
+            data: event[data_start..data_start + data_length].to_vec(),
+        });
+        index = end;
+    }
+    reports
+}
+
+/// Reassembler of LE Extended Advertising Report fragments.
+///
+/// Reports with Data_Status "Incomplete, more data to come" are kept per address and
+/// Advertising_SID, and joined with the following reports of the same advertiser.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct ReportReassembler {
+    pending: HashMap<(String, Option<u8>), Vec<u8>>,
+}
+
+impl ReportReassembler {
+    /// Create empty [`ReportReassembler`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::replay::ReportReassembler;
+    ///
+    /// let result = ReportReassembler::new();
+    /// assert!(!result.is_in_progress());
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// check any advertiser has pending fragments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::replay::{
+    ///     AdvertisingReport, ReportReassembler, DATA_STATUS_INCOMPLETE_MORE,
+    /// };
+    ///
+    /// let mut reassembler = ReportReassembler::new();
+    /// reassembler.push(AdvertisingReport {
+    ///     address: "01:02:03:04:05:06".to_string(),
+    ///     rssi: -60,
+    ///     sid: Some(1),
+    ///     data_status: DATA_STATUS_INCOMPLETE_MORE,
+    ///     data: vec![0x02, 0x01],
+    /// });
+    /// assert!(reassembler.is_in_progress());
+    /// ```
+    pub fn is_in_progress(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    /// Add [`AdvertisingReport`], and return the report with joined data after the last fragment.
+    ///
+    /// Returned report has [`DATA_STATUS_COMPLETE`], or [`DATA_STATUS_INCOMPLETE_TRUNCATED`] if the
+    /// controller truncated the data. Data exceeding 1650 octets is truncated to 1650 octets and
+    /// marked as truncated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::replay::{
+    ///     AdvertisingReport, ReportReassembler, DATA_STATUS_COMPLETE, DATA_STATUS_INCOMPLETE_MORE,
+    ///     DATA_STATUS_INCOMPLETE_TRUNCATED,
+    /// };
+    ///
+    /// let report = |data_status: u8, data: &[u8]| AdvertisingReport {
+    ///     address: "01:02:03:04:05:06".to_string(),
+    ///     rssi: -60,
+    ///     sid: Some(1),
+    ///     data_status,
+    ///     data: data.to_vec(),
+    /// };
+    /// let mut reassembler = ReportReassembler::new();
+    /// assert_eq!(None, reassembler.push(report(DATA_STATUS_INCOMPLETE_MORE, &[0x02, 0x01])));
+    /// assert_eq!(
+    ///     Some(report(DATA_STATUS_COMPLETE, &[0x02, 0x01, 0x06])),
+    ///     reassembler.push(report(DATA_STATUS_COMPLETE, &[0x06]))
+    /// );
+    ///
+    /// assert_eq!(None, reassembler.push(report(DATA_STATUS_INCOMPLETE_MORE, &[0x02, 0x01])));
+    /// let result = reassembler.push(report(DATA_STATUS_INCOMPLETE_TRUNCATED, &[]));
+    /// assert_eq!(Some(report(DATA_STATUS_INCOMPLETE_TRUNCATED, &[0x02, 0x01])), result);
+    /// assert!(!reassembler.is_in_progress());
+    /// ```
+    pub fn push(&mut self, report: AdvertisingReport) -> Option<AdvertisingReport> {
+        let key = (report.address.clone(), report.sid);
+        let mut data = self.pending.remove(&key).unwrap_or_default();
+        data.extend_from_slice(&report.data);
+        let mut data_status = report.data_status;
+        if data.len() > EXTENDED_ADVERTISING_DATA_MAX {
+            data.truncate(EXTENDED_ADVERTISING_DATA_MAX);
+            data_status = DATA_STATUS_INCOMPLETE_TRUNCATED;
+        } else if data_status == DATA_STATUS_INCOMPLETE_MORE {
+            self.pending.insert(key, data);
+            return None;
+        }
+        Some(AdvertisingReport {
+            data_status,
+            data,
+            ..report
+        })
+    }
+
+    /// Discard pending fragments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::replay::{
+    ///     AdvertisingReport, ReportReassembler, DATA_STATUS_INCOMPLETE_MORE,
+    /// };
+    ///
+    /// let mut reassembler = ReportReassembler::new();
+    /// reassembler.push(AdvertisingReport {
+    ///     address: "01:02:03:04:05:06".to_string(),
+    ///     rssi: -60,
+    ///     sid: Some(1),
+    ///     data_status: DATA_STATUS_INCOMPLETE_MORE,
+    ///     data: vec![0x02, 0x01],
+    /// });
+    /// reassembler.reset();
+    /// assert!(!reassembler.is_in_progress());
+    /// ```
+    pub fn reset(&mut self) {
+        self.pending.clear();
+    }
+}
+
+pub(crate) fn to_hex(value: &[u8]) -> String {
+    value.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+pub(crate) fn parse_hex(value: &str) -> Result<Vec<u8>, String> {
+    if !value.len().is_multiple_of(2) {
+        return Err(format!("Invalid hex length :{}", value.len()));
+    }
+    (0..value.len())
+        .step_by(2)
+        .map(|index| {
+            let pair = value.get(index..index + 2).unwrap_or_default();
+            u8::from_str_radix(pair, 16).map_err(|_| format!("Invalid hex :{}", pair))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::replay::*;
+
+    fn btsnoop(datalink: u32, packets: &[(u32, Vec<u8>)]) -> Vec<u8> {
+        let mut data: Vec<u8> = b"btsnoop\0".to_vec();
+        data.extend_from_slice(&1u32.to_be_bytes());
+        data.extend_from_slice(&datalink.to_be_bytes());
+        for (flags, packet) in packets {
+            data.extend_from_slice(&(packet.len() as u32).to_be_bytes());
+            data.extend_from_slice(&(packet.len() as u32).to_be_bytes());
+            data.extend_from_slice(&flags.to_be_bytes());
+            data.extend_from_slice(&0u32.to_be_bytes());
+            data.extend_from_slice(&(BTSNOOP_UNIX_EPOCH + 1_000_000).to_be_bytes());
+            data.extend_from_slice(packet);
+        }
+        data
+    }
+
+    fn le_advertising_report(payload: &[u8], rssi: i8) -> Vec<u8> {
+        let mut event: Vec<u8> = vec![0x3e, 0x00, 0x02, 0x01, 0x00, 0x00];
+        event.extend_from_slice(&[0x06, 0x05, 0x04, 0x03, 0x02, 0x01]);
+        event.push(payload.len() as u8);
+        event.extend_from_slice(payload);
+        event.push(rssi as u8);
+        event[1] = event.len() as u8 - 2;
+        event
+    }
+
+    fn le_extended_advertising_report(data_status: u8, sid: u8, payload: &[u8]) -> Vec<u8> {
+        let mut event: Vec<u8> = vec![0x3e, 0x00, 0x0d, 0x01, 0x13 | (data_status << 5), 0x00];
+        event.extend_from_slice(&[0x00, 0x06, 0x05, 0x04, 0x03, 0x02, 0x01]);
+        event.extend_from_slice(&[0x01, 0x00, sid, 0x7f, 0xc4, 0x00, 0x00, 0x00]);
+        event.extend_from_slice(&[0x00; 6]);
+        event.push(payload.len() as u8);
+        event.extend_from_slice(payload);
+        event[1] = event.len() as u8 - 2;
+        event
+    }
+
+    fn report(sid: Option<u8>, data_status: u8, data: &[u8]) -> AdvertisingReport {
+        AdvertisingReport {
+            address: "01:02:03:04:05:06".to_string(),
+            rssi: -60,
+            sid,
+            data_status,
+            data: data.to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_scan_record() {
+        let record = ScanRecord::new(3, Some("01:02:03:04:05:06"), Some(-1), &[0x02, 0x0a, 0xfc]);
+        assert_eq!("3 01:02:03:04:05:06 -1 020afc", record.to_string());
+        assert_eq!(1, record.results().results.len());
+        assert_eq!(
+            Ok(vec![record.clone()]),
+            parse_scan_log(&record.to_string())
+        );
+
+        let record = ScanRecord::default();
+        assert_eq!("0 - - ", record.to_string());
+        assert_eq!(
+            Ok(vec![record.clone()]),
+            parse_scan_log(&record.to_string())
+        );
+
+        let mut record = ScanRecord::new(4, None, Some(-1), &[]);
+        record.truncated = true;
+        assert_eq!("4 - -1  truncated", record.to_string());
+        assert_eq!(
+            Ok(vec![record.clone()]),
+            parse_scan_log(&record.to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_scan_log() {
+        let errors = [
+            ("1", "line 1 :Invalid line :1"),
+            ("1 - - 00 00", "line 1 :Invalid line :1 - - 00 00"),
+            ("a - - 00", "line 1 :Invalid timestamp :a"),
+            ("1 - 128 00", "line 1 :Invalid rssi :128"),
+            ("\n1 - - 0", "line 2 :Invalid hex length :1"),
+            ("1 - - 0g", "line 1 :Invalid hex :0g"),
+            ("1 - truncated", "line 1 :Invalid rssi :truncated"),
+            (
+                "1 - - 00 truncated 00",
+                "line 1 :Invalid line :1 - - 00 truncated 00",
+            ),
+        ];
+        for (text, error) in errors {
+            assert_eq!(Err(error.to_string()), parse_scan_log(text));
+        }
+        assert_eq!(Ok(Vec::new()), parse_scan_log("# comment\n\n"));
+    }
+
+    #[test]
+    fn test_advertising_reports() {
+        let event = le_advertising_report(&[0x02, 0x01, 0x06], -60);
+        assert_eq!(
+            vec![report(None, DATA_STATUS_COMPLETE, &[0x02, 0x01, 0x06])],
+            advertising_reports(&event)
+        );
+
+        let event = le_extended_advertising_report(DATA_STATUS_COMPLETE, 0xff, &[0x02, 0x0a, 0x00]);
+        assert_eq!(
+            vec![report(None, DATA_STATUS_COMPLETE, &[0x02, 0x0a, 0x00])],
+            advertising_reports(&event)
+        );
+
+        let event = le_extended_advertising_report(DATA_STATUS_INCOMPLETE_MORE, 0x01, &[0x02]);
+        assert_eq!(
+            vec![report(Some(0x01), DATA_STATUS_INCOMPLETE_MORE, &[0x02])],
+            advertising_reports(&event)
+        );
+
+        let event = le_extended_advertising_report(DATA_STATUS_INCOMPLETE_TRUNCATED, 0x01, &[]);
+        assert_eq!(
+            vec![report(Some(0x01), DATA_STATUS_INCOMPLETE_TRUNCATED, &[])],
+            advertising_reports(&event)
+        );
+
+        assert!(advertising_reports(&[0x0e, 0x04, 0x01, 0x00]).is_empty());
+        assert!(advertising_reports(&event[..event.len() - 1]).is_empty());
+    }
+
+    #[test]
+    fn test_read_btsnoop() {
+        let event = le_advertising_report(&[0x02, 0x01, 0x06], -60);
+        let expected = ScanRecord::new(
+            1_000_000,
+            Some("01:02:03:04:05:06"),
+            Some(-60),
+            &[0x02, 0x01, 0x06],
+        );
+
+        let mut h4 = vec![0x04];
+        h4.extend_from_slice(&event);
+        let data = btsnoop(
+            BTSNOOP_HCI_UART,
+            &[(0x03, vec![0x01, 0x03, 0x0c, 0x00]), (0x03, h4)],
+        );
+        assert_eq!(Ok(vec![(2, expected.clone())]), read_btsnoop(&data));
+
+        let data = btsnoop(
+            BTSNOOP_HCI_UNENCAPSULATED,
+            &[(0x00, event.clone()), (0x03, event.clone())],
+        );
+        assert_eq!(Ok(vec![(2, expected.clone())]), read_btsnoop(&data));
+
+        let data = btsnoop(
+            BTSNOOP_LINUX_MONITOR,
+            &[
+                (0x0001_0002, vec![0x03, 0x0c, 0x00]),
+                (0x0001_0003, event.clone()),
+            ],
+        );
+        assert_eq!(Ok(vec![(2, expected)]), read_btsnoop(&data));
+
+        let data = btsnoop(1000, &[]);
+        assert_eq!(
+            Err("Unsupported datalink type :1000".to_string()),
+            read_btsnoop(&data)
+        );
+        assert!(read_btsnoop(b"snoop").is_err());
+
+        let data = btsnoop(BTSNOOP_HCI_UART, &[(0x03, vec![0x04, 0x3e])]);
+        assert_eq!(
+            Err("Truncated record :1".to_string()),
+            read_btsnoop(&data[..data.len() - 1])
+        );
+    }
+
+    #[test]
+    fn test_read_btsnoop_fragments() {
+        let data = btsnoop(
+            BTSNOOP_HCI_UNENCAPSULATED,
+            &[
+                (
+                    0x03,
+                    le_extended_advertising_report(DATA_STATUS_INCOMPLETE_MORE, 1, &[0x02, 0x01]),
+                ),
+                (
+                    0x03,
+                    le_extended_advertising_report(DATA_STATUS_COMPLETE, 2, &[0x02, 0x0a, 0x00]),
+                ),
+                (
+                    0x03,
+                    le_extended_advertising_report(DATA_STATUS_COMPLETE, 1, &[0x06]),
+                ),
+                (
+                    0x03,
+                    le_extended_advertising_report(DATA_STATUS_INCOMPLETE_MORE, 1, &[0x02, 0x01]),
+                ),
+                (
+                    0x03,
+                    le_extended_advertising_report(DATA_STATUS_INCOMPLETE_TRUNCATED, 1, &[0x06]),
+                ),
+            ],
+        );
+        let mut truncated = ScanRecord::new(
+            1_000_000,
+            Some("01:02:03:04:05:06"),
+            Some(-60),
+            &[0x02, 0x01, 0x06],
+        );
+        truncated.truncated = true;
+        assert_eq!(
+            Ok(vec![
+                (
+                    2,
+                    ScanRecord::new(
+                        1_000_000,
+                        Some("01:02:03:04:05:06"),
+                        Some(-60),
+                        &[0x02, 0x0a, 0x00]
+                    )
+                ),
+                (
+                    3,
+                    ScanRecord::new(
+                        1_000_000,
+                        Some("01:02:03:04:05:06"),
+                        Some(-60),
+                        &[0x02, 0x01, 0x06]
+                    )
+                ),
+                (5, truncated),
+            ]),
+            read_btsnoop(&data)
+        );
+    }
+
+    #[test]
+    fn test_report_reassembler() {
+        let mut reassembler = ReportReassembler::new();
+        assert_eq!(
+            Some(report(None, DATA_STATUS_COMPLETE, &[0x02, 0x01, 0x06])),
+            reassembler.push(report(None, DATA_STATUS_COMPLETE, &[0x02, 0x01, 0x06]))
+        );
+
+        // fragments of SID 1 and SID 2 interleaved
+        assert_eq!(
+            None,
+            reassembler.push(report(Some(1), DATA_STATUS_INCOMPLETE_MORE, &[0x01]))
+        );
+        assert_eq!(
+            None,
+            reassembler.push(report(Some(2), DATA_STATUS_INCOMPLETE_MORE, &[0x11]))
+        );
+        assert_eq!(
+            None,
+            reassembler.push(report(Some(1), DATA_STATUS_INCOMPLETE_MORE, &[0x02]))
+        );
+        assert_eq!(
+            Some(report(Some(2), DATA_STATUS_COMPLETE, &[0x11, 0x12])),
+            reassembler.push(report(Some(2), DATA_STATUS_COMPLETE, &[0x12]))
+        );
+        assert!(reassembler.is_in_progress());
+        assert_eq!(
+            Some(report(Some(1), DATA_STATUS_COMPLETE, &[0x01, 0x02, 0x03])),
+            reassembler.push(report(Some(1), DATA_STATUS_COMPLETE, &[0x03]))
+        );
+        assert!(!reassembler.is_in_progress());
+
+        // oversize
+        let data = [0u8; 251];
+        for _ in 0..6 {
+            assert_eq!(
+                None,
+                reassembler.push(report(Some(1), DATA_STATUS_INCOMPLETE_MORE, &data))
+            );
+        }
+        let result = reassembler.push(report(Some(1), DATA_STATUS_INCOMPLETE_MORE, &data));
+        assert_eq!(
+            Some(report(
+                Some(1),
+                DATA_STATUS_INCOMPLETE_TRUNCATED,
+                &[0u8; EXTENDED_ADVERTISING_DATA_MAX]
+            )),
+            result
+        );
+        assert!(!reassembler.is_in_progress());
+    }
+}
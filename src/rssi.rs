@@ -0,0 +1,329 @@
+//! RSSI smoothing and proximity module.
+
+/// Default smoothing factor of [`ExponentialMovingAverage`].
+pub const DEFAULT_EMA_ALPHA: f64 = 0.3;
+
+/// Path loss at 1 m for 2.4 GHz (dB).
+///
+/// Tx Power Level is measured at 0 m, so RSSI at 1 m is about `tx_power - 41`.
+pub const PATH_LOSS_AT_1M: f64 = 41.0;
+
+/// Default path loss exponent (free space).
+pub const DEFAULT_PATH_LOSS_EXPONENT: f64 = 2.0;
+
+/// Upper distance of [`ProximityZone::Immediate`] (m).
+pub const IMMEDIATE_DISTANCE: f64 = 0.5;
+
+/// Upper distance of [`ProximityZone::Near`] (m).
+pub const NEAR_DISTANCE: f64 = 3.0;
+
+/// RSSI filter.
+pub trait RssiFilter {
+    /// Add RSSI sample and return filtered value.
+    fn update(&mut self, rssi: i8) -> f64;
+
+    /// Get filtered value, [`None`] if no samples were added.
+    fn value(&self) -> Option<f64>;
+}
+
+/// Exponential moving average RSSI filter.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ExponentialMovingAverage {
+    /// Smoothing factor (0.0 to 1.0, larger follows new samples faster)
+    pub alpha: f64,
+
+    value: Option<f64>,
+}
+
+impl ExponentialMovingAverage {
+    /// Create [`ExponentialMovingAverage`] from Parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::rssi::{ExponentialMovingAverage, RssiFilter};
+    ///
+    /// let result = ExponentialMovingAverage::new(0.5);
+    /// assert_eq!(0.5, result.alpha);
+    /// assert_eq!(None, result.value());
+    /// ```
+    pub const fn new(alpha: f64) -> Self {
+        Self { alpha, value: None }
+    }
+}
+
+impl Default for ExponentialMovingAverage {
+    /// Create [`ExponentialMovingAverage`] with [`DEFAULT_EMA_ALPHA`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::rssi::{ExponentialMovingAverage, DEFAULT_EMA_ALPHA};
+    ///
+    /// assert_eq!(DEFAULT_EMA_ALPHA, ExponentialMovingAverage::default().alpha);
+    /// ```
+    fn default() -> Self {
+        Self::new(DEFAULT_EMA_ALPHA)
+    }
+}
+
+impl RssiFilter for ExponentialMovingAverage {
+    /// Add RSSI sample and return filtered value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::rssi::{ExponentialMovingAverage, RssiFilter};
+    ///
+    /// let mut filter = ExponentialMovingAverage::new(0.5);
+    /// assert_eq!(-60.0, filter.update(-60));
+    /// assert_eq!(-70.0, filter.update(-80));
+    /// assert_eq!(Some(-70.0), filter.value());
+    /// ```
+    fn update(&mut self, rssi: i8) -> f64 {
+        let rssi = rssi as f64;
+        let value = match self.value {
+            Some(value) => value + self.alpha * (rssi - value),
+            None => rssi,
+        };
+        self.value = Some(value);
+        value
+    }
+
+    /// Get filtered value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::rssi::{ExponentialMovingAverage, RssiFilter};
+    ///
+    /// let mut filter = ExponentialMovingAverage::default();
+    /// assert_eq!(None, filter.value());
+    /// filter.update(-50);
+    /// assert_eq!(Some(-50.0), filter.value());
+    /// ```
+    fn value(&self) -> Option<f64> {
+        self.value
+    }
+}
+
+/// One-dimensional Kalman RSSI filter.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct KalmanFilter {
+    /// Process noise (variance of RSSI change between samples)
+    pub process_noise: f64,
+
+    /// Measurement noise (variance of RSSI samples)
+    pub measurement_noise: f64,
+
+    estimate: Option<f64>,
+
+    error: f64,
+}
+
+impl KalmanFilter {
+    /// Create [`KalmanFilter`] from Parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::rssi::{KalmanFilter, RssiFilter};
+    ///
+    /// let result = KalmanFilter::new(0.008, 4.0);
+    /// assert_eq!(0.008, result.process_noise);
+    /// assert_eq!(4.0, result.measurement_noise);
+    /// assert_eq!(None, result.value());
+    /// ```
+    pub const fn new(process_noise: f64, measurement_noise: f64) -> Self {
+        Self {
+            process_noise,
+            measurement_noise,
+            estimate: None,
+            error: 0.0,
+        }
+    }
+}
+
+impl Default for KalmanFilter {
+    /// Create [`KalmanFilter`] with process noise `0.008` and measurement noise `4.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::rssi::KalmanFilter;
+    ///
+    /// assert_eq!(KalmanFilter::new(0.008, 4.0), KalmanFilter::default());
+    /// ```
+    fn default() -> Self {
+        Self::new(0.008, 4.0)
+    }
+}
+
+impl RssiFilter for KalmanFilter {
+    /// Add RSSI sample and return filtered value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::rssi::{KalmanFilter, RssiFilter};
+    ///
+    /// let mut filter = KalmanFilter::default();
+    /// assert_eq!(-60.0, filter.update(-60));
+    /// let value = filter.update(-80);
+    /// assert!(-80.0 < value && value < -60.0);
+    /// ```
+    fn update(&mut self, rssi: i8) -> f64 {
+        let rssi = rssi as f64;
+        let estimate = match self.estimate {
+            Some(estimate) => {
+                let error = self.error + self.process_noise;
+                let gain = error / (error + self.measurement_noise);
+                self.error = (1.0 - gain) * error;
+                estimate + gain * (rssi - estimate)
+            }
+            None => {
+                self.error = self.measurement_noise;
+                rssi
+            }
+        };
+        self.estimate = Some(estimate);
+        estimate
+    }
+
+    /// Get filtered value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::rssi::{KalmanFilter, RssiFilter};
+    ///
+    /// let mut filter = KalmanFilter::default();
+    /// assert_eq!(None, filter.value());
+    /// filter.update(-50);
+    /// assert_eq!(Some(-50.0), filter.value());
+    /// ```
+    fn value(&self) -> Option<f64> {
+        self.estimate
+    }
+}
+
+/// Proximity zone.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ProximityZone {
+    /// Less than [`IMMEDIATE_DISTANCE`]
+    Immediate,
+
+    /// Less than [`NEAR_DISTANCE`]
+    Near,
+
+    /// [`NEAR_DISTANCE`] or more
+    Far,
+}
+
+/// Estimate distance (m) from Tx Power Level and RSSI with path loss exponent.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::rssi::estimate_distance;
+///
+/// assert_eq!(1.0, estimate_distance(0, -41.0, 2.0));
+/// assert_eq!(10.0, estimate_distance(0, -61.0, 2.0));
+/// assert_eq!(0.1, estimate_distance(-4, -25.0, 2.0));
+/// ```
+pub fn estimate_distance(tx_power: i8, rssi: f64, path_loss_exponent: f64) -> f64 {
+    let rssi_at_1m = tx_power as f64 - PATH_LOSS_AT_1M;
+    10f64.powf((rssi_at_1m - rssi) / (10.0 * path_loss_exponent))
+}
+
+/// Classify proximity zone from Tx Power Level and RSSI with [`DEFAULT_PATH_LOSS_EXPONENT`].
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::rssi::{proximity_zone, ProximityZone};
+///
+/// assert_eq!(ProximityZone::Immediate, proximity_zone(0, -30.0));
+/// assert_eq!(ProximityZone::Near, proximity_zone(0, -45.0));
+/// assert_eq!(ProximityZone::Far, proximity_zone(0, -70.0));
+/// ```
+pub fn proximity_zone(tx_power: i8, rssi: f64) -> ProximityZone {
+    let distance = estimate_distance(tx_power, rssi, DEFAULT_PATH_LOSS_EXPONENT);
+    if distance < IMMEDIATE_DISTANCE {
+        ProximityZone::Immediate
+    } else if distance < NEAR_DISTANCE {
+        ProximityZone::Near
+    } else {
+        ProximityZone::Far
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rssi::{
+        estimate_distance, proximity_zone, ExponentialMovingAverage, KalmanFilter, ProximityZone,
+        RssiFilter, IMMEDIATE_DISTANCE, NEAR_DISTANCE,
+    };
+
+    #[test]
+    fn test_exponential_moving_average() {
+        let mut filter = ExponentialMovingAverage::new(1.0);
+        assert_eq!(-10.0, filter.update(-10));
+        assert_eq!(-20.0, filter.update(-20));
+
+        let mut filter = ExponentialMovingAverage::new(0.0);
+        assert_eq!(-10.0, filter.update(-10));
+        assert_eq!(-10.0, filter.update(-20));
+
+        let mut filter = ExponentialMovingAverage::new(0.25);
+        filter.update(-60);
+        assert_eq!(-65.0, filter.update(-80));
+        assert_eq!(Some(-65.0), filter.value());
+    }
+
+    #[test]
+    fn test_kalman_filter() {
+        let mut filter = KalmanFilter::new(0.0, 1.0);
+        assert_eq!(-60.0, filter.update(-60));
+        // gain 1/2
+        assert_eq!(-70.0, filter.update(-80));
+        // gain 1/3
+        assert_eq!(-60.0, filter.update(-40));
+
+        let mut filter = KalmanFilter::default();
+        for _ in 0..100 {
+            filter.update(-70);
+        }
+        assert_eq!(Some(-70.0), filter.value());
+    }
+
+    #[test]
+    fn test_estimate_distance() {
+        assert_eq!(1.0, estimate_distance(-10, -51.0, 3.0));
+        assert_eq!(10.0, estimate_distance(-10, -81.0, 3.0));
+        assert!((estimate_distance(127, 0.0, 2.0) - 10f64.powf(4.3)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_proximity_zone() {
+        // -41 - 20 * log10(distance)
+        let rssi = |distance: f64| -41.0 - 20.0 * distance.log10();
+        assert_eq!(
+            ProximityZone::Immediate,
+            proximity_zone(0, rssi(IMMEDIATE_DISTANCE) + 0.1)
+        );
+        assert_eq!(
+            ProximityZone::Near,
+            proximity_zone(0, rssi(IMMEDIATE_DISTANCE) - 0.1)
+        );
+        assert_eq!(
+            ProximityZone::Near,
+            proximity_zone(0, rssi(NEAR_DISTANCE) + 0.1)
+        );
+        assert_eq!(
+            ProximityZone::Far,
+            proximity_zone(0, rssi(NEAR_DISTANCE) - 0.1)
+        );
+        assert_eq!(ProximityZone::Far, proximity_zone(-20, -80.0));
+    }
+}
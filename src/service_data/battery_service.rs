@@ -0,0 +1,191 @@
+//! Battery Service (Service UUID: 0x180f) service data module.
+
+use crate::{data_types::service_data_16bit_uuid::ServiceData16BitUUID, uuid_from_u16};
+
+/// Battery Service UUID
+pub const BATTERY_SERVICE_UUID: u16 = 0x180f;
+
+/// Battery Level max value (%).
+pub const BATTERY_LEVEL_MAX: u8 = 100;
+
+/// Battery Service data.
+#[derive(Debug, PartialEq, Clone)]
+pub struct BatteryServiceData {
+    /// Battery Level (%)
+    pub battery_level: u8,
+}
+
+impl BatteryServiceData {
+    /// Create [`BatteryServiceData`] from Parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::service_data::battery_service::BatteryServiceData;
+    ///
+    /// let result = BatteryServiceData::new(100);
+    /// assert_eq!(100, result.battery_level);
+    /// ```
+    pub const fn new(battery_level: u8) -> Self {
+        Self { battery_level }
+    }
+}
+
+impl TryFrom<&Vec<u8>> for BatteryServiceData {
+    type Error = String;
+    /// Create [`BatteryServiceData`] from Battery Level characteristic value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::service_data::battery_service::BatteryServiceData;
+    ///
+    /// let data: Vec<u8> = vec![0x64];
+    /// let result = BatteryServiceData::try_from(&data);
+    /// assert_eq!(Ok(BatteryServiceData::new(100)), result);
+    ///
+    /// let data: Vec<u8> = vec![0x65];
+    /// let result = BatteryServiceData::try_from(&data);
+    /// assert_eq!(Err("Invalid Battery Level :101".to_string()), result);
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = BatteryServiceData::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        let len = value.len();
+        if len != 1 {
+            return Err(format!("Invalid data size :{}", len));
+        }
+        if value[0] > BATTERY_LEVEL_MAX {
+            return Err(format!("Invalid Battery Level :{}", value[0]));
+        }
+        Ok(Self::new(value[0]))
+    }
+}
+
+impl TryFrom<&ServiceData16BitUUID> for BatteryServiceData {
+    type Error = String;
+    /// Create [`BatteryServiceData`] from [`ServiceData16BitUUID`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::service_data_16bit_uuid::ServiceData16BitUUID,
+    ///     service_data::battery_service::BatteryServiceData, uuid_from_u16,
+    /// };
+    ///
+    /// let service_data = ServiceData16BitUUID::new(&uuid_from_u16(0x180f), &vec![0x32]);
+    /// let result = BatteryServiceData::try_from(&service_data);
+    /// assert_eq!(Ok(BatteryServiceData::new(50)), result);
+    ///
+    /// let service_data = ServiceData16BitUUID::new(&uuid_from_u16(0xfeaa), &vec![0x32]);
+    /// let result = BatteryServiceData::try_from(&service_data);
+    /// assert!(result.is_err());
+    /// ```
+    fn try_from(value: &ServiceData16BitUUID) -> Result<Self, String> {
+        if value.uuid != uuid_from_u16(BATTERY_SERVICE_UUID) {
+            return Err(format!("Invalid service uuid :{}", value.uuid));
+        }
+        Self::try_from(&value.additional_service_data.to_vec())
+    }
+}
+
+impl From<BatteryServiceData> for Vec<u8> {
+    /// Create Battery Level characteristic value from [`BatteryServiceData`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::service_data::battery_service::BatteryServiceData;
+    ///
+    /// let into_data: Vec<u8> = BatteryServiceData::new(100).into();
+    /// assert_eq!(vec![0x64], into_data);
+    /// ```
+    fn from(value: BatteryServiceData) -> Self {
+        vec![value.battery_level]
+    }
+}
+
+impl From<BatteryServiceData> for ServiceData16BitUUID {
+    /// Create [`ServiceData16BitUUID`] from [`BatteryServiceData`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::service_data_16bit_uuid::ServiceData16BitUUID,
+    ///     service_data::battery_service::BatteryServiceData, uuid_from_u16,
+    /// };
+    ///
+    /// let result: ServiceData16BitUUID = BatteryServiceData::new(100).into();
+    /// assert_eq!(uuid_from_u16(0x180f), result.uuid);
+    /// assert_eq!(vec![0x64], result.additional_service_data.as_slice());
+    /// ```
+    fn from(value: BatteryServiceData) -> Self {
+        let data: Vec<u8> = value.into();
+        ServiceData16BitUUID::new(&uuid_from_u16(BATTERY_SERVICE_UUID), &data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data_types::service_data_16bit_uuid::ServiceData16BitUUID,
+        service_data::battery_service::*, uuid_from_u16,
+    };
+
+    #[test]
+    fn test_new() {
+        let result = BatteryServiceData::new(0);
+        assert_eq!(0, result.battery_level);
+    }
+
+    #[test]
+    fn test_try_from() {
+        for battery_level in 0..=BATTERY_LEVEL_MAX {
+            let data: Vec<u8> = vec![battery_level];
+            let result = BatteryServiceData::try_from(&data);
+            assert_eq!(Ok(BatteryServiceData::new(battery_level)), result);
+        }
+
+        let data: Vec<u8> = vec![0xff];
+        let result = BatteryServiceData::try_from(&data);
+        assert_eq!(Err("Invalid Battery Level :255".to_string()), result);
+
+        let data: Vec<u8> = vec![0x64, 0x00];
+        let result = BatteryServiceData::try_from(&data);
+        assert_eq!(Err("Invalid data size :2".to_string()), result);
+    }
+
+    #[test]
+    fn test_try_from_service_data() {
+        let service_data = ServiceData16BitUUID::new(&uuid_from_u16(0x180f), &vec![0x00]);
+        let result = BatteryServiceData::try_from(&service_data);
+        assert_eq!(Ok(BatteryServiceData::new(0)), result);
+
+        let service_data = ServiceData16BitUUID::new(&uuid_from_u16(0x180a), &vec![0x00]);
+        let result = BatteryServiceData::try_from(&service_data);
+        assert_eq!(
+            Err(format!("Invalid service uuid :{}", uuid_from_u16(0x180a))),
+            result
+        );
+    }
+
+    #[test]
+    fn test_into() {
+        let data: Vec<u8> = BatteryServiceData::new(42).into();
+        assert_eq!(vec![42], data);
+
+        let service_data: ServiceData16BitUUID = BatteryServiceData::new(42).into();
+        assert_eq!(
+            Ok(BatteryServiceData::new(42)),
+            BatteryServiceData::try_from(&service_data)
+        );
+    }
+}
@@ -0,0 +1,142 @@
+//! 16-bit UUID Service Data dispatch module.
+
+#[cfg(feature = "vendor")]
+use crate::beacons::eddystone::{EddystoneTlm, EddystoneUid, EddystoneUrl};
+use crate::{
+    data_types::service_data_16bit_uuid::ServiceData16BitUUID,
+    service_data::{
+        battery_service::BatteryServiceData, improv::ImprovServiceData, matter::MatterServiceData,
+        sensor::SensorData,
+    },
+};
+
+/// Decoded 16-bit UUID Service Data.
+#[derive(Debug, PartialEq, Clone)]
+pub enum ServiceData {
+    /// Battery Service
+    Battery(BatteryServiceData),
+
+    /// Eddystone-UID
+    #[cfg(feature = "vendor")]
+    EddystoneUid(EddystoneUid),
+
+    /// Eddystone-URL
+    #[cfg(feature = "vendor")]
+    EddystoneUrl(EddystoneUrl),
+
+    /// Eddystone-TLM
+    #[cfg(feature = "vendor")]
+    EddystoneTlm(EddystoneTlm),
+
+    /// Matter BLE commissioning
+    Matter(MatterServiceData),
+
+    /// Improv Wi-Fi
+    Improv(ImprovServiceData),
+
+    /// Sensor characteristic value
+    Sensor(SensorData),
+
+    /// Unknown layout
+    Unknown(ServiceData16BitUUID),
+}
+
+impl From<&ServiceData16BitUUID> for ServiceData {
+    /// Create [`ServiceData`] from [`ServiceData16BitUUID`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::service_data_16bit_uuid::ServiceData16BitUUID,
+    ///     service_data::{dispatch::ServiceData, improv::ImprovServiceData},
+    ///     uuid_from_u16,
+    /// };
+    ///
+    /// let data: Vec<u8> = vec![0x02, 0x01, 0x00, 0x00, 0x00, 0x00];
+    /// let result = ServiceData::from(&ServiceData16BitUUID::new(&uuid_from_u16(0x4677), &data));
+    /// assert_eq!(ServiceData::Improv(ImprovServiceData::new(0x02, 0x01)), result);
+    ///
+    /// let data: Vec<u8> = vec![0x01];
+    /// let result = ServiceData::from(&ServiceData16BitUUID::new(&uuid_from_u16(0x180a), &data));
+    /// assert!(matches!(result, ServiceData::Unknown(_)));
+    /// ```
+    fn from(value: &ServiceData16BitUUID) -> Self {
+        if let Ok(data) = BatteryServiceData::try_from(value) {
+            return ServiceData::Battery(data);
+        }
+        #[cfg(feature = "vendor")]
+        {
+            if let Ok(data) = EddystoneUid::try_from(value) {
+                return ServiceData::EddystoneUid(data);
+            } else if let Ok(data) = EddystoneUrl::try_from(value) {
+                return ServiceData::EddystoneUrl(data);
+            } else if let Ok(data) = EddystoneTlm::try_from(value) {
+                return ServiceData::EddystoneTlm(data);
+            }
+        }
+        if let Ok(data) = MatterServiceData::try_from(value) {
+            ServiceData::Matter(data)
+        } else if let Ok(data) = ImprovServiceData::try_from(value) {
+            ServiceData::Improv(data)
+        } else if let Ok(data) = SensorData::try_from(value) {
+            ServiceData::Sensor(data)
+        } else {
+            ServiceData::Unknown(value.clone())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "vendor")]
+    use crate::beacons::eddystone::{EddystoneTlm, EddystoneUid, EddystoneUrl, UnencryptedTlm};
+    use crate::{
+        data_types::service_data_16bit_uuid::ServiceData16BitUUID,
+        service_data::{
+            battery_service::BatteryServiceData, dispatch::ServiceData, improv::ImprovServiceData,
+            matter::MatterServiceData, sensor::SensorData,
+        },
+        uuid_from_u16,
+    };
+
+    #[test]
+    fn test_from() {
+        let battery = BatteryServiceData::new(50);
+        let data: ServiceData16BitUUID = battery.clone().into();
+        assert_eq!(ServiceData::Battery(battery), ServiceData::from(&data));
+
+        let matter = MatterServiceData::new(0x00, 0x0f00, 0, 0xfff1, 0x8000, 0);
+        let data: ServiceData16BitUUID = matter.clone().into();
+        assert_eq!(ServiceData::Matter(matter), ServiceData::from(&data));
+
+        let improv = ImprovServiceData::new(0x04, 0x00);
+        let data: ServiceData16BitUUID = improv.clone().into();
+        assert_eq!(ServiceData::Improv(improv), ServiceData::from(&data));
+
+        let data = ServiceData16BitUUID::new(&uuid_from_u16(0x2a6f), &vec![0x88, 0x13]);
+        assert_eq!(
+            ServiceData::Sensor(SensorData::Humidity(50.0)),
+            ServiceData::from(&data)
+        );
+
+        let data = ServiceData16BitUUID::new(&uuid_from_u16(0x4677), &vec![0x00]);
+        assert_eq!(ServiceData::Unknown(data.clone()), ServiceData::from(&data));
+    }
+
+    #[cfg(feature = "vendor")]
+    #[test]
+    fn test_from_eddystone() {
+        let uid = EddystoneUid::new(-20, [0x01; 10], [0x02; 6]);
+        let data: ServiceData16BitUUID = uid.clone().into();
+        assert_eq!(ServiceData::EddystoneUid(uid), ServiceData::from(&data));
+
+        let url = EddystoneUrl::new(-20, "https://example.com/");
+        let data: ServiceData16BitUUID = url.clone().try_into().unwrap();
+        assert_eq!(ServiceData::EddystoneUrl(url), ServiceData::from(&data));
+
+        let tlm = EddystoneTlm::Unencrypted(UnencryptedTlm::new(3000, 0x1800, 1, 2));
+        let data: ServiceData16BitUUID = tlm.clone().into();
+        assert_eq!(ServiceData::EddystoneTlm(tlm), ServiceData::from(&data));
+    }
+}
@@ -0,0 +1,336 @@
+//! Improv Wi-Fi (Service UUID: 0x4677) service data module.
+
+use crate::{data_types::service_data_16bit_uuid::ServiceData16BitUUID, uuid_from_u16};
+
+/// Improv service data UUID
+pub const IMPROV_SERVICE_DATA_UUID: u16 = 0x4677;
+
+/// Improv service data size.
+pub const IMPROV_SERVICE_DATA_SIZE: usize = 6;
+
+/// Current State: Authorization Required
+pub const STATE_AUTHORIZATION_REQUIRED: u8 = 0x01;
+
+/// Current State: Authorized
+pub const STATE_AUTHORIZED: u8 = 0x02;
+
+/// Current State: Provisioning
+pub const STATE_PROVISIONING: u8 = 0x03;
+
+/// Current State: Provisioned
+pub const STATE_PROVISIONED: u8 = 0x04;
+
+/// Capabilities: Identify
+pub const CAPABILITIES_IDENTIFY: u8 = 0b0000_0001;
+
+/// Error State: No error
+pub const ERROR_NO_ERROR: u8 = 0x00;
+
+/// Error State: Invalid RPC packet
+pub const ERROR_INVALID_RPC_PACKET: u8 = 0x01;
+
+/// Error State: Unknown RPC command
+pub const ERROR_UNKNOWN_RPC_COMMAND: u8 = 0x02;
+
+/// Error State: Unable to connect
+pub const ERROR_UNABLE_TO_CONNECT: u8 = 0x03;
+
+/// Error State: Not authorized
+pub const ERROR_NOT_AUTHORIZED: u8 = 0x04;
+
+/// Error State: Unknown error
+pub const ERROR_UNKNOWN: u8 = 0xff;
+
+/// Improv Wi-Fi service data.
+///
+/// Error State is not advertised; [`ImprovServiceData::error_name`] describes the value read from
+/// the Error State characteristic.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ImprovServiceData {
+    /// Current State
+    pub current_state: u8,
+
+    /// Capabilities
+    pub capabilities: u8,
+}
+
+impl ImprovServiceData {
+    /// Create [`ImprovServiceData`] from Parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::service_data::improv::*;
+    ///
+    /// let result = ImprovServiceData::new(STATE_AUTHORIZED, CAPABILITIES_IDENTIFY);
+    /// assert_eq!(STATE_AUTHORIZED, result.current_state);
+    /// assert_eq!(CAPABILITIES_IDENTIFY, result.capabilities);
+    /// ```
+    pub const fn new(current_state: u8, capabilities: u8) -> Self {
+        Self {
+            current_state,
+            capabilities,
+        }
+    }
+
+    /// check Identify capability.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::service_data::improv::*;
+    ///
+    /// let result = ImprovServiceData::new(STATE_AUTHORIZED, CAPABILITIES_IDENTIFY);
+    /// assert!(result.is_identify_supported());
+    ///
+    /// let result = ImprovServiceData::new(STATE_AUTHORIZED, 0);
+    /// assert!(!result.is_identify_supported());
+    /// ```
+    pub fn is_identify_supported(&self) -> bool {
+        self.capabilities & CAPABILITIES_IDENTIFY != 0
+    }
+
+    /// Get Current State name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::service_data::improv::*;
+    ///
+    /// let result = ImprovServiceData::new(STATE_PROVISIONED, 0);
+    /// assert_eq!("Provisioned", result.state_name());
+    /// ```
+    pub fn state_name(&self) -> &'static str {
+        match self.current_state {
+            STATE_AUTHORIZATION_REQUIRED => "Authorization Required",
+            STATE_AUTHORIZED => "Authorized",
+            STATE_PROVISIONING => "Provisioning",
+            STATE_PROVISIONED => "Provisioned",
+            _ => "Unknown",
+        }
+    }
+
+    /// Get Error State name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::service_data::improv::*;
+    ///
+    /// assert_eq!("Unable to connect", ImprovServiceData::error_name(ERROR_UNABLE_TO_CONNECT));
+    /// assert_eq!("Unknown error", ImprovServiceData::error_name(0x10));
+    /// ```
+    pub fn error_name(error_state: u8) -> &'static str {
+        match error_state {
+            ERROR_NO_ERROR => "No error",
+            ERROR_INVALID_RPC_PACKET => "Invalid RPC packet",
+            ERROR_UNKNOWN_RPC_COMMAND => "Unknown RPC command",
+            ERROR_UNABLE_TO_CONNECT => "Unable to connect",
+            ERROR_NOT_AUTHORIZED => "Not authorized",
+            _ => "Unknown error",
+        }
+    }
+}
+
+impl TryFrom<&Vec<u8>> for ImprovServiceData {
+    type Error = String;
+    /// Create [`ImprovServiceData`] from Improv service data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::service_data::improv::*;
+    ///
+    /// let data: Vec<u8> = vec![0x02, 0x01, 0x00, 0x00, 0x00, 0x00];
+    /// let result = ImprovServiceData::try_from(&data);
+    /// assert_eq!(Ok(ImprovServiceData::new(STATE_AUTHORIZED, CAPABILITIES_IDENTIFY)), result);
+    ///
+    /// let data: Vec<u8> = vec![0x05, 0x01, 0x00, 0x00, 0x00, 0x00];
+    /// let result = ImprovServiceData::try_from(&data);
+    /// assert_eq!(Err("Invalid Current State :5".to_string()), result);
+    ///
+    /// let data: Vec<u8> = vec![0x02, 0x01];
+    /// let result = ImprovServiceData::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        let len = value.len();
+        if len != IMPROV_SERVICE_DATA_SIZE {
+            return Err(format!("Invalid data size :{}", len));
+        }
+        if !(STATE_AUTHORIZATION_REQUIRED..=STATE_PROVISIONED).contains(&value[0]) {
+            return Err(format!("Invalid Current State :{}", value[0]));
+        }
+        Ok(Self::new(value[0], value[1]))
+    }
+}
+
+impl TryFrom<&ServiceData16BitUUID> for ImprovServiceData {
+    type Error = String;
+    /// Create [`ImprovServiceData`] from [`ServiceData16BitUUID`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::service_data_16bit_uuid::ServiceData16BitUUID,
+    ///     service_data::improv::ImprovServiceData, uuid_from_u16,
+    /// };
+    ///
+    /// let data = vec![0x01, 0x00, 0x00, 0x00, 0x00, 0x00];
+    /// let service_data = ServiceData16BitUUID::new(&uuid_from_u16(0x4677), &data);
+    /// let result = ImprovServiceData::try_from(&service_data);
+    /// assert_eq!(Ok(ImprovServiceData::new(0x01, 0x00)), result);
+    ///
+    /// let service_data = ServiceData16BitUUID::new(&uuid_from_u16(0x180f), &data);
+    /// let result = ImprovServiceData::try_from(&service_data);
+    /// assert!(result.is_err());
+    /// ```
+    fn try_from(value: &ServiceData16BitUUID) -> Result<Self, String> {
+        if value.uuid != uuid_from_u16(IMPROV_SERVICE_DATA_UUID) {
+            return Err(format!("Invalid service uuid :{}", value.uuid));
+        }
+        Self::try_from(&value.additional_service_data.to_vec())
+    }
+}
+
+impl From<ImprovServiceData> for Vec<u8> {
+    /// Create Improv service data from [`ImprovServiceData`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::service_data::improv::*;
+    ///
+    /// let into_data: Vec<u8> = ImprovServiceData::new(STATE_PROVISIONING, 0).into();
+    /// assert_eq!(vec![0x03, 0x00, 0x00, 0x00, 0x00, 0x00], into_data);
+    /// ```
+    fn from(value: ImprovServiceData) -> Self {
+        vec![
+            value.current_state,
+            value.capabilities,
+            0x00,
+            0x00,
+            0x00,
+            0x00,
+        ]
+    }
+}
+
+impl From<ImprovServiceData> for ServiceData16BitUUID {
+    /// Create [`ServiceData16BitUUID`] from [`ImprovServiceData`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::service_data_16bit_uuid::ServiceData16BitUUID,
+    ///     service_data::improv::ImprovServiceData, uuid_from_u16,
+    /// };
+    ///
+    /// let result: ServiceData16BitUUID = ImprovServiceData::new(0x04, 0x01).into();
+    /// assert_eq!(uuid_from_u16(0x4677), result.uuid);
+    /// assert_eq!(
+    ///     vec![0x04, 0x01, 0x00, 0x00, 0x00, 0x00],
+    ///     result.additional_service_data.as_slice()
+    /// );
+    /// ```
+    fn from(value: ImprovServiceData) -> Self {
+        let data: Vec<u8> = value.into();
+        ServiceData16BitUUID::new(&uuid_from_u16(IMPROV_SERVICE_DATA_UUID), &data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data_types::service_data_16bit_uuid::ServiceData16BitUUID, service_data::improv::*,
+        uuid_from_u16,
+    };
+
+    #[test]
+    fn test_new() {
+        let result = ImprovServiceData::new(STATE_PROVISIONING, 0xff);
+        assert_eq!(STATE_PROVISIONING, result.current_state);
+        assert_eq!(0xff, result.capabilities);
+        assert!(result.is_identify_supported());
+
+        let result = ImprovServiceData::new(STATE_PROVISIONING, 0xfe);
+        assert!(!result.is_identify_supported());
+    }
+
+    #[test]
+    fn test_state_name() {
+        let names = [
+            (STATE_AUTHORIZATION_REQUIRED, "Authorization Required"),
+            (STATE_AUTHORIZED, "Authorized"),
+            (STATE_PROVISIONING, "Provisioning"),
+            (STATE_PROVISIONED, "Provisioned"),
+            (0x00, "Unknown"),
+        ];
+        for (state, name) in names {
+            assert_eq!(name, ImprovServiceData::new(state, 0).state_name());
+        }
+    }
+
+    #[test]
+    fn test_error_name() {
+        let names = [
+            (ERROR_NO_ERROR, "No error"),
+            (ERROR_INVALID_RPC_PACKET, "Invalid RPC packet"),
+            (ERROR_UNKNOWN_RPC_COMMAND, "Unknown RPC command"),
+            (ERROR_UNABLE_TO_CONNECT, "Unable to connect"),
+            (ERROR_NOT_AUTHORIZED, "Not authorized"),
+            (ERROR_UNKNOWN, "Unknown error"),
+        ];
+        for (error_state, name) in names {
+            assert_eq!(name, ImprovServiceData::error_name(error_state));
+        }
+    }
+
+    #[test]
+    fn test_try_from() {
+        for state in STATE_AUTHORIZATION_REQUIRED..=STATE_PROVISIONED {
+            let data: Vec<u8> = vec![state, 0x01, 0x00, 0x00, 0x00, 0x00];
+            let result = ImprovServiceData::try_from(&data);
+            assert_eq!(Ok(ImprovServiceData::new(state, 0x01)), result);
+        }
+
+        let data: Vec<u8> = vec![0x00, 0x01, 0x00, 0x00, 0x00, 0x00];
+        let result = ImprovServiceData::try_from(&data);
+        assert_eq!(Err("Invalid Current State :0".to_string()), result);
+
+        let data: Vec<u8> = vec![0x01, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00];
+        let result = ImprovServiceData::try_from(&data);
+        assert_eq!(Err("Invalid data size :7".to_string()), result);
+    }
+
+    #[test]
+    fn test_try_from_service_data() {
+        let data: Vec<u8> = vec![0x04, 0x00, 0x00, 0x00, 0x00, 0x00];
+        let service_data = ServiceData16BitUUID::new(&uuid_from_u16(0x4677), &data);
+        let result = ImprovServiceData::try_from(&service_data);
+        assert_eq!(Ok(ImprovServiceData::new(STATE_PROVISIONED, 0)), result);
+
+        let service_data = ServiceData16BitUUID::new(&uuid_from_u16(0xfff6), &data);
+        let result = ImprovServiceData::try_from(&service_data);
+        assert_eq!(
+            Err(format!("Invalid service uuid :{}", uuid_from_u16(0xfff6))),
+            result
+        );
+    }
+
+    #[test]
+    fn test_into() {
+        let result = ImprovServiceData::new(STATE_AUTHORIZATION_REQUIRED, CAPABILITIES_IDENTIFY);
+        let data: Vec<u8> = result.clone().into();
+        assert_eq!(vec![0x01, 0x01, 0x00, 0x00, 0x00, 0x00], data);
+
+        let service_data: ServiceData16BitUUID = result.clone().into();
+        assert_eq!(Ok(result), ImprovServiceData::try_from(&service_data));
+    }
+}
@@ -0,0 +1,354 @@
+//! Matter BLE commissioning (Service UUID: 0xfff6) service data module.
+
+use crate::{data_types::service_data_16bit_uuid::ServiceData16BitUUID, uuid_from_u16};
+
+/// Matter BLE service UUID
+pub const MATTER_SERVICE_UUID: u16 = 0xfff6;
+
+/// Matter OpCode: Commissionable
+pub const OPCODE_COMMISSIONABLE: u8 = 0x00;
+
+/// Discriminator max value (12 bit).
+pub const DISCRIMINATOR_MAX: u16 = 0x0fff;
+
+/// Advertisement Version max value (4 bit).
+pub const ADVERTISEMENT_VERSION_MAX: u8 = 0x0f;
+
+/// Additional Data Flags: Additional Data (C3 characteristic present)
+pub const FLAGS_ADDITIONAL_DATA: u8 = 0b0000_0001;
+
+/// Additional Data Flags: Extended Announcement
+pub const FLAGS_EXTENDED_ANNOUNCEMENT: u8 = 0b0000_0010;
+
+/// Matter service data size.
+pub const MATTER_SERVICE_DATA_SIZE: usize = 8;
+
+/// Matter BLE commissionable node service data.
+#[derive(Debug, PartialEq, Clone)]
+pub struct MatterServiceData {
+    /// OpCode
+    pub opcode: u8,
+
+    /// Discriminator (12 bit)
+    pub discriminator: u16,
+
+    /// Advertisement Version (4 bit)
+    pub advertisement_version: u8,
+
+    /// Vendor ID
+    pub vendor_id: u16,
+
+    /// Product ID
+    pub product_id: u16,
+
+    /// Additional Data Flags
+    pub flags: u8,
+}
+
+impl MatterServiceData {
+    /// Create [`MatterServiceData`] from Parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::service_data::matter::*;
+    ///
+    /// let result = MatterServiceData::new(OPCODE_COMMISSIONABLE, 0x0f00, 0, 0xfff1, 0x8000, 0);
+    /// assert_eq!(OPCODE_COMMISSIONABLE, result.opcode);
+    /// assert_eq!(0x0f00, result.discriminator);
+    /// assert_eq!(0, result.advertisement_version);
+    /// assert_eq!(0xfff1, result.vendor_id);
+    /// assert_eq!(0x8000, result.product_id);
+    /// assert_eq!(0, result.flags);
+    /// ```
+    pub const fn new(
+        opcode: u8,
+        discriminator: u16,
+        advertisement_version: u8,
+        vendor_id: u16,
+        product_id: u16,
+        flags: u8,
+    ) -> Self {
+        Self {
+            opcode,
+            discriminator,
+            advertisement_version,
+            vendor_id,
+            product_id,
+            flags,
+        }
+    }
+
+    /// check Commissionable OpCode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::service_data::matter::*;
+    ///
+    /// let result = MatterServiceData::new(OPCODE_COMMISSIONABLE, 0, 0, 0, 0, 0);
+    /// assert!(result.is_commissionable());
+    ///
+    /// let result = MatterServiceData::new(0x01, 0, 0, 0, 0, 0);
+    /// assert!(!result.is_commissionable());
+    /// ```
+    pub fn is_commissionable(&self) -> bool {
+        self.opcode == OPCODE_COMMISSIONABLE
+    }
+
+    /// check Additional Data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::service_data::matter::*;
+    ///
+    /// let result = MatterServiceData::new(0, 0, 0, 0, 0, FLAGS_ADDITIONAL_DATA);
+    /// assert!(result.has_additional_data());
+    ///
+    /// let result = MatterServiceData::new(0, 0, 0, 0, 0, 0);
+    /// assert!(!result.has_additional_data());
+    /// ```
+    pub fn has_additional_data(&self) -> bool {
+        self.flags & FLAGS_ADDITIONAL_DATA != 0
+    }
+
+    /// check Extended Announcement.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::service_data::matter::*;
+    ///
+    /// let result = MatterServiceData::new(0, 0, 0, 0, 0, FLAGS_EXTENDED_ANNOUNCEMENT);
+    /// assert!(result.is_extended_announcement());
+    ///
+    /// let result = MatterServiceData::new(0, 0, 0, 0, 0, 0);
+    /// assert!(!result.is_extended_announcement());
+    /// ```
+    pub fn is_extended_announcement(&self) -> bool {
+        self.flags & FLAGS_EXTENDED_ANNOUNCEMENT != 0
+    }
+
+    /// Get short discriminator (upper 4 bit of discriminator), used by manual pairing code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::service_data::matter::MatterServiceData;
+    ///
+    /// let result = MatterServiceData::new(0, 0x0f00, 0, 0, 0, 0);
+    /// assert_eq!(0x0f, result.short_discriminator());
+    /// ```
+    pub fn short_discriminator(&self) -> u8 {
+        (self.discriminator >> 8) as u8 & 0x0f
+    }
+}
+
+impl TryFrom<&Vec<u8>> for MatterServiceData {
+    type Error = String;
+    /// Create [`MatterServiceData`] from Matter service data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::service_data::matter::MatterServiceData;
+    ///
+    /// let data: Vec<u8> = vec![0x00, 0x00, 0x0f, 0xf1, 0xff, 0x00, 0x80, 0x00];
+    /// let result = MatterServiceData::try_from(&data);
+    /// assert_eq!(Ok(MatterServiceData::new(0, 0x0f00, 0, 0xfff1, 0x8000, 0)), result);
+    ///
+    /// let data: Vec<u8> = vec![0x00, 0x00, 0x0f];
+    /// let result = MatterServiceData::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        let len = value.len();
+        if len != MATTER_SERVICE_DATA_SIZE {
+            return Err(format!("Invalid data size :{}", len));
+        }
+        let discriminator_and_version = u16::from_le_bytes([value[1], value[2]]);
+        Ok(Self::new(
+            value[0],
+            discriminator_and_version & DISCRIMINATOR_MAX,
+            (discriminator_and_version >> 12) as u8,
+            u16::from_le_bytes([value[3], value[4]]),
+            u16::from_le_bytes([value[5], value[6]]),
+            value[7],
+        ))
+    }
+}
+
+impl TryFrom<&ServiceData16BitUUID> for MatterServiceData {
+    type Error = String;
+    /// Create [`MatterServiceData`] from [`ServiceData16BitUUID`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::service_data_16bit_uuid::ServiceData16BitUUID,
+    ///     service_data::matter::MatterServiceData, uuid_from_u16,
+    /// };
+    ///
+    /// let data = vec![0x00, 0x00, 0x0f, 0xf1, 0xff, 0x00, 0x80, 0x00];
+    /// let service_data = ServiceData16BitUUID::new(&uuid_from_u16(0xfff6), &data);
+    /// let result = MatterServiceData::try_from(&service_data);
+    /// assert_eq!(Ok(MatterServiceData::new(0, 0x0f00, 0, 0xfff1, 0x8000, 0)), result);
+    ///
+    /// let service_data = ServiceData16BitUUID::new(&uuid_from_u16(0x180f), &data);
+    /// let result = MatterServiceData::try_from(&service_data);
+    /// assert!(result.is_err());
+    /// ```
+    fn try_from(value: &ServiceData16BitUUID) -> Result<Self, String> {
+        if value.uuid != uuid_from_u16(MATTER_SERVICE_UUID) {
+            return Err(format!("Invalid service uuid :{}", value.uuid));
+        }
+        Self::try_from(&value.additional_service_data.to_vec())
+    }
+}
+
+impl From<MatterServiceData> for Vec<u8> {
+    /// Create Matter service data from [`MatterServiceData`].
+    ///
+    /// Discriminator and Advertisement Version are masked to their bit width.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::service_data::matter::MatterServiceData;
+    ///
+    /// let into_data: Vec<u8> = MatterServiceData::new(0, 0x0f00, 0, 0xfff1, 0x8000, 0).into();
+    /// assert_eq!(vec![0x00, 0x00, 0x0f, 0xf1, 0xff, 0x00, 0x80, 0x00], into_data);
+    /// ```
+    fn from(value: MatterServiceData) -> Self {
+        let discriminator_and_version = (value.discriminator & DISCRIMINATOR_MAX)
+            | (((value.advertisement_version & ADVERTISEMENT_VERSION_MAX) as u16) << 12);
+        let mut data: Vec<u8> = Vec::with_capacity(MATTER_SERVICE_DATA_SIZE);
+        data.push(value.opcode);
+        data.extend_from_slice(&discriminator_and_version.to_le_bytes());
+        data.extend_from_slice(&value.vendor_id.to_le_bytes());
+        data.extend_from_slice(&value.product_id.to_le_bytes());
+        data.push(value.flags);
+        data
+    }
+}
+
+impl From<MatterServiceData> for ServiceData16BitUUID {
+    /// Create [`ServiceData16BitUUID`] from [`MatterServiceData`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::service_data_16bit_uuid::ServiceData16BitUUID,
+    ///     service_data::matter::MatterServiceData, uuid_from_u16,
+    /// };
+    ///
+    /// let result: ServiceData16BitUUID =
+    ///     MatterServiceData::new(0, 0x0f00, 0, 0xfff1, 0x8000, 0).into();
+    /// assert_eq!(uuid_from_u16(0xfff6), result.uuid);
+    /// assert_eq!(
+    ///     vec![0x00, 0x00, 0x0f, 0xf1, 0xff, 0x00, 0x80, 0x00],
+    ///     result.additional_service_data.as_slice()
+    /// );
+    /// ```
+    fn from(value: MatterServiceData) -> Self {
+        let data: Vec<u8> = value.into();
+        ServiceData16BitUUID::new(&uuid_from_u16(MATTER_SERVICE_UUID), &data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data_types::service_data_16bit_uuid::ServiceData16BitUUID, service_data::matter::*,
+        uuid_from_u16,
+    };
+
+    #[test]
+    fn test_new() {
+        let result = MatterServiceData::new(0x01, 0x0123, 0x02, 0x1234, 0x5678, 0x03);
+        assert_eq!(0x01, result.opcode);
+        assert_eq!(0x0123, result.discriminator);
+        assert_eq!(0x02, result.advertisement_version);
+        assert_eq!(0x1234, result.vendor_id);
+        assert_eq!(0x5678, result.product_id);
+        assert_eq!(0x03, result.flags);
+    }
+
+    #[test]
+    fn test_flags() {
+        let result = MatterServiceData::new(OPCODE_COMMISSIONABLE, 0, 0, 0, 0, 0b1111_1100);
+        assert!(result.is_commissionable());
+        assert!(!result.has_additional_data());
+        assert!(!result.is_extended_announcement());
+
+        let result = MatterServiceData::new(0xff, 0, 0, 0, 0, 0xff);
+        assert!(!result.is_commissionable());
+        assert!(result.has_additional_data());
+        assert!(result.is_extended_announcement());
+    }
+
+    #[test]
+    fn test_short_discriminator() {
+        assert_eq!(
+            0x00,
+            MatterServiceData::new(0, 0x00ff, 0, 0, 0, 0).short_discriminator()
+        );
+        assert_eq!(
+            0x0a,
+            MatterServiceData::new(0, 0x0a5c, 0, 0, 0, 0).short_discriminator()
+        );
+    }
+
+    #[test]
+    fn test_try_from() {
+        let data: Vec<u8> = vec![0x00, 0x5c, 0x3a, 0x34, 0x12, 0x78, 0x56, 0x01];
+        let result = MatterServiceData::try_from(&data);
+        assert_eq!(
+            Ok(MatterServiceData::new(0, 0x0a5c, 3, 0x1234, 0x5678, 1)),
+            result
+        );
+
+        let data: Vec<u8> = vec![0x00; 9];
+        let result = MatterServiceData::try_from(&data);
+        assert_eq!(Err("Invalid data size :9".to_string()), result);
+
+        let data: Vec<u8> = vec![0x00; 7];
+        let result = MatterServiceData::try_from(&data);
+        assert_eq!(Err("Invalid data size :7".to_string()), result);
+    }
+
+    #[test]
+    fn test_try_from_service_data() {
+        let data: Vec<u8> = vec![0x00, 0xff, 0x0f, 0xf1, 0xff, 0x01, 0x80, 0x00];
+        let service_data = ServiceData16BitUUID::new(&uuid_from_u16(0xfff6), &data);
+        let result = MatterServiceData::try_from(&service_data);
+        assert_eq!(
+            Ok(MatterServiceData::new(0, 0x0fff, 0, 0xfff1, 0x8001, 0)),
+            result
+        );
+
+        let service_data = ServiceData16BitUUID::new(&uuid_from_u16(0xfeaa), &data);
+        let result = MatterServiceData::try_from(&service_data);
+        assert_eq!(
+            Err(format!("Invalid service uuid :{}", uuid_from_u16(0xfeaa))),
+            result
+        );
+    }
+
+    #[test]
+    fn test_into() {
+        let data: Vec<u8> = MatterServiceData::new(0, 0xfa5c, 0x13, 0x1234, 0x5678, 1).into();
+        assert_eq!(vec![0x00, 0x5c, 0x3a, 0x34, 0x12, 0x78, 0x56, 0x01], data);
+
+        let result = MatterServiceData::new(0, 0x0a5c, 3, 0x1234, 0x5678, 1);
+        let service_data: ServiceData16BitUUID = result.clone().into();
+        assert_eq!(Ok(result), MatterServiceData::try_from(&service_data));
+    }
+}
@@ -0,0 +1,364 @@
+//! Environmental Sensing / Health Thermometer characteristic service data module.
+
+use crate::{data_types::service_data_16bit_uuid::ServiceData16BitUUID, short_form, ShortUuid};
+
+/// Temperature Measurement characteristic UUID
+pub const TEMPERATURE_MEASUREMENT_UUID: u16 = 0x2a1c;
+
+/// Intermediate Temperature characteristic UUID
+pub const INTERMEDIATE_TEMPERATURE_UUID: u16 = 0x2a1e;
+
+/// Elevation characteristic UUID
+pub const ELEVATION_UUID: u16 = 0x2a6c;
+
+/// Pressure characteristic UUID
+pub const PRESSURE_UUID: u16 = 0x2a6d;
+
+/// Temperature characteristic UUID
+pub const TEMPERATURE_UUID: u16 = 0x2a6e;
+
+/// Humidity characteristic UUID
+pub const HUMIDITY_UUID: u16 = 0x2a6f;
+
+/// UV Index characteristic UUID
+pub const UV_INDEX_UUID: u16 = 0x2a76;
+
+/// Temperature Measurement Flags: Temperature Units Flag (Fahrenheit)
+pub const FLAGS_FAHRENHEIT: u8 = 0b0000_0001;
+
+/// Temperature Measurement Flags: Time Stamp Flag
+pub const FLAGS_TIME_STAMP: u8 = 0b0000_0010;
+
+/// Temperature Measurement Flags: Temperature Type Flag
+pub const FLAGS_TEMPERATURE_TYPE: u8 = 0b0000_0100;
+
+/// Temperature value when not known.
+pub const TEMPERATURE_NOT_KNOWN: i16 = -0x8000;
+
+/// Humidity value when not known.
+pub const HUMIDITY_NOT_KNOWN: u16 = 0xffff;
+
+/// Convert IEEE 11073-20601 32-bit FLOAT to [`f32`].
+///
+/// NaN, NRes and reserved values are converted to [`f32::NAN`].
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::service_data::sensor::ieee11073_float;
+///
+/// assert_eq!(36.5, ieee11073_float(0xfe00_0e42));
+/// assert_eq!(-1200.0, ieee11073_float(0x02ff_fff4));
+/// assert_eq!(f32::INFINITY, ieee11073_float(0x007f_fffe));
+/// assert!(ieee11073_float(0x007f_ffff).is_nan());
+/// ```
+pub fn ieee11073_float(value: u32) -> f32 {
+    match value {
+        0x007f_fffe => return f32::INFINITY,
+        0x0080_0002 => return f32::NEG_INFINITY,
+        0x007f_ffff..=0x0080_0001 => return f32::NAN,
+        _ => {}
+    }
+    let mantissa = ((value << 8) as i32) >> 8;
+    let exponent = (value >> 24) as i8 as i32;
+    if exponent < 0 {
+        (mantissa as f64 / 10f64.powi(-exponent)) as f32
+    } else {
+        (mantissa as f64 * 10f64.powi(exponent)) as f32
+    }
+}
+
+/// Sensor characteristic value.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum SensorData {
+    /// Temperature Measurement / Intermediate Temperature (Celsius)
+    TemperatureMeasurement(f32),
+
+    /// Elevation (m)
+    Elevation(f32),
+
+    /// Pressure (Pa)
+    Pressure(f32),
+
+    /// Temperature (Celsius)
+    Temperature(f32),
+
+    /// Humidity (%)
+    Humidity(f32),
+
+    /// UV Index
+    UvIndex(f32),
+}
+
+impl SensorData {
+    /// Create [`SensorData`] from characteristic UUID and value.
+    ///
+    /// Fahrenheit Temperature Measurement is converted to Celsius.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::service_data::sensor::SensorData;
+    ///
+    /// let result = SensorData::try_new(0x2a6e, &[0x2e, 0x09]);
+    /// assert_eq!(Ok(SensorData::Temperature(23.5)), result);
+    ///
+    /// let result = SensorData::try_new(0x2a6f, &[0xff, 0xff]);
+    /// assert_eq!(Err("Value is not known".to_string()), result);
+    ///
+    /// let result = SensorData::try_new(0x2a6e, &[0x2e]);
+    /// assert_eq!(Err("Invalid data size :1".to_string()), result);
+    ///
+    /// let result = SensorData::try_new(0x2a19, &[0x64]);
+    /// assert_eq!(Err("Invalid characteristic uuid :0x2a19".to_string()), result);
+    /// ```
+    pub fn try_new(uuid: u16, data: &[u8]) -> Result<Self, String> {
+        let len = data.len();
+        let validate_size = |size: usize| {
+            if len == size {
+                Ok(())
+            } else {
+                Err(format!("Invalid data size :{}", len))
+            }
+        };
+        match uuid {
+            TEMPERATURE_MEASUREMENT_UUID | INTERMEDIATE_TEMPERATURE_UUID => {
+                if len < 5 {
+                    return Err(format!("Invalid data size :{}", len));
+                }
+                let flags = data[0];
+                let mut size = 5;
+                if flags & FLAGS_TIME_STAMP != 0 {
+                    size += 7;
+                }
+                if flags & FLAGS_TEMPERATURE_TYPE != 0 {
+                    size += 1;
+                }
+                validate_size(size)?;
+                let value = ieee11073_float(u32::from_le_bytes(data[1..5].try_into().unwrap()));
+                if flags & FLAGS_FAHRENHEIT != 0 {
+                    Ok(Self::TemperatureMeasurement((value - 32.0) * 5.0 / 9.0))
+                } else {
+                    Ok(Self::TemperatureMeasurement(value))
+                }
+            }
+            ELEVATION_UUID => {
+                validate_size(3)?;
+                let value = i32::from_le_bytes([0, data[0], data[1], data[2]]) >> 8;
+                Ok(Self::Elevation(value as f32 / 100.0))
+            }
+            PRESSURE_UUID => {
+                validate_size(4)?;
+                let value = u32::from_le_bytes(data.try_into().unwrap());
+                Ok(Self::Pressure((value as f64 / 10.0) as f32))
+            }
+            TEMPERATURE_UUID => {
+                validate_size(2)?;
+                let value = i16::from_le_bytes(data.try_into().unwrap());
+                if value == TEMPERATURE_NOT_KNOWN {
+                    return Err("Value is not known".to_string());
+                }
+                Ok(Self::Temperature(value as f32 / 100.0))
+            }
+            HUMIDITY_UUID => {
+                validate_size(2)?;
+                let value = u16::from_le_bytes(data.try_into().unwrap());
+                if value == HUMIDITY_NOT_KNOWN {
+                    return Err("Value is not known".to_string());
+                }
+                Ok(Self::Humidity(value as f32 / 100.0))
+            }
+            UV_INDEX_UUID => {
+                validate_size(1)?;
+                Ok(Self::UvIndex(data[0] as f32))
+            }
+            _ => Err(format!("Invalid characteristic uuid :{:#06x}", uuid)),
+        }
+    }
+
+    /// Get characteristic UUID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::service_data::sensor::SensorData;
+    ///
+    /// assert_eq!(0x2a6f, SensorData::Humidity(50.0).uuid());
+    /// ```
+    pub fn uuid(&self) -> u16 {
+        match self {
+            SensorData::TemperatureMeasurement(_) => TEMPERATURE_MEASUREMENT_UUID,
+            SensorData::Elevation(_) => ELEVATION_UUID,
+            SensorData::Pressure(_) => PRESSURE_UUID,
+            SensorData::Temperature(_) => TEMPERATURE_UUID,
+            SensorData::Humidity(_) => HUMIDITY_UUID,
+            SensorData::UvIndex(_) => UV_INDEX_UUID,
+        }
+    }
+
+    /// Get scaled value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::service_data::sensor::SensorData;
+    ///
+    /// assert_eq!(50.0, SensorData::Humidity(50.0).value());
+    /// ```
+    pub fn value(&self) -> f32 {
+        match self {
+            SensorData::TemperatureMeasurement(value)
+            | SensorData::Elevation(value)
+            | SensorData::Pressure(value)
+            | SensorData::Temperature(value)
+            | SensorData::Humidity(value)
+            | SensorData::UvIndex(value) => *value,
+        }
+    }
+}
+
+impl TryFrom<&ServiceData16BitUUID> for SensorData {
+    type Error = String;
+    /// Create [`SensorData`] from [`ServiceData16BitUUID`] keyed by characteristic UUID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::service_data_16bit_uuid::ServiceData16BitUUID,
+    ///     service_data::sensor::SensorData, uuid_from_u16,
+    /// };
+    ///
+    /// let service_data = ServiceData16BitUUID::new(&uuid_from_u16(0x2a6f), &vec![0x88, 0x13]);
+    /// let result = SensorData::try_from(&service_data);
+    /// assert_eq!(Ok(SensorData::Humidity(50.0)), result);
+    ///
+    /// let service_data = ServiceData16BitUUID::new(&uuid_from_u16(0x180f), &vec![0x64]);
+    /// let result = SensorData::try_from(&service_data);
+    /// assert!(result.is_err());
+    /// ```
+    fn try_from(value: &ServiceData16BitUUID) -> Result<Self, String> {
+        match short_form(&value.uuid) {
+            Some(ShortUuid::Uuid16(uuid)) => Self::try_new(uuid, &value.additional_service_data),
+            _ => Err(format!("Invalid service uuid :{}", value.uuid)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data_types::service_data_16bit_uuid::ServiceData16BitUUID, service_data::sensor::*,
+        uuid_from_u16,
+    };
+
+    #[test]
+    fn test_ieee11073_float() {
+        assert_eq!(0.0, ieee11073_float(0x0000_0000));
+        assert_eq!(1.0, ieee11073_float(0x0000_0001));
+        assert_eq!(-1.0, ieee11073_float(0x00ff_ffff));
+        assert_eq!(37.2, ieee11073_float(0xff00_0174));
+        assert_eq!(8_388_605.0, ieee11073_float(0x007f_fffd));
+        assert_eq!(f32::NEG_INFINITY, ieee11073_float(0x0080_0002));
+        assert!(ieee11073_float(0x0080_0000).is_nan());
+        assert!(ieee11073_float(0x0080_0001).is_nan());
+    }
+
+    #[test]
+    fn test_temperature_measurement() {
+        let result = SensorData::try_new(0x2a1c, &[0x00, 0x6d, 0x01, 0x00, 0xff]);
+        assert_eq!(Ok(SensorData::TemperatureMeasurement(36.5)), result);
+
+        let result = SensorData::try_new(0x2a1e, &[0x00, 0x6d, 0x01, 0x00, 0xff]);
+        assert_eq!(Ok(SensorData::TemperatureMeasurement(36.5)), result);
+
+        let result = SensorData::try_new(0x2a1c, &[0x05, 0x48, 0x08, 0x00, 0xff, 0x02]);
+        assert_eq!(Ok(SensorData::TemperatureMeasurement(100.0)), result);
+
+        let mut data = vec![0x02, 0x6d, 0x01, 0x00, 0xff];
+        data.append(&mut vec![0xe8, 0x07, 0x01, 0x01, 0x00, 0x00, 0x00]);
+        let result = SensorData::try_new(0x2a1c, &data);
+        assert_eq!(Ok(SensorData::TemperatureMeasurement(36.5)), result);
+
+        let result = SensorData::try_new(0x2a1c, &[0x02, 0x6d, 0x01, 0x00, 0xff]);
+        assert_eq!(Err("Invalid data size :5".to_string()), result);
+
+        let result = SensorData::try_new(0x2a1c, &[0x00, 0x6d, 0x01, 0x00]);
+        assert_eq!(Err("Invalid data size :4".to_string()), result);
+    }
+
+    #[test]
+    fn test_environmental_sensing() {
+        assert_eq!(
+            Ok(SensorData::Elevation(-1.0)),
+            SensorData::try_new(0x2a6c, &[0x9c, 0xff, 0xff])
+        );
+        assert_eq!(
+            Ok(SensorData::Elevation(83886.07)),
+            SensorData::try_new(0x2a6c, &[0xff, 0xff, 0x7f])
+        );
+        assert_eq!(
+            Ok(SensorData::Pressure(101325.0)),
+            SensorData::try_new(0x2a6d, &[0x02, 0x76, 0x0f, 0x00])
+        );
+        assert_eq!(
+            Ok(SensorData::Temperature(-10.25)),
+            SensorData::try_new(0x2a6e, &[0xff, 0xfb])
+        );
+        assert_eq!(
+            Err("Value is not known".to_string()),
+            SensorData::try_new(0x2a6e, &[0x00, 0x80])
+        );
+        assert_eq!(
+            Ok(SensorData::Humidity(100.0)),
+            SensorData::try_new(0x2a6f, &[0x10, 0x27])
+        );
+        assert_eq!(
+            Ok(SensorData::UvIndex(11.0)),
+            SensorData::try_new(0x2a76, &[0x0b])
+        );
+        assert_eq!(
+            Err("Invalid data size :2".to_string()),
+            SensorData::try_new(0x2a76, &[0x0b, 0x00])
+        );
+    }
+
+    #[test]
+    fn test_uuid_value() {
+        for uuid in [0x2a6c, 0x2a6d, 0x2a6e, 0x2a6f] {
+            let data = vec![
+                0x01u8;
+                if uuid == 0x2a6d {
+                    4
+                } else if uuid == 0x2a6c {
+                    3
+                } else {
+                    2
+                }
+            ];
+            let result = SensorData::try_new(uuid, &data).unwrap();
+            assert_eq!(uuid, result.uuid());
+        }
+        assert_eq!(
+            TEMPERATURE_MEASUREMENT_UUID,
+            SensorData::TemperatureMeasurement(0.0).uuid()
+        );
+        assert_eq!(UV_INDEX_UUID, SensorData::UvIndex(1.0).uuid());
+        assert_eq!(1.0, SensorData::UvIndex(1.0).value());
+    }
+
+    #[test]
+    fn test_try_from() {
+        let service_data = ServiceData16BitUUID::new(&uuid_from_u16(0x2a6e), &vec![0x2e, 0x09]);
+        assert_eq!(
+            Ok(SensorData::Temperature(23.5)),
+            SensorData::try_from(&service_data)
+        );
+
+        let service_data = ServiceData16BitUUID::new(&uuid_from_u16(0x180f), &vec![0x64]);
+        assert_eq!(
+            Err("Invalid characteristic uuid :0x180f".to_string()),
+            SensorData::try_from(&service_data)
+        );
+    }
+}
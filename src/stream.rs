@@ -0,0 +1,406 @@
+//! Async advertising payload stream module.
+
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_core::Stream;
+
+use crate::{
+    data_types::{data_type_parser::DataTypeParseResults, parse_options::ParseOptions},
+    replay::{advertising_reports, ReportReassembler},
+};
+
+/// Stream adapter which parses raw advertising payloads into [`DataTypeParseResults`].
+///
+/// Each item of inner stream (`Vec<u8>`, `bytes::Bytes`, ...) is one complete advertising payload.
+/// Use [`ChainParseStream`] for HCI advertising report events.
+pub struct ParseStream<S> {
+    inner: S,
+
+    /// Parse options
+    pub options: ParseOptions,
+}
+
+impl<S> ParseStream<S> {
+    /// Create [`ParseStream`] with default [`ParseOptions`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::complete_local_name::CompleteLocalName, stream::ParseStream,
+    /// };
+    /// use futures::{executor::block_on, stream, StreamExt};
+    ///
+    /// let data: Vec<u8> = CompleteLocalName::new(&"name".to_string()).into();
+    /// let mut parse_stream = ParseStream::new(stream::iter(vec![data]));
+    /// let results = block_on(parse_stream.next()).unwrap();
+    /// assert_eq!(Some("name"), results.device_name());
+    /// assert!(block_on(parse_stream.next()).is_none());
+    /// ```
+    pub fn new(inner: S) -> Self {
+        Self::with_options(inner, ParseOptions::default())
+    }
+
+    /// Create [`ParseStream`] with [`ParseOptions`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::{
+    ///         complete_local_name::CompleteLocalName,
+    ///         data_type::DataType,
+    ///         parse_options::{ParseOptions, Utf8DecodeMode},
+    ///     },
+    ///     stream::ParseStream,
+    /// };
+    /// use futures::{executor::block_on, stream, StreamExt};
+    ///
+    /// let data: Vec<u8> = vec![5, CompleteLocalName::data_type(), 0x6e, 0x61, 0xff, 0x65];
+    /// let parse_stream = ParseStream::with_options(
+    ///     stream::iter(vec![data]),
+    ///     ParseOptions::new(Utf8DecodeMode::Lossy),
+    /// );
+    /// let results: Vec<_> = block_on(parse_stream.collect());
+    /// assert_eq!(Some("na\u{fffd}e"), results[0].device_name());
+    /// ```
+    pub fn with_options(inner: S, options: ParseOptions) -> Self {
+        Self { inner, options }
+    }
+
+    /// Get inner stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::stream::ParseStream;
+    /// use futures::stream::{self, Iter, Stream};
+    ///
+    /// let parse_stream = ParseStream::new(stream::iter(vec![vec![0x02u8, 0x0a, 0x00]]));
+    /// let inner: Iter<_> = parse_stream.into_inner();
+    /// assert_eq!((1, Some(1)), inner.size_hint());
+    /// ```
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<S, B> Stream for ParseStream<S>
+where
+    S: Stream<Item = B> + Unpin,
+    B: AsRef<[u8]>,
+{
+    type Item = DataTypeParseResults;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let options = self.options;
+        Pin::new(&mut self.inner).poll_next(cx).map(|payload| {
+            payload.map(|payload| {
+                DataTypeParseResults::from_payload_with_options(payload.as_ref(), &options)
+            })
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+/// Stream adapter which parses LE Advertising Report / LE Extended Advertising Report events into
+/// advertiser address and [`DataTypeParseResults`].
+///
+/// Each item of inner stream (`Vec<u8>`, `bytes::Bytes`, ...) is one raw HCI LE Meta event, as
+/// read from an HCI socket (without H4 packet type). Other events are skipped. Reports are decoded
+/// by [`advertising_reports`], and fragments of LE Extended Advertising Report are joined per
+/// address and Advertising_SID by [`ReportReassembler`]. One result is yielded per complete
+/// report, and an error is yielded when the controller truncated the data. Fragments left
+/// incomplete at the end of inner stream are dropped.
+pub struct ChainParseStream<S> {
+    inner: S,
+
+    reassembler: ReportReassembler,
+
+    pending: VecDeque<Result<(String, DataTypeParseResults), String>>,
+
+    /// Parse options
+    pub options: ParseOptions,
+}
+
+impl<S> ChainParseStream<S> {
+    /// Create [`ChainParseStream`] with default [`ParseOptions`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::complete_local_name::CompleteLocalName, stream::ChainParseStream,
+    /// };
+    /// use futures::{executor::block_on, stream, StreamExt};
+    ///
+    /// // LE Extended Advertising Report event with Data_Status and Advertising_SID 1
+    /// let event = |data_status: u8, data: &[u8]| {
+    ///     let mut event: Vec<u8> = vec![0x3e, 0x00, 0x0d, 0x01, data_status << 5, 0x00, 0x00];
+    ///     event.extend_from_slice(&[0x06, 0x05, 0x04, 0x03, 0x02, 0x01]);
+    ///     event.extend_from_slice(&[0x01, 0x00, 0x01, 0x7f, 0xc4, 0x00, 0x00, 0x00]);
+    ///     event.extend_from_slice(&[0x00; 6]);
+    ///     event.push(data.len() as u8);
+    ///     event.extend_from_slice(data);
+    ///     event[1] = event.len() as u8 - 2;
+    ///     event
+    /// };
+    /// let data: Vec<u8> = CompleteLocalName::new(&"name".to_string()).into();
+    /// let mut parse_stream = ChainParseStream::new(stream::iter(vec![
+    ///     event(0b01, &data[..3]),
+    ///     event(0b00, &data[3..]),
+    ///     event(0b10, &data[..3]),
+    /// ]));
+    /// let (address, results) = block_on(parse_stream.next()).unwrap().unwrap();
+    /// assert_eq!("01:02:03:04:05:06", address);
+    /// assert_eq!(Some("name"), results.device_name());
+    /// let result = block_on(parse_stream.next()).unwrap();
+    /// assert_eq!(Some("Truncated data :01:02:03:04:05:06".to_string()), result.err());
+    /// assert!(block_on(parse_stream.next()).is_none());
+    /// ```
+    pub fn new(inner: S) -> Self {
+        Self::with_options(inner, ParseOptions::default())
+    }
+
+    /// Create [`ChainParseStream`] with [`ParseOptions`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::parse_options::{ParseOptions, Utf8DecodeMode},
+    ///     stream::ChainParseStream,
+    /// };
+    /// use futures::{executor::block_on, stream, StreamExt};
+    ///
+    /// // LE Advertising Report event with Complete Local Name
+    /// let event: Vec<u8> = vec![
+    ///     0x3e, 0x12, 0x02, 0x01, 0x00, 0x00, 0x06, 0x05, 0x04, 0x03, 0x02, 0x01, 0x06, 0x05,
+    ///     0x09, 0x6e, 0x61, 0xff, 0x65, 0xc4,
+    /// ];
+    /// let parse_stream = ChainParseStream::with_options(
+    ///     stream::iter(vec![event]),
+    ///     ParseOptions::new(Utf8DecodeMode::Lossy),
+    /// );
+    /// let results: Vec<_> = block_on(parse_stream.collect());
+    /// assert_eq!(Some("na\u{fffd}e"), results[0].as_ref().unwrap().1.device_name());
+    /// ```
+    pub fn with_options(inner: S, options: ParseOptions) -> Self {
+        Self {
+            inner,
+            reassembler: ReportReassembler::new(),
+            pending: VecDeque::new(),
+            options,
+        }
+    }
+
+    /// Get inner stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::stream::ChainParseStream;
+    /// use futures::stream::{self, Iter, Stream};
+    ///
+    /// let parse_stream = ChainParseStream::new(stream::iter(vec![vec![0x0eu8, 0x04, 0x01, 0x00]]));
+    /// let inner: Iter<_> = parse_stream.into_inner();
+    /// assert_eq!((1, Some(1)), inner.size_hint());
+    /// ```
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<S, B> Stream for ChainParseStream<S>
+where
+    S: Stream<Item = B> + Unpin,
+    B: AsRef<[u8]>,
+{
+    type Item = Result<(String, DataTypeParseResults), String>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            if let Some(item) = self.pending.pop_front() {
+                return Poll::Ready(Some(item));
+            }
+            match Pin::new(&mut self.inner).poll_next(cx) {
+                Poll::Ready(Some(event)) => {
+                    for report in advertising_reports(event.as_ref()) {
+                        let item = match self.reassembler.push(report) {
+                            Some(report) if report.is_truncated() => {
+                                Err(format!("Truncated data :{}", report.address))
+                            }
+                            Some(report) => Ok((
+                                report.address,
+                                DataTypeParseResults::from_payload_with_options(
+                                    &report.data,
+                                    &self.options,
+                                ),
+                            )),
+                            None => continue,
+                        };
+                        self.pending.push_back(item);
+                    }
+                }
+                Poll::Ready(None) => {
+                    self.reassembler.reset();
+                    return Poll::Ready(None);
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.pending.len(), None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::{executor::block_on, stream, Stream, StreamExt};
+
+    use crate::{
+        data_types::{
+            advertising_interval::AdvertisingInterval,
+            data_type_parser::DataTypeParseResult,
+            parse_options::{ParseOptions, Utf8DecodeMode},
+        },
+        stream::{ChainParseStream, ParseStream},
+    };
+
+    #[test]
+    fn test_new() {
+        let data: Vec<u8> = AdvertisingInterval::new(0x01).into();
+        let parse_stream = ParseStream::new(stream::iter(vec![data.clone(), data, vec![]]));
+        assert_eq!(ParseOptions::default(), parse_stream.options);
+        assert_eq!((3, Some(3)), parse_stream.size_hint());
+
+        let results: Vec<_> = block_on(parse_stream.collect());
+        assert_eq!(3, results.len());
+        assert!(matches!(
+            results[0].results[..],
+            [DataTypeParseResult::AdvertisingIntervalResult(Ok(_))]
+        ));
+        assert!(matches!(
+            results[1].results[..],
+            [DataTypeParseResult::AdvertisingIntervalResult(Ok(_))]
+        ));
+        assert!(results[2].results.is_empty());
+    }
+
+    #[test]
+    fn test_with_options() {
+        let parse_stream = ParseStream::with_options(
+            stream::iter(vec![[0x02u8, 0x09, 0xff]]),
+            ParseOptions::new(Utf8DecodeMode::Strict),
+        );
+        assert_eq!(
+            Utf8DecodeMode::Strict,
+            parse_stream.options.utf8_decode_mode
+        );
+
+        let results: Vec<_> = block_on(parse_stream.collect());
+        assert!(results[0].results[0].error().is_some());
+    }
+
+    #[test]
+    fn test_into_inner() {
+        let parse_stream = ParseStream::new(stream::iter(vec![vec![0x02u8, 0x0a, 0x00]]));
+        let mut inner = parse_stream.into_inner();
+        assert_eq!(Some(vec![0x02u8, 0x0a, 0x00]), block_on(inner.next()));
+    }
+
+    fn le_extended_advertising_report(data_status: u8, sid: u8, data: &[u8]) -> Vec<u8> {
+        let mut event: Vec<u8> = vec![0x3e, 0x00, 0x0d, 0x01, data_status << 5, 0x00, 0x00];
+        event.extend_from_slice(&[0x06, 0x05, 0x04, 0x03, 0x02, 0x01]);
+        event.extend_from_slice(&[0x01, 0x00, sid, 0x7f, 0xc4, 0x00, 0x00, 0x00]);
+        event.extend_from_slice(&[0x00; 6]);
+        event.push(data.len() as u8);
+        event.extend_from_slice(data);
+        event[1] = event.len() as u8 - 2;
+        event
+    }
+
+    #[test]
+    fn test_chain_new() {
+        let data: Vec<u8> = AdvertisingInterval::new(0x01).into();
+        let parse_stream = ChainParseStream::new(stream::iter(vec![
+            le_extended_advertising_report(0b01, 1, &data[..1]),
+            le_extended_advertising_report(0b01, 2, &data[..2]),
+            vec![0x0e, 0x04, 0x01, 0x03, 0x0c, 0x00],
+            le_extended_advertising_report(0b00, 1, &data[1..]),
+            le_extended_advertising_report(0b10, 2, &data[2..]),
+            le_extended_advertising_report(0b00, 0xff, &data),
+            le_extended_advertising_report(0b01, 1, &data[..1]),
+        ]));
+        assert_eq!(ParseOptions::default(), parse_stream.options);
+        assert_eq!((0, None), parse_stream.size_hint());
+
+        let results: Vec<_> = block_on(parse_stream.collect());
+        assert_eq!(3, results.len());
+        for result in [&results[0], &results[2]] {
+            let (address, results) = result.as_ref().unwrap();
+            assert_eq!("01:02:03:04:05:06", address);
+            assert!(matches!(
+                results.results[..],
+                [DataTypeParseResult::AdvertisingIntervalResult(Ok(_))]
+            ));
+        }
+        assert_eq!(
+            Some(&"Truncated data :01:02:03:04:05:06".to_string()),
+            results[1].as_ref().err()
+        );
+    }
+
+    #[test]
+    fn test_chain_multiple_reports() {
+        // LE Advertising Report event with 2 reports
+        let event: Vec<u8> = vec![
+            0x3e, 0x1a, 0x02, 0x02, 0x00, 0x00, 0x06, 0x05, 0x04, 0x03, 0x02, 0x01, 0x03, 0x02,
+            0x0a, 0x00, 0xc4, 0x00, 0x00, 0x16, 0x15, 0x14, 0x13, 0x12, 0x11, 0x00, 0xc4,
+        ];
+        let results: Vec<_> = block_on(ChainParseStream::new(stream::iter(vec![event])).collect());
+        assert_eq!(2, results.len());
+        let (address, results_0) = results[0].as_ref().unwrap();
+        assert_eq!("01:02:03:04:05:06", address);
+        assert_eq!(1, results_0.results.len());
+        let (address, results_1) = results[1].as_ref().unwrap();
+        assert_eq!("11:12:13:14:15:16", address);
+        assert!(results_1.results.is_empty());
+    }
+
+    #[test]
+    fn test_chain_with_options() {
+        let parse_stream = ChainParseStream::with_options(
+            stream::iter(vec![le_extended_advertising_report(
+                0b00,
+                0xff,
+                &[0x02, 0x09, 0xff],
+            )]),
+            ParseOptions::new(Utf8DecodeMode::Strict),
+        );
+        assert_eq!(
+            Utf8DecodeMode::Strict,
+            parse_stream.options.utf8_decode_mode
+        );
+
+        let results: Vec<_> = block_on(parse_stream.collect());
+        assert!(results[0].as_ref().unwrap().1.results[0].error().is_some());
+    }
+
+    #[test]
+    fn test_chain_into_inner() {
+        let parse_stream =
+            ChainParseStream::new(stream::iter(vec![vec![0x0eu8, 0x04, 0x01, 0x00]]));
+        let mut inner = parse_stream.into_inner();
+        assert_eq!(Some(vec![0x0eu8, 0x04, 0x01, 0x00]), block_on(inner.next()));
+    }
+}
@@ -0,0 +1,590 @@
+//! Test vector corpus module.
+//!
+//! Text corpus format: one `[name]` section per vector, `key = value` lines, `#` comments.
+//!
+//! ```text
+//! [Heart Rate sensor]
+//! payload = 020106 03030d18 0a09 4852 2053656e736f72
+//! local_name = HR Sensor
+//! service_uuids = 0000180d-0000-1000-8000-00805f9b34fb
+//! data_types = 01 03 09
+//! errors = 0
+//! ```
+//!
+//! Keys except `payload` are optional, and only given keys are checked.
+//!
+//! | key | value |
+//! | --- | --- |
+//! | `payload` | advertising payload (hex, whitespace ignored) |
+//! | `local_name` | device name |
+//! | `tx_power` | Tx Power Level (dBm) |
+//! | `service_uuids` | Service UUIDs (whitespace or comma separated) |
+//! | `manufacturer_data.<company identifier (hex)>` | Manufacturer Specific Data (hex) |
+//! | `service_data.<uuid>` | Service Data (hex) |
+//! | `data_types` | data type of each AD structure (hex, in order) |
+//! | `errors` | number of AD structures which failed to parse |
+
+use std::collections::BTreeMap;
+
+use uuid::Uuid;
+
+use crate::{
+    bluez::BlueZAdvertisement,
+    data_types::{data_type_parser::DataTypeParseResults, parse_options::ParseOptions},
+};
+
+/// Test vector (advertising payload and expected decoded fields).
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct TestVector {
+    /// Name
+    pub name: String,
+
+    /// Advertising payload
+    pub payload: Vec<u8>,
+
+    /// Expected device name
+    pub local_name: Option<String>,
+
+    /// Expected Tx Power Level
+    pub tx_power: Option<i8>,
+
+    /// Expected Service UUIDs
+    pub service_uuids: Option<Vec<Uuid>>,
+
+    /// Expected Manufacturer Specific Data
+    pub manufacturer_data: BTreeMap<u16, Vec<u8>>,
+
+    /// Expected Service Data
+    pub service_data: BTreeMap<Uuid, Vec<u8>>,
+
+    /// Expected data type of each AD structure
+    pub data_types: Option<Vec<u8>>,
+
+    /// Expected number of parse errors
+    pub errors: Option<usize>,
+}
+
+impl TestVector {
+    /// Parse [`TestVector::payload`] and check expected fields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::test_vectors::TestVector;
+    ///
+    /// let vector = TestVector {
+    ///     name: "tx power".to_string(),
+    ///     payload: vec![0x02, 0x0a, 0xfc],
+    ///     tx_power: Some(-4),
+    ///     ..Default::default()
+    /// };
+    /// assert!(vector.run().is_ok());
+    ///
+    /// let vector = TestVector {
+    ///     tx_power: Some(0),
+    ///     ..vector
+    /// };
+    /// assert_eq!(
+    ///     Err("tx power :tx_power expected Some(0), actual Some(-4)".to_string()),
+    ///     vector.run()
+    /// );
+    /// ```
+    pub fn run(&self) -> Result<(), String> {
+        let results = DataTypeParseResults::from_payload_with_options(
+            &self.payload,
+            &ParseOptions::default(),
+        );
+        let advertisement = BlueZAdvertisement::from(&results);
+        let mismatch =
+            |field: &str, expected: &dyn std::fmt::Debug, actual: &dyn std::fmt::Debug| {
+                Err(format!(
+                    "{} :{} expected {:?}, actual {:?}",
+                    self.name, field, expected, actual
+                ))
+            };
+
+        if self.local_name.is_some() && self.local_name != advertisement.local_name {
+            return mismatch("local_name", &self.local_name, &advertisement.local_name);
+        }
+        if self.tx_power.is_some() && self.tx_power != advertisement.tx_power {
+            return mismatch("tx_power", &self.tx_power, &advertisement.tx_power);
+        }
+        if let Some(service_uuids) = &self.service_uuids {
+            if *service_uuids != advertisement.service_uuids {
+                return mismatch("service_uuids", service_uuids, &advertisement.service_uuids);
+            }
+        }
+        for (company_identifier, data) in &self.manufacturer_data {
+            let actual = advertisement.manufacturer_data.get(company_identifier);
+            if actual != Some(data) {
+                return mismatch(
+                    &format!("manufacturer_data.{:04x}", company_identifier),
+                    data,
+                    &actual,
+                );
+            }
+        }
+        for (uuid, data) in &self.service_data {
+            let actual = advertisement.service_data.get(uuid);
+            if actual != Some(data) {
+                return mismatch(&format!("service_data.{}", uuid), data, &actual);
+            }
+        }
+        if let Some(data_types) = &self.data_types {
+            let actual: Vec<u8> = results
+                .offsets
+                .iter()
+                .filter_map(|offset| self.payload.get(offset + 1).copied())
+                .collect();
+            if *data_types != actual {
+                return mismatch("data_types", data_types, &actual);
+            }
+        }
+        if let Some(errors) = self.errors {
+            let actual = results.errors().count();
+            if errors != actual {
+                return mismatch("errors", &errors, &actual);
+            }
+        }
+        Ok(())
+    }
+
+    /// Set field from corpus `key` and `value`.
+    fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        let value = value.trim();
+        match key.split_once('.') {
+            Some(("manufacturer_data", company_identifier)) => {
+                let company_identifier = u16::from_str_radix(company_identifier, 16)
+                    .map_err(|_| format!("Invalid company identifier :{}", company_identifier))?;
+                self.manufacturer_data
+                    .insert(company_identifier, parse_hex(value)?);
+            }
+            Some(("service_data", uuid)) => {
+                self.service_data
+                    .insert(parse_uuid(uuid)?, parse_hex(value)?);
+            }
+            _ => match key {
+                "payload" => self.payload = parse_hex(value)?,
+                "local_name" => self.local_name = Some(value.to_string()),
+                "tx_power" => {
+                    self.tx_power = Some(
+                        value
+                            .parse()
+                            .map_err(|_| format!("Invalid tx_power :{}", value))?,
+                    )
+                }
+                "service_uuids" => {
+                    self.service_uuids = Some(
+                        value
+                            .split(|c: char| c.is_whitespace() || c == ',')
+                            .filter(|uuid| !uuid.is_empty())
+                            .map(parse_uuid)
+                            .collect::<Result<Vec<Uuid>, String>>()?,
+                    )
+                }
+                "data_types" => {
+                    self.data_types = Some(
+                        value
+                            .split(|c: char| c.is_whitespace() || c == ',')
+                            .filter(|data_type| !data_type.is_empty())
+                            .map(|data_type| {
+                                u8::from_str_radix(data_type, 16)
+                                    .map_err(|_| format!("Invalid data type :{}", data_type))
+                            })
+                            .collect::<Result<Vec<u8>, String>>()?,
+                    )
+                }
+                "errors" => {
+                    self.errors = Some(
+                        value
+                            .parse()
+                            .map_err(|_| format!("Invalid errors :{}", value))?,
+                    )
+                }
+                _ => return Err(format!("Unknown key :{}", key)),
+            },
+        }
+        Ok(())
+    }
+}
+
+/// Load [`TestVector`] list from text corpus.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::{test_vectors::parse_corpus, uuid_from_u16};
+///
+/// let corpus = "
+/// ## battery service
+/// [battery]
+/// payload = 03030f18 04160f1864
+/// service_uuids = 0000180f-0000-1000-8000-00805f9b34fb
+/// service_data.0000180f-0000-1000-8000-00805f9b34fb = 64
+/// ";
+/// let result = parse_corpus(corpus);
+/// assert!(result.is_ok());
+/// let vectors = result.unwrap();
+/// assert_eq!(1, vectors.len());
+/// assert_eq!("battery", vectors[0].name);
+/// assert_eq!(Some(vec![uuid_from_u16(0x180f)]), vectors[0].service_uuids);
+/// assert!(vectors[0].run().is_ok());
+///
+/// let result = parse_corpus("payload = 020106");
+/// assert_eq!(Err("line 1 :key outside of section".to_string()), result);
+/// ```
+pub fn parse_corpus(text: &str) -> Result<Vec<TestVector>, String> {
+    let mut vectors: Vec<TestVector> = Vec::new();
+    for (index, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let error = |message: String| format!("line {} :{}", index + 1, message);
+        if let Some(name) = line
+            .strip_prefix('[')
+            .and_then(|line| line.strip_suffix(']'))
+        {
+            vectors.push(TestVector {
+                name: name.trim().to_string(),
+                ..Default::default()
+            });
+            continue;
+        }
+        let vector = match vectors.last_mut() {
+            Some(vector) => vector,
+            None => return Err(error("key outside of section".to_string())),
+        };
+        match line.split_once('=') {
+            Some((key, value)) => vector.set(key.trim(), value).map_err(error)?,
+            None => return Err(error(format!("Invalid line :{}", line))),
+        }
+    }
+    Ok(vectors)
+}
+
+/// Load [`TestVector`] list from JSON corpus.
+///
+/// The corpus is an array of objects with `name` and the text corpus keys. `manufacturer_data` and
+/// `service_data` are objects, `tx_power` and `errors` are numbers, `service_uuids` and
+/// `data_types` are arrays.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::test_vectors::parse_json_corpus;
+///
+/// let corpus = r#"[{
+///     "name": "apple",
+///     "payload": "05ff4c000102",
+///     "manufacturer_data": {"004c": "0102"},
+///     "data_types": [255],
+///     "errors": 0
+/// }]"#;
+/// let result = parse_json_corpus(corpus);
+/// assert!(result.is_ok());
+/// let vectors = result.unwrap();
+/// assert_eq!(Some(&vec![0x01, 0x02]), vectors[0].manufacturer_data.get(&0x004c));
+/// assert!(vectors[0].run().is_ok());
+/// ```
+#[cfg(feature = "json")]
+pub fn parse_json_corpus(text: &str) -> Result<Vec<TestVector>, String> {
+    use serde_json::Value;
+
+    let value: Value = serde_json::from_str(text).map_err(|error| error.to_string())?;
+    let mut vectors: Vec<TestVector> = Vec::new();
+    for (index, object) in value
+        .as_array()
+        .ok_or("Invalid corpus :not an array")?
+        .iter()
+        .enumerate()
+    {
+        let error = |message: String| format!("vector {} :{}", index, message);
+        let object = object
+            .as_object()
+            .ok_or_else(|| error("not an object".to_string()))?;
+        let mut vector = TestVector::default();
+        for (key, value) in object {
+            match (key.as_str(), value) {
+                ("name", Value::String(name)) => vector.name = name.clone(),
+                ("manufacturer_data" | "service_data", Value::Object(map)) => {
+                    for (sub_key, sub_value) in map {
+                        vector
+                            .set(
+                                &format!("{}.{}", key, sub_key),
+                                sub_value.as_str().unwrap_or_default(),
+                            )
+                            .map_err(error)?;
+                    }
+                }
+                ("data_types", Value::Array(data_types)) => {
+                    vector.data_types = Some(
+                        data_types
+                            .iter()
+                            .map(|data_type| {
+                                data_type
+                                    .as_u64()
+                                    .and_then(|data_type| u8::try_from(data_type).ok())
+                                    .ok_or_else(|| {
+                                        error(format!("Invalid data type :{}", data_type))
+                                    })
+                            })
+                            .collect::<Result<Vec<u8>, String>>()?,
+                    )
+                }
+                (_, Value::Array(values)) => {
+                    let values: Vec<String> = values
+                        .iter()
+                        .map(|value| value.as_str().unwrap_or_default().to_string())
+                        .collect();
+                    vector.set(key, &values.join(" ")).map_err(error)?
+                }
+                (_, Value::String(value)) => vector.set(key, value).map_err(error)?,
+                (_, value) => vector.set(key, &value.to_string()).map_err(error)?,
+            }
+        }
+        vectors.push(vector);
+    }
+    Ok(vectors)
+}
+
+/// Run all [`TestVector`] and collect failures.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::test_vectors::{parse_corpus, run_all};
+///
+/// let corpus = "
+/// [ok]
+/// payload = 020afc
+/// tx_power = -4
+/// [ng]
+/// payload = 020afc 0309
+/// errors = 0
+/// ";
+/// let vectors = parse_corpus(corpus).unwrap();
+/// let result = run_all(&vectors);
+/// assert!(result.is_err());
+/// let errors = result.unwrap_err();
+/// assert_eq!(1, errors.len());
+/// assert!(errors[0].starts_with("ng :errors"));
+///
+/// assert!(run_all(&vectors[..1]).is_ok());
+/// ```
+pub fn run_all(vectors: &[TestVector]) -> Result<(), Vec<String>> {
+    let errors: Vec<String> = vectors
+        .iter()
+        .filter_map(|vector| vector.run().err())
+        .collect();
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+fn parse_hex(value: &str) -> Result<Vec<u8>, String> {
+    let digits: Vec<char> = value.chars().filter(|c| !c.is_whitespace()).collect();
+    if !digits.len().is_multiple_of(2) {
+        return Err(format!("Invalid hex length :{}", digits.len()));
+    }
+    digits
+        .chunks(2)
+        .map(|pair| {
+            let pair: String = pair.iter().collect();
+            u8::from_str_radix(&pair, 16).map_err(|_| format!("Invalid hex :{}", pair))
+        })
+        .collect()
+}
+
+fn parse_uuid(value: &str) -> Result<Uuid, String> {
+    Uuid::parse_str(value.trim()).map_err(|_| format!("Invalid UUID :{}", value))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use uuid::uuid;
+
+    use crate::{
+        test_vectors::{parse_corpus, run_all, TestVector},
+        uuid_from_u16,
+    };
+
+    #[test]
+    fn test_parse_corpus() {
+        let corpus = "
+# comment
+[ first ]
+payload = 02 01 06
+local_name = a b
+tx_power = -127
+service_uuids = 0000180f-0000-1000-8000-00805f9b34fb, 12345678-90ab-cdef-1234-567890abcdef
+manufacturer_data.004C = 0102
+service_data.0000180f-0000-1000-8000-00805f9b34fb =
+data_types = 01,ff
+errors = 2
+
+[second]
+";
+        let result = parse_corpus(corpus);
+        assert!(result.is_ok());
+        let vectors = result.unwrap();
+        assert_eq!(
+            vec![
+                TestVector {
+                    name: "first".to_string(),
+                    payload: vec![0x02, 0x01, 0x06],
+                    local_name: Some("a b".to_string()),
+                    tx_power: Some(-127),
+                    service_uuids: Some(vec![
+                        uuid_from_u16(0x180f),
+                        uuid!("12345678-90ab-cdef-1234-567890abcdef")
+                    ]),
+                    manufacturer_data: BTreeMap::from([(0x004c, vec![0x01, 0x02])]),
+                    service_data: BTreeMap::from([(uuid_from_u16(0x180f), vec![])]),
+                    data_types: Some(vec![0x01, 0xff]),
+                    errors: Some(2),
+                },
+                TestVector {
+                    name: "second".to_string(),
+                    ..Default::default()
+                }
+            ],
+            vectors
+        );
+
+        assert!(parse_corpus("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parse_corpus_error() {
+        let cases = [
+            ("payload = 00", "line 1 :key outside of section"),
+            ("[a]\npayload", "line 2 :Invalid line :payload"),
+            ("[a]\nunknown = 0", "line 2 :Unknown key :unknown"),
+            ("[a]\npayload = 012", "line 2 :Invalid hex length :3"),
+            ("[a]\npayload = 0g", "line 2 :Invalid hex :0g"),
+            ("[a]\ntx_power = 128", "line 2 :Invalid tx_power :128"),
+            ("[a]\nservice_uuids = 180f", "line 2 :Invalid UUID :180f"),
+            (
+                "[a]\nmanufacturer_data.x = 00",
+                "line 2 :Invalid company identifier :x",
+            ),
+            ("[a]\ndata_types = 100", "line 2 :Invalid data type :100"),
+            ("[a]\nerrors = -1", "line 2 :Invalid errors :-1"),
+        ];
+        for (corpus, error) in cases {
+            assert_eq!(Err(error.to_string()), parse_corpus(corpus), "{}", corpus);
+        }
+    }
+
+    #[test]
+    fn test_run() {
+        let vector = TestVector {
+            name: "all".to_string(),
+            payload: vec![
+                0x02, 0x0a, 0x00, 0x03, 0x03, 0x0f, 0x18, 0x04, 0x16, 0x0f, 0x18, 0x64, 0x04, 0xff,
+                0x4c, 0x00, 0x01, 0x02, 0x09, 0x61,
+            ],
+            local_name: Some("a".to_string()),
+            tx_power: Some(0),
+            service_uuids: Some(vec![uuid_from_u16(0x180f)]),
+            manufacturer_data: BTreeMap::from([(0x004c, vec![0x01])]),
+            service_data: BTreeMap::from([(uuid_from_u16(0x180f), vec![0x64])]),
+            data_types: Some(vec![0x0a, 0x03, 0x16, 0xff, 0x09]),
+            errors: Some(0),
+        };
+        assert_eq!(Ok(()), vector.run());
+        assert_eq!(Ok(()), TestVector::default().run());
+
+        let mismatches = [
+            TestVector {
+                local_name: Some("b".to_string()),
+                ..vector.clone()
+            },
+            TestVector {
+                tx_power: Some(1),
+                ..vector.clone()
+            },
+            TestVector {
+                service_uuids: Some(vec![]),
+                ..vector.clone()
+            },
+            TestVector {
+                manufacturer_data: BTreeMap::from([(0x0006, vec![0x01])]),
+                ..vector.clone()
+            },
+            TestVector {
+                service_data: BTreeMap::from([(uuid_from_u16(0x180f), vec![])]),
+                ..vector.clone()
+            },
+            TestVector {
+                data_types: Some(vec![0x0a]),
+                ..vector.clone()
+            },
+            TestVector {
+                errors: Some(1),
+                ..vector.clone()
+            },
+        ];
+        for mismatch in &mismatches {
+            assert!(mismatch.run().is_err());
+        }
+        assert_eq!(
+            Err("all :manufacturer_data.0006 expected [1], actual None".to_string()),
+            mismatches[3].run()
+        );
+        assert_eq!(Err(7), run_all(&mismatches).map_err(|errors| errors.len()));
+    }
+
+    #[test]
+    #[cfg(feature = "json")]
+    fn test_parse_json_corpus() {
+        use crate::test_vectors::parse_json_corpus;
+
+        let corpus = r#"[{
+            "name": "json",
+            "payload": "020afc",
+            "tx_power": -4,
+            "service_uuids": ["0000180f-0000-1000-8000-00805f9b34fb"],
+            "service_data": {"0000180f-0000-1000-8000-00805f9b34fb": "64"},
+            "data_types": [10],
+            "errors": 0
+        }]"#;
+        let vectors = parse_json_corpus(corpus).unwrap();
+        assert_eq!(
+            vec![TestVector {
+                name: "json".to_string(),
+                payload: vec![0x02, 0x0a, 0xfc],
+                tx_power: Some(-4),
+                service_uuids: Some(vec![uuid_from_u16(0x180f)]),
+                service_data: BTreeMap::from([(uuid_from_u16(0x180f), vec![0x64])]),
+                data_types: Some(vec![0x0a]),
+                errors: Some(0),
+                ..Default::default()
+            }],
+            vectors
+        );
+
+        assert_eq!(
+            Err("Invalid corpus :not an array".to_string()),
+            parse_json_corpus("{}")
+        );
+        assert_eq!(
+            Err("vector 0 :not an object".to_string()),
+            parse_json_corpus("[0]")
+        );
+        assert_eq!(
+            Err("vector 0 :Invalid data type :256".to_string()),
+            parse_json_corpus(r#"[{"data_types": [256]}]"#)
+        );
+        assert_eq!(
+            Err("vector 0 :Invalid tx_power :a".to_string()),
+            parse_json_corpus(r#"[{"tx_power": "a"}]"#)
+        );
+        assert!(parse_json_corpus("[").is_err());
+    }
+}
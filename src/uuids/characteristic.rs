@@ -0,0 +1,500 @@
+//! GATT characteristic UUID registry module.
+
+use uuid::Uuid;
+
+use crate::{short_form, ShortUuid};
+
+/// GATT characteristic names.
+const CHARACTERISTIC_NAMES: [(u16, &str); 409] = [
+    (0x2a00, "Device Name"),
+    (0x2a01, "Appearance"),
+    (0x2a02, "Peripheral Privacy Flag"),
+    (0x2a03, "Reconnection Address"),
+    (0x2a04, "Peripheral Preferred Connection Parameters"),
+    (0x2a05, "Service Changed"),
+    (0x2a06, "Alert Level"),
+    (0x2a07, "Tx Power Level"),
+    (0x2a08, "Date Time"),
+    (0x2a09, "Day of Week"),
+    (0x2a0a, "Day Date Time"),
+    (0x2a0c, "Exact Time 256"),
+    (0x2a0d, "DST Offset"),
+    (0x2a0e, "Time Zone"),
+    (0x2a0f, "Local Time Information"),
+    (0x2a11, "Time with DST"),
+    (0x2a12, "Time Accuracy"),
+    (0x2a13, "Time Source"),
+    (0x2a14, "Reference Time Information"),
+    (0x2a16, "Time Update Control Point"),
+    (0x2a17, "Time Update State"),
+    (0x2a18, "Glucose Measurement"),
+    (0x2a19, "Battery Level"),
+    (0x2a1c, "Temperature Measurement"),
+    (0x2a1d, "Temperature Type"),
+    (0x2a1e, "Intermediate Temperature"),
+    (0x2a21, "Measurement Interval"),
+    (0x2a22, "Boot Keyboard Input Report"),
+    (0x2a23, "System ID"),
+    (0x2a24, "Model Number String"),
+    (0x2a25, "Serial Number String"),
+    (0x2a26, "Firmware Revision String"),
+    (0x2a27, "Hardware Revision String"),
+    (0x2a28, "Software Revision String"),
+    (0x2a29, "Manufacturer Name String"),
+    (
+        0x2a2a,
+        "IEEE 11073-20601 Regulatory Certification Data List",
+    ),
+    (0x2a2b, "Current Time"),
+    (0x2a2c, "Magnetic Declination"),
+    (0x2a31, "Scan Refresh"),
+    (0x2a32, "Boot Keyboard Output Report"),
+    (0x2a33, "Boot Mouse Input Report"),
+    (0x2a34, "Glucose Measurement Context"),
+    (0x2a35, "Blood Pressure Measurement"),
+    (0x2a36, "Intermediate Cuff Pressure"),
+    (0x2a37, "Heart Rate Measurement"),
+    (0x2a38, "Body Sensor Location"),
+    (0x2a39, "Heart Rate Control Point"),
+    (0x2a3f, "Alert Status"),
+    (0x2a40, "Ringer Control Point"),
+    (0x2a41, "Ringer Setting"),
+    (0x2a42, "Alert Category ID Bit Mask"),
+    (0x2a43, "Alert Category ID"),
+    (0x2a44, "Alert Notification Control Point"),
+    (0x2a45, "Unread Alert Status"),
+    (0x2a46, "New Alert"),
+    (0x2a47, "Supported New Alert Category"),
+    (0x2a48, "Supported Unread Alert Category"),
+    (0x2a49, "Blood Pressure Feature"),
+    (0x2a4a, "HID Information"),
+    (0x2a4b, "Report Map"),
+    (0x2a4c, "HID Control Point"),
+    (0x2a4d, "Report"),
+    (0x2a4e, "Protocol Mode"),
+    (0x2a4f, "Scan Interval Window"),
+    (0x2a50, "PnP ID"),
+    (0x2a51, "Glucose Feature"),
+    (0x2a52, "Record Access Control Point"),
+    (0x2a53, "RSC Measurement"),
+    (0x2a54, "RSC Feature"),
+    (0x2a55, "SC Control Point"),
+    (0x2a5a, "Aggregate"),
+    (0x2a5b, "CSC Measurement"),
+    (0x2a5c, "CSC Feature"),
+    (0x2a5d, "Sensor Location"),
+    (0x2a5e, "PLX Spot-Check Measurement"),
+    (0x2a5f, "PLX Continuous Measurement"),
+    (0x2a60, "PLX Features"),
+    (0x2a63, "Cycling Power Measurement"),
+    (0x2a64, "Cycling Power Vector"),
+    (0x2a65, "Cycling Power Feature"),
+    (0x2a66, "Cycling Power Control Point"),
+    (0x2a67, "Location and Speed"),
+    (0x2a68, "Navigation"),
+    (0x2a69, "Position Quality"),
+    (0x2a6a, "LN Feature"),
+    (0x2a6b, "LN Control Point"),
+    (0x2a6c, "Elevation"),
+    (0x2a6d, "Pressure"),
+    (0x2a6e, "Temperature"),
+    (0x2a6f, "Humidity"),
+    (0x2a70, "True Wind Speed"),
+    (0x2a71, "True Wind Direction"),
+    (0x2a72, "Apparent Wind Speed"),
+    (0x2a73, "Apparent Wind Direction"),
+    (0x2a74, "Gust Factor"),
+    (0x2a75, "Pollen Concentration"),
+    (0x2a76, "UV Index"),
+    (0x2a77, "Irradiance"),
+    (0x2a78, "Rainfall"),
+    (0x2a79, "Wind Chill"),
+    (0x2a7a, "Heat Index"),
+    (0x2a7b, "Dew Point"),
+    (0x2a7d, "Descriptor Value Changed"),
+    (0x2a7e, "Aerobic Heart Rate Lower Limit"),
+    (0x2a7f, "Aerobic Threshold"),
+    (0x2a80, "Age"),
+    (0x2a81, "Anaerobic Heart Rate Lower Limit"),
+    (0x2a82, "Anaerobic Heart Rate Upper Limit"),
+    (0x2a83, "Anaerobic Threshold"),
+    (0x2a84, "Aerobic Heart Rate Upper Limit"),
+    (0x2a85, "Date of Birth"),
+    (0x2a86, "Date of Threshold Assessment"),
+    (0x2a87, "Email Address"),
+    (0x2a88, "Fat Burn Heart Rate Lower Limit"),
+    (0x2a89, "Fat Burn Heart Rate Upper Limit"),
+    (0x2a8a, "First Name"),
+    (0x2a8b, "Five Zone Heart Rate Limits"),
+    (0x2a8c, "Gender"),
+    (0x2a8d, "Heart Rate Max"),
+    (0x2a8e, "Height"),
+    (0x2a8f, "Hip Circumference"),
+    (0x2a90, "Last Name"),
+    (0x2a91, "Maximum Recommended Heart Rate"),
+    (0x2a92, "Resting Heart Rate"),
+    (0x2a93, "Sport Type for Aerobic and Anaerobic Thresholds"),
+    (0x2a94, "Three Zone Heart Rate Limits"),
+    (0x2a95, "Two Zone Heart Rate Limits"),
+    (0x2a96, "VO2 Max"),
+    (0x2a97, "Waist Circumference"),
+    (0x2a98, "Weight"),
+    (0x2a99, "Database Change Increment"),
+    (0x2a9a, "User Index"),
+    (0x2a9b, "Body Composition Feature"),
+    (0x2a9c, "Body Composition Measurement"),
+    (0x2a9d, "Weight Measurement"),
+    (0x2a9e, "Weight Scale Feature"),
+    (0x2a9f, "User Control Point"),
+    (0x2aa0, "Magnetic Flux Density - 2D"),
+    (0x2aa1, "Magnetic Flux Density - 3D"),
+    (0x2aa2, "Language"),
+    (0x2aa3, "Barometric Pressure Trend"),
+    (0x2aa4, "Bond Management Control Point"),
+    (0x2aa5, "Bond Management Feature"),
+    (0x2aa6, "Central Address Resolution"),
+    (0x2aa7, "CGM Measurement"),
+    (0x2aa8, "CGM Feature"),
+    (0x2aa9, "CGM Status"),
+    (0x2aaa, "CGM Session Start Time"),
+    (0x2aab, "CGM Session Run Time"),
+    (0x2aac, "CGM Specific Ops Control Point"),
+    (0x2aad, "Indoor Positioning Configuration"),
+    (0x2aae, "Latitude"),
+    (0x2aaf, "Longitude"),
+    (0x2ab0, "Local North Coordinate"),
+    (0x2ab1, "Local East Coordinate"),
+    (0x2ab2, "Floor Number"),
+    (0x2ab3, "Altitude"),
+    (0x2ab4, "Uncertainty"),
+    (0x2ab5, "Location Name"),
+    (0x2ab6, "URI"),
+    (0x2ab7, "HTTP Headers"),
+    (0x2ab8, "HTTP Status Code"),
+    (0x2ab9, "HTTP Entity Body"),
+    (0x2aba, "HTTP Control Point"),
+    (0x2abb, "HTTPS Security"),
+    (0x2abc, "TDS Control Point"),
+    (0x2abd, "OTS Feature"),
+    (0x2abe, "Object Name"),
+    (0x2abf, "Object Type"),
+    (0x2ac0, "Object Size"),
+    (0x2ac1, "Object First-Created"),
+    (0x2ac2, "Object Last-Modified"),
+    (0x2ac3, "Object ID"),
+    (0x2ac4, "Object Properties"),
+    (0x2ac5, "Object Action Control Point"),
+    (0x2ac6, "Object List Control Point"),
+    (0x2ac7, "Object List Filter"),
+    (0x2ac8, "Object Changed"),
+    (0x2ac9, "Resolvable Private Address Only"),
+    (0x2acc, "Fitness Machine Feature"),
+    (0x2acd, "Treadmill Data"),
+    (0x2ace, "Cross Trainer Data"),
+    (0x2acf, "Step Climber Data"),
+    (0x2ad0, "Stair Climber Data"),
+    (0x2ad1, "Rower Data"),
+    (0x2ad2, "Indoor Bike Data"),
+    (0x2ad3, "Training Status"),
+    (0x2ad4, "Supported Speed Range"),
+    (0x2ad5, "Supported Inclination Range"),
+    (0x2ad6, "Supported Resistance Level Range"),
+    (0x2ad7, "Supported Heart Rate Range"),
+    (0x2ad8, "Supported Power Range"),
+    (0x2ad9, "Fitness Machine Control Point"),
+    (0x2ada, "Fitness Machine Status"),
+    (0x2adb, "Mesh Provisioning Data In"),
+    (0x2adc, "Mesh Provisioning Data Out"),
+    (0x2add, "Mesh Proxy Data In"),
+    (0x2ade, "Mesh Proxy Data Out"),
+    (0x2ae0, "Average Current"),
+    (0x2ae1, "Average Voltage"),
+    (0x2ae2, "Boolean"),
+    (0x2ae3, "Chromatic Distance from Planckian"),
+    (0x2ae4, "Chromaticity Coordinates"),
+    (0x2ae5, "Chromaticity in CCT and Duv Values"),
+    (0x2ae6, "Chromaticity Tolerance"),
+    (0x2ae7, "CIE 13.3-1995 Color Rendering Index"),
+    (0x2ae8, "Coefficient"),
+    (0x2ae9, "Correlated Color Temperature"),
+    (0x2aea, "Count 16"),
+    (0x2aeb, "Count 24"),
+    (0x2aec, "Country Code"),
+    (0x2aed, "Date UTC"),
+    (0x2aee, "Electric Current"),
+    (0x2aef, "Electric Current Range"),
+    (0x2af0, "Electric Current Specification"),
+    (0x2af1, "Electric Current Statistics"),
+    (0x2af2, "Energy"),
+    (0x2af3, "Energy in a Period of Day"),
+    (0x2af4, "Event Statistics"),
+    (0x2af5, "Fixed String 16"),
+    (0x2af6, "Fixed String 24"),
+    (0x2af7, "Fixed String 36"),
+    (0x2af8, "Fixed String 8"),
+    (0x2af9, "Generic Level"),
+    (0x2afa, "Global Trade Item Number"),
+    (0x2afb, "Illuminance"),
+    (0x2afc, "Luminous Efficacy"),
+    (0x2afd, "Luminous Energy"),
+    (0x2afe, "Luminous Exposure"),
+    (0x2aff, "Luminous Flux"),
+    (0x2b00, "Luminous Flux Range"),
+    (0x2b01, "Luminous Intensity"),
+    (0x2b02, "Mass Flow"),
+    (0x2b03, "Perceived Lightness"),
+    (0x2b04, "Percentage 8"),
+    (0x2b05, "Power"),
+    (0x2b06, "Power Specification"),
+    (0x2b07, "Relative Runtime in a Current Range"),
+    (0x2b08, "Relative Runtime in a Generic Level Range"),
+    (0x2b09, "Relative Value in a Voltage Range"),
+    (0x2b0a, "Relative Value in an Illuminance Range"),
+    (0x2b0b, "Relative Value in a Period of Day"),
+    (0x2b0c, "Relative Value in a Temperature Range"),
+    (0x2b0d, "Temperature 8"),
+    (0x2b0e, "Temperature 8 in a Period of Day"),
+    (0x2b0f, "Temperature 8 Statistics"),
+    (0x2b10, "Temperature Range"),
+    (0x2b11, "Temperature Statistics"),
+    (0x2b12, "Time Decihour 8"),
+    (0x2b13, "Time Exponential 8"),
+    (0x2b14, "Time Hour 24"),
+    (0x2b15, "Time Millisecond 24"),
+    (0x2b16, "Time Second 16"),
+    (0x2b17, "Time Second 8"),
+    (0x2b18, "Voltage"),
+    (0x2b19, "Voltage Specification"),
+    (0x2b1a, "Voltage Statistics"),
+    (0x2b1b, "Volume Flow"),
+    (0x2b1c, "Chromaticity Coordinate"),
+    (0x2b1d, "RC Feature"),
+    (0x2b1e, "RC Settings"),
+    (0x2b1f, "Reconnection Configuration Control Point"),
+    (0x2b20, "IDD Status Changed"),
+    (0x2b21, "IDD Status"),
+    (0x2b22, "IDD Annunciation Status"),
+    (0x2b23, "IDD Features"),
+    (0x2b24, "IDD Status Reader Control Point"),
+    (0x2b25, "IDD Command Control Point"),
+    (0x2b26, "IDD Command Data"),
+    (0x2b27, "IDD Record Access Control Point"),
+    (0x2b28, "IDD History Data"),
+    (0x2b29, "Client Supported Features"),
+    (0x2b2a, "Database Hash"),
+    (0x2b2b, "BSS Control Point"),
+    (0x2b2c, "BSS Response"),
+    (0x2b2d, "Emergency ID"),
+    (0x2b2e, "Emergency Text"),
+    (0x2b34, "Enhanced Blood Pressure Measurement"),
+    (0x2b35, "Enhanced Intermediate Cuff Pressure"),
+    (0x2b36, "Blood Pressure Record"),
+    (0x2b37, "Registered User"),
+    (0x2b38, "BR-EDR Handover Data"),
+    (0x2b39, "Bluetooth SIG Data"),
+    (0x2b3a, "Server Supported Features"),
+    (0x2b3b, "Physical Activity Monitor Features"),
+    (0x2b3c, "General Activity Instantaneous Data"),
+    (0x2b3d, "General Activity Summary Data"),
+    (0x2b3e, "CardioRespiratory Activity Instantaneous Data"),
+    (0x2b3f, "CardioRespiratory Activity Summary Data"),
+    (0x2b40, "Step Counter Activity Summary Data"),
+    (0x2b41, "Sleep Activity Instantaneous Data"),
+    (0x2b42, "Sleep Activity Summary Data"),
+    (0x2b43, "Physical Activity Monitor Control Point"),
+    (0x2b44, "Physical Activity Current Session"),
+    (0x2b45, "Physical Activity Session Descriptor"),
+    (0x2b46, "Preferred Units"),
+    (0x2b47, "High Resolution Height"),
+    (0x2b48, "Middle Name"),
+    (0x2b49, "Stride Length"),
+    (0x2b4a, "Handedness"),
+    (0x2b4b, "Device Wearing Position"),
+    (0x2b4c, "Four Zone Heart Rate Limits"),
+    (0x2b4d, "High Intensity Exercise Threshold"),
+    (0x2b4e, "Activity Goal"),
+    (0x2b4f, "Sedentary Interval Notification"),
+    (0x2b50, "Caloric Intake"),
+    (0x2b51, "TMAP Role"),
+    (0x2b77, "Audio Input State"),
+    (0x2b78, "Gain Settings Attribute"),
+    (0x2b79, "Audio Input Type"),
+    (0x2b7a, "Audio Input Status"),
+    (0x2b7b, "Audio Input Control Point"),
+    (0x2b7c, "Audio Input Description"),
+    (0x2b7d, "Volume State"),
+    (0x2b7e, "Volume Control Point"),
+    (0x2b7f, "Volume Flags"),
+    (0x2b80, "Volume Offset State"),
+    (0x2b81, "Audio Location"),
+    (0x2b82, "Volume Offset Control Point"),
+    (0x2b83, "Audio Output Description"),
+    (0x2b84, "Set Identity Resolving Key"),
+    (0x2b85, "Coordinated Set Size"),
+    (0x2b86, "Set Member Lock"),
+    (0x2b87, "Set Member Rank"),
+    (0x2b88, "Encrypted Data Key Material"),
+    (0x2b89, "Apparent Energy 32"),
+    (0x2b8a, "Apparent Power"),
+    (0x2b8b, "Live Health Observations"),
+    (0x2b8c, "CO2 Concentration"),
+    (0x2b8d, "Cosine of the Angle"),
+    (0x2b8e, "Device Time Feature"),
+    (0x2b8f, "Device Time Parameters"),
+    (0x2b90, "Device Time"),
+    (0x2b91, "Device Time Control Point"),
+    (0x2b92, "Time Change Log Data"),
+    (0x2b93, "Media Player Name"),
+    (0x2b94, "Media Player Icon Object ID"),
+    (0x2b95, "Media Player Icon URL"),
+    (0x2b96, "Track Changed"),
+    (0x2b97, "Track Title"),
+    (0x2b98, "Track Duration"),
+    (0x2b99, "Track Position"),
+    (0x2b9a, "Playback Speed"),
+    (0x2b9b, "Seeking Speed"),
+    (0x2b9c, "Current Track Segments Object ID"),
+    (0x2b9d, "Current Track Object ID"),
+    (0x2b9e, "Next Track Object ID"),
+    (0x2b9f, "Parent Group Object ID"),
+    (0x2ba0, "Current Group Object ID"),
+    (0x2ba1, "Playing Order"),
+    (0x2ba2, "Playing Orders Supported"),
+    (0x2ba3, "Media State"),
+    (0x2ba4, "Media Control Point"),
+    (0x2ba5, "Media Control Point Opcodes Supported"),
+    (0x2ba6, "Search Results Object ID"),
+    (0x2ba7, "Search Control Point"),
+    (0x2ba8, "Energy 32"),
+    (0x2ba9, "Media Player Icon Object Type"),
+    (0x2baa, "Track Segments Object Type"),
+    (0x2bab, "Track Object Type"),
+    (0x2bac, "Group Object Type"),
+    (0x2bad, "Constant Tone Extension Enable"),
+    (0x2bae, "Advertising Constant Tone Extension Minimum Length"),
+    (
+        0x2baf,
+        "Advertising Constant Tone Extension Minimum Transmit Count",
+    ),
+    (
+        0x2bb0,
+        "Advertising Constant Tone Extension Transmit Duration",
+    ),
+    (0x2bb1, "Advertising Constant Tone Extension Interval"),
+    (0x2bb2, "Advertising Constant Tone Extension PHY"),
+    (0x2bb3, "Bearer Provider Name"),
+    (0x2bb4, "Bearer UCI"),
+    (0x2bb5, "Bearer Technology"),
+    (0x2bb6, "Bearer URI Schemes Supported List"),
+    (0x2bb7, "Bearer Signal Strength"),
+    (0x2bb8, "Bearer Signal Strength Reporting Interval"),
+    (0x2bb9, "Bearer List Current Calls"),
+    (0x2bba, "Content Control ID"),
+    (0x2bbb, "Status Flags"),
+    (0x2bbc, "Incoming Call Target Bearer URI"),
+    (0x2bbd, "Call State"),
+    (0x2bbe, "Call Control Point"),
+    (0x2bbf, "Call Control Point Optional Opcodes"),
+    (0x2bc0, "Termination Reason"),
+    (0x2bc1, "Incoming Call"),
+    (0x2bc2, "Call Friendly Name"),
+    (0x2bc3, "Mute"),
+    (0x2bc4, "Sink ASE"),
+    (0x2bc5, "Source ASE"),
+    (0x2bc6, "ASE Control Point"),
+    (0x2bc7, "Broadcast Audio Scan Control Point"),
+    (0x2bc8, "Broadcast Receive State"),
+    (0x2bc9, "Sink PAC"),
+    (0x2bca, "Sink Audio Locations"),
+    (0x2bcb, "Source PAC"),
+    (0x2bcc, "Source Audio Locations"),
+    (0x2bcd, "Available Audio Contexts"),
+    (0x2bce, "Supported Audio Contexts"),
+    (0x2bcf, "Ammonia Concentration"),
+    (0x2bd0, "Carbon Monoxide Concentration"),
+    (0x2bd1, "Methane Concentration"),
+    (0x2bd2, "Nitrogen Dioxide Concentration"),
+    (
+        0x2bd3,
+        "Non-Methane Volatile Organic Compounds Concentration",
+    ),
+    (0x2bd4, "Ozone Concentration"),
+    (0x2bd5, "Particulate Matter - PM1 Concentration"),
+    (0x2bd6, "Particulate Matter - PM2.5 Concentration"),
+    (0x2bd7, "Particulate Matter - PM10 Concentration"),
+    (0x2bd8, "Sulfur Dioxide Concentration"),
+    (0x2bd9, "Sulfur Hexafluoride Concentration"),
+    (0x2bda, "Hearing Aid Features"),
+    (0x2bdb, "Hearing Aid Preset Control Point"),
+    (0x2bdc, "Active Preset Index"),
+];
+
+/// Get name of GATT characteristic UUID.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::uuids::characteristic::characteristic_name;
+///
+/// assert_eq!(Some("Battery Level"), characteristic_name(0x2a19));
+/// assert_eq!(None, characteristic_name(0x2a0b));
+/// ```
+pub fn characteristic_name(uuid: u16) -> Option<&'static str> {
+    CHARACTERISTIC_NAMES
+        .binary_search_by_key(&uuid, |&(key, _)| key)
+        .ok()
+        .map(|index| CHARACTERISTIC_NAMES[index].1)
+}
+
+/// Get name of GATT characteristic [`Uuid`].
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::{uuid_from_u16, uuids::characteristic::characteristic_name_of};
+/// use uuid::uuid;
+///
+/// assert_eq!(Some("Battery Level"), characteristic_name_of(&uuid_from_u16(0x2a19)));
+/// assert_eq!(None, characteristic_name_of(&uuid!("00002a19-0000-0000-0000-000000000000")));
+/// ```
+pub fn characteristic_name_of(uuid: &Uuid) -> Option<&'static str> {
+    match short_form(uuid) {
+        Some(ShortUuid::Uuid16(value)) => characteristic_name(value),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::uuid;
+
+    use crate::{uuid_from_u16, uuid_from_u32, uuids::characteristic::*};
+
+    #[test]
+    fn test_characteristic_names_sorted() {
+        assert!(CHARACTERISTIC_NAMES.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn test_characteristic_name() {
+        assert_eq!(Some("Device Name"), characteristic_name(0x2a00));
+        assert_eq!(Some("Battery Level"), characteristic_name(0x2a19));
+        assert_eq!(Some("Heart Rate Measurement"), characteristic_name(0x2a37));
+        assert_eq!(Some("Broadcast Receive State"), characteristic_name(0x2bc8));
+        assert_eq!(None, characteristic_name(0x2902));
+        assert_eq!(None, characteristic_name(0x2a0b));
+    }
+
+    #[test]
+    fn test_characteristic_name_of() {
+        assert_eq!(
+            Some("Battery Level"),
+            characteristic_name_of(&uuid_from_u16(0x2a19))
+        );
+        assert_eq!(None, characteristic_name_of(&uuid_from_u32(0x00012a19)));
+        assert_eq!(
+            None,
+            characteristic_name_of(&uuid!("00002a19-0000-0000-0000-000000000000"))
+        );
+    }
+}
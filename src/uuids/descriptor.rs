@@ -0,0 +1,133 @@
+//! GATT descriptor UUID registry module.
+
+use uuid::Uuid;
+
+use crate::{short_form, ShortUuid};
+
+/// GATT descriptor names.
+const DESCRIPTOR_NAMES: [(u16, &str); 22] = [
+    (0x2900, "Characteristic Extended Properties"),
+    (0x2901, "Characteristic User Description"),
+    (0x2902, "Client Characteristic Configuration"),
+    (0x2903, "Server Characteristic Configuration"),
+    (0x2904, "Characteristic Presentation Format"),
+    (0x2905, "Characteristic Aggregate Format"),
+    (0x2906, "Valid Range"),
+    (0x2907, "External Report Reference"),
+    (0x2908, "Report Reference"),
+    (0x2909, "Number of Digitals"),
+    (0x290a, "Value Trigger Setting"),
+    (0x290b, "Environmental Sensing Configuration"),
+    (0x290c, "Environmental Sensing Measurement"),
+    (0x290d, "Environmental Sensing Trigger Setting"),
+    (0x290e, "Time Trigger Setting"),
+    (0x290f, "Complete BR-EDR Transport Block Data"),
+    (0x2910, "Observation Schedule"),
+    (0x2911, "Valid Range and Accuracy"),
+    (0x2912, "Measurement Description"),
+    (0x2913, "Manufacturer Limits"),
+    (0x2914, "Process Tolerances"),
+    (0x2915, "IMD Trigger Setting"),
+];
+
+/// Get name of GATT descriptor UUID.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::uuids::descriptor::descriptor_name;
+///
+/// assert_eq!(Some("Client Characteristic Configuration"), descriptor_name(0x2902));
+/// assert_eq!(None, descriptor_name(0x2a19));
+/// ```
+pub fn descriptor_name(uuid: u16) -> Option<&'static str> {
+    DESCRIPTOR_NAMES
+        .binary_search_by_key(&uuid, |&(key, _)| key)
+        .ok()
+        .map(|index| DESCRIPTOR_NAMES[index].1)
+}
+
+/// Get name of GATT descriptor [`Uuid`].
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::{uuid_from_u16, uuids::descriptor::descriptor_name_of};
+/// use uuid::uuid;
+///
+/// assert_eq!(Some("Client Characteristic Configuration"), descriptor_name_of(&uuid_from_u16(0x2902)));
+/// assert_eq!(None, descriptor_name_of(&uuid!("00002902-0000-0000-0000-000000000000")));
+/// ```
+pub fn descriptor_name_of(uuid: &Uuid) -> Option<&'static str> {
+    match short_form(uuid) {
+        Some(ShortUuid::Uuid16(value)) => descriptor_name(value),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::uuid;
+
+    use crate::{
+        descriptors::{
+            characteristic_aggregate_format::CharacteristicAggregateFormat,
+            characteristic_extended_properties::CharacteristicExtendedProperties,
+            characteristic_presentation_format::CharacteristicPresentationFormat,
+            characteristic_user_description::CharacteristicUserDescription,
+            client_characteristic_configuration::ClientCharacteristicConfiguration,
+            server_characteristic_configuration::ServerCharacteristicConfiguration,
+        },
+        uuid_from_u16, uuid_from_u32,
+        uuids::descriptor::*,
+        Uuid16bit,
+    };
+
+    #[test]
+    fn test_descriptor_names_sorted() {
+        assert!(DESCRIPTOR_NAMES.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn test_descriptor_name() {
+        assert_eq!(
+            Some("Characteristic Extended Properties"),
+            descriptor_name(CharacteristicExtendedProperties::uuid_16bit())
+        );
+        assert_eq!(
+            Some("Characteristic User Description"),
+            descriptor_name(CharacteristicUserDescription::uuid_16bit())
+        );
+        assert_eq!(
+            Some("Client Characteristic Configuration"),
+            descriptor_name(ClientCharacteristicConfiguration::uuid_16bit())
+        );
+        assert_eq!(
+            Some("Server Characteristic Configuration"),
+            descriptor_name(ServerCharacteristicConfiguration::uuid_16bit())
+        );
+        assert_eq!(
+            Some("Characteristic Presentation Format"),
+            descriptor_name(CharacteristicPresentationFormat::uuid_16bit())
+        );
+        assert_eq!(
+            Some("Characteristic Aggregate Format"),
+            descriptor_name(CharacteristicAggregateFormat::uuid_16bit())
+        );
+        assert_eq!(Some("IMD Trigger Setting"), descriptor_name(0x2915));
+        assert_eq!(None, descriptor_name(0x2a19));
+    }
+
+    #[test]
+    fn test_descriptor_name_of() {
+        assert_eq!(
+            Some("Client Characteristic Configuration"),
+            descriptor_name_of(&uuid_from_u16(0x2902))
+        );
+        assert_eq!(None, descriptor_name_of(&uuid_from_u32(0x00012902)));
+        assert_eq!(
+            None,
+            descriptor_name_of(&uuid!("00002902-0000-0000-0000-000000000000"))
+        );
+    }
+}
@@ -0,0 +1,353 @@
+//! SIG member service UUID (0xfe00 - 0xfeff) registry module.
+
+use uuid::Uuid;
+
+use crate::{short_form, ShortUuid};
+
+/// SIG member service UUID owner names.
+const MEMBER_SERVICE_NAMES: [(u16, &str); 256] = [
+    (0xfe00, "Amazon.com Services, Inc."),
+    (0xfe01, "Duracell U.S. Operations Inc."),
+    (0xfe02, "Robert Bosch GmbH"),
+    (0xfe03, "Amazon.com Services, Inc."),
+    (0xfe04, "OpenPath Security Inc"),
+    (0xfe05, "CORE Transport Technologies NZ Limited"),
+    (0xfe06, "Qualcomm Technologies, Inc."),
+    (0xfe07, "Sonos, Inc."),
+    (0xfe08, "Microsoft"),
+    (0xfe09, "Pillsy, Inc."),
+    (0xfe0a, "ruwido austria gmbh"),
+    (0xfe0b, "ruwido austria gmbh"),
+    (0xfe0c, "Procter & Gamble"),
+    (0xfe0d, "Procter & Gamble"),
+    (0xfe0e, "Setec Pty Ltd"),
+    (
+        0xfe0f,
+        "Signify Netherlands B.V. (formerly Philips Lighting B.V.)",
+    ),
+    (0xfe10, "Lapis Semiconductor Co., Ltd."),
+    (0xfe11, "GMC-I Messtechnik GmbH"),
+    (0xfe12, "M-Way Solutions GmbH"),
+    (0xfe13, "Apple Inc."),
+    (0xfe14, "Flextronics International USA Inc."),
+    (0xfe15, "Amazon.com Services, Inc."),
+    (0xfe16, "Footmarks, Inc."),
+    (0xfe17, "Telit Wireless Solutions GmbH"),
+    (0xfe18, "Runtime, Inc."),
+    (0xfe19, "Google, LLC"),
+    (0xfe1a, "Tyto Life LLC"),
+    (0xfe1b, "Tyto Life LLC"),
+    (0xfe1c, "NetMedia, Inc."),
+    (0xfe1d, "Illuminati Instrument Corporation"),
+    (0xfe1e, "Smart Innovations Co., Ltd"),
+    (0xfe1f, "Garmin International, Inc."),
+    (0xfe20, "Emerson"),
+    (0xfe21, "Bose Corporation"),
+    (0xfe22, "Zoll Medical Corporation"),
+    (0xfe23, "Zoll Medical Corporation"),
+    (0xfe24, "August Home Inc"),
+    (0xfe25, "Apple, Inc."),
+    (0xfe26, "Google"),
+    (0xfe27, "Google"),
+    (0xfe28, "Ayla Networks"),
+    (0xfe29, "Gibson Innovations"),
+    (0xfe2a, "DaisyWorks, Inc."),
+    (0xfe2b, "ITT Industries"),
+    (0xfe2c, "Google"),
+    (0xfe2d, "SMART INNOVATION Co.,Ltd"),
+    (0xfe2e, "ERi,Inc."),
+    (0xfe2f, "CRESCO Wireless, Inc"),
+    (0xfe30, "Volkswagen AG"),
+    (0xfe31, "Volkswagen AG"),
+    (0xfe32, "Pro-Mark, Inc."),
+    (0xfe33, "CHIPOLO d.o.o."),
+    (0xfe34, "SmallLoop LLC"),
+    (0xfe35, "HUAWEI Technologies Co., Ltd"),
+    (0xfe36, "HUAWEI Technologies Co., Ltd"),
+    (0xfe37, "Spaceek LTD"),
+    (0xfe38, "Spaceek LTD"),
+    (0xfe39, "TTS Tooltechnic Systems AG & Co. KG"),
+    (0xfe3a, "TTS Tooltechnic Systems AG & Co. KG"),
+    (0xfe3b, "Dolby Laboratories"),
+    (0xfe3c, "alibaba"),
+    (0xfe3d, "BD Medical"),
+    (0xfe3e, "BD Medical"),
+    (0xfe3f, "Friday Labs Limited"),
+    (0xfe40, "Inugo Systems Limited"),
+    (0xfe41, "Inugo Systems Limited"),
+    (0xfe42, "Nets A/S"),
+    (0xfe43, "Andreas Stihl AG & Co. KG"),
+    (0xfe44, "SK Telecom"),
+    (0xfe45, "Snapchat Inc"),
+    (0xfe46, "B&O Play A/S"),
+    (0xfe47, "General Motors"),
+    (0xfe48, "General Motors"),
+    (0xfe49, "SenionLab AB"),
+    (0xfe4a, "OMRON HEALTHCARE Co., Ltd."),
+    (
+        0xfe4b,
+        "Signify Netherlands B.V. (formerly Philips Lighting B.V.)",
+    ),
+    (0xfe4c, "Volkswagen AG"),
+    (0xfe4d, "Casambi Technologies Oy"),
+    (0xfe4e, "NTT docomo"),
+    (0xfe4f, "Molekule, Inc."),
+    (0xfe50, "Google Inc."),
+    (0xfe51, "SRAM"),
+    (0xfe52, "SetPoint Medical"),
+    (0xfe53, "3M"),
+    (0xfe54, "Motiv, Inc."),
+    (0xfe55, "Google Inc."),
+    (0xfe56, "Google Inc."),
+    (0xfe57, "Dotted Labs"),
+    (0xfe58, "Nordic Semiconductor ASA"),
+    (0xfe59, "Nordic Semiconductor ASA"),
+    (0xfe5a, "Cronologics Corporation"),
+    (0xfe5b, "GT-tronics HK Ltd"),
+    (0xfe5c, "million hunters GmbH"),
+    (0xfe5d, "Grundfos A/S"),
+    (0xfe5e, "Plastc Corporation"),
+    (0xfe5f, "Eyefi, Inc."),
+    (0xfe60, "Lierda Science & Technology Group Co., Ltd."),
+    (0xfe61, "Logitech International SA"),
+    (0xfe62, "Indagem Tech LLC"),
+    (0xfe63, "Connected Yard, Inc."),
+    (0xfe64, "Siemens AG"),
+    (0xfe65, "CHIPOLO d.o.o."),
+    (0xfe66, "Intel Corporation"),
+    (0xfe67, "Lab Sensor Solutions"),
+    (0xfe68, "Capsule Technologies Inc."),
+    (0xfe69, "Capsule Technologies Inc."),
+    (0xfe6a, "Kontakt Micro-Location Sp. z o.o."),
+    (0xfe6b, "TASER International, Inc."),
+    (0xfe6c, "TASER International, Inc."),
+    (0xfe6d, "The University of Tokyo"),
+    (0xfe6e, "The University of Tokyo"),
+    (0xfe6f, "LINE Corporation"),
+    (0xfe70, "Beijing Jingdong Century Trading Co., Ltd."),
+    (0xfe71, "Plume Design Inc"),
+    (0xfe72, "Abbott (formerly St. Jude Medical, Inc.)"),
+    (0xfe73, "Abbott (formerly St. Jude Medical, Inc.)"),
+    (0xfe74, "unwire"),
+    (0xfe75, "TangoMe"),
+    (0xfe76, "TangoMe"),
+    (0xfe77, "Hewlett-Packard Company"),
+    (0xfe78, "Hewlett-Packard Company"),
+    (0xfe79, "Zebra Technologies"),
+    (0xfe7a, "Bragi GmbH"),
+    (0xfe7b, "Orion Labs, Inc."),
+    (
+        0xfe7c,
+        "Telit Wireless Solutions (Formerly Stollmann E+V GmbH)",
+    ),
+    (0xfe7d, "Aterica Health Inc."),
+    (0xfe7e, "Awear Solutions Ltd"),
+    (0xfe7f, "Doppler Lab"),
+    (0xfe80, "Doppler Lab"),
+    (0xfe81, "Medtronic Inc."),
+    (0xfe82, "Medtronic Inc."),
+    (0xfe83, "Blue Bite"),
+    (0xfe84, "RF Digital Corp"),
+    (0xfe85, "RF Digital Corp"),
+    (0xfe86, "HUAWEI Technologies Co., Ltd."),
+    (0xfe87, "Qingdao Yeelink Information Technology Co., Ltd."),
+    (0xfe88, "SALTO SYSTEMS S.L."),
+    (0xfe89, "B&O Play A/S"),
+    (0xfe8a, "Apple, Inc."),
+    (0xfe8b, "Apple, Inc."),
+    (0xfe8c, "TRON Forum"),
+    (0xfe8d, "Interaxon Inc."),
+    (0xfe8e, "ARM Ltd"),
+    (0xfe8f, "CSR"),
+    (0xfe90, "JUMA"),
+    (0xfe91, "Shanghai Imilab Technology Co.,Ltd"),
+    (0xfe92, "Jarden Safety & Security"),
+    (0xfe93, "OttoQ In"),
+    (0xfe94, "OttoQ In"),
+    (0xfe95, "Xiaomi Inc."),
+    (0xfe96, "Tesla Motors Inc."),
+    (0xfe97, "Tesla Motors Inc."),
+    (0xfe98, "Currant Inc"),
+    (0xfe99, "Currant Inc"),
+    (0xfe9a, "Estimote"),
+    (0xfe9b, "Samsara Networks, Inc"),
+    (0xfe9c, "GSI Laboratories, Inc."),
+    (0xfe9d, "Mobiquity Networks Inc"),
+    (0xfe9e, "Dialog Semiconductor B.V."),
+    (0xfe9f, "Google"),
+    (0xfea0, "Google"),
+    (0xfea1, "Intrepid Control Systems, Inc."),
+    (0xfea2, "Intrepid Control Systems, Inc."),
+    (0xfea3, "ITT Industries"),
+    (0xfea4, "Paxton Access Ltd"),
+    (0xfea5, "GoPro, Inc."),
+    (0xfea6, "GoPro, Inc."),
+    (0xfea7, "UTC Fire and Security"),
+    (0xfea8, "Savant Systems LLC"),
+    (0xfea9, "Savant Systems LLC"),
+    (0xfeaa, "Google"),
+    (0xfeab, "Nokia"),
+    (0xfeac, "Nokia"),
+    (0xfead, "Nokia"),
+    (0xfeae, "Nokia"),
+    (0xfeaf, "Nest Labs Inc."),
+    (0xfeb0, "Nest Labs Inc."),
+    (0xfeb1, "Electronics Tomorrow Limited"),
+    (0xfeb2, "Microsoft Corporation"),
+    (0xfeb3, "Taobao"),
+    (0xfeb4, "WiSilica Inc."),
+    (0xfeb5, "WiSilica Inc."),
+    (0xfeb6, "Vencer Co., Ltd"),
+    (0xfeb7, "Facebook, Inc."),
+    (0xfeb8, "Facebook, Inc."),
+    (0xfeb9, "LG Electronics"),
+    (0xfeba, "Tencent Holdings Limited"),
+    (0xfebb, "adafruit industries"),
+    (0xfebc, "Dexcom Inc."),
+    (0xfebd, "Clover Network, Inc."),
+    (0xfebe, "Bose Corporation"),
+    (0xfebf, "Nod, Inc."),
+    (0xfec0, "KDDI Corporation"),
+    (0xfec1, "KDDI Corporation"),
+    (0xfec2, "Blue Spark Technologies, Inc."),
+    (0xfec3, "360fly, Inc."),
+    (0xfec4, "PLUS Location Systems"),
+    (0xfec5, "Realtek Semiconductor Corp."),
+    (0xfec6, "Kocomojo, LLC"),
+    (0xfec7, "Apple, Inc."),
+    (0xfec8, "Apple, Inc."),
+    (0xfec9, "Apple, Inc."),
+    (0xfeca, "Apple, Inc."),
+    (0xfecb, "Apple, Inc."),
+    (0xfecc, "Apple, Inc."),
+    (0xfecd, "Apple, Inc."),
+    (0xfece, "Apple, Inc."),
+    (0xfecf, "Apple, Inc."),
+    (0xfed0, "Apple, Inc."),
+    (0xfed1, "Apple, Inc."),
+    (0xfed2, "Apple, Inc."),
+    (0xfed3, "Apple, Inc."),
+    (0xfed4, "Apple, Inc."),
+    (0xfed5, "Plantronics Inc."),
+    (0xfed6, "Broadcom"),
+    (0xfed7, "Broadcom"),
+    (0xfed8, "Google"),
+    (0xfed9, "Pebble Technology Corporation"),
+    (0xfeda, "ISSC Technologies Corp."),
+    (0xfedb, "Perka, Inc."),
+    (0xfedc, "Jawbone"),
+    (0xfedd, "Jawbone"),
+    (0xfede, "Coin, Inc."),
+    (0xfedf, "Design SHIFT"),
+    (0xfee0, "Anhui Huami Information Technology Co., Ltd."),
+    (0xfee1, "Anhui Huami Information Technology Co., Ltd."),
+    (0xfee2, "Anki, Inc."),
+    (0xfee3, "Anki, Inc."),
+    (0xfee4, "Nordic Semiconductor ASA"),
+    (0xfee5, "Nordic Semiconductor ASA"),
+    (0xfee6, "Silvair, Inc."),
+    (0xfee7, "Tencent Holdings Limited"),
+    (0xfee8, "Quintic Corp."),
+    (0xfee9, "Quintic Corp."),
+    (0xfeea, "Swirl Networks, Inc."),
+    (0xfeeb, "Swirl Networks, Inc."),
+    (0xfeec, "Tile, Inc."),
+    (0xfeed, "Tile, Inc."),
+    (0xfeee, "Polar Electro Oy"),
+    (0xfeef, "Polar Electro Oy"),
+    (0xfef0, "Intel"),
+    (0xfef1, "CSR"),
+    (0xfef2, "CSR"),
+    (0xfef3, "Google"),
+    (0xfef4, "Google"),
+    (0xfef5, "Dialog Semiconductor GmbH"),
+    (0xfef6, "Wicentric, Inc."),
+    (0xfef7, "Aplix Corporation"),
+    (0xfef8, "Aplix Corporation"),
+    (0xfef9, "PayPal, Inc."),
+    (0xfefa, "PayPal, Inc."),
+    (
+        0xfefb,
+        "Telit Wireless Solutions (Formerly Stollmann E+V GmbH)",
+    ),
+    (0xfefc, "Gimbal, Inc."),
+    (0xfefd, "Gimbal, Inc."),
+    (0xfefe, "GN ReSound A/S"),
+    (0xfeff, "GN Netcom"),
+];
+
+/// Get owner name of SIG member service UUID.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::uuids::member_service::member_service_name;
+///
+/// assert_eq!(Some("Google"), member_service_name(0xfeaa));
+/// assert_eq!(Some("Xiaomi Inc."), member_service_name(0xfe95));
+/// assert_eq!(None, member_service_name(0x180f));
+/// ```
+pub fn member_service_name(uuid: u16) -> Option<&'static str> {
+    MEMBER_SERVICE_NAMES
+        .binary_search_by_key(&uuid, |&(key, _)| key)
+        .ok()
+        .map(|index| MEMBER_SERVICE_NAMES[index].1)
+}
+
+/// Get owner name of SIG member service [`Uuid`].
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::{uuid_from_u16, uuids::member_service::member_service_name_of};
+/// use uuid::uuid;
+///
+/// assert_eq!(Some("Google"), member_service_name_of(&uuid_from_u16(0xfeaa)));
+/// assert_eq!(None, member_service_name_of(&uuid!("0000feaa-0000-0000-0000-000000000000")));
+/// ```
+pub fn member_service_name_of(uuid: &Uuid) -> Option<&'static str> {
+    match short_form(uuid) {
+        Some(ShortUuid::Uuid16(value)) => member_service_name(value),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::uuid;
+
+    use crate::{uuid_from_u16, uuid_from_u32, uuids::member_service::*};
+
+    #[test]
+    fn test_member_service_names_sorted() {
+        assert!(MEMBER_SERVICE_NAMES.windows(2).all(|w| w[0].0 < w[1].0));
+        assert_eq!(0xfe00, MEMBER_SERVICE_NAMES[0].0);
+        assert_eq!(0xfeff, MEMBER_SERVICE_NAMES[255].0);
+    }
+
+    #[test]
+    fn test_member_service_name() {
+        assert_eq!(
+            Some("Amazon.com Services, Inc."),
+            member_service_name(0xfe00)
+        );
+        assert_eq!(Some("Google"), member_service_name(0xfeaa));
+        assert_eq!(Some("Apple, Inc."), member_service_name(0xfecb));
+        assert_eq!(Some("GN Netcom"), member_service_name(0xfeff));
+        assert_eq!(None, member_service_name(0xfdff));
+        assert_eq!(None, member_service_name(0x180f));
+    }
+
+    #[test]
+    fn test_member_service_name_of() {
+        assert_eq!(
+            Some("Google"),
+            member_service_name_of(&uuid_from_u16(0xfeaa))
+        );
+        assert_eq!(None, member_service_name_of(&uuid_from_u32(0x0001feaa)));
+        assert_eq!(
+            None,
+            member_service_name_of(&uuid!("0000feaa-0000-0000-0000-000000000000"))
+        );
+    }
+}
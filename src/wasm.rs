@@ -0,0 +1,287 @@
+//! wasm-bindgen (Web Bluetooth) module.
+
+use std::collections::BTreeMap;
+
+use js_sys::{Array, DataView, Map, Object, Reflect, Uint8Array};
+use uuid::Uuid;
+use wasm_bindgen::{prelude::wasm_bindgen, JsCast, JsValue};
+
+use crate::{bluez::BlueZAdvertisement, data_types::data_type_parser::DataTypeParseResults};
+
+/// Parse advertising payload into JS object.
+///
+/// Returned object has same property names as Web Bluetooth `BluetoothAdvertisingEvent`.
+///
+/// - `name`: `string` or `undefined`
+/// - `txPower`: `number` or `undefined`
+/// - `uuids`: `string[]`
+/// - `manufacturerData`: `Map<number, Uint8Array>`
+/// - `serviceData`: `Map<string, Uint8Array>`
+/// - `structures`: `{ dataType: number, data: Uint8Array, error?: string }[]`
+///
+/// # Examples
+///
+/// ```no_run
+/// use ble_data_struct::{data_types::tx_power_level::TxPowerLevel, wasm::parse};
+/// use js_sys::Reflect;
+///
+/// let data: Vec<u8> = TxPowerLevel::new(-4).into();
+/// let result = parse(&data);
+/// let tx_power = Reflect::get(&result, &"txPower".into()).unwrap();
+/// assert_eq!(Some(-4.0), tx_power.as_f64());
+/// ```
+#[wasm_bindgen(js_name = parseAdvertisingData)]
+pub fn parse(value: &[u8]) -> Object {
+    let results = DataTypeParseResults::from_payload_with_options(value, &Default::default());
+    let advertisement = BlueZAdvertisement::from(&results);
+
+    let uuids = Array::new();
+    for uuid in &advertisement.service_uuids {
+        uuids.push(&JsValue::from(uuid.to_string()));
+    }
+    let manufacturer_data = Map::new();
+    for (company_identifier, data) in &advertisement.manufacturer_data {
+        manufacturer_data.set(
+            &JsValue::from(*company_identifier),
+            &Uint8Array::from(data.as_slice()),
+        );
+    }
+    let service_data = Map::new();
+    for (uuid, data) in &advertisement.service_data {
+        service_data.set(
+            &JsValue::from(uuid.to_string()),
+            &Uint8Array::from(data.as_slice()),
+        );
+    }
+    let structures = Array::new();
+    for (index, result) in results.results.iter().enumerate() {
+        let offset = results.offsets[index];
+        let end = (offset + 1 + value[offset] as usize).min(value.len());
+        let structure = Object::new();
+        set(
+            &structure,
+            "dataType",
+            &value
+                .get(offset + 1)
+                .copied()
+                .map_or(JsValue::UNDEFINED, JsValue::from),
+        );
+        set(
+            &structure,
+            "data",
+            &Uint8Array::from(value.get(offset + 2..end).unwrap_or_default()),
+        );
+        if let Some(error) = result.error() {
+            set(&structure, "error", &JsValue::from(error));
+        }
+        structures.push(&structure);
+    }
+
+    let object = Object::new();
+    set(
+        &object,
+        "name",
+        &advertisement
+            .local_name
+            .map_or(JsValue::UNDEFINED, JsValue::from),
+    );
+    set(
+        &object,
+        "txPower",
+        &advertisement
+            .tx_power
+            .map_or(JsValue::UNDEFINED, JsValue::from),
+    );
+    set(&object, "uuids", &uuids);
+    set(&object, "manufacturerData", &manufacturer_data);
+    set(&object, "serviceData", &service_data);
+    set(&object, "structures", &structures);
+    object
+}
+
+/// Create advertising payload from JS object.
+///
+/// Reads `name`, `txPower`, `uuids`, `manufacturerData` and `serviceData` with the same shape as
+/// [`parse`] output. Map values may be `Uint8Array` or `DataView` (as in Web Bluetooth events).
+///
+/// # Examples
+///
+/// ```no_run
+/// use ble_data_struct::{
+///     data_types::tx_power_level::TxPowerLevel,
+///     wasm::{parse, serialize},
+/// };
+///
+/// let data: Vec<u8> = TxPowerLevel::new(-4).into();
+/// let result = serialize(&parse(&data));
+/// assert_eq!(Ok(data), result);
+/// ```
+#[wasm_bindgen(js_name = serializeAdvertisingData)]
+pub fn serialize(value: &JsValue) -> Result<Vec<u8>, JsValue> {
+    let mut advertisement = BlueZAdvertisement {
+        local_name: get(value, "name")?.as_string(),
+        tx_power: get(value, "txPower")?
+            .as_f64()
+            .map(|tx_power| tx_power as i8),
+        ..Default::default()
+    };
+
+    let uuids = get(value, "uuids")?;
+    if Array::is_array(&uuids) {
+        for uuid in Array::from(&uuids).iter() {
+            advertisement.service_uuids.push(parse_uuid(&uuid)?);
+        }
+    }
+
+    let mut error: Option<JsValue> = None;
+    let mut manufacturer_data: BTreeMap<u16, Vec<u8>> = BTreeMap::new();
+    if let Some(map) = get(value, "manufacturerData")?.dyn_ref::<Map>() {
+        map.for_each(
+            &mut |data, company_identifier| match company_identifier.as_f64() {
+                Some(company_identifier) if (0.0..=65535.0).contains(&company_identifier) => {
+                    manufacturer_data.insert(company_identifier as u16, to_vec(&data));
+                }
+                _ => {
+                    error.get_or_insert(JsValue::from(format!(
+                        "Invalid company identifier :{:?}",
+                        company_identifier
+                    )));
+                }
+            },
+        );
+    }
+    let mut service_data: BTreeMap<Uuid, Vec<u8>> = BTreeMap::new();
+    if let Some(map) = get(value, "serviceData")?.dyn_ref::<Map>() {
+        map.for_each(&mut |data, uuid| match parse_uuid(&uuid) {
+            Ok(uuid) => {
+                service_data.insert(uuid, to_vec(&data));
+            }
+            Err(uuid_error) => {
+                error.get_or_insert(uuid_error);
+            }
+        });
+    }
+    if let Some(error) = error {
+        return Err(error);
+    }
+    advertisement.manufacturer_data = manufacturer_data;
+    advertisement.service_data = service_data;
+    Ok((&advertisement).into())
+}
+
+fn set(target: &Object, key: &str, value: &JsValue) {
+    // defining a property on a plain object never fails
+    let _ = Reflect::set(target, &JsValue::from(key), value);
+}
+
+fn get(target: &JsValue, key: &str) -> Result<JsValue, JsValue> {
+    Reflect::get(target, &JsValue::from(key))
+}
+
+fn parse_uuid(value: &JsValue) -> Result<Uuid, JsValue> {
+    match value.as_string().map(|uuid| Uuid::parse_str(&uuid)) {
+        Some(Ok(uuid)) => Ok(uuid),
+        _ => Err(JsValue::from(format!("Invalid UUID :{:?}", value))),
+    }
+}
+
+fn to_vec(value: &JsValue) -> Vec<u8> {
+    match value.dyn_ref::<DataView>() {
+        Some(data_view) => Uint8Array::new_with_byte_offset_and_length(
+            &data_view.buffer(),
+            data_view.byte_offset() as u32,
+            data_view.byte_length() as u32,
+        )
+        .to_vec(),
+        None => Uint8Array::new(value).to_vec(),
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+#[cfg(test)]
+mod tests {
+    use js_sys::{Array, DataView, Map, Reflect, Uint8Array};
+    use wasm_bindgen::{JsCast, JsValue};
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use crate::{
+        data_types::{
+            complete_local_name::CompleteLocalName, data_type::DataType,
+            manufacturer_specific_data::ManufacturerSpecificData,
+            service_data_16bit_uuid::ServiceData16BitUUID,
+        },
+        uuid_from_u16,
+        wasm::{parse, serialize},
+    };
+
+    #[wasm_bindgen_test]
+    fn test_parse() {
+        let mut data: Vec<u8> = CompleteLocalName::new(&"name".to_string()).into();
+        data.append(&mut ServiceData16BitUUID::new(&uuid_from_u16(0x180f), &vec![0x64]).into());
+        data.append(&mut ManufacturerSpecificData::new(0x004c, &vec![0x01, 0x02]).into());
+        data.append(&mut vec![0x02, 0x0a]);
+        let result = parse(&data);
+
+        let get = |key: &str| Reflect::get(&result, &JsValue::from(key)).unwrap();
+        assert_eq!(Some("name".to_string()), get("name").as_string());
+        assert!(get("txPower").is_undefined());
+        assert_eq!(0, Array::from(&get("uuids")).length());
+
+        let manufacturer_data: Map = get("manufacturerData").unchecked_into();
+        let value: Uint8Array = manufacturer_data
+            .get(&JsValue::from(0x004c))
+            .unchecked_into();
+        assert_eq!(vec![0x01, 0x02], value.to_vec());
+
+        let service_data: Map = get("serviceData").unchecked_into();
+        let value: Uint8Array = service_data
+            .get(&JsValue::from(uuid_from_u16(0x180f).to_string()))
+            .unchecked_into();
+        assert_eq!(vec![0x64], value.to_vec());
+
+        let structures = Array::from(&get("structures"));
+        assert_eq!(4, structures.length());
+        let structure = structures.get(0);
+        let data_type = Reflect::get(&structure, &JsValue::from("dataType")).unwrap();
+        assert_eq!(
+            Some(CompleteLocalName::data_type() as f64),
+            data_type.as_f64()
+        );
+        let value: Uint8Array = Reflect::get(&structure, &JsValue::from("data"))
+            .unwrap()
+            .unchecked_into();
+        assert_eq!(b"name".to_vec(), value.to_vec());
+        assert!(Reflect::get(&structure, &JsValue::from("error"))
+            .unwrap()
+            .is_undefined());
+        let error = Reflect::get(&structures.get(3), &JsValue::from("error")).unwrap();
+        assert_eq!(Some("Invalid data size :2".to_string()), error.as_string());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_serialize() {
+        let mut data: Vec<u8> = CompleteLocalName::new(&"name".to_string()).into();
+        data.append(&mut ServiceData16BitUUID::new(&uuid_from_u16(0x180f), &vec![0x64]).into());
+        data.append(&mut ManufacturerSpecificData::new(0x004c, &vec![0x01, 0x02]).into());
+        assert_eq!(Ok(data.clone()), serialize(&parse(&data)));
+
+        let result = parse(&data);
+        let manufacturer_data = Map::new();
+        let data_view = DataView::new(&Uint8Array::from(&[0x00u8, 0x03][..]).buffer(), 1, 1);
+        manufacturer_data.set(&JsValue::from(0x004c), &data_view);
+        Reflect::set(
+            &result,
+            &JsValue::from("manufacturerData"),
+            &manufacturer_data,
+        )
+        .unwrap();
+        let mut expected: Vec<u8> = CompleteLocalName::new(&"name".to_string()).into();
+        expected.append(&mut ServiceData16BitUUID::new(&uuid_from_u16(0x180f), &vec![0x64]).into());
+        expected.append(&mut ManufacturerSpecificData::new(0x004c, &vec![0x03]).into());
+        assert_eq!(Ok(expected), serialize(&result));
+
+        let uuids = Array::of1(&JsValue::from("invalid"));
+        Reflect::set(&result, &JsValue::from("uuids"), &uuids).unwrap();
+        assert!(serialize(&result).is_err());
+    }
+}
@@ -1,7 +1,7 @@
 #[cfg(target_os = "windows")]
 use windows::{
     core::Error,
-    Storage::Streams::{DataReader, IBuffer},
+    Storage::Streams::{DataReader, DataWriter, IBuffer},
 };
 
 /// Convert [`IBuffer`] to [`Vec<u8>`].
@@ -40,12 +40,32 @@ pub fn i_buffer_to_vec(i_buffer: IBuffer) -> Result<Vec<u8>, Error> {
     }
 }
 
+/// Convert [`u8`] slice to [`IBuffer`].
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::windows::buffer::{i_buffer_to_vec, vec_to_i_buffer};
+///
+/// let data = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9].to_vec();
+///
+/// let result = vec_to_i_buffer(&data);
+/// assert!(result.is_ok());
+/// assert_eq!(data, i_buffer_to_vec(result.unwrap()).unwrap());
+/// ```
+#[cfg(target_os = "windows")]
+pub fn vec_to_i_buffer(value: &[u8]) -> Result<IBuffer, Error> {
+    let data_writer = DataWriter::new()?;
+    data_writer.WriteBytes(value)?;
+    data_writer.DetachBuffer()
+}
+
 #[cfg(target_os = "windows")]
 #[cfg(test)]
 mod tests {
     use windows::Storage::Streams::DataWriter;
 
-    use crate::windows::buffer::i_buffer_to_vec;
+    use crate::windows::buffer::{i_buffer_to_vec, vec_to_i_buffer};
 
     #[test]
     fn test_i_buffer_to_vec() {
@@ -58,4 +78,16 @@ mod tests {
         assert!(result.is_ok());
         assert_eq!(data.to_vec(), result.unwrap());
     }
+
+    #[test]
+    fn test_vec_to_i_buffer() {
+        let data = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9].to_vec();
+        let result = vec_to_i_buffer(&data);
+        assert!(result.is_ok());
+        assert_eq!(data, i_buffer_to_vec(result.unwrap()).unwrap());
+
+        let result = vec_to_i_buffer(&[]);
+        assert!(result.is_ok());
+        assert!(i_buffer_to_vec(result.unwrap()).unwrap().is_empty());
+    }
 }
@@ -2,7 +2,7 @@
 #[cfg(target_os = "windows")]
 use crate::{
     data_types::data_type_parser::{DataTypeParseResult, DataTypeParseResults},
-    windows::buffer::i_buffer_to_vec,
+    windows::buffer::{i_buffer_to_vec, vec_to_i_buffer},
 };
 #[cfg(target_os = "windows")]
 use windows::{
@@ -10,6 +10,7 @@ use windows::{
     Devices::Bluetooth::Advertisement::{
         BluetoothLEAdvertisement, BluetoothLEAdvertisementDataSection,
     },
+    Foundation::Collections::IVector,
 };
 
 #[cfg(target_os = "windows")]
@@ -100,12 +101,40 @@ impl TryFrom<BluetoothLEAdvertisement> for DataTypeParseResults {
     /// assert!(matches!(data_type_parse_results, Ok(_)));
     /// ```
     fn try_from(value: BluetoothLEAdvertisement) -> Result<Self, Self::Error> {
-        let data_sections = match value.DataSections() {
-            Ok(data_sections) => data_sections,
-            Err(error) => return Err(error.to_string()),
-        };
+        match value.DataSections() {
+            Ok(data_sections) => Self::try_from(data_sections),
+            Err(error) => Err(error.to_string()),
+        }
+    }
+}
+
+#[cfg(target_os = "windows")]
+impl TryFrom<IVector<BluetoothLEAdvertisementDataSection>> for DataTypeParseResults {
+    type Error = String;
 
-        let vec = data_sections
+    /// Create [`DataTypeParseResults`] from [`BluetoothLEAdvertisementDataSection`] collection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     data_type_parser::{DataTypeParseResult, DataTypeParseResults},
+    ///     tx_power_level::TxPowerLevel,
+    /// };
+    /// use ble_data_struct::windows::data_types::windows_data_type_parser::advertisement_from_payload;
+    ///
+    /// let data: Vec<u8> = TxPowerLevel::new(-4).into();
+    /// let advertisement = advertisement_from_payload(&data).unwrap();
+    ///
+    /// let result = DataTypeParseResults::try_from(advertisement.DataSections().unwrap());
+    /// assert!(result.is_ok());
+    /// assert!(matches!(
+    ///     result.unwrap().results[..],
+    ///     [DataTypeParseResult::TxPowerLevelResult(Ok(_))]
+    /// ));
+    /// ```
+    fn try_from(value: IVector<BluetoothLEAdvertisementDataSection>) -> Result<Self, Self::Error> {
+        let vec = value
             .into_iter()
             .map(|f| DataTypeParseResult::from(f))
             .collect();
@@ -114,6 +143,104 @@ impl TryFrom<BluetoothLEAdvertisement> for DataTypeParseResults {
     }
 }
 
+/// Create [`BluetoothLEAdvertisementDataSection`] list from advertising payload.
+///
+/// Splitting stops at a zero length byte (padding), and a truncated trailing structure is an error.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::data_types::{
+///     complete_local_name::CompleteLocalName, data_type::DataType, tx_power_level::TxPowerLevel,
+/// };
+/// use ble_data_struct::windows::{
+///     buffer::i_buffer_to_vec, data_types::windows_data_type_parser::data_sections_from_payload,
+/// };
+///
+/// let mut data: Vec<u8> = TxPowerLevel::new(-4).into();
+/// data.append(&mut CompleteLocalName::new(&"name".to_string()).into());
+///
+/// let result = data_sections_from_payload(&data);
+/// assert!(result.is_ok());
+/// let data_sections = result.unwrap();
+/// assert_eq!(2, data_sections.len());
+/// assert_eq!(TxPowerLevel::data_type(), data_sections[0].DataType().unwrap());
+/// assert_eq!(vec![0xfc], i_buffer_to_vec(data_sections[0].Data().unwrap()).unwrap());
+/// assert_eq!(CompleteLocalName::data_type(), data_sections[1].DataType().unwrap());
+/// assert_eq!(
+///     b"name".to_vec(),
+///     i_buffer_to_vec(data_sections[1].Data().unwrap()).unwrap()
+/// );
+///
+/// let data: Vec<u8> = vec![0x03, TxPowerLevel::data_type(), 0x00];
+/// let result = data_sections_from_payload(&data);
+/// assert!(result.is_err());
+/// assert_eq!(format!("Invalid data size :{}", data.len()), result.unwrap_err());
+/// ```
+#[cfg(target_os = "windows")]
+pub fn data_sections_from_payload(
+    value: &[u8],
+) -> Result<Vec<BluetoothLEAdvertisementDataSection>, String> {
+    let mut data_sections: Vec<BluetoothLEAdvertisementDataSection> = Vec::new();
+    let mut index = 0;
+    while index < value.len() {
+        let length = value[index] as usize;
+        if length == 0 {
+            break;
+        }
+        if index + length >= value.len() {
+            return Err(format!("Invalid data size :{}", value.len() - index).to_string());
+        }
+        let data_section =
+            vec_to_i_buffer(&value[index + 2..index + 1 + length]).and_then(|buffer| {
+                BluetoothLEAdvertisementDataSection::Create(value[index + 1], &buffer)
+            });
+        match data_section {
+            Ok(data_section) => data_sections.push(data_section),
+            Err(error) => return Err(error.to_string()),
+        }
+        index += 1 + length;
+    }
+    Ok(data_sections)
+}
+
+/// Create [`BluetoothLEAdvertisement`] from advertising payload.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::data_types::{
+///     data_type_parser::DataTypeParseResults,
+///     manufacturer_specific_data::ManufacturerSpecificData,
+/// };
+/// use ble_data_struct::windows::data_types::windows_data_type_parser::advertisement_from_payload;
+///
+/// let data: Vec<u8> = ManufacturerSpecificData::new(0x004c, &vec![0x01, 0x02]).into();
+///
+/// let result = advertisement_from_payload(&data);
+/// assert!(result.is_ok());
+/// let results = DataTypeParseResults::try_from(result.unwrap()).unwrap();
+/// assert_eq!(Some(&[0x01u8, 0x02][..]), results.manufacturer_data(0x004c));
+/// ```
+#[cfg(target_os = "windows")]
+pub fn advertisement_from_payload(value: &[u8]) -> Result<BluetoothLEAdvertisement, String> {
+    let data_sections = data_sections_from_payload(value)?;
+    let advertisement = match BluetoothLEAdvertisement::new() {
+        Ok(advertisement) => advertisement,
+        Err(error) => return Err(error.to_string()),
+    };
+    let advertisement_data_sections = match advertisement.DataSections() {
+        Ok(advertisement_data_sections) => advertisement_data_sections,
+        Err(error) => return Err(error.to_string()),
+    };
+    for data_section in data_sections {
+        if let Err(error) = advertisement_data_sections.Append(&data_section) {
+            return Err(error.to_string());
+        }
+    }
+    Ok(advertisement)
+}
+
 #[cfg(target_os = "windows")]
 #[cfg(test)]
 mod tests {
@@ -124,10 +251,20 @@ mod tests {
         Storage::Streams::DataWriter,
     };
 
-    use crate::data_types::{
-        advertising_interval::AdvertisingInterval,
-        data_type::DataType,
-        data_type_parser::{DataTypeParseResult, DataTypeParseResults},
+    use crate::{
+        data_types::{
+            advertising_interval::AdvertisingInterval,
+            complete_local_name::CompleteLocalName,
+            data_type::DataType,
+            data_type_parser::{DataTypeParseResult, DataTypeParseResults},
+            manufacturer_specific_data::ManufacturerSpecificData,
+        },
+        windows::{
+            buffer::i_buffer_to_vec,
+            data_types::windows_data_type_parser::{
+                advertisement_from_payload, data_sections_from_payload,
+            },
+        },
     };
 
     #[test]
@@ -165,4 +302,65 @@ mod tests {
 
         assert!(matches!(data_type_parse_results, Ok(_)));
     }
+    #[test]
+    fn test_try_from_data_sections() {
+        let mut data: Vec<u8> = AdvertisingInterval::new(1).into();
+        data.append(&mut CompleteLocalName::new(&"name".to_string()).into());
+        let advertisement = advertisement_from_payload(&data).unwrap();
+
+        let result = DataTypeParseResults::try_from(advertisement.DataSections().unwrap());
+        assert!(result.is_ok());
+        let results = result.unwrap();
+        assert!(matches!(
+            results.results[..],
+            [
+                DataTypeParseResult::AdvertisingIntervalResult(Ok(_)),
+                DataTypeParseResult::CompleteLocalNameResult(Ok(_))
+            ]
+        ));
+        assert_eq!(Some("name"), results.device_name());
+    }
+
+    #[test]
+    fn test_data_sections_from_payload() {
+        let mut data: Vec<u8> = AdvertisingInterval::new(1).into();
+        data.append(&mut vec![0x01, 0xff]);
+        data.append(&mut vec![0x00, 0x00]);
+        let result = data_sections_from_payload(&data);
+        assert!(result.is_ok());
+        let data_sections = result.unwrap();
+        assert_eq!(2, data_sections.len());
+        assert_eq!(
+            AdvertisingInterval::data_type(),
+            data_sections[0].DataType().unwrap()
+        );
+        assert_eq!(
+            vec![0x01, 0x00],
+            i_buffer_to_vec(data_sections[0].Data().unwrap()).unwrap()
+        );
+        assert_eq!(0xff, data_sections[1].DataType().unwrap());
+        assert!(i_buffer_to_vec(data_sections[1].Data().unwrap())
+            .unwrap()
+            .is_empty());
+
+        let result = data_sections_from_payload(&[]);
+        assert!(result.unwrap().is_empty());
+
+        let data: Vec<u8> = vec![0x02, 0x0a, 0x00, 0x05, 0x09];
+        let result = data_sections_from_payload(&data);
+        assert!(result.is_err());
+        assert_eq!("Invalid data size :2", result.unwrap_err());
+    }
+
+    #[test]
+    fn test_advertisement_from_payload() {
+        let data: Vec<u8> = ManufacturerSpecificData::new(0x004c, &vec![0x01, 0x02]).into();
+        let result = advertisement_from_payload(&data);
+        assert!(result.is_ok());
+        let results = DataTypeParseResults::try_from(result.unwrap()).unwrap();
+        assert_eq!(Some(&[0x01u8, 0x02][..]), results.manufacturer_data(0x004c));
+
+        let result = advertisement_from_payload(&[0x02, 0x0a]);
+        assert!(result.is_err());
+    }
 }
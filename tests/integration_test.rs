@@ -1,3 +1,4 @@
+use ble_data_struct::test_vectors::{parse_corpus, run_all};
 use uuid::{uuid, Uuid};
 
 #[test]
@@ -12,7 +13,14 @@ fn test_uuid() {
     assert_eq!(expected, Uuid::from_u128(serialized));
 }
 
-#[cfg(target_os = "windows")]
+#[test]
+fn test_vectors() {
+    let vectors = parse_corpus(include_str!("vectors/advertisements.txt")).unwrap();
+    assert_eq!(6, vectors.len());
+    assert_eq!(Ok(()), run_all(&vectors));
+}
+
+#[cfg(all(target_os = "windows", feature = "windows"))]
 mod windows_tests {
     use ble_data_struct::{
         data_types::data_type_parser::DataTypeParseResult, windows::buffer::i_buffer_to_vec,
@@ -239,10 +247,12 @@ mod windows_tests {
                             Err(_) => panic!(),
                             _ => {}
                         },
+                        #[cfg(feature = "le-audio")]
                         DataTypeParseResult::BigInfoResult(result) => match result {
                             Err(_) => panic!(),
                             _ => {}
                         },
+                        #[cfg(feature = "le-audio")]
                         DataTypeParseResult::BroadcastCodeResult(result) => match result {
                             Err(_) => panic!(),
                             _ => {}
@@ -257,10 +267,18 @@ mod windows_tests {
                             Err(_) => panic!(),
                             _ => {}
                         },
+                        DataTypeParseResult::ElectronicShelfLabelResult(result) => match result {
+                            Err(_) => panic!(),
+                            _ => {}
+                        },
                         DataTypeParseResult::EncryptedDataResult(result) => match result {
                             Err(_) => panic!(),
                             _ => {}
                         },
+                        DataTypeParseResult::IndoorPositioningResult(result) => match result {
+                            Err(_) => panic!(),
+                            _ => {}
+                        },
                         DataTypeParseResult::LeBluetoothDeviceAddressResult(result) => match result
                         {
                             Err(_) => panic!(),
@@ -270,12 +288,14 @@ mod windows_tests {
                             Err(_) => panic!(),
                             _ => {}
                         },
+                        #[cfg(feature = "pairing")]
                         DataTypeParseResult::LeSecureConnectionsConfirmationValueResult(result) => {
                             match result {
                                 Err(_) => panic!(),
                                 _ => {}
                             }
                         }
+                        #[cfg(feature = "pairing")]
                         DataTypeParseResult::LeSecureConnectionsRandomValueResult(result) => {
                             match result {
                                 Err(_) => panic!(),
@@ -304,6 +324,11 @@ mod windows_tests {
                                 _ => {}
                             }
                         }
+                        #[cfg(feature = "mesh")]
+                        DataTypeParseResult::MeshBeaconResult(result) => match result {
+                            Err(_) => panic!(),
+                            _ => {}
+                        },
                         DataTypeParseResult::PeriodicAdvertisingResponseTimingInformationResult(
                             result,
                         ) => match result {
@@ -324,35 +349,48 @@ mod windows_tests {
                             Err(_) => panic!(),
                             _ => {}
                         },
+                        #[cfg(feature = "le-audio")]
+                        DataTypeParseResult::ResolvableSetIdentifierResult(result) => {
+                            match result {
+                                Err(_) => panic!(),
+                                _ => {}
+                            }
+                        }
+                        #[cfg(feature = "pairing")]
                         DataTypeParseResult::SecureSimplePairingHashC192Result(result) => {
                             match result {
                                 Err(_) => panic!(),
                                 _ => {}
                             }
                         }
+                        #[cfg(feature = "pairing")]
                         DataTypeParseResult::SecureSimplePairingHashC256Result(result) => {
                             match result {
                                 Err(_) => panic!(),
                                 _ => {}
                             }
                         }
+                        #[cfg(feature = "pairing")]
                         DataTypeParseResult::SecureSimplePairingRandomizerR192Result(result) => {
                             match result {
                                 Err(_) => panic!(),
                                 _ => {}
                             }
                         }
+                        #[cfg(feature = "pairing")]
                         DataTypeParseResult::SecureSimplePairingRandomizerR256Result(result) => {
                             match result {
                                 Err(_) => panic!(),
                                 _ => {}
                             }
                         }
+                        #[cfg(feature = "pairing")]
                         DataTypeParseResult::SecurityManagerOutOfBandResult(result) => match result
                         {
                             Err(_) => panic!(),
                             _ => {}
                         },
+                        #[cfg(feature = "pairing")]
                         DataTypeParseResult::SecurityManagerTkValueResult(result) => match result {
                             Err(_) => panic!(),
                             _ => {}
@@ -369,6 +407,14 @@ mod windows_tests {
                             Err(_) => panic!(),
                             _ => {}
                         },
+                        DataTypeParseResult::ThreeDInformationDataResult(result) => match result {
+                            Err(_) => panic!(),
+                            _ => {}
+                        },
+                        DataTypeParseResult::TransportDiscoveryDataResult(result) => match result {
+                            Err(_) => panic!(),
+                            _ => {}
+                        },
                         DataTypeParseResult::TxPowerLevelResult(result) => match result {
                             Err(_) => panic!(),
                             _ => {}